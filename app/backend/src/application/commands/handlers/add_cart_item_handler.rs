@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use super::cart_loader::{find_purchasable_variant, load_cart, parse_sku_id};
+use crate::application::commands::models::AddCartItemCommand;
+use crate::application::dto::CartDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{CartRepository, ProductRepository};
+use crate::application::services::CartPricingService;
+
+/// カートアイテム追加ハンドラ（ユースケース）
+pub struct AddCartItemHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    product_repository: Arc<dyn ProductRepository>,
+    cart_pricing_service: Arc<CartPricingService>,
+}
+
+impl AddCartItemHandler {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository>,
+        product_repository: Arc<dyn ProductRepository>,
+        cart_pricing_service: Arc<CartPricingService>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            product_repository,
+            cart_pricing_service,
+        }
+    }
+
+    /// カートにアイテムを追加（同じSKUは数量を加算）
+    pub async fn handle(&self, command: AddCartItemCommand) -> Result<CartDTO, ApplicationError> {
+        println!(
+            "->> add_cart_item_handler: cart_id={}, sku_id={}",
+            command.cart_id, command.sku_id
+        );
+
+        let mut cart = load_cart(self.cart_repository.as_ref(), &command.cart_id).await?;
+        let sku_id = parse_sku_id(&command.sku_id)?;
+
+        let current_quantity = cart.get_item(&sku_id).map_or(0, |item| item.quantity());
+        let variant = find_purchasable_variant(
+            self.product_repository.as_ref(),
+            &sku_id,
+            current_quantity + command.quantity,
+        )
+        .await?;
+
+        // カートアイテムはSKU IDと数量のみ永続化され、商品情報は読み込み時に再取得される
+        let item = variant.cart_item(command.quantity)?;
+        cart.add_item(item)?;
+
+        self.cart_repository.save(&cart).await?;
+
+        // 保存後に読み直し、商品情報・価格を永続化層の値に揃える
+        let cart = load_cart(self.cart_repository.as_ref(), &command.cart_id).await?;
+        self.cart_pricing_service.price(&cart).await
+    }
+}
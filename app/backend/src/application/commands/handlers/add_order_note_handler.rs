@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use super::order_loader::load_order;
+use crate::application::commands::models::AddOrderNoteCommand;
+use crate::application::dto::OrderDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::OrderRepository;
+
+/// 注文備考追加ハンドラ（ユースケース）
+/// 備考はNoteAddedイベントとして注文のイベント履歴にも残る
+pub struct AddOrderNoteHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl AddOrderNoteHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// 注文備考追加を実行
+    pub async fn handle(&self, command: AddOrderNoteCommand) -> Result<OrderDTO, ApplicationError> {
+        println!("->> add_order_note_handler: order_id={}", command.order_id);
+
+        let mut order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        order.add_note(command.note)?;
+
+        self.order_repository.update(&order).await?;
+
+        Ok(OrderDTO::from_order(&order))
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use super::product_loader::{build_sku, load_product, save_product};
+use crate::application::commands::models::AddProductSkuCommand;
+use crate::application::dto::AdminProductDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ProductWriteRepository;
+
+/// SKU追加ハンドラ（ユースケース）
+pub struct AddProductSkuHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+}
+
+impl AddProductSkuHandler {
+    pub fn new(product_repository: Arc<dyn ProductWriteRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// 商品にSKUを追加（表示順の指定がなければ末尾に追加）
+    pub async fn handle(
+        &self,
+        command: AddProductSkuCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        println!(
+            "->> add_product_sku_handler: product_id={}, sku_code={}",
+            command.product_id, command.sku.sku_code
+        );
+
+        let mut product =
+            load_product(self.product_repository.as_ref(), &command.product_id).await?;
+
+        let next_display_order = product
+            .skus()
+            .iter()
+            .map(|sku| sku.display_order() + 1)
+            .max()
+            .unwrap_or(0);
+        let sku = build_sku(product.id(), &command.sku, next_display_order)?;
+        product.add_sku(sku)?;
+
+        save_product(self.product_repository.as_ref(), &product).await
+    }
+}
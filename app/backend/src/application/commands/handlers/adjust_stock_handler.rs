@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use super::cart_loader::parse_sku_id;
+use crate::application::commands::models::AdjustStockCommand;
+use crate::application::dto::StockMovementDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{ProductWriteRepository, StockMovementRepository};
+use crate::domain::{OrderId, StockMovement, StockMovementKind};
+
+/// 在庫調整ハンドラ（ユースケース）
+pub struct AdjustStockHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+    stock_movement_repository: Arc<dyn StockMovementRepository>,
+}
+
+impl AdjustStockHandler {
+    pub fn new(
+        product_repository: Arc<dyn ProductWriteRepository>,
+        stock_movement_repository: Arc<dyn StockMovementRepository>,
+    ) -> Self {
+        Self {
+            product_repository,
+            stock_movement_repository,
+        }
+    }
+
+    /// 入荷・補正・廃棄による在庫数の増減を反映し、在庫台帳に記録
+    pub async fn handle(
+        &self,
+        command: AdjustStockCommand,
+    ) -> Result<StockMovementDTO, ApplicationError> {
+        println!(
+            "->> adjust_stock_handler: sku_id={}, type={}, quantity={}",
+            command.sku_id, command.adjustment_type, command.quantity
+        );
+
+        let sku_id = parse_sku_id(&command.sku_id)?;
+        let kind = command.adjustment_type.parse::<StockMovementKind>()?;
+        // 廃棄は数量で指定するため、減算として台帳に記録する
+        let delta = match kind {
+            StockMovementKind::WriteOff => -command.quantity,
+            _ => command.quantity,
+        };
+        let reference_order_id = command
+            .reference_order_id
+            .as_deref()
+            .map(|order_id| {
+                Uuid::parse_str(order_id)
+                    .map(OrderId::from_uuid)
+                    .map_err(|_| {
+                        ApplicationError::InvalidInput(format!(
+                            "Invalid order ID format: {}",
+                            order_id
+                        ))
+                    })
+            })
+            .transpose()?;
+
+        let movement = StockMovement::adjustment(
+            sku_id.clone(),
+            kind,
+            delta,
+            command.reason,
+            reference_order_id,
+            command.actor,
+        )?;
+
+        // 現在の在庫で調整可能か検証（確定はリポジトリの条件付き更新で行う）
+        let mut product = self
+            .product_repository
+            .find_by_sku_id(&sku_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("SKU not found: {}", sku_id)))?;
+        let sku = product
+            .find_sku_by_id_mut(&sku_id)
+            .ok_or_else(|| ApplicationError::NotFound(format!("SKU not found: {}", sku_id)))?;
+        sku.adjust_stock(movement.stock_adjustment())?;
+
+        Ok(self
+            .stock_movement_repository
+            .record_adjustment(&movement)
+            .await?)
+    }
+}
@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use super::order_loader::load_order;
+use crate::application::commands::models::{AmendOrderCommand, CreateOrderCommandItem};
+use crate::application::dto::OrderDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{
+    CouponRepository, OrderRepository, PaymentMethodRepository, PaymentRepository,
+    ProductRepository, PromotionRepository, ShippingMethodRepository,
+};
+use crate::application::services::OrderPricingService;
+use crate::domain::aggregates::order::{Order, OrderAmendment, OrderItem};
+use crate::domain::entities::{Coupon, ShippableItem};
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::*;
+use crate::domain::{CartItem, PaymentState};
+
+/// 注文変更ハンドラ（ユースケース）
+/// 支払い前の注文の明細・配送方法・配送先・支払い方法を変更し、金額を再計算する
+pub struct AmendOrderHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    product_repository: Arc<dyn ProductRepository>,
+    coupon_repository: Arc<dyn CouponRepository>,
+    payment_repository: Arc<dyn PaymentRepository>,
+    order_pricing_service: OrderPricingService,
+    tax_policy: TaxPolicy,
+}
+
+impl AmendOrderHandler {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        product_repository: Arc<dyn ProductRepository>,
+        shipping_method_repository: Arc<dyn ShippingMethodRepository>,
+        payment_method_repository: Arc<dyn PaymentMethodRepository>,
+        coupon_repository: Arc<dyn CouponRepository>,
+        promotion_repository: Arc<dyn PromotionRepository>,
+        payment_repository: Arc<dyn PaymentRepository>,
+        tax_policy: TaxPolicy,
+    ) -> Self {
+        Self {
+            order_repository,
+            product_repository,
+            coupon_repository,
+            payment_repository,
+            order_pricing_service: OrderPricingService::new(
+                shipping_method_repository,
+                payment_method_repository,
+                promotion_repository,
+            ),
+            tax_policy,
+        }
+    }
+
+    /// 注文変更を実行
+    pub async fn handle(&self, command: AmendOrderCommand) -> Result<OrderDTO, ApplicationError> {
+        println!("->> amend_order_handler: order_id={}", command.order_id);
+
+        let mut order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+        // 料金の再計算の前に変更可能な状態かを確認する（注文の変更時にも再確認される）
+        if !order.can_be_modified() {
+            return Err(ApplicationError::Domain(
+                DomainError::BusinessRuleViolation(format!(
+                    "Only pending orders can be amended (current status: {})",
+                    order.status
+                )),
+            ));
+        }
+
+        // オーソリ済みの金額と変更後の合計がずれないよう、オーソリの取り消し後にのみ変更できる
+        let transactions = self.payment_repository.find_by_order_id(&order.id).await?;
+        if matches!(
+            PaymentState::from_transactions(&transactions),
+            PaymentState::Authorized { .. } | PaymentState::Captured { .. }
+        ) {
+            return Err(DomainError::BusinessRuleViolation(
+                "Orders with an authorized payment cannot be amended; void the authorization first"
+                    .to_string(),
+            )
+            .into());
+        }
+
+        // 1. 注文アイテムの作成（既存の明細は注文時の単価を維持し、追加分は現在の価格を使う）
+        let (mut order_items, cart_items, shippable_items) =
+            self.amend_order_items(&order, &command).await?;
+
+        // 2. 配送情報の再計算（未指定の場合は現在の配送方法・配送先を引き継ぐ）
+        let shipping_method_id = command
+            .shipping_method_id
+            .clone()
+            .unwrap_or_else(|| order.shipping_info.method_id_value().to_string());
+        let address = match &command.shipping_address {
+            Some(address) => Address::new(
+                address.postal_code.clone(),
+                address.prefecture.clone(),
+                address.city.clone(),
+                address.street_address.clone(),
+                address.building.clone(),
+            )
+            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?,
+            None => order.shipping_info.address.clone(),
+        };
+        let mut shipping_info = self
+            .order_pricing_service
+            .shipping_info(&shipping_method_id, address, &order_items, shippable_items)
+            .await?;
+
+        // 3. 割引の再計算（使用済みのクーポンは有効期限・使用回数に関わらず再適用する）
+        let coupon = self.find_redeemed_coupon(&order).await?;
+        let (applied_coupon, applied_promotions, line_discounts) = self
+            .order_pricing_service
+            .apply_discounts(cart_items, coupon, &mut shipping_info)
+            .await?;
+        order_items = order_items
+            .into_iter()
+            .zip(line_discounts)
+            .map(|(item, discount)| item.with_discount_amount(discount))
+            .collect();
+
+        // 4. 支払い情報の再計算（支払い方法が変わらない場合は支払い詳細を引き継ぐ）
+        let payment_method_id = command
+            .payment_method_id
+            .clone()
+            .unwrap_or_else(|| order.payment_info.method_id_value().to_string());
+        let mut payment_info = self
+            .order_pricing_service
+            .payment_info(&payment_method_id, &order_items)
+            .await?;
+        if payment_info.method_id == order.payment_info.method_id {
+            payment_info.payment_details = order.payment_info.payment_details.clone();
+        }
+
+        // 5. 注文の変更・保存（在庫引当の差分調整を含む）
+        let amended = order.amend(
+            OrderAmendment {
+                items: order_items,
+                shipping_info,
+                payment_info,
+                coupon: applied_coupon,
+                promotions: applied_promotions,
+            },
+            &self.tax_policy,
+        )?;
+        if amended {
+            self.order_repository.update(&order).await?;
+        }
+
+        Ok(OrderDTO::from_order(&order))
+    }
+
+    /// 変更後の注文アイテムと、割引計算用のカートアイテム、配送料計算用の商品情報を作成
+    async fn amend_order_items(
+        &self,
+        order: &Order,
+        command: &AmendOrderCommand,
+    ) -> Result<(Vec<OrderItem>, Vec<CartItem>, Vec<ShippableItem>), ApplicationError> {
+        let requested_items: Vec<CreateOrderCommandItem> = match &command.items {
+            Some(items) => items.clone(),
+            None => order
+                .items
+                .iter()
+                .map(|item| CreateOrderCommandItem {
+                    sku_id: item.sku_id.value().to_string(),
+                    quantity: item.quantity as u32,
+                })
+                .collect(),
+        };
+        if requested_items.is_empty() {
+            return Err(ApplicationError::InvalidInput(
+                "Order must have at least one item".to_string(),
+            ));
+        }
+
+        let sku_ids = requested_items
+            .iter()
+            .map(|item| {
+                Uuid::parse_str(&item.sku_id)
+                    .map(SKUId::from_uuid)
+                    .map_err(|_| {
+                        ApplicationError::InvalidInput(format!(
+                            "Invalid SKU ID format: {}",
+                            item.sku_id
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<SKUId>, ApplicationError>>()?;
+
+        let variants = self
+            .product_repository
+            .find_variants_by_ids(&sku_ids)
+            .await
+            .map_err(ApplicationError::Repository)?;
+
+        let mut order_items = Vec::new();
+        let mut cart_items = Vec::new();
+        let mut shippable_items = Vec::new();
+        for (item_request, sku_id) in requested_items.iter().zip(sku_ids) {
+            if item_request.quantity == 0 {
+                return Err(ApplicationError::InvalidInput(
+                    "Item quantity must be greater than zero".to_string(),
+                ));
+            }
+
+            let variant = variants
+                .iter()
+                .find(|v| v.id == item_request.sku_id)
+                .ok_or_else(|| {
+                    ApplicationError::NotFound(format!("SKU not found: {}", item_request.sku_id))
+                })?;
+
+            let order_item = match order.items.iter().find(|item| item.sku_id == sku_id) {
+                Some(existing) => {
+                    let mut order_item = existing.clone();
+                    order_item
+                        .update_quantity(item_request.quantity as i32)
+                        .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+                    order_item
+                }
+                None => {
+                    let sku_code = SKUCode::new(variant.sku_code.clone())
+                        .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+                    let product_name = ProductName::new(variant.name.clone())
+                        .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+                    let sku_name = SKUName::new(variant.name.clone())
+                        .map_err(|e| ApplicationError::InvalidInput(format!("{:?}", e)))?;
+                    let unit_price = Money::from_yen(variant.sale_price.unwrap_or(variant.price));
+
+                    OrderItem::new(
+                        sku_id,
+                        sku_code,
+                        product_name,
+                        sku_name,
+                        unit_price,
+                        item_request.quantity as i32,
+                    )
+                    .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?
+                    .with_tax_category(variant.tax_category)
+                }
+            };
+
+            cart_items.push(
+                variant
+                    .cart_item_at(order_item.unit_price, item_request.quantity)
+                    .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?,
+            );
+            shippable_items.push(variant.shippable_item(item_request.quantity));
+            order_items.push(order_item.with_discount_amount(Money::zero()));
+        }
+
+        Ok((order_items, cart_items, shippable_items))
+    }
+
+    /// 注文に適用済みのクーポンを取得（使用済みのため使用可否の検証は行わない）
+    async fn find_redeemed_coupon(
+        &self,
+        order: &Order,
+    ) -> Result<Option<Coupon>, ApplicationError> {
+        let Some(applied_coupon) = &order.coupon else {
+            return Ok(None);
+        };
+
+        let coupon = self
+            .coupon_repository
+            .find_by_code(&applied_coupon.code)
+            .await
+            .map_err(ApplicationError::Domain)?
+            .ok_or_else(|| {
+                ApplicationError::Domain(DomainError::InvalidCoupon {
+                    code: applied_coupon.code.value().to_string(),
+                    message: "Coupon not found".to_string(),
+                })
+            })?;
+
+        Ok(Some(coupon.as_redeemed()))
+    }
+}
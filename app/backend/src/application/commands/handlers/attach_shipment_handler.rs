@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use super::order_loader::load_order;
+use crate::application::commands::models::AttachShipmentCommand;
+use crate::application::dto::ShipmentDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::OrderRepository;
+use crate::domain::{DeliveryInfo, DeliveryStatus, DomainError};
+
+/// 出荷登録ハンドラ（ユースケース）
+pub struct AttachShipmentHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl AttachShipmentHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// 注文の配送先から配送情報を作成し、配送業者・追跡番号とともに注文へ紐付ける
+    pub async fn handle(
+        &self,
+        command: AttachShipmentCommand,
+    ) -> Result<ShipmentDTO, ApplicationError> {
+        println!(
+            "->> attach_shipment_handler: order_id={}, carrier={}",
+            command.order_id, command.carrier
+        );
+
+        let carrier = command.carrier.trim();
+        let tracking_number = command.tracking_number.trim();
+        if carrier.is_empty() || tracking_number.is_empty() {
+            return Err(ApplicationError::InvalidInput(
+                "Carrier and tracking number are required".to_string(),
+            ));
+        }
+
+        let mut order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        let mut delivery_info = DeliveryInfo::new(
+            order.customer_info.email.clone(),
+            order.customer_info.personal_info.clone(),
+            order.shipping_info.address.clone(),
+            order.customer_info.phone.clone(),
+            Some(order.shipping_info.method_name.clone()),
+        );
+        delivery_info
+            .set_tracking_info(carrier.to_string(), tracking_number.to_string())
+            .map_err(DomainError::from)?;
+        delivery_info
+            .update_status(DeliveryStatus::Processing)
+            .map_err(DomainError::from)?;
+
+        order.add_delivery_info(delivery_info)?;
+
+        self.order_repository.update(&order).await?;
+
+        let delivery_info = order
+            .delivery_info
+            .as_ref()
+            .expect("delivery info was just attached");
+        Ok(ShipmentDTO::from_delivery_info(&order.id, delivery_info))
+    }
+}
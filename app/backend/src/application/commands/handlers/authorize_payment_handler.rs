@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use super::order_loader::load_order;
+use super::payment_recorder::record_gateway_result;
+use crate::application::commands::models::AuthorizePaymentCommand;
+use crate::application::dto::PaymentResultDTO;
+use crate::application::error::ApplicationError;
+use crate::application::gateways::{PaymentAuthorizationRequest, PaymentGateway};
+use crate::application::repositories::{OrderRepository, PaymentRepository};
+use crate::domain::aggregates::order::OrderStatus;
+use crate::domain::error::DomainError;
+use crate::domain::{PaymentOperation, PaymentState};
+
+/// オーソリハンドラ（ユースケース）
+pub struct AuthorizePaymentHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    payment_repository: Arc<dyn PaymentRepository>,
+    payment_gateway: Arc<dyn PaymentGateway>,
+}
+
+impl AuthorizePaymentHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        payment_repository: Arc<dyn PaymentRepository>,
+        payment_gateway: Arc<dyn PaymentGateway>,
+    ) -> Self {
+        Self {
+            order_repository,
+            payment_repository,
+            payment_gateway,
+        }
+    }
+
+    /// 注文の合計金額でオーソリを実行
+    pub async fn handle(
+        &self,
+        command: AuthorizePaymentCommand,
+    ) -> Result<PaymentResultDTO, ApplicationError> {
+        println!(
+            "->> authorize_payment_handler: order_id={}",
+            command.order_id
+        );
+
+        let order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        if order.status != OrderStatus::Pending {
+            return Err(DomainError::BusinessRuleViolation(format!(
+                "Order is not awaiting payment (status: {})",
+                order.status
+            ))
+            .into());
+        }
+
+        let mut transactions = self.payment_repository.find_by_order_id(&order.id).await?;
+        if matches!(
+            PaymentState::from_transactions(&transactions),
+            PaymentState::Authorized { .. } | PaymentState::Captured { .. }
+        ) {
+            return Err(DomainError::BusinessRuleViolation(
+                "Payment is already authorized for this order".to_string(),
+            )
+            .into());
+        }
+
+        let amount = order.pricing.total;
+        let request = PaymentAuthorizationRequest {
+            order_id: order.id,
+            order_number: order.order_number.value().to_string(),
+            amount,
+            payment_token: command.payment_token,
+        };
+        let result = self.payment_gateway.authorize(&request).await;
+
+        let recorded = record_gateway_result(
+            self.payment_repository.as_ref(),
+            &order.id,
+            self.payment_gateway.provider(),
+            PaymentOperation::Authorize,
+            amount,
+            None,
+            result,
+        )
+        .await?;
+
+        transactions.push(recorded.clone());
+        let state = PaymentState::from_transactions(&transactions);
+
+        Ok(PaymentResultDTO::new(&order, &state, &recorded))
+    }
+}
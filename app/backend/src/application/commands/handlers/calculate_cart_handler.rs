@@ -2,10 +2,12 @@ use crate::application::commands::models::CalculateCartCommand;
 use crate::application::dto::{CalculateCartResultDto, CouponErrorDto};
 use crate::application::error::ApplicationError;
 use crate::application::repositories::{
-    CouponRepository, PaymentMethodRepository, ProductRepository, ShippingMethodRepository,
+    CouponRepository, PaymentMethodRepository, ProductRepository, PromotionRepository,
+    ShippingMethodRepository,
 };
-use crate::domain::value_objects::CouponCode;
-use crate::domain::{Cart, CartItem, Money, ProductId, ProductName, SKUId};
+use crate::domain::entities::ShipmentDetails;
+use crate::domain::value_objects::{CouponCode, TaxPolicy};
+use crate::domain::{Cart, SKUId};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -15,6 +17,8 @@ pub struct CalculateCartHandler {
     shipping_method_repository: Arc<dyn ShippingMethodRepository>,
     payment_method_repository: Arc<dyn PaymentMethodRepository>,
     coupon_repository: Arc<dyn CouponRepository>,
+    promotion_repository: Arc<dyn PromotionRepository>,
+    tax_policy: TaxPolicy,
 }
 
 impl CalculateCartHandler {
@@ -23,12 +27,16 @@ impl CalculateCartHandler {
         shipping_method_repository: Arc<dyn ShippingMethodRepository>,
         payment_method_repository: Arc<dyn PaymentMethodRepository>,
         coupon_repository: Arc<dyn CouponRepository>,
+        promotion_repository: Arc<dyn PromotionRepository>,
+        tax_policy: TaxPolicy,
     ) -> Self {
         Self {
             product_repository,
             shipping_method_repository,
             payment_method_repository,
             coupon_repository,
+            promotion_repository,
+            tax_policy,
         }
     }
 
@@ -63,12 +71,16 @@ impl CalculateCartHandler {
             }
         };
 
-        match cart.apply_coupon(coupon) {
+        // 適用条件（最低購入金額・対象商品など）を満たさないクーポンは外して coupon_error として返す
+        match cart.apply_coupon(coupon).and_then(|_| cart.calculate()) {
             Ok(_) => None,
-            Err(e) => Some(CouponErrorDto {
-                coupon_code: Some(coupon_code_str),
-                error_message: e.to_string(),
-            }),
+            Err(e) => {
+                cart.remove_coupon();
+                Some(CouponErrorDto {
+                    coupon_code: Some(coupon_code_str),
+                    error_message: e.to_string(),
+                })
+            }
         }
     }
 
@@ -116,8 +128,9 @@ impl CalculateCartHandler {
             }
         }
 
-        // 4. CartItemドメインオブジェクトを作成
+        // 4. CartItemドメインオブジェクトと配送料計算用の商品情報を作成
         let mut cart_items = Vec::new();
+        let mut shippable_items = Vec::new();
         for item_request in &command.items {
             if item_request.quantity == 0 {
                 return Err(ApplicationError::InvalidInput(
@@ -146,33 +159,13 @@ impl CalculateCartHandler {
                 )));
             }
 
-            // ドメインオブジェクトを作成
-            let sku_id =
-                SKUId::from_uuid(Uuid::parse_str(&variant.id).map_err(|_| {
-                    ApplicationError::InvalidInput("Invalid variant ID".to_string())
-                })?);
-
-            // ProductIdは実際のプロダクト情報から取得する必要があるが、
-            // 現在のVariantDTOには含まれていないため、新規UUIDを生成
-            // TODO: VariantDTOにproduct_idを追加するか、別途取得する
-            let product_id = ProductId::new();
-
-            let product_name = ProductName::new(variant.name.clone())
+            // ドメインオブジェクトを作成（商品・カテゴリはクーポンの対象判定に使う）
+            let cart_item = variant
+                .cart_item(item_request.quantity)
                 .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
 
-            // 価格を決定（セール価格がある場合はそれを使用）
-            let unit_price = Money::from_yen(variant.sale_price.unwrap_or(variant.price));
-
-            let cart_item = CartItem::new(
-                sku_id,
-                product_id,
-                product_name,
-                unit_price,
-                item_request.quantity,
-            )
-            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
-
             cart_items.push(cart_item);
+            shippable_items.push(variant.shippable_item(item_request.quantity));
         }
 
         // 5. 配送方法の取得
@@ -201,14 +194,24 @@ impl CalculateCartHandler {
                 ))
             })?;
 
-        // 7. Cart作成と設定（Domain層で全て完結）
+        // 7. 実施中のプロモーションの取得
+        let promotions = self
+            .promotion_repository
+            .find_running(chrono::Utc::now())
+            .await
+            .map_err(ApplicationError::Repository)?;
+
+        // 8. Cart作成と設定（Domain層で全て完結）
+        let shipment = ShipmentDetails::new(command.prefecture.clone(), shippable_items);
         let mut cart = Cart::from_items(cart_items);
-        cart.apply_shipping_method(&shipping_method)
+        cart.apply_tax_policy(self.tax_policy);
+        cart.apply_promotions(promotions);
+        cart.apply_shipping_method(&shipping_method, &shipment)
             .map_err(ApplicationError::Domain)?;
         cart.apply_payment_method(&payment_method)
             .map_err(ApplicationError::Domain)?;
 
-        // 8. クーポン適用処理（エラーハンドリング改善）
+        // 9. クーポン適用処理（エラーハンドリング改善）
         let coupon_error = if let Some(coupon_code_str) = command.coupon_code.clone() {
             self.try_apply_coupon(&mut cart, coupon_code_str).await
         } else {
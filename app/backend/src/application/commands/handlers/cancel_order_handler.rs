@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use super::order_loader::load_order;
+use crate::application::commands::models::CancelOrderCommand;
+use crate::application::dto::OrderDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::OrderRepository;
+
+/// 注文キャンセルハンドラ（ユースケース）
+pub struct CancelOrderHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl CancelOrderHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// 注文キャンセルを実行
+    pub async fn handle(&self, command: CancelOrderCommand) -> Result<OrderDTO, ApplicationError> {
+        println!("->> cancel_order_handler: order_id={}", command.order_id);
+
+        let mut order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        order.cancel(command.reason)?;
+
+        self.order_repository.update(&order).await?;
+
+        Ok(OrderDTO::from_order(&order))
+    }
+}
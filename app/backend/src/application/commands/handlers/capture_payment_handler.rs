@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use super::order_loader::load_order;
+use super::payment_recorder::record_gateway_result;
+use crate::application::commands::models::CapturePaymentCommand;
+use crate::application::dto::PaymentResultDTO;
+use crate::application::error::ApplicationError;
+use crate::application::gateways::PaymentGateway;
+use crate::application::repositories::{OrderRepository, PaymentRepository};
+use crate::domain::aggregates::order::OrderStatus;
+use crate::domain::error::DomainError;
+use crate::domain::{PaymentOperation, PaymentState};
+
+/// 売上確定ハンドラ（ユースケース）
+pub struct CapturePaymentHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    payment_repository: Arc<dyn PaymentRepository>,
+    payment_gateway: Arc<dyn PaymentGateway>,
+}
+
+impl CapturePaymentHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        payment_repository: Arc<dyn PaymentRepository>,
+        payment_gateway: Arc<dyn PaymentGateway>,
+    ) -> Self {
+        Self {
+            order_repository,
+            payment_repository,
+            payment_gateway,
+        }
+    }
+
+    /// オーソリ済みの決済を売上確定し、成功したら注文を支払い済みにする
+    pub async fn handle(
+        &self,
+        command: CapturePaymentCommand,
+    ) -> Result<PaymentResultDTO, ApplicationError> {
+        println!("->> capture_payment_handler: order_id={}", command.order_id);
+
+        let mut order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        let mut transactions = self.payment_repository.find_by_order_id(&order.id).await?;
+        let PaymentState::Authorized {
+            transaction_id,
+            amount,
+            ..
+        } = PaymentState::from_transactions(&transactions)
+        else {
+            return Err(DomainError::BusinessRuleViolation(
+                "No authorized payment to capture for this order".to_string(),
+            )
+            .into());
+        };
+
+        // オーソリ後に注文の合計が変わっていれば、オーソリの金額では売上確定しない
+        if amount != order.pricing.total {
+            return Err(DomainError::BusinessRuleViolation(format!(
+                "Authorized amount ({} yen) does not match the order total ({} yen); void and authorize again",
+                amount.yen(),
+                order.pricing.total.yen()
+            ))
+            .into());
+        }
+
+        // 決済代行会社を呼ぶ前に注文の状態遷移を検証する
+        order.update_status(OrderStatus::Paid)?;
+
+        let result = self.payment_gateway.capture(&transaction_id, amount).await;
+
+        let recorded = record_gateway_result(
+            self.payment_repository.as_ref(),
+            &order.id,
+            self.payment_gateway.provider(),
+            PaymentOperation::Capture,
+            amount,
+            Some(&transaction_id),
+            result,
+        )
+        .await?;
+
+        // 売上確定後に注文を更新できなかった場合（同時更新など）は、
+        // 代金だけを受け取った状態にならないよう売上確定分を返金して取り消す
+        if let Err(err) = self.order_repository.update(&order).await {
+            println!(
+                "->> capture_payment_handler: order update failed, refunding capture: {}",
+                err
+            );
+            let result = self.payment_gateway.refund(&transaction_id, amount).await;
+            record_gateway_result(
+                self.payment_repository.as_ref(),
+                &order.id,
+                self.payment_gateway.provider(),
+                PaymentOperation::Refund,
+                amount,
+                Some(&transaction_id),
+                result,
+            )
+            .await?;
+            return Err(err.into());
+        }
+
+        transactions.push(recorded.clone());
+        let state = PaymentState::from_transactions(&transactions);
+
+        Ok(PaymentResultDTO::new(&order, &state, &recorded))
+    }
+}
@@ -0,0 +1,62 @@
+use uuid::Uuid;
+
+use crate::application::dto::VariantDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{CartRepository, ProductRepository};
+use crate::domain::{Cart, CartId, SKUId};
+
+/// カートIDの文字列からカートを取得（カート操作系コマンド共通）
+pub(super) async fn load_cart(
+    cart_repository: &dyn CartRepository,
+    cart_id: &str,
+) -> Result<Cart, ApplicationError> {
+    let uuid = Uuid::parse_str(cart_id).map_err(|_| {
+        ApplicationError::InvalidInput(format!("Invalid cart ID format: {}", cart_id))
+    })?;
+
+    cart_repository
+        .find_by_id(&CartId::from_uuid(uuid))
+        .await?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Cart not found: {}", cart_id)))
+}
+
+/// SKU IDの文字列をパース
+pub(super) fn parse_sku_id(sku_id: &str) -> Result<SKUId, ApplicationError> {
+    Uuid::parse_str(sku_id)
+        .map(SKUId::from_uuid)
+        .map_err(|_| ApplicationError::InvalidInput(format!("Invalid SKU ID format: {}", sku_id)))
+}
+
+/// SKUを取得し、指定数量をカートに入れられるか検証
+/// 在庫の確保は注文作成時に行うため、ここでは引当済みを除いた在庫数との比較のみ
+pub(super) async fn find_purchasable_variant(
+    product_repository: &dyn ProductRepository,
+    sku_id: &SKUId,
+    quantity: u32,
+) -> Result<VariantDTO, ApplicationError> {
+    let variant = product_repository
+        .find_variants_by_ids(std::slice::from_ref(sku_id))
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ApplicationError::NotFound(format!("SKU not found: {}", sku_id)))?;
+
+    if variant.is_sold_out {
+        return Err(ApplicationError::InvalidInput(format!(
+            "SKU {} is sold out",
+            variant.sku_code
+        )));
+    }
+
+    let available = variant
+        .stock_quantity
+        .saturating_sub(variant.reserved_quantity);
+    if available < quantity {
+        return Err(ApplicationError::InvalidInput(format!(
+            "Insufficient stock for SKU {}: requested {}, available {}",
+            variant.sku_code, quantity, available
+        )));
+    }
+
+    Ok(variant)
+}
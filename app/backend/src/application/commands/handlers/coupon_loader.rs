@@ -0,0 +1,144 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use uuid::Uuid;
+
+use crate::application::commands::models::CouponDiscountCommandItem;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CouponRepository;
+use crate::domain::entities::Coupon;
+use crate::domain::value_objects::{
+    CategoryId, CouponId, DiscountCondition, DiscountPolicy, DiscountType, Money, ProductId,
+};
+
+/// クーポンIDの文字列からクーポンを取得（クーポン管理系コマンド共通）
+pub(super) async fn load_coupon(
+    coupon_repository: &dyn CouponRepository,
+    coupon_id: &str,
+) -> Result<Coupon, ApplicationError> {
+    let uuid = Uuid::parse_str(coupon_id).map_err(|_| {
+        ApplicationError::InvalidInput(format!("Invalid coupon ID format: {}", coupon_id))
+    })?;
+
+    coupon_repository
+        .find_by_id(&CouponId::from_uuid(uuid))
+        .await?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Coupon not found: {}", coupon_id)))
+}
+
+/// コマンドの指定から割引ポリシーを構築（割引種別に必要な項目がなければエラー）
+pub(super) fn build_discount_policy(
+    item: &CouponDiscountCommandItem,
+) -> Result<DiscountPolicy, ApplicationError> {
+    let required = |value: Option<u32>, field: &str| {
+        value.ok_or_else(|| {
+            ApplicationError::Validation(format!(
+                "{} discount requires {}",
+                item.discount_type, field
+            ))
+        })
+    };
+    let percentage = |value: Option<u32>| {
+        u8::try_from(required(value, "discount_value")?).map_err(|_| {
+            ApplicationError::Validation("Discount percentage must be 100 or less".to_string())
+        })
+    };
+
+    let discount_type = match item.discount_type.as_str() {
+        "fixed_amount" => DiscountType::FixedAmount(Money::from_yen(required(
+            item.discount_value,
+            "discount_value",
+        )?)),
+        "percentage" => DiscountType::Percentage(percentage(item.discount_value)?),
+        "capped_percentage" => DiscountType::CappedPercentage {
+            percentage: percentage(item.discount_value)?,
+            max_discount: Money::from_yen(required(
+                item.max_discount_amount,
+                "max_discount_amount",
+            )?),
+        },
+        "free_shipping" => DiscountType::FreeShipping,
+        "buy_x_get_y" => DiscountType::BuyXGetY {
+            buy_quantity: required(item.buy_quantity, "buy_quantity")?,
+            get_quantity: required(item.get_quantity, "get_quantity")?,
+        },
+        other => {
+            return Err(ApplicationError::Validation(format!(
+                "Invalid discount type: {} (expected fixed_amount, percentage, capped_percentage, free_shipping or buy_x_get_y)",
+                other
+            )));
+        }
+    };
+
+    let condition = build_discount_condition(item)?;
+
+    let policy = DiscountPolicy::new(discount_type, condition);
+    policy.validate()?;
+    Ok(policy)
+}
+
+/// 適用条件を構築（最低購入金額・対象商品・対象カテゴリはいずれか一つのみ指定できる）
+fn build_discount_condition(
+    item: &CouponDiscountCommandItem,
+) -> Result<Option<DiscountCondition>, ApplicationError> {
+    let specified = [
+        item.minimum_amount.is_some(),
+        !item.target_product_ids.is_empty(),
+        !item.target_category_ids.is_empty(),
+    ];
+    if specified.iter().filter(|s| **s).count() > 1 {
+        return Err(ApplicationError::Validation(
+            "Only one of minimum_amount, target_product_ids and target_category_ids can be specified"
+                .to_string(),
+        ));
+    }
+
+    if let Some(amount) = item.minimum_amount {
+        return Ok(Some(DiscountCondition::MinimumPurchase(Money::from_yen(
+            amount,
+        ))));
+    }
+
+    if !item.target_product_ids.is_empty() {
+        let product_ids = item
+            .target_product_ids
+            .iter()
+            .map(|id| {
+                Uuid::parse_str(id).map(ProductId::from_uuid).map_err(|_| {
+                    ApplicationError::InvalidInput(format!("Invalid product ID format: {}", id))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(Some(DiscountCondition::ProductSpecific(product_ids)));
+    }
+
+    if !item.target_category_ids.is_empty() {
+        let category_ids = item
+            .target_category_ids
+            .iter()
+            .map(|id| CategoryId::new(id.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(Some(DiscountCondition::CategorySpecific(category_ids)));
+    }
+
+    Ok(None)
+}
+
+/// 有効期間（YYYY-MM-DD）を日時に変換（開始日は0時から、終了日は終日有効）
+pub(super) fn parse_validity(
+    valid_from: &str,
+    valid_until: &str,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), ApplicationError> {
+    let parse = |value: &str| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+            ApplicationError::InvalidInput(format!(
+                "Invalid date format (expected YYYY-MM-DD): {}",
+                value
+            ))
+        })
+    };
+
+    let from = parse(valid_from)?.and_time(NaiveTime::MIN).and_utc();
+    let until =
+        parse(valid_until)?.and_time(NaiveTime::MIN).and_utc() + chrono::Duration::seconds(86_399);
+
+    Ok((from, until))
+}
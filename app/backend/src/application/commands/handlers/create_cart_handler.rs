@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::application::dto::CartDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CartRepository;
+use crate::application::services::CartPricingService;
+use crate::domain::Cart;
+
+/// カート作成ハンドラ（ユースケース）
+pub struct CreateCartHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    cart_pricing_service: Arc<CartPricingService>,
+}
+
+impl CreateCartHandler {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository>,
+        cart_pricing_service: Arc<CartPricingService>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            cart_pricing_service,
+        }
+    }
+
+    /// 空のカートを作成
+    pub async fn handle(&self) -> Result<CartDTO, ApplicationError> {
+        // 放置されたカートはカート作成のタイミングでまとめて削除
+        let expired = self.cart_repository.delete_expired().await?;
+        if expired > 0 {
+            println!("->> create_cart_handler: deleted {} expired carts", expired);
+        }
+
+        let cart = Cart::new();
+        self.cart_repository.save(&cart).await?;
+
+        println!("->> create_cart_handler: cart_id={}", cart.id());
+
+        self.cart_pricing_service.price(&cart).await
+    }
+}
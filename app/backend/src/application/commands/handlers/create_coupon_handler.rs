@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use super::coupon_loader::{build_discount_policy, parse_validity};
+use crate::application::commands::models::CreateCouponCommand;
+use crate::application::dto::CouponDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CouponRepository;
+use crate::domain::entities::Coupon;
+use crate::domain::value_objects::CouponCode;
+
+/// クーポン作成ハンドラ（ユースケース）
+pub struct CreateCouponHandler {
+    coupon_repository: Arc<dyn CouponRepository>,
+}
+
+impl CreateCouponHandler {
+    pub fn new(coupon_repository: Arc<dyn CouponRepository>) -> Self {
+        Self { coupon_repository }
+    }
+
+    /// クーポンを作成
+    pub async fn handle(
+        &self,
+        command: CreateCouponCommand,
+    ) -> Result<CouponDTO, ApplicationError> {
+        println!(
+            "->> create_coupon_handler: code={}, discount_type={}",
+            command.code, command.discount.discount_type
+        );
+
+        let (valid_from, valid_until) = parse_validity(&command.valid_from, &command.valid_until)?;
+        let coupon = Coupon::create(
+            CouponCode::from_string(command.code)?,
+            command.name,
+            command.description,
+            build_discount_policy(&command.discount)?,
+            valid_from,
+            valid_until,
+            command.usage_limit,
+            command.per_customer_limit,
+        )?;
+
+        self.coupon_repository
+            .create_all(std::slice::from_ref(&coupon))
+            .await?;
+
+        Ok(CouponDTO::from_coupon(&coupon))
+    }
+}
@@ -2,20 +2,26 @@ use crate::application::commands::models::CreateOrderCommand;
 use crate::application::dto::CreateOrderResultDTO;
 use crate::application::error::ApplicationError;
 use crate::application::repositories::{
-    OrderRepository, PaymentMethodRepository, ProductRepository, ShippingMethodRepository,
+    CouponRepository, OrderRepository, PaymentMethodRepository, ProductRepository,
+    PromotionRepository, ShippingMethodRepository,
 };
+use crate::application::services::OrderPricingService;
+use crate::domain::CartItem;
 use crate::domain::aggregates::order::{CustomerInfo, Order, OrderItem, PaymentInfo, ShippingInfo};
+use crate::domain::entities::{Coupon, ShippableItem};
+use crate::domain::error::DomainError;
 use crate::domain::value_objects::*;
-use chrono::Datelike;
 use std::sync::Arc;
 use uuid::Uuid;
 
 /// 注文作成ハンドラ（ユースケース）
 pub struct CreateOrderHandler {
     product_repository: Arc<dyn ProductRepository>,
-    shipping_method_repository: Arc<dyn ShippingMethodRepository>,
-    payment_method_repository: Arc<dyn PaymentMethodRepository>,
     order_repository: Arc<dyn OrderRepository>,
+    coupon_repository: Arc<dyn CouponRepository>,
+    order_pricing_service: OrderPricingService,
+    tax_policy: TaxPolicy,
+    order_number_format: OrderNumberFormat,
 }
 
 impl CreateOrderHandler {
@@ -24,60 +30,112 @@ impl CreateOrderHandler {
         shipping_method_repository: Arc<dyn ShippingMethodRepository>,
         payment_method_repository: Arc<dyn PaymentMethodRepository>,
         order_repository: Arc<dyn OrderRepository>,
+        coupon_repository: Arc<dyn CouponRepository>,
+        promotion_repository: Arc<dyn PromotionRepository>,
+        tax_policy: TaxPolicy,
     ) -> Self {
         Self {
             product_repository,
-            shipping_method_repository,
-            payment_method_repository,
             order_repository,
+            coupon_repository,
+            order_pricing_service: OrderPricingService::new(
+                shipping_method_repository,
+                payment_method_repository,
+                promotion_repository,
+            ),
+            tax_policy,
+            order_number_format: OrderNumberFormat::default(),
         }
     }
 
+    /// 注文番号の書式を指定（未指定の場合はORD-YYYY-NNNNNN）
+    pub fn with_order_number_format(mut self, order_number_format: OrderNumberFormat) -> Self {
+        self.order_number_format = order_number_format;
+        self
+    }
+
     /// 注文作成を実行
     pub async fn handle(
         &self,
         command: CreateOrderCommand,
     ) -> Result<CreateOrderResultDTO, ApplicationError> {
-        // 1. 顧客情報の作成
+        // 1. 顧客情報の作成（ログイン中の場合は会員に紐付ける）
         let customer_info = self.create_customer_info(&command)?;
+        let customer_id = Self::parse_customer_id(&command)?;
+
+        // 2. 注文アイテムの作成（クーポン計算用のカートアイテムも同じSKU情報から作る）
+        let (mut order_items, cart_items, shippable_items) =
+            self.create_order_items(&command).await?;
+
+        // 3. 配送情報の作成（配送料は配送先と商品から料金表で求める）
+        let mut shipping_info = self
+            .create_shipping_info(&command, &order_items, shippable_items)
+            .await?;
+
+        // 4. プロモーション・クーポンの適用（送料無料の場合は配送料を免除する）
+        // 割引は対象の注文アイテムごとに割り当てる
+        let coupon = match &command.coupon_code {
+            Some(coupon_code) => Some(
+                self.find_usable_coupon(coupon_code, &customer_info.email)
+                    .await?,
+            ),
+            None => None,
+        };
+        let (applied_coupon, applied_promotions, line_discounts) = self
+            .order_pricing_service
+            .apply_discounts(cart_items, coupon, &mut shipping_info)
+            .await?;
+        order_items = order_items
+            .into_iter()
+            .zip(line_discounts)
+            .map(|(item, discount)| item.with_discount_amount(discount))
+            .collect();
 
-        // 2. 注文アイテムの作成
-        let order_items = self.create_order_items(&command).await?;
-
-        // 3. 配送情報の作成
-        let shipping_info = self.create_shipping_info(&command).await?;
-
-        // 4. 支払い情報の作成
-        let payment_info = self.create_payment_info(&command).await?;
-
-        // 5. 注文番号の生成
-        let current_year = chrono::Utc::now().year();
-        let sequence_number = self.order_repository
-            .get_next_sequence_number(current_year)
-            .await
-            .map_err(ApplicationError::Repository)?;
-        let order_number = OrderNumber::generate(current_year, sequence_number);
-
-        // 6. 注文の作成
-        let order = Order::new(
-            order_number,
-            customer_info,
-            order_items,
-            shipping_info,
-            payment_info,
-        )
-        .map_err(ApplicationError::Domain)?;
-
-        // 7. 注文の保存
-        self.order_repository
-            .save(&order)
-            .await
-            .map_err(ApplicationError::Repository)?;
+        // 5. 支払い情報の作成（手数料はカート計算と同じく割引後の小計に対して求める）
+        let payment_info = self.create_payment_info(&command, &order_items).await?;
+
+        // 6. 注文番号の採番・注文の作成・保存（在庫の引当・クーポン使用回数の消費を含む）
+        // 採番は保存と同じトランザクションで行い、同時に注文されても番号が重複しないようにする
+        let tax_policy = self.tax_policy;
+        let order = self
+            .order_repository
+            .create(
+                &self.order_number_format,
+                chrono::Utc::now(),
+                Box::new(move |order_number| {
+                    Ok(Order::new(
+                        order_number,
+                        customer_info,
+                        order_items,
+                        shipping_info,
+                        payment_info,
+                        applied_coupon,
+                        applied_promotions,
+                        &tax_policy,
+                    )?
+                    .with_customer_id(customer_id))
+                }),
+            )
+            .await?;
 
-        // 8. 結果DTOの作成
+        // 7. 結果DTOの作成
         Ok(CreateOrderResultDTO::from_order(&order))
     }
 
+    fn parse_customer_id(
+        command: &CreateOrderCommand,
+    ) -> Result<Option<CustomerId>, ApplicationError> {
+        command
+            .customer_id
+            .as_deref()
+            .map(|id| {
+                Uuid::parse_str(id).map(CustomerId::from_uuid).map_err(|_| {
+                    ApplicationError::InvalidInput(format!("Invalid customer ID format: {}", id))
+                })
+            })
+            .transpose()
+    }
+
     fn create_customer_info(
         &self,
         command: &CreateOrderCommand,
@@ -99,10 +157,11 @@ impl CreateOrderHandler {
         Ok(CustomerInfo::new(personal_info, email, phone))
     }
 
+    /// 注文アイテムと、クーポン計算用のカートアイテム、配送料計算用の商品情報を作成
     async fn create_order_items(
         &self,
         command: &CreateOrderCommand,
-    ) -> Result<Vec<OrderItem>, ApplicationError> {
+    ) -> Result<(Vec<OrderItem>, Vec<CartItem>, Vec<ShippableItem>), ApplicationError> {
         if command.items.is_empty() {
             return Err(ApplicationError::InvalidInput(
                 "Order must have at least one item".to_string(),
@@ -136,6 +195,8 @@ impl CreateOrderHandler {
 
         // 注文アイテムの作成
         let mut order_items = Vec::new();
+        let mut cart_items = Vec::new();
+        let mut shippable_items = Vec::new();
         for item_request in &command.items {
             if item_request.quantity == 0 {
                 return Err(ApplicationError::InvalidInput(
@@ -151,20 +212,8 @@ impl CreateOrderHandler {
                     ApplicationError::NotFound(format!("SKU not found: {}", item_request.sku_id))
                 })?;
 
-            // 在庫チェック
-            if variant.is_sold_out {
-                return Err(ApplicationError::InvalidInput(format!(
-                    "SKU {} is sold out",
-                    variant.sku_code
-                )));
-            }
-
-            if variant.stock_quantity < item_request.quantity {
-                return Err(ApplicationError::InvalidInput(format!(
-                    "Insufficient stock for SKU {}: requested {}, available {}",
-                    variant.sku_code, item_request.quantity, variant.stock_quantity
-                )));
-            }
+            // 在庫の確認と引当は、同時注文による過剰販売を防ぐため
+            // 注文保存時に同一トランザクション内で行う
 
             // OrderItemの作成
             let sku_id =
@@ -191,31 +240,58 @@ impl CreateOrderHandler {
                 unit_price,
                 item_request.quantity as i32,
             )
-            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?
+            .with_tax_category(variant.tax_category);
 
             order_items.push(order_item);
+            cart_items.push(
+                variant
+                    .cart_item(item_request.quantity)
+                    .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?,
+            );
+            shippable_items.push(variant.shippable_item(item_request.quantity));
         }
 
-        Ok(order_items)
+        Ok((order_items, cart_items, shippable_items))
     }
 
-    async fn create_shipping_info(
+    /// クーポンを取得し、顧客ごとの使用上限を検証
+    async fn find_usable_coupon(
         &self,
-        command: &CreateOrderCommand,
-    ) -> Result<ShippingInfo, ApplicationError> {
-        // 配送方法の取得
-        let shipping_method = self
-            .shipping_method_repository
-            .find_by_id(&command.shipping_method_id)
+        coupon_code: &str,
+        customer_email: &Email,
+    ) -> Result<Coupon, ApplicationError> {
+        let code =
+            CouponCode::from_string(coupon_code.to_string()).map_err(ApplicationError::Domain)?;
+
+        let coupon = self
+            .coupon_repository
+            .find_by_code(&code)
             .await
-            .map_err(ApplicationError::Repository)?
+            .map_err(ApplicationError::Domain)?
             .ok_or_else(|| {
-                ApplicationError::NotFound(format!(
-                    "Shipping method not found: {}",
-                    command.shipping_method_id
-                ))
+                ApplicationError::Domain(DomainError::InvalidCoupon {
+                    code: coupon_code.to_string(),
+                    message: "Coupon not found".to_string(),
+                })
             })?;
 
+        // 顧客ごとの使用上限（保存時にも同時注文を考慮して再確認する）
+        let customer_usage_count = self
+            .coupon_repository
+            .count_customer_usage(coupon.id(), customer_email.value())
+            .await?;
+        coupon.ensure_usable_by_customer(customer_usage_count)?;
+
+        Ok(coupon)
+    }
+
+    async fn create_shipping_info(
+        &self,
+        command: &CreateOrderCommand,
+        order_items: &[OrderItem],
+        shippable_items: Vec<ShippableItem>,
+    ) -> Result<ShippingInfo, ApplicationError> {
         // 配送先住所の作成
         let address = Address::new(
             command.shipping_address.postal_code.clone(),
@@ -226,46 +302,23 @@ impl CreateOrderHandler {
         )
         .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
 
-        // ShippingMethodIdの作成
-        let shipping_method_id = ShippingMethodId::new(command.shipping_method_id.clone())
-            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
-
-        let shipping_fee = *shipping_method.price();
-
-        Ok(ShippingInfo::new(
-            shipping_method_id,
-            shipping_method.name().to_string(),
-            shipping_fee,
-            address,
-        ))
+        self.order_pricing_service
+            .shipping_info(
+                &command.shipping_method_id,
+                address,
+                order_items,
+                shippable_items,
+            )
+            .await
     }
 
     async fn create_payment_info(
         &self,
         command: &CreateOrderCommand,
+        order_items: &[OrderItem],
     ) -> Result<PaymentInfo, ApplicationError> {
-        // 支払い方法の取得
-        let payment_method = self
-            .payment_method_repository
-            .find_by_id(&command.payment_method_id)
+        self.order_pricing_service
+            .payment_info(&command.payment_method_id, order_items)
             .await
-            .map_err(ApplicationError::Repository)?
-            .ok_or_else(|| {
-                ApplicationError::NotFound(format!(
-                    "Payment method not found: {}",
-                    command.payment_method_id
-                ))
-            })?;
-
-        let payment_method_id = PaymentMethodId::new(command.payment_method_id.clone())
-            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
-        let payment_fee = Money::from_yen(0); // TODO: PaymentMethodDTOにfeeフィールドを追加
-
-        Ok(PaymentInfo::new(
-            payment_method_id,
-            payment_method.name().to_string(),
-            payment_fee,
-            None,
-        ))
     }
 }
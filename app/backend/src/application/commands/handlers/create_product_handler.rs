@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use super::product_loader::{build_images, build_sku, resolve_tags, save_product};
+use crate::application::commands::models::CreateProductCommand;
+use crate::application::dto::AdminProductDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ProductWriteRepository;
+use crate::domain::{CategoryId, Description, Product, ProductId, ProductName};
+
+/// 商品作成ハンドラ（ユースケース）
+pub struct CreateProductHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+}
+
+impl CreateProductHandler {
+    pub fn new(product_repository: Arc<dyn ProductWriteRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// 商品をSKU・画像・タグごと作成（publishが指定されない限り非公開で作成）
+    pub async fn handle(
+        &self,
+        command: CreateProductCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        println!(
+            "->> create_product_handler: name={}, skus={}",
+            command.name,
+            command.skus.len()
+        );
+
+        let product_id = ProductId::new();
+        let mut product = Product::new(
+            product_id.clone(),
+            ProductName::new(command.name)?,
+            Description::new(command.description),
+            CategoryId::new(command.category_id)?,
+        )?;
+
+        if command.is_best_seller {
+            product.mark_as_best_seller();
+        }
+        if command.is_quick_ship {
+            product.enable_quick_ship();
+        }
+
+        for (index, item) in command.skus.iter().enumerate() {
+            product.add_sku(build_sku(&product_id, item, index as u32)?)?;
+        }
+
+        product.replace_images(build_images(&product_id, &command.images)?);
+
+        for tag in resolve_tags(self.product_repository.as_ref(), &command.tag_slugs).await? {
+            product.add_tag(tag);
+        }
+
+        if command.publish {
+            product.publish()?;
+        }
+
+        save_product(self.product_repository.as_ref(), &product).await
+    }
+}
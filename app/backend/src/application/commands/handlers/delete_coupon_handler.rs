@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use super::coupon_loader::load_coupon;
+use crate::application::commands::models::DeleteCouponCommand;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CouponRepository;
+use crate::domain::error::DomainError;
+
+/// クーポン削除ハンドラ（ユースケース）
+pub struct DeleteCouponHandler {
+    coupon_repository: Arc<dyn CouponRepository>,
+}
+
+impl DeleteCouponHandler {
+    pub fn new(coupon_repository: Arc<dyn CouponRepository>) -> Self {
+        Self { coupon_repository }
+    }
+
+    /// 未使用のクーポンを削除（使用済みのクーポンは有効期間の変更で停止する）
+    pub async fn handle(&self, command: DeleteCouponCommand) -> Result<(), ApplicationError> {
+        println!("->> delete_coupon_handler: coupon_id={}", command.coupon_id);
+
+        let coupon = load_coupon(self.coupon_repository.as_ref(), &command.coupon_id).await?;
+
+        if !coupon.can_be_deleted() {
+            return Err(DomainError::BusinessRuleViolation(format!(
+                "Coupon {} has already been used and cannot be deleted",
+                coupon.code().value()
+            ))
+            .into());
+        }
+
+        self.coupon_repository.delete(coupon.id()).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::commands::models::DeleteProductCommand;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ProductWriteRepository;
+use crate::domain::ProductId;
+
+/// 商品削除ハンドラ（ユースケース）
+pub struct DeleteProductHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+}
+
+impl DeleteProductHandler {
+    pub fn new(product_repository: Arc<dyn ProductWriteRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// 商品をSKU・画像・タグごと削除（注文履歴のある商品は削除できない）
+    pub async fn handle(&self, command: DeleteProductCommand) -> Result<(), ApplicationError> {
+        println!(
+            "->> delete_product_handler: product_id={}",
+            command.product_id
+        );
+
+        let uuid = Uuid::parse_str(&command.product_id).map_err(|_| {
+            ApplicationError::InvalidInput(format!(
+                "Invalid product ID format: {}",
+                command.product_id
+            ))
+        })?;
+
+        let deleted = self
+            .product_repository
+            .delete(&ProductId::from_uuid(uuid))
+            .await?;
+        if !deleted {
+            return Err(ApplicationError::NotFound(format!(
+                "Product not found: {}",
+                command.product_id
+            )));
+        }
+
+        Ok(())
+    }
+}
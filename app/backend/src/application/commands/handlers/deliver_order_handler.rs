@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use super::order_loader::load_order;
+use crate::application::commands::models::DeliverOrderCommand;
+use crate::application::dto::OrderDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::OrderRepository;
+use crate::domain::aggregates::order::OrderStatus;
+
+/// 注文配達完了ハンドラ（ユースケース）
+pub struct DeliverOrderHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl DeliverOrderHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// 注文配達完了を実行
+    pub async fn handle(&self, command: DeliverOrderCommand) -> Result<OrderDTO, ApplicationError> {
+        println!("->> deliver_order_handler: order_id={}", command.order_id);
+
+        let mut order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        order.update_status(OrderStatus::Delivered)?;
+
+        self.order_repository.update(&order).await?;
+
+        Ok(OrderDTO::from_order(&order))
+    }
+}
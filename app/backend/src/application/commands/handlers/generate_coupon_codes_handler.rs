@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use super::coupon_loader::{build_discount_policy, parse_validity};
+use crate::application::commands::models::GenerateCouponCodesCommand;
+use crate::application::dto::CouponListDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CouponRepository;
+use crate::domain::entities::Coupon;
+use crate::domain::value_objects::CouponCode;
+
+/// 生成するコードの長さ（プレフィックスを含む、CouponCodeの最大長）
+const GENERATED_CODE_LENGTH: usize = 10;
+/// ランダム部分の最小文字数
+const MIN_RANDOM_LENGTH: usize = 6;
+/// 一度に発行できる最大数
+const MAX_GENERATE_COUNT: u32 = 1000;
+/// ランダム部分に使う文字（見間違えやすい0/O、1/Iを除く）
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// 使い切りクーポン一括発行ハンドラ（ユースケース）
+pub struct GenerateCouponCodesHandler {
+    coupon_repository: Arc<dyn CouponRepository>,
+}
+
+impl GenerateCouponCodesHandler {
+    pub fn new(coupon_repository: Arc<dyn CouponRepository>) -> Self {
+        Self { coupon_repository }
+    }
+
+    /// 同じ割引内容の使い切りクーポンを指定数発行する（コードが重複した場合は全件ロールバック）
+    pub async fn handle(
+        &self,
+        command: GenerateCouponCodesCommand,
+    ) -> Result<CouponListDTO, ApplicationError> {
+        println!(
+            "->> generate_coupon_codes_handler: prefix={}, count={}",
+            command.prefix, command.count
+        );
+
+        if command.count == 0 || command.count > MAX_GENERATE_COUNT {
+            return Err(ApplicationError::Validation(format!(
+                "Coupon count must be between 1 and {}",
+                MAX_GENERATE_COUNT
+            )));
+        }
+        if command.prefix.len() > GENERATED_CODE_LENGTH - MIN_RANDOM_LENGTH {
+            return Err(ApplicationError::Validation(format!(
+                "Coupon code prefix cannot exceed {} characters",
+                GENERATED_CODE_LENGTH - MIN_RANDOM_LENGTH
+            )));
+        }
+
+        let discount_policy = build_discount_policy(&command.discount)?;
+        let (valid_from, valid_until) = parse_validity(&command.valid_from, &command.valid_until)?;
+
+        let mut codes = HashSet::new();
+        while codes.len() < command.count as usize {
+            codes.insert(Self::generate_code(&command.prefix));
+        }
+
+        let coupons = codes
+            .into_iter()
+            .map(|code| {
+                Coupon::create(
+                    CouponCode::from_string(code)?,
+                    command.name.clone(),
+                    command.description.clone(),
+                    discount_policy.clone(),
+                    valid_from,
+                    valid_until,
+                    Some(1),
+                    None,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.coupon_repository.create_all(&coupons).await?;
+
+        Ok(CouponListDTO::from_coupons(&coupons))
+    }
+
+    /// プレフィックスに続けてランダムな文字を付けたコードを生成
+    fn generate_code(prefix: &str) -> String {
+        let random = Uuid::new_v4();
+        let suffix: String = random
+            .as_bytes()
+            .iter()
+            .take(GENERATED_CODE_LENGTH - prefix.len())
+            .map(|byte| CODE_ALPHABET[*byte as usize % CODE_ALPHABET.len()] as char)
+            .collect();
+
+        format!("{}{}", prefix, suffix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_code_has_prefix_and_fixed_length() {
+        let code = GenerateCouponCodesHandler::generate_code("SPR");
+
+        assert!(code.starts_with("SPR"));
+        assert_eq!(code.len(), GENERATED_CODE_LENGTH);
+        assert!(code[3..].bytes().all(|byte| CODE_ALPHABET.contains(&byte)));
+    }
+}
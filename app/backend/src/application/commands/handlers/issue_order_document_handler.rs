@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use super::order_loader::load_order;
+use crate::application::commands::models::IssueOrderDocumentCommand;
+use crate::application::documents::{DocumentFormat, DocumentRenderer};
+use crate::application::dto::{OrderDocumentDTO, RenderedDocumentDTO};
+use crate::application::error::ApplicationError;
+use crate::application::repositories::OrderRepository;
+use crate::domain::DomainError;
+use crate::domain::aggregates::order::OrderDocumentKind;
+use crate::domain::value_objects::{DocumentIssuer, TaxPolicy};
+
+/// 請求書・領収書発行ハンドラ（ユースケース）
+pub struct IssueOrderDocumentHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    document_renderer: Arc<dyn DocumentRenderer>,
+    issuer: DocumentIssuer,
+    tax_policy: TaxPolicy,
+}
+
+impl IssueOrderDocumentHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        document_renderer: Arc<dyn DocumentRenderer>,
+        issuer: DocumentIssuer,
+        tax_policy: TaxPolicy,
+    ) -> Self {
+        Self {
+            order_repository,
+            document_renderer,
+            issuer,
+            tax_policy,
+        }
+    }
+
+    /// 請求書・領収書の発行を実行
+    /// 発行のたびに注文の発行回数を記録し、2回目以降は再発行として描画する
+    pub async fn handle(
+        &self,
+        command: IssueOrderDocumentCommand,
+    ) -> Result<RenderedDocumentDTO, ApplicationError> {
+        println!(
+            "->> issue_order_document_handler: order_id={}, kind={}, format={}",
+            command.order_id, command.kind, command.format
+        );
+
+        let kind: OrderDocumentKind = command
+            .kind
+            .parse()
+            .map_err(|e: DomainError| ApplicationError::InvalidInput(e.to_string()))?;
+        let format: DocumentFormat = command.format.parse()?;
+
+        let order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        order.ensure_document_issuable(kind)?;
+
+        // 発行回数だけを加算し、注文の他の項目は書き戻さない
+        let issue_number = self
+            .order_repository
+            .record_document_issue(&order.id, kind)
+            .await?;
+
+        // 宛名の指定がなければ注文者の氏名を宛名にする
+        let addressee = command
+            .addressee
+            .map(|addressee| addressee.trim().to_string())
+            .filter(|addressee| !addressee.is_empty())
+            .unwrap_or_else(|| {
+                format!(
+                    "{} {} 様",
+                    order.customer_info.last_name(),
+                    order.customer_info.first_name()
+                )
+            });
+
+        let document = OrderDocumentDTO::from_order(
+            &order,
+            kind,
+            issue_number,
+            addressee,
+            &self.issuer,
+            &self.tax_policy,
+            Utc::now(),
+        );
+        let body = match format {
+            DocumentFormat::Html => self.document_renderer.render_html(&document).into_bytes(),
+            DocumentFormat::Pdf => self.document_renderer.render_pdf(&document),
+        };
+
+        Ok(RenderedDocumentDTO {
+            content_type: format.content_type().to_string(),
+            file_name: format!(
+                "{}-{}.{}",
+                kind.code(),
+                order.order_number.value(),
+                format.extension()
+            ),
+            body,
+        })
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+
+use super::session_issuer::issue_session;
+use crate::application::commands::models::LoginCustomerCommand;
+use crate::application::dto::CustomerSessionDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CustomerRepository;
+use crate::application::security::{PasswordHasher, SessionTokenSigner};
+use crate::domain::value_objects::Email;
+
+/// ログインハンドラ（ユースケース）
+pub struct LoginCustomerHandler {
+    customer_repository: Arc<dyn CustomerRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    token_signer: Arc<dyn SessionTokenSigner>,
+    session_ttl: Duration,
+}
+
+impl LoginCustomerHandler {
+    pub fn new(
+        customer_repository: Arc<dyn CustomerRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        token_signer: Arc<dyn SessionTokenSigner>,
+        session_ttl: Duration,
+    ) -> Self {
+        Self {
+            customer_repository,
+            password_hasher,
+            token_signer,
+            session_ttl,
+        }
+    }
+
+    /// メールアドレスとパスワードを照合してセッションを発行
+    /// 会員が存在しない場合もパスワード不一致と同じエラーを返す
+    pub async fn handle(
+        &self,
+        command: LoginCustomerCommand,
+    ) -> Result<CustomerSessionDTO, ApplicationError> {
+        println!("->> login_customer_handler");
+
+        let invalid_credentials =
+            || ApplicationError::Unauthorized("Invalid email or password".to_string());
+
+        let email =
+            Email::new(command.email.trim().to_string()).map_err(|_| invalid_credentials())?;
+        let customer = self
+            .customer_repository
+            .find_by_email(&email)
+            .await?
+            .ok_or_else(invalid_credentials)?;
+
+        if !self
+            .password_hasher
+            .verify(&command.password, customer.password_hash())
+        {
+            return Err(invalid_credentials());
+        }
+
+        issue_session(
+            self.customer_repository.as_ref(),
+            self.token_signer.as_ref(),
+            &customer,
+            self.session_ttl,
+        )
+        .await
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::application::commands::models::LogoutCustomerCommand;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CustomerRepository;
+use crate::application::security::SessionTokenSigner;
+
+/// ログアウトハンドラ（ユースケース）
+pub struct LogoutCustomerHandler {
+    customer_repository: Arc<dyn CustomerRepository>,
+    token_signer: Arc<dyn SessionTokenSigner>,
+}
+
+impl LogoutCustomerHandler {
+    pub fn new(
+        customer_repository: Arc<dyn CustomerRepository>,
+        token_signer: Arc<dyn SessionTokenSigner>,
+    ) -> Self {
+        Self {
+            customer_repository,
+            token_signer,
+        }
+    }
+
+    /// トークンのセッションを失効させる（以降そのトークンは使用できない）
+    pub async fn handle(&self, command: LogoutCustomerCommand) -> Result<(), ApplicationError> {
+        let invalid_session = || ApplicationError::Unauthorized("Invalid session".to_string());
+
+        let session_id = self
+            .token_signer
+            .verify(&command.token)
+            .ok_or_else(invalid_session)?;
+        println!("->> logout_customer_handler: session_id={}", session_id);
+
+        let now = Utc::now();
+        let mut session = self
+            .customer_repository
+            .find_session(&session_id)
+            .await?
+            .filter(|session| session.is_valid_at(now))
+            .ok_or_else(invalid_session)?;
+
+        session.revoke(now);
+        self.customer_repository.save_session(&session).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use super::order_loader::load_order;
+use crate::application::commands::models::MarkOrderPaidCommand;
+use crate::application::dto::OrderDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::OrderRepository;
+use crate::domain::aggregates::order::OrderStatus;
+
+/// 注文支払い完了ハンドラ（ユースケース）
+pub struct MarkOrderPaidHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl MarkOrderPaidHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// 注文支払い完了を実行
+    pub async fn handle(
+        &self,
+        command: MarkOrderPaidCommand,
+    ) -> Result<OrderDTO, ApplicationError> {
+        println!("->> mark_order_paid_handler: order_id={}", command.order_id);
+
+        let mut order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        order.update_status(OrderStatus::Paid)?;
+
+        self.order_repository.update(&order).await?;
+
+        Ok(OrderDTO::from_order(&order))
+    }
+}
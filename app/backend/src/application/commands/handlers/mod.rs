@@ -1,5 +1,100 @@
+mod add_cart_item_handler;
+mod add_order_note_handler;
+mod add_product_sku_handler;
+mod adjust_stock_handler;
+mod amend_order_handler;
+mod approve_return_handler;
+mod attach_shipment_handler;
+mod authorize_payment_handler;
 mod calculate_cart_handler;
+mod cancel_order_handler;
+mod capture_payment_handler;
+mod cart_loader;
+mod coupon_loader;
+mod create_cart_handler;
+mod create_coupon_handler;
 mod create_order_handler;
+mod create_product_handler;
+mod delete_coupon_handler;
+mod delete_product_handler;
+mod deliver_order_handler;
+mod generate_coupon_codes_handler;
+mod issue_order_document_handler;
+mod login_customer_handler;
+mod logout_customer_handler;
+mod mark_order_paid_handler;
+mod order_loader;
+mod payment_recorder;
+mod process_order_handler;
+mod product_loader;
+mod publish_product_handler;
+mod receive_return_handler;
+mod record_shipment_event_handler;
+mod refund_order_handler;
+mod refund_return_handler;
+mod register_customer_handler;
+mod reject_return_handler;
+mod remove_cart_item_handler;
+mod remove_product_sku_handler;
+mod request_return_handler;
+mod return_loader;
+mod schedule_sku_sale_handler;
+mod session_issuer;
+mod set_cart_coupon_handler;
+mod set_cart_payment_method_handler;
+mod set_cart_shipping_method_handler;
+mod ship_order_handler;
+mod unpublish_product_handler;
+mod update_cart_item_handler;
+mod update_coupon_handler;
+mod update_payment_fee_rule_handler;
+mod update_product_handler;
+mod update_product_sku_handler;
+mod void_payment_handler;
 
+pub use add_cart_item_handler::AddCartItemHandler;
+pub use add_order_note_handler::AddOrderNoteHandler;
+pub use add_product_sku_handler::AddProductSkuHandler;
+pub use adjust_stock_handler::AdjustStockHandler;
+pub use amend_order_handler::AmendOrderHandler;
+pub use approve_return_handler::ApproveReturnHandler;
+pub use attach_shipment_handler::AttachShipmentHandler;
+pub use authorize_payment_handler::AuthorizePaymentHandler;
 pub use calculate_cart_handler::CalculateCartHandler;
+pub use cancel_order_handler::CancelOrderHandler;
+pub use capture_payment_handler::CapturePaymentHandler;
+pub use create_cart_handler::CreateCartHandler;
+pub use create_coupon_handler::CreateCouponHandler;
 pub use create_order_handler::CreateOrderHandler;
+pub use create_product_handler::CreateProductHandler;
+pub use delete_coupon_handler::DeleteCouponHandler;
+pub use delete_product_handler::DeleteProductHandler;
+pub use deliver_order_handler::DeliverOrderHandler;
+pub use generate_coupon_codes_handler::GenerateCouponCodesHandler;
+pub use issue_order_document_handler::IssueOrderDocumentHandler;
+pub use login_customer_handler::LoginCustomerHandler;
+pub use logout_customer_handler::LogoutCustomerHandler;
+pub use mark_order_paid_handler::MarkOrderPaidHandler;
+pub use process_order_handler::ProcessOrderHandler;
+pub use publish_product_handler::PublishProductHandler;
+pub use receive_return_handler::ReceiveReturnHandler;
+pub use record_shipment_event_handler::RecordShipmentEventHandler;
+pub use refund_order_handler::RefundOrderHandler;
+pub use refund_return_handler::RefundReturnHandler;
+pub use register_customer_handler::RegisterCustomerHandler;
+pub use reject_return_handler::RejectReturnHandler;
+pub use remove_cart_item_handler::RemoveCartItemHandler;
+pub use remove_product_sku_handler::RemoveProductSkuHandler;
+pub use request_return_handler::RequestReturnHandler;
+pub use schedule_sku_sale_handler::ScheduleSkuSaleHandler;
+pub use set_cart_coupon_handler::SetCartCouponHandler;
+pub use set_cart_payment_method_handler::SetCartPaymentMethodHandler;
+pub use set_cart_shipping_method_handler::SetCartShippingMethodHandler;
+pub use ship_order_handler::ShipOrderHandler;
+pub use unpublish_product_handler::UnpublishProductHandler;
+pub use update_cart_item_handler::UpdateCartItemHandler;
+pub use update_coupon_handler::UpdateCouponHandler;
+pub use update_payment_fee_rule_handler::UpdatePaymentFeeRuleHandler;
+pub use update_product_handler::UpdateProductHandler;
+pub use update_product_sku_handler::UpdateProductSkuHandler;
+pub use void_payment_handler::VoidPaymentHandler;
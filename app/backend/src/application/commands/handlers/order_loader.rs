@@ -0,0 +1,21 @@
+use uuid::Uuid;
+
+use crate::application::error::ApplicationError;
+use crate::application::repositories::OrderRepository;
+use crate::domain::aggregates::order::Order;
+use crate::domain::value_objects::OrderId;
+
+/// 注文IDの文字列から注文を取得（ステータス遷移系コマンド共通）
+pub(super) async fn load_order(
+    order_repository: &dyn OrderRepository,
+    order_id: &str,
+) -> Result<Order, ApplicationError> {
+    let uuid = Uuid::parse_str(order_id).map_err(|_| {
+        ApplicationError::InvalidInput(format!("Invalid order ID format: {}", order_id))
+    })?;
+
+    order_repository
+        .find_by_id(&OrderId::from_uuid(uuid))
+        .await?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Order not found: {}", order_id)))
+}
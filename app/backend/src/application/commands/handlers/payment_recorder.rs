@@ -0,0 +1,68 @@
+use crate::application::error::ApplicationError;
+use crate::application::gateways::{PaymentGatewayError, PaymentGatewayResponse};
+use crate::application::repositories::PaymentRepository;
+use crate::domain::value_objects::{Money, OrderId};
+use crate::domain::{PaymentOperation, PaymentTransaction};
+
+/// 決済代行会社の応答を成否にかかわらず記録（決済系コマンド共通）
+/// 拒否は`PaymentDeclined`、通信エラーは`PaymentGateway`として返す
+pub(super) async fn record_gateway_result(
+    payment_repository: &dyn PaymentRepository,
+    order_id: &OrderId,
+    provider: &str,
+    operation: PaymentOperation,
+    amount: Money,
+    transaction_id: Option<&str>,
+    result: Result<PaymentGatewayResponse, PaymentGatewayError>,
+) -> Result<PaymentTransaction, ApplicationError> {
+    let response_transaction_id = |response: &PaymentGatewayResponse| {
+        response
+            .transaction_id
+            .clone()
+            .or_else(|| transaction_id.map(|id| id.to_string()))
+    };
+
+    match result {
+        Ok(response) if response.approved => {
+            let recorded = PaymentTransaction::succeeded(
+                *order_id,
+                provider,
+                operation,
+                amount,
+                response_transaction_id(&response).unwrap_or_default(),
+            );
+            payment_repository.record(&recorded).await?;
+            Ok(recorded)
+        }
+        Ok(response) => {
+            let reason = response
+                .decline_reason
+                .clone()
+                .unwrap_or_else(|| "declined".to_string());
+            payment_repository
+                .record(&PaymentTransaction::failed(
+                    *order_id,
+                    provider,
+                    operation,
+                    amount,
+                    response_transaction_id(&response),
+                    reason.clone(),
+                ))
+                .await?;
+            Err(ApplicationError::PaymentDeclined(reason))
+        }
+        Err(err) => {
+            payment_repository
+                .record(&PaymentTransaction::failed(
+                    *order_id,
+                    provider,
+                    operation,
+                    amount,
+                    transaction_id.map(|id| id.to_string()),
+                    err.to_string(),
+                ))
+                .await?;
+            Err(ApplicationError::PaymentGateway(err))
+        }
+    }
+}
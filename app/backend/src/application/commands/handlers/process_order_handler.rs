@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use super::order_loader::load_order;
+use crate::application::commands::models::ProcessOrderCommand;
+use crate::application::dto::OrderDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::OrderRepository;
+use crate::domain::aggregates::order::OrderStatus;
+
+/// 注文処理開始ハンドラ（ユースケース）
+pub struct ProcessOrderHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl ProcessOrderHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// 注文処理開始を実行
+    pub async fn handle(&self, command: ProcessOrderCommand) -> Result<OrderDTO, ApplicationError> {
+        println!("->> process_order_handler: order_id={}", command.order_id);
+
+        let mut order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        order.update_status(OrderStatus::Processing)?;
+
+        self.order_repository.update(&order).await?;
+
+        Ok(OrderDTO::from_order(&order))
+    }
+}
@@ -0,0 +1,131 @@
+use uuid::Uuid;
+
+use crate::application::commands::models::{ProductImageCommandItem, ProductSkuCommandItem};
+use crate::application::dto::AdminProductDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ProductWriteRepository;
+use crate::domain::{
+    ColorId, Dimensions, ImageUrl, Material, Money, Product, ProductId, ProductImage,
+    ProductImageId, ProductImageProductId, SKU, SKUCode, SKUId, SKUName, Tag, TaxCategory,
+    VariantAttributes,
+};
+
+/// 商品IDの文字列から商品集約を取得（商品管理系コマンド共通）
+pub(super) async fn load_product(
+    product_repository: &dyn ProductWriteRepository,
+    product_id: &str,
+) -> Result<Product, ApplicationError> {
+    let uuid = Uuid::parse_str(product_id).map_err(|_| {
+        ApplicationError::InvalidInput(format!("Invalid product ID format: {}", product_id))
+    })?;
+
+    product_repository
+        .find_by_id(&ProductId::from_uuid(uuid))
+        .await?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Product not found: {}", product_id)))
+}
+
+/// 商品集約を保存し、永続化後の状態を読み直してDTOを返す
+pub(super) async fn save_product(
+    product_repository: &dyn ProductWriteRepository,
+    product: &Product,
+) -> Result<AdminProductDTO, ApplicationError> {
+    product_repository.save(product).await?;
+
+    let saved = product_repository
+        .find_by_id(product.id())
+        .await?
+        .ok_or_else(|| {
+            ApplicationError::NotFound(format!("Product not found: {}", product.id()))
+        })?;
+
+    Ok(AdminProductDTO::from_product(&saved))
+}
+
+/// コマンドの指定からSKUを作成
+pub(super) fn build_sku(
+    product_id: &ProductId,
+    item: &ProductSkuCommandItem,
+    default_display_order: u32,
+) -> Result<SKU, ApplicationError> {
+    let mut sku = SKU::create_with_display_order(
+        SKUId::new(),
+        product_id.clone(),
+        SKUCode::new(item.sku_code.clone())?,
+        SKUName::new(item.name.clone())?,
+        build_variant_attributes(
+            item.color_id,
+            item.dimensions.clone(),
+            item.material.clone(),
+        )?,
+        Money::from_yen(item.base_price),
+        item.initial_stock,
+        item.display_order.unwrap_or(default_display_order),
+    )?;
+
+    if let Some(sale_price) = item.sale_price {
+        sku.set_sale_price(Money::from_yen(sale_price))?;
+    }
+    if let Some(tax_category) = &item.tax_category {
+        sku = sku.with_tax_category(tax_category.parse::<TaxCategory>()?);
+    }
+
+    Ok(sku)
+}
+
+/// バリエーション属性を作成
+pub(super) fn build_variant_attributes(
+    color_id: u32,
+    dimensions: Option<String>,
+    material: Option<String>,
+) -> Result<VariantAttributes, ApplicationError> {
+    let mut attributes = VariantAttributes::new().with_color_id(ColorId::new(color_id)?);
+    if let Some(dimensions) = dimensions {
+        attributes = attributes.with_dimensions(Dimensions::new(dimensions)?);
+    }
+    if let Some(material) = material {
+        attributes = attributes.with_material(Material::new(material)?);
+    }
+    Ok(attributes)
+}
+
+/// コマンドの指定から商品画像を作成（配列の順序を表示順とする）
+pub(super) fn build_images(
+    product_id: &ProductId,
+    items: &[ProductImageCommandItem],
+) -> Result<Vec<ProductImage>, ApplicationError> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            Ok(ProductImage::new(
+                // IDは永続化時に採番される
+                ProductImageId::new(0),
+                ProductImageProductId::new(product_id.value().to_string())?,
+                ImageUrl::new(item.url.clone())?,
+                item.alt_text.clone(),
+                index as u32,
+            )?)
+        })
+        .collect()
+}
+
+/// タグのスラッグから登録済みのタグを取得（未登録のスラッグはエラー）
+pub(super) async fn resolve_tags(
+    product_repository: &dyn ProductWriteRepository,
+    slugs: &[String],
+) -> Result<Vec<Tag>, ApplicationError> {
+    let tags = product_repository.find_tags_by_slugs(slugs).await?;
+
+    if let Some(unknown) = slugs
+        .iter()
+        .find(|slug| !tags.iter().any(|tag| tag.slug().value() == slug.as_str()))
+    {
+        return Err(ApplicationError::InvalidInput(format!(
+            "Unknown tag: {}",
+            unknown
+        )));
+    }
+
+    Ok(tags)
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use super::product_loader::{load_product, save_product};
+use crate::application::commands::models::PublishProductCommand;
+use crate::application::dto::AdminProductDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ProductWriteRepository;
+
+/// 商品公開ハンドラ（ユースケース）
+pub struct PublishProductHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+}
+
+impl PublishProductHandler {
+    pub fn new(product_repository: Arc<dyn ProductWriteRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// 商品を公開（購入可能なSKUが必要）
+    pub async fn handle(
+        &self,
+        command: PublishProductCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        println!(
+            "->> publish_product_handler: product_id={}",
+            command.product_id
+        );
+
+        let mut product =
+            load_product(self.product_repository.as_ref(), &command.product_id).await?;
+        product.publish()?;
+
+        save_product(self.product_repository.as_ref(), &product).await
+    }
+}
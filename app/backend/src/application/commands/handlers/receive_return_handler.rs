@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use super::return_loader::load_return;
+use crate::application::commands::models::ReceiveReturnCommand;
+use crate::application::dto::ReturnDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ReturnRepository;
+use crate::domain::DomainError;
+use crate::domain::aggregates::return_request::ReturnCondition;
+
+/// 返品受領ハンドラ（ユースケース）
+pub struct ReceiveReturnHandler {
+    return_repository: Arc<dyn ReturnRepository>,
+}
+
+impl ReceiveReturnHandler {
+    pub fn new(return_repository: Arc<dyn ReturnRepository>) -> Self {
+        Self { return_repository }
+    }
+
+    /// 返品受領を実行
+    /// 再販売できる商品は受領と同じトランザクションで在庫に戻す
+    pub async fn handle(
+        &self,
+        command: ReceiveReturnCommand,
+    ) -> Result<ReturnDTO, ApplicationError> {
+        println!(
+            "->> receive_return_handler: return_id={}",
+            command.return_id
+        );
+
+        let condition = command
+            .condition
+            .as_deref()
+            .map(|condition| condition.parse::<ReturnCondition>())
+            .transpose()
+            .map_err(|e: DomainError| ApplicationError::InvalidInput(e.to_string()))?;
+
+        let mut return_request =
+            load_return(self.return_repository.as_ref(), &command.return_id).await?;
+
+        let previous_status = return_request.status;
+        let restocks = return_request.receive(condition)?;
+
+        // 同時に受領された場合は競合として中断し、在庫を二重に戻さない
+        self.return_repository
+            .update(&return_request, previous_status, &restocks)
+            .await?;
+
+        Ok(ReturnDTO::from_return(&return_request))
+    }
+}
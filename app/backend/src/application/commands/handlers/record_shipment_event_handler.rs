@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::commands::models::RecordShipmentEventCommand;
+use crate::application::dto::ShipmentDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{DeliveryInfoRepository, OrderRepository};
+use crate::domain::{DeliveryInfoId, DeliveryStatus, DomainError};
+
+/// 配送状況通知ハンドラ（ユースケース）
+pub struct RecordShipmentEventHandler {
+    delivery_info_repository: Arc<dyn DeliveryInfoRepository>,
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl RecordShipmentEventHandler {
+    pub fn new(
+        delivery_info_repository: Arc<dyn DeliveryInfoRepository>,
+        order_repository: Arc<dyn OrderRepository>,
+    ) -> Self {
+        Self {
+            delivery_info_repository,
+            order_repository,
+        }
+    }
+
+    /// 配送状態を進め、注文ステータスを発送済み・配達済みに同期する
+    pub async fn handle(
+        &self,
+        command: RecordShipmentEventCommand,
+    ) -> Result<ShipmentDTO, ApplicationError> {
+        println!(
+            "->> record_shipment_event_handler: shipment_id={}, status={}",
+            command.shipment_id, command.status
+        );
+
+        let shipment_id = Uuid::parse_str(&command.shipment_id)
+            .map(DeliveryInfoId::from_uuid)
+            .map_err(|_| {
+                ApplicationError::InvalidInput(format!(
+                    "Invalid shipment ID format: {}",
+                    command.shipment_id
+                ))
+            })?;
+        let status = command.status.parse::<DeliveryStatus>()?;
+
+        let (order_id, _) = self
+            .delivery_info_repository
+            .find_by_id(&shipment_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Shipment not found: {}", command.shipment_id))
+            })?;
+        let mut order = self
+            .order_repository
+            .find_by_id(&order_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Order not found: {}", order_id)))?;
+
+        // 再出荷で差し替えられた配送情報への通知は受け付けない
+        if order.delivery_info.as_ref().map(|d| d.id()) != Some(&shipment_id) {
+            return Err(DomainError::BusinessRuleViolation(format!(
+                "Shipment {} is no longer active for order {}",
+                shipment_id, order_id
+            ))
+            .into());
+        }
+
+        if order.advance_delivery(status)? {
+            self.order_repository.update(&order).await?;
+        }
+
+        let delivery_info = order
+            .delivery_info
+            .as_ref()
+            .expect("active shipment was checked above");
+        Ok(ShipmentDTO::from_delivery_info(&order.id, delivery_info))
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use super::order_loader::load_order;
+use super::payment_recorder::record_gateway_result;
+use crate::application::commands::models::RefundOrderCommand;
+use crate::application::dto::OrderDTO;
+use crate::application::error::ApplicationError;
+use crate::application::gateways::PaymentGateway;
+use crate::application::repositories::{OrderRepository, PaymentRepository, ReturnRepository};
+use crate::domain::DomainError;
+use crate::domain::aggregates::order::OrderStatus;
+use crate::domain::aggregates::return_request::ReturnStatus;
+use crate::domain::value_objects::Money;
+use crate::domain::{PaymentOperation, PaymentState};
+
+/// 注文返金ハンドラ（ユースケース）
+pub struct RefundOrderHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    payment_repository: Arc<dyn PaymentRepository>,
+    payment_gateway: Arc<dyn PaymentGateway>,
+    return_repository: Arc<dyn ReturnRepository>,
+}
+
+impl RefundOrderHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        payment_repository: Arc<dyn PaymentRepository>,
+        payment_gateway: Arc<dyn PaymentGateway>,
+        return_repository: Arc<dyn ReturnRepository>,
+    ) -> Self {
+        Self {
+            order_repository,
+            payment_repository,
+            payment_gateway,
+            return_repository,
+        }
+    }
+
+    /// 注文返金を実行
+    /// 決済代行会社で売上確定済みの場合は返金が成功したときだけ返金済みにする
+    /// （銀行振込などゲートウェイを通さない支払いはステータスのみ変更）
+    /// 返品で一部返金済みの場合は残額のみ返金する
+    pub async fn handle(&self, command: RefundOrderCommand) -> Result<OrderDTO, ApplicationError> {
+        println!("->> refund_order_handler: order_id={}", command.order_id);
+
+        let mut order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        order.update_status(OrderStatus::Refunded)?;
+
+        // 返品手続き中・返品済みの注文を全額返金すると在庫の戻し入れと返金が二重になる
+        let returns = self.return_repository.find_by_order_id(&order.id).await?;
+        if returns
+            .iter()
+            .any(|return_request| return_request.status != ReturnStatus::Rejected)
+        {
+            return Err(DomainError::BusinessRuleViolation(
+                "Order with returns cannot be fully refunded; refund each return instead"
+                    .to_string(),
+            )
+            .into());
+        }
+
+        let transactions = self.payment_repository.find_by_order_id(&order.id).await?;
+        if let PaymentState::Captured {
+            transaction_id,
+            amount,
+            refunded_amount,
+            ..
+        } = PaymentState::from_transactions(&transactions)
+        {
+            let remaining = amount.subtract(refunded_amount).unwrap_or(Money::zero());
+            if remaining.is_positive() {
+                let result = self
+                    .payment_gateway
+                    .refund(&transaction_id, remaining)
+                    .await;
+
+                record_gateway_result(
+                    self.payment_repository.as_ref(),
+                    &order.id,
+                    self.payment_gateway.provider(),
+                    PaymentOperation::Refund,
+                    remaining,
+                    Some(&transaction_id),
+                    result,
+                )
+                .await?;
+            }
+        }
+
+        // 返金後に注文を更新できなかった場合（同時更新など）は、返金だけが済んだ状態にならないよう
+        // 最新の注文に返金済みへの遷移を適用し直す。返金は記録済みのため二重には返金しない
+        if let Err(err) = self.order_repository.update(&order).await {
+            println!(
+                "->> refund_order_handler: order update failed, retrying with latest order: {}",
+                err
+            );
+            order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+            if order.status != OrderStatus::Refunded {
+                order.update_status(OrderStatus::Refunded)?;
+                self.order_repository.update(&order).await?;
+            }
+        }
+
+        Ok(OrderDTO::from_order(&order))
+    }
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use super::payment_recorder::record_gateway_result;
+use super::return_loader::load_return;
+use crate::application::commands::models::RefundReturnCommand;
+use crate::application::dto::ReturnDTO;
+use crate::application::error::ApplicationError;
+use crate::application::gateways::PaymentGateway;
+use crate::application::repositories::{PaymentRepository, ReturnRepository};
+use crate::domain::aggregates::return_request::ReturnStatus;
+use crate::domain::value_objects::Money;
+use crate::domain::{PaymentOperation, PaymentState};
+
+/// 返品返金ハンドラ（ユースケース）
+pub struct RefundReturnHandler {
+    return_repository: Arc<dyn ReturnRepository>,
+    payment_repository: Arc<dyn PaymentRepository>,
+    payment_gateway: Arc<dyn PaymentGateway>,
+}
+
+impl RefundReturnHandler {
+    pub fn new(
+        return_repository: Arc<dyn ReturnRepository>,
+        payment_repository: Arc<dyn PaymentRepository>,
+        payment_gateway: Arc<dyn PaymentGateway>,
+    ) -> Self {
+        Self {
+            return_repository,
+            payment_repository,
+            payment_gateway,
+        }
+    }
+
+    /// 返品返金を実行
+    /// 先に返金済みへの更新で返品を確保してから、決済代行会社で売上確定済みの場合は返金額だけ一部返金する
+    /// 返金に失敗した場合は受領済みに戻す（銀行振込などゲートウェイを通さない支払いはステータスのみ変更）
+    pub async fn handle(
+        &self,
+        command: RefundReturnCommand,
+    ) -> Result<ReturnDTO, ApplicationError> {
+        println!("->> refund_return_handler: return_id={}", command.return_id);
+
+        let mut return_request =
+            load_return(self.return_repository.as_ref(), &command.return_id).await?;
+
+        // 返金済みへの更新に成功したリクエストだけが決済代行会社に返金を依頼する
+        // （同時に返金された場合は競合となり、二重に返金しない）
+        let received = return_request.clone();
+        return_request.mark_refunded()?;
+        self.return_repository
+            .update(&return_request, received.status, &[])
+            .await?;
+
+        let transactions = self
+            .payment_repository
+            .find_by_order_id(&return_request.order_id)
+            .await?;
+        if let PaymentState::Captured {
+            transaction_id,
+            amount,
+            refunded_amount,
+            ..
+        } = PaymentState::from_transactions(&transactions)
+        {
+            // 売上確定額の残りを超えて返金しない
+            let remaining = amount.subtract(refunded_amount).unwrap_or(Money::zero());
+            let refund = return_request.refund_amount.min(remaining);
+
+            if refund.is_positive() {
+                let result = self.payment_gateway.refund(&transaction_id, refund).await;
+
+                let recorded = record_gateway_result(
+                    self.payment_repository.as_ref(),
+                    &return_request.order_id,
+                    self.payment_gateway.provider(),
+                    PaymentOperation::Refund,
+                    refund,
+                    Some(&transaction_id),
+                    result,
+                )
+                .await;
+                if let Err(e) = recorded {
+                    self.return_repository
+                        .update(&received, ReturnStatus::Refunded, &[])
+                        .await?;
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(ReturnDTO::from_return(&return_request))
+    }
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+
+use super::session_issuer::issue_session;
+use crate::application::commands::models::RegisterCustomerCommand;
+use crate::application::dto::CustomerSessionDTO;
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::repositories::CustomerRepository;
+use crate::application::security::{PasswordHasher, SessionTokenSigner};
+use crate::domain::aggregates::customer::Customer;
+use crate::domain::value_objects::{Email, FirstName, LastName, PersonalInfo, PhoneNumber};
+
+/// 会員登録ハンドラ（ユースケース）
+pub struct RegisterCustomerHandler {
+    customer_repository: Arc<dyn CustomerRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    token_signer: Arc<dyn SessionTokenSigner>,
+    session_ttl: Duration,
+}
+
+impl RegisterCustomerHandler {
+    /// パスワードの最小文字数
+    pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+    pub fn new(
+        customer_repository: Arc<dyn CustomerRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        token_signer: Arc<dyn SessionTokenSigner>,
+        session_ttl: Duration,
+    ) -> Self {
+        Self {
+            customer_repository,
+            password_hasher,
+            token_signer,
+            session_ttl,
+        }
+    }
+
+    /// 会員を登録し、そのままログインした状態のセッションを発行
+    pub async fn handle(
+        &self,
+        command: RegisterCustomerCommand,
+    ) -> Result<CustomerSessionDTO, ApplicationError> {
+        println!("->> register_customer_handler");
+
+        let email = Email::new(command.email.trim().to_string())
+            .map_err(|e| ApplicationError::InvalidInput(format!("{:?}", e)))?;
+        let first_name = FirstName::new(command.first_name)
+            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+        let last_name = LastName::new(command.last_name)
+            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+        let phone = PhoneNumber::new(command.phone)
+            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+
+        if command.password.chars().count() < Self::MIN_PASSWORD_LENGTH {
+            return Err(ApplicationError::InvalidInput(format!(
+                "Password must be at least {} characters",
+                Self::MIN_PASSWORD_LENGTH
+            )));
+        }
+        let password_hash = self
+            .password_hasher
+            .hash(&command.password)
+            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+
+        let customer = Customer::register(
+            email,
+            password_hash,
+            PersonalInfo::new(first_name, last_name),
+            phone,
+        );
+        self.customer_repository
+            .save(&customer)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::UniqueConstraint { field, .. } if field == "email" => {
+                    ApplicationError::Conflict(
+                        "This email address is already registered".to_string(),
+                    )
+                }
+                e => e.into(),
+            })?;
+
+        issue_session(
+            self.customer_repository.as_ref(),
+            self.token_signer.as_ref(),
+            &customer,
+            self.session_ttl,
+        )
+        .await
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use super::return_loader::load_return;
+use crate::application::commands::models::RejectReturnCommand;
+use crate::application::dto::ReturnDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ReturnRepository;
+
+/// 返品却下ハンドラ（ユースケース）
+pub struct RejectReturnHandler {
+    return_repository: Arc<dyn ReturnRepository>,
+}
+
+impl RejectReturnHandler {
+    pub fn new(return_repository: Arc<dyn ReturnRepository>) -> Self {
+        Self { return_repository }
+    }
+
+    /// 返品却下を実行
+    pub async fn handle(
+        &self,
+        command: RejectReturnCommand,
+    ) -> Result<ReturnDTO, ApplicationError> {
+        println!("->> reject_return_handler: return_id={}", command.return_id);
+
+        let mut return_request =
+            load_return(self.return_repository.as_ref(), &command.return_id).await?;
+
+        let previous_status = return_request.status;
+        return_request.reject(command.reason)?;
+
+        self.return_repository
+            .update(&return_request, previous_status, &[])
+            .await?;
+
+        Ok(ReturnDTO::from_return(&return_request))
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use super::cart_loader::{load_cart, parse_sku_id};
+use crate::application::commands::models::RemoveCartItemCommand;
+use crate::application::dto::CartDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CartRepository;
+use crate::application::services::CartPricingService;
+
+/// カートアイテム削除ハンドラ（ユースケース）
+pub struct RemoveCartItemHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    cart_pricing_service: Arc<CartPricingService>,
+}
+
+impl RemoveCartItemHandler {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository>,
+        cart_pricing_service: Arc<CartPricingService>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            cart_pricing_service,
+        }
+    }
+
+    /// カートからアイテムを削除
+    pub async fn handle(
+        &self,
+        command: RemoveCartItemCommand,
+    ) -> Result<CartDTO, ApplicationError> {
+        println!(
+            "->> remove_cart_item_handler: cart_id={}, sku_id={}",
+            command.cart_id, command.sku_id
+        );
+
+        let mut cart = load_cart(self.cart_repository.as_ref(), &command.cart_id).await?;
+        let sku_id = parse_sku_id(&command.sku_id)?;
+
+        if !cart.contains_sku(&sku_id) {
+            return Err(ApplicationError::NotFound(format!(
+                "SKU not found in cart: {}",
+                command.sku_id
+            )));
+        }
+
+        cart.remove_item(&sku_id);
+
+        self.cart_repository.save(&cart).await?;
+
+        self.cart_pricing_service.price(&cart).await
+    }
+}
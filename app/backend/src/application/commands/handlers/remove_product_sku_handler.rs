@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use super::cart_loader::parse_sku_id;
+use super::product_loader::{load_product, save_product};
+use crate::application::commands::models::RemoveProductSkuCommand;
+use crate::application::dto::AdminProductDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ProductWriteRepository;
+
+/// SKU削除ハンドラ（ユースケース）
+pub struct RemoveProductSkuHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+}
+
+impl RemoveProductSkuHandler {
+    pub fn new(product_repository: Arc<dyn ProductWriteRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// 商品からSKUを削除（注文履歴のあるSKUは削除できないため販売停止で対応する）
+    pub async fn handle(
+        &self,
+        command: RemoveProductSkuCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        println!(
+            "->> remove_product_sku_handler: product_id={}, sku_id={}",
+            command.product_id, command.sku_id
+        );
+
+        let mut product =
+            load_product(self.product_repository.as_ref(), &command.product_id).await?;
+        let sku_id = parse_sku_id(&command.sku_id)?;
+        if product.find_sku_by_id(&sku_id).is_none() {
+            return Err(ApplicationError::NotFound(format!(
+                "SKU not found: {}",
+                command.sku_id
+            )));
+        }
+        product.remove_sku(&sku_id)?;
+
+        save_product(self.product_repository.as_ref(), &product).await
+    }
+}
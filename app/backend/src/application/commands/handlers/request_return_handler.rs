@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use super::order_loader::load_order;
+use crate::application::commands::models::RequestReturnCommand;
+use crate::application::dto::ReturnDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{OrderRepository, ReturnRepository};
+use crate::domain::aggregates::return_request::{ReturnCondition, ReturnReason, ReturnRequest};
+use crate::domain::value_objects::{SKUId, TaxPolicy};
+
+/// 返品申請ハンドラ（ユースケース）
+pub struct RequestReturnHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    return_repository: Arc<dyn ReturnRepository>,
+    tax_policy: TaxPolicy,
+    restocking_fee_rate: u32,
+}
+
+impl RequestReturnHandler {
+    /// 再入荷手数料率の既定値（%）
+    pub const DEFAULT_RESTOCKING_FEE_RATE: u32 = 10;
+
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        return_repository: Arc<dyn ReturnRepository>,
+        tax_policy: TaxPolicy,
+    ) -> Self {
+        Self {
+            order_repository,
+            return_repository,
+            tax_policy,
+            restocking_fee_rate: Self::DEFAULT_RESTOCKING_FEE_RATE,
+        }
+    }
+
+    /// お客様都合の返品で差し引く再入荷手数料率（%）を指定
+    pub fn with_restocking_fee_rate(mut self, restocking_fee_rate: u32) -> Self {
+        self.restocking_fee_rate = restocking_fee_rate;
+        self
+    }
+
+    /// 返品申請を実行
+    pub async fn handle(
+        &self,
+        command: RequestReturnCommand,
+    ) -> Result<ReturnDTO, ApplicationError> {
+        println!("->> request_return_handler: order_id={}", command.order_id);
+
+        let order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        let reason: ReturnReason =
+            command
+                .reason
+                .parse()
+                .map_err(|e: crate::domain::DomainError| {
+                    ApplicationError::InvalidInput(e.to_string())
+                })?;
+        let condition: ReturnCondition =
+            command
+                .condition
+                .parse()
+                .map_err(|e: crate::domain::DomainError| {
+                    ApplicationError::InvalidInput(e.to_string())
+                })?;
+
+        let lines = command
+            .items
+            .iter()
+            .map(|item| {
+                Uuid::parse_str(&item.sku_id)
+                    .map(|uuid| (SKUId::from_uuid(uuid), item.quantity))
+                    .map_err(|_| {
+                        ApplicationError::InvalidInput(format!(
+                            "Invalid SKU ID format: {}",
+                            item.sku_id
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, ApplicationError>>()?;
+
+        // 同じ注文の返品と合わせて注文数量を超えないよう、既存の返品を渡して検証する
+        let existing_returns = self.return_repository.find_by_order_id(&order.id).await?;
+        let return_request = ReturnRequest::request(
+            &order,
+            lines,
+            reason,
+            condition,
+            &existing_returns,
+            &self.tax_policy,
+            self.restocking_fee_rate,
+        )?
+        .with_note(command.note)?;
+
+        self.return_repository.save(&return_request).await?;
+
+        Ok(ReturnDTO::from_return(&return_request))
+    }
+}
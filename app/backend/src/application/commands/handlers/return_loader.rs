@@ -0,0 +1,21 @@
+use uuid::Uuid;
+
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ReturnRepository;
+use crate::domain::aggregates::return_request::ReturnRequest;
+use crate::domain::value_objects::ReturnId;
+
+/// 返品IDの文字列から返品を取得（返品のステータス遷移系コマンド共通）
+pub(super) async fn load_return(
+    return_repository: &dyn ReturnRepository,
+    return_id: &str,
+) -> Result<ReturnRequest, ApplicationError> {
+    let uuid = Uuid::parse_str(return_id).map_err(|_| {
+        ApplicationError::InvalidInput(format!("Invalid return ID format: {}", return_id))
+    })?;
+
+    return_repository
+        .find_by_id(&ReturnId::from_uuid(uuid))
+        .await?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Return not found: {}", return_id)))
+}
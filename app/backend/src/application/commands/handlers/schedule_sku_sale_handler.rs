@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use super::cart_loader::parse_sku_id;
+use super::product_loader::{load_product, save_product};
+use crate::application::commands::models::ScheduleSkuSaleCommand;
+use crate::application::dto::AdminProductDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ProductWriteRepository;
+use crate::domain::{Money, PriceSchedule};
+
+/// SKUの期間指定セール予約ハンドラ（ユースケース）
+pub struct ScheduleSkuSaleHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+}
+
+impl ScheduleSkuSaleHandler {
+    pub fn new(product_repository: Arc<dyn ProductWriteRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// SKUに価格スケジュールを追加（期間中は手動のセール価格より優先して適用される）
+    pub async fn handle(
+        &self,
+        command: ScheduleSkuSaleCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        println!(
+            "->> schedule_sku_sale_handler: product_id={}, sku_id={}, {} - {}",
+            command.product_id, command.sku_id, command.starts_at, command.ends_at
+        );
+
+        let schedule = PriceSchedule::new(
+            Money::from_yen(command.sale_price),
+            parse_datetime(&command.starts_at)?,
+            parse_datetime(&command.ends_at)?,
+        )?;
+
+        let mut product =
+            load_product(self.product_repository.as_ref(), &command.product_id).await?;
+        let sku_id = parse_sku_id(&command.sku_id)?;
+        let sku = product.find_sku_by_id_mut(&sku_id).ok_or_else(|| {
+            ApplicationError::NotFound(format!("SKU not found: {}", command.sku_id))
+        })?;
+
+        sku.schedule_sale(schedule, Utc::now())?;
+
+        save_product(self.product_repository.as_ref(), &product).await
+    }
+}
+
+/// RFC3339形式の日時をパース
+fn parse_datetime(value: &str) -> Result<DateTime<Utc>, ApplicationError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .map_err(|_| {
+            ApplicationError::InvalidInput(format!(
+                "Invalid datetime format (expected RFC3339): {}",
+                value
+            ))
+        })
+}
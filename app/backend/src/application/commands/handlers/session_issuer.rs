@@ -0,0 +1,21 @@
+use chrono::{Duration, Utc};
+
+use crate::application::dto::CustomerSessionDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CustomerRepository;
+use crate::application::security::SessionTokenSigner;
+use crate::domain::aggregates::customer::{Customer, CustomerSession};
+
+/// 会員のログインセッションを発行し、署名済みトークンを返す（会員登録・ログイン共通）
+pub(super) async fn issue_session(
+    customer_repository: &dyn CustomerRepository,
+    token_signer: &dyn SessionTokenSigner,
+    customer: &Customer,
+    session_ttl: Duration,
+) -> Result<CustomerSessionDTO, ApplicationError> {
+    let session = CustomerSession::issue(customer.id(), Utc::now(), session_ttl);
+    customer_repository.save_session(&session).await?;
+
+    let token = token_signer.sign(session.id());
+    Ok(CustomerSessionDTO::new(token, &session, customer))
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use super::cart_loader::load_cart;
+use crate::application::commands::models::SetCartCouponCommand;
+use crate::application::dto::CartDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{CartRepository, CouponRepository};
+use crate::application::services::CartPricingService;
+use crate::domain::CouponCode;
+
+/// カートクーポン設定ハンドラ（ユースケース）
+pub struct SetCartCouponHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    coupon_repository: Arc<dyn CouponRepository>,
+    cart_pricing_service: Arc<CartPricingService>,
+}
+
+impl SetCartCouponHandler {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository>,
+        coupon_repository: Arc<dyn CouponRepository>,
+        cart_pricing_service: Arc<CartPricingService>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            coupon_repository,
+            cart_pricing_service,
+        }
+    }
+
+    /// カートにクーポンを設定（None の場合は取り外し）
+    /// 設定時点で適用条件を満たさないクーポンはエラーとし、カートは変更しない
+    pub async fn handle(&self, command: SetCartCouponCommand) -> Result<CartDTO, ApplicationError> {
+        println!(
+            "->> set_cart_coupon_handler: cart_id={}, coupon_code={:?}",
+            command.cart_id, command.coupon_code
+        );
+
+        let mut cart = load_cart(self.cart_repository.as_ref(), &command.cart_id).await?;
+
+        match command.coupon_code {
+            Some(code) => {
+                let coupon_code = CouponCode::from_string(code.clone())?;
+                let coupon = self
+                    .coupon_repository
+                    .find_by_code(&coupon_code)
+                    .await?
+                    .ok_or_else(|| {
+                        ApplicationError::NotFound(format!("Coupon not found: {}", code))
+                    })?;
+                cart.apply_coupon(coupon)?;
+            }
+            None => cart.remove_coupon(),
+        }
+
+        // 割引条件は選択済みの配送方法・支払い方法も含めて判定されるため、保存前に計算して検証
+        let priced = self.cart_pricing_service.price(&cart).await?;
+        if let Some(error) = priced.totals.coupon_error {
+            return Err(ApplicationError::InvalidInput(error.error_message));
+        }
+
+        self.cart_repository.save(&cart).await?;
+
+        Ok(priced)
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use super::cart_loader::load_cart;
+use crate::application::commands::models::SetCartPaymentMethodCommand;
+use crate::application::dto::CartDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{CartRepository, PaymentMethodRepository};
+use crate::application::services::CartPricingService;
+
+/// カート支払い方法設定ハンドラ（ユースケース）
+pub struct SetCartPaymentMethodHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    payment_method_repository: Arc<dyn PaymentMethodRepository>,
+    cart_pricing_service: Arc<CartPricingService>,
+}
+
+impl SetCartPaymentMethodHandler {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository>,
+        payment_method_repository: Arc<dyn PaymentMethodRepository>,
+        cart_pricing_service: Arc<CartPricingService>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            payment_method_repository,
+            cart_pricing_service,
+        }
+    }
+
+    /// カートの支払い方法を設定
+    pub async fn handle(
+        &self,
+        command: SetCartPaymentMethodCommand,
+    ) -> Result<CartDTO, ApplicationError> {
+        println!(
+            "->> set_cart_payment_method_handler: cart_id={}, payment_method_id={}",
+            command.cart_id, command.payment_method_id
+        );
+
+        let mut cart = load_cart(self.cart_repository.as_ref(), &command.cart_id).await?;
+
+        let payment_method = self
+            .payment_method_repository
+            .find_by_id(&command.payment_method_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "Payment method not found: {}",
+                    command.payment_method_id
+                ))
+            })?;
+        cart.apply_payment_method(&payment_method)?;
+
+        self.cart_repository.save(&cart).await?;
+
+        self.cart_pricing_service.price(&cart).await
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use super::cart_loader::load_cart;
+use crate::application::commands::models::SetCartShippingMethodCommand;
+use crate::application::dto::CartDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{CartRepository, ShippingMethodRepository};
+use crate::application::services::CartPricingService;
+
+/// カート配送方法設定ハンドラ（ユースケース）
+pub struct SetCartShippingMethodHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    shipping_method_repository: Arc<dyn ShippingMethodRepository>,
+    cart_pricing_service: Arc<CartPricingService>,
+}
+
+impl SetCartShippingMethodHandler {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository>,
+        shipping_method_repository: Arc<dyn ShippingMethodRepository>,
+        cart_pricing_service: Arc<CartPricingService>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            shipping_method_repository,
+            cart_pricing_service,
+        }
+    }
+
+    /// カートの配送方法を設定
+    pub async fn handle(
+        &self,
+        command: SetCartShippingMethodCommand,
+    ) -> Result<CartDTO, ApplicationError> {
+        println!(
+            "->> set_cart_shipping_method_handler: cart_id={}, shipping_method_id={}",
+            command.cart_id, command.shipping_method_id
+        );
+
+        let mut cart = load_cart(self.cart_repository.as_ref(), &command.cart_id).await?;
+
+        let shipping_method = self
+            .shipping_method_repository
+            .find_by_id(&command.shipping_method_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "Shipping method not found: {}",
+                    command.shipping_method_id
+                ))
+            })?;
+        let shipment = self.cart_pricing_service.shipment_details(&cart).await?;
+        cart.apply_shipping_method(&shipping_method, &shipment)?;
+
+        self.cart_repository.save(&cart).await?;
+
+        self.cart_pricing_service.price(&cart).await
+    }
+}
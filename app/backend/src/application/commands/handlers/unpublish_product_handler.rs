@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use super::product_loader::{load_product, save_product};
+use crate::application::commands::models::UnpublishProductCommand;
+use crate::application::dto::AdminProductDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ProductWriteRepository;
+
+/// 商品非公開ハンドラ（ユースケース）
+pub struct UnpublishProductHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+}
+
+impl UnpublishProductHandler {
+    pub fn new(product_repository: Arc<dyn ProductWriteRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// 商品を非公開にし、ストアフロントから外す
+    pub async fn handle(
+        &self,
+        command: UnpublishProductCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        println!(
+            "->> unpublish_product_handler: product_id={}",
+            command.product_id
+        );
+
+        let mut product =
+            load_product(self.product_repository.as_ref(), &command.product_id).await?;
+        product.unpublish();
+
+        save_product(self.product_repository.as_ref(), &product).await
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use super::cart_loader::{find_purchasable_variant, load_cart, parse_sku_id};
+use crate::application::commands::models::UpdateCartItemCommand;
+use crate::application::dto::CartDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{CartRepository, ProductRepository};
+use crate::application::services::CartPricingService;
+
+/// カートアイテム数量変更ハンドラ（ユースケース）
+pub struct UpdateCartItemHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    product_repository: Arc<dyn ProductRepository>,
+    cart_pricing_service: Arc<CartPricingService>,
+}
+
+impl UpdateCartItemHandler {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository>,
+        product_repository: Arc<dyn ProductRepository>,
+        cart_pricing_service: Arc<CartPricingService>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            product_repository,
+            cart_pricing_service,
+        }
+    }
+
+    /// カートアイテムの数量を変更（0の場合は削除）
+    pub async fn handle(
+        &self,
+        command: UpdateCartItemCommand,
+    ) -> Result<CartDTO, ApplicationError> {
+        println!(
+            "->> update_cart_item_handler: cart_id={}, sku_id={}, quantity={}",
+            command.cart_id, command.sku_id, command.quantity
+        );
+
+        let mut cart = load_cart(self.cart_repository.as_ref(), &command.cart_id).await?;
+        let sku_id = parse_sku_id(&command.sku_id)?;
+
+        if !cart.contains_sku(&sku_id) {
+            return Err(ApplicationError::NotFound(format!(
+                "SKU not found in cart: {}",
+                command.sku_id
+            )));
+        }
+
+        if command.quantity > 0 {
+            find_purchasable_variant(self.product_repository.as_ref(), &sku_id, command.quantity)
+                .await?;
+        }
+
+        cart.update_item_quantity(&sku_id, command.quantity)?;
+
+        self.cart_repository.save(&cart).await?;
+
+        self.cart_pricing_service.price(&cart).await
+    }
+}
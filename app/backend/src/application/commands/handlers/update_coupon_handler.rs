@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use super::coupon_loader::{build_discount_policy, load_coupon, parse_validity};
+use crate::application::commands::models::UpdateCouponCommand;
+use crate::application::dto::CouponDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CouponRepository;
+use crate::domain::value_objects::CouponCode;
+
+/// クーポン更新ハンドラ（ユースケース）
+pub struct UpdateCouponHandler {
+    coupon_repository: Arc<dyn CouponRepository>,
+}
+
+impl UpdateCouponHandler {
+    pub fn new(coupon_repository: Arc<dyn CouponRepository>) -> Self {
+        Self { coupon_repository }
+    }
+
+    /// クーポンの設定を置き換える（使用回数は引き継ぐ）
+    pub async fn handle(
+        &self,
+        command: UpdateCouponCommand,
+    ) -> Result<CouponDTO, ApplicationError> {
+        println!(
+            "->> update_coupon_handler: coupon_id={}, code={}",
+            command.coupon_id, command.code
+        );
+
+        let mut coupon = load_coupon(self.coupon_repository.as_ref(), &command.coupon_id).await?;
+
+        let (valid_from, valid_until) = parse_validity(&command.valid_from, &command.valid_until)?;
+        coupon.change_code(CouponCode::from_string(command.code)?);
+        coupon.rename(command.name, command.description)?;
+        coupon.change_discount_policy(build_discount_policy(&command.discount)?)?;
+        coupon.change_validity(valid_from, valid_until)?;
+        coupon.change_usage_limit(command.usage_limit)?;
+        coupon.change_per_customer_limit(command.per_customer_limit)?;
+
+        self.coupon_repository.update(&coupon).await?;
+
+        Ok(CouponDTO::from_coupon(&coupon))
+    }
+}
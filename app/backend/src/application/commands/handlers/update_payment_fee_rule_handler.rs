@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use crate::application::commands::models::UpdatePaymentFeeRuleCommand;
+use crate::application::dto::PaymentMethodDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::PaymentMethodRepository;
+use crate::domain::entities::{PaymentFeeBracket, PaymentFeeKind, PaymentFeeRule};
+use crate::domain::value_objects::Money;
+
+/// 支払い手数料ルール更新ハンドラ（ユースケース）
+pub struct UpdatePaymentFeeRuleHandler {
+    payment_method_repository: Arc<dyn PaymentMethodRepository>,
+}
+
+impl UpdatePaymentFeeRuleHandler {
+    pub fn new(payment_method_repository: Arc<dyn PaymentMethodRepository>) -> Self {
+        Self {
+            payment_method_repository,
+        }
+    }
+
+    /// 支払い方法の手数料ルールを置き換える
+    pub async fn handle(
+        &self,
+        command: UpdatePaymentFeeRuleCommand,
+    ) -> Result<PaymentMethodDTO, ApplicationError> {
+        println!(
+            "->> update_payment_fee_rule_handler: payment_method_id={}, fee_type={}",
+            command.payment_method_id, command.fee_type
+        );
+
+        let mut payment_method = self
+            .payment_method_repository
+            .find_by_id(&command.payment_method_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "Payment method not found: {}",
+                    command.payment_method_id
+                ))
+            })?;
+
+        payment_method.change_fee_rule(Self::build_fee_rule(&command)?);
+
+        self.payment_method_repository
+            .update(&payment_method)
+            .await?;
+
+        Ok(PaymentMethodDTO::from_payment_method(&payment_method))
+    }
+
+    /// コマンドから手数料ルールを構築（計算方式に必要な項目がなければエラー）
+    fn build_fee_rule(
+        command: &UpdatePaymentFeeRuleCommand,
+    ) -> Result<PaymentFeeRule, ApplicationError> {
+        let kind = match command.fee_type.as_str() {
+            "flat" => PaymentFeeKind::Flat {
+                amount: Money::from_yen(command.amount.ok_or_else(|| {
+                    ApplicationError::Validation("Flat payment fee requires amount".to_string())
+                })?),
+            },
+            "percentage" => PaymentFeeKind::Percentage {
+                rate: command.rate.ok_or_else(|| {
+                    ApplicationError::Validation("Percentage payment fee requires rate".to_string())
+                })?,
+            },
+            "tiered" => PaymentFeeKind::Tiered {
+                brackets: command
+                    .brackets
+                    .iter()
+                    .map(|bracket| {
+                        PaymentFeeBracket::new(
+                            bracket.up_to.map(Money::from_yen),
+                            Money::from_yen(bracket.fee),
+                        )
+                    })
+                    .collect(),
+            },
+            other => {
+                return Err(ApplicationError::Validation(format!(
+                    "Invalid payment fee type: {} (expected flat, percentage or tiered)",
+                    other
+                )));
+            }
+        };
+
+        Ok(PaymentFeeRule::new(
+            kind,
+            command.min_fee.map(Money::from_yen),
+            command.max_fee.map(Money::from_yen),
+        )?)
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use super::product_loader::{build_images, load_product, resolve_tags, save_product};
+use crate::application::commands::models::UpdateProductCommand;
+use crate::application::dto::AdminProductDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ProductWriteRepository;
+use crate::domain::{CategoryId, Description, ProductName};
+
+/// 商品更新ハンドラ（ユースケース）
+pub struct UpdateProductHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+}
+
+impl UpdateProductHandler {
+    pub fn new(product_repository: Arc<dyn ProductWriteRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// 商品の基本情報・画像・タグを更新
+    pub async fn handle(
+        &self,
+        command: UpdateProductCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        println!(
+            "->> update_product_handler: product_id={}",
+            command.product_id
+        );
+
+        let mut product =
+            load_product(self.product_repository.as_ref(), &command.product_id).await?;
+
+        if let Some(name) = command.name {
+            product.rename(ProductName::new(name)?);
+        }
+        if let Some(description) = command.description {
+            product.update_description(Description::new(description));
+        }
+        if let Some(category_id) = command.category_id {
+            product.change_category(CategoryId::new(category_id)?);
+        }
+        match command.is_best_seller {
+            Some(true) => product.mark_as_best_seller(),
+            Some(false) => product.unmark_as_best_seller(),
+            None => {}
+        }
+        match command.is_quick_ship {
+            Some(true) => product.enable_quick_ship(),
+            Some(false) => product.disable_quick_ship(),
+            None => {}
+        }
+
+        if let Some(images) = &command.images {
+            let images = build_images(product.id(), images)?;
+            product.replace_images(images);
+        }
+
+        if let Some(tag_slugs) = &command.tag_slugs {
+            let tags = resolve_tags(self.product_repository.as_ref(), tag_slugs).await?;
+            let current_slugs: Vec<String> = product
+                .tags()
+                .iter()
+                .map(|tag| tag.slug().value().to_string())
+                .collect();
+            for slug in &current_slugs {
+                product.remove_tag(slug);
+            }
+            for tag in tags {
+                product.add_tag(tag);
+            }
+        }
+
+        save_product(self.product_repository.as_ref(), &product).await
+    }
+}
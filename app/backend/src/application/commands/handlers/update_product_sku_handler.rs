@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use super::cart_loader::parse_sku_id;
+use super::product_loader::{build_variant_attributes, load_product, save_product};
+use crate::application::commands::models::UpdateProductSkuCommand;
+use crate::application::dto::AdminProductDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::ProductWriteRepository;
+use crate::domain::{Money, SKUName, SKUStatus, TaxCategory};
+
+/// SKU更新ハンドラ（ユースケース）
+pub struct UpdateProductSkuHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+}
+
+impl UpdateProductSkuHandler {
+    pub fn new(product_repository: Arc<dyn ProductWriteRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// SKUの名称・属性・価格・ステータス・表示順・税区分を更新
+    pub async fn handle(
+        &self,
+        command: UpdateProductSkuCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        println!(
+            "->> update_product_sku_handler: product_id={}, sku_id={}",
+            command.product_id, command.sku_id
+        );
+
+        if command.clear_sale_price && command.sale_price.is_some() {
+            return Err(ApplicationError::InvalidInput(
+                "salePrice and clearSalePrice cannot be specified together".to_string(),
+            ));
+        }
+
+        let mut product =
+            load_product(self.product_repository.as_ref(), &command.product_id).await?;
+        let sku_id = parse_sku_id(&command.sku_id)?;
+        let sku = product.find_sku_by_id_mut(&sku_id).ok_or_else(|| {
+            ApplicationError::NotFound(format!("SKU not found: {}", command.sku_id))
+        })?;
+
+        if let Some(name) = command.name {
+            sku.rename(SKUName::new(name)?);
+        }
+
+        if command.color_id.is_some() || command.dimensions.is_some() || command.material.is_some()
+        {
+            let current = sku.variant_attributes();
+            let color_id = match command.color_id {
+                Some(color_id) => color_id,
+                None => current.color_id().map(|c| c.value()).ok_or_else(|| {
+                    ApplicationError::InvalidInput("colorId is required".to_string())
+                })?,
+            };
+            let dimensions = command
+                .dimensions
+                .or_else(|| current.dimensions().map(|d| d.value().to_string()));
+            let material = command
+                .material
+                .or_else(|| current.material().map(|m| m.value().to_string()));
+            sku.update_variant_attributes(build_variant_attributes(
+                color_id, dimensions, material,
+            )?)?;
+        }
+
+        // 基本価格とセール価格の大小関係を保つため、セール価格を外してから基本価格を更新する
+        if command.clear_sale_price || command.sale_price.is_some() {
+            sku.clear_sale_price();
+        }
+        if let Some(base_price) = command.base_price {
+            sku.update_base_price(Money::from_yen(base_price))?;
+        }
+        if let Some(sale_price) = command.sale_price {
+            sku.set_sale_price(Money::from_yen(sale_price))?;
+        }
+
+        if let Some(status) = command.status {
+            match status.parse::<SKUStatus>()? {
+                SKUStatus::Active => sku.activate(),
+                SKUStatus::Inactive => sku.deactivate(),
+                SKUStatus::Discontinued => sku.discontinue(),
+            }
+        }
+
+        if let Some(display_order) = command.display_order {
+            sku.set_display_order(display_order);
+        }
+
+        if let Some(tax_category) = command.tax_category {
+            sku.change_tax_category(tax_category.parse::<TaxCategory>()?);
+        }
+
+        save_product(self.product_repository.as_ref(), &product).await
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use super::order_loader::load_order;
+use super::payment_recorder::record_gateway_result;
+use crate::application::commands::models::VoidPaymentCommand;
+use crate::application::dto::PaymentResultDTO;
+use crate::application::error::ApplicationError;
+use crate::application::gateways::PaymentGateway;
+use crate::application::repositories::{OrderRepository, PaymentRepository};
+use crate::domain::error::DomainError;
+use crate::domain::{PaymentOperation, PaymentState};
+
+/// オーソリ取消ハンドラ（ユースケース）
+pub struct VoidPaymentHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    payment_repository: Arc<dyn PaymentRepository>,
+    payment_gateway: Arc<dyn PaymentGateway>,
+}
+
+impl VoidPaymentHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        payment_repository: Arc<dyn PaymentRepository>,
+        payment_gateway: Arc<dyn PaymentGateway>,
+    ) -> Self {
+        Self {
+            order_repository,
+            payment_repository,
+            payment_gateway,
+        }
+    }
+
+    /// 売上確定前のオーソリを取消（注文のステータスは変更しない）
+    pub async fn handle(
+        &self,
+        command: VoidPaymentCommand,
+    ) -> Result<PaymentResultDTO, ApplicationError> {
+        println!("->> void_payment_handler: order_id={}", command.order_id);
+
+        let order = load_order(self.order_repository.as_ref(), &command.order_id).await?;
+
+        let mut transactions = self.payment_repository.find_by_order_id(&order.id).await?;
+        let PaymentState::Authorized {
+            transaction_id,
+            amount,
+            ..
+        } = PaymentState::from_transactions(&transactions)
+        else {
+            return Err(DomainError::BusinessRuleViolation(
+                "No authorized payment to void for this order".to_string(),
+            )
+            .into());
+        };
+
+        let result = self.payment_gateway.void(&transaction_id).await;
+
+        let recorded = record_gateway_result(
+            self.payment_repository.as_ref(),
+            &order.id,
+            self.payment_gateway.provider(),
+            PaymentOperation::Void,
+            amount,
+            Some(&transaction_id),
+            result,
+        )
+        .await?;
+
+        transactions.push(recorded.clone());
+        let state = PaymentState::from_transactions(&transactions);
+
+        Ok(PaymentResultDTO::new(&order, &state, &recorded))
+    }
+}
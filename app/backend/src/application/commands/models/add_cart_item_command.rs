@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// カートアイテム追加コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddCartItemCommand {
+    pub cart_id: String,
+    pub sku_id: String,
+    pub quantity: u32,
+}
+
+impl AddCartItemCommand {
+    pub fn new(cart_id: String, sku_id: String, quantity: u32) -> Self {
+        Self {
+            cart_id,
+            sku_id,
+            quantity,
+        }
+    }
+}
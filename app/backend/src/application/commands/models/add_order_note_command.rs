@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// 注文備考追加コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddOrderNoteCommand {
+    pub order_id: String,
+    pub note: String,
+}
+
+impl AddOrderNoteCommand {
+    pub fn new(order_id: String, note: String) -> Self {
+        Self { order_id, note }
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use super::create_product_command::ProductSkuCommandItem;
+
+/// SKU追加コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddProductSkuCommand {
+    pub product_id: String,
+    pub sku: ProductSkuCommandItem,
+}
+
+impl AddProductSkuCommand {
+    pub fn new(product_id: String, sku: ProductSkuCommandItem) -> Self {
+        Self { product_id, sku }
+    }
+}
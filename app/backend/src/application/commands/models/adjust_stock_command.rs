@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// 在庫調整コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjustStockCommand {
+    pub sku_id: String,
+    /// "receive"（入荷） / "correction"（補正） / "write_off"（破損・紛失による廃棄）
+    pub adjustment_type: String,
+    /// 入荷・廃棄は正の数量、補正は増減数（減らす場合は負数）
+    pub quantity: i64,
+    pub reason: String,
+    pub reference_order_id: Option<String>,
+    /// 調整を行った担当者
+    pub actor: String,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CreateOrderCommandItem, CreateOrderCommandShippingAddress};
+
+/// 注文変更コマンド（未指定の項目は現在の注文内容を引き継ぐ）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmendOrderCommand {
+    pub order_id: String,
+    /// 変更後の注文アイテム（指定した場合は明細全体を置き換える）
+    pub items: Option<Vec<CreateOrderCommandItem>>,
+    pub shipping_method_id: Option<String>,
+    pub payment_method_id: Option<String>,
+    pub shipping_address: Option<CreateOrderCommandShippingAddress>,
+}
+
+impl AmendOrderCommand {
+    pub fn new(
+        order_id: String,
+        items: Option<Vec<CreateOrderCommandItem>>,
+        shipping_method_id: Option<String>,
+        payment_method_id: Option<String>,
+        shipping_address: Option<CreateOrderCommandShippingAddress>,
+    ) -> Self {
+        Self {
+            order_id,
+            items,
+            shipping_method_id,
+            payment_method_id,
+            shipping_address,
+        }
+    }
+}
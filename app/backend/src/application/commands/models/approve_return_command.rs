@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 返品承認コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApproveReturnCommand {
+    pub return_id: String,
+}
+
+impl ApproveReturnCommand {
+    pub fn new(return_id: String) -> Self {
+        Self { return_id }
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// 出荷登録コマンド（注文に配送業者・追跡番号を紐付ける）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachShipmentCommand {
+    pub order_id: String,
+    pub carrier: String,
+    pub tracking_number: String,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// オーソリコマンド（注文の合計金額で与信枠を確保する）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizePaymentCommand {
+    pub order_id: String,
+    /// フロントエンドで取得したカードトークンなど
+    pub payment_token: Option<String>,
+}
@@ -14,6 +14,8 @@ pub struct CalculateCartCommand {
     pub shipping_method_id: String,
     pub payment_method_id: String,
     pub coupon_code: Option<String>,
+    /// 配送先の都道府県（未定の場合は地域別の配送料を適用しない）
+    pub prefecture: Option<String>,
 }
 
 impl CalculateCartCommand {
@@ -22,12 +24,14 @@ impl CalculateCartCommand {
         shipping_method_id: String,
         payment_method_id: String,
         coupon_code: Option<String>,
+        prefecture: Option<String>,
     ) -> Self {
         Self {
             items,
             shipping_method_id,
             payment_method_id,
             coupon_code,
+            prefecture,
         }
     }
 }
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// 注文キャンセルコマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelOrderCommand {
+    pub order_id: String,
+    pub reason: String,
+}
+
+impl CancelOrderCommand {
+    pub fn new(order_id: String, reason: String) -> Self {
+        Self { order_id, reason }
+    }
+}
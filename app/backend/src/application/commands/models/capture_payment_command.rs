@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 売上確定コマンド（オーソリ済みの決済を確定し、注文を支払い済みにする）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapturePaymentCommand {
+    pub order_id: String,
+}
+
+impl CapturePaymentCommand {
+    pub fn new(order_id: String) -> Self {
+        Self { order_id }
+    }
+}
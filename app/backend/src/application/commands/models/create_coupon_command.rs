@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// クーポンの割引内容の指定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CouponDiscountCommandItem {
+    /// 割引種別（fixed_amount, percentage, capped_percentage, free_shipping, buy_x_get_y）
+    pub discount_type: String,
+    /// 割引額（fixed_amount）または割引率（percentage, capped_percentage）
+    pub discount_value: Option<u32>,
+    /// 割引額の上限（capped_percentage）
+    pub max_discount_amount: Option<u32>,
+    /// 購入数（buy_x_get_y）
+    pub buy_quantity: Option<u32>,
+    /// 無料になる数（buy_x_get_y）
+    pub get_quantity: Option<u32>,
+    /// 最低購入金額（商品小計）
+    pub minimum_amount: Option<u32>,
+    /// 対象商品ID（指定した商品の明細のみ割引）
+    #[serde(default)]
+    pub target_product_ids: Vec<String>,
+    /// 対象カテゴリID（指定したカテゴリの商品の明細のみ割引）
+    #[serde(default)]
+    pub target_category_ids: Vec<String>,
+}
+
+/// クーポン作成コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCouponCommand {
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub discount: CouponDiscountCommandItem,
+    pub usage_limit: Option<u32>,
+    pub per_customer_limit: Option<u32>,
+    /// 有効期間の開始日（YYYY-MM-DD）
+    pub valid_from: String,
+    /// 有効期間の終了日（YYYY-MM-DD、終日有効）
+    pub valid_until: String,
+}
@@ -34,6 +34,9 @@ pub struct CreateOrderCommand {
     pub shipping_method_id: String,
     pub payment_method_id: String,
     pub shipping_address: CreateOrderCommandShippingAddress,
+    pub coupon_code: Option<String>,
+    /// ログイン中の会員ID（ゲスト注文の場合はNone）
+    pub customer_id: Option<String>,
 }
 
 impl CreateOrderCommand {
@@ -43,6 +46,7 @@ impl CreateOrderCommand {
         shipping_method_id: String,
         payment_method_id: String,
         shipping_address: CreateOrderCommandShippingAddress,
+        coupon_code: Option<String>,
     ) -> Self {
         Self {
             customer_info,
@@ -50,6 +54,14 @@ impl CreateOrderCommand {
             shipping_method_id,
             payment_method_id,
             shipping_address,
+            coupon_code,
+            customer_id: None,
         }
     }
+
+    /// ログイン中の会員の注文として作成する
+    pub fn with_customer_id(mut self, customer_id: Option<String>) -> Self {
+        self.customer_id = customer_id;
+        self
+    }
 }
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// 商品画像の指定（配列の順序が表示順になる）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductImageCommandItem {
+    pub url: String,
+    pub alt_text: Option<String>,
+}
+
+/// SKUの指定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductSkuCommandItem {
+    pub sku_code: String,
+    pub name: String,
+    pub color_id: u32,
+    pub dimensions: Option<String>,
+    pub material: Option<String>,
+    pub base_price: u32,
+    pub sale_price: Option<u32>,
+    pub initial_stock: u32,
+    pub display_order: Option<u32>,
+    /// "standard" / "reduced"（省略時は標準税率）
+    pub tax_category: Option<String>,
+}
+
+/// 商品作成コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateProductCommand {
+    pub name: String,
+    pub description: String,
+    pub category_id: String,
+    pub is_best_seller: bool,
+    pub is_quick_ship: bool,
+    pub images: Vec<ProductImageCommandItem>,
+    pub tag_slugs: Vec<String>,
+    pub skus: Vec<ProductSkuCommandItem>,
+    /// trueの場合は作成と同時に公開する
+    pub publish: bool,
+}
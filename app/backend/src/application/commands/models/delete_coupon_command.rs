@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// クーポン削除コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteCouponCommand {
+    pub coupon_id: String,
+}
+
+impl DeleteCouponCommand {
+    pub fn new(coupon_id: String) -> Self {
+        Self { coupon_id }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 商品削除コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteProductCommand {
+    pub product_id: String,
+}
+
+impl DeleteProductCommand {
+    pub fn new(product_id: String) -> Self {
+        Self { product_id }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 注文配達完了コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliverOrderCommand {
+    pub order_id: String,
+}
+
+impl DeliverOrderCommand {
+    pub fn new(order_id: String) -> Self {
+        Self { order_id }
+    }
+}
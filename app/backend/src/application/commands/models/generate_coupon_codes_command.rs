@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application::commands::models::CouponDiscountCommandItem;
+
+/// 使い切りクーポンの一括発行コマンド
+/// 同じ割引内容で、1回だけ使用できるクーポンコードを指定数だけ発行する
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateCouponCodesCommand {
+    /// コードの先頭に付ける文字列
+    pub prefix: String,
+    /// 発行数
+    pub count: u32,
+    pub name: String,
+    pub description: Option<String>,
+    pub discount: CouponDiscountCommandItem,
+    /// 有効期間の開始日（YYYY-MM-DD）
+    pub valid_from: String,
+    /// 有効期間の終了日（YYYY-MM-DD、終日有効）
+    pub valid_until: String,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// 請求書・領収書発行コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueOrderDocumentCommand {
+    pub order_id: String,
+    /// 帳票の種類（invoice, receipt）
+    pub kind: String,
+    /// 出力形式（html, pdf）
+    pub format: String,
+    /// 宛名（未指定の場合は注文者の氏名）
+    pub addressee: Option<String>,
+}
+
+impl IssueOrderDocumentCommand {
+    pub fn new(order_id: String, kind: String, format: String, addressee: Option<String>) -> Self {
+        Self {
+            order_id,
+            kind,
+            format,
+            addressee,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// ログインコマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginCustomerCommand {
+    pub email: String,
+    pub password: String,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// ログアウトコマンド（トークンのセッションを失効させる）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogoutCustomerCommand {
+    pub token: String,
+}
+
+impl LogoutCustomerCommand {
+    pub fn new(token: String) -> Self {
+        Self { token }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 注文支払い完了コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkOrderPaidCommand {
+    pub order_id: String,
+}
+
+impl MarkOrderPaidCommand {
+    pub fn new(order_id: String) -> Self {
+        Self { order_id }
+    }
+}
@@ -1,8 +1,98 @@
+mod add_cart_item_command;
+mod add_order_note_command;
+mod add_product_sku_command;
+mod adjust_stock_command;
+mod amend_order_command;
+mod approve_return_command;
+mod attach_shipment_command;
+mod authorize_payment_command;
 mod calculate_cart_command;
+mod cancel_order_command;
+mod capture_payment_command;
+mod create_coupon_command;
 mod create_order_command;
+mod create_product_command;
+mod delete_coupon_command;
+mod delete_product_command;
+mod deliver_order_command;
+mod generate_coupon_codes_command;
+mod issue_order_document_command;
+mod login_customer_command;
+mod logout_customer_command;
+mod mark_order_paid_command;
+mod process_order_command;
+mod publish_product_command;
+mod receive_return_command;
+mod record_shipment_event_command;
+mod refund_order_command;
+mod refund_return_command;
+mod register_customer_command;
+mod reject_return_command;
+mod remove_cart_item_command;
+mod remove_product_sku_command;
+mod request_return_command;
+mod schedule_sku_sale_command;
+mod set_cart_coupon_command;
+mod set_cart_payment_method_command;
+mod set_cart_shipping_method_command;
+mod ship_order_command;
+mod unpublish_product_command;
+mod update_cart_item_command;
+mod update_coupon_command;
+mod update_payment_fee_rule_command;
+mod update_product_command;
+mod update_product_sku_command;
+mod void_payment_command;
 
+pub use add_cart_item_command::AddCartItemCommand;
+pub use add_order_note_command::AddOrderNoteCommand;
+pub use add_product_sku_command::AddProductSkuCommand;
+pub use adjust_stock_command::AdjustStockCommand;
+pub use amend_order_command::AmendOrderCommand;
+pub use approve_return_command::ApproveReturnCommand;
+pub use attach_shipment_command::AttachShipmentCommand;
+pub use authorize_payment_command::AuthorizePaymentCommand;
 pub use calculate_cart_command::{CalculateCartCommand, CalculationCartCommandItem};
+pub use cancel_order_command::CancelOrderCommand;
+pub use capture_payment_command::CapturePaymentCommand;
+pub use create_coupon_command::{CouponDiscountCommandItem, CreateCouponCommand};
 pub use create_order_command::{
     CreateOrderCommand, CreateOrderCommandCustomerInfo, CreateOrderCommandItem,
     CreateOrderCommandShippingAddress,
 };
+pub use create_product_command::{
+    CreateProductCommand, ProductImageCommandItem, ProductSkuCommandItem,
+};
+pub use delete_coupon_command::DeleteCouponCommand;
+pub use delete_product_command::DeleteProductCommand;
+pub use deliver_order_command::DeliverOrderCommand;
+pub use generate_coupon_codes_command::GenerateCouponCodesCommand;
+pub use issue_order_document_command::IssueOrderDocumentCommand;
+pub use login_customer_command::LoginCustomerCommand;
+pub use logout_customer_command::LogoutCustomerCommand;
+pub use mark_order_paid_command::MarkOrderPaidCommand;
+pub use process_order_command::ProcessOrderCommand;
+pub use publish_product_command::PublishProductCommand;
+pub use receive_return_command::ReceiveReturnCommand;
+pub use record_shipment_event_command::RecordShipmentEventCommand;
+pub use refund_order_command::RefundOrderCommand;
+pub use refund_return_command::RefundReturnCommand;
+pub use register_customer_command::RegisterCustomerCommand;
+pub use reject_return_command::RejectReturnCommand;
+pub use remove_cart_item_command::RemoveCartItemCommand;
+pub use remove_product_sku_command::RemoveProductSkuCommand;
+pub use request_return_command::{RequestReturnCommand, RequestReturnCommandItem};
+pub use schedule_sku_sale_command::ScheduleSkuSaleCommand;
+pub use set_cart_coupon_command::SetCartCouponCommand;
+pub use set_cart_payment_method_command::SetCartPaymentMethodCommand;
+pub use set_cart_shipping_method_command::SetCartShippingMethodCommand;
+pub use ship_order_command::ShipOrderCommand;
+pub use unpublish_product_command::UnpublishProductCommand;
+pub use update_cart_item_command::UpdateCartItemCommand;
+pub use update_coupon_command::UpdateCouponCommand;
+pub use update_payment_fee_rule_command::{
+    PaymentFeeBracketCommandItem, UpdatePaymentFeeRuleCommand,
+};
+pub use update_product_command::UpdateProductCommand;
+pub use update_product_sku_command::UpdateProductSkuCommand;
+pub use void_payment_command::VoidPaymentCommand;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 注文処理開始コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessOrderCommand {
+    pub order_id: String,
+}
+
+impl ProcessOrderCommand {
+    pub fn new(order_id: String) -> Self {
+        Self { order_id }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 商品公開コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishProductCommand {
+    pub product_id: String,
+}
+
+impl PublishProductCommand {
+    pub fn new(product_id: String) -> Self {
+        Self { product_id }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// 返品受領コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiveReturnCommand {
+    pub return_id: String,
+    /// 検品した商品の状態（未指定の場合は申請時の状態）
+    pub condition: Option<String>,
+}
+
+impl ReceiveReturnCommand {
+    pub fn new(return_id: String, condition: Option<String>) -> Self {
+        Self {
+            return_id,
+            condition,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// 配送状況通知コマンド（配送業者のWebhookを想定）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordShipmentEventCommand {
+    pub shipment_id: String,
+    /// "shipped"（発送） / "in_transit"（配送中） / "delivered"（配達完了） / "failed"（配送失敗）
+    pub status: String,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 注文返金コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundOrderCommand {
+    pub order_id: String,
+}
+
+impl RefundOrderCommand {
+    pub fn new(order_id: String) -> Self {
+        Self { order_id }
+    }
+}
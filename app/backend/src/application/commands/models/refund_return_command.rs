@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 返品返金コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundReturnCommand {
+    pub return_id: String,
+}
+
+impl RefundReturnCommand {
+    pub fn new(return_id: String) -> Self {
+        Self { return_id }
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// 会員登録コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterCustomerCommand {
+    pub email: String,
+    pub password: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub phone: String,
+}
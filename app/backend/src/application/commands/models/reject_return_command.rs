@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// 返品却下コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RejectReturnCommand {
+    pub return_id: String,
+    pub reason: String,
+}
+
+impl RejectReturnCommand {
+    pub fn new(return_id: String, reason: String) -> Self {
+        Self { return_id, reason }
+    }
+}
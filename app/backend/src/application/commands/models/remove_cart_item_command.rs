@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// カートアイテム削除コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveCartItemCommand {
+    pub cart_id: String,
+    pub sku_id: String,
+}
+
+impl RemoveCartItemCommand {
+    pub fn new(cart_id: String, sku_id: String) -> Self {
+        Self { cart_id, sku_id }
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// SKU削除コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveProductSkuCommand {
+    pub product_id: String,
+    pub sku_id: String,
+}
+
+impl RemoveProductSkuCommand {
+    pub fn new(product_id: String, sku_id: String) -> Self {
+        Self { product_id, sku_id }
+    }
+}
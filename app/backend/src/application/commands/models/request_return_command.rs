@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// 返品申請の明細
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestReturnCommandItem {
+    pub sku_id: String,
+    pub quantity: u32,
+}
+
+/// 返品申請コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestReturnCommand {
+    pub order_id: String,
+    pub items: Vec<RequestReturnCommandItem>,
+    /// 返品理由（defective, wrong_item, not_as_described, changed_mind, other）
+    pub reason: String,
+    /// 商品の状態（unopened, opened, damaged）
+    pub condition: String,
+    pub note: Option<String>,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// SKUの期間指定セール予約コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleSkuSaleCommand {
+    pub product_id: String,
+    pub sku_id: String,
+    /// セール価格（円、基本価格未満）
+    pub sale_price: u32,
+    /// セール開始日時（RFC3339）
+    pub starts_at: String,
+    /// セール終了日時（RFC3339、この日時を含まない）
+    pub ends_at: String,
+}
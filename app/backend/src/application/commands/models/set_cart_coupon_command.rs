@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// カートクーポン設定コマンド
+/// coupon_code が None の場合はクーポンを取り外す
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetCartCouponCommand {
+    pub cart_id: String,
+    pub coupon_code: Option<String>,
+}
+
+impl SetCartCouponCommand {
+    pub fn new(cart_id: String, coupon_code: Option<String>) -> Self {
+        Self {
+            cart_id,
+            coupon_code,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// カート支払い方法設定コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetCartPaymentMethodCommand {
+    pub cart_id: String,
+    pub payment_method_id: String,
+}
+
+impl SetCartPaymentMethodCommand {
+    pub fn new(cart_id: String, payment_method_id: String) -> Self {
+        Self {
+            cart_id,
+            payment_method_id,
+        }
+    }
+}
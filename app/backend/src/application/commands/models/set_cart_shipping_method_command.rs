@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// カート配送方法設定コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetCartShippingMethodCommand {
+    pub cart_id: String,
+    pub shipping_method_id: String,
+}
+
+impl SetCartShippingMethodCommand {
+    pub fn new(cart_id: String, shipping_method_id: String) -> Self {
+        Self {
+            cart_id,
+            shipping_method_id,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 注文発送コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipOrderCommand {
+    pub order_id: String,
+}
+
+impl ShipOrderCommand {
+    pub fn new(order_id: String) -> Self {
+        Self { order_id }
+    }
+}
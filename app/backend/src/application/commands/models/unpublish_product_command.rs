@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// 商品非公開コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnpublishProductCommand {
+    pub product_id: String,
+}
+
+impl UnpublishProductCommand {
+    pub fn new(product_id: String) -> Self {
+        Self { product_id }
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application::commands::models::CouponDiscountCommandItem;
+
+/// クーポン更新コマンド（使用回数以外の項目を置き換える）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCouponCommand {
+    pub coupon_id: String,
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub discount: CouponDiscountCommandItem,
+    pub usage_limit: Option<u32>,
+    pub per_customer_limit: Option<u32>,
+    /// 有効期間の開始日（YYYY-MM-DD）
+    pub valid_from: String,
+    /// 有効期間の終了日（YYYY-MM-DD、終日有効）
+    pub valid_until: String,
+}
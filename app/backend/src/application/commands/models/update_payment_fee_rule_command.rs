@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// 支払い手数料ルール更新コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdatePaymentFeeRuleCommand {
+    pub payment_method_id: String,
+    /// 計算方式（flat, percentage, tiered）
+    pub fee_type: String,
+    /// 定額の手数料（flat）
+    pub amount: Option<u32>,
+    /// 割合（%、percentage）
+    pub rate: Option<f64>,
+    pub min_fee: Option<u32>,
+    pub max_fee: Option<u32>,
+    /// 金額帯（tiered）
+    pub brackets: Vec<PaymentFeeBracketCommandItem>,
+}
+
+/// 手数料の金額帯
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentFeeBracketCommandItem {
+    /// カート合計がこの金額未満のときに適用（省略時は上限なし）
+    pub up_to: Option<u32>,
+    pub fee: u32,
+}
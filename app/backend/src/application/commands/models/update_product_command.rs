@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use super::create_product_command::ProductImageCommandItem;
+
+/// 商品更新コマンド
+/// Noneの項目は変更しない。画像・タグは指定された場合に全体を置き換える
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateProductCommand {
+    pub product_id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub category_id: Option<String>,
+    pub is_best_seller: Option<bool>,
+    pub is_quick_ship: Option<bool>,
+    pub images: Option<Vec<ProductImageCommandItem>>,
+    pub tag_slugs: Option<Vec<String>>,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// SKU更新コマンド
+/// Noneの項目は変更しない。在庫数は在庫調整で扱うためここでは変更できない
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateProductSkuCommand {
+    pub product_id: String,
+    pub sku_id: String,
+    pub name: Option<String>,
+    pub color_id: Option<u32>,
+    pub dimensions: Option<String>,
+    pub material: Option<String>,
+    pub base_price: Option<u32>,
+    pub sale_price: Option<u32>,
+    /// trueの場合はセール価格を解除する
+    pub clear_sale_price: bool,
+    /// "active" / "inactive" / "discontinued"
+    pub status: Option<String>,
+    pub display_order: Option<u32>,
+    /// "standard" / "reduced"
+    pub tax_category: Option<String>,
+}
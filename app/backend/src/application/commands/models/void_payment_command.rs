@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// オーソリ取消コマンド
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoidPaymentCommand {
+    pub order_id: String,
+}
+
+impl VoidPaymentCommand {
+    pub fn new(order_id: String) -> Self {
+        Self { order_id }
+    }
+}
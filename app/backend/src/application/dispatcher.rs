@@ -1,60 +1,184 @@
 use std::sync::Arc;
 
-use crate::application::commands::handlers::CalculateCartHandler;
-use crate::application::commands::handlers::CreateOrderHandler;
-use crate::application::commands::models::{CalculateCartCommand, CreateOrderCommand};
+use crate::application::commands::handlers::{
+    AddCartItemHandler, AddOrderNoteHandler, AddProductSkuHandler, AdjustStockHandler,
+    AmendOrderHandler, ApproveReturnHandler, AttachShipmentHandler, AuthorizePaymentHandler,
+    CalculateCartHandler, CancelOrderHandler, CapturePaymentHandler, CreateCartHandler,
+    CreateCouponHandler, CreateOrderHandler, CreateProductHandler, DeleteCouponHandler,
+    DeleteProductHandler, DeliverOrderHandler, GenerateCouponCodesHandler,
+    IssueOrderDocumentHandler, LoginCustomerHandler, LogoutCustomerHandler, MarkOrderPaidHandler,
+    ProcessOrderHandler, PublishProductHandler, ReceiveReturnHandler, RecordShipmentEventHandler,
+    RefundOrderHandler, RefundReturnHandler, RegisterCustomerHandler, RejectReturnHandler,
+    RemoveCartItemHandler, RemoveProductSkuHandler, RequestReturnHandler, ScheduleSkuSaleHandler,
+    SetCartCouponHandler, SetCartPaymentMethodHandler, SetCartShippingMethodHandler,
+    ShipOrderHandler, UnpublishProductHandler, UpdateCartItemHandler, UpdateCouponHandler,
+    UpdatePaymentFeeRuleHandler, UpdateProductHandler, UpdateProductSkuHandler, VoidPaymentHandler,
+};
+use crate::application::commands::models::{
+    AddCartItemCommand, AddOrderNoteCommand, AddProductSkuCommand, AdjustStockCommand,
+    AmendOrderCommand, ApproveReturnCommand, AttachShipmentCommand, AuthorizePaymentCommand,
+    CalculateCartCommand, CancelOrderCommand, CapturePaymentCommand, CreateCouponCommand,
+    CreateOrderCommand, CreateProductCommand, DeleteCouponCommand, DeleteProductCommand,
+    DeliverOrderCommand, GenerateCouponCodesCommand, IssueOrderDocumentCommand,
+    LoginCustomerCommand, LogoutCustomerCommand, MarkOrderPaidCommand, ProcessOrderCommand,
+    PublishProductCommand, ReceiveReturnCommand, RecordShipmentEventCommand, RefundOrderCommand,
+    RefundReturnCommand, RegisterCustomerCommand, RejectReturnCommand, RemoveCartItemCommand,
+    RemoveProductSkuCommand, RequestReturnCommand, ScheduleSkuSaleCommand, SetCartCouponCommand,
+    SetCartPaymentMethodCommand, SetCartShippingMethodCommand, ShipOrderCommand,
+    UnpublishProductCommand, UpdateCartItemCommand, UpdateCouponCommand,
+    UpdatePaymentFeeRuleCommand, UpdateProductCommand, UpdateProductSkuCommand, VoidPaymentCommand,
+};
 use crate::application::dto::{
-    CalculateCartResultDto, CategoryListDTO, ColorListDTO, CreateOrderResultDTO,
-    PaymentMethodListDTO, ProductDTO, ProductListDTO, ShippingMethodListDTO, VariantSummaryDTO,
+    AdminProductDTO, CalculateCartResultDto, CartDTO, CategoryListDTO, ColorListDTO, CouponDTO,
+    CouponListDTO, CreateOrderResultDTO, CustomerDTO, CustomerSessionDTO, OrderDTO, OrderEventDTO,
+    OrderListDTO, PaymentHistoryDTO, PaymentMethodDTO, PaymentMethodListDTO, PaymentResultDTO,
+    ProductDTO, ProductListDTO, ProductSearchResultDTO, RenderedDocumentDTO, ReturnDTO,
+    ShipmentDTO, ShippingMethodListDTO, StockHistoryDTO, StockMovementDTO, VariantSummaryDTO,
 };
 use crate::application::error::ApplicationError;
 use crate::application::queries::handlers::{
-    FindVariantsHandler, GetCategoryListHandler, GetColorListHandler, GetPaymentMethodListHandler,
-    GetProductHandler, GetProductListHandler, GetShippingMethodListHandler,
+    AuthenticateCustomerHandler, FindVariantsHandler, GetAdminProductHandler, GetCartHandler,
+    GetCategoryListHandler, GetColorListHandler, GetCouponHandler, GetCouponListHandler,
+    GetOrderEventsHandler, GetOrderHandler, GetOrderReturnsHandler, GetPaymentMethodListHandler,
+    GetPaymentsHandler, GetProductHandler, GetProductListHandler, GetReturnHandler,
+    GetShipmentHandler, GetShippingMethodListHandler, GetStockHistoryHandler, ListOrdersHandler,
+    SearchProductsHandler,
+};
+use crate::application::queries::models::{
+    AuthenticateCustomerQuery, FindVariantsQuery, GetAdminProductQuery, GetCartQuery,
+    GetCouponQuery, GetOrderEventsQuery, GetOrderQuery, GetOrderReturnsQuery, GetPaymentsQuery,
+    GetProductListQuery, GetProductQuery, GetReturnQuery, GetShipmentQuery, GetStockHistoryQuery,
+    ListOrdersQuery, SearchProductsQuery,
 };
-use crate::application::queries::models::{FindVariantsQuery, GetProductQuery};
+
+/// 商品・カテゴリ・色・バリアントの参照、商品検索、配送・支払い方法一覧のハンドラ
+pub struct CatalogHandlers {
+    pub get_product_handler: Arc<GetProductHandler>,
+    pub get_product_list_handler: Arc<GetProductListHandler>,
+    pub get_category_list_handler: Arc<GetCategoryListHandler>,
+    pub get_color_list_handler: Arc<GetColorListHandler>,
+    pub find_variants_handler: Arc<FindVariantsHandler>,
+    pub search_products_handler: Arc<SearchProductsHandler>,
+    pub get_shipping_method_list_handler: Arc<GetShippingMethodListHandler>,
+    pub get_payment_method_list_handler: Arc<GetPaymentMethodListHandler>,
+}
+
+/// カート（計算・作成・明細・クーポン・配送/支払い方法）のハンドラ
+pub struct CartHandlers {
+    pub calculate_cart_handler: Arc<CalculateCartHandler>,
+    pub create_cart_handler: Arc<CreateCartHandler>,
+    pub add_cart_item_handler: Arc<AddCartItemHandler>,
+    pub update_cart_item_handler: Arc<UpdateCartItemHandler>,
+    pub remove_cart_item_handler: Arc<RemoveCartItemHandler>,
+    pub set_cart_coupon_handler: Arc<SetCartCouponHandler>,
+    pub set_cart_shipping_method_handler: Arc<SetCartShippingMethodHandler>,
+    pub set_cart_payment_method_handler: Arc<SetCartPaymentMethodHandler>,
+    pub get_cart_handler: Arc<GetCartHandler>,
+}
+
+/// 注文（作成・変更・参照・ステータス遷移・帳票）のハンドラ
+pub struct OrderHandlers {
+    pub create_order_handler: Arc<CreateOrderHandler>,
+    pub amend_order_handler: Arc<AmendOrderHandler>,
+    pub get_order_handler: Arc<GetOrderHandler>,
+    pub list_orders_handler: Arc<ListOrdersHandler>,
+    pub get_order_events_handler: Arc<GetOrderEventsHandler>,
+    pub mark_order_paid_handler: Arc<MarkOrderPaidHandler>,
+    pub process_order_handler: Arc<ProcessOrderHandler>,
+    pub ship_order_handler: Arc<ShipOrderHandler>,
+    pub deliver_order_handler: Arc<DeliverOrderHandler>,
+    pub cancel_order_handler: Arc<CancelOrderHandler>,
+    pub add_order_note_handler: Arc<AddOrderNoteHandler>,
+    pub refund_order_handler: Arc<RefundOrderHandler>,
+    pub issue_order_document_handler: Arc<IssueOrderDocumentHandler>,
+}
+
+/// 決済（オーソリ・売上確定・取消・履歴）のハンドラ
+pub struct PaymentHandlers {
+    pub authorize_payment_handler: Arc<AuthorizePaymentHandler>,
+    pub capture_payment_handler: Arc<CapturePaymentHandler>,
+    pub void_payment_handler: Arc<VoidPaymentHandler>,
+    pub get_payments_handler: Arc<GetPaymentsHandler>,
+}
+
+/// 出荷・配送追跡と返品（RMA）のハンドラ
+pub struct FulfillmentHandlers {
+    pub attach_shipment_handler: Arc<AttachShipmentHandler>,
+    pub record_shipment_event_handler: Arc<RecordShipmentEventHandler>,
+    pub get_shipment_handler: Arc<GetShipmentHandler>,
+    pub request_return_handler: Arc<RequestReturnHandler>,
+    pub approve_return_handler: Arc<ApproveReturnHandler>,
+    pub reject_return_handler: Arc<RejectReturnHandler>,
+    pub receive_return_handler: Arc<ReceiveReturnHandler>,
+    pub refund_return_handler: Arc<RefundReturnHandler>,
+    pub get_return_handler: Arc<GetReturnHandler>,
+    pub get_order_returns_handler: Arc<GetOrderReturnsHandler>,
+}
+
+/// 管理用（商品・在庫・支払い手数料・クーポン）のハンドラ
+pub struct AdminHandlers {
+    pub create_product_handler: Arc<CreateProductHandler>,
+    pub update_product_handler: Arc<UpdateProductHandler>,
+    pub delete_product_handler: Arc<DeleteProductHandler>,
+    pub publish_product_handler: Arc<PublishProductHandler>,
+    pub unpublish_product_handler: Arc<UnpublishProductHandler>,
+    pub add_product_sku_handler: Arc<AddProductSkuHandler>,
+    pub update_product_sku_handler: Arc<UpdateProductSkuHandler>,
+    pub remove_product_sku_handler: Arc<RemoveProductSkuHandler>,
+    pub schedule_sku_sale_handler: Arc<ScheduleSkuSaleHandler>,
+    pub get_admin_product_handler: Arc<GetAdminProductHandler>,
+    pub adjust_stock_handler: Arc<AdjustStockHandler>,
+    pub get_stock_history_handler: Arc<GetStockHistoryHandler>,
+    pub update_payment_fee_rule_handler: Arc<UpdatePaymentFeeRuleHandler>,
+    pub create_coupon_handler: Arc<CreateCouponHandler>,
+    pub update_coupon_handler: Arc<UpdateCouponHandler>,
+    pub delete_coupon_handler: Arc<DeleteCouponHandler>,
+    pub generate_coupon_codes_handler: Arc<GenerateCouponCodesHandler>,
+    pub get_coupon_list_handler: Arc<GetCouponListHandler>,
+    pub get_coupon_handler: Arc<GetCouponHandler>,
+}
+
+/// 会員（登録・ログイン・ログアウト・セッション認証）のハンドラ
+pub struct CustomerHandlers {
+    pub register_customer_handler: Arc<RegisterCustomerHandler>,
+    pub login_customer_handler: Arc<LoginCustomerHandler>,
+    pub logout_customer_handler: Arc<LogoutCustomerHandler>,
+    pub authenticate_customer_handler: Arc<AuthenticateCustomerHandler>,
+}
 
 /// CQRS パターンのコマンド・クエリディスパッチャ
 ///
 /// コマンドとクエリの実行を一元的に管理し、適切なハンドラに処理を委譲します。
 /// これにより、プレゼンテーション層はビジネスロジックの詳細を知る必要がなくなります。
+/// ハンドラは機能ごとの構造体（`CatalogHandlers` など）にまとめて受け取ります。
 pub struct Dispatcher {
-    // コマンドハンドラ
-    calculate_cart_handler: Arc<CalculateCartHandler>,
-    create_order_handler: Arc<CreateOrderHandler>,
-
-    // クエリハンドラ
-    get_product_handler: Arc<GetProductHandler>,
-    get_product_list_handler: Arc<GetProductListHandler>,
-    get_category_list_handler: Arc<GetCategoryListHandler>,
-    get_color_list_handler: Arc<GetColorListHandler>,
-    find_variants_handler: Arc<FindVariantsHandler>,
-    get_shipping_method_list_handler: Arc<GetShippingMethodListHandler>,
-    get_payment_method_list_handler: Arc<GetPaymentMethodListHandler>,
+    catalog: CatalogHandlers,
+    cart: CartHandlers,
+    orders: OrderHandlers,
+    payments: PaymentHandlers,
+    fulfillment: FulfillmentHandlers,
+    admin: AdminHandlers,
+    customers: CustomerHandlers,
 }
 
 impl Dispatcher {
     pub fn new(
-        calculate_cart_handler: Arc<CalculateCartHandler>,
-        create_order_handler: Arc<CreateOrderHandler>,
-        get_product_handler: Arc<GetProductHandler>,
-        get_product_list_handler: Arc<GetProductListHandler>,
-        get_category_list_handler: Arc<GetCategoryListHandler>,
-        get_color_list_handler: Arc<GetColorListHandler>,
-        find_variants_handler: Arc<FindVariantsHandler>,
-        get_shipping_method_list_handler: Arc<GetShippingMethodListHandler>,
-        get_payment_method_list_handler: Arc<GetPaymentMethodListHandler>,
+        catalog: CatalogHandlers,
+        cart: CartHandlers,
+        orders: OrderHandlers,
+        payments: PaymentHandlers,
+        fulfillment: FulfillmentHandlers,
+        admin: AdminHandlers,
+        customers: CustomerHandlers,
     ) -> Self {
         Self {
-            calculate_cart_handler,
-            create_order_handler,
-            get_product_handler,
-            get_product_list_handler,
-            get_category_list_handler,
-            get_color_list_handler,
-            find_variants_handler,
-            get_shipping_method_list_handler,
-            get_payment_method_list_handler,
+            catalog,
+            cart,
+            orders,
+            payments,
+            fulfillment,
+            admin,
+            customers,
         }
     }
 
@@ -63,7 +187,66 @@ impl Dispatcher {
         &self,
         command: CalculateCartCommand,
     ) -> Result<CalculateCartResultDto, ApplicationError> {
-        self.calculate_cart_handler.handle(command).await
+        self.cart.calculate_cart_handler.handle(command).await
+    }
+
+    /// カート作成コマンドを実行
+    pub async fn execute_create_cart_command(&self) -> Result<CartDTO, ApplicationError> {
+        self.cart.create_cart_handler.handle().await
+    }
+
+    /// カートアイテム追加コマンドを実行
+    pub async fn execute_add_cart_item_command(
+        &self,
+        command: AddCartItemCommand,
+    ) -> Result<CartDTO, ApplicationError> {
+        self.cart.add_cart_item_handler.handle(command).await
+    }
+
+    /// カートアイテム数量変更コマンドを実行
+    pub async fn execute_update_cart_item_command(
+        &self,
+        command: UpdateCartItemCommand,
+    ) -> Result<CartDTO, ApplicationError> {
+        self.cart.update_cart_item_handler.handle(command).await
+    }
+
+    /// カートアイテム削除コマンドを実行
+    pub async fn execute_remove_cart_item_command(
+        &self,
+        command: RemoveCartItemCommand,
+    ) -> Result<CartDTO, ApplicationError> {
+        self.cart.remove_cart_item_handler.handle(command).await
+    }
+
+    /// カートクーポン設定コマンドを実行
+    pub async fn execute_set_cart_coupon_command(
+        &self,
+        command: SetCartCouponCommand,
+    ) -> Result<CartDTO, ApplicationError> {
+        self.cart.set_cart_coupon_handler.handle(command).await
+    }
+
+    /// カート配送方法設定コマンドを実行
+    pub async fn execute_set_cart_shipping_method_command(
+        &self,
+        command: SetCartShippingMethodCommand,
+    ) -> Result<CartDTO, ApplicationError> {
+        self.cart
+            .set_cart_shipping_method_handler
+            .handle(command)
+            .await
+    }
+
+    /// カート支払い方法設定コマンドを実行
+    pub async fn execute_set_cart_payment_method_command(
+        &self,
+        command: SetCartPaymentMethodCommand,
+    ) -> Result<CartDTO, ApplicationError> {
+        self.cart
+            .set_cart_payment_method_handler
+            .handle(command)
+            .await
     }
 
     /// 注文作成コマンドを実行
@@ -71,7 +254,71 @@ impl Dispatcher {
         &self,
         command: CreateOrderCommand,
     ) -> Result<CreateOrderResultDTO, ApplicationError> {
-        self.create_order_handler.handle(command).await
+        self.orders.create_order_handler.handle(command).await
+    }
+
+    /// 注文支払い完了コマンドを実行
+    pub async fn execute_mark_order_paid_command(
+        &self,
+        command: MarkOrderPaidCommand,
+    ) -> Result<OrderDTO, ApplicationError> {
+        self.orders.mark_order_paid_handler.handle(command).await
+    }
+
+    /// 注文処理開始コマンドを実行
+    pub async fn execute_process_order_command(
+        &self,
+        command: ProcessOrderCommand,
+    ) -> Result<OrderDTO, ApplicationError> {
+        self.orders.process_order_handler.handle(command).await
+    }
+
+    /// 注文発送コマンドを実行
+    pub async fn execute_ship_order_command(
+        &self,
+        command: ShipOrderCommand,
+    ) -> Result<OrderDTO, ApplicationError> {
+        self.orders.ship_order_handler.handle(command).await
+    }
+
+    /// 注文配達完了コマンドを実行
+    pub async fn execute_deliver_order_command(
+        &self,
+        command: DeliverOrderCommand,
+    ) -> Result<OrderDTO, ApplicationError> {
+        self.orders.deliver_order_handler.handle(command).await
+    }
+
+    /// 注文変更コマンドを実行
+    pub async fn execute_amend_order_command(
+        &self,
+        command: AmendOrderCommand,
+    ) -> Result<OrderDTO, ApplicationError> {
+        self.orders.amend_order_handler.handle(command).await
+    }
+
+    /// 注文キャンセルコマンドを実行
+    pub async fn execute_cancel_order_command(
+        &self,
+        command: CancelOrderCommand,
+    ) -> Result<OrderDTO, ApplicationError> {
+        self.orders.cancel_order_handler.handle(command).await
+    }
+
+    /// 注文備考追加コマンドを実行
+    pub async fn execute_add_order_note_command(
+        &self,
+        command: AddOrderNoteCommand,
+    ) -> Result<OrderDTO, ApplicationError> {
+        self.orders.add_order_note_handler.handle(command).await
+    }
+
+    /// 注文返金コマンドを実行
+    pub async fn execute_refund_order_command(
+        &self,
+        command: RefundOrderCommand,
+    ) -> Result<OrderDTO, ApplicationError> {
+        self.orders.refund_order_handler.handle(command).await
     }
 
     /// 商品取得クエリを実行
@@ -79,24 +326,27 @@ impl Dispatcher {
         &self,
         query: GetProductQuery,
     ) -> Result<ProductDTO, ApplicationError> {
-        self.get_product_handler.handle(query).await
+        self.catalog.get_product_handler.handle(query).await
     }
 
     /// 商品リスト取得クエリを実行
-    pub async fn execute_get_product_list_query(&self) -> Result<ProductListDTO, ApplicationError> {
-        self.get_product_list_handler.handle().await
+    pub async fn execute_get_product_list_query(
+        &self,
+        query: GetProductListQuery,
+    ) -> Result<ProductListDTO, ApplicationError> {
+        self.catalog.get_product_list_handler.handle(query).await
     }
 
     /// カテゴリリスト取得クエリを実行
     pub async fn execute_get_category_list_query(
         &self,
     ) -> Result<CategoryListDTO, ApplicationError> {
-        self.get_category_list_handler.handle().await
+        self.catalog.get_category_list_handler.handle().await
     }
 
     /// 色リスト取得クエリを実行
     pub async fn execute_get_color_list_query(&self) -> Result<ColorListDTO, ApplicationError> {
-        self.get_color_list_handler.handle().await
+        self.catalog.get_color_list_handler.handle().await
     }
 
     /// バリアントリスト取得クエリを実行
@@ -104,20 +354,397 @@ impl Dispatcher {
         &self,
         query: FindVariantsQuery,
     ) -> Result<Vec<VariantSummaryDTO>, ApplicationError> {
-        self.find_variants_handler.handle(query).await
+        self.catalog.find_variants_handler.handle(query).await
     }
 
     /// 配送方法リスト取得クエリを実行
     pub async fn execute_get_shipping_method_list_query(
         &self,
     ) -> Result<ShippingMethodListDTO, ApplicationError> {
-        self.get_shipping_method_list_handler.handle().await
+        self.catalog.get_shipping_method_list_handler.handle().await
     }
 
     /// 支払い方法リスト取得クエリを実行
     pub async fn execute_get_payment_method_list_query(
         &self,
     ) -> Result<PaymentMethodListDTO, ApplicationError> {
-        self.get_payment_method_list_handler.handle().await
+        self.catalog.get_payment_method_list_handler.handle().await
+    }
+
+    /// 注文取得クエリを実行
+    pub async fn execute_get_order_query(
+        &self,
+        query: GetOrderQuery,
+    ) -> Result<OrderDTO, ApplicationError> {
+        self.orders.get_order_handler.handle(query).await
+    }
+
+    /// 注文一覧取得クエリを実行
+    pub async fn execute_list_orders_query(
+        &self,
+        query: ListOrdersQuery,
+    ) -> Result<OrderListDTO, ApplicationError> {
+        self.orders.list_orders_handler.handle(query).await
+    }
+
+    /// 注文イベント履歴取得クエリを実行
+    pub async fn execute_get_order_events_query(
+        &self,
+        query: GetOrderEventsQuery,
+    ) -> Result<Vec<OrderEventDTO>, ApplicationError> {
+        self.orders.get_order_events_handler.handle(query).await
+    }
+
+    /// カート取得クエリを実行
+    pub async fn execute_get_cart_query(
+        &self,
+        query: GetCartQuery,
+    ) -> Result<CartDTO, ApplicationError> {
+        self.cart.get_cart_handler.handle(query).await
+    }
+
+    /// 商品キーワード検索クエリを実行
+    pub async fn execute_search_products_query(
+        &self,
+        query: SearchProductsQuery,
+    ) -> Result<ProductSearchResultDTO, ApplicationError> {
+        self.catalog.search_products_handler.handle(query).await
+    }
+
+    /// 商品作成コマンドを実行
+    pub async fn execute_create_product_command(
+        &self,
+        command: CreateProductCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        self.admin.create_product_handler.handle(command).await
+    }
+
+    /// 商品更新コマンドを実行
+    pub async fn execute_update_product_command(
+        &self,
+        command: UpdateProductCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        self.admin.update_product_handler.handle(command).await
+    }
+
+    /// 商品削除コマンドを実行
+    pub async fn execute_delete_product_command(
+        &self,
+        command: DeleteProductCommand,
+    ) -> Result<(), ApplicationError> {
+        self.admin.delete_product_handler.handle(command).await
+    }
+
+    /// 商品公開コマンドを実行
+    pub async fn execute_publish_product_command(
+        &self,
+        command: PublishProductCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        self.admin.publish_product_handler.handle(command).await
+    }
+
+    /// 商品非公開コマンドを実行
+    pub async fn execute_unpublish_product_command(
+        &self,
+        command: UnpublishProductCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        self.admin.unpublish_product_handler.handle(command).await
+    }
+
+    /// SKU追加コマンドを実行
+    pub async fn execute_add_product_sku_command(
+        &self,
+        command: AddProductSkuCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        self.admin.add_product_sku_handler.handle(command).await
+    }
+
+    /// SKU更新コマンドを実行
+    pub async fn execute_update_product_sku_command(
+        &self,
+        command: UpdateProductSkuCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        self.admin.update_product_sku_handler.handle(command).await
+    }
+
+    /// SKU削除コマンドを実行
+    pub async fn execute_remove_product_sku_command(
+        &self,
+        command: RemoveProductSkuCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        self.admin.remove_product_sku_handler.handle(command).await
+    }
+
+    /// SKUの期間指定セール予約コマンドを実行
+    pub async fn execute_schedule_sku_sale_command(
+        &self,
+        command: ScheduleSkuSaleCommand,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        self.admin.schedule_sku_sale_handler.handle(command).await
+    }
+
+    /// 管理用商品取得クエリを実行
+    pub async fn execute_get_admin_product_query(
+        &self,
+        query: GetAdminProductQuery,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        self.admin.get_admin_product_handler.handle(query).await
+    }
+
+    /// 在庫調整コマンドを実行
+    pub async fn execute_adjust_stock_command(
+        &self,
+        command: AdjustStockCommand,
+    ) -> Result<StockMovementDTO, ApplicationError> {
+        self.admin.adjust_stock_handler.handle(command).await
+    }
+
+    /// SKUの在庫履歴取得クエリを実行
+    pub async fn execute_get_stock_history_query(
+        &self,
+        query: GetStockHistoryQuery,
+    ) -> Result<StockHistoryDTO, ApplicationError> {
+        self.admin.get_stock_history_handler.handle(query).await
+    }
+
+    /// 出荷登録コマンドを実行
+    pub async fn execute_attach_shipment_command(
+        &self,
+        command: AttachShipmentCommand,
+    ) -> Result<ShipmentDTO, ApplicationError> {
+        self.fulfillment
+            .attach_shipment_handler
+            .handle(command)
+            .await
+    }
+
+    /// 配送状況通知コマンドを実行
+    pub async fn execute_record_shipment_event_command(
+        &self,
+        command: RecordShipmentEventCommand,
+    ) -> Result<ShipmentDTO, ApplicationError> {
+        self.fulfillment
+            .record_shipment_event_handler
+            .handle(command)
+            .await
+    }
+
+    /// 出荷取得クエリを実行
+    pub async fn execute_get_shipment_query(
+        &self,
+        query: GetShipmentQuery,
+    ) -> Result<ShipmentDTO, ApplicationError> {
+        self.fulfillment.get_shipment_handler.handle(query).await
+    }
+
+    /// オーソリコマンドを実行
+    pub async fn execute_authorize_payment_command(
+        &self,
+        command: AuthorizePaymentCommand,
+    ) -> Result<PaymentResultDTO, ApplicationError> {
+        self.payments
+            .authorize_payment_handler
+            .handle(command)
+            .await
+    }
+
+    /// 売上確定コマンドを実行
+    pub async fn execute_capture_payment_command(
+        &self,
+        command: CapturePaymentCommand,
+    ) -> Result<PaymentResultDTO, ApplicationError> {
+        self.payments.capture_payment_handler.handle(command).await
+    }
+
+    /// オーソリ取消コマンドを実行
+    pub async fn execute_void_payment_command(
+        &self,
+        command: VoidPaymentCommand,
+    ) -> Result<PaymentResultDTO, ApplicationError> {
+        self.payments.void_payment_handler.handle(command).await
+    }
+
+    /// 注文の決済履歴取得クエリを実行
+    pub async fn execute_get_payments_query(
+        &self,
+        query: GetPaymentsQuery,
+    ) -> Result<PaymentHistoryDTO, ApplicationError> {
+        self.payments.get_payments_handler.handle(query).await
+    }
+
+    /// 支払い手数料ルール更新コマンドを実行
+    pub async fn execute_update_payment_fee_rule_command(
+        &self,
+        command: UpdatePaymentFeeRuleCommand,
+    ) -> Result<PaymentMethodDTO, ApplicationError> {
+        self.admin
+            .update_payment_fee_rule_handler
+            .handle(command)
+            .await
+    }
+
+    /// クーポン作成コマンドを実行
+    pub async fn execute_create_coupon_command(
+        &self,
+        command: CreateCouponCommand,
+    ) -> Result<CouponDTO, ApplicationError> {
+        self.admin.create_coupon_handler.handle(command).await
+    }
+
+    /// クーポン更新コマンドを実行
+    pub async fn execute_update_coupon_command(
+        &self,
+        command: UpdateCouponCommand,
+    ) -> Result<CouponDTO, ApplicationError> {
+        self.admin.update_coupon_handler.handle(command).await
+    }
+
+    /// クーポン削除コマンドを実行
+    pub async fn execute_delete_coupon_command(
+        &self,
+        command: DeleteCouponCommand,
+    ) -> Result<(), ApplicationError> {
+        self.admin.delete_coupon_handler.handle(command).await
+    }
+
+    /// 使い切りクーポン一括発行コマンドを実行
+    pub async fn execute_generate_coupon_codes_command(
+        &self,
+        command: GenerateCouponCodesCommand,
+    ) -> Result<CouponListDTO, ApplicationError> {
+        self.admin
+            .generate_coupon_codes_handler
+            .handle(command)
+            .await
+    }
+
+    /// 管理用クーポン一覧取得クエリを実行
+    pub async fn execute_get_coupon_list_query(&self) -> Result<CouponListDTO, ApplicationError> {
+        self.admin.get_coupon_list_handler.handle().await
+    }
+
+    /// 管理用クーポン取得クエリを実行
+    pub async fn execute_get_coupon_query(
+        &self,
+        query: GetCouponQuery,
+    ) -> Result<CouponDTO, ApplicationError> {
+        self.admin.get_coupon_handler.handle(query).await
+    }
+
+    /// 会員登録コマンドを実行
+    pub async fn execute_register_customer_command(
+        &self,
+        command: RegisterCustomerCommand,
+    ) -> Result<CustomerSessionDTO, ApplicationError> {
+        self.customers
+            .register_customer_handler
+            .handle(command)
+            .await
+    }
+
+    /// ログインコマンドを実行
+    pub async fn execute_login_customer_command(
+        &self,
+        command: LoginCustomerCommand,
+    ) -> Result<CustomerSessionDTO, ApplicationError> {
+        self.customers.login_customer_handler.handle(command).await
+    }
+
+    /// ログアウトコマンドを実行
+    pub async fn execute_logout_customer_command(
+        &self,
+        command: LogoutCustomerCommand,
+    ) -> Result<(), ApplicationError> {
+        self.customers.logout_customer_handler.handle(command).await
+    }
+
+    /// セッショントークン認証クエリを実行
+    pub async fn execute_authenticate_customer_query(
+        &self,
+        query: AuthenticateCustomerQuery,
+    ) -> Result<CustomerDTO, ApplicationError> {
+        self.customers
+            .authenticate_customer_handler
+            .handle(query)
+            .await
+    }
+
+    /// 返品申請コマンドを実行
+    pub async fn execute_request_return_command(
+        &self,
+        command: RequestReturnCommand,
+    ) -> Result<ReturnDTO, ApplicationError> {
+        self.fulfillment
+            .request_return_handler
+            .handle(command)
+            .await
+    }
+
+    /// 返品承認コマンドを実行
+    pub async fn execute_approve_return_command(
+        &self,
+        command: ApproveReturnCommand,
+    ) -> Result<ReturnDTO, ApplicationError> {
+        self.fulfillment
+            .approve_return_handler
+            .handle(command)
+            .await
+    }
+
+    /// 返品却下コマンドを実行
+    pub async fn execute_reject_return_command(
+        &self,
+        command: RejectReturnCommand,
+    ) -> Result<ReturnDTO, ApplicationError> {
+        self.fulfillment.reject_return_handler.handle(command).await
+    }
+
+    /// 返品受領コマンドを実行
+    pub async fn execute_receive_return_command(
+        &self,
+        command: ReceiveReturnCommand,
+    ) -> Result<ReturnDTO, ApplicationError> {
+        self.fulfillment
+            .receive_return_handler
+            .handle(command)
+            .await
+    }
+
+    /// 返品返金コマンドを実行
+    pub async fn execute_refund_return_command(
+        &self,
+        command: RefundReturnCommand,
+    ) -> Result<ReturnDTO, ApplicationError> {
+        self.fulfillment.refund_return_handler.handle(command).await
+    }
+
+    /// 返品取得クエリを実行
+    pub async fn execute_get_return_query(
+        &self,
+        query: GetReturnQuery,
+    ) -> Result<ReturnDTO, ApplicationError> {
+        self.fulfillment.get_return_handler.handle(query).await
+    }
+
+    /// 注文の返品一覧取得クエリを実行
+    pub async fn execute_get_order_returns_query(
+        &self,
+        query: GetOrderReturnsQuery,
+    ) -> Result<Vec<ReturnDTO>, ApplicationError> {
+        self.fulfillment
+            .get_order_returns_handler
+            .handle(query)
+            .await
+    }
+
+    /// 請求書・領収書発行コマンドを実行
+    pub async fn execute_issue_order_document_command(
+        &self,
+        command: IssueOrderDocumentCommand,
+    ) -> Result<RenderedDocumentDTO, ApplicationError> {
+        self.orders
+            .issue_order_document_handler
+            .handle(command)
+            .await
     }
 }
@@ -0,0 +1,51 @@
+use crate::application::dto::OrderDocumentDTO;
+use crate::application::error::ApplicationError;
+
+/// 帳票の出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DocumentFormat {
+    #[default]
+    Html,
+    Pdf,
+}
+
+impl DocumentFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            DocumentFormat::Html => "text/html; charset=utf-8",
+            DocumentFormat::Pdf => "application/pdf",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DocumentFormat::Html => "html",
+            DocumentFormat::Pdf => "pdf",
+        }
+    }
+}
+
+impl std::str::FromStr for DocumentFormat {
+    type Err = ApplicationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "html" => Ok(DocumentFormat::Html),
+            "pdf" => Ok(DocumentFormat::Pdf),
+            _ => Err(ApplicationError::InvalidInput(format!(
+                "Invalid document format: {} (expected html or pdf)",
+                s
+            ))),
+        }
+    }
+}
+
+/// 請求書・領収書の描画
+/// 記載内容の組み立てはアプリケーション層で行い、レイアウトと出力形式は実装に任せる
+pub trait DocumentRenderer: Send + Sync {
+    /// HTMLとして描画（印刷用のスタイルを含む1ページのHTML）
+    fn render_html(&self, document: &OrderDocumentDTO) -> String;
+
+    /// PDFとして描画
+    fn render_pdf(&self, document: &OrderDocumentDTO) -> Vec<u8>;
+}
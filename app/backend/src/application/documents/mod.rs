@@ -0,0 +1,3 @@
+mod document_renderer;
+
+pub use document_renderer::{DocumentFormat, DocumentRenderer};
@@ -0,0 +1,137 @@
+use crate::domain::{PriceSchedule, Product, ProductImage, SKU};
+use chrono::{DateTime, Utc};
+
+/// 管理用商品DTO
+/// 非公開の商品・販売停止中のSKUも含めた商品集約全体を表す
+#[derive(Debug, Clone)]
+pub struct AdminProductDTO {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub category_id: String,
+    pub is_best_seller: bool,
+    pub is_quick_ship: bool,
+    pub is_available: bool,
+    pub skus: Vec<AdminProductSkuDTO>,
+    pub images: Vec<AdminProductImageDTO>,
+    pub tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 管理用SKU DTO
+#[derive(Debug, Clone)]
+pub struct AdminProductSkuDTO {
+    pub id: String,
+    pub sku_code: String,
+    pub name: String,
+    pub color_id: Option<u32>,
+    pub dimensions: Option<String>,
+    pub material: Option<String>,
+    pub base_price: u32,
+    pub sale_price: Option<u32>,
+    pub price_schedules: Vec<AdminPriceScheduleDTO>,
+    pub stock_quantity: u32,
+    pub reserved_quantity: u32,
+    pub status: String,
+    pub display_order: u32,
+    pub tax_category: String,
+}
+
+/// 管理用価格スケジュールDTO
+#[derive(Debug, Clone)]
+pub struct AdminPriceScheduleDTO {
+    pub sale_price: u32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// 現在適用中かどうか
+    pub is_active: bool,
+}
+
+/// 管理用商品画像DTO
+#[derive(Debug, Clone)]
+pub struct AdminProductImageDTO {
+    pub url: String,
+    pub alt_text: Option<String>,
+    pub display_order: u32,
+}
+
+impl AdminProductDTO {
+    pub fn from_product(product: &Product) -> Self {
+        Self {
+            id: product.id().value().to_string(),
+            name: product.name().value().to_string(),
+            description: product.description().value().to_string(),
+            category_id: product.category_id().value().to_string(),
+            is_best_seller: product.is_best_seller(),
+            is_quick_ship: product.is_quick_ship(),
+            is_available: product.is_active(),
+            skus: product
+                .skus()
+                .iter()
+                .map(AdminProductSkuDTO::from_sku)
+                .collect(),
+            images: product
+                .images()
+                .iter()
+                .map(AdminProductImageDTO::from_image)
+                .collect(),
+            tags: product
+                .tags_at(Utc::now())
+                .iter()
+                .map(|tag| tag.slug().value().to_string())
+                .collect(),
+            created_at: product.created_at(),
+            updated_at: product.updated_at(),
+        }
+    }
+}
+
+impl AdminProductSkuDTO {
+    pub fn from_sku(sku: &SKU) -> Self {
+        let attributes = sku.variant_attributes();
+        let now = Utc::now();
+
+        Self {
+            id: sku.id().value().to_string(),
+            sku_code: sku.sku_code().value().to_string(),
+            name: sku.name().value().to_string(),
+            color_id: attributes.color_id().map(|color_id| color_id.value()),
+            dimensions: attributes.dimensions().map(|d| d.value().to_string()),
+            material: attributes.material().map(|m| m.value().to_string()),
+            base_price: sku.base_price().yen(),
+            sale_price: sku.sale_price().map(|price| price.yen()),
+            price_schedules: sku
+                .price_schedules()
+                .iter()
+                .map(|schedule| AdminPriceScheduleDTO::from_schedule(schedule, now))
+                .collect(),
+            stock_quantity: sku.total_quantity(),
+            reserved_quantity: sku.reserved_quantity(),
+            status: sku.status().to_string(),
+            display_order: sku.display_order(),
+            tax_category: sku.tax_category().code().to_string(),
+        }
+    }
+}
+
+impl AdminPriceScheduleDTO {
+    pub fn from_schedule(schedule: &PriceSchedule, now: DateTime<Utc>) -> Self {
+        Self {
+            sale_price: schedule.sale_price().yen(),
+            starts_at: schedule.starts_at(),
+            ends_at: schedule.ends_at(),
+            is_active: schedule.is_active_at(now),
+        }
+    }
+}
+
+impl AdminProductImageDTO {
+    pub fn from_image(image: &ProductImage) -> Self {
+        Self {
+            url: image.url().to_string(),
+            alt_text: image.alt_text.clone(),
+            display_order: image.display_order,
+        }
+    }
+}
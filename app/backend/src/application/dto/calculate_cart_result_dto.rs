@@ -1,4 +1,4 @@
-use crate::domain::{Cart, Money};
+use crate::domain::{Cart, Money, TaxBreakdown};
 
 /// カートアイテム計算結果DTO
 #[derive(Debug, Clone)]
@@ -9,6 +9,10 @@ pub struct CalculatedCartItemDto {
     pub unit_price: Money,
     pub quantity: u32,
     pub subtotal: Money,
+    /// 明細に配分したクーポン割引額
+    pub discount_amount: Money,
+    /// 割引後の小計
+    pub discounted_subtotal: Money,
 }
 
 /// クーポン適用結果DTO
@@ -20,6 +24,15 @@ pub struct AppliedCouponDto {
     pub message: String,
 }
 
+/// プロモーション適用結果DTO
+#[derive(Debug, Clone)]
+pub struct AppliedPromotionDto {
+    pub promotion_id: String,
+    pub name: String,
+    pub discount_amount: Money,
+    pub waives_shipping: bool,
+}
+
 /// クーポンエラー詳細DTO
 #[derive(Debug, Clone)]
 pub struct CouponErrorDto {
@@ -37,11 +50,18 @@ pub struct CalculateCartResultDto {
     pub item_count: usize,
     pub subtotal: Money,
     pub tax_amount: Money,
-    pub total_with_tax: Money,
+    /// 税率ごとの内訳
+    pub tax_breakdown: Vec<TaxBreakdown>,
+    /// 価格が税込か
+    pub tax_included: bool,
+    /// 支払総額（配送料・支払い手数料・税額を含む）
+    pub total: Money,
     pub is_empty: bool,
     pub shipping_fee: Money,
     pub payment_fee: Money,
     pub applied_coupon: Option<AppliedCouponDto>,
+    /// 自動適用されたプロモーション
+    pub applied_promotions: Vec<AppliedPromotionDto>,
     pub coupon_error: Option<CouponErrorDto>,
 }
 
@@ -56,16 +76,35 @@ impl CalculateCartResultDto {
         let applied_coupon = cart.coupon().map(|coupon| AppliedCouponDto {
             coupon_code: coupon.code().value().to_string(),
             coupon_name: coupon.name().to_string(),
-            discount_amount: calculation.discount_amount,
+            discount_amount: calculation.coupon_discount(),
             message: format!("Coupon '{}' applied", coupon.name()),
         });
 
-        // カートアイテムの計算
+        let applied_promotions = calculation
+            .applied_promotions
+            .iter()
+            .map(|promotion| AppliedPromotionDto {
+                promotion_id: promotion.promotion_id.to_string(),
+                name: promotion.name.clone(),
+                discount_amount: promotion.discount,
+                waives_shipping: promotion.waives_shipping,
+            })
+            .collect();
+
+        // カートアイテムの計算（クーポン割引は明細ごとの配分額を差し引く）
         let mut items = Vec::new();
-        for item in cart.items() {
+        for (index, item) in cart.items().iter().enumerate() {
             let subtotal = item
                 .subtotal()
                 .map_err(|e| format!("Failed to calculate item subtotal: {}", e))?;
+            let discount_amount = calculation
+                .item_discounts
+                .get(index)
+                .copied()
+                .unwrap_or_else(Money::zero);
+            let discounted_subtotal = subtotal
+                .subtract(discount_amount)
+                .map_err(|e| format!("Failed to calculate item discount: {}", e))?;
 
             items.push(CalculatedCartItemDto {
                 sku_id: item.sku_id().to_string(),
@@ -74,6 +113,8 @@ impl CalculateCartResultDto {
                 unit_price: item.unit_price(),
                 quantity: item.quantity(),
                 subtotal,
+                discount_amount,
+                discounted_subtotal,
             });
         }
 
@@ -83,11 +124,14 @@ impl CalculateCartResultDto {
             item_count: cart.item_count(),
             subtotal: calculation.final_subtotal,
             tax_amount: calculation.tax_amount,
-            total_with_tax: calculation.total_with_tax,
+            tax_breakdown: calculation.tax_breakdown,
+            tax_included: calculation.tax_included,
+            total: calculation.grand_total,
             is_empty: cart.is_empty(),
             shipping_fee: calculation.shipping_fee,
             payment_fee: calculation.payment_fee,
             applied_coupon,
+            applied_promotions,
             coupon_error,
         })
     }
@@ -0,0 +1,12 @@
+use crate::application::dto::CalculateCartResultDto;
+
+/// 永続カートDTO
+/// 選択中の配送方法・支払い方法・クーポンと、現在の価格で再計算した合計を含む
+#[derive(Debug, Clone)]
+pub struct CartDTO {
+    pub id: String,
+    pub shipping_method_id: Option<String>,
+    pub payment_method_id: Option<String>,
+    pub coupon_code: Option<String>,
+    pub totals: CalculateCartResultDto,
+}
@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::Coupon;
+use crate::domain::value_objects::{DiscountCondition, DiscountType};
+
+/// 管理用クーポンDTO
+#[derive(Debug, Clone)]
+pub struct CouponDTO {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// 割引種別（fixed_amount, percentage, capped_percentage, free_shipping, buy_x_get_y）
+    pub discount_type: String,
+    /// 割引額（fixed_amount）または割引率（percentage, capped_percentage）
+    pub discount_value: Option<u32>,
+    /// 割引額の上限（capped_percentage）
+    pub max_discount_amount: Option<u32>,
+    /// 購入数（buy_x_get_y）
+    pub buy_quantity: Option<u32>,
+    /// 無料になる数（buy_x_get_y）
+    pub get_quantity: Option<u32>,
+    pub minimum_amount: Option<u32>,
+    /// 対象商品ID（商品指定のクーポン）
+    pub target_product_ids: Vec<String>,
+    /// 対象カテゴリID（カテゴリ指定のクーポン）
+    pub target_category_ids: Vec<String>,
+    pub usage_limit: Option<u32>,
+    pub usage_count: u32,
+    pub per_customer_limit: Option<u32>,
+    pub valid_from: DateTime<Utc>,
+    pub valid_until: DateTime<Utc>,
+    /// 現在有効期間内か
+    pub is_active: bool,
+}
+
+/// 管理用クーポン一覧DTO
+#[derive(Debug, Clone)]
+pub struct CouponListDTO {
+    pub coupons: Vec<CouponDTO>,
+}
+
+impl CouponDTO {
+    pub fn from_coupon(coupon: &Coupon) -> Self {
+        let discount_type = coupon.discount_policy().discount_type();
+        let (discount_value, max_discount_amount, buy_quantity, get_quantity) = match discount_type
+        {
+            DiscountType::FixedAmount(amount) => (Some(amount.yen()), None, None, None),
+            DiscountType::Percentage(percentage) => (Some(*percentage as u32), None, None, None),
+            DiscountType::CappedPercentage {
+                percentage,
+                max_discount,
+            } => (
+                Some(*percentage as u32),
+                Some(max_discount.yen()),
+                None,
+                None,
+            ),
+            DiscountType::FreeShipping => (None, None, None, None),
+            DiscountType::BuyXGetY {
+                buy_quantity,
+                get_quantity,
+            } => (None, None, Some(*buy_quantity), Some(*get_quantity)),
+        };
+
+        let (minimum_amount, target_product_ids, target_category_ids) =
+            match coupon.discount_policy().discount_condition() {
+                Some(DiscountCondition::MinimumPurchase(amount)) => {
+                    (Some(amount.yen()), Vec::new(), Vec::new())
+                }
+                Some(DiscountCondition::ProductSpecific(product_ids)) => (
+                    None,
+                    product_ids
+                        .iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                    Vec::new(),
+                ),
+                Some(DiscountCondition::CategorySpecific(category_ids)) => (
+                    None,
+                    Vec::new(),
+                    category_ids
+                        .iter()
+                        .map(|id| id.value().to_string())
+                        .collect(),
+                ),
+                None => (None, Vec::new(), Vec::new()),
+            };
+
+        Self {
+            id: coupon.id().value().to_string(),
+            code: coupon.code().value().to_string(),
+            name: coupon.name().to_string(),
+            description: coupon.description().map(|d| d.to_string()),
+            discount_type: discount_type.code().to_string(),
+            discount_value,
+            max_discount_amount,
+            buy_quantity,
+            get_quantity,
+            minimum_amount,
+            target_product_ids,
+            target_category_ids,
+            usage_limit: coupon.usage_limit(),
+            usage_count: coupon.usage_count(),
+            per_customer_limit: coupon.per_customer_limit(),
+            valid_from: coupon.valid_from(),
+            valid_until: coupon.valid_until(),
+            is_active: coupon.is_valid(),
+        }
+    }
+}
+
+impl CouponListDTO {
+    pub fn from_coupons(coupons: &[Coupon]) -> Self {
+        Self {
+            coupons: coupons.iter().map(CouponDTO::from_coupon).collect(),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::aggregates::customer::{Customer, CustomerSession};
+
+/// 会員DTO
+#[derive(Debug, Clone)]
+pub struct CustomerDTO {
+    pub id: String,
+    pub email: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub phone: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// ログインセッションDTO（発行したトークンと会員情報）
+#[derive(Debug, Clone)]
+pub struct CustomerSessionDTO {
+    /// Authorizationヘッダーに指定するBearerトークン
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+    pub customer: CustomerDTO,
+}
+
+impl CustomerDTO {
+    pub fn from_customer(customer: &Customer) -> Self {
+        Self {
+            id: customer.id().to_string(),
+            email: customer.email().value().to_string(),
+            first_name: customer.personal_info().first_name().value().to_string(),
+            last_name: customer.personal_info().last_name().value().to_string(),
+            phone: customer.phone().value().to_string(),
+            created_at: customer.created_at(),
+        }
+    }
+}
+
+impl CustomerSessionDTO {
+    pub fn new(token: String, session: &CustomerSession, customer: &Customer) -> Self {
+        Self {
+            token,
+            expires_at: session.expires_at(),
+            customer: CustomerDTO::from_customer(customer),
+        }
+    }
+}
@@ -1,21 +1,51 @@
+mod admin_product_dto;
 mod calculate_cart_result_dto;
+mod cart_dto;
 mod category_list_dto;
 mod color_list_dto;
+mod coupon_dto;
 mod create_order_result_dto;
+mod customer_dto;
+mod order_document_dto;
+mod order_dto;
+mod order_event_dto;
+mod payment_dto;
 mod payment_method_list_dto;
 mod product_dto;
 mod product_list_dto;
+mod product_search_result_dto;
+mod return_dto;
+mod shipment_dto;
 mod shipping_method_list_dto;
+mod stock_movement_dto;
 mod variant_summary_dto;
 
-pub use self::calculate_cart_result_dto::{
-    AppliedCouponDto, CalculateCartResultDto, CalculatedCartItemDto, CouponErrorDto,
+pub use self::admin_product_dto::{
+    AdminPriceScheduleDTO, AdminProductDTO, AdminProductImageDTO, AdminProductSkuDTO,
 };
+pub use self::calculate_cart_result_dto::{CalculateCartResultDto, CouponErrorDto};
+pub use self::cart_dto::CartDTO;
 pub use self::category_list_dto::{CategoryDTO, CategoryListDTO};
 pub use self::color_list_dto::{ColorDTO, ColorListDTO};
+pub use self::coupon_dto::{CouponDTO, CouponListDTO};
 pub use self::create_order_result_dto::CreateOrderResultDTO;
-pub use self::payment_method_list_dto::{PaymentMethodDTO, PaymentMethodListDTO};
+pub use self::customer_dto::{CustomerDTO, CustomerSessionDTO};
+pub use self::order_document_dto::{
+    OrderDocumentDTO, OrderDocumentItemDTO, OrderDocumentTaxDTO, RenderedDocumentDTO,
+};
+pub use self::order_dto::{
+    OrderDTO, OrderItemDTO, OrderListDTO, OrderPromotionDTO, TaxBreakdownDTO,
+};
+pub use self::order_event_dto::OrderEventDTO;
+pub use self::payment_dto::{PaymentHistoryDTO, PaymentResultDTO, PaymentTransactionDTO};
+pub use self::payment_method_list_dto::{
+    PaymentFeeRuleDTO, PaymentMethodDTO, PaymentMethodListDTO,
+};
 pub use self::product_dto::{ProductDTO, VariantDTO};
 pub use self::product_list_dto::{ProductListDTO, ProductSummaryDTO};
+pub use self::product_search_result_dto::{ProductSearchHitDTO, ProductSearchResultDTO};
+pub use self::return_dto::ReturnDTO;
+pub use self::shipment_dto::ShipmentDTO;
 pub use self::shipping_method_list_dto::{ShippingMethodDTO, ShippingMethodListDTO};
+pub use self::stock_movement_dto::{StockHistoryDTO, StockMovementDTO};
 pub use self::variant_summary_dto::VariantSummaryDTO;
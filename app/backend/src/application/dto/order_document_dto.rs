@@ -0,0 +1,176 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::aggregates::order::{Order, OrderDocumentKind, OrderItem};
+use crate::domain::value_objects::{DocumentIssuer, TaxCategory, TaxPolicy};
+
+/// 請求書・領収書の記載内容DTO
+#[derive(Debug, Clone)]
+pub struct OrderDocumentDTO {
+    /// 帳票の種類（invoice, receipt）
+    pub kind: String,
+    /// 帳票番号（再発行しても変わらない）
+    pub document_number: String,
+    /// 何回目の発行か（2回目以降は再発行）
+    pub issue_number: u32,
+    pub issued_at: DateTime<Utc>,
+    /// 宛名（敬称を含む）
+    pub addressee: String,
+    pub issuer_name: String,
+    /// 適格請求書発行事業者の登録番号
+    pub issuer_registration_number: Option<String>,
+    pub issuer_address: Option<String>,
+    pub issuer_phone: Option<String>,
+    pub order_number: String,
+    pub ordered_at: DateTime<Utc>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub customer_name: String,
+    pub shipping_postal_code: String,
+    /// 届け先住所（都道府県から建物名まで）
+    pub shipping_address: String,
+    pub shipping_method_name: String,
+    pub payment_method_name: String,
+    pub items: Vec<OrderDocumentItemDTO>,
+    pub subtotal: u32,
+    pub discount: u32,
+    pub shipping_fee: u32,
+    pub payment_fee: u32,
+    pub tax_amount: u32,
+    pub total: u32,
+    pub tax_included: bool,
+    pub tax_breakdown: Vec<OrderDocumentTaxDTO>,
+}
+
+/// 帳票の明細行
+#[derive(Debug, Clone)]
+pub struct OrderDocumentItemDTO {
+    pub product_name: String,
+    pub sku_name: String,
+    pub sku_code: String,
+    pub quantity: u32,
+    pub unit_price: u32,
+    pub amount: u32,
+    pub tax_rate: u32,
+    /// 軽減税率の対象品目か
+    pub reduced_rate: bool,
+}
+
+/// 帳票の税率ごとの内訳
+#[derive(Debug, Clone)]
+pub struct OrderDocumentTaxDTO {
+    pub rate: u32,
+    /// 対象額（税抜）
+    pub taxable_amount: u32,
+    pub tax_amount: u32,
+    pub reduced_rate: bool,
+}
+
+/// 描画済みの帳票（HTML・PDF）
+#[derive(Debug, Clone)]
+pub struct RenderedDocumentDTO {
+    pub content_type: String,
+    pub file_name: String,
+    pub body: Vec<u8>,
+}
+
+impl OrderDocumentDTO {
+    /// 注文から帳票の記載内容を組み立てる（`issue_number`は記録した発行回数）
+    pub fn from_order(
+        order: &Order,
+        kind: OrderDocumentKind,
+        issue_number: u32,
+        addressee: String,
+        issuer: &DocumentIssuer,
+        tax_policy: &TaxPolicy,
+        issued_at: DateTime<Utc>,
+    ) -> Self {
+        let address = &order.shipping_info.address;
+        let prefix = match kind {
+            OrderDocumentKind::Invoice => "INV",
+            OrderDocumentKind::Receipt => "RCP",
+        };
+        let is_reduced_rate =
+            |rate: u32| rate == tax_policy.reduced_rate() && rate != tax_policy.standard_rate();
+
+        Self {
+            kind: kind.code().to_string(),
+            document_number: format!("{}-{}", prefix, order.order_number.value()),
+            issue_number,
+            issued_at,
+            addressee,
+            issuer_name: issuer.name().to_string(),
+            issuer_registration_number: issuer.registration_number().map(|n| n.to_string()),
+            issuer_address: issuer.address().map(|a| a.to_string()),
+            issuer_phone: issuer.phone().map(|p| p.to_string()),
+            order_number: order.order_number.value().to_string(),
+            ordered_at: order.timestamps.created_at,
+            paid_at: order.timestamps.paid_at,
+            customer_name: format!(
+                "{} {}",
+                order.customer_info.last_name(),
+                order.customer_info.first_name()
+            ),
+            shipping_postal_code: address.postal_code().to_string(),
+            shipping_address: [
+                Some(address.prefecture()),
+                Some(address.city()),
+                Some(address.street()),
+                address.building(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" "),
+            shipping_method_name: order.shipping_info.method_name.clone(),
+            payment_method_name: order.payment_info.method_name.clone(),
+            items: order
+                .items
+                .iter()
+                .map(|item| OrderDocumentItemDTO::from_order_item(item, tax_policy))
+                .collect(),
+            subtotal: order.pricing.subtotal.yen(),
+            discount: order.pricing.discount.yen(),
+            shipping_fee: order.pricing.shipping_fee.yen(),
+            payment_fee: order.pricing.payment_fee.yen(),
+            tax_amount: order.pricing.tax_amount.yen(),
+            total: order.pricing.total.yen(),
+            tax_included: order.pricing.tax_included,
+            tax_breakdown: order
+                .pricing
+                .tax_breakdown
+                .iter()
+                .map(|breakdown| OrderDocumentTaxDTO {
+                    rate: breakdown.rate,
+                    taxable_amount: breakdown.taxable_amount.yen(),
+                    tax_amount: breakdown.tax_amount.yen(),
+                    reduced_rate: is_reduced_rate(breakdown.rate),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn is_receipt(&self) -> bool {
+        self.kind == OrderDocumentKind::Receipt.code()
+    }
+
+    pub fn is_reissue(&self) -> bool {
+        self.issue_number > 1
+    }
+}
+
+impl OrderDocumentItemDTO {
+    fn from_order_item(item: &OrderItem, tax_policy: &TaxPolicy) -> Self {
+        let unit_price = item.unit_price.yen();
+        let quantity = item.quantity as u32;
+
+        Self {
+            product_name: item.product_name.value().to_string(),
+            sku_name: item.sku_name.value().to_string(),
+            sku_code: item.sku_code.value().to_string(),
+            quantity,
+            unit_price,
+            amount: unit_price * quantity,
+            tax_rate: tax_policy.rate_for(item.tax_category),
+            reduced_rate: item.tax_category == TaxCategory::Reduced,
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use super::ShipmentDTO;
+use crate::domain::aggregates::order::{Order, OrderItem};
+use chrono::{DateTime, Utc};
+
+/// 注文詳細DTO
+/// CQRS Query側：Order集約から構築する読み取り専用データ
+#[derive(Debug, Clone)]
+pub struct OrderDTO {
+    pub id: String,
+    pub order_number: String,
+    pub status: String,
+    pub customer_first_name: String,
+    pub customer_last_name: String,
+    pub customer_email: String,
+    pub customer_phone: String,
+    pub items: Vec<OrderItemDTO>,
+    pub shipping_method_id: String,
+    pub shipping_method_name: String,
+    pub shipping_postal_code: String,
+    pub shipping_prefecture: String,
+    pub shipping_city: String,
+    pub shipping_street: String,
+    pub shipping_building: Option<String>,
+    pub payment_method_id: String,
+    pub payment_method_name: String,
+    pub subtotal: u32,
+    pub coupon_code: Option<String>,
+    /// 自動適用されたプロモーション
+    pub promotions: Vec<OrderPromotionDTO>,
+    /// 割引額の合計（クーポン・プロモーション）
+    pub discount: u32,
+    pub shipping_fee: u32,
+    pub payment_fee: u32,
+    pub tax_amount: u32,
+    /// 税率ごとの内訳
+    pub tax_breakdown: Vec<TaxBreakdownDTO>,
+    /// 価格が税込か
+    pub tax_included: bool,
+    pub total_amount: u32,
+    pub notes: Option<String>,
+    pub shipment: Option<ShipmentDTO>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+}
+
+/// 注文アイテムDTO
+#[derive(Debug, Clone)]
+pub struct OrderItemDTO {
+    pub sku_id: String,
+    pub sku_code: String,
+    pub product_name: String,
+    pub sku_name: String,
+    pub unit_price: u32,
+    pub quantity: u32,
+    pub subtotal: u32,
+    /// 税区分（standard / reduced）
+    pub tax_category: String,
+}
+
+/// 注文に適用されたプロモーションDTO
+#[derive(Debug, Clone)]
+pub struct OrderPromotionDTO {
+    pub promotion_id: String,
+    pub name: String,
+    pub discount_amount: u32,
+    pub waives_shipping: bool,
+}
+
+/// 税率別内訳DTO
+#[derive(Debug, Clone)]
+pub struct TaxBreakdownDTO {
+    pub rate: u32,
+    pub taxable_amount: u32,
+    pub tax_amount: u32,
+}
+
+/// 注文一覧DTO
+#[derive(Debug, Clone)]
+pub struct OrderListDTO {
+    pub orders: Vec<OrderDTO>,
+    pub total_count: u32,
+    pub page: u32,
+    pub per_page: u32,
+    pub has_next_page: bool,
+    pub has_previous_page: bool,
+}
+
+impl OrderDTO {
+    pub fn from_order(order: &Order) -> Self {
+        let address = &order.shipping_info.address;
+
+        Self {
+            id: order.id.to_string(),
+            order_number: order.order_number.value().to_string(),
+            status: order.status.to_string(),
+            customer_first_name: order.customer_info.first_name().to_string(),
+            customer_last_name: order.customer_info.last_name().to_string(),
+            customer_email: order.customer_info.email_address().to_string(),
+            customer_phone: order.customer_info.phone_number().to_string(),
+            items: order
+                .items
+                .iter()
+                .map(OrderItemDTO::from_order_item)
+                .collect(),
+            shipping_method_id: order.shipping_info.method_id_value().to_string(),
+            shipping_method_name: order.shipping_info.method_name.clone(),
+            shipping_postal_code: address.postal_code().to_string(),
+            shipping_prefecture: address.prefecture().to_string(),
+            shipping_city: address.city().to_string(),
+            shipping_street: address.street().to_string(),
+            shipping_building: address.building().map(|b| b.to_string()),
+            payment_method_id: order.payment_info.method_id_value().to_string(),
+            payment_method_name: order.payment_info.method_name.clone(),
+            subtotal: order.pricing.subtotal_yen(),
+            coupon_code: order.coupon.as_ref().map(|c| c.code.value().to_string()),
+            promotions: order
+                .promotions
+                .iter()
+                .map(|promotion| OrderPromotionDTO {
+                    promotion_id: promotion.promotion_id.to_string(),
+                    name: promotion.name.clone(),
+                    discount_amount: promotion.discount.yen(),
+                    waives_shipping: promotion.waives_shipping,
+                })
+                .collect(),
+            discount: order.pricing.discount_yen(),
+            shipping_fee: order.pricing.shipping_fee_yen(),
+            payment_fee: order.pricing.payment_fee_yen(),
+            tax_amount: order.pricing.tax_amount_yen(),
+            tax_breakdown: order
+                .pricing
+                .tax_breakdown
+                .iter()
+                .map(|breakdown| TaxBreakdownDTO {
+                    rate: breakdown.rate,
+                    taxable_amount: breakdown.taxable_amount.yen(),
+                    tax_amount: breakdown.tax_amount.yen(),
+                })
+                .collect(),
+            tax_included: order.pricing.tax_included,
+            total_amount: order.pricing.total_yen(),
+            notes: order.notes.clone(),
+            shipment: order
+                .delivery_info
+                .as_ref()
+                .map(|delivery_info| ShipmentDTO::from_delivery_info(&order.id, delivery_info)),
+            created_at: order.timestamps.created_at,
+            updated_at: order.timestamps.updated_at,
+            paid_at: order.timestamps.paid_at,
+            shipped_at: order.timestamps.shipped_at,
+            delivered_at: order.timestamps.delivered_at,
+            cancelled_at: order.timestamps.cancelled_at,
+        }
+    }
+}
+
+impl OrderItemDTO {
+    pub fn from_order_item(item: &OrderItem) -> Self {
+        let unit_price = item.unit_price.yen();
+        let quantity = item.quantity as u32;
+
+        Self {
+            sku_id: item.sku_id.value().to_string(),
+            sku_code: item.sku_code.value().to_string(),
+            product_name: item.product_name.value().to_string(),
+            sku_name: item.sku_name.value().to_string(),
+            unit_price,
+            quantity,
+            subtotal: unit_price * quantity,
+            tax_category: item.tax_category.code().to_string(),
+        }
+    }
+}
+
+impl OrderListDTO {
+    pub fn new(orders: Vec<OrderDTO>, total_count: u32, page: u32, per_page: u32) -> Self {
+        Self {
+            orders,
+            total_count,
+            page,
+            per_page,
+            has_next_page: page * per_page < total_count,
+            has_previous_page: page > 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_list_dto_paging_flags() {
+        let first_page = OrderListDTO::new(Vec::new(), 45, 1, 20);
+        assert!(first_page.has_next_page);
+        assert!(!first_page.has_previous_page);
+
+        let last_page = OrderListDTO::new(Vec::new(), 45, 3, 20);
+        assert!(!last_page.has_next_page);
+        assert!(last_page.has_previous_page);
+
+        let empty = OrderListDTO::new(Vec::new(), 0, 1, 20);
+        assert!(!empty.has_next_page);
+        assert!(!empty.has_previous_page);
+    }
+}
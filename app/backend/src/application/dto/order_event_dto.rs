@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+
+/// 注文イベントDTO（監査用タイムライン）
+/// CQRS Query側：order_eventsテーブルから直接構築
+#[derive(Debug, Clone)]
+pub struct OrderEventDTO {
+    pub id: i64,
+    pub event_type: String,
+    pub event_data: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::aggregates::order::Order;
+use crate::domain::{PaymentState, PaymentTransaction};
+
+/// 決済記録DTO
+#[derive(Debug, Clone)]
+pub struct PaymentTransactionDTO {
+    pub provider: String,
+    pub operation: String,
+    pub amount: u32,
+    pub status: String,
+    pub transaction_id: Option<String>,
+    pub failure_reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PaymentTransactionDTO {
+    pub fn from_transaction(transaction: &PaymentTransaction) -> Self {
+        Self {
+            provider: transaction.provider.clone(),
+            operation: transaction.operation.to_string(),
+            amount: transaction.amount.yen(),
+            status: transaction.status.to_string(),
+            transaction_id: transaction.transaction_id.clone(),
+            failure_reason: transaction.failure_reason.clone(),
+            created_at: transaction.occurred_at,
+        }
+    }
+}
+
+/// 決済操作の結果DTO（記録した操作と、操作後の注文・決済状態）
+#[derive(Debug, Clone)]
+pub struct PaymentResultDTO {
+    pub order_id: String,
+    pub order_status: String,
+    pub payment_status: String,
+    pub transaction: PaymentTransactionDTO,
+}
+
+impl PaymentResultDTO {
+    pub fn new(order: &Order, state: &PaymentState, transaction: &PaymentTransaction) -> Self {
+        Self {
+            order_id: order.id.to_string(),
+            order_status: order.status.to_string(),
+            payment_status: state.code().to_string(),
+            transaction: PaymentTransactionDTO::from_transaction(transaction),
+        }
+    }
+}
+
+/// 注文の決済履歴DTO
+#[derive(Debug, Clone)]
+pub struct PaymentHistoryDTO {
+    pub order_id: String,
+    pub payment_status: String,
+    pub transactions: Vec<PaymentTransactionDTO>,
+}
+
+impl PaymentHistoryDTO {
+    pub fn from_transactions(order_id: String, transactions: &[PaymentTransaction]) -> Self {
+        Self {
+            order_id,
+            payment_status: PaymentState::from_transactions(transactions)
+                .code()
+                .to_string(),
+            transactions: transactions
+                .iter()
+                .map(PaymentTransactionDTO::from_transaction)
+                .collect(),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::domain::entities::{PaymentFeeKind, PaymentMethod};
+
 #[derive(Debug, Clone)]
 pub struct PaymentMethodListDTO {
     pub items: Vec<PaymentMethodDTO>,
@@ -8,6 +10,28 @@ pub struct PaymentMethodDTO {
     pub id: String,
     pub name: String,
     pub description: String,
+    pub fee_rule: PaymentFeeRuleDTO,
+}
+
+/// 支払い手数料ルールDTO
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentFeeRuleDTO {
+    /// 計算方式（flat, percentage, tiered）
+    pub fee_type: String,
+    /// 定額の手数料（flatのみ）
+    pub amount: Option<u32>,
+    /// 割合（%、percentageのみ）
+    pub rate: Option<f64>,
+    pub min_fee: Option<u32>,
+    pub max_fee: Option<u32>,
+    /// 金額帯（tieredのみ）
+    pub brackets: Vec<PaymentFeeBracketDTO>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentFeeBracketDTO {
+    pub up_to: Option<u32>,
+    pub fee: u32,
 }
 
 impl PaymentMethodListDTO {
@@ -15,3 +39,38 @@ impl PaymentMethodListDTO {
         Self { items }
     }
 }
+
+impl PaymentMethodDTO {
+    pub fn from_payment_method(payment_method: &PaymentMethod) -> Self {
+        let fee_rule = payment_method.fee_rule();
+        let (amount, rate, brackets) = match fee_rule.kind() {
+            PaymentFeeKind::Flat { amount } => (Some(amount.yen()), None, Vec::new()),
+            PaymentFeeKind::Percentage { rate } => (None, Some(*rate), Vec::new()),
+            PaymentFeeKind::Tiered { brackets } => (
+                None,
+                None,
+                brackets
+                    .iter()
+                    .map(|bracket| PaymentFeeBracketDTO {
+                        up_to: bracket.up_to.map(|up_to| up_to.yen()),
+                        fee: bracket.fee.yen(),
+                    })
+                    .collect(),
+            ),
+        };
+
+        Self {
+            id: payment_method.id().to_string(),
+            name: payment_method.name().to_string(),
+            description: payment_method.description().to_string(),
+            fee_rule: PaymentFeeRuleDTO {
+                fee_type: fee_rule.kind().code().to_string(),
+                amount,
+                rate,
+                min_fee: fee_rule.min_fee().map(|fee| fee.yen()),
+                max_fee: fee_rule.max_fee().map(|fee| fee.yen()),
+                brackets,
+            },
+        }
+    }
+}
@@ -1,3 +1,12 @@
+use uuid::Uuid;
+
+use crate::domain::aggregates::CartItem;
+use crate::domain::entities::ShippableItem;
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{
+    CategoryId, Dimensions, Money, ProductId, ProductName, SKUId, TaxCategory,
+};
+
 /// ProductのViewModel（Application層）
 /// CQRS Query側専用：Infrastructure層から直接構築
 /// パフォーマンス重視でドメインモデルを経由しない
@@ -30,6 +39,14 @@ pub struct VariantDTO {
     pub image: Option<String>,
     pub is_on_sale: bool,
     pub is_sold_out: bool,
+    /// 商品が即日出荷対象か（配送方法の選択条件に使う）
+    pub is_quick_ship: bool,
+    /// 税区分（軽減税率の対象か）
+    pub tax_category: TaxCategory,
+    /// 商品ID（対象商品を限定したクーポンの判定に使う）
+    pub product_id: Option<String>,
+    /// 商品のカテゴリID（対象カテゴリを限定したクーポンの判定に使う）
+    pub category_id: Option<String>,
 }
 
 impl ProductDTO {}
@@ -68,6 +85,73 @@ impl VariantDTO {
             image,
             is_on_sale,
             is_sold_out,
+            is_quick_ship: false,
+            tax_category: TaxCategory::default(),
+            product_id: None,
+            category_id: None,
+        }
+    }
+
+    /// 商品の即日出荷対象フラグを設定
+    pub fn with_quick_ship(mut self, is_quick_ship: bool) -> Self {
+        self.is_quick_ship = is_quick_ship;
+        self
+    }
+
+    /// SKUの税区分を設定
+    pub fn with_tax_category(mut self, tax_category: TaxCategory) -> Self {
+        self.tax_category = tax_category;
+        self
+    }
+
+    /// SKUが属する商品とカテゴリを設定
+    pub fn with_product(mut self, product_id: String, category_id: String) -> Self {
+        self.product_id = Some(product_id);
+        self.category_id = Some(category_id);
+        self
+    }
+
+    /// 配送料計算用の商品情報に変換
+    pub fn shippable_item(&self, quantity: u32) -> ShippableItem {
+        ShippableItem::new(
+            quantity,
+            Dimensions::new(self.dimensions.clone()).ok(),
+            self.is_quick_ship,
+        )
+    }
+
+    /// 割引・税計算用のカートアイテムに変換（セール価格がある場合はそれを使用）
+    /// 商品が設定されていない場合は、クーポンの対象商品・対象カテゴリに一致しないものとして扱う
+    pub fn cart_item(&self, quantity: u32) -> Result<CartItem, DomainError> {
+        self.cart_item_at(
+            Money::from_yen(self.sale_price.unwrap_or(self.price)),
+            quantity,
+        )
+    }
+
+    /// 指定した単価で割引・税計算用のカートアイテムに変換（注文時の単価で再計算する場合に使用）
+    pub fn cart_item_at(&self, unit_price: Money, quantity: u32) -> Result<CartItem, DomainError> {
+        let parse_uuid = |value: &str| {
+            Uuid::parse_str(value)
+                .map_err(|_| DomainError::InvalidProductData(format!("Invalid ID: {}", value)))
+        };
+
+        let product_id = match &self.product_id {
+            Some(product_id) => ProductId::from_uuid(parse_uuid(product_id)?),
+            None => ProductId::new(),
+        };
+        let item = CartItem::new(
+            SKUId::from_uuid(parse_uuid(&self.id)?),
+            product_id,
+            ProductName::new(self.name.clone())?,
+            unit_price,
+            quantity,
+        )?
+        .with_tax_category(self.tax_category);
+
+        match &self.category_id {
+            Some(category_id) => Ok(item.with_category_id(CategoryId::new(category_id.clone())?)),
+            None => Ok(item),
         }
     }
 }
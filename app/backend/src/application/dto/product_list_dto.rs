@@ -22,6 +22,24 @@ pub struct ProductSummaryDTO {
     pub stock_quantity: u32,
 }
 
+impl ProductListDTO {
+    pub fn new(
+        products: Vec<ProductSummaryDTO>,
+        total_count: u32,
+        page: u32,
+        per_page: u32,
+    ) -> Self {
+        Self {
+            products,
+            total_count,
+            page,
+            per_page,
+            has_next_page: page * per_page < total_count,
+            has_previous_page: page > 1,
+        }
+    }
+}
+
 impl ProductSummaryDTO {
     pub fn new(
         id: String,
@@ -0,0 +1,33 @@
+use super::ProductSummaryDTO;
+
+/// 商品キーワード検索結果（関連度順）
+#[derive(Debug, Clone)]
+pub struct ProductSearchResultDTO {
+    pub hits: Vec<ProductSearchHitDTO>,
+    pub total_count: u32,
+    pub page: u32,
+    pub per_page: u32,
+    pub has_next_page: bool,
+    pub has_previous_page: bool,
+}
+
+/// 検索に一致した商品と一致箇所のスニペット
+#[derive(Debug, Clone)]
+pub struct ProductSearchHitDTO {
+    pub product: ProductSummaryDTO,
+    /// 一致箇所を<mark>タグで囲んだ抜粋
+    pub snippet: String,
+}
+
+impl ProductSearchResultDTO {
+    pub fn new(hits: Vec<ProductSearchHitDTO>, total_count: u32, page: u32, per_page: u32) -> Self {
+        Self {
+            hits,
+            total_count,
+            page,
+            per_page,
+            has_next_page: page * per_page < total_count,
+            has_previous_page: page > 1,
+        }
+    }
+}
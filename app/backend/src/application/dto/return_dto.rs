@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::aggregates::return_request::{ReturnItem, ReturnRequest};
+
+/// 返品DTO
+#[derive(Debug, Clone)]
+pub struct ReturnDTO {
+    pub id: String,
+    pub order_id: String,
+    pub status: String,
+    pub reason: String,
+    pub condition: String,
+    pub note: Option<String>,
+    pub rejection_reason: Option<String>,
+    pub items: Vec<ReturnItemDTO>,
+    pub items_amount: u32,
+    pub tax_amount: u32,
+    pub tax_included: bool,
+    pub restocking_fee_rate: u32,
+    pub restocking_fee: u32,
+    pub refund_amount: u32,
+    pub restockable: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub received_at: Option<DateTime<Utc>>,
+    pub refunded_at: Option<DateTime<Utc>>,
+    pub rejected_at: Option<DateTime<Utc>>,
+}
+
+/// 返品明細DTO
+#[derive(Debug, Clone)]
+pub struct ReturnItemDTO {
+    pub sku_id: String,
+    pub sku_code: String,
+    pub product_name: String,
+    pub quantity: u32,
+    pub tax_rate: u32,
+    pub amount: u32,
+    pub tax_amount: u32,
+}
+
+impl ReturnDTO {
+    pub fn from_return(return_request: &ReturnRequest) -> Self {
+        Self {
+            id: return_request.id.to_string(),
+            order_id: return_request.order_id.to_string(),
+            status: return_request.status.code().to_string(),
+            reason: return_request.reason.code().to_string(),
+            condition: return_request.condition.code().to_string(),
+            note: return_request.note.clone(),
+            rejection_reason: return_request.rejection_reason.clone(),
+            items: return_request
+                .items
+                .iter()
+                .map(ReturnItemDTO::from_item)
+                .collect(),
+            items_amount: return_request.items_amount.yen(),
+            tax_amount: return_request.tax_amount.yen(),
+            tax_included: return_request.tax_included,
+            restocking_fee_rate: return_request.restocking_fee_rate,
+            restocking_fee: return_request.restocking_fee.yen(),
+            refund_amount: return_request.refund_amount.yen(),
+            restockable: return_request.is_restockable(),
+            created_at: return_request.created_at,
+            updated_at: return_request.updated_at,
+            approved_at: return_request.approved_at,
+            received_at: return_request.received_at,
+            refunded_at: return_request.refunded_at,
+            rejected_at: return_request.rejected_at,
+        }
+    }
+}
+
+impl ReturnItemDTO {
+    fn from_item(item: &ReturnItem) -> Self {
+        Self {
+            sku_id: item.sku_id.to_string(),
+            sku_code: item.sku_code.value().to_string(),
+            product_name: item.product_name.value().to_string(),
+            quantity: item.quantity,
+            tax_rate: item.tax_rate,
+            amount: item.amount.yen(),
+            tax_amount: item.tax_amount.yen(),
+        }
+    }
+}
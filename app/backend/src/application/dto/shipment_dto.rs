@@ -0,0 +1,49 @@
+use crate::domain::DeliveryInfo;
+use crate::domain::value_objects::OrderId;
+use chrono::{DateTime, Utc};
+
+/// 出荷（配送情報）DTO
+#[derive(Debug, Clone)]
+pub struct ShipmentDTO {
+    pub id: String,
+    pub order_id: String,
+    pub status: String,
+    pub carrier: Option<String>,
+    pub tracking_number: Option<String>,
+    pub shipping_method: Option<String>,
+    pub recipient_name: String,
+    pub postal_code: String,
+    pub prefecture: String,
+    pub city: String,
+    pub street: String,
+    pub building: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl ShipmentDTO {
+    pub fn from_delivery_info(order_id: &OrderId, delivery_info: &DeliveryInfo) -> Self {
+        let address = delivery_info.address();
+
+        Self {
+            id: delivery_info.id().to_string(),
+            order_id: order_id.to_string(),
+            status: delivery_info.status().code().to_string(),
+            carrier: delivery_info.carrier().map(|c| c.to_string()),
+            tracking_number: delivery_info.tracking_number().map(|t| t.to_string()),
+            shipping_method: delivery_info.shipping_method().map(|m| m.to_string()),
+            recipient_name: delivery_info.personal_info().to_string(),
+            postal_code: address.postal_code().to_string(),
+            prefecture: address.prefecture().to_string(),
+            city: address.city().to_string(),
+            street: address.street().to_string(),
+            building: address.building().map(|b| b.to_string()),
+            created_at: delivery_info.created_at,
+            updated_at: delivery_info.updated_at,
+            shipped_at: delivery_info.shipped_at,
+            delivered_at: delivery_info.delivered_at,
+        }
+    }
+}
@@ -18,26 +18,56 @@ pub struct ShippingMethodDTO {
     pub name: String,
     pub description: String,
     pub price: u32,
+    pub free_shipping_threshold: Option<u32>,
+    pub quick_ship_only: bool,
+    pub region_rates: Vec<ShippingRegionRateDTO>,
+    pub oversize_surcharge: Option<OversizeSurchargeDTO>,
+}
+
+/// 地域別の配送料
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShippingRegionRateDTO {
+    pub region: String,
+    pub fee: u32,
+}
+
+/// 大型商品の追加料金
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OversizeSurchargeDTO {
+    pub min_size: f64,
+    pub fee_per_item: u32,
 }
 
 impl From<ShippingMethod> for ShippingMethodDTO {
     fn from(method: ShippingMethod) -> Self {
-        Self {
-            id: method.id().value().to_string(),
-            name: method.name().to_string(),
-            description: method.description().to_string(),
-            price: method.price().yen(),
-        }
+        Self::from(&method)
     }
 }
 
 impl From<&ShippingMethod> for ShippingMethodDTO {
     fn from(method: &ShippingMethod) -> Self {
+        let rate_table = method.rate_table();
         Self {
             id: method.id().value().to_string(),
             name: method.name().to_string(),
             description: method.description().to_string(),
             price: method.price().yen(),
+            free_shipping_threshold: rate_table.free_shipping_threshold().map(|fee| fee.yen()),
+            quick_ship_only: rate_table.is_quick_ship_only(),
+            region_rates: rate_table
+                .region_rates()
+                .iter()
+                .map(|rate| ShippingRegionRateDTO {
+                    region: rate.region.code().to_string(),
+                    fee: rate.fee.yen(),
+                })
+                .collect(),
+            oversize_surcharge: rate_table.oversize_surcharge().map(|surcharge| {
+                OversizeSurchargeDTO {
+                    min_size: surcharge.min_size,
+                    fee_per_item: surcharge.fee_per_item.yen(),
+                }
+            }),
         }
     }
 }
@@ -85,5 +115,43 @@ mod tests {
         assert_eq!(dto.name, "速達配送");
         assert_eq!(dto.description, "2-3営業日");
         assert_eq!(dto.price, 1000);
+        assert_eq!(dto.free_shipping_threshold, None);
+        assert!(dto.region_rates.is_empty());
+    }
+
+    #[test]
+    fn test_shipping_method_dto_includes_rate_table() {
+        use crate::domain::entities::{ShippingRateTable, ShippingRegion, ShippingRegionRate};
+
+        let id = ShippingMethodId::new("overnight".to_string()).unwrap();
+        let method = ShippingMethod::new(
+            id,
+            "翌日配送".to_string(),
+            "翌営業日".to_string(),
+            Money::from_yen(2000),
+            true,
+            3,
+        )
+        .with_rate_table(
+            ShippingRateTable::new(
+                vec![ShippingRegionRate::new(
+                    ShippingRegion::Okinawa,
+                    Money::from_yen(3500),
+                )],
+                Some(Money::from_yen(30000)),
+                None,
+                true,
+            )
+            .unwrap(),
+        );
+
+        let dto = ShippingMethodDTO::from(&method);
+
+        assert_eq!(dto.free_shipping_threshold, Some(30000));
+        assert!(dto.quick_ship_only);
+        assert_eq!(dto.region_rates.len(), 1);
+        assert_eq!(dto.region_rates[0].region, "okinawa");
+        assert_eq!(dto.region_rates[0].fee, 3500);
+        assert!(dto.oversize_surcharge.is_none());
     }
 }
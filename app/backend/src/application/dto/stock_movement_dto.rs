@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+
+/// 在庫台帳DTO
+/// CQRS Query側：stock_movementsテーブルから直接構築
+#[derive(Debug, Clone)]
+pub struct StockMovementDTO {
+    pub id: i64,
+    pub sku_id: String,
+    pub movement_type: String,
+    pub delta: i64,
+    /// 変動後の実在庫数
+    pub stock_after: i64,
+    pub reason: String,
+    pub reference_order_id: Option<String>,
+    pub actor: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// SKUの在庫履歴DTO
+/// 現在の在庫数と、台帳をページ単位で返す
+#[derive(Debug, Clone)]
+pub struct StockHistoryDTO {
+    pub sku_id: String,
+    pub sku_code: String,
+    pub stock_quantity: u32,
+    pub reserved_quantity: u32,
+    pub movements: Vec<StockMovementDTO>,
+    pub total_count: u32,
+    pub page: u32,
+    pub per_page: u32,
+    pub has_next_page: bool,
+    pub has_previous_page: bool,
+}
+
+impl StockHistoryDTO {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sku_id: String,
+        sku_code: String,
+        stock_quantity: u32,
+        reserved_quantity: u32,
+        movements: Vec<StockMovementDTO>,
+        total_count: u32,
+        page: u32,
+        per_page: u32,
+    ) -> Self {
+        Self {
+            sku_id,
+            sku_code,
+            stock_quantity,
+            reserved_quantity,
+            movements,
+            total_count,
+            page,
+            per_page,
+            has_next_page: page * per_page < total_count,
+            has_previous_page: page > 1,
+        }
+    }
+}
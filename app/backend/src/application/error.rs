@@ -1,3 +1,4 @@
+use crate::application::gateways::PaymentGatewayError;
 use crate::domain::DomainError;
 
 #[derive(Debug)]
@@ -14,6 +15,14 @@ pub enum ApplicationError {
     InvalidInput(String),
     /// リソースが見つからない
     NotFound(String),
+    /// 決済代行会社による決済の拒否
+    PaymentDeclined(String),
+    /// 決済代行会社との通信エラー
+    PaymentGateway(PaymentGatewayError),
+    /// 認証エラー（ログイン失敗・無効なセッション）
+    Unauthorized(String),
+    /// 既存のデータとの競合（登録済みのメールアドレスなど）
+    Conflict(String),
 }
 
 #[derive(Debug)]
@@ -31,6 +40,21 @@ pub enum RepositoryError {
         /// 内部エラー詳細
         message: String,
     },
+    /// 一意制約エラー
+    UniqueConstraint {
+        /// 重複したフィールド
+        field: String,
+        /// 内部エラー詳細
+        message: String,
+    },
+    /// 在庫不足エラー（不足しているSKUごとの明細）
+    InsufficientStock(Vec<StockShortage>),
+    /// クーポンの使用回数上限エラー
+    CouponUsageLimitExceeded { code: String },
+    /// クーポンの顧客ごとの使用回数上限エラー
+    CouponCustomerLimitExceeded { code: String },
+    /// 同時更新による競合（読み込み後に他のリクエストが更新した）
+    Conflict(String),
     /// データ変換エラー
     DataConversionError(String),
     /// データベースエラー（他のリポジトリとの互換性のため）
@@ -39,6 +63,17 @@ pub enum RepositoryError {
     Unknown(String),
 }
 
+/// SKUごとの在庫不足明細
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StockShortage {
+    /// SKUコード
+    pub sku_code: String,
+    /// 注文数量
+    pub requested: u32,
+    /// 引当可能な在庫数
+    pub available: u32,
+}
+
 impl std::fmt::Display for ApplicationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -48,6 +83,10 @@ impl std::fmt::Display for ApplicationError {
             ApplicationError::Validation(msg) => write!(f, "Validation error: {}", msg),
             ApplicationError::InvalidInput(msg) => write!(f, "Invalid input error: {}", msg),
             ApplicationError::NotFound(msg) => write!(f, "Not found error: {}", msg),
+            ApplicationError::PaymentDeclined(reason) => write!(f, "Payment declined: {}", reason),
+            ApplicationError::PaymentGateway(err) => write!(f, "Payment gateway error: {}", err),
+            ApplicationError::Unauthorized(msg) => write!(f, "Unauthorized: {}", msg),
+            ApplicationError::Conflict(msg) => write!(f, "Conflict: {}", msg),
         }
     }
 }
@@ -63,6 +102,28 @@ impl std::fmt::Display for RepositoryError {
             RepositoryError::ForeignKeyConstraint { field, message } => {
                 write!(f, "Foreign key constraint error in {}: {}", field, message)
             }
+            RepositoryError::UniqueConstraint { field, message } => {
+                write!(f, "Unique constraint error in {}: {}", field, message)
+            }
+            RepositoryError::InsufficientStock(shortages) => {
+                let details: Vec<String> = shortages
+                    .iter()
+                    .map(|s| {
+                        format!(
+                            "{} (requested {}, available {})",
+                            s.sku_code, s.requested, s.available
+                        )
+                    })
+                    .collect();
+                write!(f, "Insufficient stock: {}", details.join(", "))
+            }
+            RepositoryError::CouponUsageLimitExceeded { code } => {
+                write!(f, "Coupon usage limit exceeded: {}", code)
+            }
+            RepositoryError::CouponCustomerLimitExceeded { code } => {
+                write!(f, "Coupon per-customer limit exceeded: {}", code)
+            }
+            RepositoryError::Conflict(msg) => write!(f, "Conflict: {}", msg),
             RepositoryError::DataConversionError(msg) => {
                 write!(f, "Data conversion error: {}", msg)
             }
@@ -91,8 +152,46 @@ impl RepositoryError {
                 "payment_method_id" => {
                     "指定された支払い方法が見つかりません。支払い方法を確認してください".to_string()
                 }
+                "category_id" => {
+                    "指定されたカテゴリーが見つかりません。カテゴリーを確認してください".to_string()
+                }
+                "color_id" => "指定された色が見つかりません。色を確認してください".to_string(),
+                "reference_order_id" => {
+                    "参照する注文が見つかりません。注文IDを確認してください".to_string()
+                }
+                "order_items" => {
+                    "注文履歴のあるSKUは削除できません。販売停止にしてください".to_string()
+                }
                 _ => "指定されたデータが見つかりません。入力内容を確認してください".to_string(),
             },
+            RepositoryError::UniqueConstraint { field, .. } => match field.as_str() {
+                "sku_code" => "指定されたSKUコードは既に使用されています".to_string(),
+                "coupon_code" => "指定されたクーポンコードは既に使用されています".to_string(),
+                "email" => "指定されたメールアドレスは既に登録されています".to_string(),
+                _ => "指定された値は既に使用されています".to_string(),
+            },
+            RepositoryError::InsufficientStock(shortages) => {
+                let details: Vec<String> = shortages
+                    .iter()
+                    .map(|s| {
+                        format!(
+                            "{}（注文数 {}、在庫数 {}）",
+                            s.sku_code, s.requested, s.available
+                        )
+                    })
+                    .collect();
+                format!("在庫が不足している商品があります: {}", details.join("、"))
+            }
+            RepositoryError::CouponUsageLimitExceeded { code } => {
+                format!("クーポン「{}」の使用回数が上限に達しています", code)
+            }
+            RepositoryError::CouponCustomerLimitExceeded { code } => {
+                format!("クーポン「{}」はこれ以上ご利用いただけません", code)
+            }
+            RepositoryError::Conflict(_) => {
+                "他の操作と同時に更新されました。最新の状態を確認してから再度お試しください"
+                    .to_string()
+            }
             RepositoryError::DataConversionError(_) => {
                 "データの処理中に問題が発生しました。しばらくしてから再度お試しください".to_string()
             }
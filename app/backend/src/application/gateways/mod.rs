@@ -0,0 +1,5 @@
+mod payment_gateway;
+
+pub use payment_gateway::{
+    PaymentAuthorizationRequest, PaymentGateway, PaymentGatewayError, PaymentGatewayResponse,
+};
@@ -0,0 +1,98 @@
+use crate::domain::value_objects::{Money, OrderId};
+
+/// オーソリ要求
+#[derive(Debug, Clone)]
+pub struct PaymentAuthorizationRequest {
+    pub order_id: OrderId,
+    pub order_number: String,
+    pub amount: Money,
+    /// フロントエンドで取得したカードトークンなど（決済方法によっては不要）
+    pub payment_token: Option<String>,
+}
+
+/// 決済代行会社の応答
+/// 与信不足などの拒否はエラーではなく`approved = false`として返す
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentGatewayResponse {
+    pub approved: bool,
+    /// 決済代行会社側の取引ID
+    pub transaction_id: Option<String>,
+    /// 拒否理由
+    pub decline_reason: Option<String>,
+}
+
+impl PaymentGatewayResponse {
+    pub fn approved(transaction_id: String) -> Self {
+        Self {
+            approved: true,
+            transaction_id: Some(transaction_id),
+            decline_reason: None,
+        }
+    }
+
+    pub fn declined(transaction_id: Option<String>, reason: &str) -> Self {
+        Self {
+            approved: false,
+            transaction_id,
+            decline_reason: Some(reason.to_string()),
+        }
+    }
+}
+
+/// 決済代行会社との通信エラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaymentGatewayError {
+    /// 接続できない・応答がない
+    Unavailable(String),
+    /// 要求内容が不正（存在しない取引IDなど）
+    InvalidRequest(String),
+}
+
+impl std::fmt::Display for PaymentGatewayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaymentGatewayError::Unavailable(msg) => {
+                write!(f, "Payment gateway unavailable: {}", msg)
+            }
+            PaymentGatewayError::InvalidRequest(msg) => {
+                write!(f, "Invalid payment gateway request: {}", msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PaymentGatewayError {}
+
+/// 決済代行会社（ゲートウェイ）
+/// オーソリで採番された取引IDに対して売上確定・取消・返金を行う
+#[async_trait::async_trait]
+pub trait PaymentGateway: Send + Sync {
+    /// 決済代行会社の識別子（paymentsテーブルに記録する）
+    fn provider(&self) -> &str;
+
+    /// オーソリ（与信枠の確保）
+    async fn authorize(
+        &self,
+        request: &PaymentAuthorizationRequest,
+    ) -> Result<PaymentGatewayResponse, PaymentGatewayError>;
+
+    /// 売上確定
+    async fn capture(
+        &self,
+        transaction_id: &str,
+        amount: Money,
+    ) -> Result<PaymentGatewayResponse, PaymentGatewayError>;
+
+    /// オーソリの取消
+    async fn void(
+        &self,
+        transaction_id: &str,
+    ) -> Result<PaymentGatewayResponse, PaymentGatewayError>;
+
+    /// 返金
+    async fn refund(
+        &self,
+        transaction_id: &str,
+        amount: Money,
+    ) -> Result<PaymentGatewayResponse, PaymentGatewayError>;
+}
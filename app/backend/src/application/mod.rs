@@ -1,9 +1,13 @@
 pub mod commands;
 pub mod dispatcher;
+pub mod documents;
 pub mod dto;
 pub mod error;
+pub mod gateways;
 pub mod queries;
 pub mod repositories;
+pub mod security;
+pub mod services;
 
 pub use dispatcher::Dispatcher;
 pub use error::ApplicationError;
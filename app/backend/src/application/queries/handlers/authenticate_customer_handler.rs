@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::application::dto::CustomerDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::AuthenticateCustomerQuery;
+use crate::application::repositories::CustomerRepository;
+use crate::application::security::SessionTokenSigner;
+
+/// セッショントークン認証ハンドラ
+/// 署名・有効期限・失効を確認し、トークンの会員を返す
+pub struct AuthenticateCustomerHandler {
+    customer_repository: Arc<dyn CustomerRepository>,
+    token_signer: Arc<dyn SessionTokenSigner>,
+}
+
+impl AuthenticateCustomerHandler {
+    pub fn new(
+        customer_repository: Arc<dyn CustomerRepository>,
+        token_signer: Arc<dyn SessionTokenSigner>,
+    ) -> Self {
+        Self {
+            customer_repository,
+            token_signer,
+        }
+    }
+
+    pub async fn handle(
+        &self,
+        query: AuthenticateCustomerQuery,
+    ) -> Result<CustomerDTO, ApplicationError> {
+        let invalid_session = || ApplicationError::Unauthorized("Invalid session".to_string());
+
+        let session_id = self
+            .token_signer
+            .verify(&query.token)
+            .ok_or_else(invalid_session)?;
+
+        let session = self
+            .customer_repository
+            .find_session(&session_id)
+            .await?
+            .filter(|session| session.is_valid_at(Utc::now()))
+            .ok_or_else(invalid_session)?;
+
+        let customer = self
+            .customer_repository
+            .find_by_id(&session.customer_id())
+            .await?
+            .ok_or_else(invalid_session)?;
+
+        Ok(CustomerDTO::from_customer(&customer))
+    }
+}
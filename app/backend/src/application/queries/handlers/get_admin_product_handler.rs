@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use crate::application::dto::AdminProductDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::GetAdminProductQuery;
+use crate::application::repositories::ProductWriteRepository;
+
+/// 管理用商品取得クエリハンドラ
+pub struct GetAdminProductHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+}
+
+impl GetAdminProductHandler {
+    pub fn new(product_repository: Arc<dyn ProductWriteRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// 商品集約を取得して管理用DTOに変換
+    pub async fn handle(
+        &self,
+        query: GetAdminProductQuery,
+    ) -> Result<AdminProductDTO, ApplicationError> {
+        println!(
+            "->> get_admin_product_handler: product_id={}",
+            query.product_id
+        );
+
+        let product = self
+            .product_repository
+            .find_by_id(&query.product_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Product not found: {}", query.product_id))
+            })?;
+
+        Ok(AdminProductDTO::from_product(&product))
+    }
+}
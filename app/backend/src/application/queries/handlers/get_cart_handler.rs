@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::application::dto::CartDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::GetCartQuery;
+use crate::application::repositories::CartRepository;
+use crate::application::services::CartPricingService;
+
+/// カート取得クエリハンドラ
+pub struct GetCartHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    cart_pricing_service: Arc<CartPricingService>,
+}
+
+impl GetCartHandler {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository>,
+        cart_pricing_service: Arc<CartPricingService>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            cart_pricing_service,
+        }
+    }
+
+    /// カート取得クエリを実行（現在の価格で合計を再計算）
+    pub async fn handle(&self, query: GetCartQuery) -> Result<CartDTO, ApplicationError> {
+        println!("->> get_cart_handler: cart_id={}", query.cart_id);
+
+        let cart = self
+            .cart_repository
+            .find_by_id(&query.cart_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Cart not found: {}", query.cart_id))
+            })?;
+
+        self.cart_pricing_service.price(&cart).await
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::application::dto::CouponDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::GetCouponQuery;
+use crate::application::repositories::CouponRepository;
+
+/// 管理用クーポン取得クエリハンドラ
+pub struct GetCouponHandler {
+    coupon_repository: Arc<dyn CouponRepository>,
+}
+
+impl GetCouponHandler {
+    pub fn new(coupon_repository: Arc<dyn CouponRepository>) -> Self {
+        Self { coupon_repository }
+    }
+
+    /// クーポンを取得して管理用DTOに変換
+    pub async fn handle(&self, query: GetCouponQuery) -> Result<CouponDTO, ApplicationError> {
+        println!(
+            "->> get_coupon_handler: coupon_id={}",
+            query.coupon_id.value()
+        );
+
+        let coupon = self
+            .coupon_repository
+            .find_by_id(&query.coupon_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Coupon not found: {}", query.coupon_id.value()))
+            })?;
+
+        Ok(CouponDTO::from_coupon(&coupon))
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::application::dto::CouponListDTO;
+use crate::application::error::ApplicationError;
+use crate::application::repositories::CouponRepository;
+
+/// 管理用クーポン一覧取得クエリハンドラ
+pub struct GetCouponListHandler {
+    coupon_repository: Arc<dyn CouponRepository>,
+}
+
+impl GetCouponListHandler {
+    pub fn new(coupon_repository: Arc<dyn CouponRepository>) -> Self {
+        Self { coupon_repository }
+    }
+
+    /// 有効期間外のクーポンも含めてすべて取得
+    pub async fn handle(&self) -> Result<CouponListDTO, ApplicationError> {
+        println!("->> get_coupon_list_handler");
+
+        let coupons = self.coupon_repository.find_all().await?;
+
+        Ok(CouponListDTO::from_coupons(&coupons))
+    }
+}
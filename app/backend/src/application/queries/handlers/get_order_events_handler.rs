@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::application::dto::OrderEventDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::GetOrderEventsQuery;
+use crate::application::repositories::OrderRepository;
+
+/// 注文イベント履歴取得クエリハンドラ
+pub struct GetOrderEventsHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl GetOrderEventsHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// 注文イベント履歴取得クエリを実行
+    pub async fn handle(
+        &self,
+        query: GetOrderEventsQuery,
+    ) -> Result<Vec<OrderEventDTO>, ApplicationError> {
+        println!("->> get_order_events_handler: order_id={}", query.order_id);
+
+        // 存在しない注文は空の履歴ではなく404として扱う
+        if self
+            .order_repository
+            .find_by_id(&query.order_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::NotFound(format!(
+                "Order not found: {}",
+                query.order_id
+            )));
+        }
+
+        let events = self
+            .order_repository
+            .find_events_by_order_id(&query.order_id)
+            .await?;
+
+        Ok(events)
+    }
+}
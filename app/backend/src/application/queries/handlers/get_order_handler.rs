@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::application::dto::OrderDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::{GetOrderQuery, OrderLookup};
+use crate::application::repositories::OrderRepository;
+
+/// 注文取得クエリハンドラ
+pub struct GetOrderHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl GetOrderHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// 注文取得クエリを実行
+    pub async fn handle(&self, query: GetOrderQuery) -> Result<OrderDTO, ApplicationError> {
+        println!("->> get_order_handler: lookup={:?}", query.lookup);
+
+        let order = match &query.lookup {
+            OrderLookup::Id(order_id) => self.order_repository.find_by_id(order_id).await?,
+            OrderLookup::OrderNumber(order_number) => {
+                self.order_repository
+                    .find_by_order_number(order_number)
+                    .await?
+            }
+        };
+
+        let order = order.ok_or_else(|| {
+            let key = match &query.lookup {
+                OrderLookup::Id(order_id) => order_id.to_string(),
+                OrderLookup::OrderNumber(order_number) => order_number.to_string(),
+            };
+            ApplicationError::NotFound(format!("Order not found: {}", key))
+        })?;
+
+        Ok(OrderDTO::from_order(&order))
+    }
+}
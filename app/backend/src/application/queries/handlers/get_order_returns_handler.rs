@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use crate::application::dto::ReturnDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::GetOrderReturnsQuery;
+use crate::application::repositories::{OrderRepository, ReturnRepository};
+
+/// 注文の返品一覧取得クエリハンドラ
+pub struct GetOrderReturnsHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    return_repository: Arc<dyn ReturnRepository>,
+}
+
+impl GetOrderReturnsHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        return_repository: Arc<dyn ReturnRepository>,
+    ) -> Self {
+        Self {
+            order_repository,
+            return_repository,
+        }
+    }
+
+    /// 注文の返品一覧取得クエリを実行
+    pub async fn handle(
+        &self,
+        query: GetOrderReturnsQuery,
+    ) -> Result<Vec<ReturnDTO>, ApplicationError> {
+        println!("->> get_order_returns_handler: order_id={}", query.order_id);
+
+        // 存在しない注文は空の一覧ではなく404として扱う
+        if self
+            .order_repository
+            .find_by_id(&query.order_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::NotFound(format!(
+                "Order not found: {}",
+                query.order_id
+            )));
+        }
+
+        let returns = self
+            .return_repository
+            .find_by_order_id(&query.order_id)
+            .await?;
+
+        Ok(returns.iter().map(ReturnDTO::from_return).collect())
+    }
+}
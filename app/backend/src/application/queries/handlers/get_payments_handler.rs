@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::application::dto::PaymentHistoryDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::GetPaymentsQuery;
+use crate::application::repositories::{OrderRepository, PaymentRepository};
+
+/// 注文の決済履歴取得クエリハンドラ
+pub struct GetPaymentsHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    payment_repository: Arc<dyn PaymentRepository>,
+}
+
+impl GetPaymentsHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        payment_repository: Arc<dyn PaymentRepository>,
+    ) -> Self {
+        Self {
+            order_repository,
+            payment_repository,
+        }
+    }
+
+    /// 注文の決済履歴取得クエリを実行
+    pub async fn handle(
+        &self,
+        query: GetPaymentsQuery,
+    ) -> Result<PaymentHistoryDTO, ApplicationError> {
+        println!("->> get_payments_handler: order_id={}", query.order_id);
+
+        // 存在しない注文は空の履歴ではなく404として扱う
+        if self
+            .order_repository
+            .find_by_id(&query.order_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::NotFound(format!(
+                "Order not found: {}",
+                query.order_id
+            )));
+        }
+
+        let transactions = self
+            .payment_repository
+            .find_by_order_id(&query.order_id)
+            .await?;
+
+        Ok(PaymentHistoryDTO::from_transactions(
+            query.order_id.to_string(),
+            &transactions,
+        ))
+    }
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::application::dto::ProductListDTO;
 use crate::application::error::ApplicationError;
+use crate::application::queries::models::GetProductListQuery;
 use crate::application::repositories::ProductRepository;
 
 /// 商品リスト取得クエリハンドラ
@@ -17,12 +18,21 @@ impl GetProductListHandler {
 
     /// 商品リスト取得クエリを実行
     ///
+    /// # Arguments
+    /// * `query` - 絞り込み条件・並び順・ページング
+    ///
     /// # Returns
     /// * `Result<ProductListDTO, ApplicationError>` - 成功時は商品リストデータ、失敗時はエラー
-    pub async fn handle(&self) -> Result<ProductListDTO, ApplicationError> {
-        println!("->> get_product_list_handler");
+    pub async fn handle(
+        &self,
+        query: GetProductListQuery,
+    ) -> Result<ProductListDTO, ApplicationError> {
+        println!("->> get_product_list_handler: {:?}", query);
 
-        let product_list = self.product_repository.find_all().await?;
+        let product_list = self
+            .product_repository
+            .find_all(&query.to_criteria())
+            .await?;
 
         Ok(product_list)
     }
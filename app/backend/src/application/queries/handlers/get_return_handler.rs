@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::application::dto::ReturnDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::GetReturnQuery;
+use crate::application::repositories::ReturnRepository;
+
+/// 返品取得クエリハンドラ
+pub struct GetReturnHandler {
+    return_repository: Arc<dyn ReturnRepository>,
+}
+
+impl GetReturnHandler {
+    pub fn new(return_repository: Arc<dyn ReturnRepository>) -> Self {
+        Self { return_repository }
+    }
+
+    /// 返品取得クエリを実行
+    pub async fn handle(&self, query: GetReturnQuery) -> Result<ReturnDTO, ApplicationError> {
+        println!("->> get_return_handler: return_id={}", query.return_id);
+
+        let return_request = self
+            .return_repository
+            .find_by_id(&query.return_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Return not found: {}", query.return_id))
+            })?;
+
+        Ok(ReturnDTO::from_return(&return_request))
+    }
+}
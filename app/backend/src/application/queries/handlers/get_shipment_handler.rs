@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::application::dto::ShipmentDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::GetShipmentQuery;
+use crate::application::repositories::DeliveryInfoRepository;
+
+/// 出荷（配送情報）取得クエリハンドラ
+pub struct GetShipmentHandler {
+    delivery_info_repository: Arc<dyn DeliveryInfoRepository>,
+}
+
+impl GetShipmentHandler {
+    pub fn new(delivery_info_repository: Arc<dyn DeliveryInfoRepository>) -> Self {
+        Self {
+            delivery_info_repository,
+        }
+    }
+
+    /// 出荷取得クエリを実行
+    pub async fn handle(&self, query: GetShipmentQuery) -> Result<ShipmentDTO, ApplicationError> {
+        println!(
+            "->> get_shipment_handler: shipment_id={}",
+            query.shipment_id
+        );
+
+        let (order_id, delivery_info) = self
+            .delivery_info_repository
+            .find_by_id(&query.shipment_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Shipment not found: {}", query.shipment_id))
+            })?;
+
+        Ok(ShipmentDTO::from_delivery_info(&order_id, &delivery_info))
+    }
+}
@@ -82,12 +82,20 @@ mod tests {
                 name: "標準配送".to_string(),
                 description: "5-7営業日".to_string(),
                 price: 500,
+                free_shipping_threshold: None,
+                quick_ship_only: false,
+                region_rates: Vec::new(),
+                oversize_surcharge: None,
             },
             ShippingMethodDTO {
                 id: "express".to_string(),
                 name: "速達配送".to_string(),
                 description: "2-3営業日".to_string(),
                 price: 1000,
+                free_shipping_threshold: None,
+                quick_ship_only: false,
+                region_rates: Vec::new(),
+                oversize_surcharge: None,
             },
         ]);
 
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use crate::application::dto::StockHistoryDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::GetStockHistoryQuery;
+use crate::application::repositories::{ProductWriteRepository, StockMovementRepository};
+
+/// SKUの在庫履歴取得クエリハンドラ
+pub struct GetStockHistoryHandler {
+    product_repository: Arc<dyn ProductWriteRepository>,
+    stock_movement_repository: Arc<dyn StockMovementRepository>,
+}
+
+impl GetStockHistoryHandler {
+    pub fn new(
+        product_repository: Arc<dyn ProductWriteRepository>,
+        stock_movement_repository: Arc<dyn StockMovementRepository>,
+    ) -> Self {
+        Self {
+            product_repository,
+            stock_movement_repository,
+        }
+    }
+
+    /// 現在の在庫数と在庫台帳（新しい順）を取得
+    pub async fn handle(
+        &self,
+        query: GetStockHistoryQuery,
+    ) -> Result<StockHistoryDTO, ApplicationError> {
+        println!("->> get_stock_history_handler: sku_id={}", query.sku_id);
+
+        let not_found = || ApplicationError::NotFound(format!("SKU not found: {}", query.sku_id));
+        let product = self
+            .product_repository
+            .find_by_sku_id(&query.sku_id)
+            .await?
+            .ok_or_else(not_found)?;
+        let sku = product
+            .skus()
+            .iter()
+            .find(|sku| sku.id() == &query.sku_id)
+            .ok_or_else(not_found)?;
+
+        let movements = self
+            .stock_movement_repository
+            .find_by_sku_id(&query.sku_id, query.per_page, query.offset())
+            .await?;
+        let total_count = self
+            .stock_movement_repository
+            .count_by_sku_id(&query.sku_id)
+            .await?;
+
+        Ok(StockHistoryDTO::new(
+            query.sku_id.to_string(),
+            sku.sku_code().value().to_string(),
+            sku.total_quantity(),
+            sku.reserved_quantity(),
+            movements,
+            total_count,
+            query.page,
+            query.per_page,
+        ))
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::application::dto::{OrderDTO, OrderListDTO};
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::ListOrdersQuery;
+use crate::application::repositories::OrderRepository;
+
+/// 注文一覧取得クエリハンドラ
+pub struct ListOrdersHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl ListOrdersHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// 注文一覧取得クエリを実行
+    pub async fn handle(&self, query: ListOrdersQuery) -> Result<OrderListDTO, ApplicationError> {
+        if matches!(
+            (&query.created_from, &query.created_to),
+            (Some(from), Some(to)) if from >= to
+        ) {
+            return Err(ApplicationError::InvalidInput(
+                "created_from must be earlier than created_to".to_string(),
+            ));
+        }
+
+        let (orders, total_count) = self.order_repository.find_all(&query.to_criteria()).await?;
+
+        let orders = orders.iter().map(OrderDTO::from_order).collect();
+
+        Ok(OrderListDTO::new(
+            orders,
+            total_count,
+            query.page,
+            query.per_page,
+        ))
+    }
+}
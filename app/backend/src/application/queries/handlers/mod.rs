@@ -1,15 +1,43 @@
+mod authenticate_customer_handler;
 mod find_variants_handler;
+mod get_admin_product_handler;
+mod get_cart_handler;
 mod get_category_list_handler;
 mod get_color_list_handler;
+mod get_coupon_handler;
+mod get_coupon_list_handler;
+mod get_order_events_handler;
+mod get_order_handler;
+mod get_order_returns_handler;
 mod get_payment_method_list_handler;
+mod get_payments_handler;
 mod get_product_handler;
 mod get_product_list_handler;
+mod get_return_handler;
+mod get_shipment_handler;
 mod get_shipping_method_list_handler;
+mod get_stock_history_handler;
+mod list_orders_handler;
+mod search_products_handler;
 
+pub use authenticate_customer_handler::AuthenticateCustomerHandler;
 pub use find_variants_handler::FindVariantsHandler;
+pub use get_admin_product_handler::GetAdminProductHandler;
+pub use get_cart_handler::GetCartHandler;
 pub use get_category_list_handler::GetCategoryListHandler;
 pub use get_color_list_handler::GetColorListHandler;
+pub use get_coupon_handler::GetCouponHandler;
+pub use get_coupon_list_handler::GetCouponListHandler;
+pub use get_order_events_handler::GetOrderEventsHandler;
+pub use get_order_handler::GetOrderHandler;
+pub use get_order_returns_handler::GetOrderReturnsHandler;
 pub use get_payment_method_list_handler::GetPaymentMethodListHandler;
+pub use get_payments_handler::GetPaymentsHandler;
 pub use get_product_handler::GetProductHandler;
 pub use get_product_list_handler::GetProductListHandler;
+pub use get_return_handler::GetReturnHandler;
+pub use get_shipment_handler::GetShipmentHandler;
 pub use get_shipping_method_list_handler::GetShippingMethodListHandler;
+pub use get_stock_history_handler::GetStockHistoryHandler;
+pub use list_orders_handler::ListOrdersHandler;
+pub use search_products_handler::SearchProductsHandler;
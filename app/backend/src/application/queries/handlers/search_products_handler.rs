@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::application::dto::ProductSearchResultDTO;
+use crate::application::error::ApplicationError;
+use crate::application::queries::models::SearchProductsQuery;
+use crate::application::repositories::ProductRepository;
+
+/// 商品キーワード検索クエリハンドラ
+/// CQRS パターンに基づく読み取り操作のハンドラ
+pub struct SearchProductsHandler {
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+}
+
+impl SearchProductsHandler {
+    pub fn new(product_repository: Arc<dyn ProductRepository + Send + Sync>) -> Self {
+        Self { product_repository }
+    }
+
+    /// 商品キーワード検索クエリを実行
+    ///
+    /// # Arguments
+    /// * `query` - 検索キーワードとページング
+    ///
+    /// # Returns
+    /// * `Result<ProductSearchResultDTO, ApplicationError>` - 成功時は関連度順の検索結果、失敗時はエラー
+    pub async fn handle(
+        &self,
+        query: SearchProductsQuery,
+    ) -> Result<ProductSearchResultDTO, ApplicationError> {
+        println!("->> search_products_handler: {:?}", query);
+
+        let result = self.product_repository.search(&query.to_criteria()).await?;
+
+        Ok(result)
+    }
+}
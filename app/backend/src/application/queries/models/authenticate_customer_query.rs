@@ -0,0 +1,11 @@
+/// セッショントークンから会員を特定するクエリ
+#[derive(Debug, Clone)]
+pub struct AuthenticateCustomerQuery {
+    pub token: String,
+}
+
+impl AuthenticateCustomerQuery {
+    pub fn new(token: String) -> Self {
+        Self { token }
+    }
+}
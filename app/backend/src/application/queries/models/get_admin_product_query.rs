@@ -0,0 +1,23 @@
+use uuid::Uuid;
+
+use crate::application::error::ApplicationError;
+use crate::domain::ProductId;
+
+/// 管理用商品取得クエリ
+/// 非公開の商品・販売停止中のSKUも対象とする
+#[derive(Debug, Clone)]
+pub struct GetAdminProductQuery {
+    pub product_id: ProductId,
+}
+
+impl GetAdminProductQuery {
+    pub fn new(product_id: String) -> Result<Self, ApplicationError> {
+        let uuid = Uuid::parse_str(&product_id).map_err(|_| {
+            ApplicationError::InvalidInput(format!("Invalid product ID format: {}", product_id))
+        })?;
+
+        Ok(Self {
+            product_id: ProductId::from_uuid(uuid),
+        })
+    }
+}
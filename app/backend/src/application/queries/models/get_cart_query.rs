@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+use crate::application::error::ApplicationError;
+use crate::domain::CartId;
+
+/// カート取得クエリ
+#[derive(Debug, Clone)]
+pub struct GetCartQuery {
+    pub cart_id: CartId,
+}
+
+impl GetCartQuery {
+    pub fn new(cart_id: String) -> Result<Self, ApplicationError> {
+        let uuid = Uuid::parse_str(&cart_id).map_err(|_| {
+            ApplicationError::InvalidInput(format!("Invalid cart ID format: {}", cart_id))
+        })?;
+
+        Ok(Self {
+            cart_id: CartId::from_uuid(uuid),
+        })
+    }
+}
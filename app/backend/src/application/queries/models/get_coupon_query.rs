@@ -0,0 +1,23 @@
+use uuid::Uuid;
+
+use crate::application::error::ApplicationError;
+use crate::domain::value_objects::CouponId;
+
+/// 管理用クーポン取得クエリ
+/// 有効期間外のクーポンも対象とする
+#[derive(Debug, Clone)]
+pub struct GetCouponQuery {
+    pub coupon_id: CouponId,
+}
+
+impl GetCouponQuery {
+    pub fn new(coupon_id: String) -> Result<Self, ApplicationError> {
+        let uuid = Uuid::parse_str(&coupon_id).map_err(|_| {
+            ApplicationError::InvalidInput(format!("Invalid coupon ID format: {}", coupon_id))
+        })?;
+
+        Ok(Self {
+            coupon_id: CouponId::from_uuid(uuid),
+        })
+    }
+}
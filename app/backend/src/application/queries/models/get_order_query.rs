@@ -0,0 +1,59 @@
+use uuid::Uuid;
+
+use crate::application::error::ApplicationError;
+use crate::domain::{OrderId, OrderNumber};
+
+/// 注文の検索キー
+#[derive(Debug, Clone)]
+pub enum OrderLookup {
+    /// 注文IDで検索
+    Id(OrderId),
+    /// 注文番号で検索
+    OrderNumber(OrderNumber),
+}
+
+/// 注文取得クエリ
+#[derive(Debug, Clone)]
+pub struct GetOrderQuery {
+    pub lookup: OrderLookup,
+}
+
+impl GetOrderQuery {
+    /// UUID形式であれば注文ID、それ以外は注文番号として扱う
+    pub fn new(id_or_order_number: String) -> Result<Self, ApplicationError> {
+        let lookup = match Uuid::parse_str(&id_or_order_number) {
+            Ok(uuid) => OrderLookup::Id(OrderId::from_uuid(uuid)),
+            Err(_) => OrderLookup::OrderNumber(
+                OrderNumber::from_string(id_or_order_number)
+                    .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?,
+            ),
+        };
+
+        Ok(Self { lookup })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_with_uuid() {
+        let query = GetOrderQuery::new("550e8400-e29b-41d4-a716-446655440000".to_string()).unwrap();
+        assert!(matches!(query.lookup, OrderLookup::Id(_)));
+    }
+
+    #[test]
+    fn test_new_with_order_number() {
+        let query = GetOrderQuery::new("ORD-2024-000001".to_string()).unwrap();
+        match query.lookup {
+            OrderLookup::OrderNumber(number) => assert_eq!(number.value(), "ORD-2024-000001"),
+            _ => panic!("expected order number lookup"),
+        }
+    }
+
+    #[test]
+    fn test_new_with_empty_value() {
+        assert!(GetOrderQuery::new("".to_string()).is_err());
+    }
+}
@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+use crate::application::error::ApplicationError;
+use crate::domain::OrderId;
+
+/// 注文の決済履歴取得クエリ
+#[derive(Debug, Clone)]
+pub struct GetPaymentsQuery {
+    pub order_id: OrderId,
+}
+
+impl GetPaymentsQuery {
+    pub fn new(order_id: String) -> Result<Self, ApplicationError> {
+        let uuid = Uuid::parse_str(&order_id).map_err(|_| {
+            ApplicationError::InvalidInput(format!("Invalid order ID format: {}", order_id))
+        })?;
+
+        Ok(Self {
+            order_id: OrderId::from_uuid(uuid),
+        })
+    }
+}
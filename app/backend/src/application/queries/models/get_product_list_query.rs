@@ -0,0 +1,89 @@
+use crate::application::repositories::{ProductSearchCriteria, ProductSortOrder};
+
+/// 商品一覧取得クエリ
+#[derive(Debug, Clone)]
+pub struct GetProductListQuery {
+    pub category: Option<String>,
+    pub color: Option<String>,
+    pub min_price: Option<u32>,
+    pub max_price: Option<u32>,
+    pub tag: Option<String>,
+    pub is_best_seller: Option<bool>,
+    pub is_quick_ship: Option<bool>,
+    pub in_stock: Option<bool>,
+    pub sort: ProductSortOrder,
+    pub page: u32,
+    /// 1ページあたりの件数（ページを指定しない場合はNoneで全件）
+    pub per_page: Option<u32>,
+}
+
+impl GetProductListQuery {
+    pub const DEFAULT_PER_PAGE: u32 = 20;
+    pub const MAX_PER_PAGE: u32 = 100;
+
+    pub fn new(page: Option<u32>, per_page: Option<u32>) -> Self {
+        Self {
+            category: None,
+            color: None,
+            min_price: None,
+            max_price: None,
+            tag: None,
+            is_best_seller: None,
+            is_quick_ship: None,
+            in_stock: None,
+            sort: ProductSortOrder::default(),
+            page: page.unwrap_or(1).max(1),
+            // ページを指定しない場合は従来どおり全件を返す（フロントエンドはページングせずに取得する）
+            per_page: (page.is_some() || per_page.is_some()).then(|| {
+                per_page
+                    .unwrap_or(Self::DEFAULT_PER_PAGE)
+                    .clamp(1, Self::MAX_PER_PAGE)
+            }),
+        }
+    }
+
+    /// リポジトリの検索条件に変換
+    pub fn to_criteria(&self) -> ProductSearchCriteria {
+        ProductSearchCriteria {
+            category: self.category.clone(),
+            color: self.color.clone(),
+            min_price: self.min_price,
+            max_price: self.max_price,
+            tag: self.tag.clone(),
+            is_best_seller: self.is_best_seller,
+            is_quick_ship: self.is_quick_ship,
+            in_stock: self.in_stock,
+            sort: self.sort,
+            page: self.page,
+            per_page: self.per_page,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_defaults() {
+        let query = GetProductListQuery::new(None, None);
+        assert_eq!(query.page, 1);
+        assert_eq!(query.per_page, None);
+        assert_eq!(query.sort, ProductSortOrder::DisplayOrder);
+    }
+
+    #[test]
+    fn test_new_with_page_only_uses_default_per_page() {
+        let query = GetProductListQuery::new(Some(2), None);
+        assert_eq!(query.page, 2);
+        assert_eq!(query.per_page, Some(GetProductListQuery::DEFAULT_PER_PAGE));
+    }
+
+    #[test]
+    fn test_new_clamps_paging() {
+        let query = GetProductListQuery::new(Some(0), Some(1000));
+        assert_eq!(query.page, 1);
+        assert_eq!(query.per_page, Some(GetProductListQuery::MAX_PER_PAGE));
+        assert_eq!(query.to_criteria().offset(), 0);
+    }
+}
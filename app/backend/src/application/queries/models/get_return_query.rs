@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+use crate::application::error::ApplicationError;
+use crate::domain::value_objects::ReturnId;
+
+/// 返品取得クエリ
+#[derive(Debug, Clone)]
+pub struct GetReturnQuery {
+    pub return_id: ReturnId,
+}
+
+impl GetReturnQuery {
+    pub fn new(return_id: String) -> Result<Self, ApplicationError> {
+        let uuid = Uuid::parse_str(&return_id).map_err(|_| {
+            ApplicationError::InvalidInput(format!("Invalid return ID format: {}", return_id))
+        })?;
+
+        Ok(Self {
+            return_id: ReturnId::from_uuid(uuid),
+        })
+    }
+}
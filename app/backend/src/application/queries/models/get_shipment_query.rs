@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+use crate::application::error::ApplicationError;
+use crate::domain::DeliveryInfoId;
+
+/// 出荷（配送情報）取得クエリ
+#[derive(Debug, Clone)]
+pub struct GetShipmentQuery {
+    pub shipment_id: DeliveryInfoId,
+}
+
+impl GetShipmentQuery {
+    pub fn new(shipment_id: String) -> Result<Self, ApplicationError> {
+        let uuid = Uuid::parse_str(&shipment_id).map_err(|_| {
+            ApplicationError::InvalidInput(format!("Invalid shipment ID format: {}", shipment_id))
+        })?;
+
+        Ok(Self {
+            shipment_id: DeliveryInfoId::from_uuid(uuid),
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use uuid::Uuid;
+
+use crate::application::error::ApplicationError;
+use crate::domain::SKUId;
+
+/// SKUの在庫履歴取得クエリ
+#[derive(Debug, Clone)]
+pub struct GetStockHistoryQuery {
+    pub sku_id: SKUId,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl GetStockHistoryQuery {
+    pub const DEFAULT_PER_PAGE: u32 = 50;
+    pub const MAX_PER_PAGE: u32 = 200;
+
+    pub fn new(
+        sku_id: String,
+        page: Option<u32>,
+        per_page: Option<u32>,
+    ) -> Result<Self, ApplicationError> {
+        let uuid = Uuid::parse_str(&sku_id).map_err(|_| {
+            ApplicationError::InvalidInput(format!("Invalid SKU ID format: {}", sku_id))
+        })?;
+
+        Ok(Self {
+            sku_id: SKUId::from_uuid(uuid),
+            page: page.unwrap_or(1).max(1),
+            per_page: per_page
+                .unwrap_or(Self::DEFAULT_PER_PAGE)
+                .clamp(1, Self::MAX_PER_PAGE),
+        })
+    }
+
+    pub fn offset(&self) -> u32 {
+        (self.page - 1) * self.per_page
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SKU_ID: &str = "550e8400-e29b-41d4-a716-446655440001";
+
+    #[test]
+    fn test_new_defaults() {
+        let query = GetStockHistoryQuery::new(SKU_ID.to_string(), None, None).unwrap();
+        assert_eq!(query.page, 1);
+        assert_eq!(query.per_page, GetStockHistoryQuery::DEFAULT_PER_PAGE);
+        assert_eq!(query.offset(), 0);
+    }
+
+    #[test]
+    fn test_new_clamps_paging() {
+        let query = GetStockHistoryQuery::new(SKU_ID.to_string(), Some(3), Some(1000)).unwrap();
+        assert_eq!(query.per_page, GetStockHistoryQuery::MAX_PER_PAGE);
+        assert_eq!(query.offset(), 2 * GetStockHistoryQuery::MAX_PER_PAGE);
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_sku_id() {
+        assert!(GetStockHistoryQuery::new("not-a-uuid".to_string(), None, None).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+
+use crate::application::repositories::OrderSearchCriteria;
+use crate::domain::aggregates::order::OrderStatus;
+use crate::domain::value_objects::CustomerId;
+
+/// 注文一覧取得クエリ
+#[derive(Debug, Clone)]
+pub struct ListOrdersQuery {
+    pub status: Option<OrderStatus>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub customer_email: Option<String>,
+    pub customer_id: Option<CustomerId>,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl ListOrdersQuery {
+    pub const DEFAULT_PER_PAGE: u32 = 20;
+    pub const MAX_PER_PAGE: u32 = 100;
+
+    pub fn new(page: Option<u32>, per_page: Option<u32>) -> Self {
+        Self {
+            status: None,
+            created_from: None,
+            created_to: None,
+            customer_email: None,
+            customer_id: None,
+            page: page.unwrap_or(1).max(1),
+            per_page: per_page
+                .unwrap_or(Self::DEFAULT_PER_PAGE)
+                .clamp(1, Self::MAX_PER_PAGE),
+        }
+    }
+
+    /// リポジトリの検索条件に変換
+    pub fn to_criteria(&self) -> OrderSearchCriteria {
+        OrderSearchCriteria {
+            status: self.status.clone(),
+            created_from: self.created_from,
+            created_to: self.created_to,
+            customer_email: self.customer_email.clone(),
+            customer_id: self.customer_id,
+            page: self.page,
+            per_page: self.per_page,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_defaults() {
+        let query = ListOrdersQuery::new(None, None);
+        assert_eq!(query.page, 1);
+        assert_eq!(query.per_page, ListOrdersQuery::DEFAULT_PER_PAGE);
+    }
+
+    #[test]
+    fn test_new_clamps_paging() {
+        let query = ListOrdersQuery::new(Some(0), Some(1000));
+        assert_eq!(query.page, 1);
+        assert_eq!(query.per_page, ListOrdersQuery::MAX_PER_PAGE);
+        assert_eq!(query.to_criteria().offset(), 0);
+    }
+}
@@ -1,5 +1,33 @@
+mod authenticate_customer_query;
 mod find_variants_query;
+mod get_admin_product_query;
+mod get_cart_query;
+mod get_coupon_query;
+mod get_order_events_query;
+mod get_order_query;
+mod get_order_returns_query;
+mod get_payments_query;
+mod get_product_list_query;
 mod get_product_query;
+mod get_return_query;
+mod get_shipment_query;
+mod get_stock_history_query;
+mod list_orders_query;
+mod search_products_query;
 
+pub use authenticate_customer_query::AuthenticateCustomerQuery;
 pub use find_variants_query::FindVariantsQuery;
+pub use get_admin_product_query::GetAdminProductQuery;
+pub use get_cart_query::GetCartQuery;
+pub use get_coupon_query::GetCouponQuery;
+pub use get_order_events_query::GetOrderEventsQuery;
+pub use get_order_query::{GetOrderQuery, OrderLookup};
+pub use get_order_returns_query::GetOrderReturnsQuery;
+pub use get_payments_query::GetPaymentsQuery;
+pub use get_product_list_query::GetProductListQuery;
 pub use get_product_query::GetProductQuery;
+pub use get_return_query::GetReturnQuery;
+pub use get_shipment_query::GetShipmentQuery;
+pub use get_stock_history_query::GetStockHistoryQuery;
+pub use list_orders_query::ListOrdersQuery;
+pub use search_products_query::SearchProductsQuery;
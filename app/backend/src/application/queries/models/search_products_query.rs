@@ -0,0 +1,100 @@
+use crate::application::repositories::ProductKeywordSearchCriteria;
+
+/// 商品キーワード検索クエリ
+#[derive(Debug, Clone)]
+pub struct SearchProductsQuery {
+    pub terms: Vec<String>,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl SearchProductsQuery {
+    pub const DEFAULT_PER_PAGE: u32 = 20;
+    pub const MAX_PER_PAGE: u32 = 100;
+    pub const MAX_KEYWORD_LENGTH: usize = 100;
+    pub const MAX_TERMS: usize = 10;
+
+    /// キーワードを空白（全角スペースを含む）で分割して検索クエリを作成
+    pub fn new(keyword: &str, page: Option<u32>, per_page: Option<u32>) -> Result<Self, String> {
+        let keyword = keyword.trim();
+        if keyword.is_empty() {
+            return Err("Search keyword must not be empty".to_string());
+        }
+        if keyword.chars().count() > Self::MAX_KEYWORD_LENGTH {
+            return Err(format!(
+                "Search keyword must be at most {} characters",
+                Self::MAX_KEYWORD_LENGTH
+            ));
+        }
+
+        let mut terms: Vec<String> = Vec::new();
+        for term in keyword.split_whitespace() {
+            if !terms.iter().any(|t| t == term) {
+                terms.push(term.to_string());
+            }
+        }
+        if terms.len() > Self::MAX_TERMS {
+            return Err(format!(
+                "Search keyword must contain at most {} terms",
+                Self::MAX_TERMS
+            ));
+        }
+
+        Ok(Self {
+            terms,
+            page: page.unwrap_or(1).max(1),
+            per_page: per_page
+                .unwrap_or(Self::DEFAULT_PER_PAGE)
+                .clamp(1, Self::MAX_PER_PAGE),
+        })
+    }
+
+    /// リポジトリの検索条件に変換
+    pub fn to_criteria(&self) -> ProductKeywordSearchCriteria {
+        ProductKeywordSearchCriteria {
+            terms: self.terms.clone(),
+            page: self.page,
+            per_page: self.per_page,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_splits_terms() {
+        let query =
+            SearchProductsQuery::new(" ウォールナット\u{3000}desk  desk ", None, None).unwrap();
+        assert_eq!(query.terms, vec!["ウォールナット", "desk"]);
+        assert_eq!(query.page, 1);
+        assert_eq!(query.per_page, SearchProductsQuery::DEFAULT_PER_PAGE);
+    }
+
+    #[test]
+    fn test_new_clamps_paging() {
+        let query = SearchProductsQuery::new("oak", Some(0), Some(1000)).unwrap();
+        assert_eq!(query.page, 1);
+        assert_eq!(query.per_page, SearchProductsQuery::MAX_PER_PAGE);
+        assert_eq!(query.to_criteria().offset(), 0);
+    }
+
+    #[test]
+    fn test_new_rejects_empty_keyword() {
+        assert!(SearchProductsQuery::new("", None, None).is_err());
+        assert!(SearchProductsQuery::new(" \u{3000} ", None, None).is_err());
+    }
+
+    #[test]
+    fn test_new_rejects_too_long_keyword() {
+        let keyword = "机".repeat(SearchProductsQuery::MAX_KEYWORD_LENGTH + 1);
+        assert!(SearchProductsQuery::new(&keyword, None, None).is_err());
+
+        let keyword = (0..=SearchProductsQuery::MAX_TERMS)
+            .map(|i| format!("t{}", i))
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert!(SearchProductsQuery::new(&keyword, None, None).is_err());
+    }
+}
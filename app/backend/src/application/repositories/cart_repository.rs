@@ -0,0 +1,15 @@
+use crate::application::error::RepositoryError;
+use crate::domain::Cart;
+use crate::domain::value_objects::CartId;
+
+#[async_trait::async_trait]
+pub trait CartRepository: Send + Sync {
+    /// カートを保存（存在する場合は置き換え、有効期限を延長）
+    async fn save(&self, cart: &Cart) -> Result<(), RepositoryError>;
+
+    /// IDでカートを取得（有効期限切れのカートは存在しないものとして扱う）
+    async fn find_by_id(&self, id: &CartId) -> Result<Option<Cart>, RepositoryError>;
+
+    /// 有効期限切れのカートを削除し、削除件数を返す
+    async fn delete_expired(&self) -> Result<u64, RepositoryError>;
+}
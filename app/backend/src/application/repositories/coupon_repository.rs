@@ -1,6 +1,7 @@
+use crate::application::error::RepositoryError;
 use crate::domain::entities::Coupon;
 use crate::domain::error::DomainError;
-use crate::domain::value_objects::CouponCode;
+use crate::domain::value_objects::{CouponCode, CouponId};
 use async_trait::async_trait;
 
 /// クーポンリポジトリトレイト
@@ -9,6 +10,28 @@ pub trait CouponRepository: Send + Sync {
     /// クーポンコードでクーポンを検索
     async fn find_by_code(&self, code: &CouponCode) -> Result<Option<Coupon>, DomainError>;
 
-    /// クーポンの使用回数を更新
+    /// クーポンの使用回数を1増やす（使用上限に達している場合は更新せずにエラー）
     async fn update_usage_count(&self, coupon: &Coupon) -> Result<(), DomainError>;
+
+    /// IDでクーポンを検索（有効期間外のクーポンも含む）
+    async fn find_by_id(&self, id: &CouponId) -> Result<Option<Coupon>, RepositoryError>;
+
+    /// すべてのクーポンを取得（管理用、有効期間外のクーポンも含む）
+    async fn find_all(&self) -> Result<Vec<Coupon>, RepositoryError>;
+
+    /// クーポンをまとめて新規作成（コードが重複する場合はすべてロールバック）
+    async fn create_all(&self, coupons: &[Coupon]) -> Result<(), RepositoryError>;
+
+    /// クーポンを更新（存在しない場合はNotFound）
+    async fn update(&self, coupon: &Coupon) -> Result<(), RepositoryError>;
+
+    /// クーポンを削除（存在しない場合はNotFound）
+    async fn delete(&self, id: &CouponId) -> Result<(), RepositoryError>;
+
+    /// 顧客（メールアドレス）がクーポンを使用した回数
+    async fn count_customer_usage(
+        &self,
+        id: &CouponId,
+        customer_email: &str,
+    ) -> Result<u32, RepositoryError>;
 }
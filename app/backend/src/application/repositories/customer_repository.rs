@@ -0,0 +1,24 @@
+use crate::application::error::RepositoryError;
+use crate::domain::aggregates::customer::{Customer, CustomerSession};
+use crate::domain::value_objects::{CustomerId, Email, SessionId};
+
+#[async_trait::async_trait]
+pub trait CustomerRepository: Send + Sync {
+    /// IDで会員を取得
+    async fn find_by_id(&self, id: &CustomerId) -> Result<Option<Customer>, RepositoryError>;
+
+    /// メールアドレスで会員を取得（大文字・小文字を区別しない）
+    async fn find_by_email(&self, email: &Email) -> Result<Option<Customer>, RepositoryError>;
+
+    /// 会員を保存（メールアドレスが登録済みの場合はUniqueConstraint）
+    async fn save(&self, customer: &Customer) -> Result<(), RepositoryError>;
+
+    /// ログインセッションを保存（失効日時も更新する）
+    async fn save_session(&self, session: &CustomerSession) -> Result<(), RepositoryError>;
+
+    /// IDでログインセッションを取得
+    async fn find_session(
+        &self,
+        id: &SessionId,
+    ) -> Result<Option<CustomerSession>, RepositoryError>;
+}
@@ -0,0 +1,14 @@
+use crate::application::error::RepositoryError;
+use crate::domain::DeliveryInfo;
+use crate::domain::value_objects::{DeliveryInfoId, OrderId};
+
+/// 配送情報（出荷）リポジトリ
+/// 配送情報の更新は注文集約の一部として`OrderRepository::update`で保存する
+#[async_trait::async_trait]
+pub trait DeliveryInfoRepository: Send + Sync {
+    /// IDで配送情報を取得（紐づく注文IDとともに返す）
+    async fn find_by_id(
+        &self,
+        id: &DeliveryInfoId,
+    ) -> Result<Option<(OrderId, DeliveryInfo)>, RepositoryError>;
+}
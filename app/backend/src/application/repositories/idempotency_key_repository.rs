@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+
+use crate::application::error::RepositoryError;
+
+/// 冪等キーで保存したレスポンス
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredResponse {
+    /// HTTPステータスコード
+    pub status: u16,
+    /// Content-Typeヘッダー
+    pub content_type: Option<String>,
+    /// レスポンスボディ
+    pub body: Vec<u8>,
+}
+
+/// 冪等キーの記録
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyRecord {
+    pub key: String,
+    /// 最初のリクエストのハッシュ（メソッド・パス・ボディ）
+    pub request_hash: String,
+    /// 保存済みのレスポンス（処理中の場合はNone）
+    pub response: Option<StoredResponse>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[async_trait::async_trait]
+pub trait IdempotencyKeyRepository: Send + Sync {
+    /// キーの記録を取得（期限切れの記録も含む）
+    async fn find(&self, key: &str) -> Result<Option<IdempotencyRecord>, RepositoryError>;
+
+    /// キーを処理中として確保（未使用または期限切れの場合のみ確保でき、trueを返す）
+    async fn reserve(
+        &self,
+        key: &str,
+        request_hash: &str,
+        now: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, RepositoryError>;
+
+    /// 処理結果のレスポンスを保存
+    async fn store_response(
+        &self,
+        key: &str,
+        response: &StoredResponse,
+    ) -> Result<(), RepositoryError>;
+
+    /// 確保したキーを解放（処理に失敗し、再試行を許可する場合）
+    async fn release(&self, key: &str) -> Result<(), RepositoryError>;
+
+    /// 期限切れのキーを削除
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, RepositoryError>;
+}
@@ -1,17 +1,37 @@
+mod cart_repository;
 mod category_repository;
 mod color_repository;
 mod coupon_repository;
+mod customer_repository;
+mod delivery_info_repository;
+mod idempotency_key_repository;
 mod order_repository;
 mod payment_method_repository;
+mod payment_repository;
 mod product_repository;
+mod product_write_repository;
+mod promotion_repository;
+mod return_repository;
 mod shipping_method_repository;
+mod stock_movement_repository;
 mod variant_repository;
 
+pub use cart_repository::CartRepository;
 pub use category_repository::CategoryRepository;
 pub use color_repository::ColorRepository;
 pub use coupon_repository::CouponRepository;
-pub use order_repository::OrderRepository;
+pub use customer_repository::CustomerRepository;
+pub use delivery_info_repository::DeliveryInfoRepository;
+pub use idempotency_key_repository::{IdempotencyKeyRepository, IdempotencyRecord, StoredResponse};
+pub use order_repository::{OrderFactory, OrderRepository, OrderSearchCriteria};
 pub use payment_method_repository::PaymentMethodRepository;
-pub use product_repository::ProductRepository;
+pub use payment_repository::PaymentRepository;
+pub use product_repository::{
+    ProductKeywordSearchCriteria, ProductRepository, ProductSearchCriteria, ProductSortOrder,
+};
+pub use product_write_repository::ProductWriteRepository;
+pub use promotion_repository::PromotionRepository;
+pub use return_repository::ReturnRepository;
 pub use shipping_method_repository::ShippingMethodRepository;
+pub use stock_movement_repository::StockMovementRepository;
 pub use variant_repository::VariantRepository;
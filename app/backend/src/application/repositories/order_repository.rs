@@ -1,14 +1,82 @@
-use crate::application::error::RepositoryError;
-use crate::domain::aggregates::order::Order;
+use chrono::{DateTime, Utc};
+
+use crate::application::dto::OrderEventDTO;
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::domain::aggregates::order::{Order, OrderDocumentKind, OrderStatus};
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{CustomerId, OrderId, OrderNumber, OrderNumberFormat};
+
+/// 採番した注文番号から保存する注文を組み立てる関数
+pub type OrderFactory = Box<dyn FnOnce(OrderNumber) -> Result<Order, DomainError> + Send>;
+
+/// 注文一覧の検索条件
+#[derive(Debug, Clone)]
+pub struct OrderSearchCriteria {
+    /// 注文ステータス
+    pub status: Option<OrderStatus>,
+    /// 注文日時の下限（この日時を含む）
+    pub created_from: Option<DateTime<Utc>>,
+    /// 注文日時の上限（この日時を含まない）
+    pub created_to: Option<DateTime<Utc>>,
+    /// 顧客メールアドレス
+    pub customer_email: Option<String>,
+    /// 注文した会員
+    pub customer_id: Option<CustomerId>,
+    /// ページ番号（1始まり）
+    pub page: u32,
+    /// 1ページあたりの件数
+    pub per_page: u32,
+}
+
+impl OrderSearchCriteria {
+    /// LIMIT/OFFSETのOFFSET値
+    pub fn offset(&self) -> u32 {
+        self.page.saturating_sub(1) * self.per_page
+    }
+}
 
 #[async_trait::async_trait]
 pub trait OrderRepository: Send + Sync {
-    /// 注文を保存
-    async fn save(&self, order: &Order) -> Result<(), RepositoryError>;
-
-    /// 注文を更新
+    /// 注文を更新（未保存のドメインイベントも同一トランザクションで記録）
     async fn update(&self, order: &Order) -> Result<(), RepositoryError>;
-    
-    /// 指定された年の次のシーケンス番号を取得
-    async fn get_next_sequence_number(&self, year: i32) -> Result<u32, RepositoryError>;
+
+    /// 注文番号を採番して新しい注文を保存
+    /// 採番（スコープごとの連番の更新）と保存を同一トランザクションで行うため、
+    /// 同時に注文されても番号は重複せず、保存に失敗した場合は連番も消費されない
+    async fn create(
+        &self,
+        format: &OrderNumberFormat,
+        now: DateTime<Utc>,
+        factory: OrderFactory,
+    ) -> Result<Order, ApplicationError>;
+
+    /// IDで注文を取得
+    async fn find_by_id(&self, id: &OrderId) -> Result<Option<Order>, RepositoryError>;
+
+    /// 注文番号で注文を取得
+    async fn find_by_order_number(
+        &self,
+        order_number: &OrderNumber,
+    ) -> Result<Option<Order>, RepositoryError>;
+
+    /// 検索条件に一致する注文を取得（注文日時の降順）
+    /// 戻り値は該当ページの注文と、条件に一致する総件数
+    async fn find_all(
+        &self,
+        criteria: &OrderSearchCriteria,
+    ) -> Result<(Vec<Order>, u32), RepositoryError>;
+
+    /// 請求書・領収書の発行回数を1つ進め、何回目の発行かを返す
+    /// 発行回数だけを条件なしに加算するため、同時に行われた注文の更新を上書きしない
+    async fn record_document_issue(
+        &self,
+        id: &OrderId,
+        kind: OrderDocumentKind,
+    ) -> Result<u32, RepositoryError>;
+
+    /// 注文のイベント履歴を古い順に取得
+    async fn find_events_by_order_id(
+        &self,
+        id: &OrderId,
+    ) -> Result<Vec<OrderEventDTO>, RepositoryError>;
 }
@@ -6,4 +6,6 @@ use crate::domain::entities::PaymentMethod;
 pub trait PaymentMethodRepository: Send + Sync {
     async fn find_all(&self) -> Result<PaymentMethodListDTO, RepositoryError>;
     async fn find_by_id(&self, id: &str) -> Result<Option<PaymentMethod>, RepositoryError>;
+    /// 支払い方法を手数料ルールごと保存（存在しない場合はNotFound）
+    async fn update(&self, payment_method: &PaymentMethod) -> Result<(), RepositoryError>;
 }
@@ -0,0 +1,17 @@
+use crate::application::error::RepositoryError;
+use crate::domain::PaymentTransaction;
+use crate::domain::value_objects::OrderId;
+
+/// 決済記録リポジトリ
+/// 決済代行会社への操作は成否にかかわらず記録し、注文の決済状態は記録から導出する
+#[async_trait::async_trait]
+pub trait PaymentRepository: Send + Sync {
+    /// 決済操作の結果を記録
+    async fn record(&self, transaction: &PaymentTransaction) -> Result<(), RepositoryError>;
+
+    /// 注文の決済記録を古い順に取得
+    async fn find_by_order_id(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<Vec<PaymentTransaction>, RepositoryError>;
+}
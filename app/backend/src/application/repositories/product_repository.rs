@@ -1,15 +1,149 @@
-use crate::application::dto::{ProductDTO, ProductListDTO, VariantDTO};
+use std::str::FromStr;
+
+use crate::application::dto::{ProductDTO, ProductListDTO, ProductSearchResultDTO, VariantDTO};
 use crate::application::error::RepositoryError;
 use crate::domain::{ProductId, SKUId};
 
+/// 商品一覧の並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProductSortOrder {
+    /// 表示順（同順位は商品名順）
+    #[default]
+    DisplayOrder,
+    /// 商品名順
+    Name,
+    /// 価格の安い順（セール価格があればセール価格）
+    PriceAsc,
+    /// 価格の高い順（セール価格があればセール価格）
+    PriceDesc,
+    /// 新着順
+    Newest,
+}
+
+impl FromStr for ProductSortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "display_order" => Ok(Self::DisplayOrder),
+            "name" => Ok(Self::Name),
+            "price_asc" => Ok(Self::PriceAsc),
+            "price_desc" => Ok(Self::PriceDesc),
+            "newest" => Ok(Self::Newest),
+            _ => Err(format!("Invalid sort order: {}", s)),
+        }
+    }
+}
+
+/// 商品一覧の検索条件
+/// 価格・在庫は一覧に表示される代表SKUの値で判定する
+#[derive(Debug, Clone, Default)]
+pub struct ProductSearchCriteria {
+    /// カテゴリーIDまたはスラッグ（子孫カテゴリーを含む）
+    pub category: Option<String>,
+    /// 色名
+    pub color: Option<String>,
+    /// 価格の下限（円、この価格を含む）
+    pub min_price: Option<u32>,
+    /// 価格の上限（円、この価格を含む）
+    pub max_price: Option<u32>,
+    /// タグのスラッグ
+    pub tag: Option<String>,
+    /// ベストセラー商品かどうか
+    pub is_best_seller: Option<bool>,
+    /// 即配送可能かどうか
+    pub is_quick_ship: Option<bool>,
+    /// 在庫があるかどうか
+    pub in_stock: Option<bool>,
+    /// 並び順
+    pub sort: ProductSortOrder,
+    /// ページ番号（1始まり）
+    pub page: u32,
+    /// 1ページあたりの件数（Noneの場合は全件）
+    pub per_page: Option<u32>,
+}
+
+impl ProductSearchCriteria {
+    /// LIMIT/OFFSETのOFFSET値
+    pub fn offset(&self) -> u32 {
+        self.per_page
+            .map_or(0, |per_page| self.page.saturating_sub(1) * per_page)
+    }
+}
+
+/// 商品のキーワード検索条件
+/// 商品名・説明・SKU名・素材・色名を対象に、すべてのキーワードを含む商品を探す
+#[derive(Debug, Clone, Default)]
+pub struct ProductKeywordSearchCriteria {
+    /// 検索キーワード（空白区切り済み、AND条件）
+    pub terms: Vec<String>,
+    /// ページ番号（1始まり）
+    pub page: u32,
+    /// 1ページあたりの件数
+    pub per_page: u32,
+}
+
+impl ProductKeywordSearchCriteria {
+    /// LIMIT/OFFSETのOFFSET値
+    pub fn offset(&self) -> u32 {
+        self.page.saturating_sub(1) * self.per_page
+    }
+}
+
 #[async_trait::async_trait]
 pub trait ProductRepository: Send + Sync {
     async fn find_by_id(&self, id: &ProductId) -> Result<Option<ProductDTO>, RepositoryError>;
-    async fn find_all(&self) -> Result<ProductListDTO, RepositoryError>;
 
-    /// 複数のSKU IDでバリアント（SKU）を取得
+    /// 検索条件に一致する商品を取得（総件数とページ情報を含む）
+    async fn find_all(
+        &self,
+        criteria: &ProductSearchCriteria,
+    ) -> Result<ProductListDTO, RepositoryError>;
+
+    /// キーワードに一致する商品を関連度順に取得（一致箇所のスニペットを含む）
+    async fn search(
+        &self,
+        criteria: &ProductKeywordSearchCriteria,
+    ) -> Result<ProductSearchResultDTO, RepositoryError>;
+
+    /// 複数のSKU IDでバリアント（SKU）を取得（公開中の商品の販売中SKUのみ）
     async fn find_variants_by_ids(
         &self,
         sku_ids: &[SKUId],
     ) -> Result<Vec<VariantDTO>, RepositoryError>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_order_from_str() {
+        assert_eq!(
+            "price_asc".parse::<ProductSortOrder>(),
+            Ok(ProductSortOrder::PriceAsc)
+        );
+        assert_eq!(
+            "newest".parse::<ProductSortOrder>(),
+            Ok(ProductSortOrder::Newest)
+        );
+        assert!("cheapest".parse::<ProductSortOrder>().is_err());
+    }
+
+    #[test]
+    fn test_offset() {
+        let criteria = ProductSearchCriteria {
+            page: 3,
+            per_page: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(criteria.offset(), 40);
+
+        let unpaginated = ProductSearchCriteria {
+            page: 3,
+            per_page: None,
+            ..Default::default()
+        };
+        assert_eq!(unpaginated.offset(), 0);
+    }
+}
@@ -0,0 +1,25 @@
+use crate::application::error::RepositoryError;
+use crate::domain::{Product, ProductId, SKUId, Tag};
+
+/// 商品集約の書き込み用リポジトリ（管理者によるカタログ編集で使用）
+/// 読み取り用のProductRepositoryがDTOを返すのに対し、SKU・画像・タグを含む集約を扱う
+#[async_trait::async_trait]
+pub trait ProductWriteRepository: Send + Sync {
+    /// IDで商品集約を取得（非公開の商品や販売停止中のSKUも含む）
+    async fn find_by_id(&self, id: &ProductId) -> Result<Option<Product>, RepositoryError>;
+
+    /// SKU IDからそのSKUが属する商品集約を取得
+    async fn find_by_sku_id(&self, sku_id: &SKUId) -> Result<Option<Product>, RepositoryError>;
+
+    /// 商品集約を保存（存在する場合は置き換え）
+    /// 既存SKUの在庫数は注文処理と競合しないよう更新せず、新規SKUの初期在庫のみ書き込む
+    /// 新規SKUの初期在庫は在庫台帳に入荷として記録する
+    /// 集約から取り除かれたSKU・画像・タグは削除する
+    async fn save(&self, product: &Product) -> Result<(), RepositoryError>;
+
+    /// 商品を削除（SKU・画像・タグの関連も削除）。存在しない場合はfalseを返す
+    async fn delete(&self, id: &ProductId) -> Result<bool, RepositoryError>;
+
+    /// スラッグでタグを取得（存在しないスラッグは結果に含まれない）
+    async fn find_tags_by_slugs(&self, slugs: &[String]) -> Result<Vec<Tag>, RepositoryError>;
+}
@@ -0,0 +1,10 @@
+use crate::application::error::RepositoryError;
+use crate::domain::entities::Promotion;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait PromotionRepository: Send + Sync {
+    /// 指定日時に実施中の有効なプロモーションを取得（優先度の高い順）
+    async fn find_running(&self, now: DateTime<Utc>) -> Result<Vec<Promotion>, RepositoryError>;
+}
@@ -0,0 +1,29 @@
+use crate::application::error::RepositoryError;
+use crate::domain::StockMovement;
+use crate::domain::aggregates::return_request::{ReturnRequest, ReturnStatus};
+use crate::domain::value_objects::{OrderId, ReturnId};
+
+/// 返品（RMA）リポジトリ
+#[async_trait::async_trait]
+pub trait ReturnRepository: Send + Sync {
+    /// 申請された返品を明細とともに保存
+    async fn save(&self, return_request: &ReturnRequest) -> Result<(), RepositoryError>;
+
+    /// 返品のステータス・金額を更新し、受領した商品の在庫の戻し入れを反映（同一トランザクション）
+    /// 読み込み時のステータス（`previous_status`）から変わっていれば競合として更新しない
+    async fn update(
+        &self,
+        return_request: &ReturnRequest,
+        previous_status: ReturnStatus,
+        restocks: &[StockMovement],
+    ) -> Result<(), RepositoryError>;
+
+    /// IDで返品を取得
+    async fn find_by_id(&self, id: &ReturnId) -> Result<Option<ReturnRequest>, RepositoryError>;
+
+    /// 注文の返品を申請の古い順に取得
+    async fn find_by_order_id(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<Vec<ReturnRequest>, RepositoryError>;
+}
@@ -0,0 +1,25 @@
+use crate::application::dto::StockMovementDTO;
+use crate::application::error::RepositoryError;
+use crate::domain::{SKUId, StockMovement};
+
+/// 在庫台帳リポジトリ
+#[async_trait::async_trait]
+pub trait StockMovementRepository: Send + Sync {
+    /// 在庫調整をSKUの在庫数に反映し、台帳に記録（同一トランザクション）
+    /// 引当済み数を下回る減算は在庫不足エラーとなる
+    async fn record_adjustment(
+        &self,
+        movement: &StockMovement,
+    ) -> Result<StockMovementDTO, RepositoryError>;
+
+    /// SKUの在庫台帳を新しい順に取得
+    async fn find_by_sku_id(
+        &self,
+        sku_id: &SKUId,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<StockMovementDTO>, RepositoryError>;
+
+    /// SKUの在庫台帳の件数
+    async fn count_by_sku_id(&self, sku_id: &SKUId) -> Result<u32, RepositoryError>;
+}
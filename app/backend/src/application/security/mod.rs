@@ -0,0 +1,5 @@
+mod password_hasher;
+mod session_token_signer;
+
+pub use password_hasher::{PasswordHashError, PasswordHasher};
+pub use session_token_signer::SessionTokenSigner;
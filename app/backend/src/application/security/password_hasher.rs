@@ -0,0 +1,21 @@
+/// パスワードのハッシュ化に失敗した
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordHashError(pub String);
+
+impl std::fmt::Display for PasswordHashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Password hashing failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for PasswordHashError {}
+
+/// パスワードのハッシュ化と照合
+/// 平文のパスワードは保存せず、ハッシュ文字列のみを会員に保持させる
+pub trait PasswordHasher: Send + Sync {
+    /// パスワードをハッシュ化（ソルトを含むハッシュ文字列を返す）
+    fn hash(&self, password: &str) -> Result<String, PasswordHashError>;
+
+    /// パスワードがハッシュ文字列と一致するか
+    fn verify(&self, password: &str, password_hash: &str) -> bool;
+}
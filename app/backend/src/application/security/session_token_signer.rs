@@ -0,0 +1,11 @@
+use crate::domain::value_objects::SessionId;
+
+/// セッショントークンの署名と検証
+/// トークンにはセッションIDのみを含め、有効期限・失効はセッション側で判定する
+pub trait SessionTokenSigner: Send + Sync {
+    /// セッションIDに署名したトークンを発行
+    fn sign(&self, session_id: SessionId) -> String;
+
+    /// トークンの署名を検証し、セッションIDを取り出す（改ざん・形式不正の場合はNone）
+    fn verify(&self, token: &str) -> Option<SessionId>;
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use crate::application::dto::{CalculateCartResultDto, CartDTO, CouponErrorDto};
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{
+    CouponRepository, PaymentMethodRepository, ProductRepository, PromotionRepository,
+    ShippingMethodRepository,
+};
+use crate::domain::entities::{ShipmentDetails, ShippableItem};
+use crate::domain::{Cart, TaxPolicy};
+
+/// 永続カートの合計計算サービス
+/// 保存されたカートには手数料・クーポンを持たないため、読み込むたびに
+/// 現在の配送方法・支払い方法・クーポン定義と実施中のプロモーションを再適用して計算する
+pub struct CartPricingService {
+    product_repository: Arc<dyn ProductRepository>,
+    shipping_method_repository: Arc<dyn ShippingMethodRepository>,
+    payment_method_repository: Arc<dyn PaymentMethodRepository>,
+    coupon_repository: Arc<dyn CouponRepository>,
+    promotion_repository: Arc<dyn PromotionRepository>,
+    tax_policy: TaxPolicy,
+}
+
+impl CartPricingService {
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository>,
+        shipping_method_repository: Arc<dyn ShippingMethodRepository>,
+        payment_method_repository: Arc<dyn PaymentMethodRepository>,
+        coupon_repository: Arc<dyn CouponRepository>,
+        promotion_repository: Arc<dyn PromotionRepository>,
+        tax_policy: TaxPolicy,
+    ) -> Self {
+        Self {
+            product_repository,
+            shipping_method_repository,
+            payment_method_repository,
+            coupon_repository,
+            promotion_repository,
+            tax_policy,
+        }
+    }
+
+    /// カート内の商品の配送料計算用の情報を取得
+    /// 配送先は注文時まで決まらないため、地域別の配送料は適用しない
+    pub async fn shipment_details(&self, cart: &Cart) -> Result<ShipmentDetails, ApplicationError> {
+        let sku_ids = cart
+            .items()
+            .iter()
+            .map(|item| item.sku_id().clone())
+            .collect::<Vec<_>>();
+        let variants = self
+            .product_repository
+            .find_variants_by_ids(&sku_ids)
+            .await?;
+
+        let items = cart
+            .items()
+            .iter()
+            .map(|item| {
+                let sku_id = item.sku_id().to_string();
+                variants
+                    .iter()
+                    .find(|variant| variant.id == sku_id)
+                    .map(|variant| variant.shippable_item(item.quantity()))
+                    .unwrap_or_else(|| ShippableItem::new(item.quantity(), None, false))
+            })
+            .collect();
+
+        Ok(ShipmentDetails::new(None, items))
+    }
+
+    /// カートの合計を計算
+    /// 選択後に無効になった配送方法・支払い方法は未選択として扱い、
+    /// 適用できなくなったクーポンはエラーにせず coupon_error として返す
+    pub async fn price(&self, cart: &Cart) -> Result<CartDTO, ApplicationError> {
+        let mut priced = Cart::restore(*cart.id(), cart.items().to_vec(), None, None, None);
+        priced.apply_tax_policy(self.tax_policy);
+        priced.apply_promotions(
+            self.promotion_repository
+                .find_running(chrono::Utc::now())
+                .await?,
+        );
+
+        if let Some(method_id) = cart.shipping_method_id()
+            && let Some(method) = self
+                .shipping_method_repository
+                .find_by_id(method_id.value())
+                .await?
+        {
+            let shipment = self.shipment_details(cart).await?;
+            let _ = priced.apply_shipping_method(&method, &shipment);
+        }
+
+        if let Some(method_id) = cart.payment_method_id()
+            && let Some(method) = self
+                .payment_method_repository
+                .find_by_id(method_id.value())
+                .await?
+        {
+            let _ = priced.apply_payment_method(&method);
+        }
+
+        let coupon_error = match cart.coupon_code() {
+            Some(code) => {
+                let error_message = match self.coupon_repository.find_by_code(code).await? {
+                    Some(coupon) => match priced.apply_coupon(coupon) {
+                        Ok(()) => priced.calculate().err().map(|e| {
+                            priced.remove_coupon();
+                            e.to_string()
+                        }),
+                        Err(e) => Some(e.to_string()),
+                    },
+                    None => Some("Coupon not found".to_string()),
+                };
+                error_message.map(|error_message| CouponErrorDto {
+                    coupon_code: Some(code.value().to_string()),
+                    error_message,
+                })
+            }
+            None => None,
+        };
+
+        Ok(CartDTO {
+            id: priced.id().to_string(),
+            shipping_method_id: priced.shipping_method_id().map(|id| id.value().to_string()),
+            payment_method_id: priced.payment_method_id().map(|id| id.value().to_string()),
+            coupon_code: priced.coupon_code().map(|code| code.value().to_string()),
+            totals: CalculateCartResultDto::from_cart(priced, coupon_error)
+                .map_err(ApplicationError::InvalidInput)?,
+        })
+    }
+}
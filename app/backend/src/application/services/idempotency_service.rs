@@ -0,0 +1,273 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{IdempotencyKeyRepository, StoredResponse};
+
+/// 冪等キーによるリクエストの判定結果
+pub enum IdempotencyOutcome {
+    /// 初めてのキー（処理を実行し、確保したキーに結果を保存する）
+    Proceed(IdempotencyReservation),
+    /// 同じリクエストの再送（保存済みのレスポンスを返す）
+    Replay(StoredResponse),
+    /// 同じキーで異なるリクエストが送られた
+    PayloadMismatch,
+    /// 同じキーのリクエストがまだ処理中
+    InProgress,
+}
+
+/// 冪等キー（Idempotency-Key）の管理サービス
+/// ネットワークのタイムアウト後の再送で同じコマンドが二重に実行されないよう、
+/// キーごとに最初のリクエストのハッシュとレスポンスを有効期間の間だけ保存する
+pub struct IdempotencyService {
+    idempotency_key_repository: Arc<dyn IdempotencyKeyRepository>,
+    ttl: Duration,
+}
+
+impl IdempotencyService {
+    pub fn new(
+        idempotency_key_repository: Arc<dyn IdempotencyKeyRepository>,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            idempotency_key_repository,
+            ttl,
+        }
+    }
+
+    /// キーとリクエストのハッシュから処理を続けるかを判定
+    pub async fn begin(
+        &self,
+        key: &str,
+        request_hash: &str,
+    ) -> Result<IdempotencyOutcome, ApplicationError> {
+        let now = Utc::now();
+        self.idempotency_key_repository.delete_expired(now).await?;
+
+        if self
+            .idempotency_key_repository
+            .reserve(key, request_hash, now, now + self.ttl)
+            .await?
+        {
+            return Ok(IdempotencyOutcome::Proceed(IdempotencyReservation {
+                idempotency_key_repository: self.idempotency_key_repository.clone(),
+                key: Some(key.to_string()),
+            }));
+        }
+
+        // 確保できなかった場合は有効なキーが既に存在する
+        let Some(record) = self.idempotency_key_repository.find(key).await? else {
+            // 確認までの間に解放された場合は処理中として再試行させる
+            return Ok(IdempotencyOutcome::InProgress);
+        };
+
+        if record.request_hash != request_hash {
+            return Ok(IdempotencyOutcome::PayloadMismatch);
+        }
+
+        Ok(match record.response {
+            Some(response) => IdempotencyOutcome::Replay(response),
+            None => IdempotencyOutcome::InProgress,
+        })
+    }
+}
+
+/// 処理中として確保した冪等キー
+///
+/// 結果を保存する前に破棄された場合（クライアントの切断やパニックでハンドラーが最後まで
+/// 実行されなかった場合）はキーを解放し、再送が有効期間の間ずっと409にならないようにする
+pub struct IdempotencyReservation {
+    idempotency_key_repository: Arc<dyn IdempotencyKeyRepository>,
+    /// 保存・解放が済んだらNone
+    key: Option<String>,
+}
+
+impl IdempotencyReservation {
+    /// 処理結果を保存（以降の再送にはこのレスポンスを返す）
+    pub async fn complete(mut self, response: &StoredResponse) -> Result<(), ApplicationError> {
+        if let Some(key) = &self.key {
+            self.idempotency_key_repository
+                .store_response(key, response)
+                .await?;
+        }
+        self.key = None;
+        Ok(())
+    }
+
+    /// 処理結果を保存せずにキーを解放（サーバーエラーなど再試行で成功しうる場合）
+    pub async fn abandon(mut self) -> Result<(), ApplicationError> {
+        if let Some(key) = self.key.take() {
+            self.idempotency_key_repository.release(&key).await?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for IdempotencyReservation {
+    fn drop(&mut self) {
+        let Some(key) = self.key.take() else {
+            return;
+        };
+        // Dropではawaitできないため、解放は別タスクで行う
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let idempotency_key_repository = self.idempotency_key_repository.clone();
+        runtime.spawn(async move {
+            println!("->> {:<12} - release unfinished {}", "IDEMPOTENCY", key);
+            if let Err(e) = idempotency_key_repository.release(&key).await {
+                println!(
+                    "->> {:<12} - failed to release {}: {}",
+                    "IDEMPOTENCY", key, e
+                );
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::error::RepositoryError;
+    use crate::application::repositories::IdempotencyRecord;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    // モックリポジトリ
+    #[derive(Default)]
+    struct MockIdempotencyKeyRepository {
+        records: Mutex<HashMap<String, IdempotencyRecord>>,
+    }
+
+    #[async_trait]
+    impl IdempotencyKeyRepository for MockIdempotencyKeyRepository {
+        async fn find(&self, key: &str) -> Result<Option<IdempotencyRecord>, RepositoryError> {
+            Ok(self.records.lock().unwrap().get(key).cloned())
+        }
+
+        async fn reserve(
+            &self,
+            key: &str,
+            request_hash: &str,
+            now: DateTime<Utc>,
+            expires_at: DateTime<Utc>,
+        ) -> Result<bool, RepositoryError> {
+            let mut records = self.records.lock().unwrap();
+            if records
+                .get(key)
+                .is_some_and(|record| record.expires_at > now)
+            {
+                return Ok(false);
+            }
+            records.insert(
+                key.to_string(),
+                IdempotencyRecord {
+                    key: key.to_string(),
+                    request_hash: request_hash.to_string(),
+                    response: None,
+                    expires_at,
+                },
+            );
+            Ok(true)
+        }
+
+        async fn store_response(
+            &self,
+            key: &str,
+            response: &StoredResponse,
+        ) -> Result<(), RepositoryError> {
+            if let Some(record) = self.records.lock().unwrap().get_mut(key) {
+                record.response = Some(response.clone());
+            }
+            Ok(())
+        }
+
+        async fn release(&self, key: &str) -> Result<(), RepositoryError> {
+            self.records.lock().unwrap().remove(key);
+            Ok(())
+        }
+
+        async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, RepositoryError> {
+            let mut records = self.records.lock().unwrap();
+            let before = records.len();
+            records.retain(|_, record| record.expires_at > now);
+            Ok((before - records.len()) as u64)
+        }
+    }
+
+    fn service(ttl: Duration) -> IdempotencyService {
+        IdempotencyService::new(Arc::new(MockIdempotencyKeyRepository::default()), ttl)
+    }
+
+    fn response() -> StoredResponse {
+        StoredResponse {
+            status: 200,
+            content_type: Some("application/json".to_string()),
+            body: br#"{"orderNumber":"ORD-2026-000001"}"#.to_vec(),
+        }
+    }
+
+    fn proceed(outcome: IdempotencyOutcome) -> IdempotencyReservation {
+        match outcome {
+            IdempotencyOutcome::Proceed(reservation) => reservation,
+            _ => panic!("expected Proceed"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replays_stored_response_for_same_request() {
+        let service = service(Duration::hours(24));
+
+        let reservation = proceed(service.begin("key-1", "hash-a").await.unwrap());
+        assert!(matches!(
+            service.begin("key-1", "hash-a").await.unwrap(),
+            IdempotencyOutcome::InProgress
+        ));
+
+        reservation.complete(&response()).await.unwrap();
+        assert!(matches!(
+            service.begin("key-1", "hash-a").await.unwrap(),
+            IdempotencyOutcome::Replay(stored) if stored == response()
+        ));
+        assert!(matches!(
+            service.begin("key-1", "hash-b").await.unwrap(),
+            IdempotencyOutcome::PayloadMismatch
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_abandoned_or_expired_key_can_be_reused() {
+        let service = service(Duration::hours(24));
+        let reservation = proceed(service.begin("key-1", "hash-a").await.unwrap());
+        reservation.abandon().await.unwrap();
+        proceed(service.begin("key-1", "hash-b").await.unwrap());
+
+        let expired = self::service(Duration::zero());
+        let reservation = proceed(expired.begin("key-2", "hash-a").await.unwrap());
+        reservation.complete(&response()).await.unwrap();
+        proceed(expired.begin("key-2", "hash-b").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unfinished_request_releases_key_when_dropped() {
+        let service = service(Duration::hours(24));
+
+        // クライアントの切断でハンドラーの実行ごと破棄された場合
+        let request = async {
+            let _reservation = proceed(service.begin("key-1", "hash-a").await.unwrap());
+            std::future::pending::<()>().await;
+        };
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(10), request)
+                .await
+                .is_err()
+        );
+
+        // 解放は別タスクで行われる
+        tokio::task::yield_now().await;
+        proceed(service.begin("key-1", "hash-a").await.unwrap());
+    }
+}
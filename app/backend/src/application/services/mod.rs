@@ -0,0 +1,7 @@
+mod cart_pricing_service;
+mod idempotency_service;
+mod order_pricing_service;
+
+pub use cart_pricing_service::CartPricingService;
+pub use idempotency_service::{IdempotencyOutcome, IdempotencyService};
+pub use order_pricing_service::OrderPricingService;
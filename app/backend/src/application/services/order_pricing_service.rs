@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use crate::application::error::ApplicationError;
+use crate::application::repositories::{
+    PaymentMethodRepository, PromotionRepository, ShippingMethodRepository,
+};
+use crate::domain::aggregates::order::{AppliedCoupon, OrderItem, PaymentInfo, ShippingInfo};
+use crate::domain::entities::{Coupon, ShipmentDetails, ShippableItem};
+use crate::domain::value_objects::*;
+use crate::domain::{Cart, CartItem};
+
+/// 注文の配送料・割引・決済手数料の計算サービス
+/// 注文作成と注文変更で同じ料金表・割引ロジックを使い、カート計算時の見積もりと一致させる
+pub struct OrderPricingService {
+    shipping_method_repository: Arc<dyn ShippingMethodRepository>,
+    payment_method_repository: Arc<dyn PaymentMethodRepository>,
+    promotion_repository: Arc<dyn PromotionRepository>,
+}
+
+impl OrderPricingService {
+    pub fn new(
+        shipping_method_repository: Arc<dyn ShippingMethodRepository>,
+        payment_method_repository: Arc<dyn PaymentMethodRepository>,
+        promotion_repository: Arc<dyn PromotionRepository>,
+    ) -> Self {
+        Self {
+            shipping_method_repository,
+            payment_method_repository,
+            promotion_repository,
+        }
+    }
+
+    /// 配送方法と配送先から配送情報を作成（配送料は配送先と商品から料金表で求める）
+    pub async fn shipping_info(
+        &self,
+        shipping_method_id: &str,
+        address: Address,
+        order_items: &[OrderItem],
+        shippable_items: Vec<ShippableItem>,
+    ) -> Result<ShippingInfo, ApplicationError> {
+        let shipping_method = self
+            .shipping_method_repository
+            .find_by_id(shipping_method_id)
+            .await
+            .map_err(ApplicationError::Repository)?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "Shipping method not found: {}",
+                    shipping_method_id
+                ))
+            })?;
+
+        let shipping_method_id = ShippingMethodId::new(shipping_method_id.to_string())
+            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+
+        // 送料無料の判定はカート計算と同じくクーポン割引前の商品小計で行う
+        let mut subtotal = Money::zero();
+        for item in order_items {
+            subtotal = subtotal.add(item.subtotal()?)?;
+        }
+        let shipment =
+            ShipmentDetails::new(Some(address.prefecture().to_string()), shippable_items);
+        let shipping_fee = shipping_method.calculate_fee(subtotal, &shipment)?;
+
+        Ok(ShippingInfo::new(
+            shipping_method_id,
+            shipping_method.name().to_string(),
+            shipping_fee,
+            address,
+        ))
+    }
+
+    /// 実施中のプロモーションとクーポンを適用し、割引額と注文アイテムごとの割引額を計算
+    /// クーポンまたはプロモーションで送料無料になる場合は配送料を免除する
+    pub async fn apply_discounts(
+        &self,
+        cart_items: Vec<CartItem>,
+        coupon: Option<Coupon>,
+        shipping_info: &mut ShippingInfo,
+    ) -> Result<(Option<AppliedCoupon>, Vec<AppliedPromotion>, Vec<Money>), ApplicationError> {
+        let promotions = self
+            .promotion_repository
+            .find_running(chrono::Utc::now())
+            .await
+            .map_err(ApplicationError::Repository)?;
+
+        let mut cart = Cart::from_items(cart_items);
+        cart.apply_promotions(promotions);
+
+        if let Some(coupon) = coupon {
+            cart.apply_coupon(coupon)
+                .map_err(ApplicationError::Domain)?;
+        }
+        let calculation = cart.calculate().map_err(ApplicationError::Domain)?;
+
+        let waives_shipping = cart
+            .coupon()
+            .is_some_and(|coupon| coupon.discount_policy().waives_shipping())
+            || calculation
+                .applied_promotions
+                .iter()
+                .any(|promotion| promotion.waives_shipping);
+        if waives_shipping {
+            shipping_info.fee = Money::zero();
+        }
+
+        let applied_coupon = cart.coupon().map(|coupon| {
+            AppliedCoupon::new(
+                coupon.id().clone(),
+                coupon.code().clone(),
+                calculation.coupon_discount(),
+            )
+        });
+
+        Ok((
+            applied_coupon,
+            calculation.applied_promotions,
+            calculation.item_discounts,
+        ))
+    }
+
+    /// 支払い方法から支払い情報を作成
+    /// 手数料はカート計算と同じく注文アイテムごとの割引を差し引いた小計に対して求める
+    pub async fn payment_info(
+        &self,
+        payment_method_id: &str,
+        order_items: &[OrderItem],
+    ) -> Result<PaymentInfo, ApplicationError> {
+        let payment_method = self
+            .payment_method_repository
+            .find_by_id(payment_method_id)
+            .await
+            .map_err(ApplicationError::Repository)?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "Payment method not found: {}",
+                    payment_method_id
+                ))
+            })?;
+
+        let payment_method_id = PaymentMethodId::new(payment_method_id.to_string())
+            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+
+        let mut fee_base = Money::zero();
+        for item in order_items {
+            let discounted = item
+                .subtotal()?
+                .subtract(item.discount_amount)
+                .unwrap_or_else(|_| Money::zero());
+            fee_base = fee_base.add(discounted)?;
+        }
+        let payment_fee = payment_method.calculate_fee(fee_base)?;
+
+        Ok(PaymentInfo::new(
+            payment_method_id,
+            payment_method.name().to_string(),
+            payment_fee,
+            None,
+        ))
+    }
+}
@@ -1,35 +1,68 @@
+use chrono::Utc;
+
 use crate::domain::aggregates::CartItem;
 use crate::domain::error::DomainError;
 use crate::domain::value_objects::*;
-use crate::domain::{Coupon, CouponDiscountService};
+use crate::domain::{Coupon, CouponDiscountService, Promotion, PromotionService};
 
 /// カートアグリゲート
 #[derive(Debug, Clone, PartialEq)]
 pub struct Cart {
+    id: CartId,
     items: Vec<CartItem>,
+    shipping_method_id: Option<ShippingMethodId>,
+    payment_method_id: Option<PaymentMethodId>,
+    coupon_code: Option<CouponCode>,
     shipping_fee: Option<Money>,
     payment_fee: Option<Money>,
     coupon: Option<Coupon>,
+    /// 実施中のプロモーション（保存せず、計算のたびに適用する）
+    promotions: Vec<Promotion>,
+    tax_policy: TaxPolicy,
 }
 
 impl Cart {
     /// 空のカートを作成
     pub fn new() -> Self {
+        Self::from_items(Vec::new())
+    }
+
+    /// カートアイテムのリストからカートを作成
+    pub fn from_items(items: Vec<CartItem>) -> Self {
         Self {
-            items: Vec::new(),
+            id: CartId::new(),
+            items,
+            shipping_method_id: None,
+            payment_method_id: None,
+            coupon_code: None,
             shipping_fee: None,
             payment_fee: None,
             coupon: None,
+            promotions: Vec::new(),
+            tax_policy: TaxPolicy::default(),
         }
     }
 
-    /// カートアイテムのリストからカートを作成
-    pub fn from_items(items: Vec<CartItem>) -> Self {
+    /// 永続化されたカートを復元
+    /// 手数料とクーポンは保存しないため、選択済みの配送方法・支払い方法・クーポンを再適用して計算する
+    pub fn restore(
+        id: CartId,
+        items: Vec<CartItem>,
+        shipping_method_id: Option<ShippingMethodId>,
+        payment_method_id: Option<PaymentMethodId>,
+        coupon_code: Option<CouponCode>,
+    ) -> Self {
         Self {
+            id,
             items,
+            shipping_method_id,
+            payment_method_id,
+            coupon_code,
             shipping_fee: None,
             payment_fee: None,
             coupon: None,
+            promotions: Vec::new(),
+            tax_policy: TaxPolicy::default(),
         }
     }
 
@@ -88,11 +121,12 @@ impl Cart {
 
     /// 配送料を計算
     /// Clean Architecture: Entity First - 配送料計算はCartの責務
+    /// 送料無料の判定にはクーポン割引前の商品小計を使う
     pub fn calculate_shipping_fee(
         &self,
         shipping_method: &crate::domain::entities::ShippingMethod,
+        shipment: &crate::domain::entities::ShipmentDetails,
     ) -> Result<Money, DomainError> {
-        // 配送方法エンティティ全体を使用してより柔軟な計算が可能
         if !shipping_method.is_active() {
             return Err(DomainError::InvalidProductData(
                 "Shipping method is not active".to_string(),
@@ -100,11 +134,9 @@ impl Cart {
         }
 
         let calculation = self.calculate()?;
-        let _cart_total = calculation.final_subtotal;
 
-        // 将来的にはカート金額による配送料無料、重量制限、地域制限なども考慮可能
-        // 現在はシンプルに配送方法の料金をそのまま適用
-        Ok(*shipping_method.price())
+        // 地域・送料無料条件・大型商品・即日出荷限定は配送方法の料金表で判定する
+        shipping_method.calculate_fee(calculation.original_subtotal, shipment)
     }
 
     /// 支払い手数料を計算
@@ -125,8 +157,10 @@ impl Cart {
     pub fn apply_shipping_method(
         &mut self,
         shipping_method: &crate::domain::entities::ShippingMethod,
+        shipment: &crate::domain::entities::ShipmentDetails,
     ) -> Result<(), DomainError> {
-        let fee = self.calculate_shipping_fee(shipping_method)?;
+        let fee = self.calculate_shipping_fee(shipping_method, shipment)?;
+        self.shipping_method_id = Some(shipping_method.id().clone());
         self.shipping_fee = Some(fee);
         Ok(())
     }
@@ -138,6 +172,7 @@ impl Cart {
         payment_method: &crate::domain::entities::PaymentMethod,
     ) -> Result<(), DomainError> {
         let fee = self.calculate_payment_fee(payment_method)?;
+        self.payment_method_id = Some(PaymentMethodId::new(payment_method.id().to_string())?);
         self.payment_fee = Some(fee);
         Ok(())
     }
@@ -161,15 +196,28 @@ impl Cart {
             ));
         }
 
+        self.coupon_code = Some(coupon.code().clone());
         self.coupon = Some(coupon);
         Ok(())
     }
 
     /// クーポンを削除
     pub fn remove_coupon(&mut self) {
+        self.coupon_code = None;
         self.coupon = None;
     }
 
+    /// 実施中のプロモーションを適用（適用条件は計算時に判定する）
+    /// 支払い手数料は割引後の小計で計算するため、支払い方法より先に適用する
+    pub fn apply_promotions(&mut self, promotions: Vec<Promotion>) {
+        self.promotions = promotions;
+    }
+
+    /// 税計算ポリシーを適用
+    pub fn apply_tax_policy(&mut self, tax_policy: TaxPolicy) {
+        self.tax_policy = tax_policy;
+    }
+
     /// カートをクリア
     pub fn clear(&mut self) {
         self.items.clear();
@@ -195,45 +243,72 @@ impl Cart {
             original_subtotal = original_subtotal.add(item_subtotal)?;
         }
 
-        // 2. クーポン割引を計算
-        let discount_amount = if let Some(coupon) = &self.coupon {
-            let purchase_info = self.to_purchase_info(original_subtotal)?;
+        // 2. プロモーション割引を計算（優先度・併用ルールはPromotionServiceで判定する）
+        let purchase_info = self.to_purchase_info(original_subtotal)?;
+        let promotion_result =
+            PromotionService::apply_promotions(&self.promotions, &purchase_info, Utc::now())?;
+        let mut item_discounts = promotion_result.line_discounts;
+        let mut discount_amount = promotion_result.discount_amount;
+        let mut shipping_discount = promotion_result.shipping_discount;
+
+        // 3. クーポン割引を計算（送料無料クーポンは配送料の割引額も返す）
+        // 割引は対象のカートアイテムごとに割り当て、プロモーション割引後の金額を超えないようにする
+        if let Some(coupon) = &self.coupon {
             let discount_result = CouponDiscountService::apply_coupon(coupon, &purchase_info)?;
-            discount_result.discount_amount
-        } else {
-            Money::from_yen(0)
-        };
+            for ((item, item_discount), coupon_discount) in self
+                .items
+                .iter()
+                .zip(item_discounts.iter_mut())
+                .zip(discount_result.line_discounts)
+            {
+                let remaining = item.subtotal()?.subtract(*item_discount)?;
+                let coupon_discount = coupon_discount.min(remaining);
+                *item_discount = item_discount.add(coupon_discount)?;
+                discount_amount = discount_amount.add(coupon_discount)?;
+            }
+            shipping_discount = shipping_discount.max(discount_result.shipping_discount);
+        }
 
-        // 3. 割引後小計
+        // 4. 割引後小計
         let final_subtotal = original_subtotal.subtract(discount_amount)?;
 
-        // 4. 税額と税込み合計
-        let tax_amount = final_subtotal.tax_amount();
-        let total_with_tax = final_subtotal.with_tax();
-
-        // 5. 手数料
-        let shipping_fee = self.shipping_fee.unwrap_or(Money::from_yen(0));
+        // 5. 手数料（配送料はクーポン・プロモーションによる割引後）
+        let shipping_fee = self
+            .shipping_fee
+            .unwrap_or(Money::from_yen(0))
+            .subtract(shipping_discount)?;
         let payment_fee = self.payment_fee.unwrap_or(Money::from_yen(0));
 
-        // 6. 最終合計を計算
-        let grand_total = total_with_tax
-            .add(shipping_fee)?
-            .add(payment_fee)?;
+        // 6. 税額（商品は税区分ごとの税率で割引後の金額に課税、手数料は標準税率）
+        let mut lines = Vec::with_capacity(self.items.len() + 2);
+        for (item, item_discount) in self.items.iter().zip(&item_discounts) {
+            lines.push(
+                TaxableLine::item(item.subtotal()?, item.tax_category())
+                    .with_discount(*item_discount),
+            );
+        }
+        lines.push(TaxableLine::fee(shipping_fee));
+        lines.push(TaxableLine::fee(payment_fee));
+        let tax = self.tax_policy.calculate(&lines, discount_amount)?;
 
         // 7. 結果を構築
         Ok(CartCalculationResult::new(
             original_subtotal,
             discount_amount,
             final_subtotal,
-            tax_amount,
-            total_with_tax,
             shipping_fee,
             payment_fee,
-            grand_total,
-        ))
+            tax,
+        )
+        .with_item_discounts(item_discounts)
+        .with_applied_promotions(promotion_result.applied_promotions))
     }
 
     // Getters
+    pub fn id(&self) -> &CartId {
+        &self.id
+    }
+
     pub fn items(&self) -> &[CartItem] {
         &self.items
     }
@@ -246,10 +321,30 @@ impl Cart {
         self.payment_fee
     }
 
+    pub fn shipping_method_id(&self) -> Option<&ShippingMethodId> {
+        self.shipping_method_id.as_ref()
+    }
+
+    pub fn payment_method_id(&self) -> Option<&PaymentMethodId> {
+        self.payment_method_id.as_ref()
+    }
+
+    pub fn coupon_code(&self) -> Option<&CouponCode> {
+        self.coupon_code.as_ref()
+    }
+
     pub fn coupon(&self) -> Option<&crate::domain::entities::Coupon> {
         self.coupon.as_ref()
     }
 
+    pub fn promotions(&self) -> &[Promotion] {
+        &self.promotions
+    }
+
+    pub fn tax_policy(&self) -> &TaxPolicy {
+        &self.tax_policy
+    }
+
     /// PurchaseInfoに変換
     /// クーポン割引計算に必要な情報を集約したPurchaseInfoを生成
     fn to_purchase_info(&self, items_subtotal: Money) -> Result<PurchaseInfo, DomainError> {
@@ -389,7 +484,101 @@ mod tests {
         let calculation = cart.calculate().unwrap();
 
         assert_eq!(calculation.tax_amount.yen(), 100); // 10% of 1000
-        assert_eq!(calculation.total_with_tax.yen(), 1100); // 1000 + 100
+        assert_eq!(calculation.grand_total.yen(), 1100); // 1000 + 100
+    }
+
+    #[test]
+    fn calculate_reduced_rate_items_and_taxed_fees() {
+        let mut cart = Cart::new();
+        cart.add_item(
+            create_test_cart_item("Food", 1000, 1).with_tax_category(TaxCategory::Reduced),
+        )
+        .unwrap();
+        cart.add_item(create_test_cart_item("Product", 2000, 1)).unwrap();
+        cart.shipping_fee = Some(Money::from_yen(500));
+
+        let calculation = cart.calculate().unwrap();
+
+        // 10%: (2000 + 500) -> 250, 8%: 1000 -> 80
+        assert_eq!(calculation.tax_breakdown.len(), 2);
+        assert_eq!(calculation.tax_breakdown[0].rate, 10);
+        assert_eq!(calculation.tax_breakdown[0].tax_amount.yen(), 250);
+        assert_eq!(calculation.tax_breakdown[1].rate, 8);
+        assert_eq!(calculation.tax_breakdown[1].tax_amount.yen(), 80);
+        assert_eq!(calculation.tax_amount.yen(), 330);
+        assert_eq!(calculation.grand_total.yen(), 3830);
+    }
+
+    #[test]
+    fn calculate_with_tax_inclusive_policy() {
+        let mut cart = Cart::new();
+        cart.apply_tax_policy(TaxPolicy::default().with_price_mode(TaxPriceMode::Inclusive));
+        cart.add_item(create_test_cart_item("Product", 1100, 1)).unwrap();
+
+        let calculation = cart.calculate().unwrap();
+
+        assert_eq!(calculation.tax_amount.yen(), 100);
+        assert_eq!(calculation.grand_total.yen(), 1100);
+        assert!(calculation.tax_included);
+    }
+
+    #[test]
+    fn calculate_with_free_shipping_coupon() {
+        let mut cart = Cart::new();
+        cart.add_item(create_test_cart_item("Product", 1000, 1)).unwrap();
+        cart.shipping_fee = Some(Money::from_yen(500));
+        cart.apply_coupon(Coupon::new(
+            CouponId::new(),
+            CouponCode::from_string("FREESHIP".to_string()).unwrap(),
+            "送料無料".to_string(),
+            None,
+            DiscountPolicy::new(DiscountType::FreeShipping, None),
+            chrono::Utc::now() - chrono::Duration::days(1),
+            chrono::Utc::now() + chrono::Duration::days(1),
+            None,
+            0,
+        ))
+        .unwrap();
+
+        let calculation = cart.calculate().unwrap();
+
+        assert_eq!(calculation.discount_amount.yen(), 0);
+        assert_eq!(calculation.shipping_fee.yen(), 0);
+        assert_eq!(calculation.grand_total.yen(), 1100);
+    }
+
+    #[test]
+    fn calculate_with_product_specific_coupon() {
+        let target = create_test_cart_item("Target", 2000, 1);
+        let mut cart = Cart::new();
+        cart.add_item(create_test_cart_item("Other", 3000, 1)).unwrap();
+        cart.add_item(target.clone()).unwrap();
+        cart.apply_coupon(Coupon::new(
+            CouponId::new(),
+            CouponCode::from_string("TARGET50".to_string()).unwrap(),
+            "対象商品50%オフ".to_string(),
+            None,
+            DiscountPolicy::new(
+                DiscountType::Percentage(50),
+                Some(DiscountCondition::ProductSpecific(vec![
+                    target.product_id().clone(),
+                ])),
+            ),
+            chrono::Utc::now() - chrono::Duration::days(1),
+            chrono::Utc::now() + chrono::Duration::days(1),
+            None,
+            0,
+        ))
+        .unwrap();
+
+        let calculation = cart.calculate().unwrap();
+
+        assert_eq!(calculation.discount_amount.yen(), 1000);
+        assert_eq!(
+            calculation.item_discounts,
+            vec![Money::zero(), Money::from_yen(1000)]
+        );
+        assert_eq!(calculation.grand_total.yen(), 4400);
     }
 
     #[test]
@@ -422,4 +611,29 @@ mod tests {
 
         assert_eq!(purchase_info.subtotal().yen(), 2000);
     }
+
+    #[test]
+    fn restore_keeps_identity_and_selections() {
+        let id = CartId::new();
+        let item = create_test_cart_item("Product 1", 1000, 2);
+        let mut cart = Cart::restore(
+            id,
+            vec![item],
+            Some(ShippingMethodId::new("standard".to_string()).unwrap()),
+            Some(PaymentMethodId::new("credit_card".to_string()).unwrap()),
+            Some(CouponCode::from_string("WELCOME10".to_string()).unwrap()),
+        );
+
+        assert_eq!(cart.id(), &id);
+        assert_eq!(cart.item_count(), 1);
+        assert_eq!(cart.shipping_method_id().unwrap().value(), "standard");
+        assert_eq!(cart.payment_method_id().unwrap().value(), "credit_card");
+        assert_eq!(cart.coupon_code().unwrap().value(), "WELCOME10");
+        // 手数料とクーポンは再適用されるまで計算に含まれない
+        assert_eq!(cart.shipping_fee(), None);
+        assert!(cart.coupon().is_none());
+
+        cart.remove_coupon();
+        assert!(cart.coupon_code().is_none());
+    }
 }
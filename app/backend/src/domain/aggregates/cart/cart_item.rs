@@ -9,6 +9,8 @@ pub struct CartItem {
     product_name: ProductName,
     unit_price: Money,
     quantity: u32,
+    tax_category: TaxCategory,
+    category_id: Option<CategoryId>,
 }
 
 impl CartItem {
@@ -38,9 +40,23 @@ impl CartItem {
             product_name,
             unit_price,
             quantity,
+            tax_category: TaxCategory::default(),
+            category_id: None,
         })
     }
 
+    /// 税区分を設定（省略時は標準税率）
+    pub fn with_tax_category(mut self, tax_category: TaxCategory) -> Self {
+        self.tax_category = tax_category;
+        self
+    }
+
+    /// 商品のカテゴリを設定（カテゴリ指定クーポンの対象判定に使う）
+    pub fn with_category_id(mut self, category_id: CategoryId) -> Self {
+        self.category_id = Some(category_id);
+        self
+    }
+
     /// 小計を計算（単価 × 数量）
     pub fn subtotal(&self) -> Result<Money, DomainError> {
         self.unit_price.multiply(self.quantity)
@@ -98,6 +114,14 @@ impl CartItem {
     pub fn quantity(&self) -> u32 {
         self.quantity
     }
+
+    pub fn tax_category(&self) -> TaxCategory {
+        self.tax_category
+    }
+
+    pub fn category_id(&self) -> Option<&CategoryId> {
+        self.category_id.as_ref()
+    }
 }
 
 #[cfg(test)]
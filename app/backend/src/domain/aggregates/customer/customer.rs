@@ -0,0 +1,120 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::aggregates::order::CustomerInfo;
+use crate::domain::value_objects::{CustomerId, Email, PersonalInfo, PhoneNumber};
+
+/// 会員（ログイン可能な顧客アカウント）
+/// パスワードはハッシュ化済みの文字列のみを保持する
+#[derive(Debug, Clone)]
+pub struct Customer {
+    id: CustomerId,
+    email: Email,
+    password_hash: String,
+    personal_info: PersonalInfo,
+    phone: PhoneNumber,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Customer {
+    /// 新規会員を登録
+    pub fn register(
+        email: Email,
+        password_hash: String,
+        personal_info: PersonalInfo,
+        phone: PhoneNumber,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: CustomerId::new(),
+            email,
+            password_hash,
+            personal_info,
+            phone,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 永続化された値から会員を復元
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: CustomerId,
+        email: Email,
+        password_hash: String,
+        personal_info: PersonalInfo,
+        phone: PhoneNumber,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            email,
+            password_hash,
+            personal_info,
+            phone,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// 注文に記録する顧客情報
+    pub fn customer_info(&self) -> CustomerInfo {
+        CustomerInfo::new(
+            self.personal_info.clone(),
+            self.email.clone(),
+            self.phone.clone(),
+        )
+    }
+
+    pub fn id(&self) -> CustomerId {
+        self.id
+    }
+
+    pub fn email(&self) -> &Email {
+        &self.email
+    }
+
+    pub fn password_hash(&self) -> &str {
+        &self.password_hash
+    }
+
+    pub fn personal_info(&self) -> &PersonalInfo {
+        &self.personal_info
+    }
+
+    pub fn phone(&self) -> &PhoneNumber {
+        &self.phone
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_customer() {
+        let customer = Customer::register(
+            Email::new("tanaka@example.com".to_string()).unwrap(),
+            "hashed".to_string(),
+            PersonalInfo::from_strings("太郎".to_string(), "田中".to_string()).unwrap(),
+            PhoneNumber::new("090-1234-5678".to_string()).unwrap(),
+        );
+
+        assert_eq!(customer.password_hash(), "hashed");
+        assert_eq!(customer.created_at(), customer.updated_at());
+
+        let info = customer.customer_info();
+        assert_eq!(info.email_address(), "tanaka@example.com");
+        assert_eq!(info.full_name(), "太郎 田中");
+        assert_eq!(info.phone_number(), "090-1234-5678");
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::value_objects::{CustomerId, SessionId};
+
+/// 会員のログインセッション
+/// 有効期限内かつログアウトされていない間だけ有効
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomerSession {
+    id: SessionId,
+    customer_id: CustomerId,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl CustomerSession {
+    /// 新しいセッションを発行
+    pub fn issue(customer_id: CustomerId, now: DateTime<Utc>, ttl: Duration) -> Self {
+        Self {
+            id: SessionId::new(),
+            customer_id,
+            created_at: now,
+            expires_at: now + ttl,
+            revoked_at: None,
+        }
+    }
+
+    /// 永続化された値からセッションを復元
+    pub fn restore(
+        id: SessionId,
+        customer_id: CustomerId,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+        revoked_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            customer_id,
+            created_at,
+            expires_at,
+            revoked_at,
+        }
+    }
+
+    /// 指定日時にセッションが有効か
+    pub fn is_valid_at(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && now < self.expires_at
+    }
+
+    /// セッションを失効させる（ログアウト）
+    pub fn revoke(&mut self, now: DateTime<Utc>) {
+        if self.revoked_at.is_none() {
+            self.revoked_at = Some(now);
+        }
+    }
+
+    pub fn id(&self) -> SessionId {
+        self.id
+    }
+
+    pub fn customer_id(&self) -> CustomerId {
+        self.customer_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_valid_until_expiry() {
+        let now = Utc::now();
+        let session = CustomerSession::issue(CustomerId::new(), now, Duration::hours(1));
+
+        assert!(session.is_valid_at(now));
+        assert!(session.is_valid_at(now + Duration::minutes(59)));
+        assert!(!session.is_valid_at(now + Duration::hours(1)));
+    }
+
+    #[test]
+    fn test_revoked_session_is_invalid() {
+        let now = Utc::now();
+        let mut session = CustomerSession::issue(CustomerId::new(), now, Duration::hours(1));
+
+        session.revoke(now);
+        session.revoke(now + Duration::minutes(5));
+
+        assert!(!session.is_valid_at(now));
+        assert_eq!(session.revoked_at(), Some(now));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod customer;
+pub mod customer_session;
+
+pub use self::customer::Customer;
+pub use self::customer_session::CustomerSession;
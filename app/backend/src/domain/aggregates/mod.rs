@@ -1,4 +1,6 @@
 mod cart;
+pub mod customer;
 pub mod order;
+pub mod return_request;
 
 pub use self::cart::*;
@@ -0,0 +1,20 @@
+use crate::domain::value_objects::{CouponCode, CouponId, Money};
+
+/// 注文に適用されたクーポン
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedCoupon {
+    pub coupon_id: CouponId,
+    pub code: CouponCode,
+    /// 割引金額（税抜）
+    pub discount: Money,
+}
+
+impl AppliedCoupon {
+    pub fn new(coupon_id: CouponId, code: CouponCode, discount: Money) -> Self {
+        Self {
+            coupon_id,
+            code,
+            discount,
+        }
+    }
+}
@@ -1,12 +1,20 @@
+pub mod applied_coupon;
 pub mod customer_info;
 pub mod order;
+pub mod order_amendment;
+pub mod order_document;
+pub mod order_event;
 pub mod order_item;
 pub mod order_pricing;
 pub mod payment_info;
 pub mod shipping_info;
 
+pub use self::applied_coupon::AppliedCoupon;
 pub use self::customer_info::CustomerInfo;
-pub use self::order::Order;
+pub use self::order::{Order, OrderStatus, OrderTimestamps};
+pub use self::order_amendment::OrderAmendment;
+pub use self::order_document::OrderDocumentKind;
+pub use self::order_event::{OrderEvent, OrderEventKind, ReservationChange, StockEffect};
 pub use self::order_item::OrderItem;
 pub use self::order_pricing::OrderPricing;
 pub use self::payment_info::PaymentInfo;
@@ -1,5 +1,8 @@
-use super::{CustomerInfo, OrderItem, OrderPricing, PaymentInfo, ShippingInfo};
-use crate::domain::entities::DeliveryInfo;
+use super::{
+    AppliedCoupon, CustomerInfo, OrderAmendment, OrderDocumentKind, OrderEvent, OrderEventKind,
+    OrderItem, OrderPricing, PaymentInfo, ReservationChange, ShippingInfo,
+};
+use crate::domain::entities::{DeliveryInfo, DeliveryStatus};
 use crate::domain::error::DomainError;
 use crate::domain::value_objects::*;
 use chrono::{DateTime, Utc};
@@ -29,6 +32,18 @@ impl std::fmt::Display for OrderStatus {
     }
 }
 
+impl OrderStatus {
+    /// 在庫を引当（予約）のまま保持しているステータスか
+    pub fn holds_stock_reservation(&self) -> bool {
+        matches!(self, OrderStatus::Pending)
+    }
+
+    /// 在庫を減算済みで、まだ出荷していないステータスか
+    pub fn holds_committed_stock(&self) -> bool {
+        matches!(self, OrderStatus::Paid | OrderStatus::Processing)
+    }
+}
+
 impl std::str::FromStr for OrderStatus {
     type Err = crate::domain::error::DomainError;
 
@@ -63,23 +78,37 @@ pub struct Order {
     pub id: OrderId,
     pub order_number: OrderNumber,
     pub customer_info: CustomerInfo,
+    /// 注文した会員（ゲスト注文の場合はNone）
+    pub customer_id: Option<CustomerId>,
     pub items: Vec<OrderItem>,
     pub shipping_info: ShippingInfo,
     pub payment_info: PaymentInfo,
     pub pricing: OrderPricing,
+    /// 適用されたクーポン
+    pub coupon: Option<AppliedCoupon>,
+    /// 自動適用されたプロモーション（適用した順）
+    pub promotions: Vec<AppliedPromotion>,
     pub status: OrderStatus,
     pub timestamps: OrderTimestamps,
     pub delivery_info: Option<DeliveryInfo>,
     pub notes: Option<String>,
+    /// 読み込み時のバージョン（リポジトリが更新のたびに1つ進め、同時更新の検出に使う）
+    pub version: u32,
+    /// 未保存のドメインイベント（リポジトリが保存時にorder_eventsへ書き込む）
+    pub events: Vec<OrderEvent>,
 }
 
 impl Order {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         order_number: OrderNumber,
         customer_info: CustomerInfo,
         items: Vec<OrderItem>,
         shipping_info: ShippingInfo,
         payment_info: PaymentInfo,
+        coupon: Option<AppliedCoupon>,
+        promotions: Vec<AppliedPromotion>,
+        tax_policy: &TaxPolicy,
     ) -> Result<Self, DomainError> {
         if items.is_empty() {
             return Err(DomainError::InvalidProductData(
@@ -87,17 +116,29 @@ impl Order {
             ));
         }
 
-        let pricing = Self::calculate_pricing(&items, &shipping_info, &payment_info)?;
+        let discount = Self::total_discount(coupon.as_ref(), &promotions)?;
+        let pricing =
+            Self::calculate_pricing(&items, discount, &shipping_info, &payment_info, tax_policy)?;
         let now = Utc::now();
+        let created_event = OrderEvent::new(
+            OrderEventKind::OrderCreated {
+                order_number: order_number.value().to_string(),
+                total: pricing.total_yen(),
+            },
+            now,
+        );
 
         Ok(Order {
             id: OrderId::new(),
             order_number,
             customer_info,
+            customer_id: None,
             items,
             shipping_info,
             payment_info,
             pricing,
+            coupon,
+            promotions,
             status: OrderStatus::Pending,
             timestamps: OrderTimestamps {
                 created_at: now,
@@ -109,13 +150,28 @@ impl Order {
             },
             delivery_info: None,
             notes: None,
+            version: 0,
+            events: vec![created_event],
         })
     }
 
+    /// 会員の注文として紐付ける
+    pub fn with_customer_id(mut self, customer_id: Option<CustomerId>) -> Self {
+        self.customer_id = customer_id;
+        self
+    }
+
     pub fn update_status(&mut self, new_status: OrderStatus) -> Result<(), DomainError> {
         self.validate_status_transition(&new_status)?;
 
         let now = Utc::now();
+        self.events.push(OrderEvent::new(
+            OrderEventKind::StatusChanged {
+                from: self.status.clone(),
+                to: new_status.clone(),
+            },
+            now,
+        ));
         self.status = new_status.clone();
         self.timestamps.updated_at = now;
 
@@ -138,31 +194,99 @@ impl Order {
                 self.status
             )));
         }
+        // 配送失敗した出荷のみ再出荷として差し替えられる
+        if let Some(current) = &self.delivery_info
+            && current.status != DeliveryStatus::Failed
+        {
+            return Err(DomainError::BusinessRuleViolation(format!(
+                "Order already has an active shipment: {}",
+                current.id
+            )));
+        }
 
+        let now = Utc::now();
+        self.events.push(OrderEvent::new(
+            OrderEventKind::DeliveryInfoAttached {
+                delivery_info_id: delivery_info.id().value().to_string(),
+            },
+            now,
+        ));
         self.delivery_info = Some(delivery_info);
-        self.timestamps.updated_at = Utc::now();
+        self.timestamps.updated_at = now;
         Ok(())
     }
 
+    /// 配送業者から通知された配送状態を反映し、注文ステータスを同期する
+    ///
+    /// 既に同じ状態であれば何もせず`false`を返す（通知の再送に対して冪等）。
+    /// 発送で注文を発送済みに、配達完了で配達済みに遷移させる。
+    pub fn advance_delivery(&mut self, new_status: DeliveryStatus) -> Result<bool, DomainError> {
+        let Some(delivery_info) = self.delivery_info.as_mut() else {
+            return Err(DomainError::BusinessRuleViolation(
+                "Order has no shipment attached".to_string(),
+            ));
+        };
+        if delivery_info.status == new_status {
+            return Ok(false);
+        }
+
+        match new_status {
+            DeliveryStatus::Shipped => delivery_info.mark_as_shipped()?,
+            DeliveryStatus::InTransit => delivery_info.mark_as_in_transit()?,
+            DeliveryStatus::Delivered => delivery_info.mark_as_delivered()?,
+            DeliveryStatus::Failed => delivery_info.mark_as_failed()?,
+            DeliveryStatus::Pending | DeliveryStatus::Processing => {
+                delivery_info.update_status(new_status.clone())?
+            }
+        }
+
+        match new_status {
+            DeliveryStatus::Shipped => {
+                if self.status == OrderStatus::Paid {
+                    self.update_status(OrderStatus::Processing)?;
+                }
+                if self.status == OrderStatus::Processing {
+                    self.update_status(OrderStatus::Shipped)?;
+                }
+            }
+            DeliveryStatus::Delivered if self.status != OrderStatus::Delivered => {
+                self.update_status(OrderStatus::Delivered)?;
+            }
+            _ => {}
+        }
+        self.timestamps.updated_at = Utc::now();
+
+        Ok(true)
+    }
+
     pub fn cancel(&mut self, reason: String) -> Result<(), DomainError> {
         if matches!(
             self.status,
             OrderStatus::Delivered | OrderStatus::Cancelled | OrderStatus::Refunded
         ) {
-            return Err(DomainError::InvalidProductData(format!(
-                "Cannot cancel order in status {:?}",
-                self.status
-            )));
+            return Err(DomainError::InvalidStatusTransition {
+                from: self.status.to_string(),
+                to: OrderStatus::Cancelled.to_string(),
+            });
         }
 
+        let now = Utc::now();
+        self.events.push(OrderEvent::new(
+            OrderEventKind::Cancelled {
+                from: self.status.clone(),
+                reason: reason.clone(),
+            },
+            now,
+        ));
         self.status = OrderStatus::Cancelled;
         self.notes = Some(reason);
-        self.timestamps.cancelled_at = Some(Utc::now());
-        self.timestamps.updated_at = Utc::now();
+        self.timestamps.cancelled_at = Some(now);
+        self.timestamps.updated_at = now;
 
         Ok(())
     }
 
+    /// 注文に備考を追加し、イベント履歴に記録する（ステータスは変えない）
     pub fn add_note(&mut self, note: String) -> Result<(), DomainError> {
         if note.len() > 1000 {
             return Err(DomainError::InvalidProductData(
@@ -170,11 +294,136 @@ impl Order {
             ));
         }
 
+        let now = Utc::now();
+        self.events.push(OrderEvent::new(
+            OrderEventKind::NoteAdded { note: note.clone() },
+            now,
+        ));
         self.notes = Some(note);
-        self.timestamps.updated_at = Utc::now();
+        self.timestamps.updated_at = now;
         Ok(())
     }
 
+    /// 保留中（Pending）の注文の明細・配送方法・支払い方法・配送先を変更し、金額を再計算する
+    ///
+    /// 変更がなければ何もせず`false`を返す。
+    /// 引当済みの在庫は明細の差分だけ増減させるよう、SKUごとの増減をイベントに記録する。
+    pub fn amend(
+        &mut self,
+        amendment: OrderAmendment,
+        tax_policy: &TaxPolicy,
+    ) -> Result<bool, DomainError> {
+        if !self.can_be_modified() {
+            return Err(DomainError::BusinessRuleViolation(format!(
+                "Only pending orders can be amended (current status: {})",
+                self.status
+            )));
+        }
+        if amendment.items.is_empty() {
+            return Err(DomainError::InvalidProductData(
+                "Order must have at least one item".to_string(),
+            ));
+        }
+        for (index, item) in amendment.items.iter().enumerate() {
+            if amendment.items[..index]
+                .iter()
+                .any(|other| other.is_same_sku(&item.sku_id))
+            {
+                return Err(DomainError::InvalidProductData(format!(
+                    "Duplicate SKU in order items: {}",
+                    item.sku_code.value()
+                )));
+            }
+        }
+
+        let reservation_changes = Self::reservation_changes(&self.items, &amendment.items);
+        let mut changes = Vec::new();
+        if !reservation_changes.is_empty() {
+            changes.push("items");
+        }
+        if self.shipping_info.method_id != amendment.shipping_info.method_id {
+            changes.push("shipping_method");
+        }
+        if self.shipping_info.address != amendment.shipping_info.address {
+            changes.push("shipping_address");
+        }
+        if self.payment_info.method_id != amendment.payment_info.method_id {
+            changes.push("payment_method");
+        }
+        if changes.is_empty() {
+            return Ok(false);
+        }
+
+        let discount = Self::total_discount(amendment.coupon.as_ref(), &amendment.promotions)?;
+        let pricing = Self::calculate_pricing(
+            &amendment.items,
+            discount,
+            &amendment.shipping_info,
+            &amendment.payment_info,
+            tax_policy,
+        )?;
+
+        let now = Utc::now();
+        self.events.push(OrderEvent::new(
+            OrderEventKind::Amended {
+                changes,
+                previous_total: self.pricing.total_yen(),
+                total: pricing.total_yen(),
+                reservation_changes,
+            },
+            now,
+        ));
+        self.items = amendment.items;
+        self.shipping_info = amendment.shipping_info;
+        self.payment_info = amendment.payment_info;
+        self.coupon = amendment.coupon;
+        self.promotions = amendment.promotions;
+        self.pricing = pricing;
+        self.timestamps.updated_at = now;
+
+        Ok(true)
+    }
+
+    /// 変更前後の明細から、SKUごとの引当数の増減を求める（増減のないSKUは含めない）
+    fn reservation_changes(before: &[OrderItem], after: &[OrderItem]) -> Vec<ReservationChange> {
+        let quantity_in = |items: &[OrderItem], item: &OrderItem| {
+            items
+                .iter()
+                .find(|other| other.is_same_sku(&item.sku_id))
+                .map_or(0, |other| other.quantity)
+        };
+
+        let changed = after
+            .iter()
+            .map(|item| (item, item.quantity - quantity_in(before, item)));
+        let removed = before
+            .iter()
+            .filter(|item| !after.iter().any(|other| other.is_same_sku(&item.sku_id)))
+            .map(|item| (item, -item.quantity));
+
+        changed
+            .chain(removed)
+            .filter(|(_, delta)| *delta != 0)
+            .map(|(item, quantity_delta)| ReservationChange {
+                sku_id: item.sku_id.clone(),
+                sku_code: item.sku_code.clone(),
+                quantity_delta,
+            })
+            .collect()
+    }
+
+    /// 割引額はクーポンとプロモーションの割引の合計
+    fn total_discount(
+        coupon: Option<&AppliedCoupon>,
+        promotions: &[AppliedPromotion],
+    ) -> Result<Money, DomainError> {
+        promotions
+            .iter()
+            .try_fold(coupon.map_or(Money::zero(), |c| c.discount), |total, p| {
+                total.add(p.discount)
+            })
+    }
+
     fn validate_status_transition(&self, new_status: &OrderStatus) -> Result<(), DomainError> {
         use OrderStatus::*;
 
@@ -191,10 +440,10 @@ impl Order {
         };
 
         if !valid {
-            return Err(DomainError::InvalidProductData(format!(
-                "Invalid status transition from {:?} to {:?}",
-                self.status, new_status
-            )));
+            return Err(DomainError::InvalidStatusTransition {
+                from: self.status.to_string(),
+                to: new_status.to_string(),
+            });
         }
 
         Ok(())
@@ -202,25 +451,35 @@ impl Order {
 
     fn calculate_pricing(
         items: &[OrderItem],
+        discount: Money,
         shipping_info: &ShippingInfo,
         payment_info: &PaymentInfo,
+        tax_policy: &TaxPolicy,
     ) -> Result<OrderPricing, DomainError> {
         let mut subtotal = Money::zero();
+        let mut lines = Vec::with_capacity(items.len() + 2);
         for item in items {
-            subtotal = subtotal.add(item.subtotal()?)?;
+            let item_subtotal = item.subtotal()?;
+            subtotal = subtotal.add(item_subtotal)?;
+            lines.push(
+                TaxableLine::item(item_subtotal, item.tax_category)
+                    .with_discount(item.discount_amount),
+            );
         }
+        lines.push(TaxableLine::fee(shipping_info.fee));
+        lines.push(TaxableLine::fee(payment_info.fee));
 
-        let total_before_tax = subtotal.add(shipping_info.fee)?.add(payment_info.fee)?;
-        let tax_amount = total_before_tax.tax_amount();
-        let total = total_before_tax.add(tax_amount)?;
+        // 税額はクーポン割引を商品に按分した後の金額に対して、税率ごとに計算する
+        // 明細ごとの割引額がある場合はその明細に、残りは金額比で按分する
+        let tax = tax_policy.calculate(&lines, discount)?;
 
-        Ok(OrderPricing {
+        Ok(OrderPricing::from_tax_calculation(
             subtotal,
-            shipping_fee: shipping_info.fee,
-            payment_fee: payment_info.fee,
-            tax_amount,
-            total,
-        })
+            discount,
+            shipping_info.fee,
+            payment_info.fee,
+            tax,
+        ))
     }
 
     pub fn can_be_cancelled(&self) -> bool {
@@ -234,6 +493,27 @@ impl Order {
         matches!(self.status, OrderStatus::Pending)
     }
 
+    /// 請求書・領収書を発行できるか確認する
+    /// 請求書はキャンセル以外の注文、領収書は支払い済みで返金・キャンセルされていない注文に発行できる
+    pub fn ensure_document_issuable(&self, kind: OrderDocumentKind) -> Result<(), DomainError> {
+        let issuable = match kind {
+            OrderDocumentKind::Invoice => self.status != OrderStatus::Cancelled,
+            OrderDocumentKind::Receipt => {
+                self.timestamps.paid_at.is_some()
+                    && !matches!(self.status, OrderStatus::Cancelled | OrderStatus::Refunded)
+            }
+        };
+        if !issuable {
+            return Err(DomainError::BusinessRuleViolation(format!(
+                "Cannot issue {} for this order (current status: {})",
+                kind.code(),
+                self.status
+            )));
+        }
+
+        Ok(())
+    }
+
     pub fn total_item_count(&self) -> i32 {
         self.items.iter().map(|item| item.quantity).sum()
     }
@@ -245,6 +525,10 @@ mod tests {
     use crate::domain::value_objects::*;
 
     fn create_test_order() -> Order {
+        create_test_order_with_coupon(None)
+    }
+
+    fn create_test_order_with_coupon(coupon: Option<AppliedCoupon>) -> Order {
         let order_number = OrderNumber::generate(2024, 1);
         let customer_info = CustomerInfo::new(
             PersonalInfo::new(
@@ -294,10 +578,117 @@ mod tests {
             items,
             shipping_info,
             payment_info,
+            coupon,
+            Vec::new(),
+            &TaxPolicy::default(),
         )
         .unwrap()
     }
 
+    #[test]
+    fn test_create_order_with_coupon() {
+        let coupon = AppliedCoupon::new(
+            CouponId::new(),
+            CouponCode::from_string("SAVE20".to_string()).unwrap(),
+            Money::from_yen(400),
+        );
+        let order = create_test_order_with_coupon(Some(coupon));
+
+        // (2000 - 400 + 500 + 100) = 2200 yen before tax, 10% tax = 220 yen
+        assert_eq!(order.pricing.subtotal_yen(), 2000);
+        assert_eq!(order.pricing.discount_yen(), 400);
+        assert_eq!(order.pricing.tax_amount_yen(), 220);
+        assert_eq!(order.pricing.total_yen(), 2420);
+        assert!(order.pricing.verify_calculations());
+    }
+
+    fn amendment_of(order: &Order, items: Vec<OrderItem>) -> OrderAmendment {
+        OrderAmendment {
+            items,
+            shipping_info: order.shipping_info.clone(),
+            payment_info: order.payment_info.clone(),
+            coupon: order.coupon.clone(),
+            promotions: order.promotions.clone(),
+        }
+    }
+
+    #[test]
+    fn test_amend_reprices_and_records_reservation_changes() {
+        let mut order = create_test_order();
+        let mut existing = order.items[0].clone();
+        existing.update_quantity(3).unwrap();
+        let added = OrderItem::new(
+            SKUId::new(),
+            SKUCode::new("TEST-002".to_string()).unwrap(),
+            ProductName::new("Added Product".to_string()).unwrap(),
+            SKUName::new("Added SKU".to_string()).unwrap(),
+            Money::from_yen(500),
+            1,
+        )
+        .unwrap();
+
+        let amendment = amendment_of(&order, vec![existing, added.clone()]);
+        assert!(order.amend(amendment, &TaxPolicy::default()).unwrap());
+
+        // (3000 + 500 + 500 + 100) = 4100 yen before tax, 10% tax = 410 yen
+        assert_eq!(order.pricing.subtotal_yen(), 3500);
+        assert_eq!(order.pricing.total_yen(), 4510);
+        let Some(OrderEventKind::Amended {
+            changes,
+            previous_total,
+            reservation_changes,
+            ..
+        }) = order.events.last().map(|event| &event.kind)
+        else {
+            panic!("expected amended event");
+        };
+        assert_eq!(changes, &vec!["items"]);
+        assert_eq!(*previous_total, 2860);
+        assert_eq!(
+            reservation_changes
+                .iter()
+                .map(|change| (change.sku_code.value(), change.quantity_delta))
+                .collect::<Vec<_>>(),
+            vec![("TEST-001", 1), ("TEST-002", 1)]
+        );
+
+        // 明細を削除すると、その数量分の引当を解除する
+        let amendment = amendment_of(&order, vec![added]);
+        assert!(order.amend(amendment, &TaxPolicy::default()).unwrap());
+        let Some(OrderEventKind::Amended {
+            reservation_changes,
+            ..
+        }) = order.events.last().map(|event| &event.kind)
+        else {
+            panic!("expected amended event");
+        };
+        assert_eq!(reservation_changes.len(), 1);
+        assert_eq!(reservation_changes[0].quantity_delta, -3);
+    }
+
+    #[test]
+    fn test_amend_without_changes_or_after_payment() {
+        let mut order = create_test_order();
+        let events = order.events.len();
+
+        let unchanged = amendment_of(&order, order.items.clone());
+        assert!(
+            !order
+                .amend(unchanged.clone(), &TaxPolicy::default())
+                .unwrap()
+        );
+        assert_eq!(order.events.len(), events);
+
+        let duplicated = amendment_of(&order, vec![order.items[0].clone(), order.items[0].clone()]);
+        assert!(order.amend(duplicated, &TaxPolicy::default()).is_err());
+
+        order.update_status(OrderStatus::Paid).unwrap();
+        assert!(matches!(
+            order.amend(unchanged, &TaxPolicy::default()),
+            Err(DomainError::BusinessRuleViolation(_))
+        ));
+    }
+
     #[test]
     fn test_create_order() {
         let order = create_test_order();
@@ -341,6 +732,19 @@ mod tests {
         assert!(order.update_status(OrderStatus::Shipped).is_err());
     }
 
+    #[test]
+    fn test_invalid_status_transition_error() {
+        let mut order = create_test_order();
+
+        assert_eq!(
+            order.update_status(OrderStatus::Delivered),
+            Err(DomainError::InvalidStatusTransition {
+                from: "pending".to_string(),
+                to: "delivered".to_string(),
+            })
+        );
+    }
+
     #[test]
     fn test_cancel_order() {
         let mut order = create_test_order();
@@ -351,6 +755,171 @@ mod tests {
         assert_eq!(order.notes, Some("Customer request".to_string()));
     }
 
+    #[test]
+    fn test_domain_events_recorded() {
+        let mut order = create_test_order();
+        assert_eq!(order.events.len(), 1);
+        assert_eq!(order.events[0].event_type(), "order_created");
+
+        order.update_status(OrderStatus::Paid).unwrap();
+        order.cancel("Customer request".to_string()).unwrap();
+
+        assert_eq!(order.events.len(), 3);
+        assert_eq!(
+            order.events[1].kind,
+            OrderEventKind::StatusChanged {
+                from: OrderStatus::Pending,
+                to: OrderStatus::Paid,
+            }
+        );
+        assert_eq!(
+            order.events[2].kind,
+            OrderEventKind::Cancelled {
+                from: OrderStatus::Paid,
+                reason: "Customer request".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_add_note_records_event() {
+        let mut order = create_test_order();
+
+        order
+            .add_note("配達日時の変更依頼あり".to_string())
+            .unwrap();
+
+        assert_eq!(order.notes, Some("配達日時の変更依頼あり".to_string()));
+        assert_eq!(
+            order.events.last().unwrap().kind,
+            OrderEventKind::NoteAdded {
+                note: "配達日時の変更依頼あり".to_string(),
+            }
+        );
+        assert!(order.add_note("a".repeat(1001)).is_err());
+        assert_eq!(order.events.len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_transition_records_no_event() {
+        let mut order = create_test_order();
+
+        assert!(order.update_status(OrderStatus::Shipped).is_err());
+        assert_eq!(order.events.len(), 1);
+    }
+
+    fn create_test_delivery_info(order: &Order) -> DeliveryInfo {
+        let mut delivery_info = DeliveryInfo::new(
+            order.customer_info.email.clone(),
+            order.customer_info.personal_info.clone(),
+            order.shipping_info.address.clone(),
+            order.customer_info.phone.clone(),
+            Some(order.shipping_info.method_name.clone()),
+        );
+        delivery_info
+            .set_tracking_info("ヤマト運輸".to_string(), "1234567890".to_string())
+            .unwrap();
+        delivery_info
+            .update_status(DeliveryStatus::Processing)
+            .unwrap();
+        delivery_info
+    }
+
+    #[test]
+    fn test_advance_delivery_syncs_order_status() {
+        let mut order = create_test_order();
+        order.update_status(OrderStatus::Paid).unwrap();
+        let delivery_info = create_test_delivery_info(&order);
+        order.add_delivery_info(delivery_info).unwrap();
+
+        assert_eq!(order.advance_delivery(DeliveryStatus::Shipped), Ok(true));
+        assert_eq!(order.status, OrderStatus::Shipped);
+        assert!(order.timestamps.shipped_at.is_some());
+
+        assert_eq!(order.advance_delivery(DeliveryStatus::InTransit), Ok(true));
+        assert_eq!(order.status, OrderStatus::Shipped);
+
+        assert_eq!(order.advance_delivery(DeliveryStatus::Delivered), Ok(true));
+        assert_eq!(order.status, OrderStatus::Delivered);
+        assert!(order.delivery_info.as_ref().unwrap().is_delivered());
+
+        // 同じ状態の再通知は何もしない
+        assert_eq!(order.advance_delivery(DeliveryStatus::Delivered), Ok(false));
+    }
+
+    #[test]
+    fn test_advance_delivery_rejects_invalid_transition() {
+        let mut order = create_test_order();
+        order.update_status(OrderStatus::Paid).unwrap();
+        let delivery_info = create_test_delivery_info(&order);
+        order.add_delivery_info(delivery_info).unwrap();
+
+        assert_eq!(
+            order.advance_delivery(DeliveryStatus::Delivered),
+            Err(DomainError::InvalidStatusTransition {
+                from: "processing".to_string(),
+                to: "delivered".to_string(),
+            })
+        );
+        assert_eq!(order.status, OrderStatus::Paid);
+    }
+
+    #[test]
+    fn test_add_delivery_info_rejects_active_shipment() {
+        let mut order = create_test_order();
+        order.update_status(OrderStatus::Paid).unwrap();
+        let first = create_test_delivery_info(&order);
+        order.add_delivery_info(first).unwrap();
+
+        let second = create_test_delivery_info(&order);
+        assert!(order.add_delivery_info(second.clone()).is_err());
+
+        // 配送失敗後は再出荷できる
+        order.advance_delivery(DeliveryStatus::Failed).unwrap();
+        assert!(order.add_delivery_info(second).is_ok());
+    }
+
+    #[test]
+    fn test_receipt_requires_payment() {
+        let mut order = create_test_order();
+
+        // 未払いの注文には請求書のみ発行できる
+        assert!(
+            order
+                .ensure_document_issuable(OrderDocumentKind::Invoice)
+                .is_ok()
+        );
+        assert!(
+            order
+                .ensure_document_issuable(OrderDocumentKind::Receipt)
+                .is_err()
+        );
+
+        order.update_status(OrderStatus::Paid).unwrap();
+        assert!(
+            order
+                .ensure_document_issuable(OrderDocumentKind::Receipt)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_cannot_issue_documents_for_cancelled_order() {
+        let mut order = create_test_order();
+        order.cancel("Customer request".to_string()).unwrap();
+
+        assert!(
+            order
+                .ensure_document_issuable(OrderDocumentKind::Invoice)
+                .is_err()
+        );
+        assert!(
+            order
+                .ensure_document_issuable(OrderDocumentKind::Receipt)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_cannot_cancel_delivered_order() {
         let mut order = create_test_order();
@@ -400,6 +969,9 @@ mod tests {
             items,
             shipping_info,
             payment_info,
+            None,
+            Vec::new(),
+            &TaxPolicy::default(),
         );
         assert!(result.is_err());
     }
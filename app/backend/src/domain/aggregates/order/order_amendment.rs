@@ -0,0 +1,13 @@
+use super::{AppliedCoupon, OrderItem, PaymentInfo, ShippingInfo};
+use crate::domain::value_objects::AppliedPromotion;
+
+/// 注文変更の内容（変更後の明細・配送・支払いと、再計算した割引）
+/// 配送料・決済手数料・割引額は変更後の内容で計算済みのものを渡す
+#[derive(Debug, Clone)]
+pub struct OrderAmendment {
+    pub items: Vec<OrderItem>,
+    pub shipping_info: ShippingInfo,
+    pub payment_info: PaymentInfo,
+    pub coupon: Option<AppliedCoupon>,
+    pub promotions: Vec<AppliedPromotion>,
+}
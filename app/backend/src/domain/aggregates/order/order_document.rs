@@ -0,0 +1,35 @@
+use crate::domain::error::DomainError;
+
+/// 注文の帳票の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderDocumentKind {
+    /// 請求書（発行元に登録番号がある場合は適格請求書）
+    Invoice,
+    /// 領収書
+    Receipt,
+}
+
+impl OrderDocumentKind {
+    /// APIで使う帳票の種類のコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            OrderDocumentKind::Invoice => "invoice",
+            OrderDocumentKind::Receipt => "receipt",
+        }
+    }
+}
+
+impl std::str::FromStr for OrderDocumentKind {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "invoice" => Ok(OrderDocumentKind::Invoice),
+            "receipt" => Ok(OrderDocumentKind::Receipt),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid document kind: {}",
+                s
+            ))),
+        }
+    }
+}
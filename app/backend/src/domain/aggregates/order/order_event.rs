@@ -0,0 +1,256 @@
+use super::order::OrderStatus;
+use crate::domain::value_objects::{SKUCode, SKUId};
+use chrono::{DateTime, Utc};
+use serde_json::json;
+
+/// 注文変更に伴うSKUごとの引当数の増減
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReservationChange {
+    pub sku_id: SKUId,
+    pub sku_code: SKUCode,
+    /// 増やす場合は正、減らす場合は負
+    pub quantity_delta: i32,
+}
+
+/// 注文集約で発生したドメインイベントの種類
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderEventKind {
+    /// 注文作成
+    OrderCreated { order_number: String, total: u32 },
+    /// ステータス変更
+    StatusChanged { from: OrderStatus, to: OrderStatus },
+    /// キャンセル（理由付き）
+    Cancelled { from: OrderStatus, reason: String },
+    /// 備考追加
+    NoteAdded { note: String },
+    /// 配送情報の紐付け
+    DeliveryInfoAttached { delivery_info_id: String },
+    /// 注文内容の変更（明細・配送方法・支払い方法・配送先）と再計算
+    Amended {
+        /// 変更した項目（items / shipping_method / payment_method / shipping_address）
+        changes: Vec<&'static str>,
+        previous_total: u32,
+        total: u32,
+        /// 引当数の増減（リポジトリが明細ごとの差分だけ引当・解除する）
+        reservation_changes: Vec<ReservationChange>,
+    },
+}
+
+/// イベントに伴う在庫への影響
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StockEffect {
+    /// 引当（予約数を増やす）
+    Reserve,
+    /// 引当分を実在庫から減算（予約数と在庫数を減らす）
+    Commit,
+    /// 引当の解除（予約数を減らす）
+    Release,
+    /// 減算済み在庫の戻し入れ（在庫数を増やす）
+    Restock,
+}
+
+/// 注文ドメインイベント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderEvent {
+    pub kind: OrderEventKind,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl OrderEvent {
+    pub fn new(kind: OrderEventKind, occurred_at: DateTime<Utc>) -> Self {
+        Self { kind, occurred_at }
+    }
+
+    /// order_events.event_typeに保存するイベント種別
+    pub fn event_type(&self) -> &'static str {
+        match self.kind {
+            OrderEventKind::OrderCreated { .. } => "order_created",
+            OrderEventKind::StatusChanged { .. } => "order_status_changed",
+            OrderEventKind::Cancelled { .. } => "order_cancelled",
+            OrderEventKind::NoteAdded { .. } => "order_note_added",
+            OrderEventKind::DeliveryInfoAttached { .. } => "order_delivery_info_attached",
+            OrderEventKind::Amended { .. } => "order_amended",
+        }
+    }
+
+    /// このイベントが注文明細の在庫に与える影響
+    ///
+    /// 注文作成で引当し、支払い完了（出荷前）で実在庫を減算する。
+    /// 減算前のキャンセルは引当の解除、出荷前のキャンセル・返金は在庫を戻す。
+    pub fn stock_effect(&self) -> Option<StockEffect> {
+        match &self.kind {
+            OrderEventKind::OrderCreated { .. } => Some(StockEffect::Reserve),
+            OrderEventKind::StatusChanged { from, to } => {
+                if from.holds_stock_reservation()
+                    && matches!(to, OrderStatus::Paid | OrderStatus::Shipped)
+                {
+                    Some(StockEffect::Commit)
+                } else if from.holds_committed_stock() && *to == OrderStatus::Refunded {
+                    Some(StockEffect::Restock)
+                } else {
+                    None
+                }
+            }
+            OrderEventKind::Cancelled { from, .. } => {
+                if from.holds_stock_reservation() {
+                    Some(StockEffect::Release)
+                } else if from.holds_committed_stock() {
+                    Some(StockEffect::Restock)
+                } else {
+                    None
+                }
+            }
+            // 注文変更は明細全体ではなく差分（reservation_changes）だけを引当・解除する
+            OrderEventKind::NoteAdded { .. }
+            | OrderEventKind::DeliveryInfoAttached { .. }
+            | OrderEventKind::Amended { .. } => None,
+        }
+    }
+
+    /// order_events.event_dataに保存するイベント内容
+    pub fn event_data(&self) -> serde_json::Value {
+        match &self.kind {
+            OrderEventKind::OrderCreated {
+                order_number,
+                total,
+            } => json!({ "order_number": order_number, "total": total }),
+            OrderEventKind::StatusChanged { from, to } => {
+                json!({ "from": from.to_string(), "to": to.to_string() })
+            }
+            OrderEventKind::Cancelled { from, reason } => {
+                json!({ "from": from.to_string(), "reason": reason })
+            }
+            OrderEventKind::NoteAdded { note } => json!({ "note": note }),
+            OrderEventKind::DeliveryInfoAttached { delivery_info_id } => {
+                json!({ "delivery_info_id": delivery_info_id })
+            }
+            OrderEventKind::Amended {
+                changes,
+                previous_total,
+                total,
+                reservation_changes,
+            } => json!({
+                "changes": changes,
+                "previous_total": previous_total,
+                "total": total,
+                "reservation_changes": reservation_changes
+                    .iter()
+                    .map(|change| json!({
+                        "sku_code": change.sku_code.value(),
+                        "quantity_delta": change.quantity_delta,
+                    }))
+                    .collect::<Vec<_>>(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_changed_event() {
+        let event = OrderEvent::new(
+            OrderEventKind::StatusChanged {
+                from: OrderStatus::Pending,
+                to: OrderStatus::Paid,
+            },
+            Utc::now(),
+        );
+
+        assert_eq!(event.event_type(), "order_status_changed");
+        assert_eq!(
+            event.event_data(),
+            json!({ "from": "pending", "to": "paid" })
+        );
+    }
+
+    #[test]
+    fn test_stock_effects() {
+        let event = |kind| OrderEvent::new(kind, Utc::now());
+
+        assert_eq!(
+            event(OrderEventKind::OrderCreated {
+                order_number: "ORD-2024-000001".to_string(),
+                total: 1000,
+            })
+            .stock_effect(),
+            Some(StockEffect::Reserve)
+        );
+        assert_eq!(
+            event(OrderEventKind::StatusChanged {
+                from: OrderStatus::Pending,
+                to: OrderStatus::Paid,
+            })
+            .stock_effect(),
+            Some(StockEffect::Commit)
+        );
+        assert_eq!(
+            event(OrderEventKind::StatusChanged {
+                from: OrderStatus::Processing,
+                to: OrderStatus::Shipped,
+            })
+            .stock_effect(),
+            None
+        );
+        assert_eq!(
+            event(OrderEventKind::Cancelled {
+                from: OrderStatus::Pending,
+                reason: "Customer request".to_string(),
+            })
+            .stock_effect(),
+            Some(StockEffect::Release)
+        );
+        assert_eq!(
+            event(OrderEventKind::Cancelled {
+                from: OrderStatus::Processing,
+                reason: "Customer request".to_string(),
+            })
+            .stock_effect(),
+            Some(StockEffect::Restock)
+        );
+        assert_eq!(
+            event(OrderEventKind::StatusChanged {
+                from: OrderStatus::Delivered,
+                to: OrderStatus::Refunded,
+            })
+            .stock_effect(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cancelled_event() {
+        let event = OrderEvent::new(
+            OrderEventKind::Cancelled {
+                from: OrderStatus::Paid,
+                reason: "Customer request".to_string(),
+            },
+            Utc::now(),
+        );
+
+        assert_eq!(event.event_type(), "order_cancelled");
+        assert_eq!(
+            event.event_data(),
+            json!({ "from": "paid", "reason": "Customer request" })
+        );
+    }
+
+    #[test]
+    fn test_note_added_event() {
+        let event = OrderEvent::new(
+            OrderEventKind::NoteAdded {
+                note: "配達日時の変更依頼あり".to_string(),
+            },
+            Utc::now(),
+        );
+
+        assert_eq!(event.event_type(), "order_note_added");
+        assert_eq!(
+            event.event_data(),
+            json!({ "note": "配達日時の変更依頼あり" })
+        );
+        assert_eq!(event.stock_effect(), None);
+    }
+}
@@ -9,6 +9,10 @@ pub struct OrderItem {
     pub sku_name: SKUName,
     pub unit_price: Money,
     pub quantity: i32,
+    /// 税区分（注文時点のSKUの税区分）
+    pub tax_category: TaxCategory,
+    /// この明細に割り当てられたクーポン割引額
+    pub discount_amount: Money,
 }
 
 impl OrderItem {
@@ -39,9 +43,23 @@ impl OrderItem {
             sku_name,
             unit_price,
             quantity,
+            tax_category: TaxCategory::default(),
+            discount_amount: Money::zero(),
         })
     }
 
+    /// 税区分を設定（省略時は標準税率）
+    pub fn with_tax_category(mut self, tax_category: TaxCategory) -> Self {
+        self.tax_category = tax_category;
+        self
+    }
+
+    /// クーポン割引額を設定（対象商品を限定したクーポンの明細ごとの割引）
+    pub fn with_discount_amount(mut self, discount_amount: Money) -> Self {
+        self.discount_amount = discount_amount;
+        self
+    }
+
     pub fn subtotal(&self) -> Result<Money, DomainError> {
         self.unit_price.multiply(self.quantity as u32)
     }
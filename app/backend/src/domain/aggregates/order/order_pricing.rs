@@ -1,17 +1,24 @@
-use crate::domain::value_objects::Money;
+use crate::domain::value_objects::{Money, TaxBreakdown, TaxCalculation};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OrderPricing {
     pub subtotal: Money,
+    /// クーポン割引額（税計算前に小計から差し引く）
+    pub discount: Money,
     pub shipping_fee: Money,
     pub payment_fee: Money,
     pub tax_amount: Money,
     pub total: Money,
+    /// 税率ごとの内訳（適格請求書の記載事項）
+    pub tax_breakdown: Vec<TaxBreakdown>,
+    /// 価格が税込か（税込の場合、税額は合計に含まれている）
+    pub tax_included: bool,
 }
 
 impl OrderPricing {
     pub fn new(
         subtotal: Money,
+        discount: Money,
         shipping_fee: Money,
         payment_fee: Money,
         tax_amount: Money,
@@ -19,17 +26,54 @@ impl OrderPricing {
     ) -> Self {
         OrderPricing {
             subtotal,
+            discount,
             shipping_fee,
             payment_fee,
             tax_amount,
             total,
+            tax_breakdown: Vec::new(),
+            tax_included: false,
         }
     }
 
+    /// 税率ごとの内訳を設定
+    pub fn with_tax_breakdown(
+        mut self,
+        tax_breakdown: Vec<TaxBreakdown>,
+        tax_included: bool,
+    ) -> Self {
+        self.tax_breakdown = tax_breakdown;
+        self.tax_included = tax_included;
+        self
+    }
+
+    /// 税計算結果から料金を構築
+    pub fn from_tax_calculation(
+        subtotal: Money,
+        discount: Money,
+        shipping_fee: Money,
+        payment_fee: Money,
+        tax: TaxCalculation,
+    ) -> Self {
+        Self::new(
+            subtotal,
+            discount,
+            shipping_fee,
+            payment_fee,
+            tax.tax_amount,
+            tax.total,
+        )
+        .with_tax_breakdown(tax.breakdown, tax.tax_included)
+    }
+
     pub fn subtotal_yen(&self) -> u32 {
         self.subtotal.amount_in_yen()
     }
 
+    pub fn discount_yen(&self) -> u32 {
+        self.discount.amount_in_yen()
+    }
+
     pub fn shipping_fee_yen(&self) -> u32 {
         self.shipping_fee.amount_in_yen()
     }
@@ -46,44 +90,80 @@ impl OrderPricing {
         self.total.amount_in_yen()
     }
 
+    /// 割引・手数料を反映した税加算前の合計（税込価格の場合は税込の合計）
     pub fn total_before_tax(&self) -> Result<Money, crate::domain::error::DomainError> {
-        self.subtotal.add(self.shipping_fee)?.add(self.payment_fee)
+        self.subtotal
+            .subtract(self.discount)?
+            .add(self.shipping_fee)?
+            .add(self.payment_fee)
     }
 
+    /// 税率ごとの内訳・税額・合計の整合性を検証
     pub fn verify_calculations(&self) -> bool {
-        match self.total_before_tax() {
-            Ok(expected_total_before_tax) => {
-                let expected_tax = expected_total_before_tax.tax_amount();
-                match expected_total_before_tax.add(expected_tax) {
-                    Ok(expected_total) => {
-                        self.tax_amount == expected_tax && self.total == expected_total
-                    }
-                    Err(_) => false,
-                }
-            }
-            Err(_) => false,
+        let Ok(total_before_tax) = self.total_before_tax() else {
+            return false;
+        };
+
+        let breakdown_tax: u32 = self.tax_breakdown.iter().map(|b| b.tax_amount.yen()).sum();
+        let breakdown_taxable: u32 = self
+            .tax_breakdown
+            .iter()
+            .map(|b| b.taxable_amount.yen())
+            .sum();
+        if breakdown_tax != self.tax_amount.yen() {
+            return false;
         }
+
+        let (expected_taxable, expected_total) = if self.tax_included {
+            (
+                total_before_tax.yen().checked_sub(self.tax_amount.yen()),
+                Some(total_before_tax.yen()),
+            )
+        } else {
+            (
+                Some(total_before_tax.yen()),
+                total_before_tax.yen().checked_add(self.tax_amount.yen()),
+            )
+        };
+
+        expected_taxable == Some(breakdown_taxable) && expected_total == Some(self.total.yen())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::value_objects::{TaxCategory, TaxPolicy, TaxPriceMode, TaxableLine};
+
+    fn priced(
+        policy: TaxPolicy,
+        subtotal: u32,
+        discount: u32,
+        shipping_fee: u32,
+        payment_fee: u32,
+    ) -> OrderPricing {
+        let tax = policy
+            .calculate(
+                &[
+                    TaxableLine::item(Money::from_yen(subtotal), TaxCategory::Standard),
+                    TaxableLine::fee(Money::from_yen(shipping_fee)),
+                    TaxableLine::fee(Money::from_yen(payment_fee)),
+                ],
+                Money::from_yen(discount),
+            )
+            .unwrap();
+        OrderPricing::from_tax_calculation(
+            Money::from_yen(subtotal),
+            Money::from_yen(discount),
+            Money::from_yen(shipping_fee),
+            Money::from_yen(payment_fee),
+            tax,
+        )
+    }
 
     #[test]
     fn test_order_pricing() {
-        let subtotal = Money::from_yen(2000);
-        let shipping_fee = Money::from_yen(500);
-        let payment_fee = Money::from_yen(100);
-        let total_before_tax = subtotal
-            .add(shipping_fee)
-            .unwrap()
-            .add(payment_fee)
-            .unwrap();
-        let tax_amount = total_before_tax.tax_amount();
-        let total = total_before_tax.add(tax_amount).unwrap();
-
-        let pricing = OrderPricing::new(subtotal, shipping_fee, payment_fee, tax_amount, total);
+        let pricing = priced(TaxPolicy::default(), 2000, 0, 500, 100);
 
         assert_eq!(pricing.subtotal_yen(), 2000);
         assert_eq!(pricing.shipping_fee_yen(), 500);
@@ -97,28 +177,30 @@ mod tests {
         let subtotal = Money::from_yen(1000);
         let shipping_fee = Money::from_yen(500);
         let payment_fee = Money::from_yen(100);
-        let tax_amount = Money::from_yen(50); // Incorrect tax amount
-        let total = Money::from_yen(1650); // Incorrect total
+        let tax_amount = Money::from_yen(160);
+        let total = Money::from_yen(1700); // Incorrect total
 
-        let pricing = OrderPricing::new(subtotal, shipping_fee, payment_fee, tax_amount, total);
+        let pricing = OrderPricing::new(
+            subtotal,
+            Money::zero(),
+            shipping_fee,
+            payment_fee,
+            tax_amount,
+            total,
+        );
+        // 内訳がない
+        assert!(!pricing.verify_calculations());
 
+        let pricing = pricing.with_tax_breakdown(
+            vec![TaxBreakdown::new(10, Money::from_yen(1600), tax_amount)],
+            false,
+        );
         assert!(!pricing.verify_calculations());
     }
 
     #[test]
     fn test_correct_tax_calculation() {
-        let subtotal = Money::from_yen(1000);
-        let shipping_fee = Money::from_yen(500);
-        let payment_fee = Money::from_yen(0);
-        let total_before_tax = subtotal
-            .add(shipping_fee)
-            .unwrap()
-            .add(payment_fee)
-            .unwrap();
-        let tax_amount = total_before_tax.tax_amount();
-        let total = total_before_tax.add(tax_amount).unwrap();
-
-        let pricing = OrderPricing::new(subtotal, shipping_fee, payment_fee, tax_amount, total);
+        let pricing = priced(TaxPolicy::default(), 1000, 0, 500, 0);
 
         // 1500 yen before tax, 10% tax = 150 yen tax, total = 1650 yen
         assert_eq!(pricing.total_before_tax().unwrap(), Money::from_yen(1500));
@@ -126,4 +208,28 @@ mod tests {
         assert_eq!(pricing.total_yen(), 1650);
         assert!(pricing.verify_calculations());
     }
+
+    #[test]
+    fn test_tax_calculated_on_discounted_total() {
+        let pricing = priced(TaxPolicy::default(), 2000, 400, 500, 0);
+
+        // (2000 - 400 + 500) = 2100 yen before tax, 10% tax = 210 yen
+        assert_eq!(pricing.discount_yen(), 400);
+        assert_eq!(pricing.total_before_tax().unwrap(), Money::from_yen(2100));
+        assert_eq!(pricing.tax_amount_yen(), 210);
+        assert_eq!(pricing.total_yen(), 2310);
+        assert!(pricing.verify_calculations());
+    }
+
+    #[test]
+    fn test_tax_inclusive_pricing() {
+        let policy = TaxPolicy::default().with_price_mode(TaxPriceMode::Inclusive);
+        let pricing = priced(policy, 2200, 0, 550, 0);
+
+        // 税込2750円に含まれる税額250円
+        assert!(pricing.tax_included);
+        assert_eq!(pricing.tax_amount_yen(), 250);
+        assert_eq!(pricing.total_yen(), 2750);
+        assert!(pricing.verify_calculations());
+    }
 }
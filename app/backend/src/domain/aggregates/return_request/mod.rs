@@ -0,0 +1,5 @@
+pub mod return_item;
+pub mod return_request;
+
+pub use self::return_item::ReturnItem;
+pub use self::return_request::{ReturnCondition, ReturnReason, ReturnRequest, ReturnStatus};
@@ -0,0 +1,16 @@
+use crate::domain::value_objects::{Money, ProductName, SKUCode, SKUId};
+
+/// 返品明細（注文アイテムのうち返品する数量と、その返金額）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReturnItem {
+    pub sku_id: SKUId,
+    pub sku_code: SKUCode,
+    pub product_name: ProductName,
+    pub quantity: u32,
+    /// 注文時の税率（%）
+    pub tax_rate: u32,
+    /// 返金対象額（明細・注文の割引を按分して差し引いた額。税込価格の場合は税込）
+    pub amount: Money,
+    /// 注文の消費税額から按分した税額（税込価格の場合は`amount`に含まれる）
+    pub tax_amount: Money,
+}
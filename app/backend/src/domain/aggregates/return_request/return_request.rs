@@ -0,0 +1,760 @@
+use chrono::{DateTime, Utc};
+
+use super::ReturnItem;
+use crate::domain::aggregates::order::{Order, OrderItem, OrderStatus};
+use crate::domain::entities::{StockMovement, StockMovementKind};
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{Money, OrderId, ReturnId, SKUId, TaxPolicy};
+
+/// 返品理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnReason {
+    /// 初期不良・破損
+    Defective,
+    /// 誤配送
+    WrongItem,
+    /// 商品説明との相違
+    NotAsDescribed,
+    /// お客様都合
+    ChangedMind,
+    /// その他
+    Other,
+}
+
+impl ReturnReason {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ReturnReason::Defective => "defective",
+            ReturnReason::WrongItem => "wrong_item",
+            ReturnReason::NotAsDescribed => "not_as_described",
+            ReturnReason::ChangedMind => "changed_mind",
+            ReturnReason::Other => "other",
+        }
+    }
+
+    /// 販売側の責任による返品か（再入荷手数料を請求しない）
+    pub fn is_merchant_fault(&self) -> bool {
+        matches!(
+            self,
+            ReturnReason::Defective | ReturnReason::WrongItem | ReturnReason::NotAsDescribed
+        )
+    }
+}
+
+impl std::str::FromStr for ReturnReason {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "defective" => Ok(ReturnReason::Defective),
+            "wrong_item" => Ok(ReturnReason::WrongItem),
+            "not_as_described" => Ok(ReturnReason::NotAsDescribed),
+            "changed_mind" => Ok(ReturnReason::ChangedMind),
+            "other" => Ok(ReturnReason::Other),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid return reason: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 返品商品の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnCondition {
+    /// 未開封
+    Unopened,
+    /// 開封済み
+    Opened,
+    /// 破損・汚損
+    Damaged,
+}
+
+impl ReturnCondition {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ReturnCondition::Unopened => "unopened",
+            ReturnCondition::Opened => "opened",
+            ReturnCondition::Damaged => "damaged",
+        }
+    }
+
+    /// 再販売できる状態か
+    pub fn is_resellable(&self) -> bool {
+        matches!(self, ReturnCondition::Unopened | ReturnCondition::Opened)
+    }
+}
+
+impl std::str::FromStr for ReturnCondition {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unopened" => Ok(ReturnCondition::Unopened),
+            "opened" => Ok(ReturnCondition::Opened),
+            "damaged" => Ok(ReturnCondition::Damaged),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid return condition: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 返品ステータス
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnStatus {
+    /// 申請済み
+    Requested,
+    /// 承認済み（返送待ち）
+    Approved,
+    /// 受領済み（返金待ち）
+    Received,
+    /// 返金済み
+    Refunded,
+    /// 却下
+    Rejected,
+}
+
+impl ReturnStatus {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ReturnStatus::Requested => "requested",
+            ReturnStatus::Approved => "approved",
+            ReturnStatus::Received => "received",
+            ReturnStatus::Refunded => "refunded",
+            ReturnStatus::Rejected => "rejected",
+        }
+    }
+}
+
+impl std::fmt::Display for ReturnStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl std::str::FromStr for ReturnStatus {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "requested" => Ok(ReturnStatus::Requested),
+            "approved" => Ok(ReturnStatus::Approved),
+            "received" => Ok(ReturnStatus::Received),
+            "refunded" => Ok(ReturnStatus::Refunded),
+            "rejected" => Ok(ReturnStatus::Rejected),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid return status: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 返品（RMA）集約
+///
+/// 配達済みの注文アイテムの一部を返品し、その分だけを返金する。
+/// 返金額は明細の小計から割引を按分して差し引き、注文の消費税額を税率ごとに按分して求める。
+/// お客様都合で開封・破損した商品は、返金額から再入荷手数料を差し引く。
+#[derive(Debug, Clone)]
+pub struct ReturnRequest {
+    pub id: ReturnId,
+    pub order_id: OrderId,
+    pub items: Vec<ReturnItem>,
+    pub reason: ReturnReason,
+    pub condition: ReturnCondition,
+    /// お客様からのコメント
+    pub note: Option<String>,
+    pub status: ReturnStatus,
+    pub rejection_reason: Option<String>,
+    /// 返金対象額の合計（税込価格の場合は税込）
+    pub items_amount: Money,
+    /// 按分した消費税額の合計
+    pub tax_amount: Money,
+    /// 価格が税込か（税込の場合、税額は`items_amount`に含まれている）
+    pub tax_included: bool,
+    /// 再入荷手数料率（%）
+    pub restocking_fee_rate: u32,
+    pub restocking_fee: Money,
+    /// 返金額（返金対象額＋税額−再入荷手数料）
+    pub refund_amount: Money,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub received_at: Option<DateTime<Utc>>,
+    pub refunded_at: Option<DateTime<Utc>>,
+    pub rejected_at: Option<DateTime<Utc>>,
+}
+
+impl ReturnRequest {
+    const MAX_TEXT_LENGTH: usize = 1000;
+
+    /// 返品を申請
+    ///
+    /// 同じ注文の却下されていない返品と合わせて、注文数量を超えて返品することはできない。
+    pub fn request(
+        order: &Order,
+        lines: Vec<(SKUId, u32)>,
+        reason: ReturnReason,
+        condition: ReturnCondition,
+        existing_returns: &[ReturnRequest],
+        tax_policy: &TaxPolicy,
+        restocking_fee_rate: u32,
+    ) -> Result<Self, DomainError> {
+        if order.status != OrderStatus::Delivered {
+            return Err(DomainError::BusinessRuleViolation(format!(
+                "Only delivered orders can be returned (current status: {})",
+                order.status
+            )));
+        }
+        if lines.is_empty() {
+            return Err(DomainError::InvalidProductData(
+                "Return must have at least one item".to_string(),
+            ));
+        }
+        if restocking_fee_rate > 100 {
+            return Err(DomainError::InvalidProductData(
+                "Restocking fee rate must be between 0 and 100".to_string(),
+            ));
+        }
+
+        let mut items = Vec::with_capacity(lines.len());
+        for (index, (sku_id, quantity)) in lines.iter().enumerate() {
+            if lines[..index].iter().any(|(other, _)| other == sku_id) {
+                return Err(DomainError::InvalidProductData(format!(
+                    "Duplicate SKU in return: {}",
+                    sku_id
+                )));
+            }
+            if *quantity == 0 {
+                return Err(DomainError::InvalidProductData(
+                    "Return quantity must be positive".to_string(),
+                ));
+            }
+
+            let order_item = order
+                .items
+                .iter()
+                .find(|item| &item.sku_id == sku_id)
+                .ok_or_else(|| {
+                    DomainError::InvalidProductData(format!(
+                        "SKU is not part of the order: {}",
+                        sku_id
+                    ))
+                })?;
+
+            let already_returned: u32 = existing_returns
+                .iter()
+                .filter(|r| r.order_id == order.id && r.status != ReturnStatus::Rejected)
+                .flat_map(|r| r.items.iter())
+                .filter(|item| &item.sku_id == sku_id)
+                .map(|item| item.quantity)
+                .sum();
+            let returnable = (order_item.quantity as u32).saturating_sub(already_returned);
+            if *quantity > returnable {
+                return Err(DomainError::BusinessRuleViolation(format!(
+                    "Cannot return {} of {} (returnable quantity: {})",
+                    quantity,
+                    order_item.sku_code.value(),
+                    returnable
+                )));
+            }
+
+            items.push(Self::price_item(order, order_item, *quantity, tax_policy)?);
+        }
+
+        let mut items_amount = Money::zero();
+        let mut tax_amount = Money::zero();
+        for item in &items {
+            items_amount = items_amount.add(item.amount)?;
+            tax_amount = tax_amount.add(item.tax_amount)?;
+        }
+
+        let now = Utc::now();
+        let mut return_request = Self {
+            id: ReturnId::new(),
+            order_id: order.id,
+            items,
+            reason,
+            condition,
+            note: None,
+            status: ReturnStatus::Requested,
+            rejection_reason: None,
+            items_amount,
+            tax_amount,
+            tax_included: order.pricing.tax_included,
+            restocking_fee_rate,
+            restocking_fee: Money::zero(),
+            refund_amount: Money::zero(),
+            created_at: now,
+            updated_at: now,
+            approved_at: None,
+            received_at: None,
+            refunded_at: None,
+            rejected_at: None,
+        };
+        return_request.recalculate_refund()?;
+
+        Ok(return_request)
+    }
+
+    /// お客様からのコメントを設定
+    pub fn with_note(mut self, note: Option<String>) -> Result<Self, DomainError> {
+        if let Some(note) = &note {
+            Self::validate_text(note, "Note")?;
+        }
+        self.note = note;
+        Ok(self)
+    }
+
+    /// 返品を承認（返送を受け付ける）
+    pub fn approve(&mut self) -> Result<(), DomainError> {
+        self.transition(ReturnStatus::Requested, ReturnStatus::Approved)?;
+        self.approved_at = Some(self.updated_at);
+        Ok(())
+    }
+
+    /// 返品を却下（申請中・承認済みのみ）
+    pub fn reject(&mut self, reason: String) -> Result<(), DomainError> {
+        Self::validate_text(&reason, "Rejection reason")?;
+        if !matches!(
+            self.status,
+            ReturnStatus::Requested | ReturnStatus::Approved
+        ) {
+            return Err(self.invalid_transition(ReturnStatus::Rejected));
+        }
+
+        self.status = ReturnStatus::Rejected;
+        self.rejection_reason = Some(reason);
+        self.updated_at = Utc::now();
+        self.rejected_at = Some(self.updated_at);
+        Ok(())
+    }
+
+    /// 返送された商品を受領
+    ///
+    /// 検品した商品状態で再入荷手数料と返金額を再計算し、再販売できる商品は在庫に戻す。
+    /// 戻し入れる在庫の変動（在庫台帳の行）を返す。
+    pub fn receive(
+        &mut self,
+        condition: Option<ReturnCondition>,
+    ) -> Result<Vec<StockMovement>, DomainError> {
+        self.transition(ReturnStatus::Approved, ReturnStatus::Received)?;
+        self.received_at = Some(self.updated_at);
+        if let Some(condition) = condition {
+            self.condition = condition;
+        }
+        self.recalculate_refund()?;
+
+        if !self.is_restockable() {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .items
+            .iter()
+            .map(|item| {
+                StockMovement::new(
+                    item.sku_id.clone(),
+                    StockMovementKind::Restock,
+                    item.quantity as i64,
+                    "返品受領",
+                    Some(self.order_id),
+                    "system",
+                )
+            })
+            .collect())
+    }
+
+    /// 返金済みにする（受領済みのみ）
+    pub fn mark_refunded(&mut self) -> Result<(), DomainError> {
+        self.transition(ReturnStatus::Received, ReturnStatus::Refunded)?;
+        self.refunded_at = Some(self.updated_at);
+        Ok(())
+    }
+
+    /// 返品された商品を再販売用の在庫に戻せるか
+    /// 初期不良や破損した商品は在庫に戻さない
+    pub fn is_restockable(&self) -> bool {
+        self.condition.is_resellable() && self.reason != ReturnReason::Defective
+    }
+
+    /// 返品数量の合計
+    pub fn total_quantity(&self) -> u32 {
+        self.items.iter().map(|item| item.quantity).sum()
+    }
+
+    fn transition(&mut self, from: ReturnStatus, to: ReturnStatus) -> Result<(), DomainError> {
+        if self.status != from {
+            return Err(self.invalid_transition(to));
+        }
+        self.status = to;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    fn invalid_transition(&self, to: ReturnStatus) -> DomainError {
+        DomainError::InvalidStatusTransition {
+            from: self.status.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    fn validate_text(text: &str, field: &str) -> Result<(), DomainError> {
+        if text.trim().is_empty() || text.chars().count() > Self::MAX_TEXT_LENGTH {
+            return Err(DomainError::InvalidProductData(format!(
+                "{} must be 1-{} characters",
+                field,
+                Self::MAX_TEXT_LENGTH
+            )));
+        }
+        Ok(())
+    }
+
+    /// 再入荷手数料と返金額を再計算
+    /// お客様都合の返品で、未開封でない場合にのみ再入荷手数料を請求する
+    fn recalculate_refund(&mut self) -> Result<(), DomainError> {
+        let gross = if self.tax_included {
+            self.items_amount
+        } else {
+            self.items_amount.add(self.tax_amount)?
+        };
+
+        let charges_fee =
+            !self.reason.is_merchant_fault() && self.condition != ReturnCondition::Unopened;
+        self.restocking_fee = if charges_fee {
+            Money::from_yen(Self::prorate(gross.yen(), self.restocking_fee_rate, 100))
+        } else {
+            Money::zero()
+        };
+        self.refund_amount = gross.subtract(self.restocking_fee)?;
+        Ok(())
+    }
+
+    /// 注文アイテムのうち返品する数量分の返金対象額と税額を求める
+    ///
+    /// 明細に割り当てられた割引は数量で按分し、明細に割り当てられていない注文全体の割引は
+    /// 明細の割引後金額の比で按分する。税額は同じ税率の対象額に占める割合で注文の税額を按分する。
+    /// 端数はいずれも切り捨て、注文時の支払額を超えて返金しないようにする。
+    fn price_item(
+        order: &Order,
+        order_item: &OrderItem,
+        quantity: u32,
+        tax_policy: &TaxPolicy,
+    ) -> Result<ReturnItem, DomainError> {
+        let ordered_quantity = order_item.quantity as u32;
+        let gross = order_item.unit_price.multiply(quantity)?.yen();
+        let line_discount =
+            Self::prorate(order_item.discount_amount.yen(), quantity, ordered_quantity);
+
+        let mut items_net_total = 0u64;
+        let mut allocated_discount = 0u64;
+        for item in &order.items {
+            let subtotal = item.subtotal()?.yen() as u64;
+            let discount = item.discount_amount.yen() as u64;
+            items_net_total += subtotal.saturating_sub(discount);
+            allocated_discount += discount;
+        }
+        let order_discount =
+            (order.pricing.discount.yen() as u64).saturating_sub(allocated_discount);
+
+        let net = gross.saturating_sub(line_discount);
+        let order_discount_share = (order_discount * net as u64)
+            .checked_div(items_net_total)
+            .unwrap_or(0) as u32;
+        let amount = net.saturating_sub(order_discount_share);
+
+        let tax_rate = tax_policy.rate_for(order_item.tax_category);
+        let tax_amount = match order
+            .pricing
+            .tax_breakdown
+            .iter()
+            .find(|breakdown| breakdown.rate == tax_rate)
+        {
+            Some(breakdown) => {
+                let base = if order.pricing.tax_included {
+                    breakdown.taxable_amount.yen() + breakdown.tax_amount.yen()
+                } else {
+                    breakdown.taxable_amount.yen()
+                };
+                Self::prorate(breakdown.tax_amount.yen(), amount, base)
+            }
+            // 税率別内訳のない注文は税率から求める
+            None if order.pricing.tax_included => Self::prorate(amount, tax_rate, 100 + tax_rate),
+            None => Self::prorate(amount, tax_rate, 100),
+        };
+
+        Ok(ReturnItem {
+            sku_id: order_item.sku_id.clone(),
+            sku_code: order_item.sku_code.clone(),
+            product_name: order_item.product_name.clone(),
+            quantity,
+            tax_rate,
+            amount: Money::from_yen(amount),
+            tax_amount: Money::from_yen(tax_amount),
+        })
+    }
+
+    /// value × numerator / denominator（切り捨て、分母が0の場合は0）
+    fn prorate(value: u32, numerator: u32, denominator: u32) -> u32 {
+        if denominator == 0 {
+            return 0;
+        }
+        (value as u64 * numerator as u64 / denominator as u64) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::order::{
+        AppliedCoupon, CustomerInfo, PaymentInfo, ShippingInfo,
+    };
+    use crate::domain::value_objects::*;
+
+    fn delivered_order(coupon: Option<AppliedCoupon>) -> Order {
+        let items = vec![
+            OrderItem::new(
+                SKUId::new(),
+                SKUCode::new("TEST-001".to_string()).unwrap(),
+                ProductName::new("Test Product".to_string()).unwrap(),
+                SKUName::new("Test SKU".to_string()).unwrap(),
+                Money::from_yen(1000),
+                2,
+            )
+            .unwrap(),
+            OrderItem::new(
+                SKUId::new(),
+                SKUCode::new("FOOD-001".to_string()).unwrap(),
+                ProductName::new("Test Food".to_string()).unwrap(),
+                SKUName::new("Test Food".to_string()).unwrap(),
+                Money::from_yen(500),
+                1,
+            )
+            .unwrap()
+            .with_tax_category(TaxCategory::Reduced),
+        ];
+
+        let mut order = Order::new(
+            OrderNumber::generate(2024, 1),
+            CustomerInfo::new(
+                PersonalInfo::new(
+                    FirstName::new("太郎".to_string()).unwrap(),
+                    LastName::new("田中".to_string()).unwrap(),
+                ),
+                Email::new("test@example.com".to_string()).unwrap(),
+                PhoneNumber::new("090-1234-5678".to_string()).unwrap(),
+            ),
+            items,
+            ShippingInfo::new(
+                ShippingMethodId::new("standard".to_string()).unwrap(),
+                "Standard Shipping".to_string(),
+                Money::from_yen(500),
+                Address::new(
+                    "123-4567".to_string(),
+                    "Tokyo".to_string(),
+                    "Shibuya".to_string(),
+                    "1-2-3".to_string(),
+                    None,
+                )
+                .unwrap(),
+            ),
+            PaymentInfo::new(
+                PaymentMethodId::new("credit_card".to_string()).unwrap(),
+                "Credit Card".to_string(),
+                Money::zero(),
+                None,
+            ),
+            coupon,
+            Vec::new(),
+            &TaxPolicy::default(),
+        )
+        .unwrap();
+        for status in [
+            OrderStatus::Paid,
+            OrderStatus::Processing,
+            OrderStatus::Shipped,
+            OrderStatus::Delivered,
+        ] {
+            order.update_status(status).unwrap();
+        }
+        order
+    }
+
+    fn request(
+        order: &Order,
+        lines: Vec<(SKUId, u32)>,
+        reason: ReturnReason,
+        condition: ReturnCondition,
+        existing_returns: &[ReturnRequest],
+    ) -> Result<ReturnRequest, DomainError> {
+        ReturnRequest::request(
+            order,
+            lines,
+            reason,
+            condition,
+            existing_returns,
+            &TaxPolicy::default(),
+            10,
+        )
+    }
+
+    #[test]
+    fn test_refund_prorates_discount_and_tax() {
+        // 割引300円を商品2500円に按分: 標準税率の明細2000円 → 1760円
+        let order = delivered_order(Some(AppliedCoupon::new(
+            CouponId::new(),
+            CouponCode::from_string("SAVE300".to_string()).unwrap(),
+            Money::from_yen(300),
+        )));
+        let sku_id = order.items[0].sku_id.clone();
+
+        let return_request = request(
+            &order,
+            vec![(sku_id, 1)],
+            ReturnReason::Defective,
+            ReturnCondition::Opened,
+            &[],
+        )
+        .unwrap();
+
+        // 1個分: 1000円 − 割引120円 = 880円、標準税率の税額を対象額比で按分
+        let item = &return_request.items[0];
+        assert_eq!(item.amount.yen(), 880);
+        assert_eq!(item.tax_rate, 10);
+        assert_eq!(item.tax_amount.yen(), 88);
+        assert_eq!(return_request.restocking_fee.yen(), 0);
+        assert_eq!(return_request.refund_amount.yen(), 968);
+        assert_eq!(return_request.status, ReturnStatus::Requested);
+    }
+
+    #[test]
+    fn test_restocking_fee_for_customer_reason() {
+        let order = delivered_order(None);
+        let sku_id = order.items[1].sku_id.clone();
+
+        let mut return_request = request(
+            &order,
+            vec![(sku_id, 1)],
+            ReturnReason::ChangedMind,
+            ReturnCondition::Unopened,
+            &[],
+        )
+        .unwrap();
+        // 軽減税率8%: 500円 + 40円、未開封のため手数料なし
+        assert_eq!(return_request.tax_amount.yen(), 40);
+        assert_eq!(return_request.refund_amount.yen(), 540);
+
+        return_request.approve().unwrap();
+        let restocks = return_request
+            .receive(Some(ReturnCondition::Opened))
+            .unwrap();
+
+        // 開封済みで受領: 540円の10% = 54円を差し引く
+        assert_eq!(return_request.restocking_fee.yen(), 54);
+        assert_eq!(return_request.refund_amount.yen(), 486);
+        assert_eq!(restocks.len(), 1);
+        assert_eq!(restocks[0].delta, 1);
+        assert_eq!(restocks[0].kind, StockMovementKind::Restock);
+
+        return_request.mark_refunded().unwrap();
+        assert_eq!(return_request.status, ReturnStatus::Refunded);
+        assert!(return_request.refunded_at.is_some());
+    }
+
+    #[test]
+    fn test_cannot_return_more_than_ordered() {
+        let order = delivered_order(None);
+        let sku_id = order.items[0].sku_id.clone();
+
+        let first = request(
+            &order,
+            vec![(sku_id.clone(), 1)],
+            ReturnReason::ChangedMind,
+            ReturnCondition::Unopened,
+            &[],
+        )
+        .unwrap();
+        assert!(
+            request(
+                &order,
+                vec![(sku_id.clone(), 2)],
+                ReturnReason::ChangedMind,
+                ReturnCondition::Unopened,
+                std::slice::from_ref(&first),
+            )
+            .is_err()
+        );
+
+        // 却下された返品の数量は再び返品できる
+        let mut rejected = first;
+        rejected.reject("返品期間外".to_string()).unwrap();
+        assert!(
+            request(
+                &order,
+                vec![(sku_id, 2)],
+                ReturnReason::ChangedMind,
+                ReturnCondition::Unopened,
+                &[rejected],
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_damaged_items_are_not_restocked_and_transitions_are_enforced() {
+        let order = delivered_order(None);
+        let sku_id = order.items[0].sku_id.clone();
+
+        let mut return_request = request(
+            &order,
+            vec![(sku_id, 2)],
+            ReturnReason::ChangedMind,
+            ReturnCondition::Opened,
+            &[],
+        )
+        .unwrap();
+
+        assert!(matches!(
+            return_request.receive(None),
+            Err(DomainError::InvalidStatusTransition { .. })
+        ));
+        assert!(return_request.mark_refunded().is_err());
+
+        return_request.approve().unwrap();
+        let restocks = return_request
+            .receive(Some(ReturnCondition::Damaged))
+            .unwrap();
+        assert!(restocks.is_empty());
+        assert!(return_request.reject("破損".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_only_delivered_orders_can_be_returned() {
+        let mut order = delivered_order(None);
+        order.status = OrderStatus::Shipped;
+        let sku_id = order.items[0].sku_id.clone();
+
+        assert!(matches!(
+            request(
+                &order,
+                vec![(sku_id, 1)],
+                ReturnReason::Defective,
+                ReturnCondition::Opened,
+                &[],
+            ),
+            Err(DomainError::BusinessRuleViolation(_))
+        ));
+        assert!(
+            request(
+                &order,
+                vec![(SKUId::new(), 1)],
+                ReturnReason::Defective,
+                ReturnCondition::Opened,
+                &[],
+            )
+            .is_err()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use chrono::prelude::*;
 
+use crate::domain::error::DomainError;
 use crate::domain::value_objects::{CouponCode, CouponId, DiscountPolicy};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +14,7 @@ pub struct Coupon {
     valid_until: DateTime<Utc>,
     usage_limit: Option<u32>,
     usage_count: u32,
+    per_customer_limit: Option<u32>,
 }
 
 impl Coupon {
@@ -37,9 +39,48 @@ impl Coupon {
             valid_until,
             usage_limit,
             usage_count,
+            per_customer_limit: None,
         }
     }
 
+    /// 管理画面から新規クーポンを作成（割引内容・有効期間・使用上限を検証する）
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        code: CouponCode,
+        name: String,
+        description: Option<String>,
+        discount_policy: DiscountPolicy,
+        valid_from: DateTime<Utc>,
+        valid_until: DateTime<Utc>,
+        usage_limit: Option<u32>,
+        per_customer_limit: Option<u32>,
+    ) -> Result<Self, DomainError> {
+        discount_policy.validate()?;
+
+        let mut coupon = Self::new(
+            CouponId::new(),
+            code,
+            String::new(),
+            None,
+            discount_policy,
+            valid_from,
+            valid_until,
+            None,
+            0,
+        );
+        coupon.rename(name, description)?;
+        coupon.change_validity(valid_from, valid_until)?;
+        coupon.change_usage_limit(usage_limit)?;
+        coupon.change_per_customer_limit(per_customer_limit)?;
+        Ok(coupon)
+    }
+
+    /// 顧客ごとの使用上限を設定（復元時に使用）
+    pub fn with_per_customer_limit(mut self, per_customer_limit: Option<u32>) -> Self {
+        self.per_customer_limit = per_customer_limit;
+        self
+    }
+
     pub fn is_valid(&self) -> bool {
         let now = Utc::now();
         self.valid_from <= now && now <= self.valid_until
@@ -49,6 +90,111 @@ impl Coupon {
         self.usage_limit.is_none() || self.usage_count < self.usage_limit.unwrap()
     }
 
+    /// 注文で使用済みのクーポンとして割引を再計算するための複製（注文変更時に使用）
+    /// 有効期間・使用回数は注文時に確認済みのため、現在の有効期間・使用上限では判定しない
+    pub fn as_redeemed(&self) -> Self {
+        let now = Utc::now();
+        Self {
+            valid_from: self.valid_from.min(now),
+            // 再計算の間に期限切れと判定されないよう、現在時刻より後まで延ばす
+            valid_until: self.valid_until.max(now + chrono::Duration::days(1)),
+            usage_limit: None,
+            ..self.clone()
+        }
+    }
+
+    /// 顧客の使用回数が顧客ごとの上限に達していないことを確認
+    pub fn ensure_usable_by_customer(&self, customer_usage_count: u32) -> Result<(), DomainError> {
+        match self.per_customer_limit {
+            Some(limit) if customer_usage_count >= limit => Err(DomainError::InvalidCoupon {
+                code: self.code.value().to_string(),
+                message: format!("このクーポンはお一人様{}回までご利用いただけます", limit),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// クーポンコードを変更
+    pub fn change_code(&mut self, code: CouponCode) {
+        self.code = code;
+    }
+
+    /// 名称と説明を変更
+    pub fn rename(&mut self, name: String, description: Option<String>) -> Result<(), DomainError> {
+        if name.trim().is_empty() {
+            return Err(DomainError::InvalidProductData(
+                "Coupon name cannot be empty".to_string(),
+            ));
+        }
+        self.name = name;
+        self.description = description;
+        Ok(())
+    }
+
+    /// 割引内容を変更
+    pub fn change_discount_policy(
+        &mut self,
+        discount_policy: DiscountPolicy,
+    ) -> Result<(), DomainError> {
+        discount_policy.validate()?;
+        self.discount_policy = discount_policy;
+        Ok(())
+    }
+
+    /// 有効期間を変更
+    pub fn change_validity(
+        &mut self,
+        valid_from: DateTime<Utc>,
+        valid_until: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if valid_from > valid_until {
+            return Err(DomainError::InvalidProductData(
+                "Coupon valid_from must be before valid_until".to_string(),
+            ));
+        }
+        self.valid_from = valid_from;
+        self.valid_until = valid_until;
+        Ok(())
+    }
+
+    /// 使用回数の上限を変更（使用済みの回数より小さくはできない）
+    pub fn change_usage_limit(&mut self, usage_limit: Option<u32>) -> Result<(), DomainError> {
+        if let Some(limit) = usage_limit {
+            if limit == 0 {
+                return Err(DomainError::InvalidProductData(
+                    "Coupon usage limit must be positive".to_string(),
+                ));
+            }
+            if limit < self.usage_count {
+                return Err(DomainError::BusinessRuleViolation(format!(
+                    "Coupon usage limit {} is below the current usage count {}",
+                    limit, self.usage_count
+                )));
+            }
+        }
+        self.usage_limit = usage_limit;
+        Ok(())
+    }
+
+    /// 顧客ごとの使用上限を変更
+    pub fn change_per_customer_limit(
+        &mut self,
+        per_customer_limit: Option<u32>,
+    ) -> Result<(), DomainError> {
+        if per_customer_limit == Some(0) {
+            return Err(DomainError::InvalidProductData(
+                "Coupon per-customer limit must be positive".to_string(),
+            ));
+        }
+        self.per_customer_limit = per_customer_limit;
+        Ok(())
+    }
+
+    /// 削除可能か（使用済みのクーポンは注文から参照されるため削除できない）
+    pub fn can_be_deleted(&self) -> bool {
+        self.usage_count == 0
+    }
+
     // Getters
     pub fn id(&self) -> &CouponId {
         &self.id
@@ -85,4 +231,103 @@ impl Coupon {
     pub fn usage_count(&self) -> u32 {
         self.usage_count
     }
+
+    pub fn per_customer_limit(&self) -> Option<u32> {
+        self.per_customer_limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{DiscountType, Money};
+
+    fn create_coupon(per_customer_limit: Option<u32>) -> Result<Coupon, DomainError> {
+        Coupon::create(
+            CouponCode::from_string("SPRING".to_string()).unwrap(),
+            "春のセール".to_string(),
+            None,
+            DiscountPolicy::new(
+                DiscountType::CappedPercentage {
+                    percentage: 20,
+                    max_discount: Money::from_yen(3000),
+                },
+                None,
+            ),
+            Utc::now() - chrono::Duration::days(1),
+            Utc::now() + chrono::Duration::days(30),
+            Some(100),
+            per_customer_limit,
+        )
+    }
+
+    #[test]
+    fn create_validates_fields() {
+        assert!(create_coupon(Some(1)).is_ok());
+        assert!(create_coupon(Some(0)).is_err());
+
+        let invalid_period = Coupon::create(
+            CouponCode::from_string("PERIOD".to_string()).unwrap(),
+            "期間エラー".to_string(),
+            None,
+            DiscountPolicy::new(DiscountType::FreeShipping, None),
+            Utc::now(),
+            Utc::now() - chrono::Duration::days(1),
+            None,
+            None,
+        );
+        assert!(invalid_period.is_err());
+    }
+
+    #[test]
+    fn per_customer_limit_is_enforced() {
+        let coupon = create_coupon(Some(2)).unwrap();
+
+        assert!(coupon.ensure_usable_by_customer(1).is_ok());
+        assert!(matches!(
+            coupon.ensure_usable_by_customer(2),
+            Err(DomainError::InvalidCoupon { .. })
+        ));
+    }
+
+    #[test]
+    fn usage_limit_cannot_go_below_usage_count() {
+        let mut coupon = Coupon::new(
+            CouponId::new(),
+            CouponCode::from_string("USED".to_string()).unwrap(),
+            "使用済み".to_string(),
+            None,
+            DiscountPolicy::new(DiscountType::Percentage(10), None),
+            Utc::now(),
+            Utc::now() + chrono::Duration::days(1),
+            Some(10),
+            5,
+        );
+
+        assert!(coupon.change_usage_limit(Some(4)).is_err());
+        assert!(coupon.change_usage_limit(Some(5)).is_ok());
+        assert!(!coupon.can_be_deleted());
+    }
+
+    #[test]
+    fn redeemed_coupon_ignores_expiry_and_usage_limit() {
+        let coupon = Coupon::new(
+            CouponId::new(),
+            CouponCode::from_string("LAST".to_string()).unwrap(),
+            "最後の1枚".to_string(),
+            None,
+            DiscountPolicy::new(DiscountType::Percentage(10), None),
+            Utc::now() - chrono::Duration::days(10),
+            Utc::now() - chrono::Duration::days(1),
+            Some(1),
+            1,
+        );
+        assert!(!coupon.is_valid());
+        assert!(!coupon.is_valid_usage_limit());
+
+        let redeemed = coupon.as_redeemed();
+        assert!(redeemed.is_valid());
+        assert!(redeemed.is_valid_usage_limit());
+        assert_eq!(redeemed.discount_policy(), coupon.discount_policy());
+    }
 }
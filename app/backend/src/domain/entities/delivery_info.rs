@@ -1,6 +1,8 @@
+use crate::domain::error::DomainError;
 use crate::domain::value_objects::{Address, DeliveryInfoId, Email, PersonalInfo, PhoneNumber};
 use chrono::{DateTime, Utc};
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Debug, Clone)]
 pub struct DeliveryInfo {
@@ -233,6 +235,39 @@ impl fmt::Display for DeliveryStatus {
     }
 }
 
+impl DeliveryStatus {
+    /// 永続化・API連携で用いる状態コード
+    pub fn code(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "pending",
+            DeliveryStatus::Processing => "processing",
+            DeliveryStatus::Shipped => "shipped",
+            DeliveryStatus::InTransit => "in_transit",
+            DeliveryStatus::Delivered => "delivered",
+            DeliveryStatus::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for DeliveryStatus {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(DeliveryStatus::Pending),
+            "processing" => Ok(DeliveryStatus::Processing),
+            "shipped" => Ok(DeliveryStatus::Shipped),
+            "in_transit" => Ok(DeliveryStatus::InTransit),
+            "delivered" => Ok(DeliveryStatus::Delivered),
+            "failed" => Ok(DeliveryStatus::Failed),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid delivery status: {}",
+                s
+            ))),
+        }
+    }
+}
+
 impl fmt::Display for DeliveryInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -285,6 +320,20 @@ impl fmt::Display for DeliveryInfoError {
     }
 }
 
+impl From<DeliveryInfoError> for DomainError {
+    fn from(error: DeliveryInfoError) -> Self {
+        match error {
+            DeliveryInfoError::InvalidStatusTransition { from, to } => {
+                DomainError::InvalidStatusTransition {
+                    from: from.code().to_string(),
+                    to: to.code().to_string(),
+                }
+            }
+            other => DomainError::BusinessRuleViolation(other.to_string()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -368,4 +417,36 @@ mod tests {
         assert!(delivery_info.mark_as_failed().is_ok());
         assert_eq!(delivery_info.status, DeliveryStatus::Failed);
     }
+
+    #[test]
+    fn test_status_code_round_trip() {
+        for status in [
+            DeliveryStatus::Pending,
+            DeliveryStatus::Processing,
+            DeliveryStatus::Shipped,
+            DeliveryStatus::InTransit,
+            DeliveryStatus::Delivered,
+            DeliveryStatus::Failed,
+        ] {
+            assert_eq!(status.code().parse::<DeliveryStatus>(), Ok(status));
+        }
+        assert!("unknown".parse::<DeliveryStatus>().is_err());
+    }
+
+    #[test]
+    fn test_invalid_transition_converts_to_domain_error() {
+        let mut delivery_info = create_test_delivery_info();
+
+        let error: DomainError = delivery_info
+            .update_status(DeliveryStatus::Delivered)
+            .unwrap_err()
+            .into();
+        assert_eq!(
+            error,
+            DomainError::InvalidStatusTransition {
+                from: "pending".to_string(),
+                to: "delivered".to_string(),
+            }
+        );
+    }
 }
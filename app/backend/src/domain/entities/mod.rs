@@ -2,17 +2,31 @@ mod category;
 mod color;
 mod coupon;
 mod delivery_info;
+mod payment_fee_rule;
 mod payment_method;
+mod payment_transaction;
 mod product;
 mod product_image;
+mod promotion;
 mod shipping_method;
+mod shipping_rate_table;
 mod sku;
+mod stock_movement;
 mod tag;
 
 pub use self::coupon::Coupon;
-pub use self::delivery_info::DeliveryInfo;
+pub use self::delivery_info::{DeliveryInfo, DeliveryStatus};
+pub use self::payment_fee_rule::{PaymentFeeBracket, PaymentFeeKind, PaymentFeeRule};
 pub use self::payment_method::PaymentMethod;
-pub use self::product_image::ProductImage;
+pub use self::payment_transaction::{PaymentOperation, PaymentState, PaymentTransaction};
+pub use self::product::Product;
+pub use self::product_image::{ImageUrl, ProductImage, ProductImageId, ProductImageProductId};
+pub use self::promotion::Promotion;
 pub use self::shipping_method::ShippingMethod;
-pub use self::sku::SKU;
-pub use self::tag::Tag;
+pub use self::shipping_rate_table::{
+    OversizeSurcharge, ShipmentDetails, ShippableItem, ShippingRateTable, ShippingRegion,
+    ShippingRegionRate,
+};
+pub use self::sku::{SKU, SKUStatus, Stock};
+pub use self::stock_movement::{StockMovement, StockMovementKind};
+pub use self::tag::{Tag, TagSlug};
@@ -0,0 +1,279 @@
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::Money;
+
+/// 手数料の金額帯
+/// カート合計が`up_to`未満のときに`fee`を適用する（`up_to`なしは上限なし）
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentFeeBracket {
+    pub up_to: Option<Money>,
+    pub fee: Money,
+}
+
+impl PaymentFeeBracket {
+    pub fn new(up_to: Option<Money>, fee: Money) -> Self {
+        Self { up_to, fee }
+    }
+}
+
+/// 手数料の計算方式
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaymentFeeKind {
+    /// 定額
+    Flat { amount: Money },
+    /// カート合計に対する割合（%、小数第2位まで、1円未満切り上げ）
+    Percentage { rate: f64 },
+    /// カート合計の金額帯ごとの定額
+    Tiered { brackets: Vec<PaymentFeeBracket> },
+}
+
+impl PaymentFeeKind {
+    /// DBやAPIで使う計算方式のコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            PaymentFeeKind::Flat { .. } => "flat",
+            PaymentFeeKind::Percentage { .. } => "percentage",
+            PaymentFeeKind::Tiered { .. } => "tiered",
+        }
+    }
+}
+
+/// 支払い手数料ルール
+/// 計算方式で求めた手数料を最低額・最高額の範囲に収める
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentFeeRule {
+    kind: PaymentFeeKind,
+    min_fee: Option<Money>,
+    max_fee: Option<Money>,
+}
+
+impl PaymentFeeRule {
+    pub fn new(
+        kind: PaymentFeeKind,
+        min_fee: Option<Money>,
+        max_fee: Option<Money>,
+    ) -> Result<Self, DomainError> {
+        match &kind {
+            PaymentFeeKind::Flat { .. } => {}
+            PaymentFeeKind::Percentage { rate } => {
+                if !rate.is_finite() || *rate < 0.0 || *rate > 100.0 {
+                    return Err(DomainError::InvalidProductData(
+                        "Payment fee rate must be between 0 and 100".to_string(),
+                    ));
+                }
+            }
+            PaymentFeeKind::Tiered { brackets } => Self::validate_brackets(brackets)?,
+        }
+
+        if let (Some(min_fee), Some(max_fee)) = (min_fee, max_fee)
+            && min_fee.yen() > max_fee.yen()
+        {
+            return Err(DomainError::InvalidProductData(
+                "Minimum payment fee cannot exceed maximum payment fee".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            kind,
+            min_fee,
+            max_fee,
+        })
+    }
+
+    /// 金額帯は上限の昇順に並び、最後の金額帯だけが上限なしであること
+    fn validate_brackets(brackets: &[PaymentFeeBracket]) -> Result<(), DomainError> {
+        let Some((last, rest)) = brackets.split_last() else {
+            return Err(DomainError::InvalidProductData(
+                "Tiered payment fee requires at least one bracket".to_string(),
+            ));
+        };
+
+        if last.up_to.is_some() {
+            return Err(DomainError::InvalidProductData(
+                "The last payment fee bracket must not have an upper bound".to_string(),
+            ));
+        }
+
+        let mut previous = 0;
+        for bracket in rest {
+            let Some(up_to) = bracket.up_to else {
+                return Err(DomainError::InvalidProductData(
+                    "Only the last payment fee bracket can omit the upper bound".to_string(),
+                ));
+            };
+            if up_to.yen() <= previous {
+                return Err(DomainError::InvalidProductData(
+                    "Payment fee brackets must be in ascending order of upper bound".to_string(),
+                ));
+            }
+            previous = up_to.yen();
+        }
+
+        Ok(())
+    }
+
+    /// カート合計に対する手数料を計算
+    pub fn calculate(&self, cart_total: Money) -> Result<Money, DomainError> {
+        let fee = match &self.kind {
+            PaymentFeeKind::Flat { amount } => *amount,
+            PaymentFeeKind::Percentage { rate } => {
+                // 浮動小数点の誤差で切り上げがずれないよう、0.01%単位の整数で計算する
+                let basis_points = (rate * 100.0).round() as u64;
+                let fee = (cart_total.yen() as u64 * basis_points).div_ceil(10_000);
+                Money::from_yen(u32::try_from(fee).map_err(|_| {
+                    DomainError::InvalidProductData("Payment fee overflow".to_string())
+                })?)
+            }
+            PaymentFeeKind::Tiered { brackets } => brackets
+                .iter()
+                .find(|bracket| {
+                    bracket
+                        .up_to
+                        .is_none_or(|up_to| cart_total.yen() < up_to.yen())
+                })
+                .map(|bracket| bracket.fee)
+                .unwrap_or_else(Money::zero),
+        };
+
+        let fee = match self.min_fee {
+            Some(min_fee) if fee.yen() < min_fee.yen() => min_fee,
+            _ => fee,
+        };
+        let fee = match self.max_fee {
+            Some(max_fee) if fee.yen() > max_fee.yen() => max_fee,
+            _ => fee,
+        };
+
+        Ok(fee)
+    }
+
+    pub fn kind(&self) -> &PaymentFeeKind {
+        &self.kind
+    }
+
+    pub fn min_fee(&self) -> Option<Money> {
+        self.min_fee
+    }
+
+    pub fn max_fee(&self) -> Option<Money> {
+        self.max_fee
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiered(brackets: &[(Option<u32>, u32)]) -> PaymentFeeKind {
+        PaymentFeeKind::Tiered {
+            brackets: brackets
+                .iter()
+                .map(|(up_to, fee)| {
+                    PaymentFeeBracket::new(up_to.map(Money::from_yen), Money::from_yen(*fee))
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_flat_fee() {
+        let rule = PaymentFeeRule::new(
+            PaymentFeeKind::Flat {
+                amount: Money::from_yen(200),
+            },
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rule.calculate(Money::from_yen(10000)).unwrap(),
+            Money::from_yen(200)
+        );
+    }
+
+    #[test]
+    fn test_percentage_fee_with_caps() {
+        let rule = PaymentFeeRule::new(
+            PaymentFeeKind::Percentage { rate: 3.5 },
+            Some(Money::from_yen(100)),
+            Some(Money::from_yen(1000)),
+        )
+        .unwrap();
+
+        // 3.5%（切り上げ）
+        assert_eq!(
+            rule.calculate(Money::from_yen(10001)).unwrap(),
+            Money::from_yen(351)
+        );
+        // 割り切れる場合は誤差で切り上げない
+        assert_eq!(
+            rule.calculate(Money::from_yen(6000)).unwrap(),
+            Money::from_yen(210)
+        );
+        // 最低額
+        assert_eq!(
+            rule.calculate(Money::from_yen(1000)).unwrap(),
+            Money::from_yen(100)
+        );
+        // 最高額
+        assert_eq!(
+            rule.calculate(Money::from_yen(100000)).unwrap(),
+            Money::from_yen(1000)
+        );
+    }
+
+    #[test]
+    fn test_tiered_fee_brackets() {
+        let rule = PaymentFeeRule::new(
+            tiered(&[(Some(10000), 330), (Some(30000), 440), (None, 660)]),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rule.calculate(Money::from_yen(9999)).unwrap(),
+            Money::from_yen(330)
+        );
+        assert_eq!(
+            rule.calculate(Money::from_yen(10000)).unwrap(),
+            Money::from_yen(440)
+        );
+        assert_eq!(
+            rule.calculate(Money::from_yen(500000)).unwrap(),
+            Money::from_yen(660)
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        // 金額帯なし
+        assert!(PaymentFeeRule::new(tiered(&[]), None, None).is_err());
+        // 最後の金額帯に上限がある
+        assert!(PaymentFeeRule::new(tiered(&[(Some(10000), 330)]), None, None).is_err());
+        // 上限が昇順でない
+        assert!(
+            PaymentFeeRule::new(
+                tiered(&[(Some(30000), 440), (Some(10000), 330), (None, 660)]),
+                None,
+                None
+            )
+            .is_err()
+        );
+        // 割合が範囲外
+        assert!(
+            PaymentFeeRule::new(PaymentFeeKind::Percentage { rate: 120.0 }, None, None).is_err()
+        );
+        // 最低額が最高額を超える
+        assert!(
+            PaymentFeeRule::new(
+                PaymentFeeKind::Flat {
+                    amount: Money::zero()
+                },
+                Some(Money::from_yen(500)),
+                Some(Money::from_yen(100)),
+            )
+            .is_err()
+        );
+    }
+}
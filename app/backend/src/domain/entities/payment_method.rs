@@ -1,3 +1,4 @@
+use crate::domain::entities::PaymentFeeRule;
 use crate::domain::error::DomainError;
 use crate::domain::value_objects::*;
 
@@ -9,6 +10,7 @@ pub struct PaymentMethod {
     description: String,
     is_active: bool,
     sort_order: u32,
+    fee_rule: PaymentFeeRule,
 }
 
 impl PaymentMethod {
@@ -19,6 +21,7 @@ impl PaymentMethod {
         description: String,
         is_active: bool,
         sort_order: u32,
+        fee_rule: PaymentFeeRule,
     ) -> Result<Self, DomainError> {
         if id.trim().is_empty() {
             return Err(DomainError::InvalidProductData(
@@ -38,6 +41,7 @@ impl PaymentMethod {
             description,
             is_active,
             sort_order,
+            fee_rule,
         })
     }
 
@@ -49,27 +53,12 @@ impl PaymentMethod {
             ));
         }
 
-        match self.id.as_str() {
-            "cod" => self.calculate_cod_fee(cart_total),
-            "convenience_store" => Ok(Money::from_yen(200)),
-            "credit_card" | "bank_transfer" => Ok(Money::from_yen(0)),
-            _ => Ok(Money::from_yen(0)),
-        }
+        self.fee_rule.calculate(cart_total)
     }
 
-    /// 代引き手数料の計算
-    fn calculate_cod_fee(&self, total_amount: Money) -> Result<Money, DomainError> {
-        let yen_amount = total_amount.yen();
-
-        let fee_amount = match yen_amount {
-            0..=9999 => 330,         // 1万円未満
-            10000..=29999 => 440,    // 3万円未満
-            30000..=99999 => 660,    // 10万円未満
-            100000..=299999 => 1100, // 30万円未満
-            _ => 1650,               // 30万円以上
-        };
-
-        Ok(Money::from_yen(fee_amount))
+    /// 手数料ルールを変更
+    pub fn change_fee_rule(&mut self, fee_rule: PaymentFeeRule) {
+        self.fee_rule = fee_rule;
     }
 
     /// 支払い方法が利用可能かチェック
@@ -102,11 +91,34 @@ impl PaymentMethod {
     pub fn sort_order(&self) -> u32 {
         self.sort_order
     }
+
+    pub fn fee_rule(&self) -> &PaymentFeeRule {
+        &self.fee_rule
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::entities::{PaymentFeeBracket, PaymentFeeKind};
+
+    /// 代引き手数料（金額帯ごとの定額）
+    fn cod_fee_rule() -> PaymentFeeRule {
+        let brackets = [
+            (Some(10000), 330),
+            (Some(30000), 440),
+            (Some(100000), 660),
+            (Some(300000), 1100),
+            (None, 1650),
+        ]
+        .into_iter()
+        .map(|(up_to, fee)| {
+            PaymentFeeBracket::new(up_to.map(Money::from_yen), Money::from_yen(fee))
+        })
+        .collect();
+
+        PaymentFeeRule::new(PaymentFeeKind::Tiered { brackets }, None, None).unwrap()
+    }
 
     #[test]
     fn create_payment_method() {
@@ -116,6 +128,7 @@ mod tests {
             "商品お届け時にお支払い".to_string(),
             true,
             1,
+            cod_fee_rule(),
         )
         .unwrap();
 
@@ -133,6 +146,7 @@ mod tests {
             "商品お届け時にお支払い".to_string(),
             true,
             1,
+            cod_fee_rule(),
         )
         .unwrap();
 
@@ -175,6 +189,14 @@ mod tests {
             "コンビニでお支払い".to_string(),
             true,
             2,
+            PaymentFeeRule::new(
+                PaymentFeeKind::Flat {
+                    amount: Money::from_yen(200),
+                },
+                None,
+                None,
+            )
+            .unwrap(),
         )
         .unwrap();
 
@@ -194,6 +216,7 @@ mod tests {
             "商品お届け時にお支払い".to_string(),
             false,
             1,
+            cod_fee_rule(),
         )
         .unwrap();
 
@@ -0,0 +1,331 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{Money, OrderId};
+
+/// 決済代行会社に対する操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentOperation {
+    /// オーソリ（与信枠の確保）
+    Authorize,
+    /// 売上確定
+    Capture,
+    /// オーソリの取消
+    Void,
+    /// 返金
+    Refund,
+}
+
+impl std::fmt::Display for PaymentOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaymentOperation::Authorize => write!(f, "authorize"),
+            PaymentOperation::Capture => write!(f, "capture"),
+            PaymentOperation::Void => write!(f, "void"),
+            PaymentOperation::Refund => write!(f, "refund"),
+        }
+    }
+}
+
+impl std::str::FromStr for PaymentOperation {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "authorize" => Ok(PaymentOperation::Authorize),
+            "capture" => Ok(PaymentOperation::Capture),
+            "void" => Ok(PaymentOperation::Void),
+            "refund" => Ok(PaymentOperation::Refund),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid payment operation: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 決済操作の結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentTransactionStatus {
+    /// 成功
+    Succeeded,
+    /// 拒否・失敗
+    Failed,
+}
+
+impl std::fmt::Display for PaymentTransactionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaymentTransactionStatus::Succeeded => write!(f, "succeeded"),
+            PaymentTransactionStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl std::str::FromStr for PaymentTransactionStatus {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "succeeded" => Ok(PaymentTransactionStatus::Succeeded),
+            "failed" => Ok(PaymentTransactionStatus::Failed),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid payment transaction status: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 決済操作の記録（注文ごとの試行と結果）
+#[derive(Debug, Clone)]
+pub struct PaymentTransaction {
+    pub order_id: OrderId,
+    /// 決済代行会社の識別子（例: "mock"）
+    pub provider: String,
+    pub operation: PaymentOperation,
+    pub amount: Money,
+    pub status: PaymentTransactionStatus,
+    /// 決済代行会社側の取引ID（オーソリ時に採番され、以降の操作で共通）
+    pub transaction_id: Option<String>,
+    /// 拒否・失敗の理由
+    pub failure_reason: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl PaymentTransaction {
+    /// 成功した操作を記録
+    pub fn succeeded(
+        order_id: OrderId,
+        provider: &str,
+        operation: PaymentOperation,
+        amount: Money,
+        transaction_id: String,
+    ) -> Self {
+        Self {
+            order_id,
+            provider: provider.to_string(),
+            operation,
+            amount,
+            status: PaymentTransactionStatus::Succeeded,
+            transaction_id: Some(transaction_id),
+            failure_reason: None,
+            occurred_at: Utc::now(),
+        }
+    }
+
+    /// 拒否・失敗した操作を記録
+    pub fn failed(
+        order_id: OrderId,
+        provider: &str,
+        operation: PaymentOperation,
+        amount: Money,
+        transaction_id: Option<String>,
+        failure_reason: String,
+    ) -> Self {
+        Self {
+            order_id,
+            provider: provider.to_string(),
+            operation,
+            amount,
+            status: PaymentTransactionStatus::Failed,
+            transaction_id,
+            failure_reason: Some(failure_reason),
+            occurred_at: Utc::now(),
+        }
+    }
+
+    pub fn is_succeeded(&self) -> bool {
+        self.status == PaymentTransactionStatus::Succeeded
+    }
+}
+
+/// 注文の決済状態（成功した操作の履歴から導出する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaymentState {
+    /// 未決済（オーソリ前、またはオーソリがすべて拒否された）
+    Unpaid,
+    /// オーソリ済み（売上未確定）
+    Authorized {
+        provider: String,
+        transaction_id: String,
+        amount: Money,
+    },
+    /// 売上確定済み（返品による一部返金を含む）
+    Captured {
+        provider: String,
+        transaction_id: String,
+        amount: Money,
+        /// 一部返金済みの金額
+        refunded_amount: Money,
+    },
+    /// オーソリ取消済み
+    Voided,
+    /// 返金済み
+    Refunded,
+}
+
+impl PaymentState {
+    /// 取引履歴（古い順）から現在の決済状態を求める
+    /// 返金は売上確定額に達するまで一部返金として累計し、達した時点で返金済みとする
+    pub fn from_transactions(transactions: &[PaymentTransaction]) -> Self {
+        transactions
+            .iter()
+            .filter(|transaction| transaction.is_succeeded())
+            .fold(PaymentState::Unpaid, |state, transaction| {
+                let transaction_id = transaction.transaction_id.clone().unwrap_or_default();
+                match transaction.operation {
+                    PaymentOperation::Authorize => PaymentState::Authorized {
+                        provider: transaction.provider.clone(),
+                        transaction_id,
+                        amount: transaction.amount,
+                    },
+                    PaymentOperation::Capture => PaymentState::Captured {
+                        provider: transaction.provider.clone(),
+                        transaction_id,
+                        amount: transaction.amount,
+                        refunded_amount: Money::zero(),
+                    },
+                    PaymentOperation::Void => PaymentState::Voided,
+                    PaymentOperation::Refund => match state {
+                        PaymentState::Captured {
+                            provider,
+                            transaction_id,
+                            amount,
+                            refunded_amount,
+                        } => {
+                            let refunded_amount =
+                                refunded_amount.add(transaction.amount).unwrap_or(amount);
+                            if refunded_amount.yen() >= amount.yen() {
+                                PaymentState::Refunded
+                            } else {
+                                PaymentState::Captured {
+                                    provider,
+                                    transaction_id,
+                                    amount,
+                                    refunded_amount,
+                                }
+                            }
+                        }
+                        other => other,
+                    },
+                }
+            })
+    }
+
+    /// APIで返す決済状態のコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            PaymentState::Unpaid => "unpaid",
+            PaymentState::Authorized { .. } => "authorized",
+            PaymentState::Captured { .. } => "captured",
+            PaymentState::Voided => "voided",
+            PaymentState::Refunded => "refunded",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn succeeded(operation: PaymentOperation) -> PaymentTransaction {
+        PaymentTransaction::succeeded(
+            OrderId::new(),
+            "mock",
+            operation,
+            Money::from_yen(1100),
+            "mock_txn_00000001".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_operation_round_trip() {
+        for operation in [
+            PaymentOperation::Authorize,
+            PaymentOperation::Capture,
+            PaymentOperation::Void,
+            PaymentOperation::Refund,
+        ] {
+            assert_eq!(
+                operation.to_string().parse::<PaymentOperation>(),
+                Ok(operation)
+            );
+        }
+        assert!("charge".parse::<PaymentOperation>().is_err());
+    }
+
+    #[test]
+    fn test_state_without_transactions_is_unpaid() {
+        assert_eq!(PaymentState::from_transactions(&[]), PaymentState::Unpaid);
+    }
+
+    #[test]
+    fn test_state_follows_succeeded_operations() {
+        let mut transactions = vec![succeeded(PaymentOperation::Authorize)];
+        assert!(matches!(
+            PaymentState::from_transactions(&transactions),
+            PaymentState::Authorized { .. }
+        ));
+
+        transactions.push(succeeded(PaymentOperation::Capture));
+        assert!(matches!(
+            PaymentState::from_transactions(&transactions),
+            PaymentState::Captured { .. }
+        ));
+
+        transactions.push(succeeded(PaymentOperation::Refund));
+        assert_eq!(
+            PaymentState::from_transactions(&transactions),
+            PaymentState::Refunded
+        );
+    }
+
+    #[test]
+    fn test_partial_refunds_accumulate_until_captured_amount() {
+        let refund = |yen| {
+            PaymentTransaction::succeeded(
+                OrderId::new(),
+                "mock",
+                PaymentOperation::Refund,
+                Money::from_yen(yen),
+                "mock_txn_00000001".to_string(),
+            )
+        };
+        let mut transactions = vec![
+            succeeded(PaymentOperation::Authorize),
+            succeeded(PaymentOperation::Capture),
+            refund(400),
+        ];
+        assert!(matches!(
+            PaymentState::from_transactions(&transactions),
+            PaymentState::Captured { refunded_amount, .. } if refunded_amount.yen() == 400
+        ));
+
+        transactions.push(refund(700));
+        assert_eq!(
+            PaymentState::from_transactions(&transactions),
+            PaymentState::Refunded
+        );
+    }
+
+    #[test]
+    fn test_failed_transactions_are_ignored() {
+        let transactions = vec![
+            succeeded(PaymentOperation::Authorize),
+            PaymentTransaction::failed(
+                OrderId::new(),
+                "mock",
+                PaymentOperation::Capture,
+                Money::from_yen(1100),
+                Some("mock_txn_00000001".to_string()),
+                "gateway timeout".to_string(),
+            ),
+        ];
+
+        assert!(matches!(
+            PaymentState::from_transactions(&transactions),
+            PaymentState::Authorized { .. }
+        ));
+    }
+}
@@ -50,6 +50,38 @@ impl Product {
         })
     }
 
+    /// 永続化された状態から商品集約を復元
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: ProductId,
+        name: ProductName,
+        description: Description,
+        category_id: CategoryId,
+        is_best_seller: bool,
+        is_quick_ship: bool,
+        is_available: bool,
+        skus: Vec<SKU>,
+        images: Vec<ProductImage>,
+        tags: Vec<Tag>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            description,
+            category_id,
+            is_best_seller,
+            is_quick_ship,
+            is_available,
+            skus,
+            images,
+            tags,
+            created_at,
+            updated_at,
+        }
+    }
+
     // SKU管理
     pub fn add_sku(&mut self, sku: SKU) -> Result<(), DomainError> {
         // ビジネスルール: 同じSKUコードは追加不可
@@ -79,6 +111,13 @@ impl Product {
             .position(|s| s.id() == sku_id)
             .ok_or_else(|| DomainError::BusinessRuleViolation("SKU not found".to_string()))?;
 
+        // ビジネスルール: 注文で引当中の在庫があるSKUは削除不可
+        if self.skus[index].reserved_quantity() > 0 {
+            return Err(DomainError::BusinessRuleViolation(
+                "Cannot remove SKU with reserved stock".to_string(),
+            ));
+        }
+
         let removed_sku = self.skus.remove(index);
         self.updated_at = Utc::now();
         Ok(removed_sku)
@@ -226,6 +265,12 @@ impl Product {
         self.updated_at = Utc::now();
     }
 
+    /// 画像を指定した並びで置き換える
+    pub fn replace_images(&mut self, images: Vec<ProductImage>) {
+        self.images = images;
+        self.updated_at = Utc::now();
+    }
+
     pub fn main_image(&self) -> Option<&ProductImage> {
         self.images.iter().find(|img| img.is_main_image())
     }
@@ -247,6 +292,26 @@ impl Product {
         self.updated_at = Utc::now();
     }
 
+    /// 指定日時に販売中のSKUがセール価格になっているか
+    pub fn is_on_sale_at(&self, now: DateTime<Utc>) -> bool {
+        self.skus
+            .iter()
+            .any(|sku| sku.status().is_active() && sku.is_on_sale_at(now))
+    }
+
+    /// 指定日時に表示するタグ
+    /// セール中は on_sale システムタグを自動で付与する（保存されたタグは変更しない）
+    pub fn tags_at(&self, now: DateTime<Utc>) -> Vec<Tag> {
+        let mut tags = self.tags.clone();
+        if self.is_on_sale_at(now)
+            && let Ok(on_sale) = Tag::on_sale()
+            && !tags.iter().any(|t| t.slug() == on_sale.slug())
+        {
+            tags.insert(0, on_sale);
+        }
+        tags
+    }
+
     // 商品の公開・非公開
     pub fn publish(&mut self) -> Result<(), DomainError> {
         // ビジネスルール: SKUが存在しない場合は公開不可
@@ -268,6 +333,12 @@ impl Product {
         Ok(())
     }
 
+    /// 商品を非公開にする（SKUのステータスは変更しない）
+    pub fn unpublish(&mut self) {
+        self.is_available = false;
+        self.updated_at = Utc::now();
+    }
+
     pub fn discontinue(&mut self) -> Result<(), DomainError> {
         self.is_available = false;
 
@@ -305,6 +376,16 @@ impl Product {
         self.updated_at = Utc::now();
     }
 
+    pub fn rename(&mut self, name: ProductName) {
+        self.name = name;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn change_category(&mut self, category_id: CategoryId) {
+        self.category_id = category_id;
+        self.updated_at = Utc::now();
+    }
+
     // Getters
     pub fn id(&self) -> &ProductId {
         &self.id
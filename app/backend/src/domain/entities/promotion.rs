@@ -0,0 +1,242 @@
+use chrono::prelude::*;
+
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{DiscountPolicy, PromotionId, PurchaseInfo};
+
+/// プロモーション（クーポンコードなしで自動適用されるセール）
+/// 期間中に適用条件を満たすカートへ、優先度の高いものから順に適用する
+#[derive(Debug, Clone, PartialEq)]
+pub struct Promotion {
+    id: PromotionId,
+    name: String,
+    description: Option<String>,
+    discount_policy: DiscountPolicy,
+    /// 対象商品の最低購入点数（「2点以上で5%オフ」など）
+    minimum_quantity: Option<u32>,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    /// 優先度（大きいほど先に適用する）
+    priority: i32,
+    /// 他のプロモーションと併用できるか（併用不可のプロモーションは単独でのみ適用する）
+    stackable: bool,
+    is_active: bool,
+}
+
+impl Promotion {
+    /// プロモーションを作成（割引内容・期間を検証する）
+    pub fn new(
+        id: PromotionId,
+        name: String,
+        discount_policy: DiscountPolicy,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        if name.trim().is_empty() {
+            return Err(DomainError::InvalidProductData(
+                "Promotion name cannot be empty".to_string(),
+            ));
+        }
+        if starts_at > ends_at {
+            return Err(DomainError::InvalidProductData(
+                "Promotion starts_at must be before ends_at".to_string(),
+            ));
+        }
+        discount_policy.validate()?;
+
+        Ok(Self {
+            id,
+            name,
+            description: None,
+            discount_policy,
+            minimum_quantity: None,
+            starts_at,
+            ends_at,
+            priority: 0,
+            stackable: true,
+            is_active: true,
+        })
+    }
+
+    /// 説明を設定
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// 対象商品の最低購入点数を設定
+    pub fn with_minimum_quantity(
+        mut self,
+        minimum_quantity: Option<u32>,
+    ) -> Result<Self, DomainError> {
+        if minimum_quantity == Some(0) {
+            return Err(DomainError::InvalidProductData(
+                "Promotion minimum quantity must be positive".to_string(),
+            ));
+        }
+        self.minimum_quantity = minimum_quantity;
+        Ok(self)
+    }
+
+    /// 優先度を設定
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// 他のプロモーションと併用できるかを設定
+    pub fn with_stackable(mut self, stackable: bool) -> Self {
+        self.stackable = stackable;
+        self
+    }
+
+    /// 有効・無効を設定（無効のプロモーションは期間中でも適用しない）
+    pub fn with_active(mut self, is_active: bool) -> Self {
+        self.is_active = is_active;
+        self
+    }
+
+    /// 指定日時に実施中か
+    pub fn is_running_at(&self, now: DateTime<Utc>) -> bool {
+        self.is_active && self.starts_at <= now && now <= self.ends_at
+    }
+
+    /// 購入情報が適用条件（割引ポリシーの条件と最低購入点数）を満たしているか
+    pub fn is_applicable_to(&self, purchase_info: &PurchaseInfo) -> bool {
+        if !self.discount_policy.is_satisfied_by(purchase_info) {
+            return false;
+        }
+
+        match self.minimum_quantity {
+            Some(minimum_quantity) => {
+                let eligible_quantity: u32 = purchase_info
+                    .cart_items()
+                    .iter()
+                    .filter(|item| self.discount_policy.applies_to(item))
+                    .map(|item| item.quantity())
+                    .sum();
+                eligible_quantity >= minimum_quantity
+            }
+            None => true,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> &PromotionId {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn discount_policy(&self) -> &DiscountPolicy {
+        &self.discount_policy
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn is_stackable(&self) -> bool {
+        self.stackable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::CartItem;
+    use crate::domain::value_objects::{
+        CategoryId, DiscountCondition, DiscountType, Money, ProductId, ProductName, SKUId,
+    };
+    use uuid::Uuid;
+
+    fn chair(quantity: u32) -> CartItem {
+        CartItem::new(
+            SKUId::from_uuid(Uuid::new_v4()),
+            ProductId::from_uuid(Uuid::new_v4()),
+            ProductName::new("Chair".to_string()).unwrap(),
+            Money::from_yen(10000),
+            quantity,
+        )
+        .unwrap()
+        .with_category_id(CategoryId::new("chairs".to_string()).unwrap())
+    }
+
+    fn chairs_promotion() -> Promotion {
+        Promotion::new(
+            PromotionId::from_uuid(Uuid::new_v4()),
+            "チェア2点以上で5%オフ".to_string(),
+            DiscountPolicy::new(
+                DiscountType::Percentage(5),
+                Some(DiscountCondition::CategorySpecific(vec![
+                    CategoryId::new("chairs".to_string()).unwrap(),
+                ])),
+            ),
+            Utc::now() - chrono::Duration::days(1),
+            Utc::now() + chrono::Duration::days(1),
+        )
+        .unwrap()
+        .with_minimum_quantity(Some(2))
+        .unwrap()
+    }
+
+    #[test]
+    fn new_validates_fields() {
+        let now = Utc::now();
+        let policy = DiscountPolicy::new(DiscountType::Percentage(10), None);
+        let id = PromotionId::from_uuid(Uuid::new_v4());
+
+        assert!(Promotion::new(id.clone(), " ".to_string(), policy.clone(), now, now).is_err());
+        assert!(
+            Promotion::new(
+                id.clone(),
+                "Sale".to_string(),
+                policy.clone(),
+                now,
+                now - chrono::Duration::days(1),
+            )
+            .is_err()
+        );
+        assert!(
+            Promotion::new(id, "Sale".to_string(), policy, now, now)
+                .unwrap()
+                .with_minimum_quantity(Some(0))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn running_period_and_active_flag() {
+        let promotion = chairs_promotion();
+
+        assert!(promotion.is_running_at(Utc::now()));
+        assert!(!promotion.is_running_at(Utc::now() + chrono::Duration::days(2)));
+        assert!(!promotion.with_active(false).is_running_at(Utc::now()));
+    }
+
+    #[test]
+    fn minimum_quantity_counts_eligible_items_only() {
+        let promotion = chairs_promotion();
+        let other = CartItem::new(
+            SKUId::from_uuid(Uuid::new_v4()),
+            ProductId::from_uuid(Uuid::new_v4()),
+            ProductName::new("Lamp".to_string()).unwrap(),
+            Money::from_yen(3000),
+            5,
+        )
+        .unwrap();
+
+        let one_chair = PurchaseInfo::new(
+            vec![chair(1), other.clone()],
+            Money::from_yen(25000),
+            None,
+            None,
+        );
+        let two_chairs =
+            PurchaseInfo::new(vec![chair(2), other], Money::from_yen(35000), None, None);
+
+        assert!(!promotion.is_applicable_to(&one_chair));
+        assert!(promotion.is_applicable_to(&two_chairs));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::domain::entities::{ShipmentDetails, ShippingRateTable};
+use crate::domain::error::DomainError;
 use crate::domain::value_objects::{Money, ShippingMethodId};
 use chrono::{DateTime, Utc};
 use std::fmt;
@@ -10,6 +12,7 @@ pub struct ShippingMethod {
     price: Money,
     is_active: bool,
     sort_order: u32,
+    rate_table: ShippingRateTable,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -31,6 +34,7 @@ impl ShippingMethod {
             price,
             is_active,
             sort_order,
+            rate_table: ShippingRateTable::default(),
             created_at: now,
             updated_at: now,
         }
@@ -53,11 +57,28 @@ impl ShippingMethod {
             price,
             is_active,
             sort_order,
+            rate_table: ShippingRateTable::default(),
             created_at,
             updated_at,
         }
     }
 
+    /// 配送料金表を設定（未設定の場合は地域・商品に関わらず基本料金）
+    pub fn with_rate_table(mut self, rate_table: ShippingRateTable) -> Self {
+        self.rate_table = rate_table;
+        self
+    }
+
+    /// 配送料を計算
+    /// 基本料金に配送料金表の地域別料金・送料無料条件・追加料金を適用する
+    pub fn calculate_fee(
+        &self,
+        subtotal: Money,
+        shipment: &ShipmentDetails,
+    ) -> Result<Money, DomainError> {
+        self.rate_table.calculate(self.price, subtotal, shipment)
+    }
+
     // Getters
     pub fn id(&self) -> &ShippingMethodId {
         &self.id
@@ -79,6 +100,10 @@ impl ShippingMethod {
         self.is_active
     }
 
+    pub fn rate_table(&self) -> &ShippingRateTable {
+        &self.rate_table
+    }
+
     pub fn sort_order(&self) -> u32 {
         self.sort_order
     }
@@ -101,7 +126,7 @@ impl fmt::Display for ShippingMethod {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::error::DomainError;
+    use crate::domain::entities::{ShippableItem, ShippingRegion, ShippingRegionRate};
 
     #[test]
     fn test_new_shipping_method() -> Result<(), DomainError> {
@@ -135,4 +160,45 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_calculate_fee_uses_rate_table() -> Result<(), DomainError> {
+        let id = ShippingMethodId::new("standard".to_string())?;
+        let method = ShippingMethod::new(
+            id,
+            "標準配送".to_string(),
+            "5-7営業日".to_string(),
+            Money::from_yen(500),
+            true,
+            1,
+        );
+        let items = vec![ShippableItem::new(1, None, false)];
+        let hokkaido = ShipmentDetails::new(Some("北海道".to_string()), items.clone());
+
+        // 料金表なしは基本料金
+        assert_eq!(
+            method.calculate_fee(Money::from_yen(3000), &hokkaido)?,
+            Money::from_yen(500)
+        );
+
+        let method = method.with_rate_table(ShippingRateTable::new(
+            vec![ShippingRegionRate::new(
+                ShippingRegion::Hokkaido,
+                Money::from_yen(1000),
+            )],
+            Some(Money::from_yen(10000)),
+            None,
+            false,
+        )?);
+        assert_eq!(
+            method.calculate_fee(Money::from_yen(3000), &hokkaido)?,
+            Money::from_yen(1000)
+        );
+        assert_eq!(
+            method.calculate_fee(Money::from_yen(10000), &hokkaido)?,
+            Money::zero()
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,410 @@
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{Dimensions, Money};
+
+/// 配送料の地域区分
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShippingRegion {
+    Hokkaido,
+    Tohoku,
+    Kanto,
+    Chubu,
+    Kinki,
+    Chugoku,
+    Shikoku,
+    Kyushu,
+    Okinawa,
+}
+
+/// 都道府県と地域区分の対応
+const PREFECTURE_REGIONS: [(&str, ShippingRegion); 47] = [
+    ("北海道", ShippingRegion::Hokkaido),
+    ("青森県", ShippingRegion::Tohoku),
+    ("岩手県", ShippingRegion::Tohoku),
+    ("宮城県", ShippingRegion::Tohoku),
+    ("秋田県", ShippingRegion::Tohoku),
+    ("山形県", ShippingRegion::Tohoku),
+    ("福島県", ShippingRegion::Tohoku),
+    ("茨城県", ShippingRegion::Kanto),
+    ("栃木県", ShippingRegion::Kanto),
+    ("群馬県", ShippingRegion::Kanto),
+    ("埼玉県", ShippingRegion::Kanto),
+    ("千葉県", ShippingRegion::Kanto),
+    ("東京都", ShippingRegion::Kanto),
+    ("神奈川県", ShippingRegion::Kanto),
+    ("新潟県", ShippingRegion::Chubu),
+    ("富山県", ShippingRegion::Chubu),
+    ("石川県", ShippingRegion::Chubu),
+    ("福井県", ShippingRegion::Chubu),
+    ("山梨県", ShippingRegion::Chubu),
+    ("長野県", ShippingRegion::Chubu),
+    ("岐阜県", ShippingRegion::Chubu),
+    ("静岡県", ShippingRegion::Chubu),
+    ("愛知県", ShippingRegion::Chubu),
+    ("三重県", ShippingRegion::Kinki),
+    ("滋賀県", ShippingRegion::Kinki),
+    ("京都府", ShippingRegion::Kinki),
+    ("大阪府", ShippingRegion::Kinki),
+    ("兵庫県", ShippingRegion::Kinki),
+    ("奈良県", ShippingRegion::Kinki),
+    ("和歌山県", ShippingRegion::Kinki),
+    ("鳥取県", ShippingRegion::Chugoku),
+    ("島根県", ShippingRegion::Chugoku),
+    ("岡山県", ShippingRegion::Chugoku),
+    ("広島県", ShippingRegion::Chugoku),
+    ("山口県", ShippingRegion::Chugoku),
+    ("徳島県", ShippingRegion::Shikoku),
+    ("香川県", ShippingRegion::Shikoku),
+    ("愛媛県", ShippingRegion::Shikoku),
+    ("高知県", ShippingRegion::Shikoku),
+    ("福岡県", ShippingRegion::Kyushu),
+    ("佐賀県", ShippingRegion::Kyushu),
+    ("長崎県", ShippingRegion::Kyushu),
+    ("熊本県", ShippingRegion::Kyushu),
+    ("大分県", ShippingRegion::Kyushu),
+    ("宮崎県", ShippingRegion::Kyushu),
+    ("鹿児島県", ShippingRegion::Kyushu),
+    ("沖縄県", ShippingRegion::Okinawa),
+];
+
+impl ShippingRegion {
+    /// 都道府県名から地域区分を求める（「東京」のように都府県を省略した名前も可）
+    pub fn from_prefecture(prefecture: &str) -> Option<Self> {
+        let prefecture = prefecture.trim();
+        PREFECTURE_REGIONS
+            .iter()
+            .find(|(name, _)| {
+                *name == prefecture || name.strip_suffix(['都', '府', '県']) == Some(prefecture)
+            })
+            .map(|(_, region)| *region)
+    }
+
+    /// DBやAPIで使う地域区分のコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            ShippingRegion::Hokkaido => "hokkaido",
+            ShippingRegion::Tohoku => "tohoku",
+            ShippingRegion::Kanto => "kanto",
+            ShippingRegion::Chubu => "chubu",
+            ShippingRegion::Kinki => "kinki",
+            ShippingRegion::Chugoku => "chugoku",
+            ShippingRegion::Shikoku => "shikoku",
+            ShippingRegion::Kyushu => "kyushu",
+            ShippingRegion::Okinawa => "okinawa",
+        }
+    }
+}
+
+impl std::str::FromStr for ShippingRegion {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hokkaido" => Ok(ShippingRegion::Hokkaido),
+            "tohoku" => Ok(ShippingRegion::Tohoku),
+            "kanto" => Ok(ShippingRegion::Kanto),
+            "chubu" => Ok(ShippingRegion::Chubu),
+            "kinki" => Ok(ShippingRegion::Kinki),
+            "chugoku" => Ok(ShippingRegion::Chugoku),
+            "shikoku" => Ok(ShippingRegion::Shikoku),
+            "kyushu" => Ok(ShippingRegion::Kyushu),
+            "okinawa" => Ok(ShippingRegion::Okinawa),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid shipping region: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 地域区分ごとの配送料
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShippingRegionRate {
+    pub region: ShippingRegion,
+    pub fee: Money,
+}
+
+impl ShippingRegionRate {
+    pub fn new(region: ShippingRegion, fee: Money) -> Self {
+        Self { region, fee }
+    }
+}
+
+/// 大型商品の追加料金
+/// 3辺合計が`min_size`以上の商品1点ごとに`fee_per_item`を加算する
+#[derive(Debug, Clone, PartialEq)]
+pub struct OversizeSurcharge {
+    pub min_size: f64,
+    pub fee_per_item: Money,
+}
+
+/// 配送料計算に使う商品ごとの情報
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShippableItem {
+    pub quantity: u32,
+    pub dimensions: Option<Dimensions>,
+    pub is_quick_ship: bool,
+}
+
+impl ShippableItem {
+    pub fn new(quantity: u32, dimensions: Option<Dimensions>, is_quick_ship: bool) -> Self {
+        Self {
+            quantity,
+            dimensions,
+            is_quick_ship,
+        }
+    }
+}
+
+/// 配送料計算の入力（配送先と商品）
+/// 配送先が未定の場合（カート計算など）は地域別料金を適用しない
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShipmentDetails {
+    pub prefecture: Option<String>,
+    pub items: Vec<ShippableItem>,
+}
+
+impl ShipmentDetails {
+    pub fn new(prefecture: Option<String>, items: Vec<ShippableItem>) -> Self {
+        Self { prefecture, items }
+    }
+}
+
+/// 配送料金表
+/// 配送方法の基本料金に対し、地域別料金・送料無料条件・大型商品の追加料金・即日出荷限定を適用する
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShippingRateTable {
+    region_rates: Vec<ShippingRegionRate>,
+    free_shipping_threshold: Option<Money>,
+    oversize_surcharge: Option<OversizeSurcharge>,
+    quick_ship_only: bool,
+}
+
+impl ShippingRateTable {
+    pub fn new(
+        region_rates: Vec<ShippingRegionRate>,
+        free_shipping_threshold: Option<Money>,
+        oversize_surcharge: Option<OversizeSurcharge>,
+        quick_ship_only: bool,
+    ) -> Result<Self, DomainError> {
+        for (index, rate) in region_rates.iter().enumerate() {
+            if region_rates[..index]
+                .iter()
+                .any(|other| other.region == rate.region)
+            {
+                return Err(DomainError::InvalidProductData(format!(
+                    "Duplicate shipping rate for region: {}",
+                    rate.region.code()
+                )));
+            }
+        }
+
+        if let Some(surcharge) = &oversize_surcharge
+            && (!surcharge.min_size.is_finite() || surcharge.min_size <= 0.0)
+        {
+            return Err(DomainError::InvalidProductData(
+                "Oversize threshold must be greater than zero".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            region_rates,
+            free_shipping_threshold,
+            oversize_surcharge,
+            quick_ship_only,
+        })
+    }
+
+    /// 配送料を計算
+    /// 送料無料の判定は割引前の商品小計で行い、大型商品の追加料金は送料無料でも加算する
+    pub fn calculate(
+        &self,
+        base_fee: Money,
+        subtotal: Money,
+        shipment: &ShipmentDetails,
+    ) -> Result<Money, DomainError> {
+        if self.quick_ship_only && shipment.items.iter().any(|item| !item.is_quick_ship) {
+            return Err(DomainError::BusinessRuleViolation(
+                "Shipping method is only available for quick ship items".to_string(),
+            ));
+        }
+
+        let region = shipment
+            .prefecture
+            .as_deref()
+            .and_then(ShippingRegion::from_prefecture);
+        let mut fee = region
+            .and_then(|region| self.region_rates.iter().find(|rate| rate.region == region))
+            .map_or(base_fee, |rate| rate.fee);
+
+        if let Some(threshold) = self.free_shipping_threshold
+            && subtotal.yen() >= threshold.yen()
+        {
+            fee = Money::zero();
+        }
+
+        if let Some(surcharge) = &self.oversize_surcharge {
+            for item in &shipment.items {
+                let is_oversized = item
+                    .dimensions
+                    .as_ref()
+                    .and_then(Dimensions::size_sum)
+                    .is_some_and(|size| size >= surcharge.min_size);
+                if is_oversized {
+                    fee = fee.add(surcharge.fee_per_item.multiply(item.quantity)?)?;
+                }
+            }
+        }
+
+        Ok(fee)
+    }
+
+    pub fn region_rates(&self) -> &[ShippingRegionRate] {
+        &self.region_rates
+    }
+
+    pub fn free_shipping_threshold(&self) -> Option<Money> {
+        self.free_shipping_threshold
+    }
+
+    pub fn oversize_surcharge(&self) -> Option<&OversizeSurcharge> {
+        self.oversize_surcharge.as_ref()
+    }
+
+    pub fn is_quick_ship_only(&self) -> bool {
+        self.quick_ship_only
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> ShippingRateTable {
+        ShippingRateTable::new(
+            vec![
+                ShippingRegionRate::new(ShippingRegion::Hokkaido, Money::from_yen(1000)),
+                ShippingRegionRate::new(ShippingRegion::Okinawa, Money::from_yen(1500)),
+            ],
+            Some(Money::from_yen(10000)),
+            Some(OversizeSurcharge {
+                min_size: 100.0,
+                fee_per_item: Money::from_yen(800),
+            }),
+            false,
+        )
+        .unwrap()
+    }
+
+    fn shipment(prefecture: Option<&str>, items: Vec<ShippableItem>) -> ShipmentDetails {
+        ShipmentDetails::new(prefecture.map(str::to_string), items)
+    }
+
+    fn item(quantity: u32, dimensions: &str) -> ShippableItem {
+        ShippableItem::new(
+            quantity,
+            Some(Dimensions::new(dimensions.to_string()).unwrap()),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_region_lookup() {
+        assert_eq!(
+            ShippingRegion::from_prefecture("北海道"),
+            Some(ShippingRegion::Hokkaido)
+        );
+        assert_eq!(
+            ShippingRegion::from_prefecture("京都"),
+            Some(ShippingRegion::Kinki)
+        );
+        assert_eq!(
+            ShippingRegion::from_prefecture("東京都"),
+            Some(ShippingRegion::Kanto)
+        );
+        assert_eq!(ShippingRegion::from_prefecture("Tokyo"), None);
+    }
+
+    #[test]
+    fn test_region_rate_and_fallback() {
+        let table = table();
+        let base = Money::from_yen(500);
+        let subtotal = Money::from_yen(3000);
+
+        let hokkaido = shipment(Some("北海道"), vec![item(1, "10\" x 10\" x 10\"")]);
+        assert_eq!(
+            table.calculate(base, subtotal, &hokkaido).unwrap(),
+            Money::from_yen(1000)
+        );
+
+        // 地域別料金がない地域・配送先未定は基本料金
+        let tokyo = shipment(Some("東京都"), vec![item(1, "10\" x 10\" x 10\"")]);
+        assert_eq!(table.calculate(base, subtotal, &tokyo).unwrap(), base);
+        let unknown = shipment(None, vec![item(1, "10\" x 10\" x 10\"")]);
+        assert_eq!(table.calculate(base, subtotal, &unknown).unwrap(), base);
+    }
+
+    #[test]
+    fn test_free_shipping_keeps_oversize_surcharge() {
+        let table = table();
+        let base = Money::from_yen(500);
+
+        let regular = shipment(Some("沖縄県"), vec![item(1, "10\" x 10\" x 10\"")]);
+        assert_eq!(
+            table
+                .calculate(base, Money::from_yen(10000), &regular)
+                .unwrap(),
+            Money::zero()
+        );
+
+        // 3辺合計138の大型商品2点
+        let oversized = shipment(
+            Some("沖縄県"),
+            vec![item(2, "72\" x 36\" x 30\""), item(1, "10\" x 10\" x 10\"")],
+        );
+        assert_eq!(
+            table
+                .calculate(base, Money::from_yen(10000), &oversized)
+                .unwrap(),
+            Money::from_yen(1600)
+        );
+        assert_eq!(
+            table
+                .calculate(base, Money::from_yen(3000), &oversized)
+                .unwrap(),
+            Money::from_yen(3100)
+        );
+    }
+
+    #[test]
+    fn test_quick_ship_only() {
+        let table = ShippingRateTable::new(Vec::new(), None, None, true).unwrap();
+        let base = Money::from_yen(2000);
+        let subtotal = Money::from_yen(3000);
+
+        let quick = shipment(None, vec![ShippableItem::new(1, None, true)]);
+        assert_eq!(table.calculate(base, subtotal, &quick).unwrap(), base);
+
+        let mixed = shipment(
+            None,
+            vec![
+                ShippableItem::new(1, None, true),
+                ShippableItem::new(1, None, false),
+            ],
+        );
+        assert!(table.calculate(base, subtotal, &mixed).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_region_is_rejected() {
+        let result = ShippingRateTable::new(
+            vec![
+                ShippingRegionRate::new(ShippingRegion::Kanto, Money::from_yen(500)),
+                ShippingRegionRate::new(ShippingRegion::Kanto, Money::from_yen(600)),
+            ],
+            None,
+            None,
+            false,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -11,9 +11,12 @@ pub struct SKU {
     variant_attributes: VariantAttributes,
     base_price: Money,
     sale_price: Option<Money>,
+    /// 期間指定のセール価格（開始日時順）。適用中のスケジュールは手動のセール価格より優先する
+    price_schedules: Vec<PriceSchedule>,
     stock: Stock,
     status: SKUStatus,
     display_order: u32,
+    tax_category: TaxCategory,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -79,14 +82,74 @@ impl SKU {
             variant_attributes,
             base_price,
             sale_price: None,
+            price_schedules: Vec::new(),
             stock,
             status: SKUStatus::Active,
             display_order,
+            tax_category: TaxCategory::default(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         })
     }
 
+    /// 永続化された状態からSKUを復元
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: SKUId,
+        product_id: ProductId,
+        sku_code: SKUCode,
+        name: SKUName,
+        variant_attributes: VariantAttributes,
+        base_price: Money,
+        sale_price: Option<Money>,
+        stock: Stock,
+        status: SKUStatus,
+        display_order: u32,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            product_id,
+            sku_code,
+            name,
+            variant_attributes,
+            base_price,
+            sale_price,
+            price_schedules: Vec::new(),
+            stock,
+            status,
+            display_order,
+            tax_category: TaxCategory::default(),
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// 税区分を設定（作成・復元時に使用）
+    pub fn with_tax_category(mut self, tax_category: TaxCategory) -> Self {
+        self.tax_category = tax_category;
+        self
+    }
+
+    /// 価格スケジュールを設定（復元時に使用）
+    pub fn with_price_schedules(mut self, mut price_schedules: Vec<PriceSchedule>) -> Self {
+        price_schedules.sort_by_key(|schedule| schedule.starts_at());
+        self.price_schedules = price_schedules;
+        self
+    }
+
+    pub fn rename(&mut self, name: SKUName) {
+        self.name = name;
+        self.updated_at = Utc::now();
+    }
+
+    /// 税区分を変更
+    pub fn change_tax_category(&mut self, tax_category: TaxCategory) {
+        self.tax_category = tax_category;
+        self.updated_at = Utc::now();
+    }
+
     // 在庫管理
     pub fn adjust_stock(&mut self, adjustment: StockAdjustment) -> Result<(), DomainError> {
         self.stock.adjust(adjustment)?;
@@ -123,6 +186,40 @@ impl SKU {
         self.updated_at = Utc::now();
     }
 
+    /// 期間指定のセールを予約
+    /// セール価格は基本価格未満で、終了済みの期間や既存のスケジュールと重なる期間は指定できない
+    pub fn schedule_sale(
+        &mut self,
+        schedule: PriceSchedule,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if schedule.sale_price().yen() >= self.base_price.yen() {
+            return Err(DomainError::InvalidPrice(
+                "Sale price must be less than base price".to_string(),
+            ));
+        }
+        if schedule.has_ended_at(now) {
+            return Err(DomainError::InvalidPrice(
+                "Price schedule has already ended".to_string(),
+            ));
+        }
+        if self
+            .price_schedules
+            .iter()
+            .any(|existing| existing.overlaps(&schedule))
+        {
+            return Err(DomainError::BusinessRuleViolation(
+                "Price schedule overlaps an existing schedule".to_string(),
+            ));
+        }
+
+        self.price_schedules.push(schedule);
+        self.price_schedules
+            .sort_by_key(|schedule| schedule.starts_at());
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     pub fn update_base_price(&mut self, price: Money) -> Result<(), DomainError> {
         if !price.is_positive() {
             return Err(DomainError::InvalidPrice(
@@ -138,6 +235,15 @@ impl SKU {
             }
         }
 
+        let now = Utc::now();
+        if self.price_schedules.iter().any(|schedule| {
+            !schedule.has_ended_at(now) && schedule.sale_price().yen() >= price.yen()
+        }) {
+            return Err(DomainError::InvalidPrice(
+                "Base price must be higher than scheduled sale prices".to_string(),
+            ));
+        }
+
         self.base_price = price;
         self.updated_at = Utc::now();
         Ok(())
@@ -181,23 +287,40 @@ impl SKU {
     }
 
     // ビジネスロジック
+    /// 指定日時に適用されるセール価格（適用中のスケジュールがあればその価格、なければ手動のセール価格）
+    pub fn sale_price_at(&self, now: DateTime<Utc>) -> Option<Money> {
+        self.price_schedules
+            .iter()
+            .find(|schedule| schedule.is_active_at(now))
+            .map(|schedule| schedule.sale_price())
+            .or(self.sale_price)
+    }
+
+    pub fn current_price_at(&self, now: DateTime<Utc>) -> Money {
+        self.sale_price_at(now).unwrap_or(self.base_price)
+    }
+
     pub fn current_price(&self) -> Money {
-        self.sale_price.unwrap_or(self.base_price)
+        self.current_price_at(Utc::now())
+    }
+
+    pub fn is_on_sale_at(&self, now: DateTime<Utc>) -> bool {
+        self.sale_price_at(now).is_some()
     }
 
     pub fn is_on_sale(&self) -> bool {
-        self.sale_price.is_some()
+        self.is_on_sale_at(Utc::now())
     }
 
     pub fn discount_percentage(&self) -> Option<u8> {
-        self.sale_price.map(|sale_price| {
+        self.sale_price_at(Utc::now()).map(|sale_price| {
             let discount = self.base_price.yen() - sale_price.yen();
             ((discount as f64 / self.base_price.yen() as f64) * 100.0).round() as u8
         })
     }
 
     pub fn savings_amount(&self) -> Money {
-        if let Some(sale_price) = self.sale_price {
+        if let Some(sale_price) = self.sale_price_at(Utc::now()) {
             self.base_price
                 .subtract(sale_price)
                 .unwrap_or(Money::from_yen(0))
@@ -263,6 +386,10 @@ impl SKU {
         self.sale_price
     }
 
+    pub fn price_schedules(&self) -> &[PriceSchedule] {
+        &self.price_schedules
+    }
+
     pub fn stock(&self) -> &Stock {
         &self.stock
     }
@@ -291,6 +418,10 @@ impl SKU {
         self.updated_at
     }
 
+    pub fn tax_category(&self) -> TaxCategory {
+        self.tax_category
+    }
+
     pub fn display_order(&self) -> u32 {
         self.display_order
     }
@@ -374,6 +505,10 @@ impl Stock {
         self.low_stock_threshold = threshold;
     }
 
+    pub fn low_stock_threshold(&self) -> u32 {
+        self.low_stock_threshold
+    }
+
     pub fn is_low_stock(&self) -> bool {
         self.available_quantity() > 0 && self.available_quantity() <= self.low_stock_threshold
     }
@@ -397,3 +532,93 @@ impl SKUStatus {
         matches!(self, SKUStatus::Active)
     }
 }
+
+impl std::fmt::Display for SKUStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SKUStatus::Active => write!(f, "active"),
+            SKUStatus::Inactive => write!(f, "inactive"),
+            SKUStatus::Discontinued => write!(f, "discontinued"),
+        }
+    }
+}
+
+impl std::str::FromStr for SKUStatus {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "active" => Ok(SKUStatus::Active),
+            "inactive" => Ok(SKUStatus::Inactive),
+            "discontinued" => Ok(SKUStatus::Discontinued),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid SKU status: {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 5, day, 0, 0, 0).unwrap()
+    }
+
+    fn sku(base_price: u32) -> SKU {
+        SKU::create(
+            SKUId::new(),
+            ProductId::new(),
+            SKUCode::new("SKU-001".to_string()).unwrap(),
+            SKUName::new("Test SKU".to_string()).unwrap(),
+            Money::from_yen(base_price),
+            10,
+        )
+        .unwrap()
+    }
+
+    fn schedule(sale_price: u32, from: u32, to: u32) -> PriceSchedule {
+        PriceSchedule::new(Money::from_yen(sale_price), at(from), at(to)).unwrap()
+    }
+
+    #[test]
+    fn test_scheduled_sale_price_applies_only_during_period() {
+        let mut sku = sku(10000);
+        sku.schedule_sale(schedule(8000, 10, 20), at(1)).unwrap();
+
+        assert_eq!(sku.current_price_at(at(5)), Money::from_yen(10000));
+        assert_eq!(sku.current_price_at(at(10)), Money::from_yen(8000));
+        assert!(sku.is_on_sale_at(at(15)));
+        assert!(!sku.is_on_sale_at(at(20)));
+    }
+
+    #[test]
+    fn test_scheduled_sale_overrides_manual_sale_price() {
+        let mut sku = sku(10000);
+        sku.set_sale_price(Money::from_yen(9000)).unwrap();
+        sku.schedule_sale(schedule(7000, 10, 20), at(1)).unwrap();
+
+        assert_eq!(sku.sale_price_at(at(5)), Some(Money::from_yen(9000)));
+        assert_eq!(sku.sale_price_at(at(12)), Some(Money::from_yen(7000)));
+        assert_eq!(sku.sale_price_at(at(25)), Some(Money::from_yen(9000)));
+    }
+
+    #[test]
+    fn test_schedule_sale_rejects_invalid_schedules() {
+        let mut sku = sku(10000);
+        sku.schedule_sale(schedule(8000, 10, 20), at(1)).unwrap();
+
+        // 基本価格以上のセール価格
+        assert!(sku.schedule_sale(schedule(10000, 21, 25), at(1)).is_err());
+        // 終了済みの期間
+        assert!(sku.schedule_sale(schedule(8000, 2, 5), at(6)).is_err());
+        // 既存のスケジュールと重なる期間
+        assert!(sku.schedule_sale(schedule(7000, 15, 25), at(1)).is_err());
+
+        sku.schedule_sale(schedule(7000, 20, 25), at(1)).unwrap();
+        assert_eq!(sku.price_schedules().len(), 2);
+    }
+}
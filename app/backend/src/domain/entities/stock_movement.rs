@@ -0,0 +1,263 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::sku::StockAdjustment;
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{OrderId, SKUId};
+
+/// 在庫変動の種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StockMovementKind {
+    /// 入荷
+    Receive,
+    /// 棚卸などによる数量補正
+    Correction,
+    /// 破損・紛失による廃棄
+    WriteOff,
+    /// 注文確定による出庫
+    Sale,
+    /// 注文の取消・返金による戻し入れ
+    Restock,
+}
+
+impl StockMovementKind {
+    /// 在庫調整APIから手動で登録できる種別か
+    pub fn is_manual(&self) -> bool {
+        matches!(
+            self,
+            StockMovementKind::Receive
+                | StockMovementKind::Correction
+                | StockMovementKind::WriteOff
+        )
+    }
+}
+
+impl std::fmt::Display for StockMovementKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StockMovementKind::Receive => write!(f, "receive"),
+            StockMovementKind::Correction => write!(f, "correction"),
+            StockMovementKind::WriteOff => write!(f, "write_off"),
+            StockMovementKind::Sale => write!(f, "sale"),
+            StockMovementKind::Restock => write!(f, "restock"),
+        }
+    }
+}
+
+impl std::str::FromStr for StockMovementKind {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "receive" => Ok(StockMovementKind::Receive),
+            "correction" => Ok(StockMovementKind::Correction),
+            "write_off" => Ok(StockMovementKind::WriteOff),
+            "sale" => Ok(StockMovementKind::Sale),
+            "restock" => Ok(StockMovementKind::Restock),
+            _ => Err(DomainError::InvalidStock(format!(
+                "Invalid stock movement kind: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 在庫台帳の1行
+/// SKUの実在庫数の増減と、その理由・実行者を記録する
+#[derive(Debug, Clone)]
+pub struct StockMovement {
+    pub sku_id: SKUId,
+    pub kind: StockMovementKind,
+    /// 実在庫数の増減（出庫・廃棄は負数）
+    pub delta: i64,
+    pub reason: String,
+    pub reference_order_id: Option<OrderId>,
+    pub actor: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl StockMovement {
+    const MAX_REASON_LENGTH: usize = 500;
+
+    /// 注文処理や商品登録に伴って自動で記録する在庫変動を作成
+    pub fn new(
+        sku_id: SKUId,
+        kind: StockMovementKind,
+        delta: i64,
+        reason: &str,
+        reference_order_id: Option<OrderId>,
+        actor: &str,
+    ) -> Self {
+        Self {
+            sku_id,
+            kind,
+            delta,
+            reason: reason.to_string(),
+            reference_order_id,
+            actor: actor.to_string(),
+            occurred_at: Utc::now(),
+        }
+    }
+
+    /// 手動の在庫調整を作成
+    /// 入荷は増加、廃棄は減少、補正はどちらも可（0は不可）
+    pub fn adjustment(
+        sku_id: SKUId,
+        kind: StockMovementKind,
+        delta: i64,
+        reason: String,
+        reference_order_id: Option<OrderId>,
+        actor: String,
+    ) -> Result<Self, DomainError> {
+        if !kind.is_manual() {
+            return Err(DomainError::InvalidStock(format!(
+                "Stock movement '{}' cannot be registered manually",
+                kind
+            )));
+        }
+
+        let direction_is_valid = match kind {
+            StockMovementKind::Receive => delta > 0,
+            StockMovementKind::WriteOff => delta < 0,
+            _ => delta != 0,
+        };
+        if !direction_is_valid {
+            return Err(DomainError::InvalidStock(format!(
+                "Invalid quantity {} for stock movement '{}'",
+                delta, kind
+            )));
+        }
+        if delta.unsigned_abs() > u32::MAX as u64 {
+            return Err(DomainError::InvalidStock(format!(
+                "Quantity {} is too large",
+                delta
+            )));
+        }
+
+        let reason = reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(DomainError::InvalidStock(
+                "Adjustment reason is required".to_string(),
+            ));
+        }
+        if reason.chars().count() > Self::MAX_REASON_LENGTH {
+            return Err(DomainError::InvalidStock(format!(
+                "Adjustment reason cannot exceed {} characters",
+                Self::MAX_REASON_LENGTH
+            )));
+        }
+
+        let actor = actor.trim().to_string();
+        if actor.is_empty() {
+            return Err(DomainError::InvalidStock(
+                "Adjustment actor is required".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            sku_id,
+            kind,
+            delta,
+            reason,
+            reference_order_id,
+            actor,
+            occurred_at: Utc::now(),
+        })
+    }
+
+    /// SKUの在庫に適用する増減
+    pub fn stock_adjustment(&self) -> StockAdjustment {
+        if self.delta >= 0 {
+            StockAdjustment::Increase(self.delta as u32)
+        } else {
+            StockAdjustment::Decrease(self.delta.unsigned_abs() as u32)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adjustment(kind: StockMovementKind, delta: i64) -> Result<StockMovement, DomainError> {
+        StockMovement::adjustment(
+            SKUId::new(),
+            kind,
+            delta,
+            "棚卸".to_string(),
+            None,
+            "warehouse".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_receive_requires_positive_quantity() {
+        assert!(adjustment(StockMovementKind::Receive, 10).is_ok());
+        assert!(adjustment(StockMovementKind::Receive, 0).is_err());
+        assert!(adjustment(StockMovementKind::Receive, -1).is_err());
+    }
+
+    #[test]
+    fn test_write_off_requires_negative_quantity() {
+        let movement = adjustment(StockMovementKind::WriteOff, -2).unwrap();
+        assert!(matches!(
+            movement.stock_adjustment(),
+            StockAdjustment::Decrease(2)
+        ));
+        assert!(adjustment(StockMovementKind::WriteOff, 2).is_err());
+    }
+
+    #[test]
+    fn test_correction_accepts_both_directions() {
+        assert!(matches!(
+            adjustment(StockMovementKind::Correction, 3)
+                .unwrap()
+                .stock_adjustment(),
+            StockAdjustment::Increase(3)
+        ));
+        assert!(adjustment(StockMovementKind::Correction, -3).is_ok());
+        assert!(adjustment(StockMovementKind::Correction, 0).is_err());
+    }
+
+    #[test]
+    fn test_order_driven_kinds_cannot_be_registered_manually() {
+        assert!(adjustment(StockMovementKind::Sale, -1).is_err());
+        assert!(adjustment(StockMovementKind::Restock, 1).is_err());
+    }
+
+    #[test]
+    fn test_reason_and_actor_are_required() {
+        let result = StockMovement::adjustment(
+            SKUId::new(),
+            StockMovementKind::Receive,
+            1,
+            "  ".to_string(),
+            None,
+            "warehouse".to_string(),
+        );
+        assert!(result.is_err());
+
+        let result = StockMovement::adjustment(
+            SKUId::new(),
+            StockMovementKind::Receive,
+            1,
+            "入荷".to_string(),
+            None,
+            "".to_string(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_kind_round_trips_through_string() {
+        for kind in [
+            StockMovementKind::Receive,
+            StockMovementKind::Correction,
+            StockMovementKind::WriteOff,
+            StockMovementKind::Sale,
+            StockMovementKind::Restock,
+        ] {
+            assert_eq!(kind.to_string().parse::<StockMovementKind>().unwrap(), kind);
+        }
+        assert!("damage".parse::<StockMovementKind>().is_err());
+    }
+}
@@ -18,6 +18,8 @@ pub enum DomainError {
     InvalidProductState(String),
     /// 無効なクーポン
     InvalidCoupon { code: String, message: String },
+    /// 無効なステータス遷移エラー
+    InvalidStatusTransition { from: String, to: String },
 }
 
 impl std::fmt::Display for DomainError {
@@ -57,6 +59,9 @@ impl std::fmt::Display for DomainError {
             DomainError::InvalidCoupon { code, message } => {
                 write!(f, "Invalid coupon: {} - {}", code, message)
             }
+            DomainError::InvalidStatusTransition { from, to } => {
+                write!(f, "Invalid status transition: {} -> {}", from, to)
+            }
         }
     }
 }
@@ -1,7 +1,7 @@
 use crate::domain::entities::Coupon;
 use crate::domain::error::DomainError;
 use crate::domain::value_objects::{
-    DiscountCondition, DiscountType, Money, ProductId, PurchaseInfo,
+    CategoryId, DiscountCondition, DiscountType, Money, ProductId, PurchaseInfo,
 };
 
 /// クーポン割引計算サービス
@@ -10,10 +10,14 @@ pub struct CouponDiscountService;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DiscountResult {
-    /// 割引金額
+    /// 割引金額（商品小計からの割引）
     pub discount_amount: Money,
     /// 割引後の金額
     pub discounted_amount: Money,
+    /// 配送料の割引額（送料無料クーポンの場合は配送料全額）
+    pub shipping_discount: Money,
+    /// カートアイテムごとの割引額（購入情報のカートアイテムと同じ順、対象外の商品は0円）
+    pub line_discounts: Vec<Money>,
     /// 適用されたクーポンのメッセージ
     pub message: String,
 }
@@ -30,22 +34,41 @@ impl CouponDiscountService {
         // 適用条件をチェック
         Self::check_conditions(coupon, purchase_info)?;
 
-        // 割引金額を計算
-        let discount_amount = Self::calculate_discount(coupon, purchase_info)?;
+        // 割引金額を計算し、対象のカートアイテムに割り当てる
+        let line_discounts = coupon.discount_policy().line_discounts(purchase_info)?;
+        let discount_amount = line_discounts
+            .iter()
+            .try_fold(Money::zero(), |total, discount| total.add(*discount))?;
+        Self::check_buy_x_get_y(coupon, discount_amount)?;
 
         // 割引後の金額を計算
         let original_amount = purchase_info.subtotal();
         let discounted_amount = original_amount.subtract(discount_amount)?;
 
-        let message = format!(
-            "クーポン「{}」が適用されました。割引額: {}",
-            coupon.name(),
-            discount_amount.format_jpy()
-        );
+        let shipping_discount = if coupon.discount_policy().waives_shipping() {
+            purchase_info.shipping_fee()
+        } else {
+            Money::zero()
+        };
+
+        let message = if coupon.discount_policy().waives_shipping() {
+            format!(
+                "クーポン「{}」が適用されました。配送料が無料になります",
+                coupon.name()
+            )
+        } else {
+            format!(
+                "クーポン「{}」が適用されました。割引額: {}",
+                coupon.name(),
+                discount_amount.format_jpy()
+            )
+        };
 
         Ok(DiscountResult {
             discount_amount,
             discounted_amount,
+            shipping_discount,
+            line_discounts,
             message,
         })
     }
@@ -92,13 +115,13 @@ impl CouponDiscountService {
                         });
                     }
                 }
-                DiscountCondition::CategorySpecific(_category_ids) => {
-                    // カテゴリ条件は今回は実装をスキップ
-                    // 実装する場合は、商品情報からカテゴリを取得する必要がある
-                    return Err(DomainError::InvalidCoupon {
-                        code: coupon.code().value().to_string(),
-                        message: "カテゴリ指定のクーポンは現在サポートされていません".to_string(),
-                    });
+                DiscountCondition::CategorySpecific(category_ids) => {
+                    if !Self::has_target_categories(purchase_info, category_ids) {
+                        return Err(DomainError::InvalidCoupon {
+                            code: coupon.code().value().to_string(),
+                            message: "対象カテゴリの商品がカートに含まれていません".to_string(),
+                        });
+                    }
                 }
             }
         }
@@ -113,27 +136,34 @@ impl CouponDiscountService {
             .any(|product_id| purchase_info.contains_product(product_id))
     }
 
-    /// 割引金額を計算
-    fn calculate_discount(
-        coupon: &Coupon,
+    /// 購入情報に対象カテゴリの商品が含まれているかチェック
+    fn has_target_categories(
         purchase_info: &PurchaseInfo,
-    ) -> Result<Money, DomainError> {
-        let base_amount = purchase_info.subtotal();
-
-        match coupon.discount_policy().discount_type() {
-            DiscountType::FixedAmount(amount) => {
-                // 固定金額割引の場合、割引額が商品金額を超えないようにする
-                if *amount > base_amount {
-                    Ok(base_amount)
-                } else {
-                    Ok(*amount)
-                }
-            }
-            DiscountType::Percentage(percentage) => {
-                // パーセンテージ割引の場合
-                base_amount.subtract(base_amount.apply_discount(*percentage)?)
-            }
+        target_category_ids: &[CategoryId],
+    ) -> bool {
+        target_category_ids
+            .iter()
+            .any(|category_id| purchase_info.contains_category(category_id))
+    }
+
+    /// X点購入ごとにY点無料のクーポンが、無料にできる点数を満たしているかチェック
+    fn check_buy_x_get_y(coupon: &Coupon, discount_amount: Money) -> Result<(), DomainError> {
+        if let DiscountType::BuyXGetY {
+            buy_quantity,
+            get_quantity,
+        } = coupon.discount_policy().discount_type()
+            && discount_amount.yen() == 0
+        {
+            return Err(DomainError::InvalidCoupon {
+                code: coupon.code().value().to_string(),
+                message: format!(
+                    "商品を{}点以上購入するとクーポンを適用できます",
+                    buy_quantity + get_quantity
+                ),
+            });
         }
+
+        Ok(())
     }
 }
 
@@ -143,8 +173,8 @@ mod tests {
     use crate::domain::aggregates::CartItem;
     use crate::domain::entities::Coupon;
     use crate::domain::value_objects::{
-        CouponCode, CouponId, DiscountCondition, DiscountPolicy, DiscountType, ProductId,
-        ProductName, SKUId,
+        CategoryId, CouponCode, CouponId, DiscountCondition, DiscountPolicy, DiscountType,
+        ProductId, ProductName, SKUId,
     };
     use chrono::prelude::*;
     use uuid::Uuid;
@@ -330,4 +360,176 @@ mod tests {
             _ => panic!("Expected InvalidCoupon error"),
         }
     }
+
+    #[test]
+    fn capped_percentage_discount_is_limited_to_max() {
+        let coupon = create_valid_coupon(
+            "CAP20",
+            "20% Off (最大3000円)",
+            DiscountType::CappedPercentage {
+                percentage: 20,
+                max_discount: Money::from_yen(3000),
+            },
+            None,
+        );
+
+        let items = vec![create_test_cart_item("Product", 10000, 2)];
+        let purchase_info = PurchaseInfo::new(items, Money::from_yen(20000), None, None);
+
+        let result = CouponDiscountService::apply_coupon(&coupon, &purchase_info).unwrap();
+
+        assert_eq!(result.discount_amount.yen(), 3000); // 20% of 20000 = 4000 -> capped
+    }
+
+    #[test]
+    fn free_shipping_discounts_shipping_fee_only() {
+        let coupon = create_valid_coupon("FREESHIP", "送料無料", DiscountType::FreeShipping, None);
+
+        let items = vec![create_test_cart_item("Product", 1000, 2)];
+        let purchase_info = PurchaseInfo::new(
+            items,
+            Money::from_yen(2000),
+            Some(Money::from_yen(800)),
+            None,
+        );
+
+        let result = CouponDiscountService::apply_coupon(&coupon, &purchase_info).unwrap();
+
+        assert_eq!(result.discount_amount.yen(), 0);
+        assert_eq!(result.discounted_amount.yen(), 2000);
+        assert_eq!(result.shipping_discount.yen(), 800);
+    }
+
+    #[test]
+    fn buy_x_get_y_frees_cheapest_units() {
+        let coupon = create_valid_coupon(
+            "BUY2GET1",
+            "2点購入で1点無料",
+            DiscountType::BuyXGetY {
+                buy_quantity: 2,
+                get_quantity: 1,
+            },
+            None,
+        );
+
+        // 7点 → 2セット分の2点が無料（安い順に500円と1000円）
+        let items = vec![
+            create_test_cart_item("Cheap", 500, 1),
+            create_test_cart_item("Middle", 1000, 2),
+            create_test_cart_item("Expensive", 3000, 4),
+        ];
+        let purchase_info = PurchaseInfo::new(items, Money::from_yen(14500), None, None);
+
+        let result = CouponDiscountService::apply_coupon(&coupon, &purchase_info).unwrap();
+
+        assert_eq!(result.discount_amount.yen(), 1500);
+    }
+
+    #[test]
+    fn buy_x_get_y_requires_full_set() {
+        let coupon = create_valid_coupon(
+            "BUY2GET1",
+            "2点購入で1点無料",
+            DiscountType::BuyXGetY {
+                buy_quantity: 2,
+                get_quantity: 1,
+            },
+            None,
+        );
+
+        let items = vec![create_test_cart_item("Product", 1000, 2)];
+        let purchase_info = PurchaseInfo::new(items, Money::from_yen(2000), None, None);
+
+        let result = CouponDiscountService::apply_coupon(&coupon, &purchase_info);
+
+        assert!(matches!(result, Err(DomainError::InvalidCoupon { .. })));
+    }
+
+    #[test]
+    fn product_specific_discount_applies_only_to_target_lines() {
+        let target = create_test_cart_item("Target", 3000, 1);
+        let other = create_test_cart_item("Other", 5000, 1);
+        let coupon = create_valid_coupon(
+            "PRODUCT10",
+            "対象商品10%オフ",
+            DiscountType::Percentage(10),
+            Some(DiscountCondition::ProductSpecific(vec![
+                target.product_id().clone(),
+            ])),
+        );
+
+        let purchase_info =
+            PurchaseInfo::new(vec![other, target], Money::from_yen(8000), None, None);
+
+        let result = CouponDiscountService::apply_coupon(&coupon, &purchase_info).unwrap();
+
+        assert_eq!(result.discount_amount.yen(), 300); // 10% of 3000
+        assert_eq!(
+            result.line_discounts,
+            vec![Money::zero(), Money::from_yen(300)]
+        );
+    }
+
+    #[test]
+    fn category_specific_fixed_discount_is_allocated_by_subtotal() {
+        let desks = CategoryId::new("desks".to_string()).unwrap();
+        let desk = create_test_cart_item("Desk", 2000, 1).with_category_id(desks.clone());
+        let riser = create_test_cart_item("Riser", 1000, 1).with_category_id(desks.clone());
+        let lamp = create_test_cart_item("Lamp", 4000, 1)
+            .with_category_id(CategoryId::new("lighting".to_string()).unwrap());
+        let coupon = create_valid_coupon(
+            "DESK1000",
+            "デスク1000円引き",
+            DiscountType::FixedAmount(Money::from_yen(1000)),
+            Some(DiscountCondition::CategorySpecific(vec![desks])),
+        );
+
+        let purchase_info = PurchaseInfo::new(
+            vec![desk, lamp.clone(), riser],
+            Money::from_yen(7000),
+            None,
+            None,
+        );
+
+        let result = CouponDiscountService::apply_coupon(&coupon, &purchase_info).unwrap();
+
+        // 2000:1000で按分し、端数は最後の対象商品で調整する
+        assert_eq!(
+            result.line_discounts,
+            vec![Money::from_yen(666), Money::zero(), Money::from_yen(334)]
+        );
+        assert_eq!(result.discount_amount.yen(), 1000);
+
+        let lamp_only = PurchaseInfo::new(vec![lamp], Money::from_yen(4000), None, None);
+        assert!(matches!(
+            CouponDiscountService::apply_coupon(&coupon, &lamp_only),
+            Err(DomainError::InvalidCoupon { .. })
+        ));
+    }
+
+    #[test]
+    fn buy_x_get_y_discount_is_assigned_to_free_lines() {
+        let coupon = create_valid_coupon(
+            "BUY2GET1",
+            "2点購入で1点無料",
+            DiscountType::BuyXGetY {
+                buy_quantity: 2,
+                get_quantity: 1,
+            },
+            None,
+        );
+
+        let items = vec![
+            create_test_cart_item("Expensive", 3000, 2),
+            create_test_cart_item("Cheap", 500, 1),
+        ];
+        let purchase_info = PurchaseInfo::new(items, Money::from_yen(6500), None, None);
+
+        let result = CouponDiscountService::apply_coupon(&coupon, &purchase_info).unwrap();
+
+        assert_eq!(
+            result.line_discounts,
+            vec![Money::zero(), Money::from_yen(500)]
+        );
+    }
 }
@@ -1,3 +1,5 @@
 mod coupon_discount_service;
+mod promotion_service;
 
-pub use coupon_discount_service::{CouponDiscountService, DiscountResult};
+pub use coupon_discount_service::CouponDiscountService;
+pub use promotion_service::PromotionService;
@@ -0,0 +1,254 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::Promotion;
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{AppliedPromotion, Money, PurchaseInfo};
+
+/// プロモーション適用サービス
+/// 実施中のプロモーションを優先度順に評価し、カートアイテムごとの割引額を計算する
+pub struct PromotionService;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromotionResult {
+    /// 適用されたプロモーション（適用した順）
+    pub applied_promotions: Vec<AppliedPromotion>,
+    /// プロモーション割引の合計（商品小計からの割引）
+    pub discount_amount: Money,
+    /// 配送料の割引額（送料無料プロモーションの場合は配送料全額）
+    pub shipping_discount: Money,
+    /// カートアイテムごとの割引額（購入情報のカートアイテムと同じ順）
+    pub line_discounts: Vec<Money>,
+}
+
+impl PromotionService {
+    /// プロモーションを購入情報に適用する
+    /// - 優先度の高いものから評価し、同じ優先度の場合は渡された順に評価する
+    /// - 併用不可のプロモーションは単独でのみ適用する（先に適用したものがあれば適用せず、
+    ///   適用した場合は以降のプロモーションを評価しない）
+    /// - 併用する場合、各割引は割引前の金額で計算し、明細の残りの金額を超えないようにする
+    pub fn apply_promotions(
+        promotions: &[Promotion],
+        purchase_info: &PurchaseInfo,
+        now: DateTime<Utc>,
+    ) -> Result<PromotionResult, DomainError> {
+        let mut candidates = promotions
+            .iter()
+            .filter(|promotion| promotion.is_running_at(now))
+            .filter(|promotion| promotion.is_applicable_to(purchase_info))
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|promotion| std::cmp::Reverse(promotion.priority()));
+
+        let mut remaining = purchase_info
+            .cart_items()
+            .iter()
+            .map(|item| item.subtotal())
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut line_discounts = vec![Money::zero(); remaining.len()];
+        let mut applied_promotions: Vec<AppliedPromotion> = Vec::new();
+        let mut discount_amount = Money::zero();
+        let mut waives_shipping = false;
+
+        for promotion in candidates {
+            if !promotion.is_stackable() && !applied_promotions.is_empty() {
+                continue;
+            }
+
+            let policy = promotion.discount_policy();
+            let discounts = policy
+                .line_discounts(purchase_info)?
+                .into_iter()
+                .zip(&remaining)
+                .map(|(discount, remaining)| discount.min(*remaining))
+                .collect::<Vec<_>>();
+            let discount = discounts
+                .iter()
+                .try_fold(Money::zero(), |total, discount| total.add(*discount))?;
+
+            // 点数が足りないX点購入でY点無料など、割引が発生しないプロモーションは適用しない
+            if discount.yen() == 0 && !policy.waives_shipping() {
+                continue;
+            }
+
+            for (index, line_discount) in discounts.iter().enumerate() {
+                remaining[index] = remaining[index].subtract(*line_discount)?;
+                line_discounts[index] = line_discounts[index].add(*line_discount)?;
+            }
+            discount_amount = discount_amount.add(discount)?;
+            waives_shipping |= policy.waives_shipping();
+            applied_promotions.push(AppliedPromotion::new(
+                promotion.id().clone(),
+                promotion.name().to_string(),
+                discount,
+                policy.waives_shipping(),
+            ));
+
+            if !promotion.is_stackable() {
+                break;
+            }
+        }
+
+        let shipping_discount = if waives_shipping {
+            purchase_info.shipping_fee()
+        } else {
+            Money::zero()
+        };
+
+        Ok(PromotionResult {
+            applied_promotions,
+            discount_amount,
+            shipping_discount,
+            line_discounts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::CartItem;
+    use crate::domain::value_objects::{
+        CategoryId, DiscountCondition, DiscountPolicy, DiscountType, ProductId, ProductName,
+        PromotionId, SKUId,
+    };
+    use uuid::Uuid;
+
+    fn create_test_cart_item(category: &str, price: u32, quantity: u32) -> CartItem {
+        CartItem::new(
+            SKUId::from_uuid(Uuid::new_v4()),
+            ProductId::from_uuid(Uuid::new_v4()),
+            ProductName::new(category.to_string()).unwrap(),
+            Money::from_yen(price),
+            quantity,
+        )
+        .unwrap()
+        .with_category_id(CategoryId::new(category.to_string()).unwrap())
+    }
+
+    fn create_promotion(
+        name: &str,
+        discount_type: DiscountType,
+        category: Option<&str>,
+    ) -> Promotion {
+        let condition = category.map(|category| {
+            DiscountCondition::CategorySpecific(vec![
+                CategoryId::new(category.to_string()).unwrap(),
+            ])
+        });
+        Promotion::new(
+            PromotionId::from_uuid(Uuid::new_v4()),
+            name.to_string(),
+            DiscountPolicy::new(discount_type, condition),
+            Utc::now() - chrono::Duration::days(1),
+            Utc::now() + chrono::Duration::days(1),
+        )
+        .unwrap()
+    }
+
+    fn purchase_info(items: Vec<CartItem>) -> PurchaseInfo {
+        let subtotal = items
+            .iter()
+            .map(|item| item.subtotal().unwrap().yen())
+            .sum();
+        PurchaseInfo::new(
+            items,
+            Money::from_yen(subtotal),
+            Some(Money::from_yen(800)),
+            None,
+        )
+    }
+
+    #[test]
+    fn category_promotion_discounts_only_target_lines() {
+        let info = purchase_info(vec![
+            create_test_cart_item("sofas", 50000, 1),
+            create_test_cart_item("lamps", 5000, 2),
+        ]);
+        let promotions = vec![create_promotion(
+            "ソファ10%オフ",
+            DiscountType::Percentage(10),
+            Some("sofas"),
+        )];
+
+        let result = PromotionService::apply_promotions(&promotions, &info, Utc::now()).unwrap();
+
+        assert_eq!(result.discount_amount, Money::from_yen(5000));
+        assert_eq!(
+            result.line_discounts,
+            vec![Money::from_yen(5000), Money::zero()]
+        );
+        assert_eq!(result.applied_promotions.len(), 1);
+        assert_eq!(result.applied_promotions[0].name, "ソファ10%オフ");
+        assert_eq!(result.shipping_discount, Money::zero());
+    }
+
+    #[test]
+    fn stackable_promotions_are_applied_in_priority_order() {
+        let info = purchase_info(vec![create_test_cart_item("sofas", 10000, 1)]);
+        let promotions = vec![
+            create_promotion("全品5%オフ", DiscountType::Percentage(5), None),
+            create_promotion(
+                "ソファ9500円引き",
+                DiscountType::FixedAmount(Money::from_yen(9500)),
+                Some("sofas"),
+            )
+            .with_priority(10),
+        ];
+
+        let result = PromotionService::apply_promotions(&promotions, &info, Utc::now()).unwrap();
+
+        // 5%（500円）は残りの500円を超えない
+        assert_eq!(result.discount_amount, Money::from_yen(10000));
+        assert_eq!(result.applied_promotions[0].name, "ソファ9500円引き");
+        assert_eq!(result.applied_promotions[1].discount, Money::from_yen(500));
+    }
+
+    #[test]
+    fn exclusive_promotion_is_applied_alone() {
+        let info = purchase_info(vec![create_test_cart_item("sofas", 10000, 1)]);
+        let exclusive = create_promotion("単独20%オフ", DiscountType::Percentage(20), None)
+            .with_stackable(false);
+
+        // 優先度が高い併用不可のプロモーションを適用すると、以降は評価しない
+        let promotions = vec![
+            exclusive.clone().with_priority(10),
+            create_promotion("全品5%オフ", DiscountType::Percentage(5), None),
+        ];
+        let result = PromotionService::apply_promotions(&promotions, &info, Utc::now()).unwrap();
+        assert_eq!(result.applied_promotions.len(), 1);
+        assert_eq!(result.discount_amount, Money::from_yen(2000));
+
+        // 先に適用したプロモーションがあれば、併用不可のプロモーションは適用しない
+        let promotions = vec![
+            exclusive,
+            create_promotion("全品5%オフ", DiscountType::Percentage(5), None).with_priority(10),
+        ];
+        let result = PromotionService::apply_promotions(&promotions, &info, Utc::now()).unwrap();
+        assert_eq!(result.applied_promotions.len(), 1);
+        assert_eq!(result.discount_amount, Money::from_yen(500));
+    }
+
+    #[test]
+    fn promotions_outside_period_or_without_discount_are_skipped() {
+        let info = purchase_info(vec![create_test_cart_item("chairs", 10000, 2)]);
+        let promotions = vec![
+            create_promotion("終了したセール", DiscountType::Percentage(50), None)
+                .with_active(false),
+            create_promotion(
+                "2点購入で1点無料",
+                DiscountType::BuyXGetY {
+                    buy_quantity: 2,
+                    get_quantity: 1,
+                },
+                None,
+            ),
+            create_promotion("送料無料", DiscountType::FreeShipping, None),
+        ];
+
+        let result = PromotionService::apply_promotions(&promotions, &info, Utc::now()).unwrap();
+
+        assert_eq!(result.applied_promotions.len(), 1);
+        assert!(result.applied_promotions[0].waives_shipping);
+        assert_eq!(result.discount_amount, Money::zero());
+        assert_eq!(result.shipping_discount, Money::from_yen(800));
+    }
+}
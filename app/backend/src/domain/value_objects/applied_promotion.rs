@@ -0,0 +1,29 @@
+use crate::domain::value_objects::{Money, PromotionId};
+
+/// カート・注文に適用されたプロモーション
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedPromotion {
+    pub promotion_id: PromotionId,
+    /// 適用時点のプロモーション名（注文後に名称が変わっても表示を変えないため保存する）
+    pub name: String,
+    /// 割引金額（商品小計からの割引）
+    pub discount: Money,
+    /// 配送料を無料にしたか
+    pub waives_shipping: bool,
+}
+
+impl AppliedPromotion {
+    pub fn new(
+        promotion_id: PromotionId,
+        name: String,
+        discount: Money,
+        waives_shipping: bool,
+    ) -> Self {
+        Self {
+            promotion_id,
+            name,
+            discount,
+            waives_shipping,
+        }
+    }
+}
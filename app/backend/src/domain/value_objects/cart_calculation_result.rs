@@ -1,4 +1,4 @@
-use crate::domain::value_objects::Money;
+use crate::domain::value_objects::{AppliedPromotion, Money, TaxBreakdown, TaxCalculation};
 
 /// カート計算結果
 /// 一括計算による効率的なデータ提供
@@ -6,19 +6,25 @@ use crate::domain::value_objects::Money;
 pub struct CartCalculationResult {
     /// 原価小計（割引適用前）
     pub original_subtotal: Money,
-    /// 割引額
+    /// 割引額（プロモーション割引とクーポン割引の合計）
     pub discount_amount: Money,
+    /// 適用されたプロモーション
+    pub applied_promotions: Vec<AppliedPromotion>,
+    /// カートアイテムごとの割引額（カートアイテムと同じ順）
+    pub item_discounts: Vec<Money>,
     /// 最終小計（割引適用後）
     pub final_subtotal: Money,
-    /// 税額
+    /// 税額（商品・配送料・支払い手数料の合計）
     pub tax_amount: Money,
-    /// 税込み合計
-    pub total_with_tax: Money,
+    /// 税率ごとの内訳
+    pub tax_breakdown: Vec<TaxBreakdown>,
+    /// 価格が税込か
+    pub tax_included: bool,
     /// 配送料
     pub shipping_fee: Money,
     /// 支払い手数料
     pub payment_fee: Money,
-    /// 最終合計（税込み + 配送料 + 支払い手数料）
+    /// 最終合計（割引後小計 + 配送料 + 支払い手数料 + 税額（税抜価格の場合））
     pub grand_total: Money,
 }
 
@@ -27,21 +33,51 @@ impl CartCalculationResult {
         original_subtotal: Money,
         discount_amount: Money,
         final_subtotal: Money,
-        tax_amount: Money,
-        total_with_tax: Money,
         shipping_fee: Money,
         payment_fee: Money,
-        grand_total: Money,
+        tax: TaxCalculation,
     ) -> Self {
         Self {
             original_subtotal,
             discount_amount,
+            item_discounts: Vec::new(),
+            applied_promotions: Vec::new(),
             final_subtotal,
-            tax_amount,
-            total_with_tax,
+            tax_amount: tax.tax_amount,
+            tax_breakdown: tax.breakdown,
+            tax_included: tax.tax_included,
             shipping_fee,
             payment_fee,
-            grand_total,
+            grand_total: tax.total,
         }
     }
-}
\ No newline at end of file
+
+    /// カートアイテムごとの割引額を設定
+    pub fn with_item_discounts(mut self, item_discounts: Vec<Money>) -> Self {
+        self.item_discounts = item_discounts;
+        self
+    }
+
+    /// 適用されたプロモーションを設定
+    pub fn with_applied_promotions(mut self, applied_promotions: Vec<AppliedPromotion>) -> Self {
+        self.applied_promotions = applied_promotions;
+        self
+    }
+
+    /// プロモーション割引の合計
+    pub fn promotion_discount(&self) -> Money {
+        Money::from_yen(
+            self.applied_promotions
+                .iter()
+                .map(|promotion| promotion.discount.yen())
+                .sum(),
+        )
+    }
+
+    /// クーポン割引額（割引額のうちプロモーション割引以外）
+    pub fn coupon_discount(&self) -> Money {
+        self.discount_amount
+            .subtract(self.promotion_discount())
+            .unwrap_or_else(|_| Money::zero())
+    }
+}
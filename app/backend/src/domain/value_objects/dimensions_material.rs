@@ -23,6 +23,23 @@ impl Dimensions {
     pub fn value(&self) -> &str {
         &self.0
     }
+
+    /// 3辺合計（`24" x 10" x 6"`のような「幅 x 奥行 x 高さ」表記を想定し、単位は表記のまま）
+    /// 3辺の数値を読み取れない場合はNone
+    pub fn size_sum(&self) -> Option<f64> {
+        let sides = self
+            .0
+            .split(['x', 'X', '×'])
+            .map(|side| {
+                side.trim()
+                    .trim_end_matches(|c: char| !c.is_ascii_digit())
+                    .parse::<f64>()
+                    .ok()
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        (sides.len() == 3).then(|| sides.iter().sum())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -65,3 +82,20 @@ impl Material {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dimensions_size_sum() {
+        let dimensions = Dimensions::new("72\" x 36\" x 30\"".to_string()).unwrap();
+        assert_eq!(dimensions.size_sum(), Some(138.0));
+
+        let dimensions = Dimensions::new("4\" x 4\" x 0.5\"".to_string()).unwrap();
+        assert_eq!(dimensions.size_sum(), Some(8.5));
+
+        let dimensions = Dimensions::new("Large".to_string()).unwrap();
+        assert_eq!(dimensions.size_sum(), None);
+    }
+}
@@ -1,5 +1,7 @@
+use crate::domain::aggregates::CartItem;
+use crate::domain::error::DomainError;
 use crate::domain::value_objects::{
-    discount_condition::DiscountCondition, discount_type::DiscountType,
+    Money, PurchaseInfo, discount_condition::DiscountCondition, discount_type::DiscountType,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -16,15 +18,160 @@ impl DiscountPolicy {
         }
     }
 
-    pub fn unconditional(discount_type: DiscountType) -> Self {
-        Self {
-            discount_type,
-            discount_condition: None,
+    /// 配送料を免除するポリシーか
+    pub fn waives_shipping(&self) -> bool {
+        self.discount_type.waives_shipping()
+    }
+
+    /// カートアイテムが割引の対象か
+    /// 対象商品・対象カテゴリの条件がある場合は一致する商品のみ、それ以外はすべての商品が対象
+    pub fn applies_to(&self, item: &CartItem) -> bool {
+        match &self.discount_condition {
+            Some(DiscountCondition::ProductSpecific(product_ids)) => {
+                product_ids.contains(item.product_id())
+            }
+            Some(DiscountCondition::CategorySpecific(category_ids)) => item
+                .category_id()
+                .is_some_and(|category_id| category_ids.contains(category_id)),
+            _ => true,
+        }
+    }
+
+    /// 適用条件（最低購入金額・対象商品・対象カテゴリ）を購入情報が満たしているか
+    pub fn is_satisfied_by(&self, purchase_info: &PurchaseInfo) -> bool {
+        match &self.discount_condition {
+            Some(DiscountCondition::MinimumPurchase(minimum_amount)) => {
+                purchase_info.meets_minimum_amount(*minimum_amount)
+            }
+            Some(DiscountCondition::ProductSpecific(product_ids)) => product_ids
+                .iter()
+                .any(|product_id| purchase_info.contains_product(product_id)),
+            Some(DiscountCondition::CategorySpecific(category_ids)) => category_ids
+                .iter()
+                .any(|category_id| purchase_info.contains_category(category_id)),
+            None => true,
+        }
+    }
+
+    /// 割引金額を計算し、カートアイテムごとの割引額を返す（購入情報のカートアイテムと同じ順）
+    /// 対象商品・対象カテゴリの条件がある場合は、対象の商品の小計のみを割り引く
+    pub fn line_discounts(&self, purchase_info: &PurchaseInfo) -> Result<Vec<Money>, DomainError> {
+        let eligible_subtotals = purchase_info
+            .cart_items()
+            .iter()
+            .map(|item| {
+                if self.applies_to(item) {
+                    item.subtotal()
+                } else {
+                    Ok(Money::zero())
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let base_amount = eligible_subtotals
+            .iter()
+            .try_fold(Money::zero(), |total, subtotal| total.add(*subtotal))?;
+
+        let discount = match &self.discount_type {
+            // 固定金額割引の場合、割引額が商品金額を超えないようにする
+            DiscountType::FixedAmount(amount) => (*amount).min(base_amount),
+            DiscountType::Percentage(percentage) => {
+                base_amount.subtract(base_amount.apply_discount(*percentage)?)?
+            }
+            DiscountType::CappedPercentage {
+                percentage,
+                max_discount,
+            } => {
+                // 上限付きパーセンテージ割引の場合、上限金額を超えないようにする
+                let discount = base_amount.subtract(base_amount.apply_discount(*percentage)?)?;
+                discount.min(*max_discount)
+            }
+            // 送料無料は商品小計を割り引かない（配送料の割引は呼び出し側で扱う）
+            DiscountType::FreeShipping => Money::zero(),
+            DiscountType::BuyXGetY {
+                buy_quantity,
+                get_quantity,
+            } => {
+                return self.buy_x_get_y_discounts(purchase_info, *buy_quantity, *get_quantity);
+            }
+        };
+
+        Ok(Self::allocate_discount(discount, &eligible_subtotals))
+    }
+
+    /// 割引額を対象商品の小計の比率で按分する（端数は最後の対象商品で調整する）
+    fn allocate_discount(discount: Money, eligible_subtotals: &[Money]) -> Vec<Money> {
+        let total: u64 = eligible_subtotals
+            .iter()
+            .map(|subtotal| subtotal.yen() as u64)
+            .sum();
+        let last_eligible = eligible_subtotals
+            .iter()
+            .rposition(|subtotal| subtotal.yen() > 0);
+        let discount = discount.yen() as u64;
+        let mut remaining = discount;
+
+        eligible_subtotals
+            .iter()
+            .enumerate()
+            .map(|(index, subtotal)| {
+                let share = if Some(index) == last_eligible {
+                    remaining
+                } else {
+                    (discount * subtotal.yen() as u64)
+                        .checked_div(total)
+                        .unwrap_or(0)
+                };
+                remaining -= share;
+                Money::from_yen(share as u32)
+            })
+            .collect()
+    }
+
+    /// X点購入ごとにY点無料の割引金額を計算し、無料にした商品のカートアイテムに割り当てる
+    /// (X+Y)点ごとにY点を無料にし、無料にする商品は対象商品のうち単価の安いものから選ぶ
+    /// 点数が足りない場合はすべて0円になる
+    fn buy_x_get_y_discounts(
+        &self,
+        purchase_info: &PurchaseInfo,
+        buy_quantity: u32,
+        get_quantity: u32,
+    ) -> Result<Vec<Money>, DomainError> {
+        let items = purchase_info.cart_items();
+
+        // (単価, カートアイテムの位置) を1点ずつ展開する
+        let mut units: Vec<(Money, usize)> = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| self.applies_to(item))
+            .flat_map(|(index, item)| {
+                std::iter::repeat_n((item.unit_price(), index), item.quantity() as usize)
+            })
+            .collect();
+
+        let set_size = buy_quantity + get_quantity;
+        let free_count = (units.len() as u32 / set_size) * get_quantity;
+
+        units.sort();
+        let mut line_discounts = vec![Money::zero(); items.len()];
+        for (price, index) in units.iter().take(free_count as usize) {
+            line_discounts[*index] = line_discounts[*index].add(*price)?;
         }
+        Ok(line_discounts)
     }
 
-    pub fn has_condition(&self) -> bool {
-        self.discount_condition.is_some()
+    /// 割引内容と適用条件の妥当性を検証
+    pub fn validate(&self) -> Result<(), DomainError> {
+        self.discount_type.validate()?;
+
+        match &self.discount_condition {
+            Some(DiscountCondition::ProductSpecific(ids)) if ids.is_empty() => Err(
+                DomainError::InvalidProductData("Target products must not be empty".to_string()),
+            ),
+            Some(DiscountCondition::CategorySpecific(ids)) if ids.is_empty() => Err(
+                DomainError::InvalidProductData("Target categories must not be empty".to_string()),
+            ),
+            _ => Ok(()),
+        }
     }
 
     // Getters
@@ -1,7 +1,101 @@
+use crate::domain::error::DomainError;
 use crate::domain::value_objects::Money;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum DiscountType {
     FixedAmount(Money),
     Percentage(u8),
+    /// 上限金額付きのパーセンテージ割引
+    CappedPercentage {
+        percentage: u8,
+        max_discount: Money,
+    },
+    /// 配送料無料
+    FreeShipping,
+    /// X点購入ごとにY点無料（安い商品から無料にする）
+    BuyXGetY {
+        buy_quantity: u32,
+        get_quantity: u32,
+    },
+}
+
+impl DiscountType {
+    /// 永続化・API用の種別コード
+    pub fn code(&self) -> &'static str {
+        match self {
+            DiscountType::FixedAmount(_) => "fixed_amount",
+            DiscountType::Percentage(_) => "percentage",
+            DiscountType::CappedPercentage { .. } => "capped_percentage",
+            DiscountType::FreeShipping => "free_shipping",
+            DiscountType::BuyXGetY { .. } => "buy_x_get_y",
+        }
+    }
+
+    /// 配送料を免除する割引か
+    pub fn waives_shipping(&self) -> bool {
+        matches!(self, DiscountType::FreeShipping)
+    }
+
+    /// 割引内容の妥当性を検証
+    pub fn validate(&self) -> Result<(), DomainError> {
+        match self {
+            DiscountType::FixedAmount(amount) if amount.yen() == 0 => Err(
+                DomainError::InvalidPrice("Fixed discount amount must be positive".to_string()),
+            ),
+            DiscountType::Percentage(percentage)
+            | DiscountType::CappedPercentage { percentage, .. }
+                if *percentage == 0 || *percentage > 100 =>
+            {
+                Err(DomainError::InvalidPrice(
+                    "Discount percentage must be between 1 and 100".to_string(),
+                ))
+            }
+            DiscountType::CappedPercentage { max_discount, .. } if max_discount.yen() == 0 => Err(
+                DomainError::InvalidPrice("Maximum discount amount must be positive".to_string()),
+            ),
+            DiscountType::BuyXGetY {
+                buy_quantity,
+                get_quantity,
+            } if *buy_quantity == 0 || *get_quantity == 0 => Err(DomainError::InvalidProductData(
+                "Buy and get quantities must be positive".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_discount_types() {
+        assert!(DiscountType::Percentage(10).validate().is_ok());
+        assert!(DiscountType::Percentage(0).validate().is_err());
+        assert!(DiscountType::Percentage(101).validate().is_err());
+        assert!(DiscountType::FixedAmount(Money::zero()).validate().is_err());
+        assert!(
+            DiscountType::CappedPercentage {
+                percentage: 20,
+                max_discount: Money::zero(),
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            DiscountType::BuyXGetY {
+                buy_quantity: 2,
+                get_quantity: 0,
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(DiscountType::FreeShipping.validate().is_ok());
+    }
+
+    #[test]
+    fn only_free_shipping_waives_shipping() {
+        assert!(DiscountType::FreeShipping.waives_shipping());
+        assert!(!DiscountType::Percentage(10).waives_shipping());
+    }
 }
@@ -0,0 +1,118 @@
+use crate::domain::error::DomainError;
+
+/// 適格請求書発行事業者の登録番号（「T」＋13桁の数字）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvoiceRegistrationNumber {
+    value: String,
+}
+
+impl InvoiceRegistrationNumber {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        let value = value.trim();
+        let digits = value.strip_prefix('T').unwrap_or_default();
+
+        if digits.len() != 13 || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(DomainError::InvalidProductData(format!(
+                "Invalid invoice registration number: {} (expected T followed by 13 digits)",
+                value
+            )));
+        }
+
+        Ok(Self {
+            value: value.to_string(),
+        })
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl std::fmt::Display for InvoiceRegistrationNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// 請求書・領収書の発行元（販売事業者）
+/// 登録番号がある場合、請求書は適格請求書として発行する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentIssuer {
+    name: String,
+    registration_number: Option<InvoiceRegistrationNumber>,
+    address: Option<String>,
+    phone: Option<String>,
+}
+
+impl DocumentIssuer {
+    /// 発行元名のデフォルト値
+    pub const DEFAULT_NAME: &'static str = "ECサイト";
+
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            registration_number: None,
+            address: None,
+            phone: None,
+        }
+    }
+
+    pub fn with_registration_number(
+        mut self,
+        registration_number: Option<InvoiceRegistrationNumber>,
+    ) -> Self {
+        self.registration_number = registration_number;
+        self
+    }
+
+    pub fn with_address(mut self, address: Option<String>) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_phone(mut self, phone: Option<String>) -> Self {
+        self.phone = phone;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn registration_number(&self) -> Option<&InvoiceRegistrationNumber> {
+        self.registration_number.as_ref()
+    }
+
+    pub fn address(&self) -> Option<&str> {
+        self.address.as_deref()
+    }
+
+    pub fn phone(&self) -> Option<&str> {
+        self.phone.as_deref()
+    }
+}
+
+impl Default for DocumentIssuer {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_NAME.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registration_number_accepts_t_and_13_digits() {
+        let number = InvoiceRegistrationNumber::new(" T1234567890123 ").unwrap();
+        assert_eq!(number.value(), "T1234567890123");
+    }
+
+    #[test]
+    fn test_registration_number_rejects_invalid_format() {
+        assert!(InvoiceRegistrationNumber::new("1234567890123").is_err());
+        assert!(InvoiceRegistrationNumber::new("T123456789012").is_err());
+        assert!(InvoiceRegistrationNumber::new("T12345678901234").is_err());
+        assert!(InvoiceRegistrationNumber::new("T12345678901AB").is_err());
+    }
+}
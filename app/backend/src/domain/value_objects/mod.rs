@@ -1,4 +1,5 @@
 mod address;
+mod applied_promotion;
 mod cart_calculation_result;
 mod coupon_code;
 mod descriptions;
@@ -6,6 +7,7 @@ mod dimensions_material;
 mod discount_condition;
 mod discount_policy;
 mod discount_type;
+mod document_issuer;
 mod email;
 mod identifiers;
 mod money;
@@ -13,10 +15,13 @@ mod names;
 mod order_number;
 mod personal_info;
 mod phone_number;
+mod price_schedule;
 mod purchase_info;
+mod tax;
 mod variant_attributes;
 
 pub use self::address::Address;
+pub use self::applied_promotion::AppliedPromotion;
 pub use self::cart_calculation_result::CartCalculationResult;
 pub use self::coupon_code::CouponCode;
 pub use self::descriptions::Description;
@@ -24,15 +29,21 @@ pub use self::dimensions_material::{Dimensions, Material};
 pub use self::discount_condition::DiscountCondition;
 pub use self::discount_policy::DiscountPolicy;
 pub use self::discount_type::DiscountType;
+pub use self::document_issuer::{DocumentIssuer, InvoiceRegistrationNumber};
 pub use self::email::Email;
 pub use self::identifiers::{
-    CategoryId, ColorId, CouponId, CustomerId, DeliveryInfoId, OrderId, PaymentMethodId, ProductId,
-    SKUId, ShippingMethodId,
+    CartId, CategoryId, ColorId, CouponId, CustomerId, DeliveryInfoId, OrderId, PaymentMethodId,
+    ProductId, PromotionId, ReturnId, SKUId, SessionId, ShippingMethodId,
 };
 pub use self::money::Money;
 pub use self::names::{ProductName, SKUCode, SKUName};
-pub use self::order_number::OrderNumber;
+pub use self::order_number::{OrderNumber, OrderNumberFormat, OrderNumberReset};
 pub use self::personal_info::{FirstName, LastName, PersonalInfo};
 pub use self::phone_number::PhoneNumber;
+pub use self::price_schedule::PriceSchedule;
 pub use self::purchase_info::PurchaseInfo;
+pub use self::tax::{
+    TaxBreakdown, TaxCalculation, TaxCategory, TaxPolicy, TaxPriceMode, TaxRounding,
+    TaxRoundingUnit, TaxableLine,
+};
 pub use self::variant_attributes::VariantAttributes;
@@ -108,18 +108,6 @@ impl Money {
         self.subtract(discount_amount)
     }
 
-    /// 税込価格を計算（消費税10%、切り上げ）
-    pub fn with_tax(&self) -> Money {
-        let tax_amount = (self.amount_in_yen as f64 * 0.10).ceil() as u32;
-        Money::from_yen(self.amount_in_yen + tax_amount)
-    }
-
-    /// 消費税額を計算（10%、切り上げ）
-    pub fn tax_amount(&self) -> Money {
-        let tax = (self.amount_in_yen as f64 * 0.10).ceil() as u32;
-        Money::from_yen(tax)
-    }
-
     /// 日本円フォーマット
     pub fn format_jpy(&self) -> String {
         format!("¥{}", self.amount_in_yen)
@@ -213,21 +201,6 @@ mod tests {
         assert_eq!(discounted.yen(), 800); // 1000 - 200 = 800
     }
 
-    #[test]
-    fn tax_calculations() {
-        let money = Money::from_yen(1000);
-        let with_tax = money.with_tax();
-        assert_eq!(with_tax.yen(), 1100); // 1000 + 100 = 1100
-
-        let tax_amount = money.tax_amount();
-        assert_eq!(tax_amount.yen(), 100);
-
-        // 切り上げテスト
-        let money2 = Money::from_yen(999);
-        let tax_amount2 = money2.tax_amount();
-        assert_eq!(tax_amount2.yen(), 100); // 999 * 0.1 = 99.9 -> ceil = 100
-    }
-
     #[test]
     fn formatting() {
         let money = Money::from_yen(12345);
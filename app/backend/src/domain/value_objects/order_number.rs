@@ -1,4 +1,5 @@
 use crate::domain::error::DomainError;
+use chrono::{DateTime, Datelike, Utc};
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -11,6 +12,8 @@ impl OrderNumber {
         Self(format!("ORD-{}-{:06}", year, sequence))
     }
 
+    /// 注文番号として使える文字列か検証する
+    /// 過去に別の書式で採番した注文番号も扱えるよう、書式までは検証しない
     pub fn from_string(value: String) -> Result<Self, DomainError> {
         if value.is_empty() {
             return Err(DomainError::InvalidProductData(
@@ -25,21 +28,32 @@ impl OrderNumber {
             )));
         }
 
-        // Basic format validation for generated order numbers
-        if value.starts_with("ORD-") && value.len() == 15 {
-            // Validate format: ORD-YYYY-NNNNNN
-            let parts: Vec<&str> = value.split('-').collect();
-            if parts.len() == 3 {
-                if let (Ok(_year), Ok(_seq)) = (parts[1].parse::<i32>(), parts[2].parse::<u32>()) {
-                    return Ok(Self(value));
-                }
-            }
+        if !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(DomainError::InvalidProductData(format!(
+                "Order number contains invalid characters: {}",
+                value
+            )));
         }
 
-        // Allow custom order numbers that don't follow the standard format
         Ok(Self(value))
     }
 
+    /// 指定した書式（プレフィックス・期間・桁数・チェックディジット）に一致するか検証する
+    pub fn from_string_with_format(
+        value: String,
+        format: &OrderNumberFormat,
+    ) -> Result<Self, DomainError> {
+        let order_number = Self::from_string(value)?;
+        if !format.matches(order_number.value()) {
+            return Err(DomainError::InvalidProductData(format!(
+                "Order number does not match the configured format: {}",
+                order_number.value()
+            )));
+        }
+
+        Ok(order_number)
+    }
+
     pub fn value(&self) -> &str {
         &self.0
     }
@@ -51,9 +65,220 @@ impl fmt::Display for OrderNumber {
     }
 }
 
+/// 連番をリセットする期間
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OrderNumberReset {
+    /// リセットしない（ORD-000001）
+    Never,
+    /// 年ごとにリセットする（ORD-2024-000001）
+    #[default]
+    Yearly,
+    /// 月ごとにリセットする（ORD-202401-000001）
+    Monthly,
+}
+
+impl std::str::FromStr for OrderNumberReset {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "never" => Ok(OrderNumberReset::Never),
+            "yearly" => Ok(OrderNumberReset::Yearly),
+            "monthly" => Ok(OrderNumberReset::Monthly),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid order number reset: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 注文番号の書式
+/// `{プレフィックス}-{期間}-{連番}{チェックディジット}` の形式で採番する
+/// 連番は期間（スコープ）ごとに1から始まる
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderNumberFormat {
+    prefix: String,
+    reset: OrderNumberReset,
+    width: usize,
+    check_digit: bool,
+}
+
+impl Default for OrderNumberFormat {
+    /// 従来の書式（ORD-YYYY-NNNNNN）
+    fn default() -> Self {
+        Self {
+            prefix: Self::DEFAULT_PREFIX.to_string(),
+            reset: OrderNumberReset::Yearly,
+            width: Self::DEFAULT_WIDTH,
+            check_digit: false,
+        }
+    }
+}
+
+impl OrderNumberFormat {
+    pub const DEFAULT_PREFIX: &'static str = "ORD";
+    pub const DEFAULT_WIDTH: usize = 6;
+    const MAX_PREFIX_LENGTH: usize = 8;
+    /// u32の連番を表せる桁数
+    const MAX_WIDTH: usize = 9;
+
+    pub fn new(
+        prefix: String,
+        reset: OrderNumberReset,
+        width: usize,
+        check_digit: bool,
+    ) -> Result<Self, DomainError> {
+        if prefix.is_empty()
+            || prefix.len() > Self::MAX_PREFIX_LENGTH
+            || !prefix
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        {
+            return Err(DomainError::InvalidProductData(format!(
+                "Order number prefix must be 1 to {} uppercase letters or digits: {}",
+                Self::MAX_PREFIX_LENGTH,
+                prefix
+            )));
+        }
+
+        if width == 0 || width > Self::MAX_WIDTH {
+            return Err(DomainError::InvalidProductData(format!(
+                "Order number width must be between 1 and {}",
+                Self::MAX_WIDTH
+            )));
+        }
+
+        let format = Self {
+            prefix,
+            reset,
+            width,
+            check_digit,
+        };
+        if format.max_length() > OrderNumber::MAX_LENGTH {
+            return Err(DomainError::InvalidProductData(format!(
+                "Order number format cannot exceed {} characters",
+                OrderNumber::MAX_LENGTH
+            )));
+        }
+
+        Ok(format)
+    }
+
+    /// 連番を共有する範囲（注文番号の連番より前の部分。例: ORD-2024）
+    pub fn sequence_scope(&self, at: DateTime<Utc>) -> String {
+        match self.reset {
+            OrderNumberReset::Never => self.prefix.clone(),
+            OrderNumberReset::Yearly => format!("{}-{:04}", self.prefix, at.year()),
+            OrderNumberReset::Monthly => {
+                format!("{}-{:04}{:02}", self.prefix, at.year(), at.month())
+            }
+        }
+    }
+
+    /// スコープ内の連番から注文番号を生成
+    pub fn generate(&self, at: DateTime<Utc>, sequence: u32) -> Result<OrderNumber, DomainError> {
+        let digits = format!("{:0width$}", sequence, width = self.width);
+        if sequence == 0 || digits.len() > self.width {
+            return Err(DomainError::InvalidProductData(format!(
+                "Order number sequence {} does not fit in {} digits",
+                sequence, self.width
+            )));
+        }
+
+        let mut value = format!("{}-{}", self.sequence_scope(at), digits);
+        if self.check_digit {
+            value.push(Self::luhn_check_digit(&value));
+        }
+
+        OrderNumber::from_string(value)
+    }
+
+    /// 注文番号がこの書式に一致するか（チェックディジットの検証を含む）
+    pub fn matches(&self, value: &str) -> bool {
+        let Some(rest) = value
+            .strip_prefix(self.prefix.as_str())
+            .and_then(|rest| rest.strip_prefix('-'))
+        else {
+            return false;
+        };
+
+        let sequence = match self.reset {
+            OrderNumberReset::Never => rest,
+            OrderNumberReset::Yearly | OrderNumberReset::Monthly => {
+                let Some((period, sequence)) = rest.split_once('-') else {
+                    return false;
+                };
+                if !Self::is_valid_period(self.reset, period) {
+                    return false;
+                }
+                sequence
+            }
+        };
+
+        let expected_length = self.width + usize::from(self.check_digit);
+        if sequence.len() != expected_length
+            || !sequence.bytes().all(|b| b.is_ascii_digit())
+            || sequence[..self.width].bytes().all(|b| b == b'0')
+        {
+            return false;
+        }
+
+        !self.check_digit || value.ends_with(Self::luhn_check_digit(&value[..value.len() - 1]))
+    }
+
+    fn is_valid_period(reset: OrderNumberReset, period: &str) -> bool {
+        let length = match reset {
+            OrderNumberReset::Never => return period.is_empty(),
+            OrderNumberReset::Yearly => 4,
+            OrderNumberReset::Monthly => 6,
+        };
+        if period.len() != length || !period.bytes().all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+
+        reset != OrderNumberReset::Monthly || matches!(period[4..].parse::<u32>(), Ok(1..=12))
+    }
+
+    /// 数字部分のLuhnチェックディジット（入力ミスの検出用）
+    fn luhn_check_digit(value: &str) -> char {
+        let sum: u32 = value
+            .chars()
+            .rev()
+            .filter_map(|c| c.to_digit(10))
+            .enumerate()
+            .map(|(i, digit)| {
+                if i % 2 == 0 {
+                    let doubled = digit * 2;
+                    if doubled > 9 { doubled - 9 } else { doubled }
+                } else {
+                    digit
+                }
+            })
+            .sum();
+
+        char::from_digit((10 - sum % 10) % 10, 10).unwrap_or('0')
+    }
+
+    /// 生成される注文番号の最大長
+    fn max_length(&self) -> usize {
+        let period = match self.reset {
+            OrderNumberReset::Never => 0,
+            OrderNumberReset::Yearly => 5,
+            OrderNumberReset::Monthly => 7,
+        };
+        self.prefix.len() + period + 1 + self.width + usize::from(self.check_digit)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
+
+    fn at(year: i32, month: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, 15, 0, 0, 0).unwrap()
+    }
 
     #[test]
     fn test_generate_order_number() {
@@ -85,4 +310,100 @@ mod tests {
         let result = OrderNumber::from_string(long_string);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_from_string_invalid_characters() {
+        assert!(OrderNumber::from_string("ORD 2024/01".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_default_format_matches_legacy_numbers() {
+        let format = OrderNumberFormat::default();
+
+        let order_number = format.generate(at(2024, 3), 123).unwrap();
+        assert_eq!(order_number, OrderNumber::generate(2024, 123));
+        assert_eq!(format.sequence_scope(at(2024, 3)), "ORD-2024");
+        assert!(format.generate(at(2024, 3), 0).is_err());
+        assert!(format.matches("ORD-2024-000123"));
+        assert!(!format.matches("ORD-2024-00123"));
+        assert!(!format.matches("ORD-2024-000000"));
+        assert!(!format.matches("CUSTOM-001"));
+    }
+
+    #[test]
+    fn test_monthly_format_with_check_digit() {
+        let format =
+            OrderNumberFormat::new("EC".to_string(), OrderNumberReset::Monthly, 5, true).unwrap();
+
+        let order_number = format.generate(at(2026, 10), 42).unwrap();
+        assert_eq!(format.sequence_scope(at(2026, 10)), "EC-202610");
+        // 数字部分 20261000042 のLuhnチェックディジットは6
+        assert_eq!(order_number.value(), "EC-202610-000426");
+        assert!(
+            OrderNumber::from_string_with_format(order_number.value().to_string(), &format).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_from_string_with_format_rejects_wrong_prefix() {
+        let format =
+            OrderNumberFormat::new("EC".to_string(), OrderNumberReset::Monthly, 5, true).unwrap();
+
+        let result = OrderNumber::from_string_with_format("ORD-202610-000426".to_string(), &format);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_string_with_format_rejects_wrong_period() {
+        let format =
+            OrderNumberFormat::new("EC".to_string(), OrderNumberReset::Monthly, 5, true).unwrap();
+
+        // 13月は存在しない
+        assert!(
+            OrderNumber::from_string_with_format("EC-202613-000421".to_string(), &format).is_err()
+        );
+        // 月次の書式に年だけの期間は一致しない
+        assert!(
+            OrderNumber::from_string_with_format("EC-2026-000426".to_string(), &format).is_err()
+        );
+    }
+
+    #[test]
+    fn test_from_string_with_format_rejects_bad_check_digit() {
+        let format =
+            OrderNumberFormat::new("EC".to_string(), OrderNumberReset::Monthly, 5, true).unwrap();
+
+        // 正しいチェックディジットは6
+        assert!(
+            OrderNumber::from_string_with_format("EC-202610-000427".to_string(), &format).is_err()
+        );
+    }
+
+    #[test]
+    fn test_never_reset_format() {
+        let format =
+            OrderNumberFormat::new("ORD".to_string(), OrderNumberReset::Never, 8, false).unwrap();
+
+        assert_eq!(
+            format.generate(at(2024, 1), 7).unwrap().value(),
+            "ORD-00000007"
+        );
+        assert_eq!(format.sequence_scope(at(2030, 1)), "ORD");
+        assert!(format.generate(at(2024, 1), 100_000_000).is_err());
+    }
+
+    #[test]
+    fn test_invalid_format() {
+        assert!(
+            OrderNumberFormat::new("ord".to_string(), OrderNumberReset::Yearly, 6, false).is_err()
+        );
+        assert!(
+            OrderNumberFormat::new("ORD".to_string(), OrderNumberReset::Yearly, 0, false).is_err()
+        );
+        // EXAMPLE1-202401-000000001X は20文字を超える
+        assert!(
+            OrderNumberFormat::new("EXAMPLE1".to_string(), OrderNumberReset::Monthly, 9, true)
+                .is_err()
+        );
+    }
 }
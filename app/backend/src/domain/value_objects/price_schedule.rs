@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::Money;
+
+/// 期間指定のセール価格（SKUごとの価格スケジュール）
+/// 開始日時以降・終了日時より前の間だけセール価格を適用する
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceSchedule {
+    sale_price: Money,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+}
+
+impl PriceSchedule {
+    /// 価格スケジュールを作成（セール価格と期間を検証する）
+    pub fn new(
+        sale_price: Money,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        if !sale_price.is_positive() {
+            return Err(DomainError::InvalidPrice(
+                "Scheduled sale price must be positive".to_string(),
+            ));
+        }
+        if starts_at >= ends_at {
+            return Err(DomainError::InvalidPrice(
+                "Price schedule starts_at must be before ends_at".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            sale_price,
+            starts_at,
+            ends_at,
+        })
+    }
+
+    /// 指定日時にセール価格が適用されるか
+    pub fn is_active_at(&self, now: DateTime<Utc>) -> bool {
+        self.starts_at <= now && now < self.ends_at
+    }
+
+    /// 指定日時までに終了しているか
+    pub fn has_ended_at(&self, now: DateTime<Utc>) -> bool {
+        self.ends_at <= now
+    }
+
+    /// 他のスケジュールと期間が重なるか
+    pub fn overlaps(&self, other: &PriceSchedule) -> bool {
+        self.starts_at < other.ends_at && other.starts_at < self.ends_at
+    }
+
+    pub fn sale_price(&self) -> Money {
+        self.sale_price
+    }
+
+    pub fn starts_at(&self) -> DateTime<Utc> {
+        self.starts_at
+    }
+
+    pub fn ends_at(&self) -> DateTime<Utc> {
+        self.ends_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 5, day, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_active_only_within_period() {
+        let schedule = PriceSchedule::new(Money::from_yen(8000), at(1), at(8)).unwrap();
+
+        assert!(!schedule.is_active_at(at(1) - Duration::seconds(1)));
+        assert!(schedule.is_active_at(at(1)));
+        assert!(schedule.is_active_at(at(7)));
+        assert!(!schedule.is_active_at(at(8)));
+        assert!(schedule.has_ended_at(at(8)));
+    }
+
+    #[test]
+    fn test_rejects_invalid_period_and_price() {
+        assert!(PriceSchedule::new(Money::from_yen(8000), at(8), at(8)).is_err());
+        assert!(PriceSchedule::new(Money::from_yen(8000), at(9), at(8)).is_err());
+        assert!(PriceSchedule::new(Money::from_yen(0), at(1), at(8)).is_err());
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let schedule = PriceSchedule::new(Money::from_yen(8000), at(1), at(8)).unwrap();
+        let adjacent = PriceSchedule::new(Money::from_yen(7000), at(8), at(15)).unwrap();
+        let overlapping = PriceSchedule::new(Money::from_yen(7000), at(7), at(15)).unwrap();
+
+        assert!(!schedule.overlaps(&adjacent));
+        assert!(schedule.overlaps(&overlapping));
+        assert!(overlapping.overlaps(&schedule));
+    }
+}
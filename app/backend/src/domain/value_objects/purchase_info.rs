@@ -36,6 +36,16 @@ impl PurchaseInfo {
         self.subtotal
     }
 
+    /// カートアイテムのリスト
+    pub fn cart_items(&self) -> &[CartItem] {
+        &self.cart_items
+    }
+
+    /// 配送料（未選択の場合は0円）
+    pub fn shipping_fee(&self) -> Money {
+        self.shipping_fee.unwrap_or(Money::zero())
+    }
+
     /// 特定の商品IDが含まれているかどうかを判定
     pub fn contains_product(&self, product_id: &crate::domain::value_objects::ProductId) -> bool {
         self.cart_items
@@ -43,11 +53,20 @@ impl PurchaseInfo {
             .any(|item| item.product_id() == product_id)
     }
 
+    /// 特定のカテゴリの商品が含まれているかどうかを判定
+    pub fn contains_category(
+        &self,
+        category_id: &crate::domain::value_objects::CategoryId,
+    ) -> bool {
+        self.cart_items
+            .iter()
+            .any(|item| item.category_id() == Some(category_id))
+    }
+
     /// 最低購入金額の条件を満たしているかを判定
     pub fn meets_minimum_amount(&self, minimum_amount: Money) -> bool {
         self.subtotal >= minimum_amount
     }
-
 }
 
 #[cfg(test)]
@@ -0,0 +1,642 @@
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::Money;
+
+/// 商品の税区分
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaxCategory {
+    /// 標準税率
+    #[default]
+    Standard,
+    /// 軽減税率（飲食料品など）
+    Reduced,
+}
+
+impl TaxCategory {
+    /// DBやAPIで使う税区分のコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            TaxCategory::Standard => "standard",
+            TaxCategory::Reduced => "reduced",
+        }
+    }
+}
+
+impl std::str::FromStr for TaxCategory {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "standard" => Ok(TaxCategory::Standard),
+            "reduced" => Ok(TaxCategory::Reduced),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid tax category: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 税額の端数処理
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaxRounding {
+    /// 切り捨て
+    Floor,
+    /// 四捨五入
+    Round,
+    /// 切り上げ
+    #[default]
+    Ceil,
+}
+
+impl TaxRounding {
+    pub fn code(&self) -> &'static str {
+        match self {
+            TaxRounding::Floor => "floor",
+            TaxRounding::Round => "round",
+            TaxRounding::Ceil => "ceil",
+        }
+    }
+
+    /// numerator / denominator を端数処理して整数にする
+    fn apply(&self, numerator: u64, denominator: u64) -> u64 {
+        match self {
+            TaxRounding::Floor => numerator / denominator,
+            TaxRounding::Round => (numerator * 2 + denominator) / (denominator * 2),
+            TaxRounding::Ceil => numerator.div_ceil(denominator),
+        }
+    }
+}
+
+impl std::str::FromStr for TaxRounding {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "floor" => Ok(TaxRounding::Floor),
+            "round" => Ok(TaxRounding::Round),
+            "ceil" => Ok(TaxRounding::Ceil),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid tax rounding: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 端数処理の単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaxRoundingUnit {
+    /// 明細行ごとに端数処理する
+    PerLine,
+    /// 税率ごとに合計してから1回だけ端数処理する（インボイス制度の原則）
+    #[default]
+    PerInvoice,
+}
+
+impl TaxRoundingUnit {
+    pub fn code(&self) -> &'static str {
+        match self {
+            TaxRoundingUnit::PerLine => "per_line",
+            TaxRoundingUnit::PerInvoice => "per_invoice",
+        }
+    }
+}
+
+impl std::str::FromStr for TaxRoundingUnit {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "per_line" => Ok(TaxRoundingUnit::PerLine),
+            "per_invoice" => Ok(TaxRoundingUnit::PerInvoice),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid tax rounding unit: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 価格の税表示方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaxPriceMode {
+    /// 税抜価格（税額を加算する）
+    #[default]
+    Exclusive,
+    /// 税込価格（価格に含まれる税額を割り戻す）
+    Inclusive,
+}
+
+impl TaxPriceMode {
+    pub fn code(&self) -> &'static str {
+        match self {
+            TaxPriceMode::Exclusive => "exclusive",
+            TaxPriceMode::Inclusive => "inclusive",
+        }
+    }
+}
+
+impl std::str::FromStr for TaxPriceMode {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "exclusive" => Ok(TaxPriceMode::Exclusive),
+            "inclusive" => Ok(TaxPriceMode::Inclusive),
+            _ => Err(DomainError::InvalidProductData(format!(
+                "Invalid tax price mode: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 税計算の対象となる明細行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaxableLine {
+    amount: Money,
+    category: TaxCategory,
+    /// クーポン割引の按分対象か（商品のみ）
+    discountable: bool,
+    /// 明細行に割り当て済みのクーポン割引額
+    discount: Money,
+}
+
+impl TaxableLine {
+    /// 商品の明細行
+    pub fn item(amount: Money, category: TaxCategory) -> Self {
+        Self {
+            amount,
+            category,
+            discountable: true,
+            discount: Money::zero(),
+        }
+    }
+
+    /// 配送料・支払い手数料の明細行（標準税率、割引対象外）
+    pub fn fee(amount: Money) -> Self {
+        Self {
+            amount,
+            category: TaxCategory::Standard,
+            discountable: false,
+            discount: Money::zero(),
+        }
+    }
+
+    /// 明細行に割り当て済みの割引額を設定（対象商品を限定したクーポンの場合）
+    pub fn with_discount(mut self, discount: Money) -> Self {
+        self.discount = discount;
+        self
+    }
+}
+
+/// 税率ごとの内訳（適格請求書の記載事項）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaxBreakdown {
+    /// 税率（%）
+    pub rate: u32,
+    /// 対象額（税抜）
+    pub taxable_amount: Money,
+    /// 消費税額
+    pub tax_amount: Money,
+}
+
+impl TaxBreakdown {
+    pub fn new(rate: u32, taxable_amount: Money, tax_amount: Money) -> Self {
+        Self {
+            rate,
+            taxable_amount,
+            tax_amount,
+        }
+    }
+}
+
+/// 税計算の結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxCalculation {
+    /// 税率ごとの内訳（税率の高い順）
+    pub breakdown: Vec<TaxBreakdown>,
+    /// 消費税額の合計
+    pub tax_amount: Money,
+    /// 割引後の支払総額（税込）
+    pub total: Money,
+    /// 価格が税込か
+    pub tax_included: bool,
+}
+
+/// 消費税の計算ポリシー
+/// 税率・税込/税抜・端数処理の方法と単位をまとめて扱う
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaxPolicy {
+    standard_rate: u32,
+    reduced_rate: u32,
+    price_mode: TaxPriceMode,
+    rounding: TaxRounding,
+    rounding_unit: TaxRoundingUnit,
+}
+
+impl TaxPolicy {
+    pub const DEFAULT_STANDARD_RATE: u32 = 10;
+    pub const DEFAULT_REDUCED_RATE: u32 = 8;
+
+    pub fn new(standard_rate: u32, reduced_rate: u32) -> Result<Self, DomainError> {
+        if standard_rate > 100 || reduced_rate > 100 {
+            return Err(DomainError::InvalidProductData(
+                "Tax rate must be between 0 and 100".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            standard_rate,
+            reduced_rate,
+            price_mode: TaxPriceMode::default(),
+            rounding: TaxRounding::default(),
+            rounding_unit: TaxRoundingUnit::default(),
+        })
+    }
+
+    pub fn with_price_mode(mut self, price_mode: TaxPriceMode) -> Self {
+        self.price_mode = price_mode;
+        self
+    }
+
+    pub fn with_rounding(mut self, rounding: TaxRounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn with_rounding_unit(mut self, rounding_unit: TaxRoundingUnit) -> Self {
+        self.rounding_unit = rounding_unit;
+        self
+    }
+
+    /// 税区分に対応する税率（%）
+    pub fn rate_for(&self, category: TaxCategory) -> u32 {
+        match category {
+            TaxCategory::Standard => self.standard_rate,
+            TaxCategory::Reduced => self.reduced_rate,
+        }
+    }
+
+    /// 明細行の税額を計算する
+    /// クーポン割引は商品の明細行に金額比で按分し、按分後の金額に課税する
+    pub fn calculate(
+        &self,
+        lines: &[TaxableLine],
+        discount: Money,
+    ) -> Result<TaxCalculation, DomainError> {
+        let net_amounts = Self::allocate_discount(lines, discount)?;
+
+        // 税率ごとに (対象額, 明細行ごとに端数処理した税額) を集計する
+        let mut groups: Vec<(u32, u64, u64)> = Vec::new();
+        for (line, amount) in lines.iter().zip(net_amounts) {
+            if amount == 0 {
+                continue;
+            }
+            let rate = self.rate_for(line.category);
+            let line_tax = match self.rounding_unit {
+                TaxRoundingUnit::PerLine => self.tax_of(amount, rate),
+                TaxRoundingUnit::PerInvoice => 0,
+            };
+            match groups.iter_mut().find(|(r, _, _)| *r == rate) {
+                Some(group) => {
+                    group.1 += amount;
+                    group.2 += line_tax;
+                }
+                None => groups.push((rate, amount, line_tax)),
+            }
+        }
+        groups.sort_by_key(|group| std::cmp::Reverse(group.0));
+
+        let mut breakdown = Vec::with_capacity(groups.len());
+        let mut amount_total = 0u64;
+        let mut tax_total = 0u64;
+        for (rate, amount, line_tax) in groups {
+            let tax = match self.rounding_unit {
+                TaxRoundingUnit::PerLine => line_tax,
+                TaxRoundingUnit::PerInvoice => self.tax_of(amount, rate),
+            };
+            let taxable_amount = match self.price_mode {
+                TaxPriceMode::Exclusive => amount,
+                TaxPriceMode::Inclusive => amount - tax,
+            };
+            breakdown.push(TaxBreakdown::new(
+                rate,
+                Self::to_money(taxable_amount)?,
+                Self::to_money(tax)?,
+            ));
+            amount_total += amount;
+            tax_total += tax;
+        }
+
+        let total = match self.price_mode {
+            TaxPriceMode::Exclusive => amount_total + tax_total,
+            TaxPriceMode::Inclusive => amount_total,
+        };
+
+        Ok(TaxCalculation {
+            breakdown,
+            tax_amount: Self::to_money(tax_total)?,
+            total: Self::to_money(total)?,
+            tax_included: self.price_mode == TaxPriceMode::Inclusive,
+        })
+    }
+
+    /// 割引額を商品の明細行へ金額比で按分し、明細行ごとの割引後金額を返す
+    /// 明細行に割り当て済みの割引額を先に差し引き、残りを按分する（端数は最後の商品明細で調整する）
+    fn allocate_discount(lines: &[TaxableLine], discount: Money) -> Result<Vec<u64>, DomainError> {
+        let exceeds_subtotal = || {
+            DomainError::BusinessRuleViolation(
+                "Discount cannot exceed the item subtotal".to_string(),
+            )
+        };
+
+        let mut allocated = 0u64;
+        let mut net_amounts = Vec::with_capacity(lines.len());
+        for line in lines {
+            let amount = line.amount.yen() as u64;
+            let line_discount = line.discount.yen() as u64;
+            if line_discount > amount {
+                return Err(exceeds_subtotal());
+            }
+            allocated += line_discount;
+            net_amounts.push(amount - line_discount);
+        }
+
+        let discount = (discount.yen() as u64)
+            .checked_sub(allocated)
+            .ok_or_else(|| {
+                DomainError::BusinessRuleViolation(
+                    "Line discounts exceed the total discount".to_string(),
+                )
+            })?;
+        let discountable_total: u64 = lines
+            .iter()
+            .zip(&net_amounts)
+            .filter(|(line, _)| line.discountable)
+            .map(|(_, amount)| amount)
+            .sum();
+        if discount > discountable_total {
+            return Err(exceeds_subtotal());
+        }
+
+        let last_discountable = lines.iter().rposition(|line| line.discountable);
+        let mut remaining = discount;
+        Ok(lines
+            .iter()
+            .zip(net_amounts)
+            .enumerate()
+            .map(|(index, (line, amount))| {
+                if !line.discountable || discount == 0 {
+                    return amount;
+                }
+                let share = if Some(index) == last_discountable {
+                    remaining
+                } else {
+                    discount * amount / discountable_total
+                };
+                remaining -= share;
+                amount - share
+            })
+            .collect())
+    }
+
+    /// 金額に対する税額（税込価格の場合は含まれる税額）
+    fn tax_of(&self, amount: u64, rate: u32) -> u64 {
+        let rate = rate as u64;
+        let denominator = match self.price_mode {
+            TaxPriceMode::Exclusive => 100,
+            TaxPriceMode::Inclusive => 100 + rate,
+        };
+        self.rounding.apply(amount * rate, denominator)
+    }
+
+    fn to_money(amount: u64) -> Result<Money, DomainError> {
+        u32::try_from(amount)
+            .map(Money::from_yen)
+            .map_err(|_| DomainError::InvalidProductData("Tax calculation overflow".to_string()))
+    }
+
+    pub fn standard_rate(&self) -> u32 {
+        self.standard_rate
+    }
+
+    pub fn reduced_rate(&self) -> u32 {
+        self.reduced_rate
+    }
+}
+
+impl Default for TaxPolicy {
+    /// 標準税率10%・軽減税率8%、税抜価格、税率ごとに1円未満切り上げ
+    fn default() -> Self {
+        Self {
+            standard_rate: Self::DEFAULT_STANDARD_RATE,
+            reduced_rate: Self::DEFAULT_REDUCED_RATE,
+            price_mode: TaxPriceMode::default(),
+            rounding: TaxRounding::default(),
+            rounding_unit: TaxRoundingUnit::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(yen: u32, category: TaxCategory) -> TaxableLine {
+        TaxableLine::item(Money::from_yen(yen), category)
+    }
+
+    #[test]
+    fn test_default_policy_taxes_items_and_fees_at_standard_rate() {
+        let policy = TaxPolicy::default();
+        let result = policy
+            .calculate(
+                &[
+                    item(999, TaxCategory::Standard),
+                    TaxableLine::fee(Money::from_yen(500)),
+                ],
+                Money::zero(),
+            )
+            .unwrap();
+
+        // 1499 * 10% = 149.9 -> 150
+        assert_eq!(result.tax_amount, Money::from_yen(150));
+        assert_eq!(result.total, Money::from_yen(1649));
+        assert_eq!(
+            result.breakdown,
+            vec![TaxBreakdown::new(
+                10,
+                Money::from_yen(1499),
+                Money::from_yen(150)
+            )]
+        );
+        assert!(!result.tax_included);
+    }
+
+    #[test]
+    fn test_reduced_rate_breakdown() {
+        let policy = TaxPolicy::default();
+        let result = policy
+            .calculate(
+                &[
+                    item(1080, TaxCategory::Reduced),
+                    item(2000, TaxCategory::Standard),
+                    TaxableLine::fee(Money::from_yen(500)),
+                ],
+                Money::zero(),
+            )
+            .unwrap();
+
+        // 10%: 2500 -> 250, 8%: 1080 -> 86.4 -> 87
+        assert_eq!(
+            result.breakdown,
+            vec![
+                TaxBreakdown::new(10, Money::from_yen(2500), Money::from_yen(250)),
+                TaxBreakdown::new(8, Money::from_yen(1080), Money::from_yen(87)),
+            ]
+        );
+        assert_eq!(result.tax_amount, Money::from_yen(337));
+        assert_eq!(result.total, Money::from_yen(3917));
+    }
+
+    #[test]
+    fn test_discount_is_allocated_across_items() {
+        let policy = TaxPolicy::default();
+        let result = policy
+            .calculate(
+                &[
+                    item(3000, TaxCategory::Standard),
+                    item(1000, TaxCategory::Reduced),
+                    TaxableLine::fee(Money::from_yen(500)),
+                ],
+                Money::from_yen(400),
+            )
+            .unwrap();
+
+        // 割引は商品に3:1で按分し、手数料には按分しない
+        assert_eq!(
+            result.breakdown,
+            vec![
+                TaxBreakdown::new(10, Money::from_yen(3200), Money::from_yen(320)),
+                TaxBreakdown::new(8, Money::from_yen(900), Money::from_yen(72)),
+            ]
+        );
+        assert_eq!(result.total, Money::from_yen(4492));
+
+        assert!(
+            policy
+                .calculate(&[item(100, TaxCategory::Standard)], Money::from_yen(101))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_line_discounts_are_applied_to_their_own_lines() {
+        let policy = TaxPolicy::default();
+        let result = policy
+            .calculate(
+                &[
+                    item(3000, TaxCategory::Standard),
+                    item(1000, TaxCategory::Reduced).with_discount(Money::from_yen(400)),
+                ],
+                Money::from_yen(400),
+            )
+            .unwrap();
+
+        // 割引は対象の軽減税率の商品にのみ適用する
+        assert_eq!(
+            result.breakdown,
+            vec![
+                TaxBreakdown::new(10, Money::from_yen(3000), Money::from_yen(300)),
+                TaxBreakdown::new(8, Money::from_yen(600), Money::from_yen(48)),
+            ]
+        );
+
+        assert!(
+            policy
+                .calculate(
+                    &[item(1000, TaxCategory::Standard).with_discount(Money::from_yen(500))],
+                    Money::from_yen(400),
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_rounding_per_line_and_per_invoice() {
+        let lines = [
+            item(105, TaxCategory::Standard),
+            item(105, TaxCategory::Standard),
+        ];
+
+        // 明細ごと: 10.5 -> 10 を2行
+        let per_line = TaxPolicy::default()
+            .with_rounding(TaxRounding::Floor)
+            .with_rounding_unit(TaxRoundingUnit::PerLine)
+            .calculate(&lines, Money::zero())
+            .unwrap();
+        assert_eq!(per_line.tax_amount, Money::from_yen(20));
+
+        // 請求書ごと: 21.0 -> 21
+        let per_invoice = TaxPolicy::default()
+            .with_rounding(TaxRounding::Floor)
+            .calculate(&lines, Money::zero())
+            .unwrap();
+        assert_eq!(per_invoice.tax_amount, Money::from_yen(21));
+
+        // 四捨五入: 10.5 -> 11 を2行
+        let rounded = TaxPolicy::default()
+            .with_rounding(TaxRounding::Round)
+            .with_rounding_unit(TaxRoundingUnit::PerLine)
+            .calculate(&lines, Money::zero())
+            .unwrap();
+        assert_eq!(rounded.tax_amount, Money::from_yen(22));
+    }
+
+    #[test]
+    fn test_tax_inclusive_prices() {
+        let policy = TaxPolicy::default()
+            .with_price_mode(TaxPriceMode::Inclusive)
+            .with_rounding(TaxRounding::Floor);
+        let result = policy
+            .calculate(
+                &[
+                    item(1100, TaxCategory::Standard),
+                    item(1080, TaxCategory::Reduced),
+                ],
+                Money::zero(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            result.breakdown,
+            vec![
+                TaxBreakdown::new(10, Money::from_yen(1000), Money::from_yen(100)),
+                TaxBreakdown::new(8, Money::from_yen(1000), Money::from_yen(80)),
+            ]
+        );
+        // 税込価格では税額を加算しない
+        assert_eq!(result.total, Money::from_yen(2180));
+        assert!(result.tax_included);
+    }
+
+    #[test]
+    fn test_parse_codes() {
+        assert_eq!(
+            "reduced".parse::<TaxCategory>().unwrap(),
+            TaxCategory::Reduced
+        );
+        assert_eq!("round".parse::<TaxRounding>().unwrap(), TaxRounding::Round);
+        assert_eq!(
+            "per_line".parse::<TaxRoundingUnit>().unwrap(),
+            TaxRoundingUnit::PerLine
+        );
+        assert_eq!(
+            "inclusive".parse::<TaxPriceMode>().unwrap(),
+            TaxPriceMode::Inclusive
+        );
+        assert!("zero".parse::<TaxCategory>().is_err());
+        assert!(TaxPolicy::new(120, 8).is_err());
+    }
+}
@@ -11,6 +11,14 @@ pub enum Error {
     InternalServerError,
     ServerError(Option<String>),
     ValidationError(String),
+    InvalidStatusTransition(String),
+    InsufficientStock(String),
+    PaymentDeclined(String),
+    PaymentGatewayError(String),
+    Unauthorized(String),
+    IdempotencyKeyReused(String),
+    IdempotencyKeyInUse(String),
+    Conflict(String),
 }
 
 impl IntoResponse for Error {
@@ -58,6 +66,70 @@ impl IntoResponse for Error {
                     details: None,
                 },
             ),
+            Error::InvalidStatusTransition(msg) => (
+                StatusCode::CONFLICT,
+                ErrorResponse {
+                    code: "INVALID_STATUS_TRANSITION".to_string(),
+                    message: msg,
+                    details: None,
+                },
+            ),
+            Error::InsufficientStock(msg) => (
+                StatusCode::CONFLICT,
+                ErrorResponse {
+                    code: "INSUFFICIENT_STOCK".to_string(),
+                    message: msg,
+                    details: None,
+                },
+            ),
+            Error::PaymentDeclined(msg) => (
+                StatusCode::PAYMENT_REQUIRED,
+                ErrorResponse {
+                    code: "PAYMENT_DECLINED".to_string(),
+                    message: msg,
+                    details: None,
+                },
+            ),
+            Error::PaymentGatewayError(msg) => (
+                StatusCode::BAD_GATEWAY,
+                ErrorResponse {
+                    code: "PAYMENT_GATEWAY_ERROR".to_string(),
+                    message: msg,
+                    details: None,
+                },
+            ),
+            Error::Unauthorized(msg) => (
+                StatusCode::UNAUTHORIZED,
+                ErrorResponse {
+                    code: "UNAUTHORIZED".to_string(),
+                    message: msg,
+                    details: None,
+                },
+            ),
+            Error::IdempotencyKeyReused(msg) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ErrorResponse {
+                    code: "IDEMPOTENCY_KEY_REUSED".to_string(),
+                    message: msg,
+                    details: None,
+                },
+            ),
+            Error::IdempotencyKeyInUse(msg) => (
+                StatusCode::CONFLICT,
+                ErrorResponse {
+                    code: "IDEMPOTENCY_KEY_IN_USE".to_string(),
+                    message: msg,
+                    details: None,
+                },
+            ),
+            Error::Conflict(msg) => (
+                StatusCode::CONFLICT,
+                ErrorResponse {
+                    code: "CONFLICT".to_string(),
+                    message: msg,
+                    details: None,
+                },
+            ),
         };
 
         (status, Json(error_response)).into_response()
@@ -79,8 +151,18 @@ impl From<ApplicationError> for Error {
             ApplicationError::ProductNotFound(_) => Error::NotFound,
             ApplicationError::Domain(domain_error) => {
                 println!("->> Domain error details: {:?}", domain_error);
-                // ドメインエラーは通常、バリデーションエラーとして扱う
-                Error::ValidationError(domain_error.to_string())
+                match domain_error {
+                    // ステータス遷移の競合は409として扱う
+                    crate::domain::DomainError::InvalidStatusTransition { .. } => {
+                        Error::InvalidStatusTransition(domain_error.to_string())
+                    }
+                    // 在庫数を超える減算は注文時の在庫不足と同じく409として扱う
+                    crate::domain::DomainError::InsufficientStock { .. } => {
+                        Error::InsufficientStock(domain_error.to_string())
+                    }
+                    // ドメインエラーは通常、バリデーションエラーとして扱う
+                    _ => Error::ValidationError(domain_error.to_string()),
+                }
             }
             ApplicationError::Repository(repo_error) => {
                 println!("->> [ErrorHandler] Repository error details: {:?}", repo_error);
@@ -91,6 +173,23 @@ impl From<ApplicationError> for Error {
                         // 集約されたユーザーメッセージ変換を使用
                         Error::ValidationError(repo_error.to_user_message())
                     }
+                    crate::application::error::RepositoryError::UniqueConstraint { .. } => {
+                        Error::ValidationError(repo_error.to_user_message())
+                    }
+                    crate::application::error::RepositoryError::CouponUsageLimitExceeded { .. } => {
+                        Error::ValidationError(repo_error.to_user_message())
+                    }
+                    crate::application::error::RepositoryError::CouponCustomerLimitExceeded { .. } => {
+                        Error::ValidationError(repo_error.to_user_message())
+                    }
+                    crate::application::error::RepositoryError::InsufficientStock(..) => {
+                        // 在庫の競合は409として扱う
+                        Error::InsufficientStock(repo_error.to_user_message())
+                    }
+                    crate::application::error::RepositoryError::Conflict(..) => {
+                        // 同時更新の競合は409として扱う（最新の状態で再試行できる）
+                        Error::Conflict(repo_error.to_user_message())
+                    }
                     crate::application::error::RepositoryError::QueryExecution(..) => {
                         Error::InternalServerError
                     }
@@ -111,6 +210,13 @@ impl From<ApplicationError> for Error {
             ApplicationError::Validation(msg) => Error::ValidationError(msg),
             ApplicationError::InvalidInput(msg) => Error::ValidationError(msg),
             ApplicationError::NotFound(_) => Error::NotFound,
+            // 決済の拒否は402、決済代行会社側の障害は502として扱う
+            ApplicationError::PaymentDeclined(reason) => {
+                Error::PaymentDeclined(format!("Payment declined: {}", reason))
+            }
+            ApplicationError::PaymentGateway(err) => Error::PaymentGatewayError(err.to_string()),
+            ApplicationError::Unauthorized(msg) => Error::Unauthorized(msg),
+            ApplicationError::Conflict(msg) => Error::Conflict(msg),
         }
     }
 }
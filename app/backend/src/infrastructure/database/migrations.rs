@@ -26,6 +26,60 @@ pub async fn run_migrations(database_url: &str) -> Result<()> {
     // Phase 7: クーポンテーブル作成
     create_coupon_tables(&pool).await?;
 
+    // Phase 8: カートテーブル作成（SKUテーブルに依存）
+    create_cart_tables(&pool).await?;
+
+    // Phase 9: 商品検索インデックス作成（商品・SKU・色テーブルに依存）
+    create_product_search_index(&pool).await?;
+
+    // Phase 10: 在庫台帳テーブル作成（SKU・注文テーブルに依存）
+    create_stock_movements_table(&pool).await?;
+
+    // Phase 11: 配送情報テーブル作成（注文テーブルに依存）
+    create_delivery_infos_table(&pool).await?;
+
+    // Phase 12: 決済テーブル作成（注文テーブルに依存）
+    create_payment_tables(&pool).await?;
+
+    // Phase 13: 支払い手数料ルール作成（支払い方法テーブルに依存）
+    create_payment_fee_rules(&pool).await?;
+
+    // Phase 14: 配送料金表作成（配送方法テーブルに依存）
+    create_shipping_rate_tables(&pool).await?;
+
+    // Phase 15: 税区分・税率別内訳作成（SKU・注文テーブルに依存）
+    create_tax_tables(&pool).await?;
+
+    // Phase 16: クーポン使用履歴作成（クーポン・注文テーブルに依存）
+    create_coupon_redemptions_table(&pool).await?;
+
+    // Phase 17: クーポン適用条件・明細別割引額作成（クーポン・注文テーブルに依存）
+    create_coupon_conditions_table(&pool).await?;
+
+    // Phase 18: プロモーション・注文のプロモーション適用履歴作成（注文テーブルに依存）
+    create_promotion_tables(&pool).await?;
+
+    // Phase 19: SKUの価格スケジュール作成（SKUテーブルに依存）
+    create_price_schedules_table(&pool).await?;
+
+    // Phase 20: 会員・ログインセッション作成、注文と会員の紐付け（注文テーブルに依存）
+    create_customer_tables(&pool).await?;
+
+    // Phase 21: 冪等キー作成（独立）
+    create_idempotency_keys_table(&pool).await?;
+
+    // Phase 22: 注文番号の連番作成（注文テーブルに依存）
+    create_order_sequences_table(&pool).await?;
+
+    // Phase 23: 返品（RMA）作成（注文テーブルに依存）
+    create_returns_tables(&pool).await?;
+
+    // Phase 24: 請求書・領収書の発行回数（注文テーブルに依存）
+    add_document_issue_counts(&pool).await?;
+
+    // Phase 25: 注文のバージョン（注文テーブルに依存）
+    add_order_version(&pool).await?;
+
     println!("✅ All migrations completed successfully!");
     Ok(())
 }
@@ -131,6 +185,8 @@ async fn create_products_table(pool: &sqlx::SqlitePool) -> Result<()> {
             category_id TEXT NOT NULL,
             is_best_seller BOOLEAN DEFAULT FALSE,
             is_quick_ship BOOLEAN DEFAULT FALSE,
+            is_available BOOLEAN NOT NULL DEFAULT TRUE,
+            display_order INTEGER NOT NULL DEFAULT 0,
             created_at TEXT NOT NULL DEFAULT (datetime('now')),
             updated_at TEXT NOT NULL DEFAULT (datetime('now')),
             FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE RESTRICT
@@ -140,6 +196,23 @@ async fn create_products_table(pool: &sqlx::SqlitePool) -> Result<()> {
     .execute(pool)
     .await?;
 
+    // 既存のproductsテーブルに表示順カラムを追加
+    add_column_if_missing(
+        pool,
+        "products",
+        "display_order",
+        "INTEGER NOT NULL DEFAULT 0",
+    )
+    .await?;
+    // 既存のproductsテーブルに公開状態カラムを追加（既存商品は公開中として扱う）
+    add_column_if_missing(
+        pool,
+        "products",
+        "is_available",
+        "BOOLEAN NOT NULL DEFAULT TRUE",
+    )
+    .await?;
+
     // 商品インデックス
     sqlx::query("CREATE INDEX IF NOT EXISTS idx_products_category ON products(category_id)")
         .execute(pool)
@@ -147,6 +220,12 @@ async fn create_products_table(pool: &sqlx::SqlitePool) -> Result<()> {
     sqlx::query("CREATE INDEX IF NOT EXISTS idx_products_best_seller ON products(is_best_seller) WHERE is_best_seller = 1")
         .execute(pool).await?;
 
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_products_display_order ON products(display_order, name)",
+    )
+    .execute(pool)
+    .await?;
+
     println!("📦 Products table created with constraints and indexes");
     Ok(())
 }
@@ -170,6 +249,7 @@ async fn create_product_related_tables(pool: &sqlx::SqlitePool) -> Result<()> {
             reserved_quantity INTEGER DEFAULT 0,
             low_stock_threshold INTEGER DEFAULT 5,
             display_order INTEGER NOT NULL DEFAULT 0,
+            status TEXT NOT NULL DEFAULT 'active' CHECK (status IN ('active', 'inactive', 'discontinued')),
             image_url TEXT,
             created_at TEXT NOT NULL DEFAULT (datetime('now')),
             updated_at TEXT NOT NULL DEFAULT (datetime('now')),
@@ -185,6 +265,15 @@ async fn create_product_related_tables(pool: &sqlx::SqlitePool) -> Result<()> {
     .execute(pool)
     .await?;
 
+    // 既存のskusテーブルに販売ステータスカラムを追加
+    add_column_if_missing(
+        pool,
+        "skus",
+        "status",
+        "TEXT NOT NULL DEFAULT 'active' CHECK (status IN ('active', 'inactive', 'discontinued'))",
+    )
+    .await?;
+
     // SKUインデックス
     sqlx::query("CREATE INDEX IF NOT EXISTS idx_skus_product_id ON skus(product_id)")
         .execute(pool)
@@ -362,6 +451,25 @@ async fn create_payment_methods_table(pool: &sqlx::SqlitePool) -> Result<()> {
     Ok(())
 }
 
+/// 注文イベントテーブル定義
+const ORDER_EVENTS_TABLE_SQL: &str = r#"
+        CREATE TABLE IF NOT EXISTS order_events (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            order_id TEXT NOT NULL,
+            event_type TEXT NOT NULL,
+            event_data TEXT NOT NULL,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            
+            FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE,
+            CONSTRAINT valid_event_type CHECK (event_type IN (
+                'order_created', 'order_paid', 'order_shipped', 
+                'order_delivered', 'order_cancelled', 'order_refunded',
+                'order_status_changed', 'order_note_added', 'order_delivery_info_attached',
+                'order_amended'
+            ))
+        )
+"#;
+
 /// Phase 6: 注文関連テーブル作成
 async fn create_order_tables(pool: &sqlx::SqlitePool) -> Result<()> {
     // 注文テーブル
@@ -407,6 +515,11 @@ async fn create_order_tables(pool: &sqlx::SqlitePool) -> Result<()> {
             delivered_at TEXT,
             cancelled_at TEXT,
             
+            -- クーポン
+            coupon_id TEXT,
+            coupon_code TEXT,
+            discount_amount INTEGER NOT NULL DEFAULT 0,
+            
             -- オプション
             delivery_info_id TEXT,
             notes TEXT,
@@ -421,6 +534,17 @@ async fn create_order_tables(pool: &sqlx::SqlitePool) -> Result<()> {
     .execute(pool)
     .await?;
 
+    // 既存のordersテーブルにクーポン関連カラムを追加
+    add_column_if_missing(pool, "orders", "coupon_id", "TEXT").await?;
+    add_column_if_missing(pool, "orders", "coupon_code", "TEXT").await?;
+    add_column_if_missing(
+        pool,
+        "orders",
+        "discount_amount",
+        "INTEGER NOT NULL DEFAULT 0",
+    )
+    .await?;
+
     // 注文アイテムテーブル
     sqlx::query(
         r#"
@@ -453,25 +577,10 @@ async fn create_order_tables(pool: &sqlx::SqlitePool) -> Result<()> {
     .await?;
 
     // 注文イベントテーブル
-    sqlx::query(
-        r#"
-        CREATE TABLE IF NOT EXISTS order_events (
-            id INTEGER PRIMARY KEY AUTOINCREMENT,
-            order_id TEXT NOT NULL,
-            event_type TEXT NOT NULL,
-            event_data TEXT NOT NULL,
-            created_at TEXT NOT NULL DEFAULT (datetime('now')),
-            
-            FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE,
-            CONSTRAINT valid_event_type CHECK (event_type IN (
-                'order_created', 'order_paid', 'order_shipped', 
-                'order_delivered', 'order_cancelled', 'order_refunded'
-            ))
-        )
-        "#,
-    )
-    .execute(pool)
-    .await?;
+    sqlx::query(ORDER_EVENTS_TABLE_SQL).execute(pool).await?;
+
+    // 旧スキーマのorder_eventsはイベント種別の制約が狭いため再作成する
+    upgrade_order_events_event_types(pool).await?;
 
     // インデックス作成
     sqlx::query("CREATE INDEX IF NOT EXISTS idx_orders_customer_email ON orders(customer_email)")
@@ -510,11 +619,117 @@ async fn create_order_tables(pool: &sqlx::SqlitePool) -> Result<()> {
     Ok(())
 }
 
-/// Phase 7: クーポンテーブル作成
-async fn create_coupon_tables(pool: &sqlx::SqlitePool) -> Result<()> {
-    // クーポンテーブル
+/// テーブルにカラムが存在しなければ追加する（追加した場合はtrue）
+/// CREATE TABLE IF NOT EXISTSでは既存テーブルにカラムが追加されないため、後から増えたカラムに使う
+async fn add_column_if_missing(
+    pool: &sqlx::SqlitePool,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<bool> {
+    let exists: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2")
+            .bind(table)
+            .bind(column)
+            .fetch_one(pool)
+            .await?;
+
+    if exists == 0 {
+        sqlx::query(&format!(
+            "ALTER TABLE {} ADD COLUMN {} {}",
+            table, column, definition
+        ))
+        .execute(pool)
+        .await?;
+        println!("📦 {}.{} column added", table, column);
+    }
+
+    Ok(exists == 0)
+}
+
+/// order_eventsのCHECK制約に新しいイベント種別が含まれていなければテーブルを再作成する
+/// SQLiteはCHECK制約を変更できないため、既存データをコピーして入れ替える
+async fn upgrade_order_events_event_types(pool: &sqlx::SqlitePool) -> Result<()> {
+    let table_sql: Option<String> = sqlx::query_scalar(
+        "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'order_events'",
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    if table_sql.is_none_or(|sql| sql.contains("order_amended")) {
+        return Ok(());
+    }
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("ALTER TABLE order_events RENAME TO order_events_old")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(ORDER_EVENTS_TABLE_SQL)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        r#"
+        INSERT INTO order_events (id, order_id, event_type, event_data, created_at)
+        SELECT id, order_id, event_type, event_data, created_at FROM order_events_old
+        "#,
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query("DROP TABLE order_events_old")
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    println!("📦 order_events table upgraded (new event types)");
+    Ok(())
+}
+
+/// couponsのCHECK制約に新しい割引種別が含まれていなければテーブルを再作成する
+/// SQLiteはCHECK制約を変更できないため、既存データをコピーして入れ替える
+async fn upgrade_coupons_discount_types(pool: &sqlx::SqlitePool) -> Result<()> {
+    let table_sql: Option<String> = sqlx::query_scalar(
+        "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'coupons'",
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    if table_sql.is_none_or(|sql| sql.contains("free_shipping")) {
+        return Ok(());
+    }
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("ALTER TABLE coupons RENAME TO coupons_old")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(COUPONS_TABLE_SQL).execute(&mut *tx).await?;
     sqlx::query(
         r#"
+        INSERT INTO coupons (
+            id, code, name, description, discount_type, discount_value, minimum_amount,
+            usage_limit, used_count, valid_from, valid_until, created_at, updated_at
+        )
+        SELECT id, code, name, description, discount_type, discount_value, minimum_amount,
+               usage_limit, used_count, valid_from, valid_until, created_at, updated_at
+        FROM coupons_old
+        "#,
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query("DROP TABLE coupons_old")
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    println!("🎫 coupons table upgraded (new discount types)");
+    Ok(())
+}
+
+/// クーポンテーブル定義
+const COUPONS_TABLE_SQL: &str = r#"
         CREATE TABLE IF NOT EXISTS coupons (
             id TEXT PRIMARY KEY NOT NULL,
             code TEXT NOT NULL UNIQUE,
@@ -523,23 +738,39 @@ async fn create_coupon_tables(pool: &sqlx::SqlitePool) -> Result<()> {
             discount_type TEXT NOT NULL,
             discount_value INTEGER NOT NULL,
             minimum_amount INTEGER,
+            max_discount_amount INTEGER,
+            buy_quantity INTEGER,
+            get_quantity INTEGER,
             usage_limit INTEGER,
             used_count INTEGER NOT NULL DEFAULT 0,
+            per_customer_limit INTEGER,
             valid_from TEXT NOT NULL,
             valid_until TEXT NOT NULL,
             created_at TEXT NOT NULL DEFAULT (datetime('now')),
             updated_at TEXT NOT NULL DEFAULT (datetime('now')),
-            
-            CONSTRAINT valid_discount_type CHECK (discount_type IN ('percentage', 'fixed_amount')),
-            CONSTRAINT positive_discount_value CHECK (discount_value > 0),
+
+            CONSTRAINT valid_discount_type CHECK (discount_type IN (
+                'percentage', 'fixed_amount', 'capped_percentage', 'free_shipping', 'buy_x_get_y'
+            )),
+            CONSTRAINT positive_discount_value CHECK (discount_value >= 0),
             CONSTRAINT positive_minimum_amount CHECK (minimum_amount IS NULL OR minimum_amount >= 0),
+            CONSTRAINT positive_max_discount_amount CHECK (max_discount_amount IS NULL OR max_discount_amount > 0),
+            CONSTRAINT positive_buy_get_quantity CHECK (
+                (buy_quantity IS NULL OR buy_quantity > 0) AND (get_quantity IS NULL OR get_quantity > 0)
+            ),
             CONSTRAINT positive_usage_limit CHECK (usage_limit IS NULL OR usage_limit > 0),
-            CONSTRAINT positive_used_count CHECK (used_count >= 0)
+            CONSTRAINT positive_used_count CHECK (used_count >= 0),
+            CONSTRAINT positive_per_customer_limit CHECK (per_customer_limit IS NULL OR per_customer_limit > 0)
         )
-        "#,
-    )
-    .execute(pool)
-    .await?;
+"#;
+
+/// Phase 7: クーポンテーブル作成
+async fn create_coupon_tables(pool: &sqlx::SqlitePool) -> Result<()> {
+    // クーポンテーブル
+    sqlx::query(COUPONS_TABLE_SQL).execute(pool).await?;
+
+    // 旧スキーマのcouponsは割引種別の制約が狭く、追加の割引設定カラムもないため再作成する
+    upgrade_coupons_discount_types(pool).await?;
 
     // クーポンインデックス
     sqlx::query("CREATE INDEX IF NOT EXISTS idx_coupons_code ON coupons(code)")
@@ -568,7 +799,958 @@ async fn create_coupon_tables(pool: &sqlx::SqlitePool) -> Result<()> {
     )
     .execute(pool)
     .await?;
+    sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO coupons (
+            id, code, name, description, discount_type, discount_value, minimum_amount,
+            max_discount_amount, buy_quantity, get_quantity, usage_limit, per_customer_limit,
+            valid_from, valid_until
+        ) VALUES
+        ('550e8400-e29b-41d4-a716-446655440004', 'FREESHIP', '送料無料クーポン', '配送料が無料', 'free_shipping', 0, 5000, NULL, NULL, NULL, NULL, 1, '2024-01-01', '2044-12-31'),
+        ('550e8400-e29b-41d4-a716-446655440005', 'BUY2GET1', '2点購入で1点無料', '3点ごとに最も安い1点が無料', 'buy_x_get_y', 0, NULL, NULL, 2, 1, 100, NULL, '2024-01-01', '2044-12-31'),
+        ('550e8400-e29b-41d4-a716-446655440006', 'MAX3000', '15%オフ（最大3000円）', '割引額は最大3000円', 'capped_percentage', 15, NULL, 3000, NULL, NULL, NULL, 2, '2024-01-01', '2044-12-31')
+        "#,
+    )
+    .execute(pool)
+    .await?;
 
     println!("🎫 Coupon tables created with initial test data");
     Ok(())
 }
+
+/// Phase 8: カートテーブル作成
+/// cart_itemsにはSKUと数量のみを保存し、商品名・価格は読み込み時にSKUから取得する
+async fn create_cart_tables(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS carts (
+            id TEXT PRIMARY KEY NOT NULL,
+            shipping_method_id TEXT,
+            payment_method_id TEXT,
+            coupon_code TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            expires_at TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS cart_items (
+            cart_id TEXT NOT NULL,
+            sku_id TEXT NOT NULL,
+            quantity INTEGER NOT NULL,
+            position INTEGER NOT NULL,
+            PRIMARY KEY (cart_id, sku_id),
+            FOREIGN KEY (cart_id) REFERENCES carts(id) ON DELETE CASCADE,
+            FOREIGN KEY (sku_id) REFERENCES skus(id) ON DELETE CASCADE,
+            CONSTRAINT positive_quantity CHECK (quantity > 0)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_carts_expires_at ON carts(expires_at)")
+        .execute(pool)
+        .await?;
+
+    println!("🛒 Cart tables created (carts, cart_items)");
+    Ok(())
+}
+
+/// 条件に一致する商品の検索インデックス行を作り直すSQL
+/// `product_condition`は`p.id`に続く条件式（例: `= NEW.id`）
+fn refresh_product_search_sql(product_condition: &str) -> String {
+    format!(
+        r#"
+        DELETE FROM product_search
+        WHERE product_id IN (SELECT p.id FROM products p WHERE p.id {condition});
+        INSERT INTO product_search (product_id, name, description, sku_names, materials, colors)
+        SELECT
+            p.id,
+            p.name,
+            COALESCE(p.description, ''),
+            COALESCE((SELECT group_concat(s.name, ' ') FROM skus s WHERE s.product_id = p.id), ''),
+            COALESCE((
+                SELECT group_concat(material, ' ') FROM (
+                    SELECT DISTINCT s.material FROM skus s
+                    WHERE s.product_id = p.id AND s.material IS NOT NULL
+                )
+            ), ''),
+            COALESCE((
+                SELECT group_concat(color_name, ' ') FROM (
+                    SELECT DISTINCT c.name as color_name FROM skus s
+                    JOIN colors c ON c.id = s.color_id
+                    WHERE s.product_id = p.id
+                )
+            ), '')
+        FROM products p
+        WHERE p.id {condition};
+        "#,
+        condition = product_condition
+    )
+}
+
+/// Phase 9: 商品検索インデックス作成
+/// 商品名・説明・SKU名・素材・色名をFTS5で全文検索する。
+/// 日本語は単語の区切りに空白がないため、trigramトークナイザで部分一致検索を行う。
+/// インデックスは商品・SKU・色のトリガーで同期する
+async fn create_product_search_index(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE VIRTUAL TABLE IF NOT EXISTS product_search USING fts5(
+            product_id UNINDEXED,
+            name,
+            description,
+            sku_names,
+            materials,
+            colors,
+            tokenize = 'trigram'
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    let triggers = [
+        (
+            "products_search_after_insert",
+            "AFTER INSERT ON products",
+            refresh_product_search_sql("= NEW.id"),
+        ),
+        (
+            "products_search_after_update",
+            "AFTER UPDATE OF name, description ON products",
+            refresh_product_search_sql("= NEW.id"),
+        ),
+        (
+            "products_search_after_delete",
+            "AFTER DELETE ON products",
+            "DELETE FROM product_search WHERE product_id = OLD.id;".to_string(),
+        ),
+        (
+            "skus_search_after_insert",
+            "AFTER INSERT ON skus",
+            refresh_product_search_sql("= NEW.product_id"),
+        ),
+        (
+            "skus_search_after_update",
+            "AFTER UPDATE OF product_id, name, material, color_id ON skus",
+            refresh_product_search_sql("IN (OLD.product_id, NEW.product_id)"),
+        ),
+        (
+            "skus_search_after_delete",
+            "AFTER DELETE ON skus",
+            refresh_product_search_sql("= OLD.product_id"),
+        ),
+        (
+            "colors_search_after_update",
+            "AFTER UPDATE OF name ON colors",
+            refresh_product_search_sql("IN (SELECT product_id FROM skus WHERE color_id = NEW.id)"),
+        ),
+    ];
+
+    for (name, event, body) in &triggers {
+        sqlx::query(&format!(
+            "CREATE TRIGGER IF NOT EXISTS {} {} BEGIN {} END",
+            name, event, body
+        ))
+        .execute(pool)
+        .await?;
+    }
+
+    // 既存データ（トリガー作成前に登録された商品を含む）からインデックスを再構築
+    sqlx::query("DELETE FROM product_search")
+        .execute(pool)
+        .await?;
+    sqlx::raw_sql(&refresh_product_search_sql("IS NOT NULL"))
+        .execute(pool)
+        .await?;
+
+    println!("🔎 Product search index created (product_search)");
+    Ok(())
+}
+
+/// Phase 10: 在庫台帳テーブル作成
+/// SKUの実在庫数の増減をすべて記録し、倉庫の実数と照合できるようにする
+async fn create_stock_movements_table(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS stock_movements (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            sku_id TEXT NOT NULL,
+            movement_type TEXT NOT NULL CHECK (movement_type IN ('receive', 'correction', 'write_off', 'sale', 'restock')),
+            delta INTEGER NOT NULL,
+            stock_after INTEGER NOT NULL,
+            reason TEXT NOT NULL,
+            reference_order_id TEXT,
+            actor TEXT NOT NULL,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (sku_id) REFERENCES skus(id) ON DELETE CASCADE,
+            FOREIGN KEY (reference_order_id) REFERENCES orders(id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_stock_movements_sku_id ON stock_movements(sku_id, id)",
+    )
+    .execute(pool)
+    .await?;
+
+    // 台帳導入前から在庫があるSKUは、現在の在庫数を期首在庫として記録する
+    sqlx::query(
+        r#"
+        INSERT INTO stock_movements (sku_id, movement_type, delta, stock_after, reason, actor)
+        SELECT s.id, 'correction', s.stock_quantity, s.stock_quantity, '期首在庫', 'system'
+        FROM skus s
+        WHERE s.stock_quantity > 0
+          AND NOT EXISTS (SELECT 1 FROM stock_movements m WHERE m.sku_id = s.id)
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    println!("📒 Stock movements table created (stock_movements)");
+    Ok(())
+}
+
+/// Phase 11: 配送情報テーブル作成
+/// 注文に紐づく出荷ごとの配送先・配送業者・追跡番号・配送状態を保持する
+async fn create_delivery_infos_table(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS delivery_infos (
+            id TEXT PRIMARY KEY,
+            order_id TEXT NOT NULL,
+            email TEXT NOT NULL,
+            first_name TEXT NOT NULL,
+            last_name TEXT NOT NULL,
+            phone TEXT NOT NULL,
+            postal_code TEXT NOT NULL,
+            prefecture TEXT NOT NULL,
+            city TEXT NOT NULL,
+            street TEXT NOT NULL,
+            building TEXT,
+            status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'processing', 'shipped', 'in_transit', 'delivered', 'failed')),
+            carrier TEXT,
+            tracking_number TEXT,
+            shipping_method TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            shipped_at TEXT,
+            delivered_at TEXT,
+            FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_delivery_infos_order_id ON delivery_infos(order_id)",
+    )
+    .execute(pool)
+    .await?;
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_delivery_infos_tracking_number ON delivery_infos(tracking_number)",
+    )
+    .execute(pool)
+    .await?;
+
+    println!("🚚 Delivery infos table created (delivery_infos)");
+    Ok(())
+}
+
+/// Phase 12: 決済テーブル作成
+/// payments: 注文ごとの決済操作（オーソリ・売上確定・取消・返金）の試行と結果
+/// mock_payment_gateway_transactions: ローカル開発用のモック決済代行会社が保持する取引
+async fn create_payment_tables(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS payments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            order_id TEXT NOT NULL,
+            provider TEXT NOT NULL,
+            operation TEXT NOT NULL CHECK (operation IN ('authorize', 'capture', 'void', 'refund')),
+            amount INTEGER NOT NULL CHECK (amount >= 0),
+            status TEXT NOT NULL CHECK (status IN ('succeeded', 'failed')),
+            transaction_id TEXT,
+            failure_reason TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_payments_order_id ON payments(order_id, id)")
+        .execute(pool)
+        .await?;
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_payments_transaction_id ON payments(transaction_id)",
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS mock_payment_gateway_transactions (
+            seq INTEGER PRIMARY KEY AUTOINCREMENT,
+            transaction_id TEXT NOT NULL UNIQUE,
+            order_id TEXT NOT NULL,
+            status TEXT NOT NULL CHECK (status IN ('authorized', 'captured', 'voided', 'refunded')),
+            authorized_amount INTEGER NOT NULL,
+            captured_amount INTEGER NOT NULL DEFAULT 0,
+            refunded_amount INTEGER NOT NULL DEFAULT 0,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    println!("💳 Payment tables created (payments, mock_payment_gateway_transactions)");
+    Ok(())
+}
+
+/// Phase 13: 支払い手数料ルール作成
+/// 計算方式（定額・割合・金額帯）と最低額・最高額はpayment_methodsに、金額帯はpayment_fee_bracketsに保持する
+async fn create_payment_fee_rules(pool: &sqlx::SqlitePool) -> Result<()> {
+    let fee_type_added = add_column_if_missing(
+        pool,
+        "payment_methods",
+        "fee_type",
+        "TEXT NOT NULL DEFAULT 'flat' CHECK (fee_type IN ('flat', 'percentage', 'tiered'))",
+    )
+    .await?;
+    add_column_if_missing(
+        pool,
+        "payment_methods",
+        "fee_amount",
+        "INTEGER NOT NULL DEFAULT 0",
+    )
+    .await?;
+    add_column_if_missing(
+        pool,
+        "payment_methods",
+        "fee_rate",
+        "REAL NOT NULL DEFAULT 0",
+    )
+    .await?;
+    add_column_if_missing(pool, "payment_methods", "min_fee", "INTEGER").await?;
+    add_column_if_missing(pool, "payment_methods", "max_fee", "INTEGER").await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS payment_fee_brackets (
+            payment_method_id TEXT NOT NULL,
+            position INTEGER NOT NULL,
+            up_to INTEGER,
+            fee INTEGER NOT NULL CHECK (fee >= 0),
+            PRIMARY KEY (payment_method_id, position),
+            FOREIGN KEY (payment_method_id) REFERENCES payment_methods(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // 初回のみ、これまでコードで計算していた手数料をルールとして登録する
+    // （以降は管理APIで変更された内容を上書きしない）
+    if fee_type_added {
+        sqlx::query(
+            "UPDATE payment_methods SET fee_type = 'flat', fee_amount = 200 WHERE id = 'convenience_store'",
+        )
+        .execute(pool)
+        .await?;
+        sqlx::query("UPDATE payment_methods SET fee_type = 'tiered' WHERE id = 'cod'")
+            .execute(pool)
+            .await?;
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO payment_fee_brackets (payment_method_id, position, up_to, fee)
+            SELECT 'cod', position, up_to, fee
+            FROM (
+                SELECT 1 AS position, 10000 AS up_to, 330 AS fee
+                UNION ALL SELECT 2, 30000, 440
+                UNION ALL SELECT 3, 100000, 660
+                UNION ALL SELECT 4, 300000, 1100
+                UNION ALL SELECT 5, NULL, 1650
+            )
+            WHERE EXISTS (SELECT 1 FROM payment_methods WHERE id = 'cod')
+            "#,
+        )
+        .execute(pool)
+        .await?;
+    }
+
+    println!("💴 Payment fee rules created (payment_methods fee columns, payment_fee_brackets)");
+    Ok(())
+}
+
+/// Phase 14: 配送料金表作成
+/// 送料無料条件・大型商品の追加料金・即日出荷限定はshipping_methodsに、地域別料金はshipping_region_ratesに保持する
+async fn create_shipping_rate_tables(pool: &sqlx::SqlitePool) -> Result<()> {
+    let threshold_added = add_column_if_missing(
+        pool,
+        "shipping_methods",
+        "free_shipping_threshold",
+        "INTEGER CHECK (free_shipping_threshold >= 0)",
+    )
+    .await?;
+    add_column_if_missing(pool, "shipping_methods", "oversize_min_size", "REAL").await?;
+    add_column_if_missing(
+        pool,
+        "shipping_methods",
+        "oversize_surcharge",
+        "INTEGER NOT NULL DEFAULT 0 CHECK (oversize_surcharge >= 0)",
+    )
+    .await?;
+    add_column_if_missing(
+        pool,
+        "shipping_methods",
+        "quick_ship_only",
+        "BOOLEAN NOT NULL DEFAULT FALSE",
+    )
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS shipping_region_rates (
+            shipping_method_id TEXT NOT NULL,
+            region TEXT NOT NULL CHECK (region IN (
+                'hokkaido', 'tohoku', 'kanto', 'chubu', 'kinki',
+                'chugoku', 'shikoku', 'kyushu', 'okinawa'
+            )),
+            fee INTEGER NOT NULL CHECK (fee >= 0),
+            PRIMARY KEY (shipping_method_id, region),
+            FOREIGN KEY (shipping_method_id) REFERENCES shipping_methods(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // 初回のみ初期の料金表を登録する（以降に変更された内容を上書きしない）
+    // 3辺合計の単位はSKUの寸法表記（インチ）に合わせる
+    if threshold_added {
+        sqlx::query(
+            r#"
+            UPDATE shipping_methods SET
+                free_shipping_threshold = CASE id
+                    WHEN 'standard' THEN 10000
+                    WHEN 'express' THEN 30000
+                END,
+                oversize_min_size = 100,
+                oversize_surcharge = CASE id
+                    WHEN 'standard' THEN 1000
+                    WHEN 'express' THEN 1500
+                    ELSE 2000
+                END,
+                quick_ship_only = (id = 'overnight')
+            WHERE id IN ('standard', 'express', 'overnight')
+            "#,
+        )
+        .execute(pool)
+        .await?;
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO shipping_region_rates (shipping_method_id, region, fee)
+            SELECT shipping_method_id, region, fee
+            FROM (
+                SELECT 'standard' AS shipping_method_id, 'hokkaido' AS region, 1000 AS fee
+                UNION ALL SELECT 'standard', 'kyushu', 700
+                UNION ALL SELECT 'standard', 'okinawa', 1500
+                UNION ALL SELECT 'express', 'hokkaido', 1500
+                UNION ALL SELECT 'express', 'kyushu', 1200
+                UNION ALL SELECT 'express', 'okinawa', 2500
+                UNION ALL SELECT 'overnight', 'hokkaido', 2500
+                UNION ALL SELECT 'overnight', 'okinawa', 3500
+            )
+            WHERE shipping_method_id IN (SELECT id FROM shipping_methods)
+            "#,
+        )
+        .execute(pool)
+        .await?;
+    }
+
+    println!(
+        "🚚 Shipping rate tables created (shipping_methods rate columns, shipping_region_rates)"
+    );
+    Ok(())
+}
+
+/// Phase 15: 税区分・税率別内訳作成
+/// SKUと注文アイテムに税区分を持たせ、注文ごとの税率別の対象額・税額をorder_tax_breakdownsに保持する
+async fn create_tax_tables(pool: &sqlx::SqlitePool) -> Result<()> {
+    add_column_if_missing(
+        pool,
+        "skus",
+        "tax_category",
+        "TEXT NOT NULL DEFAULT 'standard' CHECK (tax_category IN ('standard', 'reduced'))",
+    )
+    .await?;
+    add_column_if_missing(
+        pool,
+        "order_items",
+        "tax_category",
+        "TEXT NOT NULL DEFAULT 'standard' CHECK (tax_category IN ('standard', 'reduced'))",
+    )
+    .await?;
+    let prices_include_tax_added = add_column_if_missing(
+        pool,
+        "orders",
+        "prices_include_tax",
+        "BOOLEAN NOT NULL DEFAULT FALSE",
+    )
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS order_tax_breakdowns (
+            order_id TEXT NOT NULL,
+            rate INTEGER NOT NULL CHECK (rate >= 0 AND rate <= 100),
+            taxable_amount INTEGER NOT NULL CHECK (taxable_amount >= 0),
+            tax_amount INTEGER NOT NULL CHECK (tax_amount >= 0),
+            PRIMARY KEY (order_id, rate),
+            FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // 既存の注文はすべて標準税率10%・税抜価格で計算されているため、1行の内訳として登録する
+    if prices_include_tax_added {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO order_tax_breakdowns (order_id, rate, taxable_amount, tax_amount)
+            SELECT id, 10, total_amount - tax_amount, tax_amount
+            FROM orders
+            WHERE total_amount > 0
+            "#,
+        )
+        .execute(pool)
+        .await?;
+    }
+
+    println!("🧾 Tax tables created (skus/order_items tax_category, order_tax_breakdowns)");
+    Ok(())
+}
+
+/// Phase 16: クーポン使用履歴作成
+/// 顧客（メールアドレス）ごとの使用上限を判定するため、注文ごとのクーポン使用を記録する
+async fn create_coupon_redemptions_table(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS coupon_redemptions (
+            coupon_id TEXT NOT NULL,
+            order_id TEXT NOT NULL,
+            customer_email TEXT NOT NULL,
+            redeemed_at TEXT NOT NULL DEFAULT (datetime('now')),
+            PRIMARY KEY (coupon_id, order_id),
+            FOREIGN KEY (coupon_id) REFERENCES coupons(id) ON DELETE CASCADE,
+            FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_coupon_redemptions_customer ON coupon_redemptions(coupon_id, customer_email)",
+    )
+    .execute(pool)
+    .await?;
+
+    // 既存の注文のクーポン使用も顧客ごとの使用回数に含める
+    sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO coupon_redemptions (coupon_id, order_id, customer_email, redeemed_at)
+        SELECT o.coupon_id, o.id, lower(o.customer_email), o.created_at
+        FROM orders o
+        JOIN coupons c ON c.id = o.coupon_id
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    println!("🎫 Coupon redemptions table created");
+    Ok(())
+}
+
+/// Phase 17: クーポン適用条件・明細別割引額作成
+/// 商品・カテゴリ指定のクーポンは対象をcoupon_conditionsに保存し、
+/// 注文明細には明細ごとに配分した割引額を記録する
+async fn create_coupon_conditions_table(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS coupon_conditions (
+            coupon_id TEXT NOT NULL,
+            condition_type TEXT NOT NULL,
+            target_id TEXT NOT NULL,
+            PRIMARY KEY (coupon_id, condition_type, target_id),
+            FOREIGN KEY (coupon_id) REFERENCES coupons(id) ON DELETE CASCADE,
+
+            CONSTRAINT valid_condition_type CHECK (condition_type IN ('product', 'category'))
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_coupon_conditions_target ON coupon_conditions(condition_type, target_id)",
+    )
+    .execute(pool)
+    .await?;
+
+    add_column_if_missing(
+        pool,
+        "order_items",
+        "discount_amount",
+        "INTEGER NOT NULL DEFAULT 0",
+    )
+    .await?;
+
+    println!("🎫 Coupon conditions table created (order_items discount_amount)");
+    Ok(())
+}
+
+/// Phase 18: プロモーション・注文のプロモーション適用履歴作成
+/// 割引内容のカラムはcouponsと同じ形式で、対象商品・カテゴリはpromotion_conditionsに保存する
+/// 実施期間（starts_at, ends_at）はRFC3339形式の日時で、時刻単位で指定できる
+async fn create_promotion_tables(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS promotions (
+            id TEXT PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL,
+            description TEXT,
+            discount_type TEXT NOT NULL,
+            discount_value INTEGER NOT NULL,
+            minimum_amount INTEGER,
+            max_discount_amount INTEGER,
+            buy_quantity INTEGER,
+            get_quantity INTEGER,
+            minimum_quantity INTEGER,
+            priority INTEGER NOT NULL DEFAULT 0,
+            stackable BOOLEAN NOT NULL DEFAULT 1,
+            is_active BOOLEAN NOT NULL DEFAULT 1,
+            starts_at TEXT NOT NULL,
+            ends_at TEXT NOT NULL,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+
+            CONSTRAINT valid_discount_type CHECK (discount_type IN (
+                'percentage', 'fixed_amount', 'capped_percentage', 'free_shipping', 'buy_x_get_y'
+            )),
+            CONSTRAINT positive_discount_value CHECK (discount_value >= 0),
+            CONSTRAINT positive_minimum_amount CHECK (minimum_amount IS NULL OR minimum_amount >= 0),
+            CONSTRAINT positive_minimum_quantity CHECK (minimum_quantity IS NULL OR minimum_quantity > 0),
+            CONSTRAINT valid_period CHECK (starts_at <= ends_at)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_promotions_period ON promotions(is_active, starts_at, ends_at)",
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS promotion_conditions (
+            promotion_id TEXT NOT NULL,
+            condition_type TEXT NOT NULL,
+            target_id TEXT NOT NULL,
+            PRIMARY KEY (promotion_id, condition_type, target_id),
+            FOREIGN KEY (promotion_id) REFERENCES promotions(id) ON DELETE CASCADE,
+
+            CONSTRAINT valid_condition_type CHECK (condition_type IN ('product', 'category'))
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // プロモーションが削除・変更されても注文時の内容を残すため、名称と割引額を保存する
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS order_promotions (
+            order_id TEXT NOT NULL,
+            promotion_id TEXT NOT NULL,
+            promotion_name TEXT NOT NULL,
+            discount_amount INTEGER NOT NULL DEFAULT 0,
+            waives_shipping BOOLEAN NOT NULL DEFAULT 0,
+            sort_order INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (order_id, promotion_id),
+            FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE,
+
+            CONSTRAINT positive_discount_amount CHECK (discount_amount >= 0)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    println!("🏷️ Promotion tables created (promotions, promotion_conditions, order_promotions)");
+    Ok(())
+}
+
+/// Phase 19: SKUの価格スケジュール作成
+/// skus.sale_price（手動のセール価格）とは別に、期間指定のセール価格を保存する
+/// 期間（starts_at, ends_at）はRFC3339形式の日時で、開始日時以降・終了日時より前の間だけ適用する
+async fn create_price_schedules_table(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS price_schedules (
+            sku_id TEXT NOT NULL,
+            sale_price INTEGER NOT NULL,
+            starts_at TEXT NOT NULL,
+            ends_at TEXT NOT NULL,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            PRIMARY KEY (sku_id, starts_at),
+            FOREIGN KEY (sku_id) REFERENCES skus(id) ON DELETE CASCADE,
+
+            CONSTRAINT positive_sale_price CHECK (sale_price > 0),
+            CONSTRAINT valid_period CHECK (starts_at < ends_at)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_price_schedules_period ON price_schedules(starts_at, ends_at)",
+    )
+    .execute(pool)
+    .await?;
+
+    println!("💴 Price schedules table created");
+    Ok(())
+}
+
+/// Phase 20: 会員・ログインセッション作成
+/// メールアドレスは大文字・小文字を区別せずに一意とし、パスワードはハッシュのみを保存する
+/// 会員としてログイン中に作成した注文はorders.customer_idで会員に紐付ける（ゲスト注文はNULL）
+async fn create_customer_tables(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS customers (
+            id TEXT PRIMARY KEY NOT NULL,
+            email TEXT NOT NULL UNIQUE COLLATE NOCASE,
+            password_hash TEXT NOT NULL,
+            first_name TEXT NOT NULL,
+            last_name TEXT NOT NULL,
+            phone TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS customer_sessions (
+            id TEXT PRIMARY KEY NOT NULL,
+            customer_id TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            expires_at TEXT NOT NULL,
+            revoked_at TEXT,
+            FOREIGN KEY (customer_id) REFERENCES customers(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_customer_sessions_customer_id ON customer_sessions(customer_id)",
+    )
+    .execute(pool)
+    .await?;
+
+    add_column_if_missing(
+        pool,
+        "orders",
+        "customer_id",
+        "TEXT REFERENCES customers(id)",
+    )
+    .await?;
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_orders_customer_id ON orders(customer_id)")
+        .execute(pool)
+        .await?;
+
+    println!("👤 Customer tables created (customers, customer_sessions)");
+    Ok(())
+}
+
+/// Phase 21: 冪等キー作成
+/// Idempotency-Keyヘッダー付きリクエストのハッシュとレスポンスを保存し、再送時に同じ結果を返す
+/// 日時は固定長のRFC3339（マイクロ秒・UTC）で保存し、文字列比較で期限切れを判定する
+async fn create_idempotency_keys_table(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS idempotency_keys (
+            key TEXT PRIMARY KEY,
+            request_hash TEXT NOT NULL,
+            status_code INTEGER,
+            content_type TEXT,
+            response_body BLOB,
+            created_at TEXT NOT NULL,
+            expires_at TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_idempotency_keys_expires_at ON idempotency_keys(expires_at)",
+    )
+    .execute(pool)
+    .await?;
+
+    println!("🔁 Idempotency keys table created");
+    Ok(())
+}
+
+/// Phase 22: 注文番号の連番作成
+/// 連番はスコープ（プレフィックスと年・月。例: ORD-2024）ごとに保持し、注文の保存と同じトランザクションで進める
+/// 既存の注文がある場合は、従来の書式（ORD-YYYY-NNNNNN）の最大値から連番を引き継ぐ
+async fn create_order_sequences_table(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS order_sequences (
+            scope TEXT PRIMARY KEY,
+            last_value INTEGER NOT NULL CHECK (last_value >= 0)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO order_sequences (scope, last_value)
+        SELECT substr(order_number, 1, 8), MAX(CAST(substr(order_number, 10) AS INTEGER))
+        FROM orders
+        WHERE order_number GLOB 'ORD-[0-9][0-9][0-9][0-9]-[0-9][0-9][0-9][0-9][0-9][0-9]'
+        GROUP BY substr(order_number, 1, 8)
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    println!("🔢 Order sequences table created");
+    Ok(())
+}
+
+/// Phase 23: 返品（RMA）作成
+/// 返品は注文ごとに複数作成でき、明細は注文アイテムのSKUと数量・返金額（割引・消費税を按分済み）を保持する
+/// 金額は申請時に計算し、受領時の商品状態に応じて再入荷手数料と返金額を更新する
+async fn create_returns_tables(pool: &sqlx::SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS returns (
+            id TEXT PRIMARY KEY NOT NULL,
+            order_id TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'requested',
+            reason TEXT NOT NULL,
+            item_condition TEXT NOT NULL,
+            note TEXT,
+            rejection_reason TEXT,
+            items_amount INTEGER NOT NULL CHECK (items_amount >= 0),
+            tax_amount INTEGER NOT NULL CHECK (tax_amount >= 0),
+            tax_included BOOLEAN NOT NULL DEFAULT FALSE,
+            restocking_fee_rate INTEGER NOT NULL CHECK (restocking_fee_rate BETWEEN 0 AND 100),
+            restocking_fee INTEGER NOT NULL CHECK (restocking_fee >= 0),
+            refund_amount INTEGER NOT NULL CHECK (refund_amount >= 0),
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            approved_at TEXT,
+            received_at TEXT,
+            refunded_at TEXT,
+            rejected_at TEXT,
+            FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE,
+            CONSTRAINT valid_return_status CHECK (status IN (
+                'requested', 'approved', 'received', 'refunded', 'rejected'
+            ))
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_returns_order_id ON returns(order_id)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS return_items (
+            return_id TEXT NOT NULL,
+            sku_id TEXT NOT NULL,
+            sku_code TEXT NOT NULL,
+            product_name TEXT NOT NULL,
+            quantity INTEGER NOT NULL CHECK (quantity > 0),
+            tax_rate INTEGER NOT NULL,
+            amount INTEGER NOT NULL CHECK (amount >= 0),
+            tax_amount INTEGER NOT NULL CHECK (tax_amount >= 0),
+            PRIMARY KEY (return_id, sku_id),
+            FOREIGN KEY (return_id) REFERENCES returns(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    println!("↩️ Returns tables created (returns, return_items)");
+    Ok(())
+}
+
+/// Phase 24: 請求書・領収書の発行回数
+/// 2回目以降の発行は帳票に再発行と明記するため、帳票の種類ごとの発行回数を注文に保持する
+async fn add_document_issue_counts(pool: &sqlx::SqlitePool) -> Result<()> {
+    add_column_if_missing(
+        pool,
+        "orders",
+        "invoice_issue_count",
+        "INTEGER NOT NULL DEFAULT 0",
+    )
+    .await?;
+    add_column_if_missing(
+        pool,
+        "orders",
+        "receipt_issue_count",
+        "INTEGER NOT NULL DEFAULT 0",
+    )
+    .await?;
+
+    println!("🧾 Document issue counts added to orders");
+    Ok(())
+}
+
+/// Phase 25: 注文のバージョン
+/// 読み込み時のバージョンを条件に更新し、同時に行われたステータス変更などを競合として検出する
+async fn add_order_version(pool: &sqlx::SqlitePool) -> Result<()> {
+    add_column_if_missing(pool, "orders", "version", "INTEGER NOT NULL DEFAULT 0").await?;
+
+    println!("🔢 Version added to orders");
+    Ok(())
+}
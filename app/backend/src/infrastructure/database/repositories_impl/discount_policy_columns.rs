@@ -0,0 +1,140 @@
+use uuid::Uuid;
+
+use crate::domain::value_objects::{
+    CategoryId, DiscountCondition, DiscountPolicy, DiscountType, Money, ProductId,
+};
+
+/// 割引ポリシーのカラム値（クーポン・プロモーション共通）
+/// 割引内容は discount_type 以下のカラムに、最低購入金額は minimum_amount に、
+/// 対象商品・対象カテゴリは条件テーブルの行（condition_type, target_id）に保存する
+pub(super) struct DiscountPolicyColumns {
+    pub discount_type: &'static str,
+    pub discount_value: i64,
+    pub max_discount_amount: Option<i64>,
+    pub buy_quantity: Option<i64>,
+    pub get_quantity: Option<i64>,
+    pub minimum_amount: Option<i64>,
+    pub conditions: Vec<(&'static str, String)>,
+}
+
+impl DiscountPolicyColumns {
+    /// 割引ポリシーをカラムの値に変換
+    pub fn from_policy(policy: &DiscountPolicy) -> Self {
+        let discount_type = policy.discount_type();
+        let (discount_value, max_discount_amount, buy_quantity, get_quantity) = match discount_type
+        {
+            DiscountType::FixedAmount(amount) => (amount.yen() as i64, None, None, None),
+            DiscountType::Percentage(percentage) => (*percentage as i64, None, None, None),
+            DiscountType::CappedPercentage {
+                percentage,
+                max_discount,
+            } => (
+                *percentage as i64,
+                Some(max_discount.yen() as i64),
+                None,
+                None,
+            ),
+            DiscountType::FreeShipping => (0, None, None, None),
+            DiscountType::BuyXGetY {
+                buy_quantity,
+                get_quantity,
+            } => (
+                0,
+                None,
+                Some(*buy_quantity as i64),
+                Some(*get_quantity as i64),
+            ),
+        };
+
+        let (minimum_amount, conditions) = match policy.discount_condition() {
+            Some(DiscountCondition::MinimumPurchase(amount)) => {
+                (Some(amount.yen() as i64), Vec::new())
+            }
+            Some(DiscountCondition::ProductSpecific(product_ids)) => (
+                None,
+                product_ids
+                    .iter()
+                    .map(|id| ("product", id.value().to_string()))
+                    .collect(),
+            ),
+            Some(DiscountCondition::CategorySpecific(category_ids)) => (
+                None,
+                category_ids
+                    .iter()
+                    .map(|id| ("category", id.value().to_string()))
+                    .collect(),
+            ),
+            None => (None, Vec::new()),
+        };
+
+        Self {
+            discount_type: discount_type.code(),
+            discount_value,
+            max_discount_amount,
+            buy_quantity,
+            get_quantity,
+            minimum_amount,
+            conditions,
+        }
+    }
+}
+
+/// カラムの値から割引内容を復元（割引種別に必要な値がなければエラーメッセージを返す）
+pub(super) fn discount_type_from_columns(
+    discount_type: &str,
+    discount_value: i64,
+    max_discount_amount: Option<i64>,
+    buy_quantity: Option<i64>,
+    get_quantity: Option<i64>,
+) -> Result<DiscountType, String> {
+    match discount_type {
+        "percentage" => Ok(DiscountType::Percentage(discount_value as u8)),
+        "fixed_amount" => Ok(DiscountType::FixedAmount(Money::from_yen(
+            discount_value as u32,
+        ))),
+        "capped_percentage" => Ok(DiscountType::CappedPercentage {
+            percentage: discount_value as u8,
+            max_discount: Money::from_yen(
+                max_discount_amount
+                    .ok_or("Capped percentage discount has no max_discount_amount")?
+                    as u32,
+            ),
+        }),
+        "free_shipping" => Ok(DiscountType::FreeShipping),
+        "buy_x_get_y" => Ok(DiscountType::BuyXGetY {
+            buy_quantity: buy_quantity.ok_or("Buy X get Y discount has no buy_quantity")? as u32,
+            get_quantity: get_quantity.ok_or("Buy X get Y discount has no get_quantity")? as u32,
+        }),
+        other => Err(format!("Unknown discount type: {}", other)),
+    }
+}
+
+/// 条件テーブルの行（condition_type, target_id）と最低購入金額から適用条件を復元
+/// 商品・カテゴリ指定がなければ最低購入金額を条件とする
+pub(super) fn discount_condition_from_columns(
+    conditions: &[(String, String)],
+    minimum_amount: Option<i64>,
+) -> Result<Option<DiscountCondition>, String> {
+    let product_ids = conditions
+        .iter()
+        .filter(|(condition_type, _)| condition_type == "product")
+        .map(|(_, target_id)| {
+            Uuid::parse_str(target_id)
+                .map(ProductId::from_uuid)
+                .map_err(|e| format!("Invalid target product ID: {}", e))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let category_ids = conditions
+        .iter()
+        .filter(|(condition_type, _)| condition_type == "category")
+        .map(|(_, target_id)| CategoryId::new(target_id.clone()).map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(if !product_ids.is_empty() {
+        Some(DiscountCondition::ProductSpecific(product_ids))
+    } else if !category_ids.is_empty() {
+        Some(DiscountCondition::CategorySpecific(category_ids))
+    } else {
+        minimum_amount.map(|a| DiscountCondition::MinimumPurchase(Money::from_yen(a as u32)))
+    })
+}
@@ -1,17 +1,38 @@
+mod discount_policy_columns;
+mod row_helpers;
+mod sku_price_sql;
+mod sqlite_cart_repository;
 mod sqlite_category_repository;
 mod sqlite_color_repository;
 mod sqlite_coupon_repository;
+mod sqlite_customer_repository;
+mod sqlite_delivery_info_repository;
+mod sqlite_idempotency_key_repository;
 mod sqlite_order_repository;
 mod sqlite_payment_method_repository;
+mod sqlite_payment_repository;
 mod sqlite_product_repository;
+mod sqlite_product_write_repository;
+mod sqlite_promotion_repository;
+mod sqlite_return_repository;
 mod sqlite_shipping_method_repository;
+mod sqlite_stock_movement_repository;
 mod sqlite_variant_repository;
 
+pub use self::sqlite_cart_repository::SqliteCartRepository;
 pub use self::sqlite_category_repository::SqliteCategoryRepository;
 pub use self::sqlite_color_repository::SqliteColorRepository;
 pub use self::sqlite_coupon_repository::SqliteCouponRepository;
+pub use self::sqlite_customer_repository::SqliteCustomerRepository;
+pub use self::sqlite_delivery_info_repository::SqliteDeliveryInfoRepository;
+pub use self::sqlite_idempotency_key_repository::SqliteIdempotencyKeyRepository;
 pub use self::sqlite_order_repository::SqliteOrderRepository;
 pub use self::sqlite_payment_method_repository::SqlitePaymentMethodRepository;
+pub use self::sqlite_payment_repository::SqlitePaymentRepository;
 pub use self::sqlite_product_repository::SqliteProductRepository;
+pub use self::sqlite_product_write_repository::SqliteProductWriteRepository;
+pub use self::sqlite_promotion_repository::SqlitePromotionRepository;
+pub use self::sqlite_return_repository::SqliteReturnRepository;
 pub use self::sqlite_shipping_method_repository::SqliteShippingMethodRepository;
+pub use self::sqlite_stock_movement_repository::SqliteStockMovementRepository;
 pub use self::sqlite_variant_repository::SqliteVariantRepository;
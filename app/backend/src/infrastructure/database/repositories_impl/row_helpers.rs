@@ -0,0 +1,25 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::Row;
+use sqlx::sqlite::SqliteRow;
+
+use crate::application::error::RepositoryError;
+
+/// カラム値を取得し、失敗時はDataConversionErrorに変換
+pub(super) fn get_column<'r, T>(row: &'r SqliteRow, column: &str) -> Result<T, RepositoryError>
+where
+    T: sqlx::Decode<'r, sqlx::Sqlite> + sqlx::Type<sqlx::Sqlite>,
+{
+    row.try_get(column)
+        .map_err(|e| RepositoryError::DataConversionError(format!("{}: {}", column, e)))
+}
+
+/// RFC3339形式またはSQLiteのdatetime('now')形式の日時文字列をパース
+pub(super) fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, RepositoryError> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+        .map(|naive| naive.and_utc())
+        .map_err(|e| RepositoryError::DataConversionError(format!("{}: {}", value, e)))
+}
@@ -0,0 +1,14 @@
+/// SKU（エイリアス s）の現在のセール価格を求めるSQL式
+/// 期間中の価格スケジュールがあればその価格、なければ手動のセール価格（skus.sale_price）を使う
+/// タイムゾーン表記の違いがあっても比較できるよう、datetime()で正規化して比較する
+pub(super) const EFFECTIVE_SALE_PRICE_SQL: &str = r#"COALESCE(
+    (
+        SELECT ps.sale_price FROM price_schedules ps
+        WHERE ps.sku_id = s.id
+          AND datetime(ps.starts_at) <= datetime('now')
+          AND datetime(ps.ends_at) > datetime('now')
+        ORDER BY ps.starts_at DESC
+        LIMIT 1
+    ),
+    s.sale_price
+)"#;
@@ -0,0 +1,225 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use sqlx::{Row, SqlitePool};
+use uuid::Uuid;
+
+use crate::application::error::RepositoryError;
+use crate::application::repositories::CartRepository;
+use crate::domain::value_objects::*;
+use crate::domain::{Cart, CartItem};
+
+use super::sku_price_sql::EFFECTIVE_SALE_PRICE_SQL;
+
+/// SQLite実装のCartRepository
+/// Clean Architecture: Frameworks & Drivers層
+pub struct SqliteCartRepository {
+    pool: SqlitePool,
+    /// 最終更新からカートが失効するまでの期間
+    ttl: Duration,
+}
+
+impl SqliteCartRepository {
+    pub fn new(pool: SqlitePool, ttl: Duration) -> Self {
+        Self { pool, ttl }
+    }
+
+    /// SQLiteのdatetime関数に渡す有効期限の修飾子（例: "+259200 seconds"）
+    fn expires_modifier(&self) -> String {
+        format!("+{} seconds", self.ttl.num_seconds())
+    }
+
+    fn query_error(context: &str, e: impl std::fmt::Display) -> RepositoryError {
+        RepositoryError::QueryExecution(format!("[SqliteCartRepository::{}] {}", context, e))
+    }
+
+    fn parse_uuid(value: &str, field: &str) -> Result<Uuid, RepositoryError> {
+        Uuid::parse_str(value).map_err(|e| {
+            RepositoryError::QueryExecution(format!("Invalid {} '{}': {}", field, value, e))
+        })
+    }
+}
+
+#[async_trait]
+impl CartRepository for SqliteCartRepository {
+    async fn save(&self, cart: &Cart) -> Result<(), RepositoryError> {
+        let cart_id = cart.id().value().to_string();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO carts (id, shipping_method_id, payment_method_id, coupon_code, expires_at)
+            VALUES (?1, ?2, ?3, ?4, datetime('now', ?5))
+            ON CONFLICT(id) DO UPDATE SET
+                shipping_method_id = excluded.shipping_method_id,
+                payment_method_id = excluded.payment_method_id,
+                coupon_code = excluded.coupon_code,
+                updated_at = datetime('now'),
+                expires_at = excluded.expires_at
+            "#,
+        )
+        .bind(&cart_id)
+        .bind(cart.shipping_method_id().map(|id| id.value().to_string()))
+        .bind(cart.payment_method_id().map(|id| id.value().to_string()))
+        .bind(cart.coupon_code().map(|code| code.value().to_string()))
+        .bind(self.expires_modifier())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Self::query_error("save", e))?;
+
+        // アイテムは丸ごと置き換える（並び順はpositionで保持）
+        sqlx::query("DELETE FROM cart_items WHERE cart_id = ?1")
+            .bind(&cart_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("save", e))?;
+
+        for (position, item) in cart.items().iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO cart_items (cart_id, sku_id, quantity, position)
+                VALUES (?1, ?2, ?3, ?4)
+                "#,
+            )
+            .bind(&cart_id)
+            .bind(item.sku_id().value().to_string())
+            .bind(item.quantity() as i64)
+            .bind(position as i64)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("save", e))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &CartId) -> Result<Option<Cart>, RepositoryError> {
+        let cart_id = id.value().to_string();
+
+        let row = sqlx::query(
+            r#"
+            SELECT shipping_method_id, payment_method_id, coupon_code
+            FROM carts
+            WHERE id = ?1 AND expires_at > datetime('now')
+            "#,
+        )
+        .bind(&cart_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_by_id", e))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        // 商品名・価格は現在のSKU情報から取得する（期間中の価格スケジュール・セール価格があればそれを使用）
+        let item_query = format!(
+            r#"
+            SELECT
+                ci.sku_id, ci.quantity, s.product_id, s.name, s.tax_category, p.category_id,
+                COALESCE({}, s.base_price) as unit_price
+            FROM cart_items ci
+            JOIN skus s ON s.id = ci.sku_id
+            JOIN products p ON p.id = s.product_id
+            WHERE ci.cart_id = ?1
+            ORDER BY ci.position
+            "#,
+            EFFECTIVE_SALE_PRICE_SQL
+        );
+        let item_rows = sqlx::query(&item_query)
+            .bind(&cart_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| Self::query_error("find_by_id", e))?;
+
+        let mut items = Vec::with_capacity(item_rows.len());
+        for item_row in item_rows {
+            let sku_id: String = item_row.get("sku_id");
+            let product_id: String = item_row.get("product_id");
+            let name: String = item_row.get("name");
+            let unit_price: i64 = item_row.get("unit_price");
+            let quantity: i64 = item_row.get("quantity");
+            let tax_category: String = item_row.get("tax_category");
+            let category_id: String = item_row.get("category_id");
+
+            let item = CartItem::new(
+                SKUId::from_uuid(Self::parse_uuid(&sku_id, "sku_id")?),
+                ProductId::from_uuid(Self::parse_uuid(&product_id, "product_id")?),
+                ProductName::new(name).map_err(|e| Self::query_error("find_by_id", e))?,
+                Money::from_yen(unit_price as u32),
+                quantity as u32,
+            )
+            .map_err(|e| Self::query_error("find_by_id", e))?
+            .with_tax_category(
+                tax_category
+                    .parse()
+                    .map_err(|e| Self::query_error("find_by_id", e))?,
+            )
+            .with_category_id(
+                CategoryId::new(category_id).map_err(|e| Self::query_error("find_by_id", e))?,
+            );
+            items.push(item);
+        }
+
+        let shipping_method_id = row
+            .get::<Option<String>, _>("shipping_method_id")
+            .map(ShippingMethodId::new)
+            .transpose()
+            .map_err(|e| Self::query_error("find_by_id", e))?;
+        let payment_method_id = row
+            .get::<Option<String>, _>("payment_method_id")
+            .map(PaymentMethodId::new)
+            .transpose()
+            .map_err(|e| Self::query_error("find_by_id", e))?;
+        let coupon_code = row
+            .get::<Option<String>, _>("coupon_code")
+            .map(CouponCode::from_string)
+            .transpose()
+            .map_err(|e| Self::query_error("find_by_id", e))?;
+
+        Ok(Some(Cart::restore(
+            *id,
+            items,
+            shipping_method_id,
+            payment_method_id,
+            coupon_code,
+        )))
+    }
+
+    async fn delete_expired(&self) -> Result<u64, RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM cart_items
+            WHERE cart_id IN (SELECT id FROM carts WHERE expires_at <= datetime('now'))
+            "#,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Self::query_error("delete_expired", e))?;
+
+        let result = sqlx::query("DELETE FROM carts WHERE expires_at <= datetime('now')")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("delete_expired", e))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,12 +1,20 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
 use sqlx::{Row, SqlitePool};
 use uuid::Uuid;
 
+use crate::application::error::RepositoryError;
 use crate::application::repositories::CouponRepository;
 use crate::domain::entities::Coupon;
 use crate::domain::error::DomainError;
-use crate::domain::value_objects::{CouponCode, CouponId, DiscountCondition, DiscountPolicy, DiscountType, Money};
+use crate::domain::value_objects::{CouponCode, CouponId, DiscountPolicy, OrderId};
+
+use super::discount_policy_columns::{
+    DiscountPolicyColumns, discount_condition_from_columns, discount_type_from_columns,
+};
 
 /// SQLite実装のCouponRepository
 /// Clean Architecture: Frameworks & Drivers層
@@ -14,10 +22,259 @@ pub struct SqliteCouponRepository {
     pool: SqlitePool,
 }
 
+const COUPON_COLUMNS: &str = r#"
+    id, code, name, description, discount_type, discount_value,
+    minimum_amount, max_discount_amount, buy_quantity, get_quantity,
+    usage_limit, used_count, per_customer_limit, valid_from, valid_until
+"#;
+
 impl SqliteCouponRepository {
     pub fn new(pool: SqlitePool) -> Self {
         Self { pool }
     }
+
+    fn query_error(context: &str, e: impl std::fmt::Display) -> RepositoryError {
+        RepositoryError::QueryExecution(format!("[SqliteCouponRepository::{}] {}", context, e))
+    }
+
+    /// クーポンの行と適用条件の行（condition_type, target_id）からクーポンエンティティを復元
+    fn row_to_coupon(
+        row: &SqliteRow,
+        conditions: &[(String, String)],
+    ) -> Result<Coupon, DomainError> {
+        let code_str: String = row.get("code");
+        let invalid = |message: String| DomainError::InvalidCoupon {
+            code: code_str.clone(),
+            message,
+        };
+
+        let discount_type: String = row.get("discount_type");
+        let discount_value: i64 = row.get("discount_value");
+        let minimum_amount: Option<i64> = row.get("minimum_amount");
+        let max_discount_amount: Option<i64> = row.get("max_discount_amount");
+        let buy_quantity: Option<i64> = row.get("buy_quantity");
+        let get_quantity: Option<i64> = row.get("get_quantity");
+        let usage_limit: Option<i64> = row.get("usage_limit");
+        let used_count: i64 = row.get("used_count");
+        let per_customer_limit: Option<i64> = row.get("per_customer_limit");
+        let valid_from_str: String = row.get("valid_from");
+        let valid_until_str: String = row.get("valid_until");
+
+        // 日付パース（有効期間は日単位で、終了日は終日有効）
+        let valid_from = DateTime::parse_from_str(
+            &format!("{}T00:00:00+00:00", valid_from_str),
+            "%Y-%m-%dT%H:%M:%S%z",
+        )
+        .map_err(|e| invalid(format!("Invalid date format: {}", e)))?
+        .with_timezone(&Utc);
+
+        let valid_until = DateTime::parse_from_str(
+            &format!("{}T23:59:59+00:00", valid_until_str),
+            "%Y-%m-%dT%H:%M:%S%z",
+        )
+        .map_err(|e| invalid(format!("Invalid date format: {}", e)))?
+        .with_timezone(&Utc);
+
+        let discount_type = discount_type_from_columns(
+            &discount_type,
+            discount_value,
+            max_discount_amount,
+            buy_quantity,
+            get_quantity,
+        )
+        .map_err(invalid)?;
+        let discount_condition =
+            discount_condition_from_columns(conditions, minimum_amount).map_err(invalid)?;
+
+        let coupon = Coupon::new(
+            CouponId::from_uuid(
+                Uuid::parse_str(&row.get::<String, _>("id"))
+                    .map_err(|e| invalid(format!("Invalid coupon ID format: {}", e)))?,
+            ),
+            CouponCode::from_string(code_str.clone())
+                .map_err(|e| invalid(format!("Invalid coupon code: {}", e)))?,
+            row.get::<String, _>("name"),
+            row.get::<Option<String>, _>("description"),
+            DiscountPolicy::new(discount_type, discount_condition),
+            valid_from,
+            valid_until,
+            usage_limit.map(|l| l as u32),
+            used_count as u32,
+        )
+        .with_per_customer_limit(per_customer_limit.map(|l| l as u32));
+
+        Ok(coupon)
+    }
+
+    /// クーポンの適用条件を保存し直す
+    async fn replace_conditions(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        coupon: &Coupon,
+    ) -> Result<(), RepositoryError> {
+        let coupon_id = coupon.id().value().to_string();
+
+        sqlx::query("DELETE FROM coupon_conditions WHERE coupon_id = ?")
+            .bind(&coupon_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| Self::query_error("replace_conditions", e))?;
+
+        let columns = DiscountPolicyColumns::from_policy(coupon.discount_policy());
+        for (condition_type, target_id) in columns.conditions {
+            sqlx::query(
+                "INSERT OR IGNORE INTO coupon_conditions (coupon_id, condition_type, target_id) VALUES (?1, ?2, ?3)",
+            )
+            .bind(&coupon_id)
+            .bind(condition_type)
+            .bind(target_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| Self::query_error("replace_conditions", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// クーポンIDごとの適用条件（condition_type, target_id）をまとめて取得
+    async fn find_conditions(
+        &self,
+        coupon_ids: &[String],
+    ) -> Result<HashMap<String, Vec<(String, String)>>, sqlx::Error> {
+        let mut conditions: HashMap<String, Vec<(String, String)>> = HashMap::new();
+        if coupon_ids.is_empty() {
+            return Ok(conditions);
+        }
+
+        // プレースホルダーを動的に生成
+        let placeholders = coupon_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let query = format!(
+            r#"
+            SELECT coupon_id, condition_type, target_id
+            FROM coupon_conditions
+            WHERE coupon_id IN ({})
+            ORDER BY coupon_id, condition_type, target_id
+            "#,
+            placeholders
+        );
+
+        let mut query_builder = sqlx::query(&query);
+        for coupon_id in coupon_ids {
+            query_builder = query_builder.bind(coupon_id);
+        }
+
+        for row in query_builder.fetch_all(&self.pool).await? {
+            conditions
+                .entry(row.get("coupon_id"))
+                .or_default()
+                .push((row.get("condition_type"), row.get("target_id")));
+        }
+
+        Ok(conditions)
+    }
+
+    /// クーポンの行に適用条件を合わせてクーポンエンティティを復元
+    async fn rows_to_coupons(&self, rows: &[SqliteRow]) -> Result<Vec<Coupon>, RepositoryError> {
+        let coupon_ids = rows
+            .iter()
+            .map(|row| row.get::<String, _>("id"))
+            .collect::<Vec<_>>();
+        let conditions = self
+            .find_conditions(&coupon_ids)
+            .await
+            .map_err(|e| Self::query_error("find_conditions", e))?;
+
+        rows.iter()
+            .zip(&coupon_ids)
+            .map(|(row, id)| {
+                Self::row_to_coupon(row, conditions.get(id).map_or(&[], Vec::as_slice))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))
+    }
+
+    /// 同じコードのクーポンが他に存在しないか確認
+    async fn ensure_code_is_unique(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        coupon: &Coupon,
+    ) -> Result<(), RepositoryError> {
+        let duplicated: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM coupons WHERE code = ?1 AND id <> ?2")
+                .bind(coupon.code().value())
+                .bind(coupon.id().value().to_string())
+                .fetch_one(&mut **tx)
+                .await
+                .map_err(|e| Self::query_error("ensure_code_is_unique", e))?;
+
+        if duplicated > 0 {
+            return Err(RepositoryError::UniqueConstraint {
+                field: "coupon_code".to_string(),
+                message: format!("Coupon code {} already exists", coupon.code().value()),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// クーポンの使用回数を1増やす（使用上限に達している場合は更新しない）
+/// 注文保存のトランザクション内からも呼び出せるよう、実行先を引数で受け取る
+/// 戻り値は使用回数を更新できたかどうか
+pub(super) async fn increment_usage_count<'e, E>(
+    executor: E,
+    coupon_id: &CouponId,
+) -> Result<bool, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+{
+    // 読み取った使用回数ではなくDB上の値を条件付きで加算し、同時使用でも上限を超えないようにする
+    let result = sqlx::query(
+        r#"
+        UPDATE coupons
+        SET used_count = used_count + 1, updated_at = datetime('now')
+        WHERE id = ? AND (usage_limit IS NULL OR used_count < usage_limit)
+        "#,
+    )
+    .bind(coupon_id.value().to_string())
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// 顧客ごとのクーポン使用履歴を記録する（顧客ごとの使用上限に達している場合は記録しない）
+/// 注文保存のトランザクション内で呼び出し、戻り値は記録できたかどうか
+pub(super) async fn record_redemption<'e, E>(
+    executor: E,
+    coupon_id: &CouponId,
+    order_id: &OrderId,
+    customer_email: &str,
+) -> Result<bool, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+{
+    // 使用回数と同じく、DB上の件数を条件にして同時注文でも上限を超えないようにする
+    let result = sqlx::query(
+        r#"
+        INSERT INTO coupon_redemptions (coupon_id, order_id, customer_email)
+        SELECT c.id, ?2, lower(?3)
+        FROM coupons c
+        WHERE c.id = ?1
+        AND (
+            c.per_customer_limit IS NULL
+            OR (
+                SELECT COUNT(*) FROM coupon_redemptions r
+                WHERE r.coupon_id = c.id AND r.customer_email = lower(?3)
+            ) < c.per_customer_limit
+        )
+        "#,
+    )
+    .bind(coupon_id.value().to_string())
+    .bind(order_id.value().to_string())
+    .bind(customer_email)
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
 }
 
 #[async_trait]
@@ -25,112 +282,214 @@ impl CouponRepository for SqliteCouponRepository {
     /// クーポンコードでクーポンを検索
     async fn find_by_code(&self, code: &CouponCode) -> Result<Option<Coupon>, DomainError> {
         let code_str = code.value();
-        
-        let row = sqlx::query(
+
+        let row = sqlx::query(&format!(
             r#"
-            SELECT id, code, name, description, discount_type, discount_value, 
-                   minimum_amount, usage_limit, used_count, valid_from, valid_until
-            FROM coupons 
+            SELECT {}
+            FROM coupons
             WHERE code = ?
             AND date('now') BETWEEN valid_from AND valid_until
             "#,
-        )
+            COUPON_COLUMNS
+        ))
         .bind(code_str)
         .fetch_optional(&self.pool)
         .await
-        .map_err(|e| DomainError::InvalidCoupon { 
-            code: code_str.to_string(), 
-            message: format!("Database error: {}", e) 
+        .map_err(|e| DomainError::InvalidCoupon {
+            code: code_str.to_string(),
+            message: format!("Database error: {}", e),
         })?;
 
-        if let Some(row) = row {
-            let discount_type: String = row.get("discount_type");
-            let discount_value: i32 = row.get("discount_value");
-            let minimum_amount: Option<i32> = row.get("minimum_amount");
-            let usage_limit: Option<i32> = row.get("usage_limit");
-            let used_count: i32 = row.get("used_count");
-            let valid_from_str: String = row.get("valid_from");
-            let valid_until_str: String = row.get("valid_until");
-
-            // 日付パース
-            let valid_from = DateTime::parse_from_str(&format!("{}T00:00:00+00:00", valid_from_str), "%Y-%m-%dT%H:%M:%S%z")
-                .map_err(|e| DomainError::InvalidCoupon { 
-                    code: code_str.to_string(), 
-                    message: format!("Invalid date format: {}", e) 
-                })?
-                .with_timezone(&Utc);
-            
-            let valid_until = DateTime::parse_from_str(&format!("{}T23:59:59+00:00", valid_until_str), "%Y-%m-%dT%H:%M:%S%z")
-                .map_err(|e| DomainError::InvalidCoupon { 
-                    code: code_str.to_string(), 
-                    message: format!("Invalid date format: {}", e) 
-                })?
-                .with_timezone(&Utc);
-
-            // DiscountPolicyを作成
-            let discount_condition = minimum_amount.map(|a| DiscountCondition::MinimumPurchase(Money::from_yen(a as u32)));
-            
-            let discount_policy = if discount_type == "percentage" {
-                DiscountPolicy::new(
-                    DiscountType::Percentage(discount_value as u8),
-                    discount_condition,
-                )
-            } else {
-                DiscountPolicy::new(
-                    DiscountType::FixedAmount(Money::from_yen(discount_value as u32)),
-                    discount_condition,
-                )
-            };
-
-            let coupon = Coupon::new(
-                CouponId::from_uuid(
-                    Uuid::parse_str(&row.get::<String, _>("id"))
-                        .map_err(|e| DomainError::InvalidCoupon { 
-                            code: code_str.to_string(), 
-                            message: format!("Invalid coupon ID format: {}", e) 
-                        })?
-                ),
-                CouponCode::from_string(row.get::<String, _>("code"))
-                    .map_err(|e| DomainError::InvalidCoupon { 
-                        code: code_str.to_string(), 
-                        message: format!("Invalid coupon code: {}", e) 
-                    })?,
-                row.get::<String, _>("name"),
-                row.get::<Option<String>, _>("description"),
-                discount_policy,
-                valid_from,
-                valid_until,
-                usage_limit.map(|l| l as u32),
-                used_count as u32,
-            );
-
-            Ok(Some(coupon))
-        } else {
-            Ok(None)
-        }
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let coupon_id: String = row.get("id");
+        let conditions = self
+            .find_conditions(std::slice::from_ref(&coupon_id))
+            .await
+            .map_err(|e| DomainError::InvalidCoupon {
+                code: code_str.to_string(),
+                message: format!("Database error: {}", e),
+            })?;
+
+        Self::row_to_coupon(&row, conditions.get(&coupon_id).map_or(&[], Vec::as_slice)).map(Some)
     }
 
     /// クーポンの使用回数を更新
     async fn update_usage_count(&self, coupon: &Coupon) -> Result<(), DomainError> {
-        let new_count = coupon.usage_count() + 1;
-        let coupon_id = coupon.id().value().to_string();
-        
-        sqlx::query(
+        let updated = increment_usage_count(&self.pool, coupon.id())
+            .await
+            .map_err(|e| DomainError::InvalidCoupon {
+                code: coupon.code().value().to_string(),
+                message: format!("Failed to update usage count: {}", e),
+            })?;
+
+        if !updated {
+            return Err(DomainError::InvalidCoupon {
+                code: coupon.code().value().to_string(),
+                message: "クーポンの使用回数が上限に達しています".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &CouponId) -> Result<Option<Coupon>, RepositoryError> {
+        let row = sqlx::query(&format!("SELECT {} FROM coupons WHERE id = ?", COUPON_COLUMNS))
+            .bind(id.value().to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| Self::query_error("find_by_id", e))?;
+
+        Ok(self
+            .rows_to_coupons(row.as_slice())
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Coupon>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM coupons ORDER BY created_at DESC, code",
+            COUPON_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_all", e))?;
+
+        self.rows_to_coupons(&rows).await
+    }
+
+    async fn create_all(&self, coupons: &[Coupon]) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| Self::query_error("create_all", e))?;
+
+        for coupon in coupons {
+            Self::ensure_code_is_unique(&mut tx, coupon).await?;
+
+            let columns = DiscountPolicyColumns::from_policy(coupon.discount_policy());
+
+            sqlx::query(
+                r#"
+                INSERT INTO coupons (
+                    id, code, name, description, discount_type, discount_value,
+                    minimum_amount, max_discount_amount, buy_quantity, get_quantity,
+                    usage_limit, used_count, per_customer_limit, valid_from, valid_until
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+                "#,
+            )
+            .bind(coupon.id().value().to_string())
+            .bind(coupon.code().value())
+            .bind(coupon.name())
+            .bind(coupon.description())
+            .bind(columns.discount_type)
+            .bind(columns.discount_value)
+            .bind(columns.minimum_amount)
+            .bind(columns.max_discount_amount)
+            .bind(columns.buy_quantity)
+            .bind(columns.get_quantity)
+            .bind(coupon.usage_limit().map(|l| l as i64))
+            .bind(coupon.usage_count() as i64)
+            .bind(coupon.per_customer_limit().map(|l| l as i64))
+            .bind(coupon.valid_from().format("%Y-%m-%d").to_string())
+            .bind(coupon.valid_until().format("%Y-%m-%d").to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("create_all", e))?;
+
+            Self::replace_conditions(&mut tx, coupon).await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| Self::query_error("create_all", e))
+    }
+
+    async fn update(&self, coupon: &Coupon) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| Self::query_error("update", e))?;
+
+        Self::ensure_code_is_unique(&mut tx, coupon).await?;
+
+        let columns = DiscountPolicyColumns::from_policy(coupon.discount_policy());
+
+        // 使用回数は注文から加算されるため、ここでは更新しない
+        let result = sqlx::query(
             r#"
-            UPDATE coupons 
-            SET used_count = ?, updated_at = datetime('now')
-            WHERE id = ?
+            UPDATE coupons SET
+                code = ?2, name = ?3, description = ?4,
+                discount_type = ?5, discount_value = ?6, minimum_amount = ?7,
+                max_discount_amount = ?8, buy_quantity = ?9, get_quantity = ?10,
+                usage_limit = ?11, per_customer_limit = ?12,
+                valid_from = ?13, valid_until = ?14,
+                updated_at = datetime('now')
+            WHERE id = ?1
             "#,
         )
-        .bind(new_count as i32)
-        .bind(&coupon_id)
-        .execute(&self.pool)
+        .bind(coupon.id().value().to_string())
+        .bind(coupon.code().value())
+        .bind(coupon.name())
+        .bind(coupon.description())
+        .bind(columns.discount_type)
+        .bind(columns.discount_value)
+        .bind(columns.minimum_amount)
+        .bind(columns.max_discount_amount)
+        .bind(columns.buy_quantity)
+        .bind(columns.get_quantity)
+        .bind(coupon.usage_limit().map(|l| l as i64))
+        .bind(coupon.per_customer_limit().map(|l| l as i64))
+        .bind(coupon.valid_from().format("%Y-%m-%d").to_string())
+        .bind(coupon.valid_until().format("%Y-%m-%d").to_string())
+        .execute(&mut *tx)
         .await
-        .map_err(|e| DomainError::InvalidCoupon { 
-            code: coupon.code().value().to_string(), 
-            message: format!("Failed to update usage count: {}", e) 
-        })?;
+        .map_err(|e| Self::query_error("update", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Self::replace_conditions(&mut tx, coupon).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| Self::query_error("update", e))
+    }
+
+    async fn delete(&self, id: &CouponId) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM coupons WHERE id = ?")
+            .bind(id.value().to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| Self::query_error("delete", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    async fn count_customer_usage(
+        &self,
+        id: &CouponId,
+        customer_email: &str,
+    ) -> Result<u32, RepositoryError> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM coupon_redemptions WHERE coupon_id = ? AND customer_email = lower(?)",
+        )
+        .bind(id.value().to_string())
+        .bind(customer_email)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("count_customer_usage", e))?;
+
+        Ok(count as u32)
+    }
+}
@@ -0,0 +1,187 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use uuid::Uuid;
+
+use crate::application::error::RepositoryError;
+use crate::application::repositories::CustomerRepository;
+use crate::domain::aggregates::customer::{Customer, CustomerSession};
+use crate::domain::value_objects::{CustomerId, Email, PersonalInfo, PhoneNumber, SessionId};
+
+const CUSTOMER_SELECT: &str = r#"
+    SELECT id, email, password_hash, first_name, last_name, phone, created_at, updated_at
+    FROM customers
+"#;
+
+/// SQLite実装のCustomerRepository
+/// Clean Architecture: Frameworks & Drivers層
+pub struct SqliteCustomerRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteCustomerRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn query_error(context: &str, e: impl std::fmt::Display) -> RepositoryError {
+        RepositoryError::QueryExecution(format!("[SqliteCustomerRepository::{}] {}", context, e))
+    }
+
+    fn conversion_error(e: impl std::fmt::Debug) -> RepositoryError {
+        RepositoryError::DataConversionError(format!("{:?}", e))
+    }
+
+    fn parse_datetime(value: &str) -> Result<DateTime<Utc>, RepositoryError> {
+        DateTime::parse_from_rfc3339(value)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(Self::conversion_error)
+    }
+
+    fn row_to_customer(row: &SqliteRow) -> Result<Customer, RepositoryError> {
+        let id = Uuid::parse_str(&row.get::<String, _>("id")).map_err(Self::conversion_error)?;
+        let email = Email::new(row.get("email")).map_err(Self::conversion_error)?;
+        let personal_info = PersonalInfo::from_strings(row.get("first_name"), row.get("last_name"))
+            .map_err(Self::conversion_error)?;
+        let phone = PhoneNumber::new(row.get("phone")).map_err(Self::conversion_error)?;
+
+        Ok(Customer::restore(
+            CustomerId::from_uuid(id),
+            email,
+            row.get("password_hash"),
+            personal_info,
+            phone,
+            Self::parse_datetime(&row.get::<String, _>("created_at"))?,
+            Self::parse_datetime(&row.get::<String, _>("updated_at"))?,
+        ))
+    }
+
+    fn row_to_session(row: &SqliteRow) -> Result<CustomerSession, RepositoryError> {
+        let id = Uuid::parse_str(&row.get::<String, _>("id")).map_err(Self::conversion_error)?;
+        let customer_id = Uuid::parse_str(&row.get::<String, _>("customer_id"))
+            .map_err(Self::conversion_error)?;
+        let revoked_at = row
+            .get::<Option<String>, _>("revoked_at")
+            .as_deref()
+            .map(Self::parse_datetime)
+            .transpose()?;
+
+        Ok(CustomerSession::restore(
+            SessionId::from_uuid(id),
+            CustomerId::from_uuid(customer_id),
+            Self::parse_datetime(&row.get::<String, _>("created_at"))?,
+            Self::parse_datetime(&row.get::<String, _>("expires_at"))?,
+            revoked_at,
+        ))
+    }
+}
+
+#[async_trait]
+impl CustomerRepository for SqliteCustomerRepository {
+    async fn find_by_id(&self, id: &CustomerId) -> Result<Option<Customer>, RepositoryError> {
+        let query = format!("{} WHERE id = ?1", CUSTOMER_SELECT);
+        sqlx::query(&query)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| Self::query_error("find_by_id", e))?
+            .as_ref()
+            .map(Self::row_to_customer)
+            .transpose()
+    }
+
+    async fn find_by_email(&self, email: &Email) -> Result<Option<Customer>, RepositoryError> {
+        // emailカラムはCOLLATE NOCASEのため大文字・小文字を区別せずに一致する
+        let query = format!("{} WHERE email = ?1", CUSTOMER_SELECT);
+        sqlx::query(&query)
+            .bind(email.value())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| Self::query_error("find_by_email", e))?
+            .as_ref()
+            .map(Self::row_to_customer)
+            .transpose()
+    }
+
+    async fn save(&self, customer: &Customer) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO customers (
+                id, email, password_hash, first_name, last_name, phone, created_at, updated_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            ON CONFLICT(id) DO UPDATE SET
+                email = excluded.email,
+                password_hash = excluded.password_hash,
+                first_name = excluded.first_name,
+                last_name = excluded.last_name,
+                phone = excluded.phone,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(customer.id().to_string())
+        .bind(customer.email().value())
+        .bind(customer.password_hash())
+        .bind(customer.personal_info().first_name().value())
+        .bind(customer.personal_info().last_name().value())
+        .bind(customer.phone().value())
+        .bind(customer.created_at().to_rfc3339())
+        .bind(customer.updated_at().to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if e.to_string()
+                .contains("UNIQUE constraint failed: customers.email")
+            {
+                RepositoryError::UniqueConstraint {
+                    field: "email".to_string(),
+                    message: format!("Customer email {} already exists", customer.email().value()),
+                }
+            } else {
+                Self::query_error("save", e)
+            }
+        })?;
+
+        Ok(())
+    }
+
+    async fn save_session(&self, session: &CustomerSession) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO customer_sessions (id, customer_id, created_at, expires_at, revoked_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(id) DO UPDATE SET revoked_at = excluded.revoked_at
+            "#,
+        )
+        .bind(session.id().to_string())
+        .bind(session.customer_id().to_string())
+        .bind(session.created_at().to_rfc3339())
+        .bind(session.expires_at().to_rfc3339())
+        .bind(session.revoked_at().map(|t| t.to_rfc3339()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("save_session", e))?;
+
+        Ok(())
+    }
+
+    async fn find_session(
+        &self,
+        id: &SessionId,
+    ) -> Result<Option<CustomerSession>, RepositoryError> {
+        sqlx::query(
+            r#"
+            SELECT id, customer_id, created_at, expires_at, revoked_at
+            FROM customer_sessions
+            WHERE id = ?1
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_session", e))?
+        .as_ref()
+        .map(Self::row_to_session)
+        .transpose()
+    }
+}
@@ -0,0 +1,200 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use sqlx::sqlite::SqliteRow;
+use uuid::Uuid;
+
+use crate::application::error::RepositoryError;
+use crate::application::repositories::DeliveryInfoRepository;
+use crate::domain::value_objects::{
+    Address, DeliveryInfoId, Email, OrderId, PersonalInfo, PhoneNumber,
+};
+use crate::domain::{DeliveryInfo, DeliveryStatus};
+
+use super::row_helpers::{get_column, parse_timestamp};
+
+/// 配送情報取得用のSELECT句
+const DELIVERY_INFO_SELECT: &str = r#"
+    SELECT
+        id, order_id, email, first_name, last_name, phone,
+        postal_code, prefecture, city, street, building,
+        status, carrier, tracking_number, shipping_method,
+        created_at, updated_at, shipped_at, delivered_at
+    FROM delivery_infos
+"#;
+
+/// SQLite実装のDeliveryInfoRepository
+/// Clean Architecture: Frameworks & Drivers層
+pub struct SqliteDeliveryInfoRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteDeliveryInfoRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// 配送情報を作成または更新する
+/// 注文の更新と同一トランザクションで保存できるよう、実行先を引数で受け取る
+pub(super) async fn upsert_delivery_info<'e, E>(
+    executor: E,
+    order_id: &OrderId,
+    delivery_info: &DeliveryInfo,
+) -> Result<(), sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+{
+    let address = delivery_info.address();
+
+    sqlx::query(
+        r#"
+        INSERT INTO delivery_infos (
+            id, order_id, email, first_name, last_name, phone,
+            postal_code, prefecture, city, street, building,
+            status, carrier, tracking_number, shipping_method,
+            created_at, updated_at, shipped_at, delivered_at
+        ) VALUES (
+            ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14,
+            ?15, ?16, ?17, ?18, ?19
+        )
+        ON CONFLICT(id) DO UPDATE SET
+            status = excluded.status,
+            carrier = excluded.carrier,
+            tracking_number = excluded.tracking_number,
+            updated_at = excluded.updated_at,
+            shipped_at = excluded.shipped_at,
+            delivered_at = excluded.delivered_at
+        "#,
+    )
+    .bind(delivery_info.id().to_string())
+    .bind(order_id.to_string())
+    .bind(delivery_info.email().value())
+    .bind(delivery_info.personal_info().first_name().value())
+    .bind(delivery_info.personal_info().last_name().value())
+    .bind(delivery_info.phone_number().value())
+    .bind(address.postal_code())
+    .bind(address.prefecture())
+    .bind(address.city())
+    .bind(address.street())
+    .bind(address.building())
+    .bind(delivery_info.status().code())
+    .bind(delivery_info.carrier())
+    .bind(delivery_info.tracking_number())
+    .bind(delivery_info.shipping_method())
+    .bind(delivery_info.created_at.to_rfc3339())
+    .bind(delivery_info.updated_at.to_rfc3339())
+    .bind(delivery_info.shipped_at.map(|t| t.to_rfc3339()))
+    .bind(delivery_info.delivered_at.map(|t| t.to_rfc3339()))
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+/// IDに一致する配送情報をまとめて取得（注文一覧の復元用）
+pub(super) async fn find_delivery_infos_by_ids(
+    pool: &SqlitePool,
+    ids: &[String],
+) -> Result<Vec<DeliveryInfo>, RepositoryError> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+    let query = format!("{} WHERE id IN ({})", DELIVERY_INFO_SELECT, placeholders);
+
+    let mut query_builder = sqlx::query(&query);
+    for id in ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let rows = query_builder.fetch_all(pool).await.map_err(|e| {
+        RepositoryError::QueryExecution(format!(
+            "[SqliteDeliveryInfoRepository::find_delivery_infos_by_ids] {}",
+            e
+        ))
+    })?;
+
+    rows.iter().map(row_to_delivery_info).collect()
+}
+
+#[async_trait]
+impl DeliveryInfoRepository for SqliteDeliveryInfoRepository {
+    async fn find_by_id(
+        &self,
+        id: &DeliveryInfoId,
+    ) -> Result<Option<(OrderId, DeliveryInfo)>, RepositoryError> {
+        let row = sqlx::query(&format!("{} WHERE id = ?1", DELIVERY_INFO_SELECT))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                RepositoryError::QueryExecution(format!(
+                    "[SqliteDeliveryInfoRepository::find_by_id] {}",
+                    e
+                ))
+            })?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let order_id: String = get_column(&row, "order_id")?;
+        let order_id = Uuid::parse_str(&order_id)
+            .map(OrderId::from_uuid)
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+
+        Ok(Some((order_id, row_to_delivery_info(&row)?)))
+    }
+}
+
+/// delivery_infosの行からDeliveryInfoを復元
+fn row_to_delivery_info(row: &SqliteRow) -> Result<DeliveryInfo, RepositoryError> {
+    let id: String = get_column(row, "id")?;
+    let id = Uuid::parse_str(&id)
+        .map(DeliveryInfoId::from_uuid)
+        .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+
+    let email = Email::new(get_column(row, "email")?)
+        .map_err(|e| RepositoryError::DataConversionError(format!("{:?}", e)))?;
+    let personal_info = PersonalInfo::from_strings(
+        get_column(row, "first_name")?,
+        get_column(row, "last_name")?,
+    )
+    .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+    let address = Address::new(
+        get_column(row, "postal_code")?,
+        get_column(row, "prefecture")?,
+        get_column(row, "city")?,
+        get_column(row, "street")?,
+        get_column(row, "building")?,
+    )
+    .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+    let phone_number = PhoneNumber::new(get_column(row, "phone")?)
+        .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+    let status = get_column::<String>(row, "status")?
+        .parse::<DeliveryStatus>()
+        .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+
+    Ok(DeliveryInfo::with_id(
+        id,
+        email,
+        personal_info,
+        address,
+        phone_number,
+        status,
+        get_column(row, "carrier")?,
+        get_column(row, "tracking_number")?,
+        get_column(row, "shipping_method")?,
+        parse_timestamp(&get_column::<String>(row, "created_at")?)?,
+        parse_timestamp(&get_column::<String>(row, "updated_at")?)?,
+        get_column::<Option<String>>(row, "shipped_at")?
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+        get_column::<Option<String>>(row, "delivered_at")?
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+    ))
+}
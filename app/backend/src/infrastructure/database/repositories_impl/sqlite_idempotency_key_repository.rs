@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+
+use crate::application::error::RepositoryError;
+use crate::application::repositories::{
+    IdempotencyKeyRepository, IdempotencyRecord, StoredResponse,
+};
+
+/// SQLite実装のIdempotencyKeyRepository
+/// Clean Architecture: Frameworks & Drivers層
+pub struct SqliteIdempotencyKeyRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteIdempotencyKeyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn query_error(context: &str, e: impl std::fmt::Display) -> RepositoryError {
+        RepositoryError::QueryExecution(format!(
+            "[SqliteIdempotencyKeyRepository::{}] {}",
+            context, e
+        ))
+    }
+
+    /// 文字列比較で前後関係を判定できるよう固定長で保存する
+    fn format_datetime(value: DateTime<Utc>) -> String {
+        value.to_rfc3339_opts(SecondsFormat::Micros, true)
+    }
+
+    fn row_to_record(row: &SqliteRow) -> Result<IdempotencyRecord, RepositoryError> {
+        let expires_at = DateTime::parse_from_rfc3339(&row.get::<String, _>("expires_at"))
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| RepositoryError::DataConversionError(format!("{:?}", e)))?;
+        let response = match row.get::<Option<i64>, _>("status_code") {
+            Some(status) => Some(StoredResponse {
+                status: u16::try_from(status)
+                    .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?,
+                content_type: row.get("content_type"),
+                body: row
+                    .get::<Option<Vec<u8>>, _>("response_body")
+                    .unwrap_or_default(),
+            }),
+            None => None,
+        };
+
+        Ok(IdempotencyRecord {
+            key: row.get("key"),
+            request_hash: row.get("request_hash"),
+            response,
+            expires_at,
+        })
+    }
+}
+
+#[async_trait]
+impl IdempotencyKeyRepository for SqliteIdempotencyKeyRepository {
+    async fn find(&self, key: &str) -> Result<Option<IdempotencyRecord>, RepositoryError> {
+        sqlx::query(
+            r#"
+            SELECT key, request_hash, status_code, content_type, response_body, expires_at
+            FROM idempotency_keys
+            WHERE key = ?1
+            "#,
+        )
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find", e))?
+        .as_ref()
+        .map(Self::row_to_record)
+        .transpose()
+    }
+
+    async fn reserve(
+        &self,
+        key: &str,
+        request_hash: &str,
+        now: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, RepositoryError> {
+        // 同時に同じキーで送られても、1件の挿入（または期限切れ行の上書き）だけが成功する
+        let result = sqlx::query(
+            r#"
+            INSERT INTO idempotency_keys (
+                key, request_hash, status_code, content_type, response_body, created_at, expires_at
+            ) VALUES (?1, ?2, NULL, NULL, NULL, ?3, ?4)
+            ON CONFLICT(key) DO UPDATE SET
+                request_hash = excluded.request_hash,
+                status_code = NULL,
+                content_type = NULL,
+                response_body = NULL,
+                created_at = excluded.created_at,
+                expires_at = excluded.expires_at
+            WHERE idempotency_keys.expires_at <= excluded.created_at
+            "#,
+        )
+        .bind(key)
+        .bind(request_hash)
+        .bind(Self::format_datetime(now))
+        .bind(Self::format_datetime(expires_at))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("reserve", e))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn store_response(
+        &self,
+        key: &str,
+        response: &StoredResponse,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            UPDATE idempotency_keys
+            SET status_code = ?2, content_type = ?3, response_body = ?4
+            WHERE key = ?1
+            "#,
+        )
+        .bind(key)
+        .bind(i64::from(response.status))
+        .bind(response.content_type.as_deref())
+        .bind(response.body.as_slice())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("store_response", e))?;
+
+        Ok(())
+    }
+
+    async fn release(&self, key: &str) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM idempotency_keys WHERE key = ?1 AND status_code IS NULL")
+            .bind(key)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| Self::query_error("release", e))?;
+
+        Ok(())
+    }
+
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("DELETE FROM idempotency_keys WHERE expires_at <= ?1")
+            .bind(Self::format_datetime(now))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| Self::query_error("delete_expired", e))?;
+
+        Ok(result.rows_affected())
+    }
+}
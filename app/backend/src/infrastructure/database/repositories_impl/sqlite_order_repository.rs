@@ -1,35 +1,690 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
 use sqlx::SqlitePool;
+use uuid::Uuid;
 
-use crate::application::error::RepositoryError;
-use crate::application::repositories::OrderRepository;
-use crate::domain::aggregates::order::Order;
+use crate::application::dto::OrderEventDTO;
+use crate::application::error::{ApplicationError, RepositoryError, StockShortage};
+use crate::application::repositories::{OrderFactory, OrderRepository, OrderSearchCriteria};
+use crate::domain::aggregates::order::payment_info::PaymentDetails;
+use crate::domain::aggregates::order::{
+    AppliedCoupon, CustomerInfo, Order, OrderDocumentKind, OrderEventKind, OrderItem,
+    OrderPricing, OrderStatus, OrderTimestamps, PaymentInfo, ShippingInfo, StockEffect,
+};
+use crate::domain::value_objects::*;
+use crate::domain::{DeliveryInfo, StockMovement, StockMovementKind};
+
+use super::row_helpers::{get_column, parse_timestamp};
+use super::sqlite_coupon_repository::{increment_usage_count, record_redemption};
+use super::sqlite_delivery_info_repository::{find_delivery_infos_by_ids, upsert_delivery_info};
+use super::sqlite_stock_movement_repository::insert_stock_movement;
+
+/// 注文取得用のSELECT句（配送方法名・支払い方法名を結合）
+const ORDER_SELECT: &str = r#"
+    SELECT
+        o.id, o.order_number, o.customer_first_name, o.customer_last_name,
+        o.customer_email, o.customer_phone, o.shipping_method_id, o.shipping_fee,
+        o.shipping_postal_code, o.shipping_prefecture, o.shipping_city,
+        o.shipping_street, o.shipping_building, o.payment_method_id,
+        o.payment_fee, o.payment_details, o.subtotal, o.shipping_fee_total,
+        o.payment_fee_total, o.tax_amount, o.total_amount, o.status,
+        o.coupon_id, o.coupon_code, o.discount_amount, o.created_at, o.updated_at, o.paid_at, o.shipped_at, o.delivered_at,
+        o.cancelled_at, o.notes, o.delivery_info_id, o.prices_include_tax, o.customer_id,
+        o.version,
+        sm.name as shipping_method_name,
+        pm.name as payment_method_name
+    FROM orders o
+    LEFT JOIN shipping_methods sm ON sm.id = o.shipping_method_id
+    LEFT JOIN payment_methods pm ON pm.id = o.payment_method_id
+"#;
 
 /// SQLite実装のOrderRepository
 /// Clean Architecture: Frameworks & Drivers層
 pub struct SqliteOrderRepository {
     pool: SqlitePool,
+    order_number_format: OrderNumberFormat,
 }
 
 impl SqliteOrderRepository {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            order_number_format: OrderNumberFormat::default(),
+        }
     }
-}
 
-#[async_trait]
-impl OrderRepository for SqliteOrderRepository {
-    async fn save(&self, order: &Order) -> Result<(), RepositoryError> {
-        let mut tx = self
-            .pool
-            .begin()
+    /// 採番に使っている注文番号の書式を設定する
+    pub fn with_order_number_format(mut self, order_number_format: OrderNumberFormat) -> Self {
+        self.order_number_format = order_number_format;
+        self
+    }
+
+    /// 注文番号が設定中の書式、または従来の書式（ORD-YYYY-NNNNNN）に一致するか
+    /// 書式の設定前に採番した注文も引き続き扱えるよう、従来の書式も受け付ける
+    fn is_known_order_number(&self, order_number: &OrderNumber) -> bool {
+        self.order_number_format.matches(order_number.value())
+            || OrderNumberFormat::default().matches(order_number.value())
+    }
+
+    /// 未保存のドメインイベントをorder_eventsに追記
+    async fn insert_events(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        order: &Order,
+    ) -> Result<(), RepositoryError> {
+        for event in &order.events {
+            sqlx::query(
+                r#"
+                INSERT INTO order_events (order_id, event_type, event_data, created_at)
+                VALUES (?1, ?2, ?3, ?4)
+                "#,
+            )
+            .bind(order.id.value().to_string())
+            .bind(event.event_type())
+            .bind(event.event_data().to_string())
+            .bind(event.occurred_at.to_rfc3339())
+            .execute(&mut **tx)
             .await
             .map_err(|e| {
-                let error_msg = e.to_string();
-                println!("->> [SqliteOrderRepository::save] Transaction begin failed: {}", error_msg);
-                RepositoryError::QueryExecution(format!("[SqliteOrderRepository::save_transaction_begin] {}", error_msg))
+                RepositoryError::QueryExecution(format!(
+                    "[SqliteOrderRepository::insert_events] {}",
+                    e
+                ))
             })?;
+        }
+
+        Ok(())
+    }
+
+    /// 未保存のドメインイベントに応じてSKUの在庫・引当数を更新
+    ///
+    /// 注文の保存と同一トランザクションで実行するため、在庫不足の場合は注文ごとロールバックされる。
+    async fn apply_stock_effects(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        order: &Order,
+    ) -> Result<(), RepositoryError> {
+        for effect in order.events.iter().filter_map(|event| event.stock_effect()) {
+            let mut shortages = Vec::new();
+
+            for item in &order.items {
+                let sku_id = item.sku_id.value().to_string();
+                let quantity = item.quantity as i64;
+
+                // 引当は条件付きUPDATEで行い、同時注文による過剰販売を防ぐ。
+                // 引当導入前の注文は予約数を持たないため、減算・解除は0未満にならないよう丸める。
+                let sql = match effect {
+                    StockEffect::Reserve => {
+                        r#"
+                        UPDATE skus
+                        SET reserved_quantity = reserved_quantity + ?1, updated_at = datetime('now')
+                        WHERE id = ?2 AND stock_quantity - reserved_quantity >= ?1
+                        "#
+                    }
+                    StockEffect::Commit => {
+                        r#"
+                        UPDATE skus
+                        SET stock_quantity = MAX(stock_quantity - ?1, 0),
+                            reserved_quantity = MAX(reserved_quantity - ?1, 0),
+                            updated_at = datetime('now')
+                        WHERE id = ?2
+                        "#
+                    }
+                    StockEffect::Release => {
+                        r#"
+                        UPDATE skus
+                        SET reserved_quantity = MAX(reserved_quantity - ?1, 0), updated_at = datetime('now')
+                        WHERE id = ?2
+                        "#
+                    }
+                    StockEffect::Restock => {
+                        r#"
+                        UPDATE skus
+                        SET stock_quantity = stock_quantity + ?1, updated_at = datetime('now')
+                        WHERE id = ?2
+                        "#
+                    }
+                };
+
+                let result = sqlx::query(sql)
+                    .bind(quantity)
+                    .bind(&sku_id)
+                    .execute(&mut **tx)
+                    .await
+                    .map_err(|e| {
+                        RepositoryError::QueryExecution(format!(
+                            "[SqliteOrderRepository::apply_stock_effects] SKU: {}, Error: {}",
+                            sku_id, e
+                        ))
+                    })?;
+
+                // 実在庫数が変わる出庫・戻し入れは在庫台帳に記録する
+                let movement = match effect {
+                    StockEffect::Commit => Some((StockMovementKind::Sale, -quantity, "注文確定")),
+                    StockEffect::Restock => {
+                        Some((StockMovementKind::Restock, quantity, "注文取消・返金"))
+                    }
+                    StockEffect::Reserve | StockEffect::Release => None,
+                };
+                if let Some((kind, delta, reason)) = movement {
+                    let movement = StockMovement::new(
+                        item.sku_id.clone(),
+                        kind,
+                        delta,
+                        reason,
+                        Some(order.id),
+                        "system",
+                    );
+                    insert_stock_movement(&mut **tx, &movement)
+                        .await
+                        .map_err(|e| {
+                            RepositoryError::QueryExecution(format!(
+                                "[SqliteOrderRepository::apply_stock_effects] SKU: {}, Error: {}",
+                                sku_id, e
+                            ))
+                        })?;
+                }
+
+                if effect == StockEffect::Reserve && result.rows_affected() == 0 {
+                    let available: Option<i64> = sqlx::query_scalar(
+                        "SELECT stock_quantity - reserved_quantity FROM skus WHERE id = ?1",
+                    )
+                    .bind(&sku_id)
+                    .fetch_optional(&mut **tx)
+                    .await
+                    .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+                    shortages.push(StockShortage {
+                        sku_code: item.sku_code.value().to_string(),
+                        requested: item.quantity as u32,
+                        available: available.unwrap_or(0).max(0) as u32,
+                    });
+                }
+            }
+
+            if !shortages.is_empty() {
+                return Err(RepositoryError::InsufficientStock(shortages));
+            }
+        }
+
+        Self::apply_reservation_changes(tx, order).await
+    }
+
+    /// 注文変更による引当数の増減を反映（増やす分は在庫の範囲内でのみ引当できる）
+    async fn apply_reservation_changes(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        order: &Order,
+    ) -> Result<(), RepositoryError> {
+        let changes = order.events.iter().flat_map(|event| match &event.kind {
+            OrderEventKind::Amended {
+                reservation_changes,
+                ..
+            } => reservation_changes.as_slice(),
+            _ => &[],
+        });
+
+        let mut shortages = Vec::new();
+        for change in changes {
+            let sku_id = change.sku_id.value().to_string();
+            let quantity = i64::from(change.quantity_delta.unsigned_abs());
+            let sql = if change.quantity_delta > 0 {
+                r#"
+                UPDATE skus
+                SET reserved_quantity = reserved_quantity + ?1, updated_at = datetime('now')
+                WHERE id = ?2 AND stock_quantity - reserved_quantity >= ?1
+                "#
+            } else {
+                r#"
+                UPDATE skus
+                SET reserved_quantity = MAX(reserved_quantity - ?1, 0), updated_at = datetime('now')
+                WHERE id = ?2
+                "#
+            };
+
+            let result = sqlx::query(sql)
+                .bind(quantity)
+                .bind(&sku_id)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| {
+                    RepositoryError::QueryExecution(format!(
+                        "[SqliteOrderRepository::apply_reservation_changes] SKU: {}, Error: {}",
+                        sku_id, e
+                    ))
+                })?;
+
+            if change.quantity_delta > 0 && result.rows_affected() == 0 {
+                let available: Option<i64> = sqlx::query_scalar(
+                    "SELECT stock_quantity - reserved_quantity FROM skus WHERE id = ?1",
+                )
+                .bind(&sku_id)
+                .fetch_optional(&mut **tx)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+                shortages.push(StockShortage {
+                    sku_code: change.sku_code.value().to_string(),
+                    requested: change.quantity_delta as u32,
+                    available: available.unwrap_or(0).max(0) as u32,
+                });
+            }
+        }
+
+        if !shortages.is_empty() {
+            return Err(RepositoryError::InsufficientStock(shortages));
+        }
+
+        Ok(())
+    }
+
+    /// 注文IDに紐づく注文アイテムをまとめて取得
+    async fn find_items_by_order_ids(
+        &self,
+        order_ids: &[String],
+    ) -> Result<Vec<(String, OrderItem)>, RepositoryError> {
+        if order_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // プレースホルダーを動的に生成
+        let placeholders = order_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
 
+        let query = format!(
+            r#"
+            SELECT order_id, sku_id, sku_code, product_name, sku_name, unit_price, quantity,
+                tax_category, discount_amount
+            FROM order_items
+            WHERE order_id IN ({})
+            ORDER BY id ASC
+            "#,
+            placeholders
+        );
+
+        let mut query_builder = sqlx::query(&query);
+        for order_id in order_ids {
+            query_builder = query_builder.bind(order_id);
+        }
+
+        let item_rows = query_builder.fetch_all(&self.pool).await.map_err(|e| {
+            RepositoryError::QueryExecution(format!(
+                "[SqliteOrderRepository::find_items_by_order_ids] {}",
+                e
+            ))
+        })?;
+
+        item_rows
+            .iter()
+            .map(|row| {
+                let order_id: String = get_column(row, "order_id")?;
+                Ok((order_id, Self::row_to_order_item(row)?))
+            })
+            .collect()
+    }
+
+    /// order_itemsの行からOrderItemを復元
+    fn row_to_order_item(row: &SqliteRow) -> Result<OrderItem, RepositoryError> {
+        let sku_id: String = get_column(row, "sku_id")?;
+        let sku_id = Uuid::parse_str(&sku_id)
+            .map(SKUId::from_uuid)
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let sku_code = SKUCode::new(get_column(row, "sku_code")?)
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let product_name = ProductName::new(get_column(row, "product_name")?)
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let sku_name = SKUName::new(get_column(row, "sku_name")?)
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let unit_price: i64 = get_column(row, "unit_price")?;
+        let quantity: i64 = get_column(row, "quantity")?;
+        let tax_category = get_column::<String>(row, "tax_category")?
+            .parse::<TaxCategory>()
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let discount_amount: i64 = get_column(row, "discount_amount")?;
+
+        Ok(OrderItem::new(
+            sku_id,
+            sku_code,
+            product_name,
+            sku_name,
+            Money::from_yen(unit_price as u32),
+            quantity as i32,
+        )
+        .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?
+        .with_tax_category(tax_category)
+        .with_discount_amount(Money::from_yen(discount_amount as u32)))
+    }
+
+    /// 注文IDに紐づく税率別内訳をまとめて取得（税率の高い順）
+    async fn find_tax_breakdowns_by_order_ids(
+        &self,
+        order_ids: &[String],
+    ) -> Result<Vec<(String, TaxBreakdown)>, RepositoryError> {
+        if order_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = order_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let query = format!(
+            r#"
+            SELECT order_id, rate, taxable_amount, tax_amount
+            FROM order_tax_breakdowns
+            WHERE order_id IN ({})
+            ORDER BY rate DESC
+            "#,
+            placeholders
+        );
+
+        let mut query_builder = sqlx::query(&query);
+        for order_id in order_ids {
+            query_builder = query_builder.bind(order_id);
+        }
+
+        let rows = query_builder.fetch_all(&self.pool).await.map_err(|e| {
+            RepositoryError::QueryExecution(format!(
+                "[SqliteOrderRepository::find_tax_breakdowns_by_order_ids] {}",
+                e
+            ))
+        })?;
+
+        rows.iter()
+            .map(|row| {
+                let order_id: String = get_column(row, "order_id")?;
+                let rate: i64 = get_column(row, "rate")?;
+                let taxable_amount: i64 = get_column(row, "taxable_amount")?;
+                let tax_amount: i64 = get_column(row, "tax_amount")?;
+                Ok((
+                    order_id,
+                    TaxBreakdown::new(
+                        rate as u32,
+                        Money::from_yen(taxable_amount as u32),
+                        Money::from_yen(tax_amount as u32),
+                    ),
+                ))
+            })
+            .collect()
+    }
+
+    /// 注文の税率別内訳を保存し直す
+    async fn replace_tax_breakdowns(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        order: &Order,
+    ) -> Result<(), RepositoryError> {
+        let order_id = order.id.value().to_string();
+        sqlx::query("DELETE FROM order_tax_breakdowns WHERE order_id = ?")
+            .bind(&order_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| {
+                RepositoryError::QueryExecution(format!(
+                    "[SqliteOrderRepository::replace_tax_breakdowns] {}",
+                    e
+                ))
+            })?;
+
+        for breakdown in &order.pricing.tax_breakdown {
+            sqlx::query(
+                r#"
+                INSERT INTO order_tax_breakdowns (order_id, rate, taxable_amount, tax_amount)
+                VALUES (?1, ?2, ?3, ?4)
+                "#,
+            )
+            .bind(&order_id)
+            .bind(breakdown.rate as i64)
+            .bind(breakdown.taxable_amount.yen() as i64)
+            .bind(breakdown.tax_amount.yen() as i64)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| {
+                RepositoryError::QueryExecution(format!(
+                    "[SqliteOrderRepository::replace_tax_breakdowns] {}",
+                    e
+                ))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// 注文IDに紐づく適用プロモーションをまとめて取得（適用した順）
+    async fn find_promotions_by_order_ids(
+        &self,
+        order_ids: &[String],
+    ) -> Result<Vec<(String, AppliedPromotion)>, RepositoryError> {
+        if order_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = order_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let query = format!(
+            r#"
+            SELECT order_id, promotion_id, promotion_name, discount_amount, waives_shipping
+            FROM order_promotions
+            WHERE order_id IN ({})
+            ORDER BY sort_order ASC
+            "#,
+            placeholders
+        );
+
+        let mut query_builder = sqlx::query(&query);
+        for order_id in order_ids {
+            query_builder = query_builder.bind(order_id);
+        }
+
+        let rows = query_builder.fetch_all(&self.pool).await.map_err(|e| {
+            RepositoryError::QueryExecution(format!(
+                "[SqliteOrderRepository::find_promotions_by_order_ids] {}",
+                e
+            ))
+        })?;
+
+        rows.iter()
+            .map(|row| {
+                let order_id: String = get_column(row, "order_id")?;
+                let promotion_id = Uuid::parse_str(&get_column::<String>(row, "promotion_id")?)
+                    .map(PromotionId::from_uuid)
+                    .map_err(|e| {
+                        RepositoryError::DataConversionError(format!("Invalid promotion ID: {}", e))
+                    })?;
+                let discount_amount: i64 = get_column(row, "discount_amount")?;
+                Ok((
+                    order_id,
+                    AppliedPromotion::new(
+                        promotion_id,
+                        get_column(row, "promotion_name")?,
+                        Money::from_yen(discount_amount as u32),
+                        get_column(row, "waives_shipping")?,
+                    ),
+                ))
+            })
+            .collect()
+    }
+
+    /// 注文の適用プロモーションを保存し直す
+    async fn replace_promotions(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        order: &Order,
+    ) -> Result<(), RepositoryError> {
+        let order_id = order.id.value().to_string();
+        sqlx::query("DELETE FROM order_promotions WHERE order_id = ?")
+            .bind(&order_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| {
+                RepositoryError::QueryExecution(format!(
+                    "[SqliteOrderRepository::replace_promotions] {}",
+                    e
+                ))
+            })?;
+
+        for (sort_order, promotion) in order.promotions.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO order_promotions (
+                    order_id, promotion_id, promotion_name, discount_amount, waives_shipping, sort_order
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                "#,
+            )
+            .bind(&order_id)
+            .bind(promotion.promotion_id.value().to_string())
+            .bind(&promotion.name)
+            .bind(promotion.discount.yen() as i64)
+            .bind(promotion.waives_shipping)
+            .bind(sort_order as i64)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| {
+                RepositoryError::QueryExecution(format!(
+                    "[SqliteOrderRepository::replace_promotions] {}",
+                    e
+                ))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// ordersの行と注文アイテム・配送情報からOrder集約を復元
+    fn row_to_order(
+        &self,
+        row: &SqliteRow,
+        items: Vec<OrderItem>,
+        tax_breakdown: Vec<TaxBreakdown>,
+        promotions: Vec<AppliedPromotion>,
+        delivery_info: Option<DeliveryInfo>,
+    ) -> Result<Order, RepositoryError> {
+        let id: String = get_column(row, "id")?;
+        let id = Uuid::parse_str(&id)
+            .map(OrderId::from_uuid)
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let order_number: String = get_column(row, "order_number")?;
+        let order_number =
+            OrderNumber::from_string_with_format(order_number.clone(), &self.order_number_format)
+                .or_else(|_| {
+                    OrderNumber::from_string_with_format(order_number, &OrderNumberFormat::default())
+                })
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+
+        // 顧客情報
+        let personal_info = PersonalInfo::from_strings(
+            get_column(row, "customer_first_name")?,
+            get_column(row, "customer_last_name")?,
+        )
+        .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let email = Email::new(get_column(row, "customer_email")?)
+            .map_err(|e| RepositoryError::DataConversionError(format!("{:?}", e)))?;
+        let phone = PhoneNumber::new(get_column(row, "customer_phone")?)
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let customer_info = CustomerInfo::new(personal_info, email, phone);
+        let customer_id = get_column::<Option<String>>(row, "customer_id")?
+            .map(|value| Uuid::parse_str(&value).map(CustomerId::from_uuid))
+            .transpose()
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+
+        // 配送情報
+        let shipping_method_id = ShippingMethodId::new(get_column(row, "shipping_method_id")?)
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let shipping_method_name: Option<String> = get_column(row, "shipping_method_name")?;
+        let shipping_fee: i64 = get_column(row, "shipping_fee")?;
+        let address = Address::new(
+            get_column(row, "shipping_postal_code")?,
+            get_column(row, "shipping_prefecture")?,
+            get_column(row, "shipping_city")?,
+            get_column(row, "shipping_street")?,
+            get_column(row, "shipping_building")?,
+        )
+        .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let shipping_info = ShippingInfo::new(
+            shipping_method_id,
+            shipping_method_name.unwrap_or_default(),
+            Money::from_yen(shipping_fee as u32),
+            address,
+        );
+
+        // 支払い情報
+        let payment_method_id = PaymentMethodId::new(get_column(row, "payment_method_id")?)
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let payment_method_name: Option<String> = get_column(row, "payment_method_name")?;
+        let payment_fee: i64 = get_column(row, "payment_fee")?;
+        let payment_details: Option<String> = get_column(row, "payment_details")?;
+        let payment_info = PaymentInfo::new(
+            payment_method_id,
+            payment_method_name.unwrap_or_default(),
+            Money::from_yen(payment_fee as u32),
+            payment_details.map(|d| PaymentDetails::from_json_string(&d)),
+        );
+
+        // 価格情報（保存時の金額をそのまま復元する）
+        let subtotal: i64 = get_column(row, "subtotal")?;
+        let shipping_fee_total: i64 = get_column(row, "shipping_fee_total")?;
+        let payment_fee_total: i64 = get_column(row, "payment_fee_total")?;
+        let tax_amount: i64 = get_column(row, "tax_amount")?;
+        let total_amount: i64 = get_column(row, "total_amount")?;
+        let discount_amount: i64 = get_column(row, "discount_amount")?;
+        let prices_include_tax: bool = get_column(row, "prices_include_tax")?;
+        let pricing = OrderPricing::new(
+            Money::from_yen(subtotal as u32),
+            Money::from_yen(discount_amount as u32),
+            Money::from_yen(shipping_fee_total as u32),
+            Money::from_yen(payment_fee_total as u32),
+            Money::from_yen(tax_amount as u32),
+            Money::from_yen(total_amount as u32),
+        )
+        .with_tax_breakdown(tax_breakdown, prices_include_tax);
+
+        // 適用クーポン（割引額の合計からプロモーションの割引を除いた分がクーポンの割引額）
+        let promotion_discount: u32 = promotions.iter().map(|p| p.discount.yen()).sum();
+        let coupon_discount = (discount_amount as u32).saturating_sub(promotion_discount);
+        let coupon_id: Option<String> = get_column(row, "coupon_id")?;
+        let coupon_code: Option<String> = get_column(row, "coupon_code")?;
+        let coupon = match (coupon_id, coupon_code) {
+            (Some(coupon_id), Some(coupon_code)) => Some(AppliedCoupon::new(
+                CouponId::from_uuid(Uuid::parse_str(&coupon_id).map_err(|e| {
+                    RepositoryError::DataConversionError(format!("Invalid coupon ID: {}", e))
+                })?),
+                CouponCode::from_string(coupon_code)
+                    .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?,
+                Money::from_yen(coupon_discount),
+            )),
+            _ => None,
+        };
+
+        let status: String = get_column(row, "status")?;
+        let status = status
+            .parse::<OrderStatus>()
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+
+        let timestamps = OrderTimestamps {
+            created_at: parse_timestamp(&get_column::<String>(row, "created_at")?)?,
+            updated_at: parse_timestamp(&get_column::<String>(row, "updated_at")?)?,
+            paid_at: parse_optional_timestamp(get_column(row, "paid_at")?)?,
+            shipped_at: parse_optional_timestamp(get_column(row, "shipped_at")?)?,
+            delivered_at: parse_optional_timestamp(get_column(row, "delivered_at")?)?,
+            cancelled_at: parse_optional_timestamp(get_column(row, "cancelled_at")?)?,
+        };
+
+        Ok(Order {
+            id,
+            order_number,
+            customer_info,
+            customer_id,
+            items,
+            shipping_info,
+            payment_info,
+            pricing,
+            coupon,
+            promotions,
+            status,
+            timestamps,
+            delivery_info,
+            notes: get_column(row, "notes")?,
+            version: get_column::<i64>(row, "version")? as u32,
+            events: Vec::new(),
+        })
+    }
+
+    /// 新しい注文を挿入（注文アイテム・在庫の引当・クーポンの使用回数の消費を含む）
+    async fn insert_order(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        order: &Order,
+    ) -> Result<(), RepositoryError> {
         // 注文データを挿入
         sqlx::query(
             r#"
@@ -40,10 +695,12 @@ impl OrderRepository for SqliteOrderRepository {
                 shipping_street, shipping_building, payment_method_id,
                 payment_fee, payment_details, subtotal, shipping_fee_total,
                 payment_fee_total, tax_amount, total_amount, status,
-                created_at, updated_at, notes
+                created_at, updated_at, notes, coupon_id, coupon_code,
+                discount_amount, prices_include_tax, customer_id
             ) VALUES (
                 ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14,
-                ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25
+                ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26,
+                ?27, ?28, ?29, ?30
             )
             "#,
         )
@@ -78,7 +735,12 @@ impl OrderRepository for SqliteOrderRepository {
         .bind(order.timestamps.created_at.to_rfc3339())
         .bind(order.timestamps.updated_at.to_rfc3339())
         .bind(order.notes.as_deref())
-        .execute(&mut *tx)
+        .bind(order.coupon.as_ref().map(|c| c.coupon_id.value().to_string()))
+        .bind(order.coupon.as_ref().map(|c| c.code.value().to_string()))
+        .bind(order.pricing.discount.amount_in_yen() as i64)
+        .bind(order.pricing.tax_included)
+        .bind(order.customer_id.map(|id| id.to_string()))
+        .execute(&mut **tx)
         .await
         .map_err(|e| {
             let error_msg = e.to_string();
@@ -116,8 +778,8 @@ impl OrderRepository for SqliteOrderRepository {
                 r#"
                 INSERT INTO order_items (
                     order_id, sku_id, sku_code, product_name, sku_name,
-                    unit_price, quantity, subtotal
-                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                    unit_price, quantity, subtotal, tax_category, discount_amount
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
                 "#,
             )
             .bind(order.id.value().to_string())
@@ -128,7 +790,9 @@ impl OrderRepository for SqliteOrderRepository {
             .bind(item.unit_price.amount_in_yen() as i64)
             .bind(item.quantity)
             .bind(item.subtotal().unwrap().amount_in_yen() as i64)
-            .execute(&mut *tx)
+            .bind(item.tax_category.code())
+            .bind(item.discount_amount.amount_in_yen() as i64)
+            .execute(&mut **tx)
             .await
             .map_err(|e| {
                 let error_msg = e.to_string();
@@ -157,17 +821,122 @@ impl OrderRepository for SqliteOrderRepository {
             })?;
         }
 
-        tx.commit()
+        // 税率別内訳・適用プロモーションを保存
+        Self::replace_tax_breakdowns(tx, order).await?;
+        Self::replace_promotions(tx, order).await?;
+
+        // 在庫の引当
+        Self::apply_stock_effects(tx, order).await?;
+
+        // クーポンの使用回数を消費（上限に達していれば注文ごとロールバック）
+        if let Some(coupon) = &order.coupon {
+            let consumed = increment_usage_count(&mut **tx, &coupon.coupon_id)
+                .await
+                .map_err(|e| {
+                    RepositoryError::QueryExecution(format!(
+                        "[SqliteOrderRepository::save_coupon_usage] {}",
+                        e
+                    ))
+                })?;
+
+            if !consumed {
+                return Err(RepositoryError::CouponUsageLimitExceeded {
+                    code: coupon.code.value().to_string(),
+                });
+            }
+
+            // 顧客ごとの使用履歴を記録（顧客ごとの上限に達していればロールバック）
+            let recorded = record_redemption(
+                &mut **tx,
+                &coupon.coupon_id,
+                &order.id,
+                order.customer_info.email.value(),
+            )
             .await
             .map_err(|e| {
-                let error_msg = e.to_string();
-                println!("->> [SqliteOrderRepository::save] Transaction commit failed: {}", error_msg);
-                RepositoryError::QueryExecution(format!("[SqliteOrderRepository::save_transaction_commit] {}", error_msg))
+                RepositoryError::QueryExecution(format!(
+                    "[SqliteOrderRepository::save_coupon_redemption] {}",
+                    e
+                ))
             })?;
 
-        Ok(())
+            if !recorded {
+                return Err(RepositoryError::CouponCustomerLimitExceeded {
+                    code: coupon.code.value().to_string(),
+                });
+            }
+        }
+
+        // ドメインイベントを記録
+        Self::insert_events(tx, order).await
+    }
+
+    /// スコープの連番を1つ進めて返す
+    /// 最初の書き込みで書き込みロックを取得するため、同時に採番しても同じ値は返らない
+    async fn next_sequence(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        scope: &str,
+    ) -> Result<u32, RepositoryError> {
+        let value: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO order_sequences (scope, last_value) VALUES (?1, 1)
+            ON CONFLICT(scope) DO UPDATE SET last_value = last_value + 1
+            RETURNING last_value
+            "#,
+        )
+        .bind(scope)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| {
+            RepositoryError::QueryExecution(format!(
+                "[SqliteOrderRepository::next_sequence] {}",
+                e
+            ))
+        })?;
+
+        u32::try_from(value).map_err(|e| RepositoryError::DataConversionError(e.to_string()))
+    }
+
+    /// 単一の注文行からOrder集約を復元（注文アイテム・配送情報も取得）
+    async fn load_order(&self, row: &SqliteRow) -> Result<Order, RepositoryError> {
+        let order_id: String = get_column(row, "id")?;
+        let items = self
+            .find_items_by_order_ids(std::slice::from_ref(&order_id))
+            .await?
+            .into_iter()
+            .map(|(_, item)| item)
+            .collect();
+        let tax_breakdown = self
+            .find_tax_breakdowns_by_order_ids(std::slice::from_ref(&order_id))
+            .await?
+            .into_iter()
+            .map(|(_, breakdown)| breakdown)
+            .collect();
+        let promotions = self
+            .find_promotions_by_order_ids(std::slice::from_ref(&order_id))
+            .await?
+            .into_iter()
+            .map(|(_, promotion)| promotion)
+            .collect();
+
+        let delivery_info_id: Option<String> = get_column(row, "delivery_info_id")?;
+        let delivery_info = find_delivery_infos_by_ids(&self.pool, delivery_info_id.as_slice())
+            .await?
+            .into_iter()
+            .next();
+
+        self.row_to_order(row, items, tax_breakdown, promotions, delivery_info)
     }
+}
 
+fn parse_optional_timestamp(
+    value: Option<String>,
+) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+    value.as_deref().map(parse_timestamp).transpose()
+}
+
+#[async_trait]
+impl OrderRepository for SqliteOrderRepository {
     async fn update(&self, order: &Order) -> Result<(), RepositoryError> {
         let mut tx = self
             .pool
@@ -175,8 +944,9 @@ impl OrderRepository for SqliteOrderRepository {
             .await
             .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
 
-        // 注文データを更新
-        sqlx::query(
+        // 注文データを更新（読み込み後に他の更新が行われていれば競合として中断し、
+        // 在庫の増減・イベントの記録が重複しないようにする）
+        let result = sqlx::query(
             r#"
             UPDATE orders SET
                 customer_first_name = ?1, customer_last_name = ?2,
@@ -189,8 +959,11 @@ impl OrderRepository for SqliteOrderRepository {
                 subtotal = ?15, shipping_fee_total = ?16,
                 payment_fee_total = ?17, tax_amount = ?18,
                 total_amount = ?19, status = ?20, updated_at = ?21,
-                notes = ?22
-            WHERE id = ?23
+                notes = ?22, paid_at = ?23, shipped_at = ?24,
+                delivered_at = ?25, cancelled_at = ?26,
+                discount_amount = ?27, delivery_info_id = ?28,
+                prices_include_tax = ?29, version = version + 1
+            WHERE id = ?30 AND version = ?31
             "#,
         )
         .bind(order.customer_info.personal_info.first_name().value())
@@ -221,11 +994,34 @@ impl OrderRepository for SqliteOrderRepository {
         .bind(order.status.to_string())
         .bind(order.timestamps.updated_at.to_rfc3339())
         .bind(order.notes.as_deref())
+        .bind(order.timestamps.paid_at.map(|t| t.to_rfc3339()))
+        .bind(order.timestamps.shipped_at.map(|t| t.to_rfc3339()))
+        .bind(order.timestamps.delivered_at.map(|t| t.to_rfc3339()))
+        .bind(order.timestamps.cancelled_at.map(|t| t.to_rfc3339()))
+        .bind(order.pricing.discount.amount_in_yen() as i64)
+        .bind(order.delivery_info.as_ref().map(|d| d.id().to_string()))
+        .bind(order.pricing.tax_included)
         .bind(order.id.value().to_string())
+        .bind(order.version as i64)
         .execute(&mut *tx)
         .await
         .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
 
+        if result.rows_affected() == 0 {
+            let exists: Option<i64> = sqlx::query_scalar("SELECT 1 FROM orders WHERE id = ?1")
+                .bind(order.id.value().to_string())
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+            return Err(match exists {
+                Some(_) => RepositoryError::Conflict(format!(
+                    "Order {} was modified by another request",
+                    order.id.value()
+                )),
+                None => RepositoryError::NotFound,
+            });
+        }
+
         // 既存の注文アイテムを削除
         sqlx::query("DELETE FROM order_items WHERE order_id = ?")
             .bind(order.id.value().to_string())
@@ -239,8 +1035,8 @@ impl OrderRepository for SqliteOrderRepository {
                 r#"
                 INSERT INTO order_items (
                     order_id, sku_id, sku_code, product_name, sku_name,
-                    unit_price, quantity, subtotal
-                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                    unit_price, quantity, subtotal, tax_category, discount_amount
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
                 "#,
             )
             .bind(order.id.value().to_string())
@@ -251,11 +1047,35 @@ impl OrderRepository for SqliteOrderRepository {
             .bind(item.unit_price.amount_in_yen() as i64)
             .bind(item.quantity)
             .bind(item.subtotal().unwrap().amount_in_yen() as i64)
+            .bind(item.tax_category.code())
+            .bind(item.discount_amount.amount_in_yen() as i64)
             .execute(&mut *tx)
             .await
             .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
         }
 
+        // 税率別内訳・適用プロモーションを保存し直す
+        Self::replace_tax_breakdowns(&mut tx, order).await?;
+        Self::replace_promotions(&mut tx, order).await?;
+
+        // 配送情報を保存
+        if let Some(delivery_info) = &order.delivery_info {
+            upsert_delivery_info(&mut *tx, &order.id, delivery_info)
+                .await
+                .map_err(|e| {
+                    RepositoryError::QueryExecution(format!(
+                        "[SqliteOrderRepository::update_delivery_info] {}",
+                        e
+                    ))
+                })?;
+        }
+
+        // ステータス変更に伴う在庫の減算・引当解除
+        Self::apply_stock_effects(&mut tx, order).await?;
+
+        // ドメインイベントを記録
+        Self::insert_events(&mut tx, order).await?;
+
         tx.commit()
             .await
             .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
@@ -263,43 +1083,269 @@ impl OrderRepository for SqliteOrderRepository {
         Ok(())
     }
     
-    async fn get_next_sequence_number(&self, year: i32) -> Result<u32, RepositoryError> {
-        // 指定された年の注文番号の最大シーケンス番号を取得
-        let pattern = format!("ORD-{}-______", year);
-        
-        let result = sqlx::query_scalar::<_, String>(
+    async fn create(
+        &self,
+        format: &OrderNumberFormat,
+        now: DateTime<Utc>,
+        factory: OrderFactory,
+    ) -> Result<Order, ApplicationError> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            RepositoryError::QueryExecution(format!(
+                "[SqliteOrderRepository::create_transaction_begin] {}",
+                e
+            ))
+        })?;
+
+        // 採番（ロールバックした場合は連番も元に戻る）
+        let sequence = Self::next_sequence(&mut tx, &format.sequence_scope(now)).await?;
+        let order_number = format.generate(now, sequence)?;
+        let order = factory(order_number)?;
+
+        Self::insert_order(&mut tx, &order).await?;
+
+        tx.commit().await.map_err(|e| {
+            RepositoryError::QueryExecution(format!(
+                "[SqliteOrderRepository::create_transaction_commit] {}",
+                e
+            ))
+        })?;
+
+        Ok(order)
+    }
+
+    async fn find_by_id(&self, id: &OrderId) -> Result<Option<Order>, RepositoryError> {
+        let query = format!("{} WHERE o.id = ?", ORDER_SELECT);
+
+        let row = sqlx::query(&query)
+            .bind(id.value().to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                RepositoryError::QueryExecution(format!("[SqliteOrderRepository::find_by_id] {}", e))
+            })?;
+
+        match row {
+            Some(row) => Ok(Some(self.load_order(&row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_order_number(
+        &self,
+        order_number: &OrderNumber,
+    ) -> Result<Option<Order>, RepositoryError> {
+        // 書式に一致しない注文番号（入力ミスなど）は照会するまでもなく存在しない
+        if !self.is_known_order_number(order_number) {
+            return Ok(None);
+        }
+
+        let query = format!("{} WHERE o.order_number = ?", ORDER_SELECT);
+
+        let row = sqlx::query(&query)
+            .bind(order_number.value())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                RepositoryError::QueryExecution(format!(
+                    "[SqliteOrderRepository::find_by_order_number] {}",
+                    e
+                ))
+            })?;
+
+        match row {
+            Some(row) => Ok(Some(self.load_order(&row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all(
+        &self,
+        criteria: &OrderSearchCriteria,
+    ) -> Result<(Vec<Order>, u32), RepositoryError> {
+        // 検索条件からWHERE句を動的に構築
+        let mut conditions: Vec<&str> = Vec::new();
+        let mut binds: Vec<String> = Vec::new();
+
+        if let Some(status) = &criteria.status {
+            conditions.push("o.status = ?");
+            binds.push(status.to_string());
+        }
+        if let Some(created_from) = &criteria.created_from {
+            conditions.push("o.created_at >= ?");
+            binds.push(created_from.to_rfc3339());
+        }
+        if let Some(created_to) = &criteria.created_to {
+            conditions.push("o.created_at < ?");
+            binds.push(created_to.to_rfc3339());
+        }
+        if let Some(customer_email) = &criteria.customer_email {
+            conditions.push("LOWER(o.customer_email) = LOWER(?)");
+            binds.push(customer_email.clone());
+        }
+        if let Some(customer_id) = &criteria.customer_id {
+            conditions.push("o.customer_id = ?");
+            binds.push(customer_id.to_string());
+        }
+
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+
+        // 総件数を取得
+        let count_query = format!("SELECT COUNT(*) FROM orders o {}", where_clause);
+        let mut count_builder = sqlx::query_scalar::<_, i64>(&count_query);
+        for value in &binds {
+            count_builder = count_builder.bind(value);
+        }
+        let total_count = count_builder.fetch_one(&self.pool).await.map_err(|e| {
+            RepositoryError::QueryExecution(format!("[SqliteOrderRepository::find_all_count] {}", e))
+        })?;
+
+        // 該当ページの注文を取得
+        let query = format!(
+            "{} {} ORDER BY o.created_at DESC, o.order_number DESC LIMIT ? OFFSET ?",
+            ORDER_SELECT, where_clause
+        );
+        let mut query_builder = sqlx::query(&query);
+        for value in &binds {
+            query_builder = query_builder.bind(value);
+        }
+        let order_rows = query_builder
+            .bind(criteria.per_page as i64)
+            .bind(criteria.offset() as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                RepositoryError::QueryExecution(format!("[SqliteOrderRepository::find_all] {}", e))
+            })?;
+
+        // 注文アイテムをまとめて取得
+        let order_ids: Vec<String> = order_rows
+            .iter()
+            .map(|row| get_column(row, "id"))
+            .collect::<Result<Vec<String>, _>>()?;
+        let mut items = self.find_items_by_order_ids(&order_ids).await?;
+        let mut tax_breakdowns = self.find_tax_breakdowns_by_order_ids(&order_ids).await?;
+        let mut promotions = self.find_promotions_by_order_ids(&order_ids).await?;
+
+        // 配送情報をまとめて取得
+        let delivery_info_ids: Vec<String> = order_rows
+            .iter()
+            .map(|row| get_column::<Option<String>>(row, "delivery_info_id"))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+        let mut delivery_infos = find_delivery_infos_by_ids(&self.pool, &delivery_info_ids).await?;
+
+        let mut orders = Vec::with_capacity(order_rows.len());
+        for (order_row, order_id) in order_rows.iter().zip(&order_ids) {
+            let (order_items, rest): (Vec<_>, Vec<_>) =
+                items.into_iter().partition(|(id, _)| id == order_id);
+            items = rest;
+
+            let order_items = order_items.into_iter().map(|(_, item)| item).collect();
+            let (tax_breakdown, rest): (Vec<_>, Vec<_>) =
+                tax_breakdowns.into_iter().partition(|(id, _)| id == order_id);
+            tax_breakdowns = rest;
+            let tax_breakdown = tax_breakdown.into_iter().map(|(_, b)| b).collect();
+            let (order_promotions, rest): (Vec<_>, Vec<_>) =
+                promotions.into_iter().partition(|(id, _)| id == order_id);
+            promotions = rest;
+            let order_promotions = order_promotions.into_iter().map(|(_, p)| p).collect();
+            let delivery_info_id: Option<String> = get_column(order_row, "delivery_info_id")?;
+            let delivery_info = delivery_info_id.and_then(|id| {
+                let index = delivery_infos
+                    .iter()
+                    .position(|delivery_info| delivery_info.id().to_string() == id)?;
+                Some(delivery_infos.swap_remove(index))
+            });
+            orders.push(self.row_to_order(
+                order_row,
+                order_items,
+                tax_breakdown,
+                order_promotions,
+                delivery_info,
+            )?);
+        }
+
+        Ok((orders, total_count as u32))
+    }
+
+    async fn record_document_issue(
+        &self,
+        id: &OrderId,
+        kind: OrderDocumentKind,
+    ) -> Result<u32, RepositoryError> {
+        let sql = match kind {
+            OrderDocumentKind::Invoice => {
+                r#"
+                UPDATE orders SET invoice_issue_count = invoice_issue_count + 1
+                WHERE id = ?1
+                RETURNING invoice_issue_count
+                "#
+            }
+            OrderDocumentKind::Receipt => {
+                r#"
+                UPDATE orders SET receipt_issue_count = receipt_issue_count + 1
+                WHERE id = ?1
+                RETURNING receipt_issue_count
+                "#
+            }
+        };
+
+        let count: Option<i64> = sqlx::query_scalar(sql)
+            .bind(id.value().to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                RepositoryError::QueryExecution(format!(
+                    "[SqliteOrderRepository::record_document_issue] {}",
+                    e
+                ))
+            })?;
+
+        count
+            .map(|count| count as u32)
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn find_events_by_order_id(
+        &self,
+        id: &OrderId,
+    ) -> Result<Vec<OrderEventDTO>, RepositoryError> {
+        let event_rows = sqlx::query(
             r#"
-            SELECT order_number 
-            FROM orders 
-            WHERE order_number LIKE ?1
-            ORDER BY order_number DESC 
-            LIMIT 1
+            SELECT id, event_type, event_data, created_at
+            FROM order_events
+            WHERE order_id = ?
+            ORDER BY created_at ASC, id ASC
             "#,
         )
-        .bind(pattern)
-        .fetch_optional(&self.pool)
+        .bind(id.value().to_string())
+        .fetch_all(&self.pool)
         .await
         .map_err(|e| {
-            let error_msg = e.to_string();
-            println!("->> [SqliteOrderRepository::get_next_sequence_number] Query failed: {}", error_msg);
-            RepositoryError::QueryExecution(format!("[SqliteOrderRepository::get_next_sequence_number] {}", error_msg))
+            RepositoryError::QueryExecution(format!(
+                "[SqliteOrderRepository::find_events_by_order_id] {}",
+                e
+            ))
         })?;
 
-        match result {
-            Some(order_number) => {
-                // ORD-YYYY-NNNNNN から NNNNNN 部分を抽出
-                if let Some(sequence_part) = order_number.split('-').nth(2) {
-                    if let Ok(current_seq) = sequence_part.parse::<u32>() {
-                        return Ok(current_seq + 1);
-                    }
-                }
-                // パースに失敗した場合は1から開始
-                Ok(1)
-            }
-            None => {
-                // 該当年の注文が存在しない場合は1から開始
-                Ok(1)
-            }
-        }
+        event_rows
+            .iter()
+            .map(|row| {
+                let event_data: String = get_column(row, "event_data")?;
+                Ok(OrderEventDTO {
+                    id: get_column(row, "id")?,
+                    event_type: get_column(row, "event_type")?,
+                    event_data: serde_json::from_str(&event_data)
+                        .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?,
+                    created_at: parse_timestamp(&get_column::<String>(row, "created_at")?)?,
+                })
+            })
+            .collect()
     }
 }
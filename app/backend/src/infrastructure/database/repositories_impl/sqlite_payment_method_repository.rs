@@ -1,10 +1,20 @@
 use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
 use sqlx::{Row, SqlitePool};
 
 use crate::application::dto::{PaymentMethodDTO, PaymentMethodListDTO};
 use crate::application::error::RepositoryError;
 use crate::application::repositories::PaymentMethodRepository;
-use crate::domain::entities::PaymentMethod;
+use crate::domain::entities::{PaymentFeeBracket, PaymentFeeKind, PaymentFeeRule, PaymentMethod};
+use crate::domain::value_objects::Money;
+
+/// 支払い方法取得用のSELECT句
+const PAYMENT_METHOD_SELECT: &str = r#"
+    SELECT
+        id, name, description, is_active, sort_order,
+        fee_type, fee_amount, fee_rate, min_fee, max_fee
+    FROM payment_methods
+"#;
 
 /// SQLite実装のPaymentMethodRepository
 /// Clean Architecture: Infrastructure層
@@ -16,51 +26,176 @@ impl SqlitePaymentMethodRepository {
     pub fn new(pool: SqlitePool) -> Self {
         Self { pool }
     }
+
+    /// 支払い方法の金額帯を取得（上限の昇順）
+    async fn find_brackets(
+        &self,
+        payment_method_id: &str,
+    ) -> Result<Vec<PaymentFeeBracket>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT up_to, fee FROM payment_fee_brackets WHERE payment_method_id = ? ORDER BY position",
+        )
+        .bind(payment_method_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                PaymentFeeBracket::new(
+                    row.get::<Option<i64>, _>("up_to")
+                        .map(|up_to| Money::from_yen(up_to as u32)),
+                    Money::from_yen(row.get::<i64, _>("fee") as u32),
+                )
+            })
+            .collect())
+    }
+
+    /// payment_methodsの行と金額帯から支払い方法を復元
+    async fn row_to_payment_method(
+        &self,
+        row: &SqliteRow,
+    ) -> Result<PaymentMethod, RepositoryError> {
+        let id: String = row.get("id");
+        let fee_type: String = row.get("fee_type");
+
+        let kind = match fee_type.as_str() {
+            "flat" => PaymentFeeKind::Flat {
+                amount: Money::from_yen(row.get::<i64, _>("fee_amount") as u32),
+            },
+            "percentage" => PaymentFeeKind::Percentage {
+                rate: row.get("fee_rate"),
+            },
+            "tiered" => PaymentFeeKind::Tiered {
+                brackets: self.find_brackets(&id).await?,
+            },
+            other => {
+                return Err(RepositoryError::DataConversionError(format!(
+                    "Unknown payment fee type: {}",
+                    other
+                )));
+            }
+        };
+        let fee_rule = PaymentFeeRule::new(
+            kind,
+            row.get::<Option<i64>, _>("min_fee")
+                .map(|fee| Money::from_yen(fee as u32)),
+            row.get::<Option<i64>, _>("max_fee")
+                .map(|fee| Money::from_yen(fee as u32)),
+        )
+        .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+
+        PaymentMethod::new(
+            id,
+            row.get::<String, _>("name"),
+            row.get::<String, _>("description"),
+            row.get::<bool, _>("is_active"),
+            row.get::<u32, _>("sort_order"),
+            fee_rule,
+        )
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))
+    }
 }
 
 #[async_trait]
 impl PaymentMethodRepository for SqlitePaymentMethodRepository {
     async fn find_all(&self) -> Result<PaymentMethodListDTO, RepositoryError> {
-        let rows = sqlx::query("SELECT id, name, description FROM payment_methods WHERE is_active = 1 ORDER BY sort_order")
-            .fetch_all(&self.pool)
-            .await
-            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        let rows = sqlx::query(&format!(
+            "{} WHERE is_active = 1 ORDER BY sort_order",
+            PAYMENT_METHOD_SELECT
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
 
-        let items: Vec<PaymentMethodDTO> = rows
-            .into_iter()
-            .map(|row| PaymentMethodDTO {
-                id: row.get("id"),
-                name: row.get("name"),
-                description: row.get("description"),
-            })
-            .collect();
+        let mut items: Vec<PaymentMethodDTO> = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let payment_method = self.row_to_payment_method(row).await?;
+            items.push(PaymentMethodDTO::from_payment_method(&payment_method));
+        }
 
         Ok(PaymentMethodListDTO::new(items))
     }
 
     async fn find_by_id(&self, id: &str) -> Result<Option<PaymentMethod>, RepositoryError> {
-        let row = sqlx::query(
-            "SELECT id, name, description, is_active, sort_order FROM payment_methods WHERE id = ?",
+        let row = sqlx::query(&format!("{} WHERE id = ?", PAYMENT_METHOD_SELECT))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        match row {
+            Some(row) => Ok(Some(self.row_to_payment_method(&row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn update(&self, payment_method: &PaymentMethod) -> Result<(), RepositoryError> {
+        let fee_rule = payment_method.fee_rule();
+        let (fee_amount, fee_rate, brackets) = match fee_rule.kind() {
+            PaymentFeeKind::Flat { amount } => (amount.yen(), 0.0, &[][..]),
+            PaymentFeeKind::Percentage { rate } => (0, *rate, &[][..]),
+            PaymentFeeKind::Tiered { brackets } => (0, 0.0, brackets.as_slice()),
+        };
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE payment_methods
+            SET name = ?1, description = ?2, is_active = ?3, sort_order = ?4,
+                fee_type = ?5, fee_amount = ?6, fee_rate = ?7, min_fee = ?8, max_fee = ?9,
+                updated_at = datetime('now')
+            WHERE id = ?10
+            "#,
         )
-        .bind(id)
-        .fetch_optional(&self.pool)
+        .bind(payment_method.name())
+        .bind(payment_method.description())
+        .bind(payment_method.is_active())
+        .bind(payment_method.sort_order() as i64)
+        .bind(fee_rule.kind().code())
+        .bind(fee_amount as i64)
+        .bind(fee_rate)
+        .bind(fee_rule.min_fee().map(|fee| fee.yen() as i64))
+        .bind(fee_rule.max_fee().map(|fee| fee.yen() as i64))
+        .bind(payment_method.id())
+        .execute(&mut *tx)
         .await
         .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
 
-        match row {
-            Some(row) => {
-                let payment_method = PaymentMethod::new(
-                    row.get::<String, _>("id"),
-                    row.get::<String, _>("name"),
-                    row.get::<String, _>("description"),
-                    row.get::<bool, _>("is_active"),
-                    row.get::<u32, _>("sort_order"),
-                )
-                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
 
-                Ok(Some(payment_method))
-            }
-            None => Ok(None),
+        // 金額帯は丸ごと入れ替える
+        sqlx::query("DELETE FROM payment_fee_brackets WHERE payment_method_id = ?")
+            .bind(payment_method.id())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        for (position, bracket) in brackets.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO payment_fee_brackets (payment_method_id, position, up_to, fee) VALUES (?, ?, ?, ?)",
+            )
+            .bind(payment_method.id())
+            .bind(position as i64 + 1)
+            .bind(bracket.up_to.map(|up_to| up_to.yen() as i64))
+            .bind(bracket.fee.yen() as i64)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
         }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(())
     }
 }
@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use sqlx::sqlite::SqliteRow;
+
+use crate::application::error::RepositoryError;
+use crate::application::repositories::PaymentRepository;
+use crate::domain::PaymentTransaction;
+use crate::domain::value_objects::{Money, OrderId};
+
+use super::row_helpers::{get_column, parse_timestamp};
+
+/// SQLite実装のPaymentRepository
+/// Clean Architecture: Frameworks & Drivers層
+pub struct SqlitePaymentRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePaymentRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn query_error(context: &str, e: impl std::fmt::Display) -> RepositoryError {
+        RepositoryError::QueryExecution(format!("[SqlitePaymentRepository::{}] {}", context, e))
+    }
+}
+
+#[async_trait]
+impl PaymentRepository for SqlitePaymentRepository {
+    async fn record(&self, transaction: &PaymentTransaction) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO payments (
+                order_id, provider, operation, amount, status,
+                transaction_id, failure_reason, created_at
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "#,
+        )
+        .bind(transaction.order_id.value().to_string())
+        .bind(&transaction.provider)
+        .bind(transaction.operation.to_string())
+        .bind(transaction.amount.yen() as i64)
+        .bind(transaction.status.to_string())
+        .bind(&transaction.transaction_id)
+        .bind(&transaction.failure_reason)
+        .bind(transaction.occurred_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("record", e))?;
+
+        Ok(())
+    }
+
+    async fn find_by_order_id(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<Vec<PaymentTransaction>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                provider, operation, amount, status,
+                transaction_id, failure_reason, created_at
+            FROM payments
+            WHERE order_id = ?1
+            ORDER BY id
+            "#,
+        )
+        .bind(order_id.value().to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_by_order_id", e))?;
+
+        rows.iter()
+            .map(|row| row_to_transaction(row, order_id))
+            .collect()
+    }
+}
+
+/// paymentsの行からPaymentTransactionを復元
+fn row_to_transaction(
+    row: &SqliteRow,
+    order_id: &OrderId,
+) -> Result<PaymentTransaction, RepositoryError> {
+    let amount: i64 = get_column(row, "amount")?;
+
+    Ok(PaymentTransaction {
+        order_id: *order_id,
+        provider: get_column(row, "provider")?,
+        operation: get_column::<String>(row, "operation")?
+            .parse()
+            .map_err(|e| RepositoryError::DataConversionError(format!("{}", e)))?,
+        amount: Money::from_yen(amount as u32),
+        status: get_column::<String>(row, "status")?
+            .parse()
+            .map_err(|e| RepositoryError::DataConversionError(format!("{}", e)))?,
+        transaction_id: get_column(row, "transaction_id")?,
+        failure_reason: get_column(row, "failure_reason")?,
+        occurred_at: parse_timestamp(&get_column::<String>(row, "created_at")?)?,
+    })
+}
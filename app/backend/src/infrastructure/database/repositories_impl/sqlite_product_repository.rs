@@ -1,10 +1,93 @@
 use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
 use sqlx::{Row, SqlitePool};
 
-use crate::application::dto::{ProductDTO, ProductListDTO, ProductSummaryDTO, VariantDTO};
+use std::collections::HashMap;
+
+use crate::application::dto::{
+    ProductDTO, ProductListDTO, ProductSearchHitDTO, ProductSearchResultDTO, ProductSummaryDTO,
+    VariantDTO,
+};
 use crate::application::error::RepositoryError;
-use crate::application::repositories::ProductRepository;
-use crate::domain::{ProductId, SKUId};
+use crate::application::repositories::{
+    ProductKeywordSearchCriteria, ProductRepository, ProductSearchCriteria, ProductSortOrder,
+};
+use crate::domain::{ProductId, SKUId, Tag, TaxCategory};
+
+use super::sku_price_sql::EFFECTIVE_SALE_PRICE_SQL;
+
+/// 各商品の代表SKU（一覧に表示するSKU）を決めるCTE
+/// 販売中のSKUのうち表示順を優先し、同順位なら在庫のあるもの、安いものを選ぶ
+/// sale_priceはリクエスト時点で適用されるセール価格（価格スケジュールを含む）
+fn first_sku_cte() -> String {
+    format!(
+        r#"
+    WITH first_sku AS (
+        SELECT
+            s.product_id,
+            s.base_price,
+            {} as sale_price,
+            s.stock_quantity,
+            s.reserved_quantity,
+            ROW_NUMBER() OVER (
+                PARTITION BY s.product_id
+                ORDER BY
+                    s.display_order ASC,
+                    CASE WHEN s.stock_quantity - s.reserved_quantity > 0 THEN 0 ELSE 1 END,
+                    s.base_price ASC,
+                    s.id ASC
+            ) as rn
+        FROM skus s
+        WHERE s.status = 'active'
+    )
+"#,
+        EFFECTIVE_SALE_PRICE_SQL
+    )
+}
+
+/// 商品一覧・件数取得で共通の結合
+const PRODUCT_LIST_JOINS: &str = r#"
+    JOIN categories c ON c.id = p.category_id
+    JOIN first_sku fs ON fs.product_id = p.id AND fs.rn = 1
+"#;
+
+/// キーワード検索の対象カラム（product_searchテーブル）
+const KEYWORD_SEARCH_COLUMNS: [&str; 5] =
+    ["name", "description", "sku_names", "materials", "colors"];
+
+/// trigramトークナイザで全文検索できる最小文字数（これより短い語はLIKEで検索する）
+const TRIGRAM_MIN_CHARS: usize = 3;
+
+/// スニペットの長さ（trigramのトークン数 = 文字数）
+const SNIPPET_LENGTH: usize = 32;
+
+/// 検索条件のバインド値（価格はINTEGERとして比較する必要があるため型を分ける）
+enum SearchBind {
+    Text(String),
+    Integer(i64),
+}
+
+impl SearchBind {
+    fn bind<'q>(
+        &'q self,
+        query: sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>>,
+    ) -> sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>> {
+        match self {
+            Self::Text(value) => query.bind(value),
+            Self::Integer(value) => query.bind(value),
+        }
+    }
+
+    fn bind_scalar<'q, O>(
+        &'q self,
+        query: sqlx::query::QueryScalar<'q, sqlx::Sqlite, O, sqlx::sqlite::SqliteArguments<'q>>,
+    ) -> sqlx::query::QueryScalar<'q, sqlx::Sqlite, O, sqlx::sqlite::SqliteArguments<'q>> {
+        match self {
+            Self::Text(value) => query.bind(value),
+            Self::Integer(value) => query.bind(value),
+        }
+    }
+}
 
 /// SQLite実装のProductRepository
 /// Clean Architecture: Frameworks & Drivers層
@@ -17,6 +100,344 @@ impl SqliteProductRepository {
     pub fn new(pool: SqlitePool) -> Self {
         Self { pool }
     }
+
+    /// 商品サマリー取得用のSELECT（代表SKUと先頭画像を結合）
+    fn product_summary_query(where_clause: &str, tail: &str) -> String {
+        format!(
+            r#"
+            {}
+            SELECT
+                p.id,
+                p.name,
+                p.is_best_seller,
+                p.is_quick_ship,
+                c.name as category_name,
+                fs.base_price,
+                fs.sale_price,
+                fs.stock_quantity,
+                fs.reserved_quantity,
+                pi.image_url as first_image
+            FROM products p
+            {}
+            LEFT JOIN (
+                SELECT product_id, image_url,
+                       ROW_NUMBER() OVER (PARTITION BY product_id ORDER BY display_order) as rn
+                FROM product_images
+            ) pi ON pi.product_id = p.id AND pi.rn = 1
+            {}
+            {}
+            "#,
+            first_sku_cte(),
+            PRODUCT_LIST_JOINS,
+            where_clause,
+            tail
+        )
+    }
+
+    /// 商品サマリーの行から色情報を付与したProductSummaryDTOを構築（行の順序を保つ）
+    async fn build_summaries(
+        &self,
+        product_rows: Vec<SqliteRow>,
+    ) -> Result<Vec<ProductSummaryDTO>, RepositoryError> {
+        if product_rows.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // 全商品のIDを収集
+        let product_ids: Vec<String> = product_rows
+            .iter()
+            .map(|row| row.try_get("id"))
+            .collect::<Result<Vec<String>, _>>()
+            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+
+        // プレースホルダーを動的に生成
+        let placeholders = product_ids
+            .iter()
+            .map(|_| "?")
+            .collect::<Vec<_>>()
+            .join(",");
+
+        // 各商品の色情報を取得
+        let color_query = format!(
+            r#"
+            SELECT DISTINCT
+                s.product_id,
+                colors.name as color_name
+            FROM skus s
+            JOIN colors ON colors.id = s.color_id
+            WHERE s.product_id IN ({}) AND s.status = 'active'
+            ORDER BY s.product_id, colors.name
+            "#,
+            placeholders
+        );
+
+        let mut color_query_builder = sqlx::query(&color_query);
+        for product_id in &product_ids {
+            color_query_builder = color_query_builder.bind(product_id);
+        }
+
+        let color_rows = color_query_builder
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let mut product_summaries = Vec::new();
+
+        for product_row in product_rows {
+            let product_id: String = product_row
+                .try_get("id")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let name: String = product_row
+                .try_get("name")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let is_best_seller: bool = product_row
+                .try_get("is_best_seller")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let is_quick_ship: bool = product_row
+                .try_get("is_quick_ship")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let category_name: String = product_row
+                .try_get("category_name")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let base_price: i64 = product_row
+                .try_get("base_price")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let sale_price: Option<i64> = product_row
+                .try_get("sale_price")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let stock_quantity: i64 = product_row
+                .try_get("stock_quantity")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let reserved_quantity: i64 = product_row
+                .try_get("reserved_quantity")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let first_image: Option<String> = product_row
+                .try_get("first_image")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+
+            // この商品の色情報を取得
+            let product_colors: Vec<String> = color_rows
+                .iter()
+                .filter(|row| {
+                    row.try_get::<String, _>("product_id")
+                        .map(|id| id == product_id)
+                        .unwrap_or(false)
+                })
+                .map(|row| row.try_get("color_name"))
+                .collect::<Result<Vec<String>, _>>()
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+
+            // 価格計算（JPYは最小単位で保存されているため100で割る）
+            let product_price = base_price as u32;
+            let sale_price_converted = sale_price.map(|p| p as u32);
+            let available_stock = (stock_quantity - reserved_quantity).max(0) as u32;
+
+            let product_summary = ProductSummaryDTO::new(
+                product_id,
+                name,
+                category_name,
+                product_price,
+                sale_price_converted,
+                first_image,
+                product_colors, // 色情報を追加
+                is_best_seller,
+                is_quick_ship,
+                available_stock,
+            );
+
+            product_summaries.push(product_summary);
+        }
+
+        Ok(product_summaries)
+    }
+
+    /// キーワードをFTS5のMATCH式に変換
+    /// 各語をフレーズとして引用符で囲むことで、記号を含む入力でも構文エラーにしない
+    fn fts_match_expression(terms: &[&String]) -> String {
+        terms
+            .iter()
+            .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// LIKE検索用に%・_・\をエスケープした部分一致パターンを作成
+    fn like_pattern(term: &str) -> String {
+        let escaped = term
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        format!("%{}%", escaped)
+    }
+
+    /// FTS5のsnippet()が使えない場合（短い語のみの検索）のスニペットを作成
+    /// 最初に語が見つかったカラムから一致箇所の前後を切り出し、語を<mark>で囲む
+    fn highlight_snippet(columns: &[String], terms: &[&String]) -> String {
+        let lower_terms: Vec<String> = terms
+            .iter()
+            .map(|term| term.to_ascii_lowercase())
+            .filter(|term| !term.is_empty())
+            .collect();
+
+        for text in columns {
+            // ASCIIのみ小文字化するため、バイト位置は元の文字列と一致する
+            let lower_text = text.to_ascii_lowercase();
+            let Some(match_start) = lower_terms
+                .iter()
+                .filter_map(|term| lower_text.find(term.as_str()))
+                .min()
+            else {
+                continue;
+            };
+
+            let char_count = text.chars().count();
+            let match_char = lower_text[..match_start].chars().count();
+            let from = match_char.saturating_sub(SNIPPET_LENGTH / 4);
+            let to = (from + SNIPPET_LENGTH).min(char_count);
+
+            let window: String = text.chars().skip(from).take(to - from).collect();
+            let lower_window = window.to_ascii_lowercase();
+
+            let mut snippet = String::new();
+            if from > 0 {
+                snippet.push('…');
+            }
+            let mut position = 0;
+            while position < window.len() {
+                let matched = lower_terms
+                    .iter()
+                    .filter(|term| lower_window[position..].starts_with(term.as_str()))
+                    .map(|term| term.len())
+                    .max();
+                match matched {
+                    Some(length) => {
+                        snippet.push_str("<mark>");
+                        snippet.push_str(&window[position..position + length]);
+                        snippet.push_str("</mark>");
+                        position += length;
+                    }
+                    None => {
+                        let ch = window[position..].chars().next().unwrap_or_default();
+                        snippet.push(ch);
+                        position += ch.len_utf8().max(1);
+                    }
+                }
+            }
+            if to < char_count {
+                snippet.push('…');
+            }
+            return snippet;
+        }
+
+        String::new()
+    }
+
+    /// 検索条件からWHERE句とバインド値を構築
+    fn build_search_conditions(criteria: &ProductSearchCriteria) -> (String, Vec<SearchBind>) {
+        // on_saleタグは、タグの関連がなくてもセール中のSKUがあれば付与されているものとして扱う
+        let on_sale_tag_condition = format!(
+            r#"(
+                EXISTS (
+                    SELECT 1 FROM product_tags pt
+                    JOIN tags t ON t.id = pt.tag_id
+                    WHERE pt.product_id = p.id AND t.slug = ?
+                )
+                OR EXISTS (
+                    SELECT 1 FROM skus s
+                    WHERE s.product_id = p.id AND s.status = 'active'
+                      AND {} IS NOT NULL
+                )
+            )"#,
+            EFFECTIVE_SALE_PRICE_SQL
+        );
+
+        // 公開中の商品のみ
+        let mut conditions: Vec<&str> = vec!["p.is_available = 1"];
+        let mut binds: Vec<SearchBind> = Vec::new();
+
+        if let Some(category) = &criteria.category {
+            // 指定カテゴリーとその子孫カテゴリーに属する商品
+            conditions.push(
+                r#"p.category_id IN (
+                    WITH RECURSIVE category_tree(id) AS (
+                        SELECT id FROM categories WHERE id = ? OR slug = ?
+                        UNION
+                        SELECT child.id FROM categories child
+                        JOIN category_tree parent ON child.parent_id = parent.id
+                    )
+                    SELECT id FROM category_tree
+                )"#,
+            );
+            binds.push(SearchBind::Text(category.clone()));
+            binds.push(SearchBind::Text(category.clone()));
+        }
+        if let Some(color) = &criteria.color {
+            conditions.push(
+                r#"EXISTS (
+                    SELECT 1 FROM skus cs
+                    JOIN colors co ON co.id = cs.color_id
+                    WHERE cs.product_id = p.id AND cs.status = 'active'
+                      AND LOWER(co.name) = LOWER(?)
+                )"#,
+            );
+            binds.push(SearchBind::Text(color.clone()));
+        }
+        if let Some(min_price) = criteria.min_price {
+            conditions.push("COALESCE(fs.sale_price, fs.base_price) >= ?");
+            binds.push(SearchBind::Integer(min_price as i64));
+        }
+        if let Some(max_price) = criteria.max_price {
+            conditions.push("COALESCE(fs.sale_price, fs.base_price) <= ?");
+            binds.push(SearchBind::Integer(max_price as i64));
+        }
+        if let Some(tag) = &criteria.tag {
+            let is_on_sale_tag = Tag::on_sale().is_ok_and(|on_sale| on_sale.slug().value() == tag);
+            conditions.push(if is_on_sale_tag {
+                &on_sale_tag_condition
+            } else {
+                r#"EXISTS (
+                    SELECT 1 FROM product_tags pt
+                    JOIN tags t ON t.id = pt.tag_id
+                    WHERE pt.product_id = p.id AND t.slug = ?
+                )"#
+            });
+            binds.push(SearchBind::Text(tag.clone()));
+        }
+        if let Some(is_best_seller) = criteria.is_best_seller {
+            conditions.push("COALESCE(p.is_best_seller, 0) = ?");
+            binds.push(SearchBind::Integer(is_best_seller as i64));
+        }
+        if let Some(is_quick_ship) = criteria.is_quick_ship {
+            conditions.push("COALESCE(p.is_quick_ship, 0) = ?");
+            binds.push(SearchBind::Integer(is_quick_ship as i64));
+        }
+        if let Some(in_stock) = criteria.in_stock {
+            conditions.push(if in_stock {
+                "fs.stock_quantity - fs.reserved_quantity > 0"
+            } else {
+                "fs.stock_quantity - fs.reserved_quantity <= 0"
+            });
+        }
+
+        let where_clause = format!("WHERE {}", conditions.join(" AND "));
+
+        (where_clause, binds)
+    }
+
+    /// 並び順のORDER BY句（ページングが安定するよう最後に商品IDで並べる）
+    fn order_by_clause(sort: ProductSortOrder) -> &'static str {
+        match sort {
+            ProductSortOrder::DisplayOrder => "p.display_order ASC, p.name ASC, p.id ASC",
+            ProductSortOrder::Name => "p.name ASC, p.id ASC",
+            ProductSortOrder::PriceAsc => {
+                "COALESCE(fs.sale_price, fs.base_price) ASC, p.name ASC, p.id ASC"
+            }
+            ProductSortOrder::PriceDesc => {
+                "COALESCE(fs.sale_price, fs.base_price) DESC, p.name ASC, p.id ASC"
+            }
+            ProductSortOrder::Newest => "p.created_at DESC, p.name ASC, p.id ASC",
+        }
+    }
 }
 
 #[async_trait]
@@ -36,7 +457,7 @@ impl ProductRepository for SqliteProductRepository {
                 c.name as category_name
             FROM products p
             JOIN categories c ON c.id = p.category_id
-            WHERE p.id = ?
+            WHERE p.id = ? AND p.is_available = 1
             "#,
         )
         .bind(&product_id_str)
@@ -50,7 +471,8 @@ impl ProductRepository for SqliteProductRepository {
         };
 
         // SKU情報（バリアント）を取得 - display_orderを使用したハイブリッドソート
-        let sku_rows = sqlx::query(
+        // セール価格はリクエスト時点で適用される価格（価格スケジュールを含む）
+        let sku_query = format!(
             r#"
             SELECT 
                 s.id,
@@ -59,7 +481,7 @@ impl ProductRepository for SqliteProductRepository {
                 s.dimensions,
                 s.material,
                 s.base_price,
-                s.sale_price,
+                {0} as sale_price,
                 s.stock_quantity,
                 s.reserved_quantity,
                 s.display_order,
@@ -68,19 +490,21 @@ impl ProductRepository for SqliteProductRepository {
                 c.hex as color_hex
             FROM skus s
             JOIN colors c ON c.id = s.color_id
-            WHERE s.product_id = ?
+            WHERE s.product_id = ? AND s.status = 'active'
             ORDER BY 
                 s.display_order ASC,
                 CASE WHEN s.stock_quantity - s.reserved_quantity > 0 THEN 0 ELSE 1 END,
-                COALESCE(s.sale_price, s.base_price) ASC,
+                COALESCE({0}, s.base_price) ASC,
                 s.dimensions ASC,
                 c.name ASC
             "#,
-        )
-        .bind(&product_id_str)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+            EFFECTIVE_SALE_PRICE_SQL
+        );
+        let sku_rows = sqlx::query(&sku_query)
+            .bind(&product_id_str)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
 
         // 商品画像を取得
         let image_rows = sqlx::query(
@@ -155,20 +579,23 @@ impl ProductRepository for SqliteProductRepository {
                 .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
 
             // バリアント作成 - VariantDTOの構造体フィールドに直接設定
-            variants.push(VariantDTO::new(
-                sku_id,
-                sku_code,
-                sku_name,
-                color_name,
-                sku_material.unwrap_or_default(),
-                sku_dimensions.unwrap_or_default(),
-                base_price as u32,
-                sku_sale_price.map(|p| p as u32),
-                stock_quantity as u32,
-                reserved_quantity as u32,
-                display_order as u32,
-                sku_image_url,
-            ));
+            variants.push(
+                VariantDTO::new(
+                    sku_id,
+                    sku_code,
+                    sku_name,
+                    color_name,
+                    sku_material.unwrap_or_default(),
+                    sku_dimensions.unwrap_or_default(),
+                    base_price as u32,
+                    sku_sale_price.map(|p| p as u32),
+                    stock_quantity as u32,
+                    reserved_quantity as u32,
+                    display_order as u32,
+                    sku_image_url,
+                )
+                .with_quick_ship(is_quick_ship),
+            );
         }
 
         // 画像URLリストを構築
@@ -193,184 +620,222 @@ impl ProductRepository for SqliteProductRepository {
         Ok(Some(product_dto))
     }
 
-    async fn find_all(&self) -> Result<ProductListDTO, RepositoryError> {
-        // 各商品の最初のSKUのデータを取得（ROW_NUMBERを使用してパフォーマンス最適化）
-        let product_rows = sqlx::query(
-            r#"
-            WITH first_sku AS (
-                SELECT 
-                    s.product_id,
-                    s.base_price,
-                    s.sale_price,
-                    s.stock_quantity,
-                    s.reserved_quantity,
-                    ROW_NUMBER() OVER (
-                        PARTITION BY s.product_id 
-                        ORDER BY 
-                            s.display_order ASC,
-                            CASE WHEN s.stock_quantity - s.reserved_quantity > 0 THEN 0 ELSE 1 END,
-                            s.base_price ASC,
-                            s.id ASC
-                    ) as rn
-                FROM skus s
-            )
-            SELECT 
-                p.id,
-                p.name,
-                p.is_best_seller,
-                p.is_quick_ship,
-                c.name as category_name,
-                fs.base_price,
-                fs.sale_price,
-                fs.stock_quantity,
-                fs.reserved_quantity,
-                pi.image_url as first_image
-            FROM products p
-            JOIN categories c ON c.id = p.category_id
-            JOIN first_sku fs ON fs.product_id = p.id AND fs.rn = 1
-            LEFT JOIN (
-                SELECT product_id, image_url,
-                       ROW_NUMBER() OVER (PARTITION BY product_id ORDER BY display_order) as rn
-                FROM product_images
-            ) pi ON pi.product_id = p.id AND pi.rn = 1
-            ORDER BY p.name
-            "#,
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
-
-        if product_rows.is_empty() {
-            let product_list = ProductListDTO {
-                products: Vec::new(),
-                total_count: 0,
-                page: 1,
-                per_page: 0,
-                has_next_page: false,
-                has_previous_page: false,
-            };
-            return Ok(product_list);
+    async fn find_all(
+        &self,
+        criteria: &ProductSearchCriteria,
+    ) -> Result<ProductListDTO, RepositoryError> {
+        let (where_clause, binds) = Self::build_search_conditions(criteria);
+
+        // 総件数を取得
+        let count_query = format!(
+            "{} SELECT COUNT(*) FROM products p {} {}",
+            first_sku_cte(),
+            PRODUCT_LIST_JOINS,
+            where_clause
+        );
+        let mut count_builder = sqlx::query_scalar::<_, i64>(&count_query);
+        for value in &binds {
+            count_builder = value.bind_scalar(count_builder);
         }
+        let total_count = count_builder
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+            as u32;
+
+        // 該当ページの商品の代表SKUのデータを取得
+        let list_query = Self::product_summary_query(
+            &where_clause,
+            &format!(
+                "ORDER BY {} LIMIT ? OFFSET ?",
+                Self::order_by_clause(criteria.sort)
+            ),
+        );
+        let mut list_builder = sqlx::query(&list_query);
+        for value in &binds {
+            list_builder = value.bind(list_builder);
+        }
+        // ページを指定しない場合はLIMIT -1（SQLiteでは上限なし）で全件を取得する
+        let product_rows = list_builder
+            .bind(criteria.per_page.map_or(-1, i64::from))
+            .bind(criteria.offset() as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
 
-        // 全商品のIDを収集
-        let product_ids: Vec<String> = product_rows
-            .iter()
-            .map(|row| row.try_get("id"))
-            .collect::<Result<Vec<String>, _>>()
-            .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+        let product_summaries = self.build_summaries(product_rows).await?;
 
-        // プレースホルダーを動的に生成
-        let placeholders = product_ids
+        Ok(ProductListDTO::new(
+            product_summaries,
+            total_count,
+            criteria.page,
+            criteria.per_page.unwrap_or(total_count),
+        ))
+    }
+
+    async fn search(
+        &self,
+        criteria: &ProductKeywordSearchCriteria,
+    ) -> Result<ProductSearchResultDTO, RepositoryError> {
+        // trigramは3文字未満の語（「机」「椅子」など）を検索できないため、短い語はLIKEで部分一致させる
+        let (match_terms, like_terms): (Vec<&String>, Vec<&String>) = criteria
+            .terms
             .iter()
-            .map(|_| "?")
-            .collect::<Vec<_>>()
-            .join(",");
+            .partition(|term| term.chars().count() >= TRIGRAM_MIN_CHARS);
+
+        // 一覧と同様に、公開中で販売中のSKUがある商品のみ対象
+        let mut conditions = vec![
+            "p.is_available = 1".to_string(),
+            "EXISTS (SELECT 1 FROM skus s WHERE s.product_id = product_search.product_id AND s.status = 'active')"
+                .to_string(),
+        ];
+        let mut binds: Vec<String> = Vec::new();
+
+        if !match_terms.is_empty() {
+            conditions.push("product_search MATCH ?".to_string());
+            binds.push(Self::fts_match_expression(&match_terms));
+        }
+        for term in &like_terms {
+            let column_conditions = KEYWORD_SEARCH_COLUMNS
+                .iter()
+                .map(|column| format!("product_search.{} LIKE ? ESCAPE '\\'", column))
+                .collect::<Vec<_>>()
+                .join(" OR ");
+            conditions.push(format!("({})", column_conditions));
+            let pattern = Self::like_pattern(term);
+            binds.extend(std::iter::repeat_n(pattern, KEYWORD_SEARCH_COLUMNS.len()));
+        }
+        let where_clause = format!("WHERE {}", conditions.join(" AND "));
 
-        // 各商品の色情報を取得
-        let color_query = format!(
+        // 総件数を取得
+        let count_query = format!(
+            "SELECT COUNT(*) FROM product_search JOIN products p ON p.id = product_search.product_id {}",
+            where_clause
+        );
+        let mut count_builder = sqlx::query_scalar::<_, i64>(&count_query);
+        for value in &binds {
+            count_builder = count_builder.bind(value);
+        }
+        let total_count = count_builder
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+            as u32;
+
+        // 全文検索できる語があればbm25（商品名・SKU名の一致を重視）で並べ、なければ表示順
+        let (snippet_column, order_by) = if match_terms.is_empty() {
+            (
+                "NULL".to_string(),
+                "p.display_order ASC, p.name ASC, p.id ASC",
+            )
+        } else {
+            (
+                format!(
+                    "snippet(product_search, -1, '<mark>', '</mark>', '…', {})",
+                    SNIPPET_LENGTH
+                ),
+                "bm25(product_search, 0.0, 10.0, 2.0, 5.0, 3.0, 3.0), p.display_order ASC, p.id ASC",
+            )
+        };
+        let hit_query = format!(
             r#"
-            SELECT DISTINCT
-                s.product_id,
-                colors.name as color_name
-            FROM skus s
-            JOIN colors ON colors.id = s.color_id
-            WHERE s.product_id IN ({})
-            ORDER BY s.product_id, colors.name
+            SELECT
+                product_search.product_id,
+                {} as snippet,
+                product_search.name,
+                product_search.description,
+                product_search.sku_names,
+                product_search.materials,
+                product_search.colors
+            FROM product_search
+            JOIN products p ON p.id = product_search.product_id
+            {}
+            ORDER BY {}
+            LIMIT ? OFFSET ?
             "#,
-            placeholders
+            snippet_column, where_clause, order_by
         );
-
-        let mut color_query_builder = sqlx::query(&color_query);
-        for product_id in &product_ids {
-            color_query_builder = color_query_builder.bind(product_id);
+        let mut hit_builder = sqlx::query(&hit_query);
+        for value in &binds {
+            hit_builder = hit_builder.bind(value);
         }
-
-        let color_rows = color_query_builder
+        let hit_rows = hit_builder
+            .bind(criteria.per_page as i64)
+            .bind(criteria.offset() as i64)
             .fetch_all(&self.pool)
             .await
             .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
 
-        let mut product_summaries = Vec::new();
-
-        for product_row in product_rows {
-            let product_id: String = product_row
-                .try_get("id")
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-            let name: String = product_row
-                .try_get("name")
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-            let is_best_seller: bool = product_row
-                .try_get("is_best_seller")
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-            let is_quick_ship: bool = product_row
-                .try_get("is_quick_ship")
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-            let category_name: String = product_row
-                .try_get("category_name")
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-            let base_price: i64 = product_row
-                .try_get("base_price")
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-            let sale_price: Option<i64> = product_row
-                .try_get("sale_price")
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-            let stock_quantity: i64 = product_row
-                .try_get("stock_quantity")
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-            let reserved_quantity: i64 = product_row
-                .try_get("reserved_quantity")
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-            let first_image: Option<String> = product_row
-                .try_get("first_image")
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-
-            // この商品の色情報を取得
-            let product_colors: Vec<String> = color_rows
-                .iter()
-                .filter(|row| {
-                    row.try_get::<String, _>("product_id")
-                        .map(|id| id == product_id)
-                        .unwrap_or(false)
-                })
-                .map(|row| row.try_get("color_name"))
-                .collect::<Result<Vec<String>, _>>()
-                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
-
-            // 価格計算（JPYは最小単位で保存されているため100で割る）
-            let product_price = base_price as u32;
-            let sale_price_converted = sale_price.map(|p| p as u32);
-            let available_stock = (stock_quantity - reserved_quantity).max(0) as u32;
-
-            let product_summary = ProductSummaryDTO::new(
-                product_id,
-                name,
-                category_name,
-                product_price,
-                sale_price_converted,
-                first_image,
-                product_colors, // 色情報を追加
-                is_best_seller,
-                is_quick_ship,
-                available_stock,
-            );
+        let mut ranked_hits: Vec<(String, String)> = Vec::new();
+        for row in &hit_rows {
+            let product_id: String = row
+                .try_get("product_id")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let snippet: Option<String> = row
+                .try_get("snippet")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let snippet = match snippet {
+                Some(snippet) => snippet,
+                None => {
+                    let columns = KEYWORD_SEARCH_COLUMNS
+                        .iter()
+                        .map(|column| row.try_get::<String, _>(*column))
+                        .collect::<Result<Vec<String>, _>>()
+                        .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+                    Self::highlight_snippet(&columns, &like_terms)
+                }
+            };
+            ranked_hits.push((product_id, snippet));
+        }
 
-            product_summaries.push(product_summary);
+        if ranked_hits.is_empty() {
+            return Ok(ProductSearchResultDTO::new(
+                Vec::new(),
+                total_count,
+                criteria.page,
+                criteria.per_page,
+            ));
         }
 
-        let total_count = product_summaries.len() as u32;
+        // 該当商品のサマリーを取得し、検索順位の順に並べ直す
+        let placeholders = ranked_hits
+            .iter()
+            .map(|_| "?")
+            .collect::<Vec<_>>()
+            .join(",");
+        let summary_query =
+            Self::product_summary_query(&format!("WHERE p.id IN ({})", placeholders), "");
+        let mut summary_builder = sqlx::query(&summary_query);
+        for (product_id, _) in &ranked_hits {
+            summary_builder = summary_builder.bind(product_id);
+        }
+        let summary_rows = summary_builder
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
 
-        // 簡単な実装：ページング無しでの全件返却
-        // 実際のプロダクションではLIMIT/OFFSETでページングを実装する
-        let product_list = ProductListDTO {
-            products: product_summaries,
+        let mut summaries: HashMap<String, ProductSummaryDTO> = self
+            .build_summaries(summary_rows)
+            .await?
+            .into_iter()
+            .map(|summary| (summary.id.clone(), summary))
+            .collect();
+
+        let hits = ranked_hits
+            .into_iter()
+            .filter_map(|(product_id, snippet)| {
+                summaries
+                    .remove(&product_id)
+                    .map(|product| ProductSearchHitDTO { product, snippet })
+            })
+            .collect();
+
+        Ok(ProductSearchResultDTO::new(
+            hits,
             total_count,
-            page: 1,
-            per_page: total_count,
-            has_next_page: false,
-            has_previous_page: false,
-        };
-
-        Ok(product_list)
+            criteria.page,
+            criteria.per_page,
+        ))
     }
 
     async fn find_variants_by_ids(
@@ -400,18 +865,23 @@ impl ProductRepository for SqliteProductRepository {
                 s.dimensions,
                 s.material,
                 s.base_price,
-                s.sale_price,
+                {} as sale_price,
                 s.stock_quantity,
                 s.reserved_quantity,
                 s.display_order,
                 s.image_url,
-                c.name as color_name
+                s.tax_category,
+                s.product_id,
+                p.category_id,
+                c.name as color_name,
+                COALESCE(p.is_quick_ship, 0) as is_quick_ship
             FROM skus s
             JOIN colors c ON c.id = s.color_id
-            WHERE s.id IN ({})
+            JOIN products p ON p.id = s.product_id
+            WHERE s.id IN ({}) AND s.status = 'active' AND p.is_available = 1
             ORDER BY s.display_order ASC, s.sku_code ASC
             "#,
-            placeholders
+            EFFECTIVE_SALE_PRICE_SQL, placeholders
         );
 
         let mut query_builder = sqlx::query(&query);
@@ -463,22 +933,42 @@ impl ProductRepository for SqliteProductRepository {
             let sku_material: Option<String> = sku_row
                 .try_get("material")
                 .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let is_quick_ship: bool = sku_row
+                .try_get("is_quick_ship")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let tax_category: String = sku_row
+                .try_get("tax_category")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let tax_category = tax_category
+                .parse::<TaxCategory>()
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let product_id: String = sku_row
+                .try_get("product_id")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+            let category_id: String = sku_row
+                .try_get("category_id")
+                .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
 
             // バリアント作成
-            variants.push(VariantDTO::new(
-                sku_id,
-                sku_code,
-                sku_name,
-                color_name,
-                sku_material.unwrap_or_default(),
-                sku_dimensions.unwrap_or_default(),
-                base_price as u32,
-                sku_sale_price.map(|p| p as u32),
-                stock_quantity as u32,
-                reserved_quantity as u32,
-                display_order as u32,
-                sku_image_url,
-            ));
+            variants.push(
+                VariantDTO::new(
+                    sku_id,
+                    sku_code,
+                    sku_name,
+                    color_name,
+                    sku_material.unwrap_or_default(),
+                    sku_dimensions.unwrap_or_default(),
+                    base_price as u32,
+                    sku_sale_price.map(|p| p as u32),
+                    stock_quantity as u32,
+                    reserved_quantity as u32,
+                    display_order as u32,
+                    sku_image_url,
+                )
+                .with_quick_ship(is_quick_ship)
+                .with_tax_category(tax_category)
+                .with_product(product_id, category_id),
+            );
         }
 
         Ok(variants)
@@ -0,0 +1,658 @@
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::application::error::RepositoryError;
+use crate::application::repositories::ProductWriteRepository;
+use crate::domain::value_objects::*;
+use crate::domain::{
+    DomainError, ImageUrl, Product, ProductImage, ProductImageId, ProductImageProductId, SKU,
+    SKUStatus, Stock, StockMovement, StockMovementKind, Tag, TagSlug,
+};
+
+use super::row_helpers::{get_column, parse_timestamp};
+use super::sqlite_stock_movement_repository::insert_stock_movement;
+
+/// SQLite実装のProductWriteRepository
+/// Clean Architecture: Frameworks & Drivers層
+/// 商品集約（商品・SKU・価格スケジュール・画像・タグ）を1トランザクションで永続化する
+pub struct SqliteProductWriteRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteProductWriteRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn query_error(context: &str, e: impl std::fmt::Display) -> RepositoryError {
+        RepositoryError::QueryExecution(format!(
+            "[SqliteProductWriteRepository::{}] {}",
+            context, e
+        ))
+    }
+
+    fn parse_uuid(value: &str, field: &str) -> Result<Uuid, RepositoryError> {
+        Uuid::parse_str(value).map_err(|e| {
+            RepositoryError::DataConversionError(format!("Invalid {} '{}': {}", field, value, e))
+        })
+    }
+
+    fn domain_error(e: DomainError) -> RepositoryError {
+        RepositoryError::DataConversionError(e.to_string())
+    }
+
+    /// SKUの行からSKUエンティティを復元
+    fn restore_sku(row: &SqliteRow, product_id: &ProductId) -> Result<SKU, RepositoryError> {
+        let id: String = get_column(row, "id")?;
+        let sku_code: String = get_column(row, "sku_code")?;
+        let name: String = get_column(row, "name")?;
+        let color_id: i64 = get_column(row, "color_id")?;
+        let dimensions: Option<String> = get_column(row, "dimensions")?;
+        let material: Option<String> = get_column(row, "material")?;
+        let base_price: i64 = get_column(row, "base_price")?;
+        let sale_price: Option<i64> = get_column(row, "sale_price")?;
+        let stock_quantity: i64 = get_column(row, "stock_quantity")?;
+        let reserved_quantity: i64 = get_column(row, "reserved_quantity")?;
+        let low_stock_threshold: i64 = get_column(row, "low_stock_threshold")?;
+        let display_order: i64 = get_column(row, "display_order")?;
+        let status: String = get_column(row, "status")?;
+        let tax_category: String = get_column(row, "tax_category")?;
+        let created_at: String = get_column(row, "created_at")?;
+        let updated_at: String = get_column(row, "updated_at")?;
+
+        let mut variant_attributes = VariantAttributes::new()
+            .with_color_id(ColorId::new(color_id as u32).map_err(Self::domain_error)?);
+        if let Some(dimensions) = dimensions {
+            variant_attributes = variant_attributes
+                .with_dimensions(Dimensions::new(dimensions).map_err(Self::domain_error)?);
+        }
+        if let Some(material) = material {
+            variant_attributes = variant_attributes
+                .with_material(Material::new(material).map_err(Self::domain_error)?);
+        }
+
+        let mut stock = Stock::new(stock_quantity as u32, reserved_quantity as u32)
+            .map_err(Self::domain_error)?;
+        stock.set_low_stock_threshold(low_stock_threshold as u32);
+
+        Ok(SKU::restore(
+            SKUId::from_uuid(Self::parse_uuid(&id, "sku id")?),
+            product_id.clone(),
+            SKUCode::new(sku_code).map_err(Self::domain_error)?,
+            SKUName::new(name).map_err(Self::domain_error)?,
+            variant_attributes,
+            Money::from_yen(base_price as u32),
+            sale_price.map(|price| Money::from_yen(price as u32)),
+            stock,
+            status.parse::<SKUStatus>().map_err(Self::domain_error)?,
+            display_order as u32,
+            parse_timestamp(&created_at)?,
+            parse_timestamp(&updated_at)?,
+        )
+        .with_tax_category(tax_category.parse().map_err(Self::domain_error)?))
+    }
+
+    /// 指定テーブルに指定IDの行が存在するか
+    async fn exists(
+        tx: &mut Transaction<'_, Sqlite>,
+        sql: &str,
+        value: impl ToString,
+    ) -> Result<bool, RepositoryError> {
+        let count: i64 = sqlx::query_scalar(sql)
+            .bind(value.to_string())
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(|e| Self::query_error("exists", e))?;
+        Ok(count > 0)
+    }
+
+    /// 注文明細から参照されているSKUがあればエラー（注文履歴を壊さないため削除不可）
+    async fn ensure_not_ordered(
+        tx: &mut Transaction<'_, Sqlite>,
+        sku_ids: &[String],
+    ) -> Result<(), RepositoryError> {
+        if sku_ids.is_empty() {
+            return Ok(());
+        }
+
+        let placeholders = sku_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let sql = format!(
+            "SELECT COUNT(*) FROM order_items WHERE sku_id IN ({})",
+            placeholders
+        );
+        let mut query = sqlx::query_scalar::<_, i64>(&sql);
+        for sku_id in sku_ids {
+            query = query.bind(sku_id);
+        }
+        let ordered = query
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(|e| Self::query_error("ensure_not_ordered", e))?;
+
+        if ordered > 0 {
+            return Err(RepositoryError::ForeignKeyConstraint {
+                field: "order_items".to_string(),
+                message: "SKU is referenced by existing orders".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// SKUを新規作成または更新（既存SKUの在庫数・引当数は更新しない）
+    async fn upsert_sku(
+        tx: &mut Transaction<'_, Sqlite>,
+        product_id: &str,
+        sku: &SKU,
+    ) -> Result<(), RepositoryError> {
+        let sku_id = sku.id().value().to_string();
+        let attributes = sku.variant_attributes();
+
+        let color_id = attributes.color_id().map(|c| c.value()).ok_or_else(|| {
+            RepositoryError::ForeignKeyConstraint {
+                field: "color_id".to_string(),
+                message: format!("SKU {} has no color", sku.sku_code().value()),
+            }
+        })?;
+        if !Self::exists(tx, "SELECT COUNT(*) FROM colors WHERE id = ?", color_id).await? {
+            return Err(RepositoryError::ForeignKeyConstraint {
+                field: "color_id".to_string(),
+                message: format!("Color {} does not exist", color_id),
+            });
+        }
+
+        let duplicated: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM skus WHERE sku_code = ?1 AND id <> ?2")
+                .bind(sku.sku_code().value())
+                .bind(&sku_id)
+                .fetch_one(&mut **tx)
+                .await
+                .map_err(|e| Self::query_error("upsert_sku", e))?;
+        if duplicated > 0 {
+            return Err(RepositoryError::UniqueConstraint {
+                field: "sku_code".to_string(),
+                message: format!("SKU code {} already exists", sku.sku_code().value()),
+            });
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO skus (
+                id, product_id, sku_code, name,
+                color_id, dimensions, material,
+                base_price, sale_price,
+                stock_quantity, reserved_quantity, low_stock_threshold,
+                display_order, status, tax_category
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+            ON CONFLICT(id) DO UPDATE SET
+                sku_code = excluded.sku_code,
+                name = excluded.name,
+                color_id = excluded.color_id,
+                dimensions = excluded.dimensions,
+                material = excluded.material,
+                base_price = excluded.base_price,
+                sale_price = excluded.sale_price,
+                low_stock_threshold = excluded.low_stock_threshold,
+                display_order = excluded.display_order,
+                status = excluded.status,
+                tax_category = excluded.tax_category,
+                updated_at = datetime('now')
+            "#,
+        )
+        .bind(&sku_id)
+        .bind(product_id)
+        .bind(sku.sku_code().value())
+        .bind(sku.name().value())
+        .bind(color_id as i64)
+        .bind(attributes.dimensions().map(|d| d.value().to_string()))
+        .bind(attributes.material().map(|m| m.value().to_string()))
+        .bind(sku.base_price().yen() as i64)
+        .bind(sku.sale_price().map(|price| price.yen() as i64))
+        .bind(sku.total_quantity() as i64)
+        .bind(sku.reserved_quantity() as i64)
+        .bind(sku.stock().low_stock_threshold() as i64)
+        .bind(sku.display_order() as i64)
+        .bind(sku.status().to_string())
+        .bind(sku.tax_category().code())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| Self::query_error("upsert_sku", e))?;
+
+        Self::replace_price_schedules(tx, &sku_id, sku).await
+    }
+
+    /// SKUの価格スケジュールを置き換える
+    async fn replace_price_schedules(
+        tx: &mut Transaction<'_, Sqlite>,
+        sku_id: &str,
+        sku: &SKU,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM price_schedules WHERE sku_id = ?1")
+            .bind(sku_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| Self::query_error("replace_price_schedules", e))?;
+
+        for schedule in sku.price_schedules() {
+            sqlx::query(
+                r#"
+                INSERT INTO price_schedules (sku_id, sale_price, starts_at, ends_at)
+                VALUES (?1, ?2, ?3, ?4)
+                "#,
+            )
+            .bind(sku_id)
+            .bind(schedule.sale_price().yen() as i64)
+            .bind(schedule.starts_at().to_rfc3339())
+            .bind(schedule.ends_at().to_rfc3339())
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| Self::query_error("replace_price_schedules", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// 商品に属するSKUの価格スケジュールをSKU IDごとにまとめて取得
+    async fn find_price_schedules(
+        &self,
+        product_id: &str,
+    ) -> Result<HashMap<String, Vec<PriceSchedule>>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT ps.sku_id, ps.sale_price, ps.starts_at, ps.ends_at
+            FROM price_schedules ps
+            JOIN skus s ON s.id = ps.sku_id
+            WHERE s.product_id = ?1
+            ORDER BY ps.sku_id, ps.starts_at
+            "#,
+        )
+        .bind(product_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_price_schedules", e))?;
+
+        let mut schedules: HashMap<String, Vec<PriceSchedule>> = HashMap::new();
+        for row in &rows {
+            let sku_id: String = get_column(row, "sku_id")?;
+            let sale_price: i64 = get_column(row, "sale_price")?;
+            let starts_at: String = get_column(row, "starts_at")?;
+            let ends_at: String = get_column(row, "ends_at")?;
+            schedules.entry(sku_id).or_default().push(
+                PriceSchedule::new(
+                    Money::from_yen(sale_price as u32),
+                    parse_timestamp(&starts_at)?,
+                    parse_timestamp(&ends_at)?,
+                )
+                .map_err(Self::domain_error)?,
+            );
+        }
+
+        Ok(schedules)
+    }
+}
+
+#[async_trait]
+impl ProductWriteRepository for SqliteProductWriteRepository {
+    async fn find_by_id(&self, id: &ProductId) -> Result<Option<Product>, RepositoryError> {
+        let product_id = id.value().to_string();
+
+        let product_row = sqlx::query(
+            r#"
+            SELECT
+                id, name, description, category_id,
+                is_best_seller, is_quick_ship, is_available,
+                created_at, updated_at
+            FROM products
+            WHERE id = ?1
+            "#,
+        )
+        .bind(&product_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_by_id", e))?;
+
+        let Some(product_row) = product_row else {
+            return Ok(None);
+        };
+
+        let sku_rows = sqlx::query(
+            r#"
+            SELECT
+                id, sku_code, name, color_id, dimensions, material,
+                base_price, sale_price,
+                stock_quantity, reserved_quantity, low_stock_threshold,
+                display_order, status, tax_category, created_at, updated_at
+            FROM skus
+            WHERE product_id = ?1
+            ORDER BY display_order ASC, sku_code ASC
+            "#,
+        )
+        .bind(&product_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_by_id", e))?;
+
+        let image_rows = sqlx::query(
+            r#"
+            SELECT id, image_url, alt_text, display_order
+            FROM product_images
+            WHERE product_id = ?1
+            ORDER BY display_order ASC, id ASC
+            "#,
+        )
+        .bind(&product_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_by_id", e))?;
+
+        let tag_rows = sqlx::query(
+            r#"
+            SELECT t.slug, t.name, t.priority, t.is_system
+            FROM product_tags pt
+            JOIN tags t ON t.id = pt.tag_id
+            WHERE pt.product_id = ?1
+            ORDER BY t.priority ASC, t.slug ASC
+            "#,
+        )
+        .bind(&product_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_by_id", e))?;
+
+        let mut price_schedules = self.find_price_schedules(&product_id).await?;
+        let skus = sku_rows
+            .iter()
+            .map(|row| {
+                let sku = Self::restore_sku(row, id)?;
+                let schedules = price_schedules
+                    .remove(&sku.id().value().to_string())
+                    .unwrap_or_default();
+                Ok(sku.with_price_schedules(schedules))
+            })
+            .collect::<Result<Vec<_>, RepositoryError>>()?;
+
+        let mut images = Vec::new();
+        for row in &image_rows {
+            let image_id: i64 = get_column(row, "id")?;
+            let image_url: String = get_column(row, "image_url")?;
+            let alt_text: Option<String> = get_column(row, "alt_text")?;
+            let display_order: Option<i64> = get_column(row, "display_order")?;
+            images.push(
+                ProductImage::new(
+                    ProductImageId::new(image_id as u32),
+                    ProductImageProductId::new(product_id.clone()).map_err(Self::domain_error)?,
+                    ImageUrl::new(image_url).map_err(Self::domain_error)?,
+                    alt_text,
+                    display_order.unwrap_or(0) as u32,
+                )
+                .map_err(Self::domain_error)?,
+            );
+        }
+
+        let tags = tag_rows
+            .iter()
+            .map(restore_tag)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let name: String = get_column(&product_row, "name")?;
+        let description: Option<String> = get_column(&product_row, "description")?;
+        let category_id: String = get_column(&product_row, "category_id")?;
+        let is_best_seller: Option<bool> = get_column(&product_row, "is_best_seller")?;
+        let is_quick_ship: Option<bool> = get_column(&product_row, "is_quick_ship")?;
+        let is_available: bool = get_column(&product_row, "is_available")?;
+        let created_at: String = get_column(&product_row, "created_at")?;
+        let updated_at: String = get_column(&product_row, "updated_at")?;
+
+        Ok(Some(Product::restore(
+            id.clone(),
+            ProductName::new(name).map_err(Self::domain_error)?,
+            Description::new(description.unwrap_or_default()),
+            CategoryId::new(category_id).map_err(Self::domain_error)?,
+            is_best_seller.unwrap_or(false),
+            is_quick_ship.unwrap_or(false),
+            is_available,
+            skus,
+            images,
+            tags,
+            parse_timestamp(&created_at)?,
+            parse_timestamp(&updated_at)?,
+        )))
+    }
+
+    async fn find_by_sku_id(&self, sku_id: &SKUId) -> Result<Option<Product>, RepositoryError> {
+        let product_id: Option<String> =
+            sqlx::query_scalar("SELECT product_id FROM skus WHERE id = ?1")
+                .bind(sku_id.value().to_string())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| Self::query_error("find_by_sku_id", e))?;
+
+        match product_id {
+            Some(product_id) => {
+                let product_id = ProductId::from_uuid(Self::parse_uuid(&product_id, "product id")?);
+                self.find_by_id(&product_id).await
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn save(&self, product: &Product) -> Result<(), RepositoryError> {
+        let product_id = product.id().value().to_string();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let category_id = product.category_id().value();
+        if !Self::exists(
+            &mut tx,
+            "SELECT COUNT(*) FROM categories WHERE id = ?",
+            category_id,
+        )
+        .await?
+        {
+            return Err(RepositoryError::ForeignKeyConstraint {
+                field: "category_id".to_string(),
+                message: format!("Category {} does not exist", category_id),
+            });
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO products (
+                id, name, description, category_id,
+                is_best_seller, is_quick_ship, is_available
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                description = excluded.description,
+                category_id = excluded.category_id,
+                is_best_seller = excluded.is_best_seller,
+                is_quick_ship = excluded.is_quick_ship,
+                is_available = excluded.is_available,
+                updated_at = datetime('now')
+            "#,
+        )
+        .bind(&product_id)
+        .bind(product.name().value())
+        .bind(product.description().value())
+        .bind(category_id)
+        .bind(product.is_best_seller())
+        .bind(product.is_quick_ship())
+        .bind(product.is_active())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Self::query_error("save", e))?;
+
+        // 集約から取り除かれたSKUを先に削除（SKUコードを再利用できるように）
+        let stored_sku_ids: Vec<String> =
+            sqlx::query_scalar("SELECT id FROM skus WHERE product_id = ?1")
+                .bind(&product_id)
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(|e| Self::query_error("save", e))?;
+        let removed_sku_ids: Vec<String> = stored_sku_ids
+            .into_iter()
+            .filter(|stored| {
+                !product
+                    .skus()
+                    .iter()
+                    .any(|sku| sku.id().value().to_string() == *stored)
+            })
+            .collect();
+
+        Self::ensure_not_ordered(&mut tx, &removed_sku_ids).await?;
+        for sku_id in &removed_sku_ids {
+            sqlx::query("DELETE FROM skus WHERE id = ?1")
+                .bind(sku_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| Self::query_error("save", e))?;
+        }
+
+        for sku in product.skus() {
+            let sku_id = sku.id().value().to_string();
+            let is_new =
+                !Self::exists(&mut tx, "SELECT COUNT(*) FROM skus WHERE id = ?", &sku_id).await?;
+
+            Self::upsert_sku(&mut tx, &product_id, sku).await?;
+
+            // 新規SKUの初期在庫は入荷として台帳に記録する
+            if is_new && sku.total_quantity() > 0 {
+                let movement = StockMovement::new(
+                    sku.id().clone(),
+                    StockMovementKind::Receive,
+                    sku.total_quantity() as i64,
+                    "初期在庫",
+                    None,
+                    "admin",
+                );
+                insert_stock_movement(&mut *tx, &movement)
+                    .await
+                    .map_err(|e| Self::query_error("save", e))?;
+            }
+        }
+
+        // 画像は並び順ごと置き換える
+        sqlx::query("DELETE FROM product_images WHERE product_id = ?1")
+            .bind(&product_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("save", e))?;
+        for image in product.images() {
+            sqlx::query(
+                r#"
+                INSERT INTO product_images (product_id, image_url, alt_text, display_order)
+                VALUES (?1, ?2, ?3, ?4)
+                "#,
+            )
+            .bind(&product_id)
+            .bind(image.url())
+            .bind(image.alt_text.as_deref())
+            .bind(image.display_order as i64)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("save", e))?;
+        }
+
+        // タグの関連を置き換える
+        sqlx::query("DELETE FROM product_tags WHERE product_id = ?1")
+            .bind(&product_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("save", e))?;
+        for tag in product.tags() {
+            sqlx::query(
+                "INSERT INTO product_tags (product_id, tag_id) SELECT ?1, id FROM tags WHERE slug = ?2",
+            )
+            .bind(&product_id)
+            .bind(tag.slug().value())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("save", e))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| Self::query_error("save", e))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &ProductId) -> Result<bool, RepositoryError> {
+        let product_id = id.value().to_string();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let sku_ids: Vec<String> = sqlx::query_scalar("SELECT id FROM skus WHERE product_id = ?1")
+            .bind(&product_id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("delete", e))?;
+        Self::ensure_not_ordered(&mut tx, &sku_ids).await?;
+
+        // SKU・画像・タグの関連とカート内のSKUはON DELETE CASCADEで削除される
+        let result = sqlx::query("DELETE FROM products WHERE id = ?1")
+            .bind(&product_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("delete", e))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| Self::query_error("delete", e))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_tags_by_slugs(&self, slugs: &[String]) -> Result<Vec<Tag>, RepositoryError> {
+        if slugs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = slugs.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let sql = format!(
+            "SELECT slug, name, priority, is_system FROM tags WHERE slug IN ({}) ORDER BY priority ASC, slug ASC",
+            placeholders
+        );
+        let mut query = sqlx::query(&sql);
+        for slug in slugs {
+            query = query.bind(slug);
+        }
+        let rows = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| Self::query_error("find_tags_by_slugs", e))?;
+
+        rows.iter().map(restore_tag).collect()
+    }
+}
+
+/// タグの行からタグエンティティを復元
+fn restore_tag(row: &SqliteRow) -> Result<Tag, RepositoryError> {
+    let slug: String = get_column(row, "slug")?;
+    let name: String = get_column(row, "name")?;
+    let priority: Option<i64> = get_column(row, "priority")?;
+    let is_system: Option<bool> = get_column(row, "is_system")?;
+
+    let slug =
+        TagSlug::new(slug).map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+    Tag::new(
+        slug,
+        name,
+        None,
+        priority.unwrap_or(0).clamp(0, u8::MAX as i64) as u8,
+        is_system.unwrap_or(false),
+    )
+    .map_err(|e| RepositoryError::DataConversionError(e.to_string()))
+}
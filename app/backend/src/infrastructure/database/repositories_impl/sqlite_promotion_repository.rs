@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use uuid::Uuid;
+
+use crate::application::error::RepositoryError;
+use crate::application::repositories::PromotionRepository;
+use crate::domain::entities::Promotion;
+use crate::domain::value_objects::{DiscountPolicy, PromotionId};
+
+use super::discount_policy_columns::{discount_condition_from_columns, discount_type_from_columns};
+
+/// SQLite実装のPromotionRepository
+/// Clean Architecture: Frameworks & Drivers層
+pub struct SqlitePromotionRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePromotionRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn query_error(context: &str, e: impl std::fmt::Display) -> RepositoryError {
+        RepositoryError::QueryExecution(format!("[SqlitePromotionRepository::{}] {}", context, e))
+    }
+
+    fn parse_datetime(value: &str) -> Result<DateTime<Utc>, String> {
+        DateTime::parse_from_rfc3339(value)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| format!("Invalid promotion period format: {}", e))
+    }
+
+    /// プロモーションの行と適用条件の行（condition_type, target_id）からプロモーションエンティティを復元
+    fn row_to_promotion(
+        row: &SqliteRow,
+        conditions: &[(String, String)],
+    ) -> Result<Promotion, String> {
+        let id = Uuid::parse_str(&row.get::<String, _>("id"))
+            .map_err(|e| format!("Invalid promotion ID format: {}", e))?;
+
+        let discount_type = discount_type_from_columns(
+            &row.get::<String, _>("discount_type"),
+            row.get("discount_value"),
+            row.get("max_discount_amount"),
+            row.get("buy_quantity"),
+            row.get("get_quantity"),
+        )?;
+        let discount_condition =
+            discount_condition_from_columns(conditions, row.get("minimum_amount"))?;
+
+        let promotion = Promotion::new(
+            PromotionId::from_uuid(id),
+            row.get("name"),
+            DiscountPolicy::new(discount_type, discount_condition),
+            Self::parse_datetime(&row.get::<String, _>("starts_at"))?,
+            Self::parse_datetime(&row.get::<String, _>("ends_at"))?,
+        )
+        .map_err(|e| e.to_string())?
+        .with_description(row.get("description"))
+        .with_minimum_quantity(
+            row.get::<Option<i64>, _>("minimum_quantity")
+                .map(|q| q as u32),
+        )
+        .map_err(|e| e.to_string())?
+        .with_priority(row.get::<i64, _>("priority") as i32)
+        .with_stackable(row.get("stackable"))
+        .with_active(row.get("is_active"));
+
+        Ok(promotion)
+    }
+
+    /// プロモーションIDごとの適用条件（condition_type, target_id）をまとめて取得
+    async fn find_conditions(
+        &self,
+        promotion_ids: &[String],
+    ) -> Result<HashMap<String, Vec<(String, String)>>, sqlx::Error> {
+        let mut conditions: HashMap<String, Vec<(String, String)>> = HashMap::new();
+        if promotion_ids.is_empty() {
+            return Ok(conditions);
+        }
+
+        // プレースホルダーを動的に生成
+        let placeholders = promotion_ids
+            .iter()
+            .map(|_| "?")
+            .collect::<Vec<_>>()
+            .join(",");
+        let query = format!(
+            r#"
+            SELECT promotion_id, condition_type, target_id
+            FROM promotion_conditions
+            WHERE promotion_id IN ({})
+            ORDER BY promotion_id, condition_type, target_id
+            "#,
+            placeholders
+        );
+
+        let mut query_builder = sqlx::query(&query);
+        for promotion_id in promotion_ids {
+            query_builder = query_builder.bind(promotion_id);
+        }
+
+        for row in query_builder.fetch_all(&self.pool).await? {
+            conditions
+                .entry(row.get("promotion_id"))
+                .or_default()
+                .push((row.get("condition_type"), row.get("target_id")));
+        }
+
+        Ok(conditions)
+    }
+}
+
+#[async_trait]
+impl PromotionRepository for SqlitePromotionRepository {
+    async fn find_running(&self, now: DateTime<Utc>) -> Result<Vec<Promotion>, RepositoryError> {
+        // タイムゾーン表記の違いがあっても比較できるよう、datetime()で正規化して比較する
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, name, description, discount_type, discount_value,
+                minimum_amount, max_discount_amount, buy_quantity, get_quantity,
+                minimum_quantity, priority, stackable, is_active, starts_at, ends_at
+            FROM promotions
+            WHERE is_active = 1
+            AND datetime(starts_at) <= datetime(?1)
+            AND datetime(ends_at) >= datetime(?1)
+            ORDER BY priority DESC, created_at, id
+            "#,
+        )
+        .bind(now.to_rfc3339())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_running", e))?;
+
+        let promotion_ids = rows
+            .iter()
+            .map(|row| row.get::<String, _>("id"))
+            .collect::<Vec<_>>();
+        let conditions = self
+            .find_conditions(&promotion_ids)
+            .await
+            .map_err(|e| Self::query_error("find_conditions", e))?;
+
+        rows.iter()
+            .zip(&promotion_ids)
+            .map(|(row, id)| {
+                Self::row_to_promotion(row, conditions.get(id).map_or(&[], Vec::as_slice))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(RepositoryError::DataConversionError)
+    }
+}
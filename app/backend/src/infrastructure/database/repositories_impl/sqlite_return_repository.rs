@@ -0,0 +1,349 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use uuid::Uuid;
+
+use crate::application::error::RepositoryError;
+use crate::application::repositories::ReturnRepository;
+use crate::domain::StockMovement;
+use crate::domain::aggregates::return_request::{ReturnItem, ReturnRequest, ReturnStatus};
+use crate::domain::value_objects::{Money, OrderId, ProductName, ReturnId, SKUCode, SKUId};
+
+use super::sqlite_stock_movement_repository::insert_stock_movement;
+
+const RETURN_SELECT: &str = r#"
+    SELECT
+        id, order_id, status, reason, item_condition, note, rejection_reason,
+        items_amount, tax_amount, tax_included, restocking_fee_rate, restocking_fee,
+        refund_amount, created_at, updated_at, approved_at, received_at, refunded_at, rejected_at
+    FROM returns
+"#;
+
+/// SQLite実装のReturnRepository
+/// Clean Architecture: Frameworks & Drivers層
+pub struct SqliteReturnRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteReturnRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn query_error(context: &str, e: impl std::fmt::Display) -> RepositoryError {
+        RepositoryError::QueryExecution(format!("[SqliteReturnRepository::{}] {}", context, e))
+    }
+
+    fn conversion_error(e: impl std::fmt::Debug) -> RepositoryError {
+        RepositoryError::DataConversionError(format!("{:?}", e))
+    }
+
+    fn parse_datetime(value: &str) -> Result<DateTime<Utc>, RepositoryError> {
+        DateTime::parse_from_rfc3339(value)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(Self::conversion_error)
+    }
+
+    fn parse_optional_datetime(
+        row: &SqliteRow,
+        column: &str,
+    ) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        row.get::<Option<String>, _>(column)
+            .as_deref()
+            .map(Self::parse_datetime)
+            .transpose()
+    }
+
+    fn money(row: &SqliteRow, column: &str) -> Money {
+        Money::from_yen(row.get::<i64, _>(column).max(0) as u32)
+    }
+
+    fn row_to_item(row: &SqliteRow) -> Result<ReturnItem, RepositoryError> {
+        let sku_id =
+            Uuid::parse_str(&row.get::<String, _>("sku_id")).map_err(Self::conversion_error)?;
+
+        Ok(ReturnItem {
+            sku_id: SKUId::from_uuid(sku_id),
+            sku_code: SKUCode::new(row.get("sku_code")).map_err(Self::conversion_error)?,
+            product_name: ProductName::new(row.get("product_name"))
+                .map_err(Self::conversion_error)?,
+            quantity: row.get::<i64, _>("quantity") as u32,
+            tax_rate: row.get::<i64, _>("tax_rate") as u32,
+            amount: Self::money(row, "amount"),
+            tax_amount: Self::money(row, "tax_amount"),
+        })
+    }
+
+    fn row_to_return(
+        row: &SqliteRow,
+        items: Vec<ReturnItem>,
+    ) -> Result<ReturnRequest, RepositoryError> {
+        let id = Uuid::parse_str(&row.get::<String, _>("id")).map_err(Self::conversion_error)?;
+        let order_id =
+            Uuid::parse_str(&row.get::<String, _>("order_id")).map_err(Self::conversion_error)?;
+
+        Ok(ReturnRequest {
+            id: ReturnId::from_uuid(id),
+            order_id: OrderId::from_uuid(order_id),
+            items,
+            reason: row
+                .get::<String, _>("reason")
+                .parse()
+                .map_err(Self::conversion_error)?,
+            condition: row
+                .get::<String, _>("item_condition")
+                .parse()
+                .map_err(Self::conversion_error)?,
+            note: row.get("note"),
+            status: row
+                .get::<String, _>("status")
+                .parse()
+                .map_err(Self::conversion_error)?,
+            rejection_reason: row.get("rejection_reason"),
+            items_amount: Self::money(row, "items_amount"),
+            tax_amount: Self::money(row, "tax_amount"),
+            tax_included: row.get("tax_included"),
+            restocking_fee_rate: row.get::<i64, _>("restocking_fee_rate") as u32,
+            restocking_fee: Self::money(row, "restocking_fee"),
+            refund_amount: Self::money(row, "refund_amount"),
+            created_at: Self::parse_datetime(&row.get::<String, _>("created_at"))?,
+            updated_at: Self::parse_datetime(&row.get::<String, _>("updated_at"))?,
+            approved_at: Self::parse_optional_datetime(row, "approved_at")?,
+            received_at: Self::parse_optional_datetime(row, "received_at")?,
+            refunded_at: Self::parse_optional_datetime(row, "refunded_at")?,
+            rejected_at: Self::parse_optional_datetime(row, "rejected_at")?,
+        })
+    }
+
+    /// 返品の行と明細の行を組み立てる
+    fn assemble(
+        rows: &[SqliteRow],
+        item_rows: &[SqliteRow],
+    ) -> Result<Vec<ReturnRequest>, RepositoryError> {
+        rows.iter()
+            .map(|row| {
+                let id: String = row.get("id");
+                let items = item_rows
+                    .iter()
+                    .filter(|item_row| item_row.get::<String, _>("return_id") == id)
+                    .map(Self::row_to_item)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Self::row_to_return(row, items)
+            })
+            .collect()
+    }
+
+    /// ステータス・金額の更新（受領時の商品状態による再計算を含む）
+    /// 読み込み時のステータスを条件にし、同時に行われた遷移と二重に適用しない
+    async fn update_return_row(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        return_request: &ReturnRequest,
+        previous_status: ReturnStatus,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE returns SET
+                status = ?2, item_condition = ?3, rejection_reason = ?4,
+                items_amount = ?5, tax_amount = ?6, restocking_fee = ?7, refund_amount = ?8,
+                updated_at = ?9, approved_at = ?10, received_at = ?11, refunded_at = ?12,
+                rejected_at = ?13
+            WHERE id = ?1 AND status = ?14
+            "#,
+        )
+        .bind(return_request.id.to_string())
+        .bind(return_request.status.code())
+        .bind(return_request.condition.code())
+        .bind(&return_request.rejection_reason)
+        .bind(return_request.items_amount.yen() as i64)
+        .bind(return_request.tax_amount.yen() as i64)
+        .bind(return_request.restocking_fee.yen() as i64)
+        .bind(return_request.refund_amount.yen() as i64)
+        .bind(return_request.updated_at.to_rfc3339())
+        .bind(return_request.approved_at.map(|t| t.to_rfc3339()))
+        .bind(return_request.received_at.map(|t| t.to_rfc3339()))
+        .bind(return_request.refunded_at.map(|t| t.to_rfc3339()))
+        .bind(return_request.rejected_at.map(|t| t.to_rfc3339()))
+        .bind(previous_status.code())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| Self::query_error("update", e))?;
+
+        if result.rows_affected() == 0 {
+            let exists: Option<i64> = sqlx::query_scalar("SELECT 1 FROM returns WHERE id = ?1")
+                .bind(return_request.id.to_string())
+                .fetch_optional(&mut **tx)
+                .await
+                .map_err(|e| Self::query_error("update", e))?;
+            return Err(match exists {
+                Some(_) => RepositoryError::Conflict(format!(
+                    "Return {} was modified by another request",
+                    return_request.id
+                )),
+                None => RepositoryError::NotFound,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ReturnRepository for SqliteReturnRepository {
+    async fn save(&self, return_request: &ReturnRequest) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO returns (
+                id, order_id, status, reason, item_condition, note, rejection_reason,
+                items_amount, tax_amount, tax_included, restocking_fee_rate, restocking_fee,
+                refund_amount, created_at, updated_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+            "#,
+        )
+        .bind(return_request.id.to_string())
+        .bind(return_request.order_id.to_string())
+        .bind(return_request.status.code())
+        .bind(return_request.reason.code())
+        .bind(return_request.condition.code())
+        .bind(&return_request.note)
+        .bind(&return_request.rejection_reason)
+        .bind(return_request.items_amount.yen() as i64)
+        .bind(return_request.tax_amount.yen() as i64)
+        .bind(return_request.tax_included)
+        .bind(return_request.restocking_fee_rate as i64)
+        .bind(return_request.restocking_fee.yen() as i64)
+        .bind(return_request.refund_amount.yen() as i64)
+        .bind(return_request.created_at.to_rfc3339())
+        .bind(return_request.updated_at.to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Self::query_error("save", e))?;
+
+        for item in &return_request.items {
+            sqlx::query(
+                r#"
+                INSERT INTO return_items (
+                    return_id, sku_id, sku_code, product_name, quantity, tax_rate, amount, tax_amount
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                "#,
+            )
+            .bind(return_request.id.to_string())
+            .bind(item.sku_id.to_string())
+            .bind(item.sku_code.value())
+            .bind(item.product_name.value())
+            .bind(item.quantity as i64)
+            .bind(item.tax_rate as i64)
+            .bind(item.amount.yen() as i64)
+            .bind(item.tax_amount.yen() as i64)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("save", e))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        return_request: &ReturnRequest,
+        previous_status: ReturnStatus,
+        restocks: &[StockMovement],
+    ) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        Self::update_return_row(&mut tx, return_request, previous_status).await?;
+
+        // 受領した商品を実在庫に戻し、在庫台帳に記録する
+        for movement in restocks {
+            let sku_id = movement.sku_id.to_string();
+            sqlx::query(
+                r#"
+                UPDATE skus
+                SET stock_quantity = stock_quantity + ?1, updated_at = datetime('now')
+                WHERE id = ?2
+                "#,
+            )
+            .bind(movement.delta)
+            .bind(&sku_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("update", e))?;
+
+            insert_stock_movement(&mut *tx, movement)
+                .await
+                .map_err(|e| {
+                    Self::query_error("update", format!("SKU: {}, Error: {}", sku_id, e))
+                })?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &ReturnId) -> Result<Option<ReturnRequest>, RepositoryError> {
+        let query = format!("{} WHERE id = ?1", RETURN_SELECT);
+        let Some(row) = sqlx::query(&query)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| Self::query_error("find_by_id", e))?
+        else {
+            return Ok(None);
+        };
+
+        let item_rows =
+            sqlx::query("SELECT * FROM return_items WHERE return_id = ?1 ORDER BY rowid")
+                .bind(id.to_string())
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| Self::query_error("find_by_id", e))?;
+
+        Ok(Self::assemble(&[row], &item_rows)?.pop())
+    }
+
+    async fn find_by_order_id(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<Vec<ReturnRequest>, RepositoryError> {
+        let query = format!(
+            "{} WHERE order_id = ?1 ORDER BY created_at, rowid",
+            RETURN_SELECT
+        );
+        let rows = sqlx::query(&query)
+            .bind(order_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| Self::query_error("find_by_order_id", e))?;
+
+        let item_rows = sqlx::query(
+            r#"
+            SELECT ri.* FROM return_items ri
+            JOIN returns r ON r.id = ri.return_id
+            WHERE r.order_id = ?1
+            ORDER BY ri.rowid
+            "#,
+        )
+        .bind(order_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_by_order_id", e))?;
+
+        Self::assemble(&rows, &item_rows)
+    }
+}
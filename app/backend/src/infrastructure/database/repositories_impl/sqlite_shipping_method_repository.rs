@@ -5,9 +5,19 @@ use std::sync::Arc;
 use crate::application::dto::{ShippingMethodDTO, ShippingMethodListDTO};
 use crate::application::error::RepositoryError;
 use crate::application::repositories::ShippingMethodRepository;
-use crate::domain::entities::ShippingMethod;
+use crate::domain::entities::{
+    OversizeSurcharge, ShippingMethod, ShippingRateTable, ShippingRegion, ShippingRegionRate,
+};
 use crate::domain::value_objects::{Money, ShippingMethodId};
 
+/// 配送方法取得用のSELECT句（料金表のカラムを含む）
+const SHIPPING_METHOD_SELECT: &str = r#"
+    SELECT
+        id, name, description, price, is_active, sort_order, created_at, updated_at,
+        free_shipping_threshold, oversize_min_size, oversize_surcharge, quick_ship_only
+    FROM shipping_methods
+"#;
+
 pub struct SqliteShippingMethodRepository {
     pool: Arc<SqlitePool>,
 }
@@ -16,75 +26,109 @@ impl SqliteShippingMethodRepository {
     pub fn new(pool: Arc<SqlitePool>) -> Self {
         Self { pool }
     }
+
+    /// shipping_methodsの行から料金表付きのShippingMethodを復元
+    async fn row_to_shipping_method(
+        &self,
+        row: &sqlx::sqlite::SqliteRow,
+    ) -> Result<ShippingMethod, RepositoryError> {
+        use chrono::{DateTime, Utc};
+
+        let shipping_method_id = ShippingMethodId::new(row.get::<String, _>("id"))
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(ShippingMethod::with_timestamps(
+            shipping_method_id,
+            row.get::<String, _>("name"),
+            row.get::<String, _>("description"),
+            Money::from_yen(row.get::<i64, _>("price") as u32),
+            row.get::<bool, _>("is_active"),
+            row.get::<u32, _>("sort_order"),
+            row.get::<DateTime<Utc>, _>("created_at"),
+            row.get::<DateTime<Utc>, _>("updated_at"),
+        )
+        .with_rate_table(self.find_rate_table(row).await?))
+    }
+
+    /// 配送方法の料金表を構築（地域別料金はshipping_region_ratesから取得）
+    async fn find_rate_table(
+        &self,
+        row: &sqlx::sqlite::SqliteRow,
+    ) -> Result<ShippingRateTable, RepositoryError> {
+        let region_rows = sqlx::query(
+            r#"
+            SELECT region, fee
+            FROM shipping_region_rates
+            WHERE shipping_method_id = ?
+            ORDER BY region ASC
+            "#,
+        )
+        .bind(row.get::<String, _>("id"))
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let region_rates = region_rows
+            .iter()
+            .map(|region_row| {
+                let region = region_row
+                    .get::<String, _>("region")
+                    .parse::<ShippingRegion>()
+                    .map_err(|e| RepositoryError::DataConversionError(e.to_string()))?;
+                Ok(ShippingRegionRate::new(
+                    region,
+                    Money::from_yen(region_row.get::<i64, _>("fee") as u32),
+                ))
+            })
+            .collect::<Result<Vec<_>, RepositoryError>>()?;
+
+        let oversize_surcharge = row
+            .get::<Option<f64>, _>("oversize_min_size")
+            .map(|min_size| OversizeSurcharge {
+                min_size,
+                fee_per_item: Money::from_yen(row.get::<i64, _>("oversize_surcharge") as u32),
+            });
+
+        ShippingRateTable::new(
+            region_rates,
+            row.get::<Option<i64>, _>("free_shipping_threshold")
+                .map(|threshold| Money::from_yen(threshold as u32)),
+            oversize_surcharge,
+            row.get::<bool, _>("quick_ship_only"),
+        )
+        .map_err(|e| RepositoryError::DataConversionError(e.to_string()))
+    }
 }
 
 #[async_trait]
 impl ShippingMethodRepository for SqliteShippingMethodRepository {
     async fn find_all(&self) -> Result<ShippingMethodListDTO, RepositoryError> {
-        let rows = sqlx::query(
-            r#"
-            SELECT id, name, description, price, is_active, sort_order, created_at, updated_at
-            FROM shipping_methods
-            WHERE is_active = 1
-            ORDER BY sort_order ASC
-            "#,
-        )
+        let rows = sqlx::query(&format!(
+            "{} WHERE is_active = 1 ORDER BY sort_order ASC",
+            SHIPPING_METHOD_SELECT
+        ))
         .fetch_all(self.pool.as_ref())
         .await
         .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
 
         let mut methods = Vec::new();
         for row in rows {
-            let id: String = row.get("id");
-            let name: String = row.get("name");
-            let description: String = row.get("description");
-            let price: i64 = row.get("price");
-
-            let dto = ShippingMethodDTO {
-                id,
-                name,
-                description,
-                price: price as u32,
-            };
-            methods.push(dto);
+            let shipping_method = self.row_to_shipping_method(&row).await?;
+            methods.push(ShippingMethodDTO::from(&shipping_method));
         }
 
         Ok(ShippingMethodListDTO::new(methods))
     }
 
     async fn find_by_id(&self, id: &str) -> Result<Option<ShippingMethod>, RepositoryError> {
-        let row = sqlx::query(
-            r#"
-            SELECT id, name, description, price, is_active, sort_order, created_at, updated_at
-            FROM shipping_methods
-            WHERE id = ?
-            "#,
-        )
-        .bind(id)
-        .fetch_optional(self.pool.as_ref())
-        .await
-        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        let row = sqlx::query(&format!("{} WHERE id = ?", SHIPPING_METHOD_SELECT))
+            .bind(id)
+            .fetch_optional(self.pool.as_ref())
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
 
         match row {
-            Some(row) => {
-                use chrono::{DateTime, Utc};
-
-                let shipping_method_id = ShippingMethodId::new(row.get::<String, _>("id"))
-                    .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
-
-                let shipping_method = ShippingMethod::with_timestamps(
-                    shipping_method_id,
-                    row.get::<String, _>("name"),
-                    row.get::<String, _>("description"),
-                    Money::from_yen(row.get::<i64, _>("price") as u32),
-                    row.get::<bool, _>("is_active"),
-                    row.get::<u32, _>("sort_order"),
-                    row.get::<DateTime<Utc>, _>("created_at"),
-                    row.get::<DateTime<Utc>, _>("updated_at"),
-                );
-
-                Ok(Some(shipping_method))
-            }
+            Some(row) => Ok(Some(self.row_to_shipping_method(&row).await?)),
             None => Ok(None),
         }
     }
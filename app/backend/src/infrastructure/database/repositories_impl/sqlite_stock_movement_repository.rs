@@ -0,0 +1,203 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use sqlx::sqlite::SqliteRow;
+
+use crate::application::dto::StockMovementDTO;
+use crate::application::error::{RepositoryError, StockShortage};
+use crate::application::repositories::StockMovementRepository;
+use crate::domain::{SKUId, StockMovement};
+
+use super::row_helpers::{get_column, parse_timestamp};
+
+/// 在庫台帳取得用のSELECT句
+const STOCK_MOVEMENT_SELECT: &str = r#"
+    SELECT
+        id, sku_id, movement_type, delta, stock_after,
+        reason, reference_order_id, actor, created_at
+    FROM stock_movements
+"#;
+
+/// SQLite実装のStockMovementRepository
+/// Clean Architecture: Frameworks & Drivers層
+pub struct SqliteStockMovementRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteStockMovementRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn query_error(context: &str, e: impl std::fmt::Display) -> RepositoryError {
+        RepositoryError::QueryExecution(format!(
+            "[SqliteStockMovementRepository::{}] {}",
+            context, e
+        ))
+    }
+}
+
+/// 在庫台帳に1行追記し、採番されたIDを返す
+/// 変動後の在庫数はSKUの現在の在庫数を記録するため、在庫数の更新後に呼び出すこと
+/// 注文・商品の保存と同一トランザクションで記録できるよう、実行先を引数で受け取る
+pub(super) async fn insert_stock_movement<'e, E>(
+    executor: E,
+    movement: &StockMovement,
+) -> Result<i64, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+{
+    let result = sqlx::query(
+        r#"
+        INSERT INTO stock_movements (
+            sku_id, movement_type, delta, stock_after,
+            reason, reference_order_id, actor, created_at
+        )
+        SELECT ?1, ?2, ?3, stock_quantity, ?4, ?5, ?6, ?7
+        FROM skus
+        WHERE id = ?1
+        "#,
+    )
+    .bind(movement.sku_id.value().to_string())
+    .bind(movement.kind.to_string())
+    .bind(movement.delta)
+    .bind(&movement.reason)
+    .bind(
+        movement
+            .reference_order_id
+            .as_ref()
+            .map(|order_id| order_id.value().to_string()),
+    )
+    .bind(&movement.actor)
+    .bind(movement.occurred_at.to_rfc3339())
+    .execute(executor)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+#[async_trait]
+impl StockMovementRepository for SqliteStockMovementRepository {
+    async fn record_adjustment(
+        &self,
+        movement: &StockMovement,
+    ) -> Result<StockMovementDTO, RepositoryError> {
+        let sku_id = movement.sku_id.value().to_string();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        if let Some(order_id) = &movement.reference_order_id {
+            let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM orders WHERE id = ?1")
+                .bind(order_id.value().to_string())
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| Self::query_error("record_adjustment", e))?;
+            if count == 0 {
+                return Err(RepositoryError::ForeignKeyConstraint {
+                    field: "reference_order_id".to_string(),
+                    message: format!("Order {} does not exist", order_id),
+                });
+            }
+        }
+
+        // 読み取った在庫数ではなくDB上の値を条件付きで更新し、引当済みの在庫を減らさないようにする
+        let result = sqlx::query(
+            r#"
+            UPDATE skus
+            SET stock_quantity = stock_quantity + ?1, updated_at = datetime('now')
+            WHERE id = ?2 AND stock_quantity - reserved_quantity + ?1 >= 0
+            "#,
+        )
+        .bind(movement.delta)
+        .bind(&sku_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Self::query_error("record_adjustment", e))?;
+
+        if result.rows_affected() == 0 {
+            let row = sqlx::query(
+                "SELECT sku_code, stock_quantity - reserved_quantity AS available FROM skus WHERE id = ?1",
+            )
+            .bind(&sku_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("record_adjustment", e))?;
+
+            let Some(row) = row else {
+                return Err(RepositoryError::NotFound);
+            };
+            let available: i64 = get_column(&row, "available")?;
+            return Err(RepositoryError::InsufficientStock(vec![StockShortage {
+                sku_code: get_column(&row, "sku_code")?,
+                requested: movement.delta.unsigned_abs() as u32,
+                available: available.max(0) as u32,
+            }]));
+        }
+
+        let id = insert_stock_movement(&mut *tx, movement)
+            .await
+            .map_err(|e| Self::query_error("record_adjustment", e))?;
+
+        let row = sqlx::query(&format!("{} WHERE id = ?1", STOCK_MOVEMENT_SELECT))
+            .bind(id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| Self::query_error("record_adjustment", e))?;
+        let recorded = to_dto(&row)?;
+
+        tx.commit()
+            .await
+            .map_err(|e| Self::query_error("record_adjustment", e))?;
+
+        Ok(recorded)
+    }
+
+    async fn find_by_sku_id(
+        &self,
+        sku_id: &SKUId,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<StockMovementDTO>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE sku_id = ?1 ORDER BY id DESC LIMIT ?2 OFFSET ?3",
+            STOCK_MOVEMENT_SELECT
+        ))
+        .bind(sku_id.value().to_string())
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Self::query_error("find_by_sku_id", e))?;
+
+        rows.iter().map(to_dto).collect()
+    }
+
+    async fn count_by_sku_id(&self, sku_id: &SKUId) -> Result<u32, RepositoryError> {
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM stock_movements WHERE sku_id = ?1")
+                .bind(sku_id.value().to_string())
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| Self::query_error("count_by_sku_id", e))?;
+
+        Ok(count as u32)
+    }
+}
+
+/// 在庫台帳の行からDTOを構築
+fn to_dto(row: &SqliteRow) -> Result<StockMovementDTO, RepositoryError> {
+    Ok(StockMovementDTO {
+        id: get_column(row, "id")?,
+        sku_id: get_column(row, "sku_id")?,
+        movement_type: get_column(row, "movement_type")?,
+        delta: get_column(row, "delta")?,
+        stock_after: get_column(row, "stock_after")?,
+        reason: get_column(row, "reason")?,
+        reference_order_id: get_column(row, "reference_order_id")?,
+        actor: get_column(row, "actor")?,
+        created_at: parse_timestamp(&get_column::<String>(row, "created_at")?)?,
+    })
+}
@@ -6,6 +6,8 @@ use async_trait::async_trait;
 use sqlx::{Row, SqlitePool};
 use uuid::Uuid;
 
+use super::sku_price_sql::EFFECTIVE_SALE_PRICE_SQL;
+
 pub struct SqliteVariantRepository {
     pool: SqlitePool,
 }
@@ -35,16 +37,16 @@ impl VariantRepository for SqliteVariantRepository {
         let query = format!(
             r#"
             SELECT 
-                id,
-                base_price,
-                sale_price,
-                image_url,
-                material,
-                dimensions
-            FROM skus 
-            WHERE id IN ({})
+                s.id,
+                s.base_price,
+                {} as sale_price,
+                s.image_url,
+                s.material,
+                s.dimensions
+            FROM skus s
+            WHERE s.id IN ({})
             "#,
-            placeholders
+            EFFECTIVE_SALE_PRICE_SQL, placeholders
         );
 
         let mut query_builder = sqlx::query(&query);
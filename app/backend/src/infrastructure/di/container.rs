@@ -1,24 +1,215 @@
 use std::sync::Arc;
 
 use crate::application::commands::CalculateCartHandler;
-use crate::application::commands::handlers::CreateOrderHandler;
+use crate::application::commands::handlers::{
+    AddCartItemHandler, AddOrderNoteHandler, AddProductSkuHandler, AdjustStockHandler,
+    AmendOrderHandler, ApproveReturnHandler, AttachShipmentHandler, AuthorizePaymentHandler,
+    CancelOrderHandler, CapturePaymentHandler, CreateCartHandler, CreateCouponHandler,
+    CreateOrderHandler, CreateProductHandler, DeleteCouponHandler, DeleteProductHandler,
+    DeliverOrderHandler, GenerateCouponCodesHandler, IssueOrderDocumentHandler,
+    LoginCustomerHandler, LogoutCustomerHandler, MarkOrderPaidHandler, ProcessOrderHandler,
+    PublishProductHandler, ReceiveReturnHandler, RecordShipmentEventHandler, RefundOrderHandler,
+    RefundReturnHandler, RegisterCustomerHandler, RejectReturnHandler, RemoveCartItemHandler,
+    RemoveProductSkuHandler, RequestReturnHandler, ScheduleSkuSaleHandler, SetCartCouponHandler,
+    SetCartPaymentMethodHandler, SetCartShippingMethodHandler, ShipOrderHandler,
+    UnpublishProductHandler, UpdateCartItemHandler, UpdateCouponHandler,
+    UpdatePaymentFeeRuleHandler, UpdateProductHandler, UpdateProductSkuHandler, VoidPaymentHandler,
+};
+use crate::application::dispatcher::{
+    AdminHandlers, CartHandlers, CatalogHandlers, CustomerHandlers, FulfillmentHandlers,
+    OrderHandlers, PaymentHandlers,
+};
+use crate::application::gateways::PaymentGateway;
 use crate::application::queries::handlers::{
-    GetPaymentMethodListHandler, GetShippingMethodListHandler,
+    AuthenticateCustomerHandler, GetAdminProductHandler, GetCartHandler, GetCouponHandler,
+    GetCouponListHandler, GetOrderEventsHandler, GetOrderHandler, GetOrderReturnsHandler,
+    GetPaymentMethodListHandler, GetPaymentsHandler, GetReturnHandler, GetShipmentHandler,
+    GetShippingMethodListHandler, GetStockHistoryHandler, ListOrdersHandler, SearchProductsHandler,
 };
 use crate::application::repositories::{
-    CategoryRepository, ColorRepository, OrderRepository, PaymentMethodRepository,
-    ProductRepository, ShippingMethodRepository, VariantRepository,
+    CartRepository, CategoryRepository, ColorRepository, DeliveryInfoRepository, OrderRepository,
+    PaymentMethodRepository, PaymentRepository, ProductRepository, ProductWriteRepository,
+    ShippingMethodRepository, StockMovementRepository, VariantRepository,
 };
+use crate::application::security::{PasswordHasher, SessionTokenSigner};
+use crate::application::services::{CartPricingService, IdempotencyService};
 use crate::application::{
     Dispatcher, FindVariantsHandler, GetCategoryListHandler, GetColorListHandler,
     GetProductHandler, GetProductListHandler,
 };
+use crate::domain::value_objects::{
+    DocumentIssuer, InvoiceRegistrationNumber, OrderNumberFormat, OrderNumberReset, TaxPolicy,
+    TaxPriceMode, TaxRounding, TaxRoundingUnit,
+};
 use crate::infrastructure::database::db::get_db;
 use crate::infrastructure::database::repositories_impl::{
-    SqliteCategoryRepository, SqliteColorRepository, SqliteCouponRepository, SqliteOrderRepository,
-    SqlitePaymentMethodRepository, SqliteProductRepository, SqliteShippingMethodRepository,
+    SqliteCartRepository, SqliteCategoryRepository, SqliteColorRepository, SqliteCouponRepository,
+    SqliteCustomerRepository, SqliteDeliveryInfoRepository, SqliteIdempotencyKeyRepository,
+    SqliteOrderRepository, SqlitePaymentMethodRepository, SqlitePaymentRepository,
+    SqliteProductRepository, SqliteProductWriteRepository, SqlitePromotionRepository,
+    SqliteReturnRepository, SqliteShippingMethodRepository, SqliteStockMovementRepository,
     SqliteVariantRepository,
 };
+use crate::infrastructure::documents::OrderDocumentRenderer;
+use crate::infrastructure::payments::MockPaymentGateway;
+use crate::infrastructure::security::{Argon2PasswordHasher, HmacSessionTokenSigner};
+
+/// カートの有効期限（時間）のデフォルト値
+const DEFAULT_CART_TTL_HOURS: i64 = 72;
+
+/// 放置されたカートが失効するまでの期間
+/// 環境変数 CART_TTL_HOURS で上書きできる
+fn cart_ttl() -> chrono::Duration {
+    let hours = std::env::var("CART_TTL_HOURS")
+        .ok()
+        .and_then(|value| value.parse::<i64>().ok())
+        .filter(|hours| *hours > 0)
+        .unwrap_or(DEFAULT_CART_TTL_HOURS);
+    chrono::Duration::hours(hours)
+}
+
+/// ログインセッションの有効期限（時間）のデフォルト値
+const DEFAULT_SESSION_TTL_HOURS: i64 = 168;
+
+/// ログインセッションの有効期間
+/// 環境変数 SESSION_TTL_HOURS で上書きできる
+fn session_ttl() -> chrono::Duration {
+    let hours = std::env::var("SESSION_TTL_HOURS")
+        .ok()
+        .and_then(|value| value.parse::<i64>().ok())
+        .filter(|hours| *hours > 0)
+        .unwrap_or(DEFAULT_SESSION_TTL_HOURS);
+    chrono::Duration::hours(hours)
+}
+
+/// 冪等キーの有効期限（時間）のデフォルト値
+const DEFAULT_IDEMPOTENCY_KEY_TTL_HOURS: i64 = 24;
+
+/// 冪等キーと保存したレスポンスを保持する期間
+/// 環境変数 IDEMPOTENCY_KEY_TTL_HOURS で上書きできる
+fn idempotency_key_ttl() -> chrono::Duration {
+    let hours = std::env::var("IDEMPOTENCY_KEY_TTL_HOURS")
+        .ok()
+        .and_then(|value| value.parse::<i64>().ok())
+        .filter(|hours| *hours > 0)
+        .unwrap_or(DEFAULT_IDEMPOTENCY_KEY_TTL_HOURS);
+    chrono::Duration::hours(hours)
+}
+
+/// お客様都合の返品で差し引く再入荷手数料率（%）
+/// 環境変数 RETURN_RESTOCKING_FEE_PERCENT で上書きできる（0〜100）
+fn return_restocking_fee_rate() -> u32 {
+    std::env::var("RETURN_RESTOCKING_FEE_PERCENT")
+        .ok()
+        .and_then(|value| value.parse::<u32>().ok())
+        .filter(|rate| *rate <= 100)
+        .unwrap_or(RequestReturnHandler::DEFAULT_RESTOCKING_FEE_RATE)
+}
+
+/// 請求書・領収書の発行者
+/// 環境変数 DOCUMENT_ISSUER_NAME / DOCUMENT_ISSUER_ADDRESS / DOCUMENT_ISSUER_PHONE、
+/// INVOICE_REGISTRATION_NUMBER（適格請求書発行事業者の登録番号 T + 13桁）で設定する
+fn document_issuer() -> DocumentIssuer {
+    fn env(name: &str) -> Option<String> {
+        std::env::var(name)
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    }
+
+    let registration_number = env("INVOICE_REGISTRATION_NUMBER").and_then(|value| {
+        match InvoiceRegistrationNumber::new(&value) {
+            Ok(number) => Some(number),
+            Err(e) => {
+                println!("->> INVOICE_REGISTRATION_NUMBER is ignored: {}", e);
+                None
+            }
+        }
+    });
+
+    DocumentIssuer::new(
+        env("DOCUMENT_ISSUER_NAME").unwrap_or_else(|| DocumentIssuer::DEFAULT_NAME.to_string()),
+    )
+    .with_registration_number(registration_number)
+    .with_address(env("DOCUMENT_ISSUER_ADDRESS"))
+    .with_phone(env("DOCUMENT_ISSUER_PHONE"))
+}
+
+/// セッショントークンの署名
+/// 環境変数 SESSION_SECRET を署名鍵に使う（未設定の場合は起動ごとのランダムな鍵）
+fn session_token_signer() -> HmacSessionTokenSigner {
+    match std::env::var("SESSION_SECRET") {
+        Ok(secret) if !secret.is_empty() => HmacSessionTokenSigner::new(secret.into_bytes()),
+        _ => {
+            println!("->> SESSION_SECRET is not set; session tokens will not survive a restart");
+            HmacSessionTokenSigner::with_random_secret()
+        }
+    }
+}
+
+/// 管理・運用向けAPIの認証に使うトークン
+/// 環境変数 ADMIN_API_TOKEN で設定する（未設定の場合は管理用APIをすべて拒否する）
+fn admin_api_token() -> Option<String> {
+    match std::env::var("ADMIN_API_TOKEN") {
+        Ok(token) if !token.trim().is_empty() => Some(token.trim().to_string()),
+        _ => {
+            println!("->> ADMIN_API_TOKEN is not set; admin APIs are disabled");
+            None
+        }
+    }
+}
+
+/// 消費税の計算ポリシー
+/// 環境変数 TAX_STANDARD_RATE / TAX_REDUCED_RATE（%）、TAX_PRICE_MODE（exclusive / inclusive）、
+/// TAX_ROUNDING（floor / round / ceil）、TAX_ROUNDING_UNIT（per_line / per_invoice）で上書きできる
+fn tax_policy() -> TaxPolicy {
+    fn env<T: std::str::FromStr>(name: &str) -> Option<T> {
+        std::env::var(name)
+            .ok()
+            .and_then(|value| value.parse().ok())
+    }
+
+    let mut policy = TaxPolicy::new(
+        env("TAX_STANDARD_RATE").unwrap_or(TaxPolicy::DEFAULT_STANDARD_RATE),
+        env("TAX_REDUCED_RATE").unwrap_or(TaxPolicy::DEFAULT_REDUCED_RATE),
+    )
+    .unwrap_or_default();
+    if let Some(price_mode) = env::<TaxPriceMode>("TAX_PRICE_MODE") {
+        policy = policy.with_price_mode(price_mode);
+    }
+    if let Some(rounding) = env::<TaxRounding>("TAX_ROUNDING") {
+        policy = policy.with_rounding(rounding);
+    }
+    if let Some(rounding_unit) = env::<TaxRoundingUnit>("TAX_ROUNDING_UNIT") {
+        policy = policy.with_rounding_unit(rounding_unit);
+    }
+    policy
+}
+
+/// 注文番号の書式
+/// 環境変数 ORDER_NUMBER_PREFIX、ORDER_NUMBER_RESET（never / yearly / monthly）、
+/// ORDER_NUMBER_WIDTH（連番の桁数）、ORDER_NUMBER_CHECK_DIGIT（true / false）で上書きできる
+fn order_number_format() -> OrderNumberFormat {
+    fn env<T: std::str::FromStr>(name: &str) -> Option<T> {
+        std::env::var(name)
+            .ok()
+            .and_then(|value| value.parse().ok())
+    }
+
+    OrderNumberFormat::new(
+        env("ORDER_NUMBER_PREFIX").unwrap_or_else(|| OrderNumberFormat::DEFAULT_PREFIX.to_string()),
+        env::<OrderNumberReset>("ORDER_NUMBER_RESET").unwrap_or_default(),
+        env("ORDER_NUMBER_WIDTH").unwrap_or(OrderNumberFormat::DEFAULT_WIDTH),
+        env("ORDER_NUMBER_CHECK_DIGIT").unwrap_or(false),
+    )
+    .unwrap_or_else(|e| {
+        println!(
+            "->> Invalid order number format ({}); using the default format",
+            e
+        );
+        OrderNumberFormat::default()
+    })
+}
 
 /// コンテナはアプリケーションの依存関係を管理します
 /// Uncle Bob's Clean Architecture: Frameworks & Drivers層でDI設定
@@ -37,6 +228,22 @@ pub struct Container {
     pub payment_method_repository: Arc<dyn PaymentMethodRepository + Send + Sync>,
     /// OrderRepositoryの実装
     pub order_repository: Arc<dyn OrderRepository + Send + Sync>,
+    /// CartRepositoryの実装
+    pub cart_repository: Arc<dyn CartRepository + Send + Sync>,
+    /// ProductWriteRepositoryの実装（管理用の商品集約の永続化）
+    pub product_write_repository: Arc<dyn ProductWriteRepository + Send + Sync>,
+    /// StockMovementRepositoryの実装（在庫台帳）
+    pub stock_movement_repository: Arc<dyn StockMovementRepository + Send + Sync>,
+    /// DeliveryInfoRepositoryの実装（出荷・配送情報）
+    pub delivery_info_repository: Arc<dyn DeliveryInfoRepository + Send + Sync>,
+    /// PaymentRepositoryの実装（決済記録）
+    pub payment_repository: Arc<dyn PaymentRepository + Send + Sync>,
+    /// PaymentGatewayの実装（ローカル開発用のモック決済代行会社）
+    pub payment_gateway: Arc<dyn PaymentGateway>,
+    /// 冪等キー（Idempotency-Key）の管理サービス
+    pub idempotency_service: Arc<IdempotencyService>,
+    /// 管理・運用向けAPIの認証トークン（未設定の場合は管理用APIを拒否する）
+    pub admin_api_token: Option<String>,
     /// CQRSディスパッチャ
     pub dispatcher: Arc<Dispatcher>,
 }
@@ -71,7 +278,18 @@ impl Container {
             Arc::new(SqliteShippingMethodRepository::new(Arc::new(pool.clone())));
         let payment_method_repository = Arc::new(SqlitePaymentMethodRepository::new(pool.clone()));
         let coupon_repository = Arc::new(SqliteCouponRepository::new(pool.clone()));
-        let order_repository = Arc::new(SqliteOrderRepository::new(pool.clone()));
+        let promotion_repository = Arc::new(SqlitePromotionRepository::new(pool.clone()));
+        let order_number_format = order_number_format();
+        let order_repository = Arc::new(
+            SqliteOrderRepository::new(pool.clone())
+                .with_order_number_format(order_number_format.clone()),
+        );
+        let cart_repository = Arc::new(SqliteCartRepository::new(pool.clone(), cart_ttl()));
+        let payment_repository = Arc::new(SqlitePaymentRepository::new(pool.clone()));
+        let return_repository = Arc::new(SqliteReturnRepository::new(pool.clone()));
+        let payment_gateway: Arc<dyn PaymentGateway> =
+            Arc::new(MockPaymentGateway::new(pool.clone()));
+        let tax_policy = tax_policy();
 
         // ハンドラを作成
         let calculate_cart_handler = Arc::new(CalculateCartHandler::new(
@@ -79,6 +297,8 @@ impl Container {
             shipping_method_repository.clone(),
             payment_method_repository.clone(),
             coupon_repository.clone(),
+            promotion_repository.clone(),
+            tax_policy,
         ));
         let get_product_handler = Arc::new(GetProductHandler::new(product_repository.clone()));
         let get_product_list_handler =
@@ -93,24 +313,322 @@ impl Container {
         let get_payment_method_list_handler = Arc::new(GetPaymentMethodListHandler::new(
             payment_method_repository.clone(),
         ));
-        let create_order_handler = Arc::new(CreateOrderHandler::new(
+        let create_order_handler = Arc::new(
+            CreateOrderHandler::new(
+                product_repository.clone(),
+                shipping_method_repository.clone(),
+                payment_method_repository.clone(),
+                order_repository.clone(),
+                coupon_repository.clone(),
+                promotion_repository.clone(),
+                tax_policy,
+            )
+            .with_order_number_format(order_number_format),
+        );
+        let amend_order_handler = Arc::new(AmendOrderHandler::new(
+            order_repository.clone(),
+            product_repository.clone(),
+            shipping_method_repository.clone(),
+            payment_method_repository.clone(),
+            coupon_repository.clone(),
+            promotion_repository.clone(),
+            payment_repository.clone(),
+            tax_policy,
+        ));
+        let get_order_handler = Arc::new(GetOrderHandler::new(order_repository.clone()));
+        let list_orders_handler = Arc::new(ListOrdersHandler::new(order_repository.clone()));
+        let mark_order_paid_handler = Arc::new(MarkOrderPaidHandler::new(order_repository.clone()));
+        let process_order_handler = Arc::new(ProcessOrderHandler::new(order_repository.clone()));
+        let ship_order_handler = Arc::new(ShipOrderHandler::new(order_repository.clone()));
+        let deliver_order_handler = Arc::new(DeliverOrderHandler::new(order_repository.clone()));
+        let cancel_order_handler = Arc::new(CancelOrderHandler::new(order_repository.clone()));
+        let add_order_note_handler = Arc::new(AddOrderNoteHandler::new(order_repository.clone()));
+        let refund_order_handler = Arc::new(RefundOrderHandler::new(
+            order_repository.clone(),
+            payment_repository.clone(),
+            payment_gateway.clone(),
+            return_repository.clone(),
+        ));
+        let get_order_events_handler =
+            Arc::new(GetOrderEventsHandler::new(order_repository.clone()));
+        let cart_pricing_service = Arc::new(CartPricingService::new(
+            product_repository.clone(),
+            shipping_method_repository.clone(),
+            payment_method_repository.clone(),
+            coupon_repository.clone(),
+            promotion_repository.clone(),
+            tax_policy,
+        ));
+        let create_cart_handler = Arc::new(CreateCartHandler::new(
+            cart_repository.clone(),
+            cart_pricing_service.clone(),
+        ));
+        let add_cart_item_handler = Arc::new(AddCartItemHandler::new(
+            cart_repository.clone(),
+            product_repository.clone(),
+            cart_pricing_service.clone(),
+        ));
+        let update_cart_item_handler = Arc::new(UpdateCartItemHandler::new(
+            cart_repository.clone(),
             product_repository.clone(),
+            cart_pricing_service.clone(),
+        ));
+        let remove_cart_item_handler = Arc::new(RemoveCartItemHandler::new(
+            cart_repository.clone(),
+            cart_pricing_service.clone(),
+        ));
+        let set_cart_coupon_handler = Arc::new(SetCartCouponHandler::new(
+            cart_repository.clone(),
+            coupon_repository.clone(),
+            cart_pricing_service.clone(),
+        ));
+        let set_cart_shipping_method_handler = Arc::new(SetCartShippingMethodHandler::new(
+            cart_repository.clone(),
             shipping_method_repository.clone(),
+            cart_pricing_service.clone(),
+        ));
+        let set_cart_payment_method_handler = Arc::new(SetCartPaymentMethodHandler::new(
+            cart_repository.clone(),
             payment_method_repository.clone(),
+            cart_pricing_service.clone(),
+        ));
+        let get_cart_handler = Arc::new(GetCartHandler::new(
+            cart_repository.clone(),
+            cart_pricing_service,
+        ));
+        let search_products_handler =
+            Arc::new(SearchProductsHandler::new(product_repository.clone()));
+
+        // 商品管理（write側）
+        let product_write_repository = Arc::new(SqliteProductWriteRepository::new(pool.clone()));
+        let create_product_handler =
+            Arc::new(CreateProductHandler::new(product_write_repository.clone()));
+        let update_product_handler =
+            Arc::new(UpdateProductHandler::new(product_write_repository.clone()));
+        let delete_product_handler =
+            Arc::new(DeleteProductHandler::new(product_write_repository.clone()));
+        let publish_product_handler =
+            Arc::new(PublishProductHandler::new(product_write_repository.clone()));
+        let unpublish_product_handler = Arc::new(UnpublishProductHandler::new(
+            product_write_repository.clone(),
+        ));
+        let add_product_sku_handler =
+            Arc::new(AddProductSkuHandler::new(product_write_repository.clone()));
+        let update_product_sku_handler = Arc::new(UpdateProductSkuHandler::new(
+            product_write_repository.clone(),
+        ));
+        let remove_product_sku_handler = Arc::new(RemoveProductSkuHandler::new(
+            product_write_repository.clone(),
+        ));
+        let schedule_sku_sale_handler = Arc::new(ScheduleSkuSaleHandler::new(
+            product_write_repository.clone(),
+        ));
+        let get_admin_product_handler = Arc::new(GetAdminProductHandler::new(
+            product_write_repository.clone(),
+        ));
+
+        // 在庫管理
+        let stock_movement_repository = Arc::new(SqliteStockMovementRepository::new(pool.clone()));
+        let adjust_stock_handler = Arc::new(AdjustStockHandler::new(
+            product_write_repository.clone(),
+            stock_movement_repository.clone(),
+        ));
+        let get_stock_history_handler = Arc::new(GetStockHistoryHandler::new(
+            product_write_repository.clone(),
+            stock_movement_repository.clone(),
+        ));
+
+        // 出荷・配送追跡
+        let delivery_info_repository = Arc::new(SqliteDeliveryInfoRepository::new(pool.clone()));
+        let attach_shipment_handler =
+            Arc::new(AttachShipmentHandler::new(order_repository.clone()));
+        let record_shipment_event_handler = Arc::new(RecordShipmentEventHandler::new(
+            delivery_info_repository.clone(),
+            order_repository.clone(),
+        ));
+        let get_shipment_handler =
+            Arc::new(GetShipmentHandler::new(delivery_info_repository.clone()));
+
+        // 決済（オーソリ・売上確定・取消）
+        let authorize_payment_handler = Arc::new(AuthorizePaymentHandler::new(
             order_repository.clone(),
+            payment_repository.clone(),
+            payment_gateway.clone(),
+        ));
+        let capture_payment_handler = Arc::new(CapturePaymentHandler::new(
+            order_repository.clone(),
+            payment_repository.clone(),
+            payment_gateway.clone(),
+        ));
+        let void_payment_handler = Arc::new(VoidPaymentHandler::new(
+            order_repository.clone(),
+            payment_repository.clone(),
+            payment_gateway.clone(),
+        ));
+        let get_payments_handler = Arc::new(GetPaymentsHandler::new(
+            order_repository.clone(),
+            payment_repository.clone(),
+        ));
+
+        // 返品（RMA）
+        let request_return_handler = Arc::new(
+            RequestReturnHandler::new(
+                order_repository.clone(),
+                return_repository.clone(),
+                tax_policy,
+            )
+            .with_restocking_fee_rate(return_restocking_fee_rate()),
+        );
+        let approve_return_handler = Arc::new(ApproveReturnHandler::new(return_repository.clone()));
+        let reject_return_handler = Arc::new(RejectReturnHandler::new(return_repository.clone()));
+        let receive_return_handler = Arc::new(ReceiveReturnHandler::new(return_repository.clone()));
+        let refund_return_handler = Arc::new(RefundReturnHandler::new(
+            return_repository.clone(),
+            payment_repository.clone(),
+            payment_gateway.clone(),
+        ));
+        let get_return_handler = Arc::new(GetReturnHandler::new(return_repository.clone()));
+        let get_order_returns_handler = Arc::new(GetOrderReturnsHandler::new(
+            order_repository.clone(),
+            return_repository,
+        ));
+
+        // 請求書・領収書
+        let issue_order_document_handler = Arc::new(IssueOrderDocumentHandler::new(
+            order_repository.clone(),
+            Arc::new(OrderDocumentRenderer::new()),
+            document_issuer(),
+            tax_policy,
+        ));
+
+        // 支払い方法の管理
+        let update_payment_fee_rule_handler = Arc::new(UpdatePaymentFeeRuleHandler::new(
+            payment_method_repository.clone(),
+        ));
+
+        // クーポンの管理
+        let create_coupon_handler = Arc::new(CreateCouponHandler::new(coupon_repository.clone()));
+        let update_coupon_handler = Arc::new(UpdateCouponHandler::new(coupon_repository.clone()));
+        let delete_coupon_handler = Arc::new(DeleteCouponHandler::new(coupon_repository.clone()));
+        let generate_coupon_codes_handler =
+            Arc::new(GenerateCouponCodesHandler::new(coupon_repository.clone()));
+        let get_coupon_list_handler =
+            Arc::new(GetCouponListHandler::new(coupon_repository.clone()));
+        let get_coupon_handler = Arc::new(GetCouponHandler::new(coupon_repository.clone()));
+
+        // 会員・ログインセッション
+        let customer_repository = Arc::new(SqliteCustomerRepository::new(pool.clone()));
+        let password_hasher: Arc<dyn PasswordHasher> = Arc::new(Argon2PasswordHasher::new());
+        let token_signer: Arc<dyn SessionTokenSigner> = Arc::new(session_token_signer());
+        let register_customer_handler = Arc::new(RegisterCustomerHandler::new(
+            customer_repository.clone(),
+            password_hasher.clone(),
+            token_signer.clone(),
+            session_ttl(),
+        ));
+        let login_customer_handler = Arc::new(LoginCustomerHandler::new(
+            customer_repository.clone(),
+            password_hasher,
+            token_signer.clone(),
+            session_ttl(),
+        ));
+        let logout_customer_handler = Arc::new(LogoutCustomerHandler::new(
+            customer_repository.clone(),
+            token_signer.clone(),
+        ));
+        let authenticate_customer_handler = Arc::new(AuthenticateCustomerHandler::new(
+            customer_repository,
+            token_signer,
         ));
 
         // ディスパッチャを作成
         let dispatcher = Arc::new(Dispatcher::new(
-            calculate_cart_handler,
-            create_order_handler,
-            get_product_handler,
-            get_product_list_handler,
-            get_category_list_handler,
-            get_color_list_handler,
-            find_variants_handler,
-            get_shipping_method_list_handler,
-            get_payment_method_list_handler,
+            CatalogHandlers {
+                get_product_handler,
+                get_product_list_handler,
+                get_category_list_handler,
+                get_color_list_handler,
+                find_variants_handler,
+                search_products_handler,
+                get_shipping_method_list_handler,
+                get_payment_method_list_handler,
+            },
+            CartHandlers {
+                calculate_cart_handler,
+                create_cart_handler,
+                add_cart_item_handler,
+                update_cart_item_handler,
+                remove_cart_item_handler,
+                set_cart_coupon_handler,
+                set_cart_shipping_method_handler,
+                set_cart_payment_method_handler,
+                get_cart_handler,
+            },
+            OrderHandlers {
+                create_order_handler,
+                amend_order_handler,
+                get_order_handler,
+                list_orders_handler,
+                get_order_events_handler,
+                mark_order_paid_handler,
+                process_order_handler,
+                ship_order_handler,
+                deliver_order_handler,
+                cancel_order_handler,
+                add_order_note_handler,
+                refund_order_handler,
+                issue_order_document_handler,
+            },
+            PaymentHandlers {
+                authorize_payment_handler,
+                capture_payment_handler,
+                void_payment_handler,
+                get_payments_handler,
+            },
+            FulfillmentHandlers {
+                attach_shipment_handler,
+                record_shipment_event_handler,
+                get_shipment_handler,
+                request_return_handler,
+                approve_return_handler,
+                reject_return_handler,
+                receive_return_handler,
+                refund_return_handler,
+                get_return_handler,
+                get_order_returns_handler,
+            },
+            AdminHandlers {
+                create_product_handler,
+                update_product_handler,
+                delete_product_handler,
+                publish_product_handler,
+                unpublish_product_handler,
+                add_product_sku_handler,
+                update_product_sku_handler,
+                remove_product_sku_handler,
+                schedule_sku_sale_handler,
+                get_admin_product_handler,
+                adjust_stock_handler,
+                get_stock_history_handler,
+                update_payment_fee_rule_handler,
+                create_coupon_handler,
+                update_coupon_handler,
+                delete_coupon_handler,
+                generate_coupon_codes_handler,
+                get_coupon_list_handler,
+                get_coupon_handler,
+            },
+            CustomerHandlers {
+                register_customer_handler,
+                login_customer_handler,
+                logout_customer_handler,
+                authenticate_customer_handler,
+            },
+        ));
+
+        let idempotency_service = Arc::new(IdempotencyService::new(
+            Arc::new(SqliteIdempotencyKeyRepository::new(pool.clone())),
+            idempotency_key_ttl(),
         ));
 
         Ok(Self {
@@ -121,6 +639,14 @@ impl Container {
             shipping_method_repository,
             payment_method_repository,
             order_repository,
+            cart_repository,
+            product_write_repository,
+            stock_movement_repository,
+            delivery_info_repository,
+            payment_repository,
+            payment_gateway,
+            idempotency_service,
+            admin_api_token: admin_api_token(),
             dispatcher,
         })
     }
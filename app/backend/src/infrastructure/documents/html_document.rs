@@ -0,0 +1,177 @@
+use std::fmt::Write as _;
+
+use crate::application::dto::OrderDocumentDTO;
+
+use super::order_document_renderer::{
+    format_date, format_yen, issuer_lines, item_label, reissue_mark, tax_line, title, total_lines,
+};
+
+const STYLE: &str = r#"
+body { font-family: "Hiragino Kaku Gothic ProN", "Noto Sans JP", "Yu Gothic", sans-serif; color: #222; margin: 0; }
+.page { width: 180mm; margin: 10mm auto; }
+h1 { text-align: center; font-size: 24px; letter-spacing: 0.5em; margin: 0 0 4px; }
+.reissue { text-align: center; color: #c00; font-size: 12px; margin: 0 0 12px; }
+.header { display: flex; justify-content: space-between; align-items: flex-start; margin: 16px 0; }
+.addressee { font-size: 18px; border-bottom: 1px solid #222; padding-bottom: 4px; min-width: 80mm; }
+.meta, .issuer { font-size: 12px; text-align: right; line-height: 1.6; }
+.issuer .name { font-size: 14px; font-weight: bold; }
+.amount { font-size: 22px; text-align: center; border: 2px solid #222; padding: 8px; margin: 16px 0 8px; }
+.proviso { font-size: 13px; text-align: center; margin: 4px 0 16px; }
+table { width: 100%; border-collapse: collapse; font-size: 12px; margin: 12px 0; }
+th, td { border: 1px solid #888; padding: 4px 6px; }
+th { background: #eee; }
+td.num { text-align: right; white-space: nowrap; }
+.totals { width: 60%; margin-left: auto; }
+.totals td:first-child { background: #f6f6f6; }
+.note, .shipping { font-size: 12px; line-height: 1.6; }
+@media print { .page { margin: 0 auto; } }
+"#;
+
+/// HTMLの特殊文字をエスケープ
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub(super) fn render(document: &OrderDocumentDTO) -> String {
+    let title = title(document);
+    let mut html = String::new();
+
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"ja\">\n<head>\n<meta charset=\"utf-8\">\n<title>{} {}</title>\n<style>{}</style>\n</head>\n<body>\n<div class=\"page\">\n<h1>{}</h1>\n",
+        title,
+        escape(&document.document_number),
+        STYLE,
+        title
+    );
+    if let Some(mark) = reissue_mark(document) {
+        let _ = writeln!(html, "<p class=\"reissue\">{}</p>", mark);
+    }
+
+    // 宛名と帳票番号・発行日
+    let _ = write!(
+        html,
+        "<div class=\"header\">\n<div class=\"addressee\">{}</div>\n<div class=\"meta\">No. {}<br>発行日: {}<br>注文番号: {}<br>注文日: {}",
+        escape(&document.addressee),
+        escape(&document.document_number),
+        format_date(document.issued_at),
+        escape(&document.order_number),
+        format_date(document.ordered_at)
+    );
+    if let Some(paid_at) = document.paid_at {
+        let _ = write!(html, "<br>お支払日: {}", format_date(paid_at));
+    }
+    html.push_str("</div>\n</div>\n");
+
+    if document.is_receipt() {
+        let _ = writeln!(
+            html,
+            "<div class=\"amount\">{}-</div>\n<p class=\"proviso\">但し お品代として<br>上記正に領収いたしました</p>",
+            format_yen(document.total)
+        );
+    } else {
+        let _ = writeln!(
+            html,
+            "<p>下記の通りご請求申し上げます。</p>\n<div class=\"amount\">ご請求金額 {}</div>",
+            format_yen(document.total)
+        );
+        write_items(&mut html, document);
+    }
+
+    // 合計・税率ごとの内訳
+    html.push_str("<table class=\"totals\">\n");
+    for (label, amount) in total_lines(document) {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td class=\"num\">{}</td></tr>",
+            label, amount
+        );
+    }
+    for tax in &document.tax_breakdown {
+        let _ = writeln!(html, "<tr><td colspan=\"2\">{}</td></tr>", tax_line(tax));
+    }
+    html.push_str("</table>\n");
+    if document.items.iter().any(|item| item.reduced_rate) {
+        html.push_str("<p class=\"note\">※は軽減税率対象商品です</p>\n");
+    }
+
+    let _ = writeln!(
+        html,
+        "<div class=\"shipping\">お届け先: 〒{} {}（{} 様）<br>配送方法: {}<br>お支払方法: {}</div>",
+        escape(&document.shipping_postal_code),
+        escape(&document.shipping_address),
+        escape(&document.customer_name),
+        escape(&document.shipping_method_name),
+        escape(&document.payment_method_name)
+    );
+
+    let _ = write!(
+        html,
+        "<div class=\"issuer\"><div class=\"name\">{}</div>",
+        escape(&document.issuer_name)
+    );
+    for line in issuer_lines(document) {
+        let _ = write!(html, "{}<br>", escape(&line));
+    }
+    html.push_str("</div>\n</div>\n</body>\n</html>\n");
+    html
+}
+
+fn write_items(html: &mut String, document: &OrderDocumentDTO) {
+    html.push_str(
+        "<table>\n<tr><th>商品名</th><th>SKU</th><th>単価</th><th>数量</th><th>税率</th><th>金額</th></tr>\n",
+    );
+    for item in &document.items {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{}%</td><td class=\"num\">{}</td></tr>",
+            escape(&item_label(item)),
+            escape(&item.sku_code),
+            format_yen(item.unit_price),
+            item.quantity,
+            item.tax_rate,
+            format_yen(item.amount)
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::documents::order_document_renderer::tests::sample_document;
+
+    #[test]
+    fn test_invoice_lists_items_with_tax_breakdown() {
+        let html = render(&sample_document("invoice", 1));
+
+        assert!(html.contains("<h1>適格請求書</h1>"));
+        assert!(html.contains("登録番号: T1234567890123"));
+        assert!(html.contains("コーヒー豆 &lt;深煎り&gt;（200g） ※"));
+        assert!(html.contains("8%対象（軽減税率） ¥2,000（税抜） 消費税 ¥160"));
+        assert!(html.contains("※は軽減税率対象商品です"));
+        assert!(!html.contains("再発行"));
+    }
+
+    #[test]
+    fn test_receipt_shows_amount_and_reissue_mark() {
+        let html = render(&sample_document("receipt", 3));
+
+        assert!(html.contains("<h1>領収書</h1>"));
+        assert!(html.contains("（再発行 3回目）"));
+        assert!(html.contains("¥2,660-"));
+        assert!(html.contains("上記正に領収いたしました"));
+        assert!(!html.contains("<th>商品名</th>"));
+    }
+}
@@ -0,0 +1,6 @@
+mod html_document;
+mod order_document_renderer;
+mod pdf_document;
+mod pdf_writer;
+
+pub use self::order_document_renderer::OrderDocumentRenderer;
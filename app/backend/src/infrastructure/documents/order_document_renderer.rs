@@ -0,0 +1,231 @@
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::application::documents::DocumentRenderer;
+use crate::application::dto::{OrderDocumentDTO, OrderDocumentItemDTO, OrderDocumentTaxDTO};
+
+use super::{html_document, pdf_document};
+
+/// 請求書・領収書の描画（HTMLは印刷用スタイル付き、PDFはA4縦1ページ〜）
+/// 日付は日本時間で表示する
+pub struct OrderDocumentRenderer;
+
+impl OrderDocumentRenderer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for OrderDocumentRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DocumentRenderer for OrderDocumentRenderer {
+    fn render_html(&self, document: &OrderDocumentDTO) -> String {
+        html_document::render(document)
+    }
+
+    fn render_pdf(&self, document: &OrderDocumentDTO) -> Vec<u8> {
+        pdf_document::render(document)
+    }
+}
+
+/// 帳票のタイトル（登録番号があれば適格請求書として発行する）
+pub(super) fn title(document: &OrderDocumentDTO) -> &'static str {
+    if document.is_receipt() {
+        "領収書"
+    } else if document.issuer_registration_number.is_some() {
+        "適格請求書"
+    } else {
+        "請求書"
+    }
+}
+
+/// 再発行の表示（初回発行は表示しない）
+pub(super) fn reissue_mark(document: &OrderDocumentDTO) -> Option<String> {
+    document
+        .is_reissue()
+        .then(|| format!("（再発行 {}回目）", document.issue_number))
+}
+
+/// 日本時間の日付（2024年4月1日）
+pub(super) fn format_date(value: DateTime<Utc>) -> String {
+    let jst = FixedOffset::east_opt(9 * 3600).expect("valid offset");
+    value
+        .with_timezone(&jst)
+        .format("%Y年%-m月%-d日")
+        .to_string()
+}
+
+/// 3桁区切りの金額（¥12,345）
+pub(super) fn format_yen(amount: u32) -> String {
+    let digits = amount.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    format!("¥{}", grouped)
+}
+
+/// 税率ごとの内訳の1行（10%対象 ¥1,000（税抜） 消費税 ¥100）
+pub(super) fn tax_line(tax: &OrderDocumentTaxDTO) -> String {
+    format!(
+        "{}%対象{} {}（税抜） 消費税 {}",
+        tax.rate,
+        if tax.reduced_rate {
+            "（軽減税率）"
+        } else {
+            ""
+        },
+        format_yen(tax.taxable_amount),
+        format_yen(tax.tax_amount)
+    )
+}
+
+/// 明細の商品名（SKU名・軽減税率の印を含む）
+pub(super) fn item_label(item: &OrderDocumentItemDTO) -> String {
+    format!(
+        "{}{}{}",
+        item.product_name,
+        if item.sku_name.is_empty() || item.sku_name == item.product_name {
+            String::new()
+        } else {
+            format!("（{}）", item.sku_name)
+        },
+        if item.reduced_rate { " ※" } else { "" }
+    )
+}
+
+/// 発行者の連絡先の行（住所・電話番号・登録番号）
+pub(super) fn issuer_lines(document: &OrderDocumentDTO) -> Vec<String> {
+    let mut lines = Vec::new();
+    if let Some(address) = &document.issuer_address {
+        lines.push(address.clone());
+    }
+    if let Some(phone) = &document.issuer_phone {
+        lines.push(format!("TEL: {}", phone));
+    }
+    if let Some(number) = &document.issuer_registration_number {
+        lines.push(format!("登録番号: {}", number));
+    }
+    lines
+}
+
+/// 合計欄の行（ラベル、金額）。割引・手数料は発生したものだけ表示する
+pub(super) fn total_lines(document: &OrderDocumentDTO) -> Vec<(String, String)> {
+    let mut lines = vec![("小計".to_string(), format_yen(document.subtotal))];
+    if document.discount > 0 {
+        lines.push((
+            "割引".to_string(),
+            format!("-{}", format_yen(document.discount)),
+        ));
+    }
+    lines.push(("送料".to_string(), format_yen(document.shipping_fee)));
+    if document.payment_fee > 0 {
+        lines.push(("決済手数料".to_string(), format_yen(document.payment_fee)));
+    }
+    lines.push((
+        if document.tax_included {
+            "（内消費税）".to_string()
+        } else {
+            "消費税".to_string()
+        },
+        format_yen(document.tax_amount),
+    ));
+    lines.push(("合計".to_string(), format_yen(document.total)));
+    lines
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    pub(in crate::infrastructure::documents) fn sample_document(
+        kind: &str,
+        issue_number: u32,
+    ) -> OrderDocumentDTO {
+        OrderDocumentDTO {
+            kind: kind.to_string(),
+            document_number: "INV-ORD-000001".to_string(),
+            issue_number,
+            issued_at: Utc.with_ymd_and_hms(2024, 3, 31, 16, 0, 0).unwrap(),
+            addressee: "山田 太郎 様".to_string(),
+            issuer_name: "ECサイト".to_string(),
+            issuer_registration_number: Some("T1234567890123".to_string()),
+            issuer_address: Some("東京都千代田区1-1".to_string()),
+            issuer_phone: None,
+            order_number: "ORD-000001".to_string(),
+            ordered_at: Utc.with_ymd_and_hms(2024, 3, 30, 1, 0, 0).unwrap(),
+            paid_at: Some(Utc.with_ymd_and_hms(2024, 3, 30, 2, 0, 0).unwrap()),
+            customer_name: "山田 太郎".to_string(),
+            shipping_postal_code: "100-0001".to_string(),
+            shipping_address: "東京都 千代田区 千代田1-1".to_string(),
+            shipping_method_name: "通常配送".to_string(),
+            payment_method_name: "クレジットカード".to_string(),
+            items: vec![OrderDocumentItemDTO {
+                product_name: "コーヒー豆 <深煎り>".to_string(),
+                sku_name: "200g".to_string(),
+                sku_code: "COFFEE-200".to_string(),
+                quantity: 2,
+                unit_price: 1080,
+                amount: 2160,
+                tax_rate: 8,
+                reduced_rate: true,
+            }],
+            subtotal: 2160,
+            discount: 0,
+            shipping_fee: 500,
+            payment_fee: 0,
+            tax_amount: 205,
+            total: 2660,
+            tax_included: true,
+            tax_breakdown: vec![
+                OrderDocumentTaxDTO {
+                    rate: 8,
+                    taxable_amount: 2000,
+                    tax_amount: 160,
+                    reduced_rate: true,
+                },
+                OrderDocumentTaxDTO {
+                    rate: 10,
+                    taxable_amount: 455,
+                    tax_amount: 45,
+                    reduced_rate: false,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_format_yen_groups_thousands() {
+        assert_eq!(format_yen(0), "¥0");
+        assert_eq!(format_yen(999), "¥999");
+        assert_eq!(format_yen(1000), "¥1,000");
+        assert_eq!(format_yen(1234567), "¥1,234,567");
+    }
+
+    #[test]
+    fn test_format_date_uses_japan_time() {
+        let document = sample_document("invoice", 1);
+        assert_eq!(format_date(document.issued_at), "2024年4月1日");
+    }
+
+    #[test]
+    fn test_title_depends_on_kind_and_registration_number() {
+        let mut document = sample_document("invoice", 1);
+        assert_eq!(title(&document), "適格請求書");
+        assert_eq!(reissue_mark(&document), None);
+
+        document.issuer_registration_number = None;
+        assert_eq!(title(&document), "請求書");
+
+        let receipt = sample_document("receipt", 2);
+        assert_eq!(title(&receipt), "領収書");
+        assert_eq!(reissue_mark(&receipt).as_deref(), Some("（再発行 2回目）"));
+    }
+}
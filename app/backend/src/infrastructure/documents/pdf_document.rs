@@ -0,0 +1,212 @@
+use crate::application::dto::OrderDocumentDTO;
+
+use super::order_document_renderer::{
+    format_date, format_yen, issuer_lines, item_label, reissue_mark, tax_line, title, total_lines,
+};
+use super::pdf_writer::{A4_HEIGHT, A4_WIDTH, PdfWriter};
+
+const MARGIN: f32 = 50.0;
+const RIGHT: f32 = A4_WIDTH - MARGIN;
+const BOTTOM: f32 = A4_HEIGHT - 60.0;
+const ROW_HEIGHT: f32 = 18.0;
+/// 明細表の列（商品名, 単価, 数量, 税率, 金額）の右端
+const COLUMNS: [f32; 5] = [MARGIN, 370.0, 415.0, 460.0, RIGHT];
+/// 商品名の列に収める最大幅
+const NAME_WIDTH: f32 = 250.0;
+
+pub(super) fn render(document: &OrderDocumentDTO) -> Vec<u8> {
+    let title = title(document);
+    let mut pdf = PdfWriter::new(&format!("{} {}", title, document.document_number));
+
+    pdf.text_center(A4_WIDTH / 2.0, 80.0, 22.0, title);
+    if let Some(mark) = reissue_mark(document) {
+        pdf.text_center(A4_WIDTH / 2.0, 100.0, 10.0, &mark);
+    }
+
+    // 宛名（左）と帳票番号・日付（右）
+    pdf.text(MARGIN, 140.0, 14.0, &document.addressee);
+    pdf.line(MARGIN, 146.0, 300.0, 146.0, 0.8);
+    let mut meta = vec![
+        format!("No. {}", document.document_number),
+        format!("発行日: {}", format_date(document.issued_at)),
+        format!("注文番号: {}", document.order_number),
+        format!("注文日: {}", format_date(document.ordered_at)),
+    ];
+    if let Some(paid_at) = document.paid_at {
+        meta.push(format!("お支払日: {}", format_date(paid_at)));
+    }
+    let mut y = 125.0;
+    for line in &meta {
+        pdf.text_right(RIGHT, y, 9.0, line);
+        y += 13.0;
+    }
+
+    // 発行者
+    y += 8.0;
+    pdf.text_right(RIGHT, y, 11.0, &document.issuer_name);
+    for line in issuer_lines(document) {
+        y += 13.0;
+        pdf.text_right(RIGHT, y, 9.0, &line);
+    }
+    y += 30.0;
+
+    if document.is_receipt() {
+        let amount = format!("{}-", format_yen(document.total));
+        pdf.fill_rect(MARGIN + 80.0, y - 24.0, RIGHT - MARGIN - 160.0, 34.0, 0.92);
+        pdf.text_center(A4_WIDTH / 2.0, y, 20.0, &amount);
+        y += 30.0;
+        pdf.text_center(A4_WIDTH / 2.0, y, 10.0, "但し お品代として");
+        y += 15.0;
+        pdf.text_center(A4_WIDTH / 2.0, y, 10.0, "上記正に領収いたしました");
+        y += 30.0;
+    } else {
+        pdf.text(MARGIN, y, 10.0, "下記の通りご請求申し上げます。");
+        y += 24.0;
+        pdf.text(
+            MARGIN,
+            y,
+            14.0,
+            &format!("ご請求金額  {}", format_yen(document.total)),
+        );
+        pdf.line(MARGIN, y + 6.0, 300.0, y + 6.0, 0.8);
+        y += 30.0;
+        y = write_items(&mut pdf, document, y);
+    }
+
+    // 合計・税率ごとの内訳
+    let lines = total_lines(document);
+    let needed = (lines.len() + document.tax_breakdown.len() + 6) as f32 * ROW_HEIGHT;
+    if y + needed > BOTTOM {
+        pdf.new_page();
+        y = 80.0;
+    }
+    for (label, amount) in lines {
+        pdf.text(330.0, y, 10.0, &label);
+        pdf.text_right(RIGHT, y, 10.0, &amount);
+        pdf.line(330.0, y + 5.0, RIGHT, y + 5.0, 0.3);
+        y += ROW_HEIGHT;
+    }
+    y += 6.0;
+    for tax in &document.tax_breakdown {
+        pdf.text_right(RIGHT, y, 9.0, &tax_line(tax));
+        y += 14.0;
+    }
+    if document.items.iter().any(|item| item.reduced_rate) {
+        pdf.text(MARGIN, y, 9.0, "※は軽減税率対象商品です");
+        y += 14.0;
+    }
+
+    y += 16.0;
+    pdf.text(
+        MARGIN,
+        y,
+        9.0,
+        &format!(
+            "お届け先: 〒{} {}（{} 様）",
+            document.shipping_postal_code, document.shipping_address, document.customer_name
+        ),
+    );
+    pdf.text(
+        MARGIN,
+        y + 14.0,
+        9.0,
+        &format!("配送方法: {}", document.shipping_method_name),
+    );
+    pdf.text(
+        MARGIN,
+        y + 28.0,
+        9.0,
+        &format!("お支払方法: {}", document.payment_method_name),
+    );
+
+    pdf.finish()
+}
+
+/// 明細表を描画し、表の下端のy座標を返す（ページに収まらない行は次ページへ送る）
+fn write_items(pdf: &mut PdfWriter, document: &OrderDocumentDTO, mut y: f32) -> f32 {
+    y = write_item_header(pdf, y);
+    for item in &document.items {
+        if y + ROW_HEIGHT > BOTTOM {
+            pdf.new_page();
+            y = write_item_header(pdf, 80.0);
+        }
+        let label = truncate(&item_label(item), NAME_WIDTH, 9.0);
+        pdf.text(COLUMNS[0] + 4.0, y, 9.0, &label);
+        pdf.text_right(COLUMNS[1] - 4.0, y, 9.0, &format_yen(item.unit_price));
+        pdf.text_right(COLUMNS[2] - 4.0, y, 9.0, &item.quantity.to_string());
+        pdf.text_right(COLUMNS[3] - 4.0, y, 9.0, &format!("{}%", item.tax_rate));
+        pdf.text_right(COLUMNS[4] - 4.0, y, 9.0, &format_yen(item.amount));
+        pdf.line(MARGIN, y + 6.0, RIGHT, y + 6.0, 0.3);
+        y += ROW_HEIGHT;
+    }
+    y + 12.0
+}
+
+fn write_item_header(pdf: &mut PdfWriter, y: f32) -> f32 {
+    pdf.fill_rect(MARGIN, y - 13.0, RIGHT - MARGIN, ROW_HEIGHT, 0.9);
+    pdf.text(COLUMNS[0] + 4.0, y, 9.0, "商品名");
+    pdf.text_right(COLUMNS[1] - 4.0, y, 9.0, "単価");
+    pdf.text_right(COLUMNS[2] - 4.0, y, 9.0, "数量");
+    pdf.text_right(COLUMNS[3] - 4.0, y, 9.0, "税率");
+    pdf.text_right(COLUMNS[4] - 4.0, y, 9.0, "金額");
+    pdf.line(MARGIN, y + 5.0, RIGHT, y + 5.0, 0.6);
+    y + ROW_HEIGHT
+}
+
+/// 列幅に収まらない文字列を省略する
+fn truncate(text: &str, max_width: f32, size: f32) -> String {
+    if PdfWriter::text_width(text, size) <= max_width {
+        return text.to_string();
+    }
+    let mut truncated = String::new();
+    for c in text.chars() {
+        let candidate = format!("{}{}…", truncated, c);
+        if PdfWriter::text_width(&candidate, size) > max_width {
+            break;
+        }
+        truncated.push(c);
+    }
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::documents::order_document_renderer::tests::sample_document;
+
+    fn hex(text: &str) -> String {
+        text.encode_utf16()
+            .map(|unit| format!("{:04X}", unit))
+            .collect()
+    }
+
+    #[test]
+    fn test_receipt_pdf_contains_title_and_amount() {
+        let pdf = render(&sample_document("receipt", 2));
+        let text = String::from_utf8_lossy(&pdf).to_string();
+
+        assert!(text.starts_with("%PDF-1.4"));
+        assert!(text.contains(&hex("領収書")));
+        assert!(text.contains(&hex("¥2,660-")));
+        assert!(text.contains(&hex("（再発行 2回目）")));
+    }
+
+    #[test]
+    fn test_invoice_items_continue_on_next_page() {
+        let mut document = sample_document("invoice", 1);
+        let item = document.items[0].clone();
+        document.items = vec![item; 60];
+
+        let text = String::from_utf8_lossy(&render(&document)).to_string();
+        assert!(text.contains("/Count 2") || text.contains("/Count 3"));
+    }
+
+    #[test]
+    fn test_truncate_long_names() {
+        let name = "あ".repeat(40);
+        let truncated = truncate(&name, 100.0, 10.0);
+        assert!(PdfWriter::text_width(&truncated, 10.0) <= 100.0);
+        assert!(truncated.ends_with('…'));
+    }
+}
@@ -0,0 +1,229 @@
+use std::fmt::Write as _;
+
+/// A4用紙の幅（pt）
+pub const A4_WIDTH: f32 = 595.28;
+/// A4用紙の高さ（pt）
+pub const A4_HEIGHT: f32 = 841.89;
+
+/// 日本語の表示に使うフォント（PDFに埋め込まず、閲覧環境の明朝/ゴシック体で代替表示される）
+const FONT_NAME: &str = "HeiseiKakuGo-W5";
+
+/// 帳票用の最小限のPDF生成
+/// テキスト・罫線・塗りつぶしのみを扱い、座標は用紙の左上を原点とする（y は下向き）
+/// 文字幅は半角0.5em・全角1emで固定し、右寄せ・中央寄せの計算に使う
+pub struct PdfWriter {
+    title: String,
+    pages: Vec<String>,
+}
+
+impl PdfWriter {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            pages: vec![String::new()],
+        }
+    }
+
+    /// 改ページ
+    pub fn new_page(&mut self) {
+        self.pages.push(String::new());
+    }
+
+    /// 文字列の表示幅（pt）
+    pub fn text_width(text: &str, size: f32) -> f32 {
+        text.chars()
+            .map(|c| if c.is_ascii() { 0.5 } else { 1.0 })
+            .sum::<f32>()
+            * size
+    }
+
+    /// 左端を指定して文字列を書く（y はベースライン）
+    pub fn text(&mut self, x: f32, y: f32, size: f32, text: &str) {
+        let encoded: String = text
+            .encode_utf16()
+            .map(|unit| format!("{:04X}", unit))
+            .collect();
+        let page = self.current_page();
+        let _ = writeln!(
+            page,
+            "BT /F1 {:.2} Tf {:.2} {:.2} Td <{}> Tj ET",
+            size,
+            x,
+            A4_HEIGHT - y,
+            encoded
+        );
+    }
+
+    /// 右端を指定して文字列を書く
+    pub fn text_right(&mut self, right: f32, y: f32, size: f32, text: &str) {
+        self.text(right - Self::text_width(text, size), y, size, text);
+    }
+
+    /// 中央を指定して文字列を書く
+    pub fn text_center(&mut self, center: f32, y: f32, size: f32, text: &str) {
+        self.text(center - Self::text_width(text, size) / 2.0, y, size, text);
+    }
+
+    /// 罫線を引く
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, width: f32) {
+        let page = self.current_page();
+        let _ = writeln!(
+            page,
+            "{:.2} w {:.2} {:.2} m {:.2} {:.2} l S",
+            width,
+            x1,
+            A4_HEIGHT - y1,
+            x2,
+            A4_HEIGHT - y2
+        );
+    }
+
+    /// 矩形を灰色（0: 黒〜1: 白）で塗りつぶす（y は矩形の上端）
+    pub fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32, gray: f32) {
+        let page = self.current_page();
+        let _ = writeln!(
+            page,
+            "{:.2} g {:.2} {:.2} {:.2} {:.2} re f 0 g",
+            gray,
+            x,
+            A4_HEIGHT - y - height,
+            width,
+            height
+        );
+    }
+
+    /// PDFのバイト列を出力
+    pub fn finish(self) -> Vec<u8> {
+        // オブジェクト番号: 1 カタログ, 2 ページツリー, 3〜5 フォント, 6 文書情報, 7以降 ページと内容
+        let page_count = self.pages.len();
+        let page_ids: Vec<usize> = (0..page_count).map(|i| 7 + i * 2).collect();
+        let kids = page_ids
+            .iter()
+            .map(|id| format!("{} 0 R", id))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut objects: Vec<Vec<u8>> = vec![
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids, page_count).into_bytes(),
+            format!(
+                "<< /Type /Font /Subtype /Type0 /BaseFont /{} /Encoding /UniJIS-UTF16-H /DescendantFonts [4 0 R] >>",
+                FONT_NAME
+            )
+            .into_bytes(),
+            format!(
+                "<< /Type /Font /Subtype /CIDFontType0 /BaseFont /{} /CIDSystemInfo << /Registry (Adobe) /Ordering (Japan1) /Supplement 5 >> /FontDescriptor 5 0 R /DW 1000 /W [1 95 500 231 632 500] >>",
+                FONT_NAME
+            )
+            .into_bytes(),
+            format!(
+                "<< /Type /FontDescriptor /FontName /{} /Flags 4 /FontBBox [-92 -250 1010 922] /ItalicAngle 0 /Ascent 880 /Descent -120 /CapHeight 737 /StemV 114 >>",
+                FONT_NAME
+            )
+            .into_bytes(),
+            format!(
+                "<< /Title <FEFF{}> /Producer (ec-rust-backend) >>",
+                self.title
+                    .encode_utf16()
+                    .map(|unit| format!("{:04X}", unit))
+                    .collect::<String>()
+            )
+            .into_bytes(),
+        ];
+
+        for (index, content) in self.pages.iter().enumerate() {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {:.2} {:.2}] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                    A4_WIDTH,
+                    A4_HEIGHT,
+                    page_ids[index] + 1
+                )
+                .into_bytes(),
+            );
+            let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+            stream.extend_from_slice(content.as_bytes());
+            stream.extend_from_slice(b"endstream");
+            objects.push(stream);
+        }
+
+        let mut output = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(output.len());
+            output.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+            output.extend_from_slice(object);
+            output.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = output.len();
+        let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(xref, "{:010} 00000 n ", offset);
+        }
+        let _ = write!(
+            xref,
+            "trailer\n<< /Size {} /Root 1 0 R /Info 6 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref_offset
+        );
+        output.extend_from_slice(xref.as_bytes());
+        output
+    }
+
+    fn current_page(&mut self) -> &mut String {
+        self.pages.last_mut().expect("PdfWriter always has a page")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_is_encoded_as_utf16() {
+        let mut writer = PdfWriter::new("領収書");
+        writer.text(10.0, 20.0, 12.0, "領収書 A");
+        let pdf = String::from_utf8_lossy(&writer.finish()).to_string();
+
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert!(pdf.contains("<981853CE66F800200041> Tj"));
+        assert!(pdf.ends_with("%%EOF\n"));
+    }
+
+    #[test]
+    fn test_xref_offsets_point_to_objects() {
+        let mut writer = PdfWriter::new("請求書");
+        writer.text(10.0, 20.0, 12.0, "1ページ目");
+        writer.new_page();
+        writer.text(10.0, 20.0, 12.0, "2ページ目");
+        let pdf = writer.finish();
+        let text = String::from_utf8_lossy(&pdf).to_string();
+
+        assert!(text.contains("/Count 2"));
+        let start: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|rest| rest.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .unwrap();
+        assert!(pdf[start..].starts_with(b"xref"));
+
+        let entries: Vec<usize> = String::from_utf8_lossy(&pdf[start..])
+            .lines()
+            .skip(3)
+            .take_while(|line| line.ends_with(" n "))
+            .map(|line| line[..10].parse().unwrap())
+            .collect();
+        assert_eq!(entries.len(), 10);
+        for (index, offset) in entries.into_iter().enumerate() {
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj", index + 1).as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_text_width_counts_full_width_characters_as_one_em() {
+        assert_eq!(PdfWriter::text_width("AB", 10.0), 10.0);
+        assert_eq!(PdfWriter::text_width("領収", 10.0), 20.0);
+    }
+}
@@ -3,6 +3,9 @@
 /// Web frameworks, databases, external APIs, dependency injection など
 pub mod database;
 pub mod di;
+pub mod documents;
+pub mod payments;
+pub mod security;
 
 // メインモジュールからのexport
 pub use di::{Container, get_container};
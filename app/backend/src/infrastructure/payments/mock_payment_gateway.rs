@@ -0,0 +1,268 @@
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool};
+use uuid::Uuid;
+
+use crate::application::gateways::{
+    PaymentAuthorizationRequest, PaymentGateway, PaymentGatewayError, PaymentGatewayResponse,
+};
+use crate::domain::value_objects::Money;
+
+/// 拒否をシミュレートするカードトークン
+const DECLINED_TOKEN: &str = "tok_decline";
+/// 与信不足をシミュレートするカードトークン
+const INSUFFICIENT_FUNDS_TOKEN: &str = "tok_insufficient_funds";
+/// 決済代行会社の障害をシミュレートするカードトークン
+const UNAVAILABLE_TOKEN: &str = "tok_unavailable";
+
+/// ローカル開発用のモック決済代行会社
+/// 取引はSQLite（mock_payment_gateway_transactions）に保持し、
+/// 取引IDは連番（mock_txn_00000001 …）、結果はカードトークンと金額だけで決まる
+pub struct MockPaymentGateway {
+    pool: SqlitePool,
+}
+
+impl MockPaymentGateway {
+    pub const PROVIDER: &'static str = "mock";
+
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn unavailable(e: sqlx::Error) -> PaymentGatewayError {
+        PaymentGatewayError::Unavailable(format!("[MockPaymentGateway] {}", e))
+    }
+
+    /// 取引の状態と金額を取得（存在しない取引IDは不正な要求）
+    async fn find_transaction(
+        &self,
+        transaction_id: &str,
+    ) -> Result<MockTransaction, PaymentGatewayError> {
+        let row = sqlx::query(
+            r#"
+            SELECT status, authorized_amount, captured_amount, refunded_amount
+            FROM mock_payment_gateway_transactions
+            WHERE transaction_id = ?1
+            "#,
+        )
+        .bind(transaction_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(Self::unavailable)?
+        .ok_or_else(|| {
+            PaymentGatewayError::InvalidRequest(format!("Unknown transaction: {}", transaction_id))
+        })?;
+
+        Ok(MockTransaction {
+            status: row.get("status"),
+            authorized_amount: row.get("authorized_amount"),
+            captured_amount: row.get("captured_amount"),
+            refunded_amount: row.get("refunded_amount"),
+        })
+    }
+
+    async fn update_transaction(
+        &self,
+        transaction_id: &str,
+        status: &str,
+        captured_amount: i64,
+        refunded_amount: i64,
+    ) -> Result<(), PaymentGatewayError> {
+        sqlx::query(
+            r#"
+            UPDATE mock_payment_gateway_transactions
+            SET status = ?1, captured_amount = ?2, refunded_amount = ?3, updated_at = datetime('now')
+            WHERE transaction_id = ?4
+            "#,
+        )
+        .bind(status)
+        .bind(captured_amount)
+        .bind(refunded_amount)
+        .bind(transaction_id)
+        .execute(&self.pool)
+        .await
+        .map_err(Self::unavailable)?;
+
+        Ok(())
+    }
+}
+
+/// モック決済代行会社が保持する取引
+struct MockTransaction {
+    status: String,
+    authorized_amount: i64,
+    captured_amount: i64,
+    refunded_amount: i64,
+}
+
+#[async_trait]
+impl PaymentGateway for MockPaymentGateway {
+    fn provider(&self) -> &str {
+        Self::PROVIDER
+    }
+
+    async fn authorize(
+        &self,
+        request: &PaymentAuthorizationRequest,
+    ) -> Result<PaymentGatewayResponse, PaymentGatewayError> {
+        println!(
+            "->> MockPaymentGateway::authorize: order_number={}, amount={}",
+            request.order_number,
+            request.amount.yen()
+        );
+
+        if request.amount.is_zero() {
+            return Err(PaymentGatewayError::InvalidRequest(
+                "Authorization amount must be positive".to_string(),
+            ));
+        }
+
+        match request.payment_token.as_deref() {
+            Some(DECLINED_TOKEN) => {
+                return Ok(PaymentGatewayResponse::declined(None, "card_declined"));
+            }
+            Some(INSUFFICIENT_FUNDS_TOKEN) => {
+                return Ok(PaymentGatewayResponse::declined(None, "insufficient_funds"));
+            }
+            Some(UNAVAILABLE_TOKEN) => {
+                return Err(PaymentGatewayError::Unavailable(
+                    "Mock gateway is unavailable".to_string(),
+                ));
+            }
+            _ => {}
+        }
+
+        // 連番はAUTOINCREMENTで採番し、同じトランザクション内で連番から取引IDを決定する
+        // （取引IDはUNIQUEなので、採番までは衝突しない仮のIDを入れておく）
+        let mut tx = self.pool.begin().await.map_err(Self::unavailable)?;
+        let seq: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO mock_payment_gateway_transactions (
+                transaction_id, order_id, status, authorized_amount
+            )
+            VALUES (?1, ?2, 'authorized', ?3)
+            RETURNING seq
+            "#,
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(request.order_id.value().to_string())
+        .bind(request.amount.yen() as i64)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(Self::unavailable)?;
+        let transaction_id = format!("mock_txn_{:08}", seq);
+
+        sqlx::query(
+            "UPDATE mock_payment_gateway_transactions SET transaction_id = ?1 WHERE seq = ?2",
+        )
+        .bind(&transaction_id)
+        .bind(seq)
+        .execute(&mut *tx)
+        .await
+        .map_err(Self::unavailable)?;
+
+        tx.commit().await.map_err(Self::unavailable)?;
+
+        Ok(PaymentGatewayResponse::approved(transaction_id))
+    }
+
+    async fn capture(
+        &self,
+        transaction_id: &str,
+        amount: Money,
+    ) -> Result<PaymentGatewayResponse, PaymentGatewayError> {
+        println!(
+            "->> MockPaymentGateway::capture: transaction_id={}, amount={}",
+            transaction_id,
+            amount.yen()
+        );
+
+        let transaction = self.find_transaction(transaction_id).await?;
+        let amount = amount.yen() as i64;
+
+        if transaction.status != "authorized" {
+            return Ok(PaymentGatewayResponse::declined(
+                Some(transaction_id.to_string()),
+                "transaction_not_authorized",
+            ));
+        }
+        if amount > transaction.authorized_amount {
+            return Ok(PaymentGatewayResponse::declined(
+                Some(transaction_id.to_string()),
+                "amount_exceeds_authorization",
+            ));
+        }
+
+        self.update_transaction(transaction_id, "captured", amount, 0)
+            .await?;
+
+        Ok(PaymentGatewayResponse::approved(transaction_id.to_string()))
+    }
+
+    async fn void(
+        &self,
+        transaction_id: &str,
+    ) -> Result<PaymentGatewayResponse, PaymentGatewayError> {
+        println!(
+            "->> MockPaymentGateway::void: transaction_id={}",
+            transaction_id
+        );
+
+        let transaction = self.find_transaction(transaction_id).await?;
+
+        if transaction.status != "authorized" {
+            return Ok(PaymentGatewayResponse::declined(
+                Some(transaction_id.to_string()),
+                "transaction_not_authorized",
+            ));
+        }
+
+        self.update_transaction(transaction_id, "voided", 0, 0)
+            .await?;
+
+        Ok(PaymentGatewayResponse::approved(transaction_id.to_string()))
+    }
+
+    async fn refund(
+        &self,
+        transaction_id: &str,
+        amount: Money,
+    ) -> Result<PaymentGatewayResponse, PaymentGatewayError> {
+        println!(
+            "->> MockPaymentGateway::refund: transaction_id={}, amount={}",
+            transaction_id,
+            amount.yen()
+        );
+
+        let transaction = self.find_transaction(transaction_id).await?;
+        let refunded_amount = transaction.refunded_amount + amount.yen() as i64;
+
+        if transaction.status != "captured" {
+            return Ok(PaymentGatewayResponse::declined(
+                Some(transaction_id.to_string()),
+                "transaction_not_captured",
+            ));
+        }
+        if refunded_amount > transaction.captured_amount {
+            return Ok(PaymentGatewayResponse::declined(
+                Some(transaction_id.to_string()),
+                "amount_exceeds_capture",
+            ));
+        }
+
+        // 全額返金した取引だけを返金済みにする（一部返金は売上確定のまま）
+        let status = if refunded_amount == transaction.captured_amount {
+            "refunded"
+        } else {
+            "captured"
+        };
+        self.update_transaction(
+            transaction_id,
+            status,
+            transaction.captured_amount,
+            refunded_amount,
+        )
+        .await?;
+
+        Ok(PaymentGatewayResponse::approved(transaction_id.to_string()))
+    }
+}
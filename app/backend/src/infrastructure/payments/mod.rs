@@ -0,0 +1,3 @@
+mod mock_payment_gateway;
+
+pub use self::mock_payment_gateway::MockPaymentGateway;
@@ -0,0 +1,56 @@
+use argon2::Argon2;
+use argon2::password_hash::{PasswordHash, PasswordHasher as _, PasswordVerifier as _, SaltString};
+use uuid::Uuid;
+
+use crate::application::security::{PasswordHashError, PasswordHasher};
+
+/// Argon2id（既定パラメータ）によるパスワードハッシュ
+/// ハッシュ文字列はPHC形式（$argon2id$...）でソルトとパラメータを含む
+#[derive(Default)]
+pub struct Argon2PasswordHasher {
+    argon2: Argon2<'static>,
+}
+
+impl Argon2PasswordHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PasswordHasher for Argon2PasswordHasher {
+    fn hash(&self, password: &str) -> Result<String, PasswordHashError> {
+        // UUID v4はOSの乱数から生成されるため、16バイトのソルトとしてそのまま使う
+        let salt = SaltString::encode_b64(Uuid::new_v4().as_bytes())
+            .map_err(|e| PasswordHashError(e.to_string()))?;
+        self.argon2
+            .hash_password(password.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+            .map_err(|e| PasswordHashError(e.to_string()))
+    }
+
+    fn verify(&self, password: &str, password_hash: &str) -> bool {
+        PasswordHash::new(password_hash)
+            .map(|hash| {
+                self.argon2
+                    .verify_password(password.as_bytes(), &hash)
+                    .is_ok()
+            })
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_and_verify() {
+        let hasher = Argon2PasswordHasher::new();
+        let hash = hasher.hash("correct horse").unwrap();
+
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(hasher.verify("correct horse", &hash));
+        assert!(!hasher.verify("wrong horse", &hash));
+        assert!(!hasher.verify("correct horse", "not-a-hash"));
+    }
+}
@@ -0,0 +1,85 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::application::security::SessionTokenSigner;
+use crate::domain::value_objects::SessionId;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// HMAC-SHA256で署名したセッショントークン
+/// 形式は「セッションID.署名（base64url）」
+pub struct HmacSessionTokenSigner {
+    secret: Vec<u8>,
+}
+
+impl HmacSessionTokenSigner {
+    pub fn new(secret: Vec<u8>) -> Self {
+        Self { secret }
+    }
+
+    /// プロセスごとのランダムな鍵で作成（再起動すると発行済みトークンは無効になる）
+    pub fn with_random_secret() -> Self {
+        // UUID v4（OSの乱数）2つ分の32バイトを鍵にする
+        let secret = [Uuid::new_v4(), Uuid::new_v4()]
+            .iter()
+            .flat_map(|uuid| *uuid.as_bytes())
+            .collect();
+        Self::new(secret)
+    }
+
+    fn mac(&self, payload: &str) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(payload.as_bytes());
+        mac
+    }
+}
+
+impl SessionTokenSigner for HmacSessionTokenSigner {
+    fn sign(&self, session_id: SessionId) -> String {
+        let payload = session_id.to_string();
+        let signature = URL_SAFE_NO_PAD.encode(self.mac(&payload).finalize().into_bytes());
+        format!("{}.{}", payload, signature)
+    }
+
+    fn verify(&self, token: &str) -> Option<SessionId> {
+        let (payload, signature) = token.split_once('.')?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+
+        // 署名の比較は定数時間で行う
+        self.mac(payload).verify_slice(&signature).ok()?;
+
+        Uuid::parse_str(payload).ok().map(SessionId::from_uuid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let signer = HmacSessionTokenSigner::new(b"secret".to_vec());
+        let session_id = SessionId::new();
+
+        let token = signer.sign(session_id);
+
+        assert_eq!(signer.verify(&token), Some(session_id));
+    }
+
+    #[test]
+    fn test_rejects_tampered_or_foreign_tokens() {
+        let signer = HmacSessionTokenSigner::new(b"secret".to_vec());
+        let token = signer.sign(SessionId::new());
+
+        let tampered = format!("{}.{}", SessionId::new(), token.split_once('.').unwrap().1);
+        let foreign = HmacSessionTokenSigner::new(b"other".to_vec()).sign(SessionId::new());
+
+        assert_eq!(signer.verify(&tampered), None);
+        assert_eq!(signer.verify(&foreign), None);
+        assert_eq!(signer.verify("garbage"), None);
+    }
+}
@@ -0,0 +1,5 @@
+mod argon2_password_hasher;
+mod hmac_session_token_signer;
+
+pub use self::argon2_password_hasher::Argon2PasswordHasher;
+pub use self::hmac_session_token_signer::HmacSessionTokenSigner;
@@ -53,6 +53,18 @@ async fn main() -> anyhow::Result<()> {
 
             let app = Router::new()
                 .merge(presentation::routes())
+                // 管理・運用向けAPIは管理用トークン（ADMIN_API_TOKEN）で保護
+                .merge(
+                    presentation::admin_routes().route_layer(middleware::from_fn_with_state(
+                        container.clone(),
+                        presentation::admin_guard_middleware,
+                    )),
+                )
+                // 冪等キー（Idempotency-Key）付きの更新系リクエストの再送を検知
+                .layer(middleware::from_fn_with_state(
+                    container.clone(),
+                    presentation::idempotency_middleware,
+                ))
                 .layer(cors) // CORSレイヤーを追加
                 .layer(middleware::map_response(main_response_mapper))
                 .with_state(container); // アプリケーション状態としてコンテナを追加
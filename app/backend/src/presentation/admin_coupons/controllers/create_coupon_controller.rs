@@ -0,0 +1,61 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_coupons::presenters::AdminCouponPresenter;
+use crate::presentation::admin_coupons::requests::CreateCouponRequest;
+use crate::presentation::admin_coupons::responses::CouponResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Create Coupon Controller - クーポン作成の単一責任
+pub struct CreateCouponController;
+
+impl CreateCouponController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/coupons", post(handle))
+    }
+}
+
+/// POST /admin/coupons - クーポン作成処理
+#[utoipa::path(
+    post,
+    path = "/admin/coupons",
+    operation_id = "create_coupon",
+    request_body = CreateCouponRequest,
+    responses(
+        (status = 201, description = "クーポン作成成功", body = CouponResponse),
+        (status = 400, description = "リクエストが無効、またはクーポンコードが重複しています", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminCoupons"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedJson(request): ValidatedJson<CreateCouponRequest>,
+) -> Result<(StatusCode, Json<CouponResponse>)> {
+    println!(
+        "->> CreateCouponController::handle - code: {}",
+        request.code
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let coupon = dispatcher
+        .execute_create_coupon_command(request.to_command())
+        .await?;
+
+    println!(
+        "->> CreateCouponController::handle - success, coupon_id: {}",
+        coupon.id
+    );
+    Ok((
+        StatusCode::CREATED,
+        Json(AdminCouponPresenter::present(coupon)),
+    ))
+}
@@ -0,0 +1,52 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Router, routing::delete};
+use std::sync::Arc;
+
+use crate::application::commands::models::DeleteCouponCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+
+/// Delete Coupon Controller - クーポン削除の単一責任
+pub struct DeleteCouponController;
+
+impl DeleteCouponController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/coupons/{id}", delete(handle))
+    }
+}
+
+/// DELETE /admin/coupons/{id} - クーポン削除処理
+/// 使用済みのクーポンは注文から参照されるため削除できない（有効期間の終了日を変更して停止する）
+#[utoipa::path(
+    delete,
+    path = "/admin/coupons/{id}",
+    operation_id = "delete_coupon",
+    params(
+        ("id" = String, Path, description = "クーポンID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 204, description = "クーポン削除成功"),
+        (status = 400, description = "クーポンIDが無効、または使用済みのため削除できません", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "クーポンが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminCoupons"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode> {
+    println!("->> DeleteCouponController::handle - coupon_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    dispatcher
+        .execute_delete_coupon_command(DeleteCouponCommand::new(id))
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,58 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_coupons::presenters::AdminCouponPresenter;
+use crate::presentation::admin_coupons::requests::GenerateCouponCodesRequest;
+use crate::presentation::admin_coupons::responses::CouponListResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Generate Coupon Codes Controller - 使い切りクーポン一括発行の単一責任
+pub struct GenerateCouponCodesController;
+
+impl GenerateCouponCodesController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/coupons/bulk", post(handle))
+    }
+}
+
+/// POST /admin/coupons/bulk - 使い切りクーポン一括発行処理
+/// 同じ割引内容で、1回だけ使用できるランダムなコードのクーポンを指定数だけ発行する
+#[utoipa::path(
+    post,
+    path = "/admin/coupons/bulk",
+    operation_id = "generate_coupon_codes",
+    request_body = GenerateCouponCodesRequest,
+    responses(
+        (status = 201, description = "クーポン一括発行成功", body = CouponListResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminCoupons"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedJson(request): ValidatedJson<GenerateCouponCodesRequest>,
+) -> Result<(StatusCode, Json<CouponListResponse>)> {
+    println!(
+        "->> GenerateCouponCodesController::handle - prefix: {}, count: {}",
+        request.prefix, request.count
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let coupons = dispatcher
+        .execute_generate_coupon_codes_command(request.to_command())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AdminCouponPresenter::present_list(coupons)),
+    ))
+}
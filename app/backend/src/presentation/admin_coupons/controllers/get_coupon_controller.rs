@@ -0,0 +1,52 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::queries::models::GetCouponQuery;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_coupons::presenters::AdminCouponPresenter;
+use crate::presentation::admin_coupons::responses::CouponResponse;
+
+/// Get Coupon Controller - 管理用クーポン取得の単一責任
+pub struct GetCouponController;
+
+impl GetCouponController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/coupons/{id}", get(handle))
+    }
+}
+
+/// GET /admin/coupons/{id} - 管理用クーポン取得処理
+#[utoipa::path(
+    get,
+    path = "/admin/coupons/{id}",
+    operation_id = "get_coupon",
+    params(
+        ("id" = String, Path, description = "クーポンID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "クーポン取得成功", body = CouponResponse),
+        (status = 400, description = "クーポンIDが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "クーポンが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminCoupons"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<CouponResponse>> {
+    println!("->> GetCouponController::handle - coupon_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let coupon = dispatcher
+        .execute_get_coupon_query(GetCouponQuery::new(id)?)
+        .await?;
+
+    Ok(Json(AdminCouponPresenter::present(coupon)))
+}
@@ -0,0 +1,42 @@
+use axum::extract::State;
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_coupons::presenters::AdminCouponPresenter;
+use crate::presentation::admin_coupons::responses::CouponListResponse;
+
+/// Get Coupon List Controller - 管理用クーポン一覧取得の単一責任
+pub struct GetCouponListController;
+
+impl GetCouponListController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/coupons", get(handle))
+    }
+}
+
+/// GET /admin/coupons - 管理用クーポン一覧取得処理
+/// 有効期間外・使用上限に達したクーポンも含めて取得する
+#[utoipa::path(
+    get,
+    path = "/admin/coupons",
+    operation_id = "get_coupon_list",
+    responses(
+        (status = 200, description = "クーポン一覧取得成功", body = CouponListResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminCoupons"
+)]
+pub async fn handle(State(container): State<Arc<Container>>) -> Result<Json<CouponListResponse>> {
+    println!("->> GetCouponListController::handle");
+
+    let dispatcher = container.get_dispatcher();
+    let coupons = dispatcher.execute_get_coupon_list_query().await?;
+
+    Ok(Json(AdminCouponPresenter::present_list(coupons)))
+}
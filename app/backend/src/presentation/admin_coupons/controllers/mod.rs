@@ -0,0 +1,13 @@
+pub mod create_coupon_controller;
+pub mod delete_coupon_controller;
+pub mod generate_coupon_codes_controller;
+pub mod get_coupon_controller;
+pub mod get_coupon_list_controller;
+pub mod update_coupon_controller;
+
+pub use create_coupon_controller::CreateCouponController;
+pub use delete_coupon_controller::DeleteCouponController;
+pub use generate_coupon_codes_controller::GenerateCouponCodesController;
+pub use get_coupon_controller::GetCouponController;
+pub use get_coupon_list_controller::GetCouponListController;
+pub use update_coupon_controller::UpdateCouponController;
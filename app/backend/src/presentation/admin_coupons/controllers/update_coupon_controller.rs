@@ -0,0 +1,56 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::put};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_coupons::presenters::AdminCouponPresenter;
+use crate::presentation::admin_coupons::requests::UpdateCouponRequest;
+use crate::presentation::admin_coupons::responses::CouponResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Update Coupon Controller - クーポン更新の単一責任
+pub struct UpdateCouponController;
+
+impl UpdateCouponController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/coupons/{id}", put(handle))
+    }
+}
+
+/// PUT /admin/coupons/{id} - クーポン更新処理
+/// 使用回数以外の項目を置き換える
+#[utoipa::path(
+    put,
+    path = "/admin/coupons/{id}",
+    operation_id = "update_coupon",
+    params(
+        ("id" = String, Path, description = "クーポンID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = UpdateCouponRequest,
+    responses(
+        (status = 200, description = "クーポン更新成功", body = CouponResponse),
+        (status = 400, description = "リクエストが無効、またはクーポンコードが重複しています", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "クーポンが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminCoupons"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateCouponRequest>,
+) -> Result<Json<CouponResponse>> {
+    println!("->> UpdateCouponController::handle - coupon_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let coupon = dispatcher
+        .execute_update_coupon_command(request.to_command(id))
+        .await?;
+
+    Ok(Json(AdminCouponPresenter::present(coupon)))
+}
@@ -0,0 +1,78 @@
+use crate::application::dto::{CouponDTO, CouponListDTO};
+use crate::presentation::admin_coupons::responses::{CouponListResponse, CouponResponse};
+
+/// /admin/coupons API専用プレゼンター
+pub struct AdminCouponPresenter;
+
+impl AdminCouponPresenter {
+    /// CouponDTOをCouponResponseに変換
+    pub fn present(coupon: CouponDTO) -> CouponResponse {
+        CouponResponse {
+            id: coupon.id,
+            code: coupon.code,
+            name: coupon.name,
+            description: coupon.description,
+            discount_type: coupon.discount_type,
+            discount_value: coupon.discount_value,
+            max_discount_amount: coupon.max_discount_amount,
+            buy_quantity: coupon.buy_quantity,
+            get_quantity: coupon.get_quantity,
+            minimum_amount: coupon.minimum_amount,
+            target_product_ids: coupon.target_product_ids,
+            target_category_ids: coupon.target_category_ids,
+            usage_limit: coupon.usage_limit,
+            usage_count: coupon.usage_count,
+            per_customer_limit: coupon.per_customer_limit,
+            valid_from: coupon.valid_from.to_rfc3339(),
+            valid_until: coupon.valid_until.to_rfc3339(),
+            is_active: coupon.is_active,
+        }
+    }
+
+    /// CouponListDTOをCouponListResponseに変換
+    pub fn present_list(list: CouponListDTO) -> CouponListResponse {
+        CouponListResponse {
+            coupons: list.coupons.into_iter().map(Self::present).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_present_coupon_list() {
+        let dto = CouponDTO {
+            id: "coupon-1".to_string(),
+            code: "MAX3000".to_string(),
+            name: "15%OFF（上限3,000円）".to_string(),
+            description: None,
+            discount_type: "capped_percentage".to_string(),
+            discount_value: Some(15),
+            max_discount_amount: Some(3000),
+            buy_quantity: None,
+            get_quantity: None,
+            minimum_amount: None,
+            target_product_ids: Vec::new(),
+            target_category_ids: vec!["category-1".to_string()],
+            usage_limit: None,
+            usage_count: 3,
+            per_customer_limit: Some(2),
+            valid_from: Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+            valid_until: Utc.with_ymd_and_hms(2025, 5, 31, 23, 59, 59).unwrap(),
+            is_active: false,
+        };
+
+        let response = AdminCouponPresenter::present_list(CouponListDTO { coupons: vec![dto] });
+
+        let coupon = &response.coupons[0];
+        assert_eq!(coupon.code, "MAX3000");
+        assert_eq!(coupon.discount_type, "capped_percentage");
+        assert_eq!(coupon.max_discount_amount, Some(3000));
+        assert_eq!(coupon.per_customer_limit, Some(2));
+        assert_eq!(coupon.target_category_ids, vec!["category-1".to_string()]);
+        assert_eq!(coupon.valid_until, "2025-05-31T23:59:59+00:00");
+    }
+}
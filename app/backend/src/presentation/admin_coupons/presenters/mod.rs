@@ -0,0 +1,3 @@
+mod admin_coupon_presenter;
+
+pub use admin_coupon_presenter::AdminCouponPresenter;
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::CouponDiscountCommandItem;
+
+/// クーポンの割引内容リクエスト（作成・更新・一括発行共通）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CouponDiscountRequest {
+    /// 割引種別（fixed_amount / percentage / capped_percentage / free_shipping / buy_x_get_y）
+    #[validate(length(min = 1, message = "Discount type cannot be empty"))]
+    #[schema(example = "capped_percentage")]
+    pub discount_type: String,
+    /// 割引額（fixed_amount、円）または割引率（percentage / capped_percentage、%）
+    #[schema(example = 15)]
+    pub discount_value: Option<u32>,
+    /// 割引額の上限（capped_percentage、円）
+    #[schema(example = 3000)]
+    pub max_discount_amount: Option<u32>,
+    /// 購入数（buy_x_get_y）
+    #[schema(example = 2)]
+    pub buy_quantity: Option<u32>,
+    /// 無料になる数（buy_x_get_y）
+    #[schema(example = 1)]
+    pub get_quantity: Option<u32>,
+    /// 最低購入金額（商品小計、円）
+    #[schema(example = 5000)]
+    pub minimum_amount: Option<u32>,
+    /// 対象商品ID（指定した商品の明細のみ割引、最低購入金額・対象カテゴリとは併用不可）
+    #[serde(default)]
+    pub target_product_ids: Vec<String>,
+    /// 対象カテゴリID（指定したカテゴリの商品の明細のみ割引、最低購入金額・対象商品とは併用不可）
+    #[serde(default)]
+    pub target_category_ids: Vec<String>,
+}
+
+impl CouponDiscountRequest {
+    pub fn to_item(&self) -> CouponDiscountCommandItem {
+        CouponDiscountCommandItem {
+            discount_type: self.discount_type.clone(),
+            discount_value: self.discount_value,
+            max_discount_amount: self.max_discount_amount,
+            buy_quantity: self.buy_quantity,
+            get_quantity: self.get_quantity,
+            minimum_amount: self.minimum_amount,
+            target_product_ids: self.target_product_ids.clone(),
+            target_category_ids: self.target_category_ids.clone(),
+        }
+    }
+}
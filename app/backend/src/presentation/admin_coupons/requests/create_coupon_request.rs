@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::CreateCouponCommand;
+use crate::presentation::admin_coupons::requests::CouponDiscountRequest;
+
+/// クーポン作成リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCouponRequest {
+    /// クーポンコード（英数字、全クーポンで一意）
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Coupon code must be between 1 and 50 characters"
+    ))]
+    #[schema(example = "SPRING2025")]
+    pub code: String,
+    /// クーポン名
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Coupon name must be between 1 and 200 characters"
+    ))]
+    #[schema(example = "春のセール")]
+    pub name: String,
+    /// 説明
+    pub description: Option<String>,
+    /// 割引内容
+    #[validate(nested)]
+    pub discount: CouponDiscountRequest,
+    /// 使用回数の上限（省略時は無制限）
+    #[schema(example = 100)]
+    pub usage_limit: Option<u32>,
+    /// お一人様あたりの使用回数の上限（省略時は無制限）
+    #[schema(example = 1)]
+    pub per_customer_limit: Option<u32>,
+    /// 有効期間の開始日（YYYY-MM-DD）
+    #[schema(example = "2025-03-01")]
+    pub valid_from: String,
+    /// 有効期間の終了日（YYYY-MM-DD、終日有効）
+    #[schema(example = "2025-05-31")]
+    pub valid_until: String,
+}
+
+impl CreateCouponRequest {
+    pub fn to_command(&self) -> CreateCouponCommand {
+        CreateCouponCommand {
+            code: self.code.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            discount: self.discount.to_item(),
+            usage_limit: self.usage_limit,
+            per_customer_limit: self.per_customer_limit,
+            valid_from: self.valid_from.clone(),
+            valid_until: self.valid_until.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converts_request_to_command() {
+        let request: CreateCouponRequest = serde_json::from_str(
+            r#"{
+                "code": "BUY2GET1",
+                "name": "2点買うと1点無料",
+                "discount": {"discountType": "buy_x_get_y", "buyQuantity": 2, "getQuantity": 1},
+                "perCustomerLimit": 1,
+                "validFrom": "2025-03-01",
+                "validUntil": "2025-05-31"
+            }"#,
+        )
+        .unwrap();
+        assert!(request.validate().is_ok());
+
+        let command = request.to_command();
+        assert_eq!(command.code, "BUY2GET1");
+        assert_eq!(command.discount.discount_type, "buy_x_get_y");
+        assert_eq!(command.discount.buy_quantity, Some(2));
+        assert_eq!(command.discount.get_quantity, Some(1));
+        assert_eq!(command.usage_limit, None);
+        assert_eq!(command.per_customer_limit, Some(1));
+    }
+
+    #[test]
+    fn test_empty_discount_type_fails_validation() {
+        let request: CreateCouponRequest = serde_json::from_str(
+            r#"{
+                "code": "EMPTY",
+                "name": "種別なし",
+                "discount": {"discountType": ""},
+                "validFrom": "2025-03-01",
+                "validUntil": "2025-05-31"
+            }"#,
+        )
+        .unwrap();
+        assert!(request.validate().is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::GenerateCouponCodesCommand;
+use crate::presentation::admin_coupons::requests::CouponDiscountRequest;
+
+/// 使い切りクーポンの一括発行リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateCouponCodesRequest {
+    /// コードの先頭に付ける文字列（英数字4文字まで）
+    #[serde(default)]
+    #[validate(length(max = 4, message = "Prefix must be at most 4 characters"))]
+    #[schema(example = "VIP")]
+    pub prefix: String,
+    /// 発行数
+    #[validate(range(min = 1, max = 1000, message = "Count must be between 1 and 1000"))]
+    #[schema(example = 50)]
+    pub count: u32,
+    /// クーポン名
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Coupon name must be between 1 and 200 characters"
+    ))]
+    #[schema(example = "VIP会員限定クーポン")]
+    pub name: String,
+    /// 説明
+    pub description: Option<String>,
+    /// 割引内容
+    #[validate(nested)]
+    pub discount: CouponDiscountRequest,
+    /// 有効期間の開始日（YYYY-MM-DD）
+    #[schema(example = "2025-03-01")]
+    pub valid_from: String,
+    /// 有効期間の終了日（YYYY-MM-DD、終日有効）
+    #[schema(example = "2025-05-31")]
+    pub valid_until: String,
+}
+
+impl GenerateCouponCodesRequest {
+    pub fn to_command(&self) -> GenerateCouponCodesCommand {
+        GenerateCouponCodesCommand {
+            prefix: self.prefix.clone(),
+            count: self.count,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            discount: self.discount.to_item(),
+            valid_from: self.valid_from.clone(),
+            valid_until: self.valid_until.clone(),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod coupon_discount_request;
+mod create_coupon_request;
+mod generate_coupon_codes_request;
+mod update_coupon_request;
+
+pub use coupon_discount_request::CouponDiscountRequest;
+pub use create_coupon_request::CreateCouponRequest;
+pub use generate_coupon_codes_request::GenerateCouponCodesRequest;
+pub use update_coupon_request::UpdateCouponRequest;
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::UpdateCouponCommand;
+use crate::presentation::admin_coupons::requests::CouponDiscountRequest;
+
+/// クーポン更新リクエスト（使用回数以外の項目を置き換える）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCouponRequest {
+    /// クーポンコード（英数字、全クーポンで一意）
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Coupon code must be between 1 and 50 characters"
+    ))]
+    #[schema(example = "SPRING2025")]
+    pub code: String,
+    /// クーポン名
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Coupon name must be between 1 and 200 characters"
+    ))]
+    #[schema(example = "春のセール")]
+    pub name: String,
+    /// 説明
+    pub description: Option<String>,
+    /// 割引内容
+    #[validate(nested)]
+    pub discount: CouponDiscountRequest,
+    /// 使用回数の上限（省略時は無制限、使用済みの回数より小さくはできない）
+    #[schema(example = 100)]
+    pub usage_limit: Option<u32>,
+    /// お一人様あたりの使用回数の上限（省略時は無制限）
+    #[schema(example = 1)]
+    pub per_customer_limit: Option<u32>,
+    /// 有効期間の開始日（YYYY-MM-DD）
+    #[schema(example = "2025-03-01")]
+    pub valid_from: String,
+    /// 有効期間の終了日（YYYY-MM-DD、終日有効）
+    #[schema(example = "2025-05-31")]
+    pub valid_until: String,
+}
+
+impl UpdateCouponRequest {
+    pub fn to_command(&self, coupon_id: String) -> UpdateCouponCommand {
+        UpdateCouponCommand {
+            coupon_id,
+            code: self.code.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            discount: self.discount.to_item(),
+            usage_limit: self.usage_limit,
+            per_customer_limit: self.per_customer_limit,
+            valid_from: self.valid_from.clone(),
+            valid_until: self.valid_until.clone(),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// /admin/coupons API共通のクーポンレスポンス
+/// 有効期間外のクーポンも含む
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CouponResponse {
+    /// クーポンID
+    pub id: String,
+    /// クーポンコード
+    pub code: String,
+    /// クーポン名
+    pub name: String,
+    /// 説明
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub description: Option<String>,
+    /// 割引種別（fixed_amount / percentage / capped_percentage / free_shipping / buy_x_get_y）
+    pub discount_type: String,
+    /// 割引額（fixed_amount、円）または割引率（percentage / capped_percentage、%）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub discount_value: Option<u32>,
+    /// 割引額の上限（capped_percentage、円）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub max_discount_amount: Option<u32>,
+    /// 購入数（buy_x_get_y）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub buy_quantity: Option<u32>,
+    /// 無料になる数（buy_x_get_y）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub get_quantity: Option<u32>,
+    /// 最低購入金額（円）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub minimum_amount: Option<u32>,
+    /// 対象商品ID（商品指定のクーポン）
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub target_product_ids: Vec<String>,
+    /// 対象カテゴリID（カテゴリ指定のクーポン）
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub target_category_ids: Vec<String>,
+    /// 使用回数の上限
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub usage_limit: Option<u32>,
+    /// 使用回数
+    pub usage_count: u32,
+    /// お一人様あたりの使用回数の上限
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub per_customer_limit: Option<u32>,
+    /// 有効期間の開始日時（RFC3339）
+    pub valid_from: String,
+    /// 有効期間の終了日時（RFC3339）
+    pub valid_until: String,
+    /// 現在有効期間内か
+    pub is_active: bool,
+}
+
+/// クーポン一覧レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CouponListResponse {
+    /// クーポン
+    pub coupons: Vec<CouponResponse>,
+}
@@ -0,0 +1,3 @@
+mod coupon_response;
+
+pub use coupon_response::{CouponListResponse, CouponResponse};
@@ -0,0 +1,18 @@
+use crate::infrastructure::Container;
+use crate::presentation::admin_coupons::controllers::{
+    CreateCouponController, DeleteCouponController, GenerateCouponCodesController,
+    GetCouponController, GetCouponListController, UpdateCouponController,
+};
+use axum::Router;
+use std::sync::Arc;
+
+/// クーポン管理関連のルーティング
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(GetCouponListController::routes())
+        .merge(CreateCouponController::routes())
+        .merge(GenerateCouponCodesController::routes())
+        .merge(GetCouponController::routes())
+        .merge(UpdateCouponController::routes())
+        .merge(DeleteCouponController::routes())
+}
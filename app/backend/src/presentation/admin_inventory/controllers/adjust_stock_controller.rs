@@ -0,0 +1,64 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_inventory::presenters::StockMovementPresenter;
+use crate::presentation::admin_inventory::requests::AdjustStockRequest;
+use crate::presentation::admin_inventory::responses::StockMovementResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Adjust Stock Controller - 在庫調整の単一責任
+pub struct AdjustStockController;
+
+impl AdjustStockController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/skus/{id}/stock-adjustments", post(handle))
+    }
+}
+
+/// POST /admin/skus/{id}/stock-adjustments - 在庫調整処理
+/// 入荷・補正・廃棄による在庫数の増減を反映し、在庫台帳に記録する
+#[utoipa::path(
+    post,
+    path = "/admin/skus/{id}/stock-adjustments",
+    operation_id = "adjust_stock",
+    params(
+        ("id" = String, Path, description = "SKU ID", example = "550e8400-e29b-41d4-a716-446655440001")
+    ),
+    request_body = AdjustStockRequest,
+    responses(
+        (status = 201, description = "在庫調整成功", body = StockMovementResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "SKUが見つかりません", body = ErrorResponse),
+        (status = 409, description = "引当済みの在庫を下回る減算です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminInventory"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<AdjustStockRequest>,
+) -> Result<(StatusCode, Json<StockMovementResponse>)> {
+    println!(
+        "->> AdjustStockController::handle - sku_id: {}, type: {}, quantity: {}",
+        id, request.adjustment_type, request.quantity
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let movement = dispatcher
+        .execute_adjust_stock_command(request.to_command(id))
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(StockMovementPresenter::present(movement)),
+    ))
+}
@@ -0,0 +1,60 @@
+use axum::extract::{Path, Query, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::queries::models::GetStockHistoryQuery;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_inventory::presenters::StockHistoryPresenter;
+use crate::presentation::admin_inventory::requests::GetStockHistoryRequest;
+use crate::presentation::admin_inventory::responses::StockHistoryResponse;
+
+/// Get Stock History Controller - 在庫履歴取得の単一責任
+pub struct GetStockHistoryController;
+
+impl GetStockHistoryController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/skus/{id}/stock-history", get(handle))
+    }
+}
+
+/// GET /admin/skus/{id}/stock-history - 在庫履歴取得処理
+/// 現在の在庫数と在庫台帳を新しい順に返す（倉庫の実数との照合用）
+#[utoipa::path(
+    get,
+    path = "/admin/skus/{id}/stock-history",
+    operation_id = "get_stock_history",
+    params(
+        ("id" = String, Path, description = "SKU ID", example = "550e8400-e29b-41d4-a716-446655440001"),
+        GetStockHistoryRequest
+    ),
+    responses(
+        (status = 200, description = "在庫履歴の取得成功", body = StockHistoryResponse),
+        (status = 400, description = "SKU IDが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "SKUが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminInventory"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    Query(request): Query<GetStockHistoryRequest>,
+) -> Result<Json<StockHistoryResponse>> {
+    println!("->> GetStockHistoryController::handle - sku_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let history = dispatcher
+        .execute_get_stock_history_query(GetStockHistoryQuery::new(
+            id,
+            request.page,
+            request.per_page,
+        )?)
+        .await?;
+
+    Ok(Json(StockHistoryPresenter::present(history)))
+}
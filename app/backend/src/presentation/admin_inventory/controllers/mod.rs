@@ -0,0 +1,5 @@
+pub mod adjust_stock_controller;
+pub mod get_stock_history_controller;
+
+pub use adjust_stock_controller::AdjustStockController;
+pub use get_stock_history_controller::GetStockHistoryController;
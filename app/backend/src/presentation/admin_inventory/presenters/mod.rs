@@ -0,0 +1,3 @@
+mod stock_history_presenter;
+
+pub use stock_history_presenter::{StockHistoryPresenter, StockMovementPresenter};
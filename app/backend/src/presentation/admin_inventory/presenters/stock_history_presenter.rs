@@ -0,0 +1,49 @@
+use crate::application::dto::{StockHistoryDTO, StockMovementDTO};
+use crate::presentation::admin_inventory::responses::{
+    StockHistoryResponse, StockMovementResponse,
+};
+
+/// 在庫台帳の1行を返すAPI用プレゼンター
+pub struct StockMovementPresenter;
+
+impl StockMovementPresenter {
+    /// StockMovementDTOをStockMovementResponseに変換
+    pub fn present(movement: StockMovementDTO) -> StockMovementResponse {
+        StockMovementResponse {
+            id: movement.id,
+            sku_id: movement.sku_id,
+            movement_type: movement.movement_type,
+            delta: movement.delta,
+            stock_after: movement.stock_after,
+            reason: movement.reason,
+            reference_order_id: movement.reference_order_id,
+            actor: movement.actor,
+            created_at: movement.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// GET /admin/skus/{id}/stock-history API専用プレゼンター
+pub struct StockHistoryPresenter;
+
+impl StockHistoryPresenter {
+    /// StockHistoryDTOをStockHistoryResponseに変換
+    pub fn present(history: StockHistoryDTO) -> StockHistoryResponse {
+        StockHistoryResponse {
+            sku_id: history.sku_id,
+            sku_code: history.sku_code,
+            stock_quantity: history.stock_quantity,
+            reserved_quantity: history.reserved_quantity,
+            movements: history
+                .movements
+                .into_iter()
+                .map(StockMovementPresenter::present)
+                .collect(),
+            total_count: history.total_count,
+            page: history.page,
+            per_page: history.per_page,
+            has_next_page: history.has_next_page,
+            has_previous_page: history.has_previous_page,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::AdjustStockCommand;
+
+/// 在庫調整リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct AdjustStockRequest {
+    /// 調整種別（receive: 入荷, correction: 補正, write_off: 破損・紛失による廃棄）
+    #[serde(rename = "type")]
+    #[schema(example = "receive")]
+    pub adjustment_type: String,
+    /// 入荷・廃棄は正の数量、補正は増減数（減らす場合は負数）
+    #[schema(example = 10)]
+    pub quantity: i64,
+    /// 調整理由
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters"
+    ))]
+    #[schema(example = "定期入荷")]
+    pub reason: String,
+    /// 関連する注文ID（返品の戻し入れなど）
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
+    pub reference_order_id: Option<String>,
+    /// 調整を行った担当者
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Actor must be between 1 and 100 characters"
+    ))]
+    #[schema(example = "warehouse-tokyo")]
+    pub actor: String,
+}
+
+impl AdjustStockRequest {
+    pub fn to_command(&self, sku_id: String) -> AdjustStockCommand {
+        AdjustStockCommand {
+            sku_id,
+            adjustment_type: self.adjustment_type.clone(),
+            quantity: self.quantity,
+            reason: self.reason.clone(),
+            reference_order_id: self.reference_order_id.clone(),
+            actor: self.actor.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converts_to_command() {
+        let request: AdjustStockRequest = serde_json::from_str(
+            r#"{"type":"write_off","quantity":2,"reason":"破損","actor":"warehouse"}"#,
+        )
+        .unwrap();
+        assert!(request.validate().is_ok());
+
+        let command = request.to_command("sku-1".to_string());
+        assert_eq!(command.sku_id, "sku-1");
+        assert_eq!(command.adjustment_type, "write_off");
+        assert_eq!(command.quantity, 2);
+        assert_eq!(command.actor, "warehouse");
+        assert!(command.reference_order_id.is_none());
+    }
+
+    #[test]
+    fn test_empty_reason_fails_validation() {
+        let request: AdjustStockRequest = serde_json::from_str(
+            r#"{"type":"receive","quantity":1,"reason":"","actor":"warehouse"}"#,
+        )
+        .unwrap();
+        assert!(request.validate().is_err());
+    }
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// 在庫履歴取得用のクエリパラメータ
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query, rename_all = "camelCase")]
+pub struct GetStockHistoryRequest {
+    /// ページ番号（1始まり）
+    #[param(example = 1)]
+    pub page: Option<u32>,
+    /// 1ページあたりの件数（最大200）
+    #[param(example = 50)]
+    pub per_page: Option<u32>,
+}
@@ -0,0 +1,5 @@
+mod adjust_stock_request;
+mod get_stock_history_request;
+
+pub use adjust_stock_request::AdjustStockRequest;
+pub use get_stock_history_request::GetStockHistoryRequest;
@@ -0,0 +1,3 @@
+mod stock_history_response;
+
+pub use stock_history_response::{StockHistoryResponse, StockMovementResponse};
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// GET /admin/skus/{id}/stock-history API専用の在庫履歴レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StockHistoryResponse {
+    /// SKU ID
+    pub sku_id: String,
+    /// SKUコード
+    pub sku_code: String,
+    /// 現在の実在庫数
+    pub stock_quantity: u32,
+    /// 引当済み数
+    pub reserved_quantity: u32,
+    /// 在庫台帳（新しい順）
+    pub movements: Vec<StockMovementResponse>,
+    /// 総件数
+    pub total_count: u32,
+    /// 現在のページ番号
+    pub page: u32,
+    /// 1ページあたりの件数
+    pub per_page: u32,
+    /// 次のページがあるかどうか
+    pub has_next_page: bool,
+    /// 前のページがあるかどうか
+    pub has_previous_page: bool,
+}
+
+/// 在庫台帳の1行
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StockMovementResponse {
+    /// 台帳ID
+    pub id: i64,
+    /// SKU ID
+    pub sku_id: String,
+    /// 変動種別（receive, correction, write_off, sale, restock）
+    #[schema(example = "receive")]
+    pub movement_type: String,
+    /// 実在庫数の増減
+    #[schema(example = 10)]
+    pub delta: i64,
+    /// 変動後の実在庫数
+    pub stock_after: i64,
+    /// 理由
+    pub reason: String,
+    /// 関連する注文ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub reference_order_id: Option<String>,
+    /// 実行者
+    pub actor: String,
+    /// 記録日時（RFC3339）
+    pub created_at: String,
+}
@@ -0,0 +1,13 @@
+use crate::infrastructure::Container;
+use crate::presentation::admin_inventory::controllers::{
+    AdjustStockController, GetStockHistoryController,
+};
+use axum::Router;
+use std::sync::Arc;
+
+/// 在庫管理関連のルーティング
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(AdjustStockController::routes())
+        .merge(GetStockHistoryController::routes())
+}
@@ -0,0 +1,3 @@
+pub mod update_payment_fee_rule_controller;
+
+pub use update_payment_fee_rule_controller::UpdatePaymentFeeRuleController;
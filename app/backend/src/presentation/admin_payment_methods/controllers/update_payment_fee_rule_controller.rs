@@ -0,0 +1,59 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::put};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_payment_methods::presenters::AdminPaymentMethodPresenter;
+use crate::presentation::admin_payment_methods::requests::UpdatePaymentFeeRuleRequest;
+use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::payment_methods::responses::PaymentMethodListItemResponse;
+
+/// Update Payment Fee Rule Controller - 支払い手数料ルール更新の単一責任
+pub struct UpdatePaymentFeeRuleController;
+
+impl UpdatePaymentFeeRuleController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/payment-methods/{id}/fee-rule", put(handle))
+    }
+}
+
+/// PUT /admin/payment-methods/{id}/fee-rule - 支払い手数料ルール更新処理
+/// 定額・割合・金額帯のいずれかの計算方式と最低額・最高額で手数料ルールを置き換える
+#[utoipa::path(
+    put,
+    path = "/admin/payment-methods/{id}/fee-rule",
+    operation_id = "update_payment_fee_rule",
+    params(
+        ("id" = String, Path, description = "支払い方法ID", example = "cod")
+    ),
+    request_body = UpdatePaymentFeeRuleRequest,
+    responses(
+        (status = 200, description = "手数料ルール更新成功", body = PaymentMethodListItemResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "支払い方法が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminPaymentMethods"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdatePaymentFeeRuleRequest>,
+) -> Result<Json<PaymentMethodListItemResponse>> {
+    println!(
+        "->> UpdatePaymentFeeRuleController::handle - payment_method_id: {}, fee_type: {}",
+        id, request.fee_type
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let payment_method = dispatcher
+        .execute_update_payment_fee_rule_command(request.to_command(id))
+        .await?;
+
+    Ok(Json(AdminPaymentMethodPresenter::present(payment_method)))
+}
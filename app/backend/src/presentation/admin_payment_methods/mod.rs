@@ -0,0 +1,6 @@
+pub mod controllers;
+pub mod presenters;
+pub mod requests;
+pub mod routes;
+
+pub use routes::routes;
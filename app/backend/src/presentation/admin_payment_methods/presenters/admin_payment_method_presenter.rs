@@ -0,0 +1,18 @@
+use crate::application::dto::PaymentMethodDTO;
+use crate::presentation::payment_methods::presenters::GetPaymentMethodListPresenter;
+use crate::presentation::payment_methods::responses::PaymentMethodListItemResponse;
+
+/// 支払い方法管理API用プレゼンター
+pub struct AdminPaymentMethodPresenter;
+
+impl AdminPaymentMethodPresenter {
+    /// PaymentMethodDTOをレスポンスに変換（一覧と同じ形式で返す）
+    pub fn present(payment_method: PaymentMethodDTO) -> PaymentMethodListItemResponse {
+        PaymentMethodListItemResponse {
+            id: payment_method.id,
+            name: Some(payment_method.name),
+            description: Some(payment_method.description),
+            fee_rule: GetPaymentMethodListPresenter::present_fee_rule(payment_method.fee_rule),
+        }
+    }
+}
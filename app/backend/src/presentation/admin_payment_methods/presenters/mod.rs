@@ -0,0 +1,3 @@
+mod admin_payment_method_presenter;
+
+pub use admin_payment_method_presenter::AdminPaymentMethodPresenter;
@@ -0,0 +1,3 @@
+mod update_payment_fee_rule_request;
+
+pub use update_payment_fee_rule_request::{PaymentFeeBracketRequest, UpdatePaymentFeeRuleRequest};
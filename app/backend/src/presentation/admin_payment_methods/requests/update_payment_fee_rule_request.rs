@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::{
+    PaymentFeeBracketCommandItem, UpdatePaymentFeeRuleCommand,
+};
+
+/// 支払い手数料ルール更新リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePaymentFeeRuleRequest {
+    /// 計算方式（flat: 定額, percentage: 割合, tiered: 金額帯）
+    #[schema(example = "tiered")]
+    pub fee_type: String,
+    /// 定額の手数料（円、flatで必須）
+    #[schema(example = 200)]
+    pub amount: Option<u32>,
+    /// カート合計に対する割合（%、percentageで必須）
+    #[validate(range(min = 0.0, max = 100.0, message = "Rate must be between 0 and 100"))]
+    #[schema(example = 3.5)]
+    pub rate: Option<f64>,
+    /// 最低手数料（円）
+    pub min_fee: Option<u32>,
+    /// 最高手数料（円）
+    pub max_fee: Option<u32>,
+    /// 金額帯（tieredで必須、上限の昇順、最後は上限なし）
+    #[serde(default)]
+    #[validate(nested)]
+    pub brackets: Vec<PaymentFeeBracketRequest>,
+}
+
+/// 手数料の金額帯
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentFeeBracketRequest {
+    /// カート合計がこの金額未満のときに適用（省略時は上限なし）
+    #[validate(range(min = 1, message = "Upper bound must be positive"))]
+    #[schema(example = 10000)]
+    pub up_to: Option<u32>,
+    /// 手数料（円）
+    #[schema(example = 330)]
+    pub fee: u32,
+}
+
+impl UpdatePaymentFeeRuleRequest {
+    pub fn to_command(&self, payment_method_id: String) -> UpdatePaymentFeeRuleCommand {
+        UpdatePaymentFeeRuleCommand {
+            payment_method_id,
+            fee_type: self.fee_type.clone(),
+            amount: self.amount,
+            rate: self.rate,
+            min_fee: self.min_fee,
+            max_fee: self.max_fee,
+            brackets: self
+                .brackets
+                .iter()
+                .map(|bracket| PaymentFeeBracketCommandItem {
+                    up_to: bracket.up_to,
+                    fee: bracket.fee,
+                })
+                .collect(),
+        }
+    }
+}
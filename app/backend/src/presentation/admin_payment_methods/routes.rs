@@ -0,0 +1,9 @@
+use crate::infrastructure::Container;
+use crate::presentation::admin_payment_methods::controllers::UpdatePaymentFeeRuleController;
+use axum::Router;
+use std::sync::Arc;
+
+/// 支払い方法管理関連のルーティング
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new().merge(UpdatePaymentFeeRuleController::routes())
+}
@@ -0,0 +1,64 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::AddProductSkuCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_products::presenters::AdminProductPresenter;
+use crate::presentation::admin_products::requests::ProductSkuRequest;
+use crate::presentation::admin_products::responses::AdminProductResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Add Product SKU Controller - SKU追加の単一責任
+pub struct AddProductSkuController;
+
+impl AddProductSkuController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/products/{id}/skus", post(handle))
+    }
+}
+
+/// POST /admin/products/{id}/skus - SKU追加処理
+/// SKUコードは全商品で一意である必要がある
+#[utoipa::path(
+    post,
+    path = "/admin/products/{id}/skus",
+    operation_id = "add_product_sku",
+    params(
+        ("id" = String, Path, description = "商品ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = ProductSkuRequest,
+    responses(
+        (status = 201, description = "SKU追加成功", body = AdminProductResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "商品が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminProducts"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<ProductSkuRequest>,
+) -> Result<(StatusCode, Json<AdminProductResponse>)> {
+    println!(
+        "->> AddProductSkuController::handle - product_id: {}, sku_code: {}",
+        id, request.sku_code
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let product = dispatcher
+        .execute_add_product_sku_command(AddProductSkuCommand::new(id, request.to_item()))
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AdminProductPresenter::present(product)),
+    ))
+}
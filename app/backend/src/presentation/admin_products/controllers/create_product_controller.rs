@@ -0,0 +1,62 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_products::presenters::AdminProductPresenter;
+use crate::presentation::admin_products::requests::CreateProductRequest;
+use crate::presentation::admin_products::responses::AdminProductResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Create Product Controller - 商品作成の単一責任
+pub struct CreateProductController;
+
+impl CreateProductController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/products", post(handle))
+    }
+}
+
+/// POST /admin/products - 商品作成処理
+/// SKU・画像・タグを含めて商品を作成する（publishを指定しない限り非公開）
+#[utoipa::path(
+    post,
+    path = "/admin/products",
+    operation_id = "create_product",
+    request_body = CreateProductRequest,
+    responses(
+        (status = 201, description = "商品作成成功", body = AdminProductResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminProducts"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedJson(request): ValidatedJson<CreateProductRequest>,
+) -> Result<(StatusCode, Json<AdminProductResponse>)> {
+    println!(
+        "->> CreateProductController::handle - name: {}",
+        request.name
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let product = dispatcher
+        .execute_create_product_command(request.to_command())
+        .await?;
+
+    println!(
+        "->> CreateProductController::handle - success, product_id: {}",
+        product.id
+    );
+    Ok((
+        StatusCode::CREATED,
+        Json(AdminProductPresenter::present(product)),
+    ))
+}
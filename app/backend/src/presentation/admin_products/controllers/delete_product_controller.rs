@@ -0,0 +1,52 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Router, routing::delete};
+use std::sync::Arc;
+
+use crate::application::commands::models::DeleteProductCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+
+/// Delete Product Controller - 商品削除の単一責任
+pub struct DeleteProductController;
+
+impl DeleteProductController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/products/{id}", delete(handle))
+    }
+}
+
+/// DELETE /admin/products/{id} - 商品削除処理
+/// 注文履歴のある商品は削除できないため、非公開化またはSKUの販売停止で対応する
+#[utoipa::path(
+    delete,
+    path = "/admin/products/{id}",
+    operation_id = "delete_product",
+    params(
+        ("id" = String, Path, description = "商品ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 204, description = "商品削除成功"),
+        (status = 400, description = "商品IDが無効、または注文履歴があり削除できません", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "商品が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminProducts"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode> {
+    println!("->> DeleteProductController::handle - product_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    dispatcher
+        .execute_delete_product_command(DeleteProductCommand::new(id))
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
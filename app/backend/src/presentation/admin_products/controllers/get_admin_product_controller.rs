@@ -0,0 +1,53 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::queries::models::GetAdminProductQuery;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_products::presenters::AdminProductPresenter;
+use crate::presentation::admin_products::responses::AdminProductResponse;
+
+/// Get Admin Product Controller - 管理用商品取得の単一責任
+pub struct GetAdminProductController;
+
+impl GetAdminProductController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/products/{id}", get(handle))
+    }
+}
+
+/// GET /admin/products/{id} - 管理用商品取得処理
+/// 非公開の商品・販売停止中のSKUも含めて取得する
+#[utoipa::path(
+    get,
+    path = "/admin/products/{id}",
+    operation_id = "get_admin_product",
+    params(
+        ("id" = String, Path, description = "商品ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "商品取得成功", body = AdminProductResponse),
+        (status = 400, description = "商品IDが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "商品が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminProducts"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<AdminProductResponse>> {
+    println!("->> GetAdminProductController::handle - product_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let product = dispatcher
+        .execute_get_admin_product_query(GetAdminProductQuery::new(id)?)
+        .await?;
+
+    Ok(Json(AdminProductPresenter::present(product)))
+}
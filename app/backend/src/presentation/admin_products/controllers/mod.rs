@@ -0,0 +1,21 @@
+pub mod add_product_sku_controller;
+pub mod create_product_controller;
+pub mod delete_product_controller;
+pub mod get_admin_product_controller;
+pub mod publish_product_controller;
+pub mod remove_product_sku_controller;
+pub mod schedule_sku_sale_controller;
+pub mod unpublish_product_controller;
+pub mod update_product_controller;
+pub mod update_product_sku_controller;
+
+pub use add_product_sku_controller::AddProductSkuController;
+pub use create_product_controller::CreateProductController;
+pub use delete_product_controller::DeleteProductController;
+pub use get_admin_product_controller::GetAdminProductController;
+pub use publish_product_controller::PublishProductController;
+pub use remove_product_sku_controller::RemoveProductSkuController;
+pub use schedule_sku_sale_controller::ScheduleSkuSaleController;
+pub use unpublish_product_controller::UnpublishProductController;
+pub use update_product_controller::UpdateProductController;
+pub use update_product_sku_controller::UpdateProductSkuController;
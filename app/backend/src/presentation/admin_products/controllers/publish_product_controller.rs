@@ -0,0 +1,53 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::PublishProductCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_products::presenters::AdminProductPresenter;
+use crate::presentation::admin_products::responses::AdminProductResponse;
+
+/// Publish Product Controller - 商品公開の単一責任
+pub struct PublishProductController;
+
+impl PublishProductController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/products/{id}/publish", post(handle))
+    }
+}
+
+/// POST /admin/products/{id}/publish - 商品公開処理
+/// 購入可能なSKUがない商品は公開できない
+#[utoipa::path(
+    post,
+    path = "/admin/products/{id}/publish",
+    operation_id = "publish_product",
+    params(
+        ("id" = String, Path, description = "商品ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "商品公開成功", body = AdminProductResponse),
+        (status = 400, description = "商品IDが無効、または公開条件を満たしていません", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "商品が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminProducts"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<AdminProductResponse>> {
+    println!("->> PublishProductController::handle - product_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let product = dispatcher
+        .execute_publish_product_command(PublishProductCommand::new(id))
+        .await?;
+
+    Ok(Json(AdminProductPresenter::present(product)))
+}
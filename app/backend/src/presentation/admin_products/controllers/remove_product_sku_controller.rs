@@ -0,0 +1,57 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::delete};
+use std::sync::Arc;
+
+use crate::application::commands::models::RemoveProductSkuCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_products::presenters::AdminProductPresenter;
+use crate::presentation::admin_products::responses::AdminProductResponse;
+
+/// Remove Product SKU Controller - SKU削除の単一責任
+pub struct RemoveProductSkuController;
+
+impl RemoveProductSkuController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/products/{id}/skus/{sku_id}", delete(handle))
+    }
+}
+
+/// DELETE /admin/products/{id}/skus/{sku_id} - SKU削除処理
+/// 注文履歴のあるSKUは削除できないため、ステータスをdiscontinuedにして販売停止する
+#[utoipa::path(
+    delete,
+    path = "/admin/products/{id}/skus/{sku_id}",
+    operation_id = "remove_product_sku",
+    params(
+        ("id" = String, Path, description = "商品ID", example = "550e8400-e29b-41d4-a716-446655440000"),
+        ("sku_id" = String, Path, description = "SKU ID", example = "550e8400-e29b-41d4-a716-446655440001")
+    ),
+    responses(
+        (status = 200, description = "SKU削除成功", body = AdminProductResponse),
+        (status = 400, description = "リクエストが無効、または注文履歴があり削除できません", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "商品またはSKUが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminProducts"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path((id, sku_id)): Path<(String, String)>,
+) -> Result<Json<AdminProductResponse>> {
+    println!(
+        "->> RemoveProductSkuController::handle - product_id: {}, sku_id: {}",
+        id, sku_id
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let product = dispatcher
+        .execute_remove_product_sku_command(RemoveProductSkuCommand::new(id, sku_id))
+        .await?;
+
+    Ok(Json(AdminProductPresenter::present(product)))
+}
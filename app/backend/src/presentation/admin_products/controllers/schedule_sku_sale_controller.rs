@@ -0,0 +1,67 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_products::presenters::AdminProductPresenter;
+use crate::presentation::admin_products::requests::ScheduleSkuSaleRequest;
+use crate::presentation::admin_products::responses::AdminProductResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Schedule SKU Sale Controller - SKUの期間指定セール予約の単一責任
+pub struct ScheduleSkuSaleController;
+
+impl ScheduleSkuSaleController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route(
+            "/admin/products/{id}/skus/{sku_id}/price-schedules",
+            post(handle),
+        )
+    }
+}
+
+/// POST /admin/products/{id}/skus/{sku_id}/price-schedules - 期間指定セール予約処理
+/// 期間中はセール価格が適用され、商品にon_saleタグが自動で付与される
+#[utoipa::path(
+    post,
+    path = "/admin/products/{id}/skus/{sku_id}/price-schedules",
+    operation_id = "schedule_sku_sale",
+    params(
+        ("id" = String, Path, description = "商品ID", example = "550e8400-e29b-41d4-a716-446655440000"),
+        ("sku_id" = String, Path, description = "SKU ID", example = "550e8400-e29b-41d4-a716-446655440001")
+    ),
+    request_body = ScheduleSkuSaleRequest,
+    responses(
+        (status = 201, description = "セール予約成功", body = AdminProductResponse),
+        (status = 400, description = "リクエストが無効です（価格・期間が不正、既存の予約と期間が重なる）", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "商品またはSKUが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminProducts"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path((id, sku_id)): Path<(String, String)>,
+    ValidatedJson(request): ValidatedJson<ScheduleSkuSaleRequest>,
+) -> Result<(StatusCode, Json<AdminProductResponse>)> {
+    println!(
+        "->> ScheduleSkuSaleController::handle - product_id: {}, sku_id: {}",
+        id, sku_id
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let product = dispatcher
+        .execute_schedule_sku_sale_command(request.to_command(id, sku_id))
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AdminProductPresenter::present(product)),
+    ))
+}
@@ -0,0 +1,56 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::UnpublishProductCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_products::presenters::AdminProductPresenter;
+use crate::presentation::admin_products::responses::AdminProductResponse;
+
+/// Unpublish Product Controller - 商品非公開の単一責任
+pub struct UnpublishProductController;
+
+impl UnpublishProductController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/products/{id}/unpublish", post(handle))
+    }
+}
+
+/// POST /admin/products/{id}/unpublish - 商品非公開処理
+/// ストアフロントの一覧・詳細・検索から商品を外す
+#[utoipa::path(
+    post,
+    path = "/admin/products/{id}/unpublish",
+    operation_id = "unpublish_product",
+    params(
+        ("id" = String, Path, description = "商品ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "商品非公開成功", body = AdminProductResponse),
+        (status = 400, description = "商品IDが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "商品が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminProducts"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<AdminProductResponse>> {
+    println!(
+        "->> UnpublishProductController::handle - product_id: {}",
+        id
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let product = dispatcher
+        .execute_unpublish_product_command(UnpublishProductCommand::new(id))
+        .await?;
+
+    Ok(Json(AdminProductPresenter::present(product)))
+}
@@ -0,0 +1,56 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::put};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_products::presenters::AdminProductPresenter;
+use crate::presentation::admin_products::requests::UpdateProductRequest;
+use crate::presentation::admin_products::responses::AdminProductResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Update Product Controller - 商品更新の単一責任
+pub struct UpdateProductController;
+
+impl UpdateProductController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/products/{id}", put(handle))
+    }
+}
+
+/// PUT /admin/products/{id} - 商品更新処理
+/// 指定された項目のみ更新する（画像・タグは指定時に全置換）
+#[utoipa::path(
+    put,
+    path = "/admin/products/{id}",
+    operation_id = "update_product",
+    params(
+        ("id" = String, Path, description = "商品ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = UpdateProductRequest,
+    responses(
+        (status = 200, description = "商品更新成功", body = AdminProductResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "商品が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminProducts"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateProductRequest>,
+) -> Result<Json<AdminProductResponse>> {
+    println!("->> UpdateProductController::handle - product_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let product = dispatcher
+        .execute_update_product_command(request.to_command(id))
+        .await?;
+
+    Ok(Json(AdminProductPresenter::present(product)))
+}
@@ -0,0 +1,60 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::put};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::admin_products::presenters::AdminProductPresenter;
+use crate::presentation::admin_products::requests::UpdateProductSkuRequest;
+use crate::presentation::admin_products::responses::AdminProductResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Update Product SKU Controller - SKU更新の単一責任
+pub struct UpdateProductSkuController;
+
+impl UpdateProductSkuController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/admin/products/{id}/skus/{sku_id}", put(handle))
+    }
+}
+
+/// PUT /admin/products/{id}/skus/{sku_id} - SKU更新処理
+/// 名称・属性・価格・ステータス・表示順を更新する（在庫数は変更しない）
+#[utoipa::path(
+    put,
+    path = "/admin/products/{id}/skus/{sku_id}",
+    operation_id = "update_product_sku",
+    params(
+        ("id" = String, Path, description = "商品ID", example = "550e8400-e29b-41d4-a716-446655440000"),
+        ("sku_id" = String, Path, description = "SKU ID", example = "550e8400-e29b-41d4-a716-446655440001")
+    ),
+    request_body = UpdateProductSkuRequest,
+    responses(
+        (status = 200, description = "SKU更新成功", body = AdminProductResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "商品またはSKUが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "AdminProducts"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path((id, sku_id)): Path<(String, String)>,
+    ValidatedJson(request): ValidatedJson<UpdateProductSkuRequest>,
+) -> Result<Json<AdminProductResponse>> {
+    println!(
+        "->> UpdateProductSkuController::handle - product_id: {}, sku_id: {}",
+        id, sku_id
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let product = dispatcher
+        .execute_update_product_sku_command(request.to_command(id, sku_id))
+        .await?;
+
+    Ok(Json(AdminProductPresenter::present(product)))
+}
@@ -0,0 +1,134 @@
+use crate::application::dto::{
+    AdminPriceScheduleDTO, AdminProductDTO, AdminProductImageDTO, AdminProductSkuDTO,
+};
+use crate::presentation::admin_products::responses::{
+    AdminPriceScheduleResponse, AdminProductImageResponse, AdminProductResponse,
+    AdminProductSkuResponse,
+};
+
+/// /admin/products API専用プレゼンター
+pub struct AdminProductPresenter;
+
+impl AdminProductPresenter {
+    /// AdminProductDTOをAdminProductResponseに変換
+    pub fn present(product: AdminProductDTO) -> AdminProductResponse {
+        AdminProductResponse {
+            id: product.id,
+            name: product.name,
+            description: product.description,
+            category_id: product.category_id,
+            is_best_seller: product.is_best_seller,
+            is_quick_ship: product.is_quick_ship,
+            is_available: product.is_available,
+            skus: product
+                .skus
+                .into_iter()
+                .map(|sku: AdminProductSkuDTO| AdminProductSkuResponse {
+                    id: sku.id,
+                    sku_code: sku.sku_code,
+                    name: sku.name,
+                    color_id: sku.color_id,
+                    dimensions: sku.dimensions,
+                    material: sku.material,
+                    base_price: sku.base_price,
+                    sale_price: sku.sale_price,
+                    price_schedules: sku
+                        .price_schedules
+                        .into_iter()
+                        .map(
+                            |schedule: AdminPriceScheduleDTO| AdminPriceScheduleResponse {
+                                sale_price: schedule.sale_price,
+                                starts_at: schedule.starts_at.to_rfc3339(),
+                                ends_at: schedule.ends_at.to_rfc3339(),
+                                is_active: schedule.is_active,
+                            },
+                        )
+                        .collect(),
+                    stock_quantity: sku.stock_quantity,
+                    reserved_quantity: sku.reserved_quantity,
+                    status: sku.status,
+                    display_order: sku.display_order,
+                    tax_category: sku.tax_category,
+                })
+                .collect(),
+            images: product
+                .images
+                .into_iter()
+                .map(|image: AdminProductImageDTO| AdminProductImageResponse {
+                    url: image.url,
+                    alt_text: image.alt_text,
+                    display_order: image.display_order,
+                })
+                .collect(),
+            tags: product.tags,
+            created_at: product.created_at.to_rfc3339(),
+            updated_at: product.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_present_admin_product() {
+        let timestamp = Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+        let dto = AdminProductDTO {
+            id: "product-1".to_string(),
+            name: "デスク".to_string(),
+            description: "説明".to_string(),
+            category_id: "desks".to_string(),
+            is_best_seller: false,
+            is_quick_ship: true,
+            is_available: false,
+            skus: vec![AdminProductSkuDTO {
+                id: "sku-1".to_string(),
+                sku_code: "DESK-001".to_string(),
+                name: "ウォールナット".to_string(),
+                color_id: Some(1),
+                dimensions: None,
+                material: Some("ウォールナット".to_string()),
+                base_price: 50000,
+                sale_price: Some(45000),
+                price_schedules: vec![AdminPriceScheduleDTO {
+                    sale_price: 40000,
+                    starts_at: timestamp,
+                    ends_at: Utc.with_ymd_and_hms(2024, 5, 8, 9, 0, 0).unwrap(),
+                    is_active: true,
+                }],
+                stock_quantity: 10,
+                reserved_quantity: 2,
+                status: "inactive".to_string(),
+                display_order: 0,
+                tax_category: "reduced".to_string(),
+            }],
+            images: vec![AdminProductImageDTO {
+                url: "/images/desk.jpg".to_string(),
+                alt_text: None,
+                display_order: 0,
+            }],
+            tags: vec!["new".to_string()],
+            created_at: timestamp,
+            updated_at: timestamp,
+        };
+
+        let response = AdminProductPresenter::present(dto);
+
+        assert_eq!(response.id, "product-1");
+        assert!(!response.is_available);
+        assert_eq!(response.skus[0].status, "inactive");
+        assert_eq!(response.skus[0].reserved_quantity, 2);
+        assert_eq!(response.skus[0].sale_price, Some(45000));
+        assert_eq!(response.skus[0].price_schedules[0].sale_price, 40000);
+        assert_eq!(
+            response.skus[0].price_schedules[0].ends_at,
+            "2024-05-08T09:00:00+00:00"
+        );
+        assert_eq!(response.skus[0].tax_category, "reduced");
+        assert_eq!(response.images[0].url, "/images/desk.jpg");
+        assert_eq!(response.tags, vec!["new".to_string()]);
+        assert_eq!(response.created_at, "2024-05-01T09:00:00+00:00");
+    }
+}
@@ -0,0 +1,3 @@
+mod admin_product_presenter;
+
+pub use admin_product_presenter::AdminProductPresenter;
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::CreateProductCommand;
+use crate::presentation::admin_products::requests::{ProductImageRequest, ProductSkuRequest};
+
+/// 商品作成リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProductRequest {
+    /// 商品名
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Product name must be between 1 and 200 characters"
+    ))]
+    #[schema(example = "ウォールナット デスク")]
+    pub name: String,
+    /// 商品説明
+    #[serde(default)]
+    pub description: String,
+    /// カテゴリID
+    #[validate(length(min = 1, message = "Category ID cannot be empty"))]
+    #[schema(example = "desks")]
+    pub category_id: String,
+    /// ベストセラー表示
+    #[serde(default)]
+    pub is_best_seller: bool,
+    /// 即納対象
+    #[serde(default)]
+    pub is_quick_ship: bool,
+    /// 商品画像
+    #[serde(default)]
+    #[validate(nested)]
+    pub images: Vec<ProductImageRequest>,
+    /// タグのスラッグ
+    #[serde(default)]
+    #[schema(example = json!(["new"]))]
+    pub tags: Vec<String>,
+    /// SKU
+    #[serde(default)]
+    #[validate(nested)]
+    pub skus: Vec<ProductSkuRequest>,
+    /// 作成と同時に公開する（省略時は非公開で作成）
+    #[serde(default)]
+    pub publish: bool,
+}
+
+impl CreateProductRequest {
+    pub fn to_command(&self) -> CreateProductCommand {
+        CreateProductCommand {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            category_id: self.category_id.clone(),
+            is_best_seller: self.is_best_seller,
+            is_quick_ship: self.is_quick_ship,
+            images: self
+                .images
+                .iter()
+                .map(ProductImageRequest::to_item)
+                .collect(),
+            tag_slugs: self.tags.clone(),
+            skus: self.skus.iter().map(ProductSkuRequest::to_item).collect(),
+            publish: self.publish,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sku_request(base_price: u32) -> ProductSkuRequest {
+        ProductSkuRequest {
+            sku_code: "DESK-001".to_string(),
+            name: "ウォールナット".to_string(),
+            color_id: 1,
+            dimensions: None,
+            material: None,
+            base_price,
+            sale_price: None,
+            initial_stock: 5,
+            display_order: None,
+            tax_category: None,
+        }
+    }
+
+    #[test]
+    fn test_defaults_are_applied_when_deserializing() {
+        let request: CreateProductRequest =
+            serde_json::from_str(r#"{"name":"デスク","categoryId":"desks"}"#).unwrap();
+
+        assert!(request.validate().is_ok());
+        assert!(!request.publish);
+        assert!(request.skus.is_empty());
+
+        let command = request.to_command();
+        assert_eq!(command.name, "デスク");
+        assert_eq!(command.category_id, "desks");
+        assert!(command.tag_slugs.is_empty());
+    }
+
+    #[test]
+    fn test_converts_skus_and_images_to_command() {
+        let request = CreateProductRequest {
+            name: "デスク".to_string(),
+            description: "説明".to_string(),
+            category_id: "desks".to_string(),
+            is_best_seller: true,
+            is_quick_ship: false,
+            images: vec![ProductImageRequest {
+                url: "/images/desk.jpg".to_string(),
+                alt_text: Some("デスク".to_string()),
+            }],
+            tags: vec!["new".to_string()],
+            skus: vec![sku_request(50000)],
+            publish: true,
+        };
+        assert!(request.validate().is_ok());
+
+        let command = request.to_command();
+        assert_eq!(command.skus.len(), 1);
+        assert_eq!(command.skus[0].sku_code, "DESK-001");
+        assert_eq!(command.skus[0].initial_stock, 5);
+        assert_eq!(command.images[0].url, "/images/desk.jpg");
+        assert_eq!(command.tag_slugs, vec!["new".to_string()]);
+        assert!(command.publish);
+    }
+
+    #[test]
+    fn test_invalid_sku_fails_validation() {
+        let request = CreateProductRequest {
+            name: "デスク".to_string(),
+            description: String::new(),
+            category_id: "desks".to_string(),
+            is_best_seller: false,
+            is_quick_ship: false,
+            images: vec![],
+            tags: vec![],
+            skus: vec![sku_request(0)],
+            publish: false,
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn test_empty_name_fails_validation() {
+        let request: CreateProductRequest =
+            serde_json::from_str(r#"{"name":"","categoryId":"desks"}"#).unwrap();
+        assert!(request.validate().is_err());
+    }
+}
@@ -0,0 +1,13 @@
+mod create_product_request;
+mod product_image_request;
+mod product_sku_request;
+mod schedule_sku_sale_request;
+mod update_product_request;
+mod update_product_sku_request;
+
+pub use create_product_request::CreateProductRequest;
+pub use product_image_request::ProductImageRequest;
+pub use product_sku_request::ProductSkuRequest;
+pub use schedule_sku_sale_request::ScheduleSkuSaleRequest;
+pub use update_product_request::UpdateProductRequest;
+pub use update_product_sku_request::UpdateProductSkuRequest;
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::ProductImageCommandItem;
+
+/// 商品画像リクエスト（配列の順序が表示順になり、先頭がメイン画像）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductImageRequest {
+    /// 画像URL（http(s)または/始まり）
+    #[validate(length(min = 1, message = "Image URL cannot be empty"))]
+    #[schema(example = "/images/products/desk-walnut.jpg")]
+    pub url: String,
+    /// 代替テキスト
+    pub alt_text: Option<String>,
+}
+
+impl ProductImageRequest {
+    pub fn to_item(&self) -> ProductImageCommandItem {
+        ProductImageCommandItem {
+            url: self.url.clone(),
+            alt_text: self.alt_text.clone(),
+        }
+    }
+}
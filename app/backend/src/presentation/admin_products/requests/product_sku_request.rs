@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::ProductSkuCommandItem;
+
+/// SKU作成リクエスト（商品作成・SKU追加共通）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductSkuRequest {
+    /// SKUコード（全商品で一意）
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "SKU code must be between 1 and 50 characters"
+    ))]
+    #[schema(example = "DESK-WALNUT-120")]
+    pub sku_code: String,
+    /// SKU名
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "SKU name must be between 1 and 200 characters"
+    ))]
+    #[schema(example = "ウォールナット / W120")]
+    pub name: String,
+    /// カラーID
+    #[validate(range(min = 1, message = "Color ID must be positive"))]
+    #[schema(example = 1)]
+    pub color_id: u32,
+    /// サイズ
+    #[schema(example = "W120×D60×H72cm")]
+    pub dimensions: Option<String>,
+    /// 素材
+    #[schema(example = "ウォールナット無垢材")]
+    pub material: Option<String>,
+    /// 基本価格（円）
+    #[validate(range(min = 1, message = "Base price must be positive"))]
+    #[schema(example = 89000)]
+    pub base_price: u32,
+    /// セール価格（円、基本価格未満）
+    #[schema(example = 79000)]
+    pub sale_price: Option<u32>,
+    /// 初期在庫数
+    #[serde(default)]
+    #[schema(example = 10)]
+    pub initial_stock: u32,
+    /// 表示順（省略時は末尾）
+    pub display_order: Option<u32>,
+    /// 税区分（standard: 標準税率 / reduced: 軽減税率、省略時は標準税率）
+    #[schema(example = "standard")]
+    pub tax_category: Option<String>,
+}
+
+impl ProductSkuRequest {
+    pub fn to_item(&self) -> ProductSkuCommandItem {
+        ProductSkuCommandItem {
+            sku_code: self.sku_code.clone(),
+            name: self.name.clone(),
+            color_id: self.color_id,
+            dimensions: self.dimensions.clone(),
+            material: self.material.clone(),
+            base_price: self.base_price,
+            sale_price: self.sale_price,
+            initial_stock: self.initial_stock,
+            display_order: self.display_order,
+            tax_category: self.tax_category.clone(),
+        }
+    }
+}
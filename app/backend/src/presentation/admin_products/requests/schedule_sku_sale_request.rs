@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::ScheduleSkuSaleCommand;
+
+/// SKUの期間指定セール予約リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleSkuSaleRequest {
+    /// セール価格（円、基本価格未満）
+    #[validate(range(min = 1, message = "Sale price must be positive"))]
+    #[schema(example = 79000)]
+    pub sale_price: u32,
+    /// セール開始日時（RFC3339）
+    #[schema(example = "2025-07-01T00:00:00+09:00")]
+    pub starts_at: String,
+    /// セール終了日時（RFC3339、この日時を含まない）
+    #[schema(example = "2025-07-08T00:00:00+09:00")]
+    pub ends_at: String,
+}
+
+impl ScheduleSkuSaleRequest {
+    pub fn to_command(&self, product_id: String, sku_id: String) -> ScheduleSkuSaleCommand {
+        ScheduleSkuSaleCommand {
+            product_id,
+            sku_id,
+            sale_price: self.sale_price,
+            starts_at: self.starts_at.clone(),
+            ends_at: self.ends_at.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converts_to_command() {
+        let request: ScheduleSkuSaleRequest = serde_json::from_str(
+            r#"{"salePrice":79000,"startsAt":"2025-07-01T00:00:00+09:00","endsAt":"2025-07-08T00:00:00+09:00"}"#,
+        )
+        .unwrap();
+        assert!(request.validate().is_ok());
+
+        let command = request.to_command("product-1".to_string(), "sku-1".to_string());
+        assert_eq!(command.product_id, "product-1");
+        assert_eq!(command.sku_id, "sku-1");
+        assert_eq!(command.sale_price, 79000);
+        assert_eq!(command.ends_at, "2025-07-08T00:00:00+09:00");
+    }
+
+    #[test]
+    fn test_zero_sale_price_fails_validation() {
+        let request: ScheduleSkuSaleRequest = serde_json::from_str(
+            r#"{"salePrice":0,"startsAt":"2025-07-01T00:00:00+09:00","endsAt":"2025-07-08T00:00:00+09:00"}"#,
+        )
+        .unwrap();
+        assert!(request.validate().is_err());
+    }
+}
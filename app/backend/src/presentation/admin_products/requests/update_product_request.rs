@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::UpdateProductCommand;
+use crate::presentation::admin_products::requests::ProductImageRequest;
+
+/// 商品更新リクエスト
+/// 省略した項目は変更しない。images・tagsを指定した場合は全体を置き換える
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProductRequest {
+    /// 商品名
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Product name must be between 1 and 200 characters"
+    ))]
+    pub name: Option<String>,
+    /// 商品説明
+    pub description: Option<String>,
+    /// カテゴリID
+    #[validate(length(min = 1, message = "Category ID cannot be empty"))]
+    pub category_id: Option<String>,
+    /// ベストセラー表示
+    pub is_best_seller: Option<bool>,
+    /// 即納対象
+    pub is_quick_ship: Option<bool>,
+    /// 商品画像（指定時は全置換）
+    #[validate(nested)]
+    pub images: Option<Vec<ProductImageRequest>>,
+    /// タグのスラッグ（指定時は全置換）
+    pub tags: Option<Vec<String>>,
+}
+
+impl UpdateProductRequest {
+    pub fn to_command(&self, product_id: String) -> UpdateProductCommand {
+        UpdateProductCommand {
+            product_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            category_id: self.category_id.clone(),
+            is_best_seller: self.is_best_seller,
+            is_quick_ship: self.is_quick_ship,
+            images: self
+                .images
+                .as_ref()
+                .map(|images| images.iter().map(ProductImageRequest::to_item).collect()),
+            tag_slugs: self.tags.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_omitted_fields_are_left_unchanged() {
+        let request: UpdateProductRequest =
+            serde_json::from_str(r#"{"isBestSeller":true}"#).unwrap();
+        assert!(request.validate().is_ok());
+
+        let command = request.to_command("product-1".to_string());
+        assert_eq!(command.product_id, "product-1");
+        assert_eq!(command.is_best_seller, Some(true));
+        assert!(command.name.is_none());
+        assert!(command.images.is_none());
+        assert!(command.tag_slugs.is_none());
+    }
+
+    #[test]
+    fn test_empty_tags_clear_all_tags() {
+        let request: UpdateProductRequest = serde_json::from_str(r#"{"tags":[]}"#).unwrap();
+        let command = request.to_command("product-1".to_string());
+        assert_eq!(command.tag_slugs, Some(vec![]));
+    }
+
+    #[test]
+    fn test_empty_name_fails_validation() {
+        let request: UpdateProductRequest = serde_json::from_str(r#"{"name":""}"#).unwrap();
+        assert!(request.validate().is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::UpdateProductSkuCommand;
+
+/// SKU更新リクエスト
+/// 省略した項目は変更しない。在庫数は変更できない
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProductSkuRequest {
+    /// SKU名
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "SKU name must be between 1 and 200 characters"
+    ))]
+    pub name: Option<String>,
+    /// カラーID
+    #[validate(range(min = 1, message = "Color ID must be positive"))]
+    pub color_id: Option<u32>,
+    /// サイズ
+    pub dimensions: Option<String>,
+    /// 素材
+    pub material: Option<String>,
+    /// 基本価格（円）
+    #[validate(range(min = 1, message = "Base price must be positive"))]
+    pub base_price: Option<u32>,
+    /// セール価格（円、基本価格未満）
+    pub sale_price: Option<u32>,
+    /// trueの場合はセール価格を解除する
+    #[serde(default)]
+    pub clear_sale_price: bool,
+    /// ステータス
+    #[schema(example = "active")]
+    pub status: Option<String>,
+    /// 表示順
+    pub display_order: Option<u32>,
+    /// 税区分（standard: 標準税率 / reduced: 軽減税率）
+    #[schema(example = "reduced")]
+    pub tax_category: Option<String>,
+}
+
+impl UpdateProductSkuRequest {
+    pub fn to_command(&self, product_id: String, sku_id: String) -> UpdateProductSkuCommand {
+        UpdateProductSkuCommand {
+            product_id,
+            sku_id,
+            name: self.name.clone(),
+            color_id: self.color_id,
+            dimensions: self.dimensions.clone(),
+            material: self.material.clone(),
+            base_price: self.base_price,
+            sale_price: self.sale_price,
+            clear_sale_price: self.clear_sale_price,
+            status: self.status.clone(),
+            display_order: self.display_order,
+            tax_category: self.tax_category.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converts_to_command() {
+        let request: UpdateProductSkuRequest = serde_json::from_str(
+            r#"{"basePrice":12000,"clearSalePrice":true,"status":"inactive","taxCategory":"reduced"}"#,
+        )
+        .unwrap();
+        assert!(request.validate().is_ok());
+
+        let command = request.to_command("product-1".to_string(), "sku-1".to_string());
+        assert_eq!(command.product_id, "product-1");
+        assert_eq!(command.sku_id, "sku-1");
+        assert_eq!(command.base_price, Some(12000));
+        assert!(command.clear_sale_price);
+        assert_eq!(command.status.as_deref(), Some("inactive"));
+        assert!(command.sale_price.is_none());
+        assert_eq!(command.tax_category.as_deref(), Some("reduced"));
+    }
+
+    #[test]
+    fn test_zero_base_price_fails_validation() {
+        let request: UpdateProductSkuRequest = serde_json::from_str(r#"{"basePrice":0}"#).unwrap();
+        assert!(request.validate().is_err());
+    }
+}
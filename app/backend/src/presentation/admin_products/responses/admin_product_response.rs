@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// /admin/products API共通の商品レスポンス
+/// 非公開の商品・販売停止中のSKUも含む
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminProductResponse {
+    /// 商品ID
+    pub id: String,
+    /// 商品名
+    pub name: String,
+    /// 商品説明
+    pub description: String,
+    /// カテゴリID
+    pub category_id: String,
+    /// ベストセラー表示
+    pub is_best_seller: bool,
+    /// 即納対象
+    pub is_quick_ship: bool,
+    /// 公開中かどうか
+    pub is_available: bool,
+    /// SKU
+    pub skus: Vec<AdminProductSkuResponse>,
+    /// 商品画像（表示順）
+    pub images: Vec<AdminProductImageResponse>,
+    /// タグのスラッグ
+    pub tags: Vec<String>,
+    /// 作成日時（RFC3339）
+    pub created_at: String,
+    /// 更新日時（RFC3339）
+    pub updated_at: String,
+}
+
+/// 管理用SKUレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminProductSkuResponse {
+    /// SKU ID
+    pub id: String,
+    /// SKUコード
+    pub sku_code: String,
+    /// SKU名
+    pub name: String,
+    /// カラーID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub color_id: Option<u32>,
+    /// サイズ
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub dimensions: Option<String>,
+    /// 素材
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub material: Option<String>,
+    /// 基本価格（円）
+    pub base_price: u32,
+    /// セール価格（円）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub sale_price: Option<u32>,
+    /// 期間指定のセール価格（開始日時順、期間中は手動のセール価格より優先する）
+    pub price_schedules: Vec<AdminPriceScheduleResponse>,
+    /// 在庫数
+    pub stock_quantity: u32,
+    /// 引当済み数
+    pub reserved_quantity: u32,
+    /// ステータス（active / inactive / discontinued）
+    pub status: String,
+    /// 表示順
+    pub display_order: u32,
+    /// 税区分（standard: 標準税率 / reduced: 軽減税率）
+    pub tax_category: String,
+}
+
+/// 管理用価格スケジュールレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminPriceScheduleResponse {
+    /// セール価格（円）
+    pub sale_price: u32,
+    /// 開始日時（RFC3339）
+    pub starts_at: String,
+    /// 終了日時（RFC3339、この日時を含まない）
+    pub ends_at: String,
+    /// 現在適用中かどうか
+    pub is_active: bool,
+}
+
+/// 管理用商品画像レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminProductImageResponse {
+    /// 画像URL
+    pub url: String,
+    /// 代替テキスト
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub alt_text: Option<String>,
+    /// 表示順
+    pub display_order: u32,
+}
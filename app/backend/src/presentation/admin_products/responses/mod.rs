@@ -0,0 +1,6 @@
+mod admin_product_response;
+
+pub use admin_product_response::{
+    AdminPriceScheduleResponse, AdminProductImageResponse, AdminProductResponse,
+    AdminProductSkuResponse,
+};
@@ -0,0 +1,24 @@
+use crate::infrastructure::Container;
+use crate::presentation::admin_products::controllers::{
+    AddProductSkuController, CreateProductController, DeleteProductController,
+    GetAdminProductController, PublishProductController, RemoveProductSkuController,
+    ScheduleSkuSaleController, UnpublishProductController, UpdateProductController,
+    UpdateProductSkuController,
+};
+use axum::Router;
+use std::sync::Arc;
+
+/// 商品管理関連のルーティング
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(CreateProductController::routes())
+        .merge(GetAdminProductController::routes())
+        .merge(UpdateProductController::routes())
+        .merge(DeleteProductController::routes())
+        .merge(PublishProductController::routes())
+        .merge(UnpublishProductController::routes())
+        .merge(AddProductSkuController::routes())
+        .merge(UpdateProductSkuController::routes())
+        .merge(RemoveProductSkuController::routes())
+        .merge(ScheduleSkuSaleController::routes())
+}
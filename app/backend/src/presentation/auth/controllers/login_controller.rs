@@ -0,0 +1,54 @@
+use axum::extract::State;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::auth::presenters::AuthPresenter;
+use crate::presentation::auth::requests::LoginRequest;
+use crate::presentation::auth::responses::SessionResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Login Controller - ログインの単一責任
+pub struct LoginController;
+
+impl LoginController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/auth/login", post(handle))
+    }
+}
+
+/// POST /auth/login - ログイン処理
+/// メールアドレスとパスワードが一致した場合に新しいセッショントークンを発行する
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    operation_id = "login_customer",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "ログイン成功", body = SessionResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "メールアドレスまたはパスワードが違います", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedJson(request): ValidatedJson<LoginRequest>,
+) -> Result<Json<SessionResponse>> {
+    println!("->> LoginController::handle - email: {}", request.email);
+
+    let dispatcher = container.get_dispatcher();
+    let session = dispatcher
+        .execute_login_customer_command(request.to_command())
+        .await?;
+
+    println!(
+        "->> LoginController::handle - success, customer_id: {}",
+        session.customer.id
+    );
+    Ok(Json(AuthPresenter::present_session(session)))
+}
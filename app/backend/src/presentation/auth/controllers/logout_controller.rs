@@ -0,0 +1,51 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::{Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::LogoutCustomerCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::AuthenticatedCustomer;
+
+/// Logout Controller - ログアウトの単一責任
+pub struct LogoutController;
+
+impl LogoutController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/auth/logout", post(handle))
+    }
+}
+
+/// POST /auth/logout - ログアウト処理
+/// リクエストのセッショントークンを失効させる
+#[utoipa::path(
+    post,
+    path = "/auth/logout",
+    operation_id = "logout_customer",
+    responses(
+        (status = 204, description = "ログアウト成功"),
+        (status = 401, description = "セッショントークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Auth"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    authenticated: AuthenticatedCustomer,
+) -> Result<StatusCode> {
+    println!(
+        "->> LogoutController::handle - customer_id: {}",
+        authenticated.customer.id
+    );
+
+    let dispatcher = container.get_dispatcher();
+    dispatcher
+        .execute_logout_customer_command(LogoutCustomerCommand::new(authenticated.token))
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,7 @@
+pub mod login_controller;
+pub mod logout_controller;
+pub mod register_controller;
+
+pub use login_controller::LoginController;
+pub use logout_controller::LogoutController;
+pub use register_controller::RegisterController;
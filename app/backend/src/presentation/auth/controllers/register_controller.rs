@@ -0,0 +1,58 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::auth::presenters::AuthPresenter;
+use crate::presentation::auth::requests::RegisterRequest;
+use crate::presentation::auth::responses::SessionResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Register Controller - 会員登録の単一責任
+pub struct RegisterController;
+
+impl RegisterController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/auth/register", post(handle))
+    }
+}
+
+/// POST /auth/register - 会員登録処理
+/// 登録後はそのままログインした状態のセッショントークンを返す
+#[utoipa::path(
+    post,
+    path = "/auth/register",
+    operation_id = "register_customer",
+    request_body = RegisterRequest,
+    responses(
+        (status = 201, description = "会員登録成功", body = SessionResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 409, description = "メールアドレスが登録済みです", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedJson(request): ValidatedJson<RegisterRequest>,
+) -> Result<(StatusCode, Json<SessionResponse>)> {
+    println!("->> RegisterController::handle - email: {}", request.email);
+
+    let dispatcher = container.get_dispatcher();
+    let session = dispatcher
+        .execute_register_customer_command(request.to_command())
+        .await?;
+
+    println!(
+        "->> RegisterController::handle - success, customer_id: {}",
+        session.customer.id
+    );
+    Ok((
+        StatusCode::CREATED,
+        Json(AuthPresenter::present_session(session)),
+    ))
+}
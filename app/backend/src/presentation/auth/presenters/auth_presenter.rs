@@ -0,0 +1,29 @@
+use crate::application::dto::{CustomerDTO, CustomerSessionDTO};
+use crate::presentation::auth::responses::{CustomerResponse, SessionResponse};
+
+/// /auth API専用プレゼンター
+pub struct AuthPresenter;
+
+impl AuthPresenter {
+    /// CustomerSessionDTOをSessionResponseに変換
+    pub fn present_session(session: CustomerSessionDTO) -> SessionResponse {
+        SessionResponse {
+            token: session.token,
+            token_type: "Bearer".to_string(),
+            expires_at: session.expires_at.to_rfc3339(),
+            customer: Self::present_customer(session.customer),
+        }
+    }
+
+    /// CustomerDTOをCustomerResponseに変換
+    pub fn present_customer(customer: CustomerDTO) -> CustomerResponse {
+        CustomerResponse {
+            id: customer.id,
+            email: customer.email,
+            first_name: customer.first_name,
+            last_name: customer.last_name,
+            phone: customer.phone,
+            created_at: customer.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod auth_presenter;
+
+pub use auth_presenter::AuthPresenter;
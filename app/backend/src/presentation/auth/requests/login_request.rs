@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::LoginCustomerCommand;
+
+/// ログインリクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginRequest {
+    /// メールアドレス
+    #[validate(length(min = 1, message = "Email is required"))]
+    #[schema(example = "taro.yamada@example.com")]
+    pub email: String,
+    /// パスワード
+    #[validate(length(min = 1, message = "Password is required"))]
+    #[schema(example = "correct-horse-battery")]
+    pub password: String,
+}
+
+impl LoginRequest {
+    pub fn to_command(&self) -> LoginCustomerCommand {
+        LoginCustomerCommand {
+            email: self.email.clone(),
+            password: self.password.clone(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod login_request;
+mod register_request;
+
+pub use login_request::LoginRequest;
+pub use register_request::RegisterRequest;
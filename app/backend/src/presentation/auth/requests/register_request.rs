@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::RegisterCustomerCommand;
+
+/// 会員登録リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterRequest {
+    /// メールアドレス（ログインID）
+    #[validate(email(message = "Invalid email format"))]
+    #[schema(example = "taro.yamada@example.com")]
+    pub email: String,
+    /// パスワード（8文字以上）
+    #[validate(length(
+        min = 8,
+        max = 128,
+        message = "Password must be between 8 and 128 characters"
+    ))]
+    #[schema(example = "correct-horse-battery")]
+    pub password: String,
+    /// 名
+    #[validate(length(min = 1, message = "First name is required"))]
+    #[schema(example = "太郎")]
+    pub first_name: String,
+    /// 姓
+    #[validate(length(min = 1, message = "Last name is required"))]
+    #[schema(example = "山田")]
+    pub last_name: String,
+    /// 電話番号
+    #[validate(length(min = 1, message = "Phone number is required"))]
+    #[schema(example = "090-1234-5678")]
+    pub phone: String,
+}
+
+impl RegisterRequest {
+    pub fn to_command(&self) -> RegisterCustomerCommand {
+        RegisterCustomerCommand {
+            email: self.email.clone(),
+            password: self.password.clone(),
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            phone: self.phone.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(password: &str) -> RegisterRequest {
+        serde_json::from_value(serde_json::json!({
+            "email": "taro.yamada@example.com",
+            "password": password,
+            "firstName": "太郎",
+            "lastName": "山田",
+            "phone": "090-1234-5678"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_deserializes_camel_case_into_command() {
+        let request = request("correct-horse-battery");
+        assert!(request.validate().is_ok());
+
+        let command = request.to_command();
+        assert_eq!(command.email, "taro.yamada@example.com");
+        assert_eq!(command.first_name, "太郎");
+        assert_eq!(command.last_name, "山田");
+    }
+
+    #[test]
+    fn test_rejects_short_password() {
+        assert!(request("short").validate().is_err());
+    }
+}
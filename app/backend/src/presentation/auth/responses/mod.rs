@@ -0,0 +1,3 @@
+mod session_response;
+
+pub use session_response::{CustomerResponse, SessionResponse};
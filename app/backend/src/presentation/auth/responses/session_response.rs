@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 会員情報レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerResponse {
+    /// 会員ID
+    pub id: String,
+    /// メールアドレス
+    pub email: String,
+    /// 名
+    pub first_name: String,
+    /// 姓
+    pub last_name: String,
+    /// 電話番号
+    pub phone: String,
+    /// 登録日時
+    pub created_at: String,
+}
+
+/// ログインセッションレスポンス（会員登録・ログイン共通）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionResponse {
+    /// セッショントークン（`Authorization: Bearer <token>` で送信する）
+    pub token: String,
+    /// トークン種別
+    #[schema(example = "Bearer")]
+    pub token_type: String,
+    /// 有効期限
+    pub expires_at: String,
+    /// ログイン中の会員
+    pub customer: CustomerResponse,
+}
@@ -0,0 +1,14 @@
+use crate::infrastructure::Container;
+use crate::presentation::auth::controllers::{
+    LoginController, LogoutController, RegisterController,
+};
+use axum::Router;
+use std::sync::Arc;
+
+/// 会員認証関連のルーティング
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(RegisterController::routes())
+        .merge(LoginController::routes())
+        .merge(LogoutController::routes())
+}
@@ -0,0 +1,58 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::cart::presenters::GetCartPresenter;
+use crate::presentation::cart::requests::AddCartItemRequest;
+use crate::presentation::cart::responses::GetCartResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Add Cart Item Controller - カートアイテム追加の単一責任
+pub struct AddCartItemController;
+
+impl AddCartItemController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/carts/{id}/items", post(handle))
+    }
+}
+
+/// POST /carts/{id}/items - カートアイテム追加処理
+/// SKUをカートに追加する（同じSKUが既にあれば数量を加算）
+#[utoipa::path(
+    post,
+    path = "/carts/{id}/items",
+    operation_id = "add_cart_item",
+    params(
+        ("id" = String, Path, description = "カートID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = AddCartItemRequest,
+    responses(
+        (status = 200, description = "カートアイテム追加成功", body = GetCartResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "カートまたはSKUが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Cart"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<AddCartItemRequest>,
+) -> Result<Json<GetCartResponse>> {
+    println!("->> AddCartItemController::handle - cart_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let cart = dispatcher
+        .execute_add_cart_item_command(request.to_command(id))
+        .await?;
+
+    println!(
+        "->> AddCartItemController::handle - success, cart_id: {}",
+        cart.id
+    );
+    Ok(Json(GetCartPresenter::present(cart)))
+}
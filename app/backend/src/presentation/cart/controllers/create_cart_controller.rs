@@ -0,0 +1,47 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::cart::presenters::GetCartPresenter;
+use crate::presentation::cart::responses::GetCartResponse;
+
+/// Create Cart Controller - カート作成の単一責任
+pub struct CreateCartController;
+
+impl CreateCartController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/carts", post(handle))
+    }
+}
+
+/// POST /carts - カート作成処理
+/// 空のカートを作成する（有効期限切れのカートはこのタイミングで削除される）
+#[utoipa::path(
+    post,
+    path = "/carts",
+    operation_id = "create_cart",
+    responses(
+        (status = 201, description = "カート作成成功", body = GetCartResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Cart"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+) -> Result<(StatusCode, Json<GetCartResponse>)> {
+    println!("->> CreateCartController::handle");
+
+    let dispatcher = container.get_dispatcher();
+    let cart = dispatcher.execute_create_cart_command().await?;
+
+    println!(
+        "->> CreateCartController::handle - success, cart_id: {}",
+        cart.id
+    );
+    Ok((StatusCode::CREATED, Json(GetCartPresenter::present(cart))))
+}
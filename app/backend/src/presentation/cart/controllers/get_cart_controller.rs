@@ -0,0 +1,55 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::queries::models::GetCartQuery;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::cart::presenters::GetCartPresenter;
+use crate::presentation::cart::responses::GetCartResponse;
+
+/// Get Cart Controller - カート取得の単一責任
+pub struct GetCartController;
+
+impl GetCartController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/carts/{id}", get(handle))
+    }
+}
+
+/// GET /carts/{id} - カート取得処理
+/// カートの内容と、現在の価格・選択内容で再計算した合計を返す
+#[utoipa::path(
+    get,
+    path = "/carts/{id}",
+    operation_id = "get_cart",
+    params(
+        ("id" = String, Path, description = "カートID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "カート取得成功", body = GetCartResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "カートが見つからないか有効期限切れです", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Cart"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<GetCartResponse>> {
+    println!("->> GetCartController::handle - cart_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let cart = dispatcher
+        .execute_get_cart_query(GetCartQuery::new(id)?)
+        .await?;
+
+    println!(
+        "->> GetCartController::handle - success, cart_id: {}",
+        cart.id
+    );
+    Ok(Json(GetCartPresenter::present(cart)))
+}
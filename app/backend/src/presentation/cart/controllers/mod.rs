@@ -1,3 +1,21 @@
+pub mod add_cart_item_controller;
 pub mod calculate_cart_controller;
+pub mod create_cart_controller;
+pub mod get_cart_controller;
+pub mod remove_cart_coupon_controller;
+pub mod remove_cart_item_controller;
+pub mod set_cart_coupon_controller;
+pub mod set_cart_payment_method_controller;
+pub mod set_cart_shipping_method_controller;
+pub mod update_cart_item_controller;
 
+pub use add_cart_item_controller::AddCartItemController;
 pub use calculate_cart_controller::CalculateCartController;
+pub use create_cart_controller::CreateCartController;
+pub use get_cart_controller::GetCartController;
+pub use remove_cart_coupon_controller::RemoveCartCouponController;
+pub use remove_cart_item_controller::RemoveCartItemController;
+pub use set_cart_coupon_controller::SetCartCouponController;
+pub use set_cart_payment_method_controller::SetCartPaymentMethodController;
+pub use set_cart_shipping_method_controller::SetCartShippingMethodController;
+pub use update_cart_item_controller::UpdateCartItemController;
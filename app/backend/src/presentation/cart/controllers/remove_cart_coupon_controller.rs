@@ -0,0 +1,55 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::delete};
+use std::sync::Arc;
+
+use crate::application::commands::models::SetCartCouponCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::cart::presenters::GetCartPresenter;
+use crate::presentation::cart::responses::GetCartResponse;
+
+/// Remove Cart Coupon Controller - カートクーポン取り外しの単一責任
+pub struct RemoveCartCouponController;
+
+impl RemoveCartCouponController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/carts/{id}/coupon", delete(handle))
+    }
+}
+
+/// DELETE /carts/{id}/coupon - カートクーポン取り外し処理
+/// カートに適用中のクーポンを取り外す
+#[utoipa::path(
+    delete,
+    path = "/carts/{id}/coupon",
+    operation_id = "remove_cart_coupon",
+    params(
+        ("id" = String, Path, description = "カートID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "クーポン取り外し成功", body = GetCartResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "カートが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Cart"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<GetCartResponse>> {
+    println!("->> RemoveCartCouponController::handle - cart_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let cart = dispatcher
+        .execute_set_cart_coupon_command(SetCartCouponCommand::new(id, None))
+        .await?;
+
+    println!(
+        "->> RemoveCartCouponController::handle - success, cart_id: {}",
+        cart.id
+    );
+    Ok(Json(GetCartPresenter::present(cart)))
+}
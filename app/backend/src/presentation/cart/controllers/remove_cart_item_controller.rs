@@ -0,0 +1,59 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::delete};
+use std::sync::Arc;
+
+use crate::application::commands::models::RemoveCartItemCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::cart::presenters::GetCartPresenter;
+use crate::presentation::cart::responses::GetCartResponse;
+
+/// Remove Cart Item Controller - カートアイテム削除の単一責任
+pub struct RemoveCartItemController;
+
+impl RemoveCartItemController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/carts/{id}/items/{sku_id}", delete(handle))
+    }
+}
+
+/// DELETE /carts/{id}/items/{sku_id} - カートアイテム削除処理
+/// カートからSKUを削除する
+#[utoipa::path(
+    delete,
+    path = "/carts/{id}/items/{sku_id}",
+    operation_id = "remove_cart_item",
+    params(
+        ("id" = String, Path, description = "カートID", example = "550e8400-e29b-41d4-a716-446655440000"),
+        ("sku_id" = String, Path, description = "SKU ID", example = "550e8400-e29b-41d4-a716-446655440001")
+    ),
+    responses(
+        (status = 200, description = "カートアイテム削除成功", body = GetCartResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "カートまたはカート内のSKUが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Cart"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path((id, sku_id)): Path<(String, String)>,
+) -> Result<Json<GetCartResponse>> {
+    println!(
+        "->> RemoveCartItemController::handle - cart_id: {}, sku_id: {}",
+        id, sku_id
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let cart = dispatcher
+        .execute_remove_cart_item_command(RemoveCartItemCommand::new(id, sku_id))
+        .await?;
+
+    println!(
+        "->> RemoveCartItemController::handle - success, cart_id: {}",
+        cart.id
+    );
+    Ok(Json(GetCartPresenter::present(cart)))
+}
@@ -0,0 +1,58 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::put};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::cart::presenters::GetCartPresenter;
+use crate::presentation::cart::requests::SetCartCouponRequest;
+use crate::presentation::cart::responses::GetCartResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Set Cart Coupon Controller - カートクーポン設定の単一責任
+pub struct SetCartCouponController;
+
+impl SetCartCouponController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/carts/{id}/coupon", put(handle))
+    }
+}
+
+/// PUT /carts/{id}/coupon - カートクーポン設定処理
+/// カートにクーポンを適用する（適用条件を満たさない場合はカートを変更せずエラー）
+#[utoipa::path(
+    put,
+    path = "/carts/{id}/coupon",
+    operation_id = "set_cart_coupon",
+    params(
+        ("id" = String, Path, description = "カートID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = SetCartCouponRequest,
+    responses(
+        (status = 200, description = "クーポン設定成功", body = GetCartResponse),
+        (status = 400, description = "リクエストが無効か、クーポンを適用できません", body = ErrorResponse),
+        (status = 404, description = "カートまたはクーポンが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Cart"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<SetCartCouponRequest>,
+) -> Result<Json<GetCartResponse>> {
+    println!("->> SetCartCouponController::handle - cart_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let cart = dispatcher
+        .execute_set_cart_coupon_command(request.to_command(id))
+        .await?;
+
+    println!(
+        "->> SetCartCouponController::handle - success, cart_id: {}",
+        cart.id
+    );
+    Ok(Json(GetCartPresenter::present(cart)))
+}
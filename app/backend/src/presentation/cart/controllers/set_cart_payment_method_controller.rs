@@ -0,0 +1,61 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::put};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::cart::presenters::GetCartPresenter;
+use crate::presentation::cart::requests::SetCartPaymentMethodRequest;
+use crate::presentation::cart::responses::GetCartResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Set Cart Payment Method Controller - カート支払い方法設定の単一責任
+pub struct SetCartPaymentMethodController;
+
+impl SetCartPaymentMethodController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/carts/{id}/payment-method", put(handle))
+    }
+}
+
+/// PUT /carts/{id}/payment-method - カート支払い方法設定処理
+/// カートの支払い方法を設定し、支払い手数料を反映する
+#[utoipa::path(
+    put,
+    path = "/carts/{id}/payment-method",
+    operation_id = "set_cart_payment_method",
+    params(
+        ("id" = String, Path, description = "カートID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = SetCartPaymentMethodRequest,
+    responses(
+        (status = 200, description = "支払い方法設定成功", body = GetCartResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "カートまたは支払い方法が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Cart"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<SetCartPaymentMethodRequest>,
+) -> Result<Json<GetCartResponse>> {
+    println!(
+        "->> SetCartPaymentMethodController::handle - cart_id: {}",
+        id
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let cart = dispatcher
+        .execute_set_cart_payment_method_command(request.to_command(id))
+        .await?;
+
+    println!(
+        "->> SetCartPaymentMethodController::handle - success, cart_id: {}",
+        cart.id
+    );
+    Ok(Json(GetCartPresenter::present(cart)))
+}
@@ -0,0 +1,61 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::put};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::cart::presenters::GetCartPresenter;
+use crate::presentation::cart::requests::SetCartShippingMethodRequest;
+use crate::presentation::cart::responses::GetCartResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Set Cart Shipping Method Controller - カート配送方法設定の単一責任
+pub struct SetCartShippingMethodController;
+
+impl SetCartShippingMethodController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/carts/{id}/shipping-method", put(handle))
+    }
+}
+
+/// PUT /carts/{id}/shipping-method - カート配送方法設定処理
+/// カートの配送方法を設定し、配送料を反映する
+#[utoipa::path(
+    put,
+    path = "/carts/{id}/shipping-method",
+    operation_id = "set_cart_shipping_method",
+    params(
+        ("id" = String, Path, description = "カートID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = SetCartShippingMethodRequest,
+    responses(
+        (status = 200, description = "配送方法設定成功", body = GetCartResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "カートまたは配送方法が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Cart"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<SetCartShippingMethodRequest>,
+) -> Result<Json<GetCartResponse>> {
+    println!(
+        "->> SetCartShippingMethodController::handle - cart_id: {}",
+        id
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let cart = dispatcher
+        .execute_set_cart_shipping_method_command(request.to_command(id))
+        .await?;
+
+    println!(
+        "->> SetCartShippingMethodController::handle - success, cart_id: {}",
+        cart.id
+    );
+    Ok(Json(GetCartPresenter::present(cart)))
+}
@@ -0,0 +1,62 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::put};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::cart::presenters::GetCartPresenter;
+use crate::presentation::cart::requests::UpdateCartItemRequest;
+use crate::presentation::cart::responses::GetCartResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+
+/// Update Cart Item Controller - カートアイテム数量変更の単一責任
+pub struct UpdateCartItemController;
+
+impl UpdateCartItemController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/carts/{id}/items/{sku_id}", put(handle))
+    }
+}
+
+/// PUT /carts/{id}/items/{sku_id} - カートアイテム数量変更処理
+/// カート内のSKUの数量を変更する（0の場合は削除）
+#[utoipa::path(
+    put,
+    path = "/carts/{id}/items/{sku_id}",
+    operation_id = "update_cart_item",
+    params(
+        ("id" = String, Path, description = "カートID", example = "550e8400-e29b-41d4-a716-446655440000"),
+        ("sku_id" = String, Path, description = "SKU ID", example = "550e8400-e29b-41d4-a716-446655440001")
+    ),
+    request_body = UpdateCartItemRequest,
+    responses(
+        (status = 200, description = "数量変更成功", body = GetCartResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "カートまたはカート内のSKUが見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Cart"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path((id, sku_id)): Path<(String, String)>,
+    ValidatedJson(request): ValidatedJson<UpdateCartItemRequest>,
+) -> Result<Json<GetCartResponse>> {
+    println!(
+        "->> UpdateCartItemController::handle - cart_id: {}, sku_id: {}",
+        id, sku_id
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let cart = dispatcher
+        .execute_update_cart_item_command(request.to_command(id, sku_id))
+        .await?;
+
+    println!(
+        "->> UpdateCartItemController::handle - success, cart_id: {}",
+        cart.id
+    );
+    Ok(Json(GetCartPresenter::present(cart)))
+}
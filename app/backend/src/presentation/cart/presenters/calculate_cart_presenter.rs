@@ -1,5 +1,6 @@
 use crate::application::dto::CalculateCartResultDto;
-use crate::presentation::cart::responses::{AppliedCouponResponse, CalculateCartItemResponse, CalculateCartResponse, CouponErrorResponse};
+use crate::presentation::common::TaxBreakdownResponse;
+use crate::presentation::cart::responses::{AppliedCouponResponse, AppliedPromotionResponse, CalculateCartItemResponse, CalculateCartResponse, CouponErrorResponse};
 
 /// カートプレゼンター
 pub struct CartPresenter;
@@ -19,6 +20,8 @@ impl CartPresenter {
                 unit_price: item.unit_price.yen(),
                 quantity: item.quantity,
                 subtotal: item.subtotal.yen(),
+                discount_amount: item.discount_amount.yen(),
+                discounted_subtotal: item.discounted_subtotal.yen(),
             })
             .collect();
 
@@ -30,6 +33,18 @@ impl CartPresenter {
             message: coupon.message,
         });
 
+        // 自動適用されたプロモーションを変換
+        let applied_promotions = result
+            .applied_promotions
+            .into_iter()
+            .map(|promotion| AppliedPromotionResponse {
+                promotion_id: promotion.promotion_id,
+                name: promotion.name,
+                discount_amount: promotion.discount_amount.yen(),
+                waives_shipping: promotion.waives_shipping,
+            })
+            .collect();
+
         // クーポンエラーを変換（セキュリティのため、全てのクーポンエラーを統一メッセージに変換）
         let coupon_error = result.coupon_error.map(|error| {
             println!("Converting coupon error to user-friendly message. Original: {}", error.error_message);
@@ -45,11 +60,22 @@ impl CartPresenter {
             item_count: result.item_count,
             subtotal: result.subtotal.yen(),
             tax_amount: result.tax_amount.yen(),
-            total: result.total_with_tax.yen(),
+            tax_breakdown: result
+                .tax_breakdown
+                .iter()
+                .map(|breakdown| TaxBreakdownResponse {
+                    rate: breakdown.rate,
+                    taxable_amount: breakdown.taxable_amount.yen(),
+                    tax_amount: breakdown.tax_amount.yen(),
+                })
+                .collect(),
+            tax_included: result.tax_included,
+            total: result.total.yen(),
             is_empty: result.is_empty,
             shipping_fee: result.shipping_fee.yen(),
             payment_fee: result.payment_fee.yen(),
             applied_coupon,
+            applied_promotions,
             coupon_error,
         }
     }
@@ -89,6 +115,7 @@ mod tests {
         assert_eq!(response.shipping_fee, 0);
         assert_eq!(response.payment_fee, 0);
         assert!(response.items.is_empty());
+        assert!(response.applied_promotions.is_empty());
     }
 
     #[test]
@@ -118,6 +145,10 @@ mod tests {
         assert_eq!(response.subtotal, 3500); // (1000 * 2) + (1500 * 1)
         assert_eq!(response.tax_amount, 350); // 10% tax
         assert_eq!(response.total, 3850); // subtotal + tax
+        assert_eq!(response.tax_breakdown.len(), 1);
+        assert_eq!(response.tax_breakdown[0].rate, 10);
+        assert_eq!(response.tax_breakdown[0].taxable_amount, 3500);
+        assert!(!response.tax_included);
         assert_eq!(response.shipping_fee, 0);
         assert_eq!(response.payment_fee, 0);
         assert_eq!(response.items.len(), 2);
@@ -143,5 +174,7 @@ mod tests {
         assert_eq!(item_response.unit_price, 1000);
         assert_eq!(item_response.quantity, 2);
         assert_eq!(item_response.subtotal, 2000);
+        assert_eq!(item_response.discount_amount, 0);
+        assert_eq!(item_response.discounted_subtotal, 2000);
     }
 }
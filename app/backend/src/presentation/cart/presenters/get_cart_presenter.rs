@@ -0,0 +1,19 @@
+use crate::application::dto::CartDTO;
+use crate::presentation::cart::presenters::CartPresenter;
+use crate::presentation::cart::responses::GetCartResponse;
+
+/// /carts API専用プレゼンター
+pub struct GetCartPresenter;
+
+impl GetCartPresenter {
+    /// CartDTOをGetCartResponseに変換
+    pub fn present(cart: CartDTO) -> GetCartResponse {
+        GetCartResponse {
+            id: cart.id,
+            shipping_method_id: cart.shipping_method_id,
+            payment_method_id: cart.payment_method_id,
+            coupon_code: cart.coupon_code,
+            totals: CartPresenter::to_response(cart.totals),
+        }
+    }
+}
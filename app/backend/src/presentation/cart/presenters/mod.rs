@@ -1,3 +1,5 @@
 mod calculate_cart_presenter;
+mod get_cart_presenter;
 
 pub use calculate_cart_presenter::CartPresenter;
+pub use get_cart_presenter::GetCartPresenter;
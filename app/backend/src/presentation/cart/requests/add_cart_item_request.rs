@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::AddCartItemCommand;
+
+/// カートアイテム追加リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct AddCartItemRequest {
+    /// SKU ID
+    #[validate(length(min = 1, message = "SKU ID cannot be empty"))]
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub sku_id: String,
+    /// 追加する数量（カート内に同じSKUがあれば加算）
+    #[validate(range(min = 1, max = 999, message = "Quantity must be between 1 and 999"))]
+    #[schema(example = 1)]
+    pub quantity: u32,
+}
+
+impl AddCartItemRequest {
+    pub fn to_command(&self, cart_id: String) -> AddCartItemCommand {
+        AddCartItemCommand::new(cart_id, self.sku_id.clone(), self.quantity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_request_converts_to_command() {
+        let request = AddCartItemRequest {
+            sku_id: "sku-123".to_string(),
+            quantity: 2,
+        };
+        assert!(request.validate().is_ok());
+
+        let command = request.to_command("cart-1".to_string());
+        assert_eq!(command.cart_id, "cart-1");
+        assert_eq!(command.sku_id, "sku-123");
+        assert_eq!(command.quantity, 2);
+    }
+
+    #[test]
+    fn test_zero_quantity_fails_validation() {
+        let request = AddCartItemRequest {
+            sku_id: "sku-123".to_string(),
+            quantity: 0,
+        };
+        assert!(request.validate().is_err());
+    }
+}
@@ -24,6 +24,8 @@ pub struct CalculateCartRequest {
     #[validate(length(min = 1, message = "Payment method ID cannot be empty"))]
     pub payment_method_id: String,
     pub coupon_code: Option<String>,
+    /// 配送先の都道府県（指定時は地域別の配送料で計算）
+    pub prefecture: Option<String>,
 }
 
 impl CalculateCartRequest {
@@ -43,6 +45,7 @@ impl CalculateCartRequest {
             self.shipping_method_id.clone(),
             self.payment_method_id.clone(),
             self.coupon_code.clone(),
+            self.prefecture.clone(),
         )
     }
 }
@@ -67,6 +70,7 @@ mod tests {
             shipping_method_id: "standard".to_string(),
             payment_method_id: "credit_card".to_string(),
             coupon_code: None,
+            prefecture: None,
         };
 
         assert!(request.validate().is_ok());
@@ -86,6 +90,7 @@ mod tests {
             shipping_method_id: "standard".to_string(),
             payment_method_id: "credit_card".to_string(),
             coupon_code: None,
+            prefecture: None,
         };
 
         assert!(request.validate().is_err());
@@ -101,6 +106,7 @@ mod tests {
             shipping_method_id: "standard".to_string(),
             payment_method_id: "credit_card".to_string(),
             coupon_code: None,
+            prefecture: None,
         };
 
         assert!(request.validate().is_err());
@@ -122,6 +128,7 @@ mod tests {
             shipping_method_id: "standard".to_string(),
             payment_method_id: "credit_card".to_string(),
             coupon_code: None,
+            prefecture: None,
         };
 
         assert!(request.validate().is_err());
@@ -1,3 +1,13 @@
+mod add_cart_item_request;
 mod calculate_cart_request;
+mod set_cart_coupon_request;
+mod set_cart_payment_method_request;
+mod set_cart_shipping_method_request;
+mod update_cart_item_request;
 
+pub use add_cart_item_request::AddCartItemRequest;
 pub use calculate_cart_request::{CalculateCartItemRequest, CalculateCartRequest};
+pub use set_cart_coupon_request::SetCartCouponRequest;
+pub use set_cart_payment_method_request::SetCartPaymentMethodRequest;
+pub use set_cart_shipping_method_request::SetCartShippingMethodRequest;
+pub use update_cart_item_request::UpdateCartItemRequest;
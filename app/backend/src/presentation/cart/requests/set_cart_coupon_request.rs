@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::SetCartCouponCommand;
+
+/// カートクーポン設定リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCartCouponRequest {
+    /// クーポンコード
+    #[validate(length(min = 1, message = "Coupon code cannot be empty"))]
+    #[schema(example = "WELCOME10")]
+    pub coupon_code: String,
+}
+
+impl SetCartCouponRequest {
+    pub fn to_command(&self, cart_id: String) -> SetCartCouponCommand {
+        SetCartCouponCommand::new(cart_id, Some(self.coupon_code.clone()))
+    }
+}
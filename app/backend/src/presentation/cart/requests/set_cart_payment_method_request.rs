@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::SetCartPaymentMethodCommand;
+
+/// カート支払い方法設定リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCartPaymentMethodRequest {
+    /// 支払い方法ID
+    #[validate(length(min = 1, message = "Payment method ID cannot be empty"))]
+    #[schema(example = "credit_card")]
+    pub payment_method_id: String,
+}
+
+impl SetCartPaymentMethodRequest {
+    pub fn to_command(&self, cart_id: String) -> SetCartPaymentMethodCommand {
+        SetCartPaymentMethodCommand::new(cart_id, self.payment_method_id.clone())
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::SetCartShippingMethodCommand;
+
+/// カート配送方法設定リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCartShippingMethodRequest {
+    /// 配送方法ID
+    #[validate(length(min = 1, message = "Shipping method ID cannot be empty"))]
+    #[schema(example = "standard")]
+    pub shipping_method_id: String,
+}
+
+impl SetCartShippingMethodRequest {
+    pub fn to_command(&self, cart_id: String) -> SetCartShippingMethodCommand {
+        SetCartShippingMethodCommand::new(cart_id, self.shipping_method_id.clone())
+    }
+}
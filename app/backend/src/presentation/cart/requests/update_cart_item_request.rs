@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::UpdateCartItemCommand;
+
+/// カートアイテム数量変更リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCartItemRequest {
+    /// 変更後の数量（0の場合はアイテムを削除）
+    #[validate(range(max = 999, message = "Quantity must be between 0 and 999"))]
+    #[schema(example = 3)]
+    pub quantity: u32,
+}
+
+impl UpdateCartItemRequest {
+    pub fn to_command(&self, cart_id: String, sku_id: String) -> UpdateCartItemCommand {
+        UpdateCartItemCommand::new(cart_id, sku_id, self.quantity)
+    }
+}
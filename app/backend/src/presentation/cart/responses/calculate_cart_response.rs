@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::presentation::common::TaxBreakdownResponse;
+
 /// HTTP レスポンス用のカートアイテム
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -11,6 +13,10 @@ pub struct CalculateCartItemResponse {
     pub unit_price: u32,
     pub quantity: u32,
     pub subtotal: u32,
+    /// 明細に配分したクーポン割引額
+    pub discount_amount: u32,
+    /// 割引後の小計
+    pub discounted_subtotal: u32,
 }
 
 /// クーポン適用結果レスポンス
@@ -23,6 +29,17 @@ pub struct AppliedCouponResponse {
     pub message: String,
 }
 
+/// プロモーション適用結果レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AppliedPromotionResponse {
+    pub promotion_id: String,
+    pub name: String,
+    pub discount_amount: u32,
+    /// 配送料が無料になるプロモーションか
+    pub waives_shipping: bool,
+}
+
 /// クーポンエラーレスポンス
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]  
@@ -40,10 +57,17 @@ pub struct CalculateCartResponse {
     pub item_count: usize,
     pub subtotal: u32,
     pub tax_amount: u32,
+    /// 税率ごとの内訳
+    pub tax_breakdown: Vec<TaxBreakdownResponse>,
+    /// 価格が税込か（trueの場合、税額は合計に含まれている）
+    pub tax_included: bool,
+    /// 支払総額（配送料・支払い手数料・税額を含む）
     pub total: u32,
     pub is_empty: bool,
     pub shipping_fee: u32,
     pub payment_fee: u32,
     pub applied_coupon: Option<AppliedCouponResponse>,
+    /// 自動適用されたプロモーション（クーポンコード不要）
+    pub applied_promotions: Vec<AppliedPromotionResponse>,
     pub coupon_error: Option<CouponErrorResponse>,
 }
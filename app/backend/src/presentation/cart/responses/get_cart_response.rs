@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::presentation::cart::responses::CalculateCartResponse;
+
+/// 永続カートのレスポンス（カート操作API共通）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCartResponse {
+    /// カートID
+    pub id: String,
+    /// 選択中の配送方法ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub shipping_method_id: Option<String>,
+    /// 選択中の支払い方法ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub payment_method_id: Option<String>,
+    /// 適用中のクーポンコード
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub coupon_code: Option<String>,
+    /// 現在の価格で計算した合計
+    pub totals: CalculateCartResponse,
+}
@@ -1,3 +1,5 @@
 mod calculate_cart_response;
+mod get_cart_response;
 
-pub use calculate_cart_response::{AppliedCouponResponse, CalculateCartItemResponse, CalculateCartResponse, CouponErrorResponse};
+pub use calculate_cart_response::{AppliedCouponResponse, AppliedPromotionResponse, CalculateCartItemResponse, CalculateCartResponse, CouponErrorResponse};
+pub use get_cart_response::GetCartResponse;
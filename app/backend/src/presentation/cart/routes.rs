@@ -1,9 +1,23 @@
 use crate::infrastructure::Container;
-use crate::presentation::cart::controllers::CalculateCartController;
+use crate::presentation::cart::controllers::{
+    AddCartItemController, CalculateCartController, CreateCartController, GetCartController,
+    RemoveCartCouponController, RemoveCartItemController, SetCartCouponController,
+    SetCartPaymentMethodController, SetCartShippingMethodController, UpdateCartItemController,
+};
 use axum::Router;
 use std::sync::Arc;
 
 /// カート関連のルーティング
 pub fn routes() -> Router<Arc<Container>> {
-    Router::new().merge(CalculateCartController::routes())
+    Router::new()
+        .merge(CalculateCartController::routes())
+        .merge(CreateCartController::routes())
+        .merge(GetCartController::routes())
+        .merge(AddCartItemController::routes())
+        .merge(UpdateCartItemController::routes())
+        .merge(RemoveCartItemController::routes())
+        .merge(SetCartCouponController::routes())
+        .merge(RemoveCartCouponController::routes())
+        .merge(SetCartShippingMethodController::routes())
+        .merge(SetCartPaymentMethodController::routes())
 }
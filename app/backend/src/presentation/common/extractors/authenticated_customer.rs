@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use axum::extract::{FromRequestParts, OptionalFromRequestParts};
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+
+use crate::Error;
+use crate::application::dto::CustomerDTO;
+use crate::application::queries::models::AuthenticateCustomerQuery;
+use crate::infrastructure::Container;
+
+/// ログイン中の会員を取り出すエクストラクター
+/// `Authorization: Bearer <token>` のセッショントークンを検証する
+///
+/// - `AuthenticatedCustomer`: トークンが無い・無効な場合は401
+/// - `Option<AuthenticatedCustomer>`: トークンが無い場合はNone（ゲスト）、無効な場合は401
+pub struct AuthenticatedCustomer {
+    pub customer: CustomerDTO,
+    /// 検証済みのセッショントークン（ログアウト時に使用）
+    pub token: String,
+}
+
+impl AuthenticatedCustomer {
+    /// Authorizationヘッダーからトークンを取り出す（ヘッダーが無い場合はNone）
+    fn bearer_token(parts: &Parts) -> Result<Option<String>, Error> {
+        let Some(value) = parts.headers.get(AUTHORIZATION) else {
+            return Ok(None);
+        };
+
+        value
+            .to_str()
+            .ok()
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+            .map(Some)
+            .ok_or_else(|| Error::Unauthorized("Invalid Authorization header".to_string()))
+    }
+
+    async fn authenticate(container: &Container, token: String) -> Result<Self, Error> {
+        let customer = container
+            .get_dispatcher()
+            .execute_authenticate_customer_query(AuthenticateCustomerQuery::new(token.clone()))
+            .await?;
+
+        Ok(Self { customer, token })
+    }
+}
+
+impl FromRequestParts<Arc<Container>> for AuthenticatedCustomer {
+    type Rejection = Error;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<Container>,
+    ) -> Result<Self, Self::Rejection> {
+        let token = Self::bearer_token(parts)?
+            .ok_or_else(|| Error::Unauthorized("Authentication required".to_string()))?;
+
+        Self::authenticate(state, token).await
+    }
+}
+
+impl OptionalFromRequestParts<Arc<Container>> for AuthenticatedCustomer {
+    type Rejection = Error;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<Container>,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        match Self::bearer_token(parts)? {
+            Some(token) => Self::authenticate(state, token).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
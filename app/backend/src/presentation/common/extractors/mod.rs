@@ -1,3 +1,5 @@
+pub mod authenticated_customer;
 pub mod validated_json;
 
-pub use validated_json::ValidatedJson;
\ No newline at end of file
+pub use authenticated_customer::AuthenticatedCustomer;
+pub use validated_json::ValidatedJson;
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use axum::extract::{Request, State};
+use axum::http::header::AUTHORIZATION;
+use axum::middleware::Next;
+use axum::response::Response;
+use sha2::{Digest, Sha256};
+
+use crate::Error;
+use crate::infrastructure::Container;
+
+/// 管理・運用向けAPI（/admin/*、注文の一覧・ステータス遷移など）を保護するミドルウェア
+///
+/// `Authorization: Bearer <ADMIN_API_TOKEN>` が設定済みの管理用トークンと一致しない場合は401。
+/// 管理用トークンが未設定の場合は、すべての管理用APIを拒否する
+pub async fn admin_guard_middleware(
+    State(container): State<Arc<Container>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, Error> {
+    let Some(expected) = container.admin_api_token.as_deref() else {
+        return Err(Error::Unauthorized(
+            "Admin API is disabled because ADMIN_API_TOKEN is not set".to_string(),
+        ));
+    };
+
+    let token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+        .ok_or_else(|| Error::Unauthorized("Admin authentication required".to_string()))?;
+
+    if !token_matches(token, expected) {
+        println!(
+            "->> {:<12} - rejected {}",
+            "ADMIN_GUARD",
+            request.uri().path()
+        );
+        return Err(Error::Unauthorized("Invalid admin token".to_string()));
+    }
+
+    Ok(next.run(request).await)
+}
+
+/// トークンを比較する
+/// 比較にかかる時間から一致した文字数を推測されないよう、ハッシュ同士を比較する
+fn token_matches(token: &str, expected: &str) -> bool {
+    Sha256::digest(token.as_bytes()) == Sha256::digest(expected.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_matches() {
+        assert!(token_matches("admin-secret", "admin-secret"));
+        assert!(!token_matches("admin-secre", "admin-secret"));
+        assert!(!token_matches("", "admin-secret"));
+    }
+}
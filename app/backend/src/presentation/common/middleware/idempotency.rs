@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use axum::body::{Body, to_bytes};
+use axum::extract::{Request, State};
+use axum::http::header::{AUTHORIZATION, CONTENT_TYPE};
+use axum::http::{HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use sha2::{Digest, Sha256};
+
+use crate::Error;
+use crate::application::repositories::StoredResponse;
+use crate::application::services::IdempotencyOutcome;
+use crate::infrastructure::Container;
+
+/// 冪等キーのリクエストヘッダー
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+/// 保存済みのレスポンスを返したことを示すレスポンスヘッダー
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// 冪等キーの最大長
+const MAX_KEY_LENGTH: usize = 255;
+/// ハッシュ計算のために読み込むリクエストボディの上限（axumのJSONエクストラクターと同じ2MB）
+const MAX_REQUEST_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Idempotency-Keyヘッダー付きの更新系リクエストを冪等にするミドルウェア
+///
+/// - 初回: ハンドラーを実行し、レスポンスをキーと共に保存する（5xxの場合は保存せず再試行を許可）
+/// - 同じキー・同じリクエストの再送: 保存済みのレスポンスをそのまま返す
+/// - 同じキー・異なるリクエスト: 422
+/// - 同じキーのリクエストが処理中: 409
+///
+/// ヘッダーが無いリクエストとGETなどの参照系リクエストはそのまま通す
+pub async fn idempotency_middleware(
+    State(container): State<Arc<Container>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, Error> {
+    if !is_command_method(request.method()) {
+        return Ok(next.run(request).await);
+    }
+    let Some(key) = idempotency_key(&request)? else {
+        return Ok(next.run(request).await);
+    };
+
+    let (parts, body) = request.into_parts();
+    let body = to_bytes(body, MAX_REQUEST_BODY_BYTES)
+        .await
+        .map_err(|e| Error::ValidationError(format!("Failed to read request body: {}", e)))?;
+    let request_hash = request_hash(
+        &parts.method,
+        parts.uri.path_and_query().map_or("", |pq| pq.as_str()),
+        parts.headers.get(AUTHORIZATION),
+        &body,
+    );
+
+    // 確保したキーは、ハンドラーが最後まで実行されずに破棄された場合も解放される
+    let reservation = match container
+        .idempotency_service
+        .begin(&key, &request_hash)
+        .await?
+    {
+        IdempotencyOutcome::Proceed(reservation) => reservation,
+        IdempotencyOutcome::Replay(stored) => {
+            println!("->> {:<12} - replay {}", "IDEMPOTENCY", key);
+            return Ok(replay_response(stored));
+        }
+        IdempotencyOutcome::PayloadMismatch => {
+            return Err(Error::IdempotencyKeyReused(
+                "Idempotency-Key has already been used with a different request".to_string(),
+            ));
+        }
+        IdempotencyOutcome::InProgress => {
+            return Err(Error::IdempotencyKeyInUse(
+                "A request with this Idempotency-Key is still being processed".to_string(),
+            ));
+        }
+    };
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+
+    if response.status().is_server_error() {
+        reservation.abandon().await?;
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(e) => {
+            reservation.abandon().await?;
+            return Err(Error::ServerError(Some(format!(
+                "Failed to read response body: {}",
+                e
+            ))));
+        }
+    };
+
+    reservation
+        .complete(&StoredResponse {
+            status: parts.status.as_u16(),
+            content_type: parts
+                .headers
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+            body: body.to_vec(),
+        })
+        .await?;
+
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
+
+fn is_command_method(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    )
+}
+
+/// Idempotency-Keyヘッダーを取り出す（ヘッダーが無い場合はNone）
+fn idempotency_key(request: &Request) -> Result<Option<String>, Error> {
+    let Some(value) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+
+    value
+        .to_str()
+        .ok()
+        .map(str::trim)
+        .filter(|key| !key.is_empty() && key.len() <= MAX_KEY_LENGTH)
+        .map(|key| Some(key.to_string()))
+        .ok_or_else(|| {
+            Error::ValidationError(format!(
+                "Idempotency-Key must be 1 to {} visible ASCII characters",
+                MAX_KEY_LENGTH
+            ))
+        })
+}
+
+/// 同じリクエストかを判定するためのハッシュ
+/// 別の会員が同じキーを使っても保存済みのレスポンスを返さないよう、Authorizationヘッダーも含める
+fn request_hash(
+    method: &Method,
+    path_and_query: &str,
+    authorization: Option<&HeaderValue>,
+    body: &[u8],
+) -> String {
+    let mut hasher = Sha256::new();
+    for part in [
+        method.as_str().as_bytes(),
+        path_and_query.as_bytes(),
+        authorization.map_or(&[][..], HeaderValue::as_bytes),
+        body,
+    ] {
+        // 区切りの曖昧さを避けるため長さを前置する
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part);
+    }
+
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn replay_response(stored: StoredResponse) -> Response {
+    let status = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+    let mut response = (status, stored.body).into_response();
+    let headers = response.headers_mut();
+    headers.remove(CONTENT_TYPE);
+    if let Some(content_type) = stored
+        .content_type
+        .and_then(|value| HeaderValue::from_str(&value).ok())
+    {
+        headers.insert(CONTENT_TYPE, content_type);
+    }
+    headers.insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_hash_depends_on_every_part() {
+        let base = request_hash(&Method::POST, "/orders", None, b"{}");
+
+        assert_eq!(base, request_hash(&Method::POST, "/orders", None, b"{}"));
+        assert_eq!(base.len(), 64);
+        assert_ne!(base, request_hash(&Method::PUT, "/orders", None, b"{}"));
+        assert_ne!(base, request_hash(&Method::POST, "/carts", None, b"{}"));
+        assert_ne!(base, request_hash(&Method::POST, "/orders", None, b"{ }"));
+        assert_ne!(
+            base,
+            request_hash(
+                &Method::POST,
+                "/orders",
+                Some(&HeaderValue::from_static("Bearer token")),
+                b"{}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_idempotency_key_validation() {
+        let request = |key: &str| {
+            Request::builder()
+                .header(IDEMPOTENCY_KEY_HEADER, key)
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        assert_eq!(
+            idempotency_key(&request("order-1")).unwrap(),
+            Some("order-1".to_string())
+        );
+        assert!(idempotency_key(&request(" ")).is_err());
+        assert!(idempotency_key(&request(&"a".repeat(256))).is_err());
+        assert_eq!(idempotency_key(&Request::new(Body::empty())).unwrap(), None);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod admin_guard;
+pub mod idempotency;
+
+pub use admin_guard::admin_guard_middleware;
+pub use idempotency::idempotency_middleware;
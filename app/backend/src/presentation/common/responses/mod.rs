@@ -1,5 +1,7 @@
 /// 共通レスポンス型
 /// アプリケーション全体で使用される汎用的なレスポンス定義
 mod error_response;
+mod tax_breakdown_response;
 
 pub use error_response::ErrorResponse;
+pub use tax_breakdown_response::TaxBreakdownResponse;
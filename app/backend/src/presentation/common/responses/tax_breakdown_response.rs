@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 税率ごとの内訳レスポンス（適格請求書の記載事項）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaxBreakdownResponse {
+    /// 税率（%）
+    #[schema(example = 10)]
+    pub rate: u32,
+    /// 対象額（税抜、円）
+    #[schema(example = 12000)]
+    pub taxable_amount: u32,
+    /// 消費税額（円）
+    #[schema(example = 1200)]
+    pub tax_amount: u32,
+}
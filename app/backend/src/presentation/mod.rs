@@ -1,14 +1,23 @@
+mod admin_coupons;
+mod admin_inventory;
+mod admin_payment_methods;
+mod admin_products;
+mod auth;
 mod cart;
 mod categories;
 mod colors;
 mod common;
 mod orders;
 mod payment_methods;
+mod payments;
 mod products;
+mod returns;
 mod routes;
+mod shipments;
 mod shipping;
 mod swagger;
 mod variants;
 
 pub use common::ErrorResponse;
-pub use routes::routes;
+pub use common::middleware::{admin_guard_middleware, idempotency_middleware};
+pub use routes::{admin_routes, routes};
@@ -0,0 +1,58 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::orders::presenters::GetOrderPresenter;
+use crate::presentation::orders::requests::AddOrderNoteRequest;
+use crate::presentation::orders::responses::GetOrderResponse;
+
+/// Add Order Note Controller - 注文備考追加の単一責任
+pub struct AddOrderNoteController;
+
+impl AddOrderNoteController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/notes", post(handle))
+    }
+}
+
+/// POST /orders/{id}/notes - 注文備考追加処理
+/// 備考を注文に記録し、イベント履歴（order_note_added）にも残す
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/notes",
+    operation_id = "add_order_note",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = AddOrderNoteRequest,
+    responses(
+        (status = 200, description = "備考追加成功", body = GetOrderResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 409, description = "他の操作と同時に更新されました", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<AddOrderNoteRequest>,
+) -> Result<Json<GetOrderResponse>> {
+    println!("->> AddOrderNoteController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let order = dispatcher
+        .execute_add_order_note_command(request.to_command(id.clone()))
+        .await?;
+
+    println!("->> AddOrderNoteController::handle - success");
+    Ok(Json(GetOrderPresenter::present(order)))
+}
@@ -0,0 +1,59 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::patch};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::orders::presenters::GetOrderPresenter;
+use crate::presentation::orders::requests::AmendOrderRequest;
+use crate::presentation::orders::responses::GetOrderResponse;
+
+/// Amend Order Controller - 注文変更の単一責任
+pub struct AmendOrderController;
+
+impl AmendOrderController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}", patch(handle))
+    }
+}
+
+/// PATCH /orders/{id} - 注文変更処理
+/// 保留中（pending）の注文の明細・配送方法・配送先・支払い方法を変更し、金額と在庫引当を再計算する
+#[utoipa::path(
+    patch,
+    path = "/orders/{id}",
+    operation_id = "amend_order",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = AmendOrderRequest,
+    responses(
+        (status = 200, description = "注文変更成功", body = GetOrderResponse),
+        (status = 400, description = "リクエストが無効、または変更できないステータスです", body = ErrorResponse),
+        (status = 404, description = "注文・SKU・配送方法・支払い方法が見つかりません", body = ErrorResponse),
+        (status = 409, description = "在庫が不足しています", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<AmendOrderRequest>,
+) -> Result<Json<GetOrderResponse>> {
+    println!("->> AmendOrderController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let order = dispatcher
+        .execute_amend_order_command(request.to_command(id.clone()))
+        .await?;
+
+    println!(
+        "->> AmendOrderController::handle - success, total: {}",
+        order.total_amount
+    );
+    Ok(Json(GetOrderPresenter::present(order)))
+}
@@ -0,0 +1,61 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::orders::presenters::GetOrderPresenter;
+use crate::presentation::orders::requests::CancelOrderRequest;
+use crate::presentation::orders::responses::GetOrderResponse;
+
+/// Cancel Order Controller - 注文キャンセルの単一責任
+pub struct CancelOrderController;
+
+impl CancelOrderController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/cancel", post(handle))
+    }
+}
+
+/// POST /orders/{id}/cancel - 注文キャンセル処理
+/// 注文をキャンセル（cancelled）し、理由を記録する
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/cancel",
+    operation_id = "cancel_order",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = CancelOrderRequest,
+    responses(
+        (status = 200, description = "注文キャンセル成功", body = GetOrderResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在のステータスからは遷移できません", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<CancelOrderRequest>,
+) -> Result<Json<GetOrderResponse>> {
+    println!("->> CancelOrderController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let order = dispatcher
+        .execute_cancel_order_command(request.to_command(id.clone()))
+        .await?;
+
+    println!(
+        "->> CancelOrderController::handle - success, status: {}",
+        order.status
+    );
+    Ok(Json(GetOrderPresenter::present(order)))
+}
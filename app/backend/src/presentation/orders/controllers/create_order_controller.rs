@@ -4,7 +4,7 @@ use std::sync::Arc;
 use crate::error::Result;
 use crate::infrastructure::Container;
 use crate::presentation::ErrorResponse;
-use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::common::extractors::{AuthenticatedCustomer, ValidatedJson};
 use crate::presentation::orders::{CreateOrderRequest, CreateOrderResponse, OrderPresenter};
 
 pub struct CreateOrderController;
@@ -17,6 +17,7 @@ impl CreateOrderController {
 
 /// POST /orders - 注文作成処理
 /// 顧客情報、商品、配送・支払い情報から注文を作成する
+/// セッショントークンを指定した場合はログイン中の会員の注文として記録する
 #[utoipa::path(
     post,
     path = "/orders",
@@ -25,12 +26,15 @@ impl CreateOrderController {
     responses(
         (status = 201, description = "注文作成成功", body = CreateOrderResponse),
         (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "セッショントークンが無効です", body = ErrorResponse),
         (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
     ),
+    security((), ("bearer_auth" = [])),
     tag = "Orders"
 )]
 pub async fn handle(
     State(container): State<Arc<Container>>,
+    authenticated: Option<AuthenticatedCustomer>,
     ValidatedJson(request): ValidatedJson<CreateOrderRequest>,
 ) -> Result<Json<CreateOrderResponse>> {
     println!(
@@ -42,7 +46,9 @@ pub async fn handle(
     // 1. バリデーションはValidatedJsonエクストラクターで完了
 
     // 2. アプリケーション層のコマンドに変換
-    let command = request.to_command();
+    let command = request
+        .to_command()
+        .with_customer_id(authenticated.map(|authenticated| authenticated.customer.id));
 
     // 3. Dispatcherを通じてユースケースを実行
     let dispatcher = container.get_dispatcher();
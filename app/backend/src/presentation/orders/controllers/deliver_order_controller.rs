@@ -0,0 +1,58 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::DeliverOrderCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::orders::presenters::GetOrderPresenter;
+use crate::presentation::orders::responses::GetOrderResponse;
+
+/// Deliver Order Controller - 注文配達完了の単一責任
+pub struct DeliverOrderController;
+
+impl DeliverOrderController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/deliver", post(handle))
+    }
+}
+
+/// POST /orders/{id}/deliver - 注文配達完了処理
+/// 発送済みの注文を配達済み（delivered）に遷移する
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/deliver",
+    operation_id = "deliver_order",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "注文配達完了成功", body = GetOrderResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在のステータスからは遷移できません", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<GetOrderResponse>> {
+    println!("->> DeliverOrderController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let order = dispatcher
+        .execute_deliver_order_command(DeliverOrderCommand::new(id.clone()))
+        .await?;
+
+    println!(
+        "->> DeliverOrderController::handle - success, status: {}",
+        order.status
+    );
+    Ok(Json(GetOrderPresenter::present(order)))
+}
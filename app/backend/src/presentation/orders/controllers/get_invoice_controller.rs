@@ -0,0 +1,67 @@
+use axum::extract::{Path, Query, State};
+use axum::response::Response;
+use axum::{Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::error::ApplicationError;
+use crate::domain::aggregates::order::OrderDocumentKind;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::orders::presenters::OrderDocumentPresenter;
+use crate::presentation::orders::requests::OrderDocumentRequest;
+
+/// Get Invoice Controller - 請求書発行の単一責任
+pub struct GetInvoiceController;
+
+impl GetInvoiceController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/invoice", get(handle))
+    }
+}
+
+/// GET /orders/{id}/invoice - 請求書発行処理
+/// 注文の請求書（登録番号があれば適格請求書）を発行する。キャンセル済みの注文には発行できない
+/// 発行回数を注文に記録し、2回目以降は再発行と表示する
+#[utoipa::path(
+    get,
+    path = "/orders/{id}/invoice",
+    operation_id = "get_invoice",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000"),
+        OrderDocumentRequest
+    ),
+    responses(
+        (status = 200, description = "請求書の発行成功", content(
+            (String = "text/html"),
+            (Vec<u8> = "application/pdf")
+        )),
+        (status = 400, description = "リクエストが無効、または請求書を発行できない注文です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    Query(request): Query<OrderDocumentRequest>,
+) -> Result<Response> {
+    println!("->> GetInvoiceController::handle - order_id: {}", id);
+
+    let command = request
+        .to_command(id, OrderDocumentKind::Invoice)
+        .map_err(ApplicationError::Validation)?;
+
+    let dispatcher = container.get_dispatcher();
+    let document = dispatcher
+        .execute_issue_order_document_command(command)
+        .await?;
+
+    println!(
+        "->> GetInvoiceController::handle - success, file: {}",
+        document.file_name
+    );
+    Ok(OrderDocumentPresenter::present(document))
+}
@@ -0,0 +1,52 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::queries::models::GetOrderQuery;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::orders::presenters::GetOrderPresenter;
+use crate::presentation::orders::responses::GetOrderResponse;
+
+/// Get Order Controller - 注文詳細取得の単一責任
+pub struct GetOrderController;
+
+impl GetOrderController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}", get(handle))
+    }
+}
+
+/// GET /orders/{id} - 注文詳細取得処理
+/// 注文IDまたは注文番号で注文を取得する
+#[utoipa::path(
+    get,
+    path = "/orders/{id}",
+    operation_id = "get_order",
+    params(
+        ("id" = String, Path, description = "注文IDまたは注文番号", example = "ORD-2024-000001")
+    ),
+    responses(
+        (status = 200, description = "注文詳細の取得成功", body = GetOrderResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<GetOrderResponse>> {
+    println!("->> GetOrderController::handle - id: {}", id);
+
+    let query = GetOrderQuery::new(id.clone())?;
+
+    let dispatcher = container.get_dispatcher();
+    let order = dispatcher.execute_get_order_query(query).await?;
+
+    println!("->> GetOrderController::handle - success for id: {}", id);
+    Ok(Json(GetOrderPresenter::present(order)))
+}
@@ -0,0 +1,57 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::queries::models::GetOrderEventsQuery;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::orders::presenters::GetOrderEventsPresenter;
+use crate::presentation::orders::responses::GetOrderEventsResponse;
+
+/// Get Order Events Controller - 注文イベント履歴取得の単一責任
+pub struct GetOrderEventsController;
+
+impl GetOrderEventsController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/events", get(handle))
+    }
+}
+
+/// GET /orders/{id}/events - 注文イベント履歴取得処理
+/// 注文の作成・ステータス変更・キャンセルなどの履歴を古い順に返す
+#[utoipa::path(
+    get,
+    path = "/orders/{id}/events",
+    operation_id = "get_order_events",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "注文イベント履歴の取得成功", body = GetOrderEventsResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<GetOrderEventsResponse>> {
+    println!("->> GetOrderEventsController::handle - order_id: {}", id);
+
+    let query = GetOrderEventsQuery::new(id.clone())?;
+
+    let dispatcher = container.get_dispatcher();
+    let events = dispatcher.execute_get_order_events_query(query).await?;
+
+    println!(
+        "->> GetOrderEventsController::handle - success, {} events",
+        events.len()
+    );
+    Ok(Json(GetOrderEventsPresenter::present(id, events)))
+}
@@ -0,0 +1,63 @@
+use axum::extract::{Query, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::error::ApplicationError;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::AuthenticatedCustomer;
+use crate::presentation::orders::presenters::ListOrdersPresenter;
+use crate::presentation::orders::requests::ListMyOrdersRequest;
+use crate::presentation::orders::responses::ListOrdersResponse;
+
+/// List My Orders Controller - ログイン中の会員の注文一覧取得の単一責任
+pub struct ListMyOrdersController;
+
+impl ListMyOrdersController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/me/orders", get(handle))
+    }
+}
+
+/// GET /me/orders - ログイン中の会員の注文一覧取得処理
+/// ログイン中に作成した注文のみを注文日時の新しい順に返す（ゲスト注文は含まない）
+#[utoipa::path(
+    get,
+    path = "/me/orders",
+    operation_id = "list_my_orders",
+    params(ListMyOrdersRequest),
+    responses(
+        (status = 200, description = "注文一覧の取得成功", body = ListOrdersResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "セッショントークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    authenticated: AuthenticatedCustomer,
+    Query(request): Query<ListMyOrdersRequest>,
+) -> Result<Json<ListOrdersResponse>> {
+    println!(
+        "->> ListMyOrdersController::handle - customer_id: {}, {:?}",
+        authenticated.customer.id, request
+    );
+
+    let query = request
+        .to_query(&authenticated.customer.id)
+        .map_err(ApplicationError::Validation)?;
+
+    let dispatcher = container.get_dispatcher();
+    let order_list = dispatcher.execute_list_orders_query(query).await?;
+
+    println!(
+        "->> ListMyOrdersController::handle - success, {} of {} orders",
+        order_list.orders.len(),
+        order_list.total_count
+    );
+    Ok(Json(ListOrdersPresenter::present(order_list)))
+}
@@ -0,0 +1,56 @@
+use axum::extract::{Query, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::error::ApplicationError;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::orders::presenters::ListOrdersPresenter;
+use crate::presentation::orders::requests::ListOrdersRequest;
+use crate::presentation::orders::responses::ListOrdersResponse;
+
+/// List Orders Controller - 注文一覧取得の単一責任
+pub struct ListOrdersController;
+
+impl ListOrdersController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders", get(handle))
+    }
+}
+
+/// GET /orders - 注文一覧取得処理
+/// ステータス・注文日・顧客メールアドレスで絞り込み、注文日時の新しい順に返す
+#[utoipa::path(
+    get,
+    path = "/orders",
+    operation_id = "list_orders",
+    params(ListOrdersRequest),
+    responses(
+        (status = 200, description = "注文一覧の取得成功", body = ListOrdersResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Query(request): Query<ListOrdersRequest>,
+) -> Result<Json<ListOrdersResponse>> {
+    println!("->> ListOrdersController::handle - {:?}", request);
+
+    let query = request.to_query().map_err(ApplicationError::Validation)?;
+
+    let dispatcher = container.get_dispatcher();
+    let order_list = dispatcher.execute_list_orders_query(query).await?;
+
+    println!(
+        "->> ListOrdersController::handle - success, {} of {} orders",
+        order_list.orders.len(),
+        order_list.total_count
+    );
+    Ok(Json(ListOrdersPresenter::present(order_list)))
+}
@@ -0,0 +1,58 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::MarkOrderPaidCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::orders::presenters::GetOrderPresenter;
+use crate::presentation::orders::responses::GetOrderResponse;
+
+/// Mark Order Paid Controller - 注文支払い完了の単一責任
+pub struct MarkOrderPaidController;
+
+impl MarkOrderPaidController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/pay", post(handle))
+    }
+}
+
+/// POST /orders/{id}/pay - 注文支払い完了処理
+/// 注文を支払い済み（paid）に遷移する
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/pay",
+    operation_id = "mark_order_paid",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "注文支払い完了成功", body = GetOrderResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在のステータスからは遷移できません", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<GetOrderResponse>> {
+    println!("->> MarkOrderPaidController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let order = dispatcher
+        .execute_mark_order_paid_command(MarkOrderPaidCommand::new(id.clone()))
+        .await?;
+
+    println!(
+        "->> MarkOrderPaidController::handle - success, status: {}",
+        order.status
+    );
+    Ok(Json(GetOrderPresenter::present(order)))
+}
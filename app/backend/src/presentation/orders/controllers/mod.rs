@@ -1,3 +1,31 @@
+pub mod add_order_note_controller;
+pub mod amend_order_controller;
+pub mod cancel_order_controller;
 pub mod create_order_controller;
+pub mod deliver_order_controller;
+pub mod get_invoice_controller;
+pub mod get_order_controller;
+pub mod get_order_events_controller;
+pub mod get_receipt_controller;
+pub mod list_my_orders_controller;
+pub mod list_orders_controller;
+pub mod mark_order_paid_controller;
+pub mod process_order_controller;
+pub mod refund_order_controller;
+pub mod ship_order_controller;
 
-pub use create_order_controller::CreateOrderController;
\ No newline at end of file
+pub use add_order_note_controller::AddOrderNoteController;
+pub use amend_order_controller::AmendOrderController;
+pub use cancel_order_controller::CancelOrderController;
+pub use create_order_controller::CreateOrderController;
+pub use deliver_order_controller::DeliverOrderController;
+pub use get_invoice_controller::GetInvoiceController;
+pub use get_order_controller::GetOrderController;
+pub use get_order_events_controller::GetOrderEventsController;
+pub use get_receipt_controller::GetReceiptController;
+pub use list_my_orders_controller::ListMyOrdersController;
+pub use list_orders_controller::ListOrdersController;
+pub use mark_order_paid_controller::MarkOrderPaidController;
+pub use process_order_controller::ProcessOrderController;
+pub use refund_order_controller::RefundOrderController;
+pub use ship_order_controller::ShipOrderController;
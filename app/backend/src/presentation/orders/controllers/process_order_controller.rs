@@ -0,0 +1,58 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::ProcessOrderCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::orders::presenters::GetOrderPresenter;
+use crate::presentation::orders::responses::GetOrderResponse;
+
+/// Process Order Controller - 注文処理開始の単一責任
+pub struct ProcessOrderController;
+
+impl ProcessOrderController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/process", post(handle))
+    }
+}
+
+/// POST /orders/{id}/process - 注文処理開始処理
+/// 支払い済みの注文を処理中（processing）に遷移する
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/process",
+    operation_id = "process_order",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "注文処理開始成功", body = GetOrderResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在のステータスからは遷移できません", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<GetOrderResponse>> {
+    println!("->> ProcessOrderController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let order = dispatcher
+        .execute_process_order_command(ProcessOrderCommand::new(id.clone()))
+        .await?;
+
+    println!(
+        "->> ProcessOrderController::handle - success, status: {}",
+        order.status
+    );
+    Ok(Json(GetOrderPresenter::present(order)))
+}
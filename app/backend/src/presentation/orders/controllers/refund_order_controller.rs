@@ -0,0 +1,62 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::RefundOrderCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::orders::presenters::GetOrderPresenter;
+use crate::presentation::orders::responses::GetOrderResponse;
+
+/// Refund Order Controller - 注文返金の単一責任
+pub struct RefundOrderController;
+
+impl RefundOrderController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/refund", post(handle))
+    }
+}
+
+/// POST /orders/{id}/refund - 注文返金処理
+/// 支払い済み以降の注文を返金済み（refunded）に遷移する
+/// 決済代行会社で売上確定済みの場合は返金が成功したときだけ遷移する
+/// 返品がある注文は全額返金できない（返品ごとに返金する）
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/refund",
+    operation_id = "refund_order",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "注文返金成功", body = GetOrderResponse),
+        (status = 400, description = "リクエストが無効、または返品がある注文です", body = ErrorResponse),
+        (status = 402, description = "決済代行会社が返金を拒否しました", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在のステータスからは遷移できません", body = ErrorResponse),
+        (status = 401, description = "管理用トークンが無い、または無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse),
+        (status = 502, description = "決済代行会社との通信に失敗しました", body = ErrorResponse)
+    ),
+    security(("admin_auth" = [])),
+    tag = "Orders"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<GetOrderResponse>> {
+    println!("->> RefundOrderController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let order = dispatcher
+        .execute_refund_order_command(RefundOrderCommand::new(id.clone()))
+        .await?;
+
+    println!(
+        "->> RefundOrderController::handle - success, status: {}",
+        order.status
+    );
+    Ok(Json(GetOrderPresenter::present(order)))
+}
@@ -4,8 +4,13 @@ pub mod requests;
 pub mod responses;
 pub mod routes;
 
-pub use controllers::CreateOrderController;
+pub use controllers::{
+    AddOrderNoteController, AmendOrderController, CancelOrderController, CreateOrderController,
+    DeliverOrderController, GetOrderController,
+    GetOrderEventsController, ListMyOrdersController, GetInvoiceController, GetReceiptController, ListOrdersController,
+    MarkOrderPaidController, ProcessOrderController, RefundOrderController, ShipOrderController,
+};
 pub use presenters::OrderPresenter;
 pub use requests::CreateOrderRequest;
 pub use responses::CreateOrderResponse;
-pub use routes::routes;
\ No newline at end of file
+pub use routes::{admin_routes, routes};
\ No newline at end of file
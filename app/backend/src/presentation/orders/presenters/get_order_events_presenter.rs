@@ -0,0 +1,23 @@
+use crate::application::dto::OrderEventDTO;
+use crate::presentation::orders::responses::{GetOrderEventsResponse, OrderEventResponse};
+
+/// GET /orders/{id}/events API専用プレゼンター
+pub struct GetOrderEventsPresenter;
+
+impl GetOrderEventsPresenter {
+    /// OrderEventDTOの一覧をGetOrderEventsResponseに変換
+    pub fn present(order_id: String, events: Vec<OrderEventDTO>) -> GetOrderEventsResponse {
+        GetOrderEventsResponse {
+            order_id,
+            events: events
+                .into_iter()
+                .map(|event| OrderEventResponse {
+                    id: event.id,
+                    event_type: event.event_type,
+                    event_data: event.event_data,
+                    created_at: event.created_at.to_rfc3339(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::application::dto::{OrderDTO, OrderItemDTO, OrderPromotionDTO, TaxBreakdownDTO};
+use crate::presentation::common::TaxBreakdownResponse;
+use crate::presentation::orders::responses::{
+    GetOrderResponse, OrderCustomerResponse, OrderItemResponse, OrderPaymentResponse,
+    OrderPricingResponse, OrderPromotionResponse, OrderShipmentResponse, OrderShippingResponse,
+};
+
+/// GET /orders/{id} API専用プレゼンター
+pub struct GetOrderPresenter;
+
+impl GetOrderPresenter {
+    /// OrderDTOをGetOrderResponseに変換
+    pub fn present(order: OrderDTO) -> GetOrderResponse {
+        GetOrderResponse {
+            id: order.id,
+            order_number: order.order_number,
+            status: order.status,
+            customer: OrderCustomerResponse {
+                first_name: order.customer_first_name,
+                last_name: order.customer_last_name,
+                email: order.customer_email,
+                phone: order.customer_phone,
+            },
+            items: order.items.into_iter().map(Self::present_item).collect(),
+            shipping: OrderShippingResponse {
+                method_id: order.shipping_method_id,
+                method_name: order.shipping_method_name,
+                postal_code: order.shipping_postal_code,
+                prefecture: order.shipping_prefecture,
+                city: order.shipping_city,
+                street_address: order.shipping_street,
+                building: order.shipping_building,
+            },
+            payment: OrderPaymentResponse {
+                method_id: order.payment_method_id,
+                method_name: order.payment_method_name,
+            },
+            pricing: OrderPricingResponse {
+                subtotal: order.subtotal,
+                coupon_code: order.coupon_code,
+                applied_promotions: order
+                    .promotions
+                    .into_iter()
+                    .map(Self::present_promotion)
+                    .collect(),
+                discount: order.discount,
+                shipping_fee: order.shipping_fee,
+                payment_fee: order.payment_fee,
+                tax_amount: order.tax_amount,
+                tax_breakdown: order
+                    .tax_breakdown
+                    .into_iter()
+                    .map(Self::present_tax_breakdown)
+                    .collect(),
+                tax_included: order.tax_included,
+                total: order.total_amount,
+            },
+            shipment: order.shipment.map(|shipment| OrderShipmentResponse {
+                id: shipment.id,
+                status: shipment.status,
+                carrier: shipment.carrier,
+                tracking_number: shipment.tracking_number,
+            }),
+            notes: order.notes,
+            created_at: order.created_at.to_rfc3339(),
+            updated_at: order.updated_at.to_rfc3339(),
+            paid_at: order.paid_at.map(|t| t.to_rfc3339()),
+            shipped_at: order.shipped_at.map(|t| t.to_rfc3339()),
+            delivered_at: order.delivered_at.map(|t| t.to_rfc3339()),
+            cancelled_at: order.cancelled_at.map(|t| t.to_rfc3339()),
+        }
+    }
+
+    fn present_item(item: OrderItemDTO) -> OrderItemResponse {
+        OrderItemResponse {
+            sku_id: item.sku_id,
+            sku_code: item.sku_code,
+            product_name: item.product_name,
+            sku_name: item.sku_name,
+            unit_price: item.unit_price,
+            quantity: item.quantity,
+            subtotal: item.subtotal,
+            tax_category: item.tax_category,
+        }
+    }
+
+    fn present_promotion(promotion: OrderPromotionDTO) -> OrderPromotionResponse {
+        OrderPromotionResponse {
+            promotion_id: promotion.promotion_id,
+            name: promotion.name,
+            discount_amount: promotion.discount_amount,
+            waives_shipping: promotion.waives_shipping,
+        }
+    }
+
+    fn present_tax_breakdown(breakdown: TaxBreakdownDTO) -> TaxBreakdownResponse {
+        TaxBreakdownResponse {
+            rate: breakdown.rate,
+            taxable_amount: breakdown.taxable_amount,
+            tax_amount: breakdown.tax_amount,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::application::dto::{OrderDTO, OrderListDTO};
+use crate::presentation::orders::responses::{ListOrdersItemResponse, ListOrdersResponse};
+
+/// GET /orders API専用プレゼンター
+pub struct ListOrdersPresenter;
+
+impl ListOrdersPresenter {
+    /// OrderListDTOをListOrdersResponseに変換
+    pub fn present(order_list: OrderListDTO) -> ListOrdersResponse {
+        ListOrdersResponse {
+            orders: order_list
+                .orders
+                .into_iter()
+                .map(Self::present_item)
+                .collect(),
+            total_count: order_list.total_count,
+            page: order_list.page,
+            per_page: order_list.per_page,
+            has_next_page: order_list.has_next_page,
+            has_previous_page: order_list.has_previous_page,
+        }
+    }
+
+    fn present_item(order: OrderDTO) -> ListOrdersItemResponse {
+        ListOrdersItemResponse {
+            item_count: order.items.iter().map(|item| item.quantity).sum(),
+            customer_name: format!("{} {}", order.customer_first_name, order.customer_last_name),
+            id: order.id,
+            order_number: order.order_number,
+            status: order.status,
+            customer_email: order.customer_email,
+            total_amount: order.total_amount,
+            created_at: order.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -1,3 +1,11 @@
 pub mod create_order_presenter;
+pub mod get_order_events_presenter;
+pub mod get_order_presenter;
+pub mod list_orders_presenter;
+pub mod order_document_presenter;
 
-pub use create_order_presenter::OrderPresenter;
\ No newline at end of file
+pub use create_order_presenter::OrderPresenter;
+pub use get_order_events_presenter::GetOrderEventsPresenter;
+pub use get_order_presenter::GetOrderPresenter;
+pub use list_orders_presenter::ListOrdersPresenter;
+pub use order_document_presenter::OrderDocumentPresenter;
@@ -0,0 +1,26 @@
+use axum::http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::response::{IntoResponse, Response};
+
+use crate::application::dto::RenderedDocumentDTO;
+
+/// GET /orders/{id}/invoice, /orders/{id}/receipt API専用プレゼンター
+pub struct OrderDocumentPresenter;
+
+impl OrderDocumentPresenter {
+    /// 描画済みの帳票をブラウザで表示できるレスポンスに変換
+    /// 発行のたびに発行回数が変わるため、キャッシュさせない
+    pub fn present(document: RenderedDocumentDTO) -> Response {
+        (
+            [
+                (CONTENT_TYPE, document.content_type),
+                (
+                    CONTENT_DISPOSITION,
+                    format!("inline; filename=\"{}\"", document.file_name),
+                ),
+                (CACHE_CONTROL, "no-store".to_string()),
+            ],
+            document.body,
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::AddOrderNoteCommand;
+
+/// 注文備考追加リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+pub struct AddOrderNoteRequest {
+    /// 備考（サポート対応の記録など）
+    #[validate(length(min = 1, max = 1000, message = "Note must be 1-1000 characters"))]
+    #[schema(example = "配達日時の変更依頼あり")]
+    pub note: String,
+}
+
+impl AddOrderNoteRequest {
+    pub fn to_command(&self, order_id: String) -> AddOrderNoteCommand {
+        AddOrderNoteCommand::new(order_id, self.note.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_note() {
+        let request = AddOrderNoteRequest {
+            note: "配達日時の変更依頼あり".to_string(),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_empty_note_fails_validation() {
+        let request = AddOrderNoteRequest {
+            note: "".to_string(),
+        };
+        assert!(request.validate().is_err());
+    }
+}
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use super::{CreateOrderRequestItem, CreateOrderRequestShippingAddress};
+use crate::application::commands::models::{
+    AmendOrderCommand, CreateOrderCommandItem, CreateOrderCommandShippingAddress,
+};
+
+/// 注文変更リクエスト（未指定の項目は変更しない）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+pub struct AmendOrderRequest {
+    /// 注文アイテム（指定時は全置換）
+    #[validate(length(min = 1, message = "Order items cannot be empty"))]
+    #[validate(nested)]
+    pub items: Option<Vec<CreateOrderRequestItem>>,
+    /// 配送方法ID
+    #[validate(length(min = 1, message = "Shipping method ID cannot be empty"))]
+    #[schema(example = "express")]
+    pub shipping_method_id: Option<String>,
+    /// 支払い方法ID
+    #[validate(length(min = 1, message = "Payment method ID cannot be empty"))]
+    #[schema(example = "convenience_store")]
+    pub payment_method_id: Option<String>,
+    /// 配送先住所
+    #[validate(nested)]
+    pub shipping_address: Option<CreateOrderRequestShippingAddress>,
+}
+
+impl AmendOrderRequest {
+    pub fn to_command(&self, order_id: String) -> AmendOrderCommand {
+        AmendOrderCommand::new(
+            order_id,
+            self.items.as_ref().map(|items| {
+                items
+                    .iter()
+                    .map(|item| CreateOrderCommandItem {
+                        sku_id: item.sku_id.clone(),
+                        quantity: item.quantity,
+                    })
+                    .collect()
+            }),
+            self.shipping_method_id.clone(),
+            self.payment_method_id.clone(),
+            self.shipping_address
+                .as_ref()
+                .map(|address| CreateOrderCommandShippingAddress {
+                    postal_code: address.postal_code.clone(),
+                    prefecture: address.prefecture.clone(),
+                    city: address.city.clone(),
+                    street_address: address.street_address.clone(),
+                    building: address.building.clone(),
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_request() -> AmendOrderRequest {
+        AmendOrderRequest {
+            items: None,
+            shipping_method_id: None,
+            payment_method_id: None,
+            shipping_address: None,
+        }
+    }
+
+    #[test]
+    fn test_partial_request_is_valid() {
+        let request = AmendOrderRequest {
+            shipping_method_id: Some("express".to_string()),
+            ..empty_request()
+        };
+        assert!(request.validate().is_ok());
+
+        let command = request.to_command("order-1".to_string());
+        assert_eq!(command.order_id, "order-1");
+        assert_eq!(command.shipping_method_id.as_deref(), Some("express"));
+        assert!(command.items.is_none());
+        assert!(command.shipping_address.is_none());
+    }
+
+    #[test]
+    fn test_empty_items_or_zero_quantity_fails_validation() {
+        let request = AmendOrderRequest {
+            items: Some(vec![]),
+            ..empty_request()
+        };
+        assert!(request.validate().is_err());
+
+        let request = AmendOrderRequest {
+            items: Some(vec![CreateOrderRequestItem {
+                sku_id: "sku-1".to_string(),
+                quantity: 0,
+            }]),
+            ..empty_request()
+        };
+        assert!(request.validate().is_err());
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::CancelOrderCommand;
+
+/// 注文キャンセルリクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+pub struct CancelOrderRequest {
+    /// キャンセル理由
+    #[validate(length(min = 1, max = 1000, message = "Reason must be 1-1000 characters"))]
+    #[schema(example = "お客様都合によるキャンセル")]
+    pub reason: String,
+}
+
+impl CancelOrderRequest {
+    pub fn to_command(&self, order_id: String) -> CancelOrderCommand {
+        CancelOrderCommand::new(order_id, self.reason.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_reason() {
+        let request = CancelOrderRequest {
+            reason: "お客様都合".to_string(),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_empty_reason_fails_validation() {
+        let request = CancelOrderRequest {
+            reason: "".to_string(),
+        };
+        assert!(request.validate().is_err());
+    }
+}
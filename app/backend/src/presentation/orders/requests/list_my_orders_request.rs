@@ -0,0 +1,74 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::application::queries::models::ListOrdersQuery;
+use crate::domain::value_objects::CustomerId;
+use crate::presentation::orders::requests::ListOrdersRequest;
+
+/// ログイン中の会員の注文一覧取得用のクエリパラメータ
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query, rename_all = "camelCase")]
+pub struct ListMyOrdersRequest {
+    /// 注文ステータス（pending, paid, processing, shipped, delivered, cancelled, refunded）
+    #[param(example = "pending")]
+    pub status: Option<String>,
+    /// ページ番号（1始まり）
+    #[param(example = 1)]
+    pub page: Option<u32>,
+    /// 1ページあたりの件数（最大100）
+    #[param(example = 20)]
+    pub per_page: Option<u32>,
+}
+
+impl ListMyOrdersRequest {
+    /// 会員IDで絞り込む注文一覧クエリに変換
+    pub fn to_query(&self, customer_id: &str) -> Result<ListOrdersQuery, String> {
+        let mut query = ListOrdersRequest {
+            status: self.status.clone(),
+            page: self.page,
+            per_page: self.per_page,
+            ..Default::default()
+        }
+        .to_query()?;
+
+        let customer_id = Uuid::parse_str(customer_id)
+            .map_err(|_| format!("Invalid customer ID format: {}", customer_id))?;
+        query.customer_id = Some(CustomerId::from_uuid(customer_id));
+
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::order::OrderStatus;
+
+    #[test]
+    fn test_to_query_filters_by_customer() {
+        let customer_id = Uuid::new_v4();
+        let request = ListMyOrdersRequest {
+            status: Some("paid".to_string()),
+            page: Some(2),
+            per_page: Some(10),
+        };
+
+        let query = request.to_query(&customer_id.to_string()).unwrap();
+        assert_eq!(query.customer_id, Some(CustomerId::from_uuid(customer_id)));
+        assert_eq!(query.status, Some(OrderStatus::Paid));
+        assert!(query.customer_email.is_none());
+        assert_eq!(query.page, 2);
+        assert_eq!(query.per_page, 10);
+    }
+
+    #[test]
+    fn test_to_query_invalid_status() {
+        let request = ListMyOrdersRequest {
+            status: Some("unknown".to_string()),
+            ..Default::default()
+        };
+        assert!(request.to_query(&Uuid::new_v4().to_string()).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use chrono::{Days, NaiveDate};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::application::queries::models::ListOrdersQuery;
+use crate::domain::aggregates::order::OrderStatus;
+
+/// 注文一覧取得用のクエリパラメータ
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query, rename_all = "camelCase")]
+pub struct ListOrdersRequest {
+    /// 注文ステータス（pending, paid, processing, shipped, delivered, cancelled, refunded）
+    #[param(example = "pending")]
+    pub status: Option<String>,
+    /// 注文日の開始日（YYYY-MM-DD、この日を含む）
+    #[param(example = "2024-01-01")]
+    pub created_from: Option<String>,
+    /// 注文日の終了日（YYYY-MM-DD、この日を含む）
+    #[param(example = "2024-12-31")]
+    pub created_to: Option<String>,
+    /// 顧客メールアドレス
+    #[param(example = "taro.yamada@example.com")]
+    pub customer_email: Option<String>,
+    /// ページ番号（1始まり）
+    #[param(example = 1)]
+    pub page: Option<u32>,
+    /// 1ページあたりの件数（最大100）
+    #[param(example = 20)]
+    pub per_page: Option<u32>,
+}
+
+impl ListOrdersRequest {
+    pub fn to_query(&self) -> Result<ListOrdersQuery, String> {
+        let mut query = ListOrdersQuery::new(self.page, self.per_page);
+
+        if let Some(status) = &self.status {
+            query.status = Some(
+                status
+                    .parse::<OrderStatus>()
+                    .map_err(|_| format!("Invalid order status: {}", status))?,
+            );
+        }
+
+        if let Some(created_from) = &self.created_from {
+            let date = Self::parse_date(created_from)?;
+            query.created_from = Some(date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+        }
+
+        if let Some(created_to) = &self.created_to {
+            // 終了日は当日を含めるため翌日0時未満で検索する
+            let date = Self::parse_date(created_to)?
+                .checked_add_days(Days::new(1))
+                .ok_or_else(|| format!("Invalid date: {}", created_to))?;
+            query.created_to = Some(date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+        }
+
+        query.customer_email = self
+            .customer_email
+            .as_ref()
+            .map(|email| email.trim().to_string())
+            .filter(|email| !email.is_empty());
+
+        Ok(query)
+    }
+
+    fn parse_date(value: &str) -> Result<NaiveDate, String> {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date format (expected YYYY-MM-DD): {}", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_query_defaults() {
+        let query = ListOrdersRequest::default().to_query().unwrap();
+        assert!(query.status.is_none());
+        assert!(query.created_from.is_none());
+        assert!(query.created_to.is_none());
+        assert!(query.customer_email.is_none());
+        assert_eq!(query.page, 1);
+        assert_eq!(query.per_page, ListOrdersQuery::DEFAULT_PER_PAGE);
+    }
+
+    #[test]
+    fn test_to_query_with_filters() {
+        let request = ListOrdersRequest {
+            status: Some("paid".to_string()),
+            created_from: Some("2024-01-01".to_string()),
+            created_to: Some("2024-01-31".to_string()),
+            customer_email: Some(" taro@example.com ".to_string()),
+            page: Some(2),
+            per_page: Some(10),
+        };
+
+        let query = request.to_query().unwrap();
+        assert_eq!(query.status, Some(OrderStatus::Paid));
+        assert_eq!(
+            query.created_from.unwrap().to_rfc3339(),
+            "2024-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            query.created_to.unwrap().to_rfc3339(),
+            "2024-02-01T00:00:00+00:00"
+        );
+        assert_eq!(query.customer_email, Some("taro@example.com".to_string()));
+        assert_eq!(query.page, 2);
+        assert_eq!(query.per_page, 10);
+    }
+
+    #[test]
+    fn test_to_query_invalid_status() {
+        let request = ListOrdersRequest {
+            status: Some("unknown".to_string()),
+            ..Default::default()
+        };
+        assert!(request.to_query().is_err());
+    }
+
+    #[test]
+    fn test_to_query_invalid_date() {
+        let request = ListOrdersRequest {
+            created_from: Some("2024/01/01".to_string()),
+            ..Default::default()
+        };
+        assert!(request.to_query().is_err());
+    }
+}
@@ -1,6 +1,18 @@
+pub mod add_order_note_request;
+pub mod amend_order_request;
+pub mod cancel_order_request;
 pub mod create_order_request;
+pub mod list_my_orders_request;
+pub mod list_orders_request;
+pub mod order_document_request;
 
+pub use add_order_note_request::AddOrderNoteRequest;
+pub use amend_order_request::AmendOrderRequest;
+pub use cancel_order_request::CancelOrderRequest;
 pub use create_order_request::{
     CreateOrderRequest, CreateOrderRequestCustomerInfo, CreateOrderRequestItem,
     CreateOrderRequestShippingAddress,
-};
\ No newline at end of file
+};
+pub use list_my_orders_request::ListMyOrdersRequest;
+pub use list_orders_request::ListOrdersRequest;
+pub use order_document_request::OrderDocumentRequest;
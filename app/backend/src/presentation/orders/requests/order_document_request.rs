@@ -0,0 +1,79 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::application::commands::models::IssueOrderDocumentCommand;
+use crate::domain::aggregates::order::OrderDocumentKind;
+
+/// 宛名の最大文字数
+const MAX_ADDRESSEE_LENGTH: usize = 100;
+
+/// 請求書・領収書発行用のクエリパラメータ
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query, rename_all = "camelCase")]
+pub struct OrderDocumentRequest {
+    /// 宛名（敬称を含めて指定、最大100文字。未指定の場合は「注文者の氏名 様」）
+    #[param(example = "株式会社サンプル 御中")]
+    pub addressee: Option<String>,
+    /// 出力形式（html, pdf。未指定の場合は html）
+    #[param(example = "pdf")]
+    pub format: Option<String>,
+}
+
+impl OrderDocumentRequest {
+    pub fn to_command(
+        &self,
+        order_id: String,
+        kind: OrderDocumentKind,
+    ) -> Result<IssueOrderDocumentCommand, String> {
+        let addressee = self
+            .addressee
+            .as_ref()
+            .map(|addressee| addressee.trim().to_string())
+            .filter(|addressee| !addressee.is_empty());
+        if addressee
+            .as_ref()
+            .is_some_and(|addressee| addressee.chars().count() > MAX_ADDRESSEE_LENGTH)
+        {
+            return Err(format!(
+                "Addressee must be at most {} characters",
+                MAX_ADDRESSEE_LENGTH
+            ));
+        }
+
+        Ok(IssueOrderDocumentCommand::new(
+            order_id,
+            kind.code().to_string(),
+            self.format.clone().unwrap_or_else(|| "html".to_string()),
+            addressee,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_command_defaults() {
+        let command = OrderDocumentRequest::default()
+            .to_command("order-1".to_string(), OrderDocumentKind::Receipt)
+            .unwrap();
+        assert_eq!(command.kind, "receipt");
+        assert_eq!(command.format, "html");
+        assert!(command.addressee.is_none());
+    }
+
+    #[test]
+    fn test_to_command_rejects_long_addressee() {
+        let request = OrderDocumentRequest {
+            addressee: Some("あ".repeat(101)),
+            format: Some("pdf".to_string()),
+        };
+        assert!(
+            request
+                .to_command("order-1".to_string(), OrderDocumentKind::Invoice)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// GET /orders/{id}/events API専用の注文イベント履歴レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOrderEventsResponse {
+    /// 注文ID
+    pub order_id: String,
+    /// イベント履歴（古い順）
+    pub events: Vec<OrderEventResponse>,
+}
+
+/// 注文イベント
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderEventResponse {
+    /// イベントID
+    pub id: i64,
+    /// イベント種別
+    #[schema(example = "order_status_changed")]
+    pub event_type: String,
+    /// イベント内容
+    #[schema(value_type = Object, example = json!({"from": "pending", "to": "paid"}))]
+    pub event_data: serde_json::Value,
+    /// 発生日時（RFC3339）
+    pub created_at: String,
+}
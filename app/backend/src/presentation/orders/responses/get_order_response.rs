@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::presentation::common::TaxBreakdownResponse;
+
+/// GET /orders/{id} API専用の注文詳細レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOrderResponse {
+    /// 注文ID
+    pub id: String,
+    /// 注文番号
+    pub order_number: String,
+    /// 注文ステータス
+    pub status: String,
+    /// 顧客情報
+    pub customer: OrderCustomerResponse,
+    /// 注文アイテム
+    pub items: Vec<OrderItemResponse>,
+    /// 配送情報
+    pub shipping: OrderShippingResponse,
+    /// 支払い情報
+    pub payment: OrderPaymentResponse,
+    /// 価格情報
+    pub pricing: OrderPricingResponse,
+    /// 出荷情報（出荷登録後のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub shipment: Option<OrderShipmentResponse>,
+    /// 備考
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub notes: Option<String>,
+    /// 注文日時（RFC3339）
+    pub created_at: String,
+    /// 更新日時（RFC3339）
+    pub updated_at: String,
+    /// 支払い日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub paid_at: Option<String>,
+    /// 発送日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub shipped_at: Option<String>,
+    /// 配達日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub delivered_at: Option<String>,
+    /// キャンセル日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub cancelled_at: Option<String>,
+}
+
+/// 注文の顧客情報
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCustomerResponse {
+    /// 名
+    pub first_name: String,
+    /// 姓
+    pub last_name: String,
+    /// メールアドレス
+    pub email: String,
+    /// 電話番号
+    pub phone: String,
+}
+
+/// 注文アイテム
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderItemResponse {
+    /// SKU ID
+    pub sku_id: String,
+    /// SKUコード
+    pub sku_code: String,
+    /// 商品名
+    pub product_name: String,
+    /// SKU名
+    pub sku_name: String,
+    /// 単価（円）
+    pub unit_price: u32,
+    /// 数量
+    pub quantity: u32,
+    /// 小計（円）
+    pub subtotal: u32,
+    /// 税区分（standard: 標準税率 / reduced: 軽減税率）
+    #[schema(example = "standard")]
+    pub tax_category: String,
+}
+
+/// 注文の配送情報
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderShippingResponse {
+    /// 配送方法ID
+    pub method_id: String,
+    /// 配送方法名
+    pub method_name: String,
+    /// 郵便番号
+    pub postal_code: String,
+    /// 都道府県
+    pub prefecture: String,
+    /// 市区町村
+    pub city: String,
+    /// 住所
+    pub street_address: String,
+    /// 建物名・部屋番号
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub building: Option<String>,
+}
+
+/// 注文の支払い情報
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderPaymentResponse {
+    /// 支払い方法ID
+    pub method_id: String,
+    /// 支払い方法名
+    pub method_name: String,
+}
+
+/// 注文の出荷情報
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderShipmentResponse {
+    /// 出荷ID
+    pub id: String,
+    /// 配送状態（pending, processing, shipped, in_transit, delivered, failed）
+    pub status: String,
+    /// 配送業者
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub carrier: Option<String>,
+    /// 追跡番号
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub tracking_number: Option<String>,
+}
+
+/// 注文の価格情報
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderPricingResponse {
+    /// 商品小計（円）
+    pub subtotal: u32,
+    /// 適用されたクーポンコード
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub coupon_code: Option<String>,
+    /// 自動適用されたプロモーション（適用した順）
+    pub applied_promotions: Vec<OrderPromotionResponse>,
+    /// 割引額（円、クーポンとプロモーションの合計）
+    pub discount: u32,
+    /// 配送料（円）
+    pub shipping_fee: u32,
+    /// 支払い手数料（円）
+    pub payment_fee: u32,
+    /// 消費税（円）
+    pub tax_amount: u32,
+    /// 税率ごとの内訳
+    pub tax_breakdown: Vec<TaxBreakdownResponse>,
+    /// 価格が税込か（trueの場合、消費税は合計金額に含まれている）
+    pub tax_included: bool,
+    /// 合計金額（円）
+    pub total: u32,
+}
+
+/// 注文に適用されたプロモーション
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderPromotionResponse {
+    /// プロモーションID
+    pub promotion_id: String,
+    /// プロモーション名
+    pub name: String,
+    /// 割引額（円）
+    pub discount_amount: u32,
+    /// 送料無料のプロモーションか
+    pub waives_shipping: bool,
+}
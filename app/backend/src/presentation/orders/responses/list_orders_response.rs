@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// GET /orders API専用の注文一覧レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListOrdersResponse {
+    /// 注文一覧
+    pub orders: Vec<ListOrdersItemResponse>,
+    /// 総件数
+    pub total_count: u32,
+    /// 現在のページ番号
+    pub page: u32,
+    /// 1ページあたりの件数
+    pub per_page: u32,
+    /// 次のページがあるかどうか
+    pub has_next_page: bool,
+    /// 前のページがあるかどうか
+    pub has_previous_page: bool,
+}
+
+/// GET /orders API用の注文項目レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListOrdersItemResponse {
+    /// 注文ID
+    pub id: String,
+    /// 注文番号
+    pub order_number: String,
+    /// 注文ステータス
+    pub status: String,
+    /// 顧客名
+    pub customer_name: String,
+    /// 顧客メールアドレス
+    pub customer_email: String,
+    /// 商品点数
+    pub item_count: u32,
+    /// 合計金額（円）
+    pub total_amount: u32,
+    /// 注文日時（RFC3339）
+    pub created_at: String,
+}
@@ -1,3 +1,12 @@
 pub mod create_order_response;
+pub mod get_order_events_response;
+pub mod get_order_response;
+pub mod list_orders_response;
 
-pub use create_order_response::CreateOrderResponse;
\ No newline at end of file
+pub use create_order_response::CreateOrderResponse;
+pub use get_order_events_response::{GetOrderEventsResponse, OrderEventResponse};
+pub use get_order_response::{
+    GetOrderResponse, OrderCustomerResponse, OrderItemResponse, OrderPaymentResponse,
+    OrderPricingResponse, OrderPromotionResponse, OrderShipmentResponse, OrderShippingResponse,
+};
+pub use list_orders_response::{ListOrdersItemResponse, ListOrdersResponse};
@@ -2,8 +2,33 @@ use axum::Router;
 use std::sync::Arc;
 
 use crate::infrastructure::Container;
-use crate::presentation::orders::CreateOrderController;
+use crate::presentation::orders::{
+    AddOrderNoteController, AmendOrderController, CancelOrderController, CreateOrderController,
+    DeliverOrderController, GetOrderController,
+    GetOrderEventsController, ListMyOrdersController, GetInvoiceController, GetReceiptController, ListOrdersController,
+    MarkOrderPaidController, ProcessOrderController, RefundOrderController, ShipOrderController,
+};
 
 pub fn routes() -> Router<Arc<Container>> {
-    Router::new().merge(CreateOrderController::routes())
-}
\ No newline at end of file
+    Router::new()
+        .merge(CreateOrderController::routes())
+        .merge(ListMyOrdersController::routes())
+        .merge(GetOrderController::routes())
+        .merge(AmendOrderController::routes())
+        .merge(GetInvoiceController::routes())
+        .merge(GetReceiptController::routes())
+}
+
+/// 注文の管理・運用向けのルーティング（全顧客の注文一覧、ステータス遷移、備考・イベント履歴）
+pub fn admin_routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(ListOrdersController::routes())
+        .merge(AddOrderNoteController::routes())
+        .merge(GetOrderEventsController::routes())
+        .merge(MarkOrderPaidController::routes())
+        .merge(ProcessOrderController::routes())
+        .merge(ShipOrderController::routes())
+        .merge(DeliverOrderController::routes())
+        .merge(CancelOrderController::routes())
+        .merge(RefundOrderController::routes())
+}
@@ -1,6 +1,7 @@
-use crate::application::dto::PaymentMethodListDTO;
+use crate::application::dto::{PaymentFeeRuleDTO, PaymentMethodListDTO};
 use crate::presentation::payment_methods::responses::{
-    GetPaymentMethodListResponse, PaymentMethodListItemResponse,
+    GetPaymentMethodListResponse, PaymentFeeBracketResponse, PaymentFeeRuleResponse,
+    PaymentMethodListItemResponse,
 };
 
 /// PaymentMethodリストプレゼンター
@@ -18,17 +19,78 @@ impl GetPaymentMethodListPresenter {
                 id: item.id,
                 name: Some(item.name),
                 description: Some(item.description),
+                fee_rule: Self::present_fee_rule(item.fee_rule),
             })
             .collect();
 
         GetPaymentMethodListResponse::new(items)
     }
+
+    /// 手数料ルールDTOをResponseに変換
+    pub fn present_fee_rule(fee_rule: PaymentFeeRuleDTO) -> PaymentFeeRuleResponse {
+        PaymentFeeRuleResponse {
+            fee_type: fee_rule.fee_type,
+            amount: fee_rule.amount,
+            rate: fee_rule.rate,
+            min_fee: fee_rule.min_fee,
+            max_fee: fee_rule.max_fee,
+            brackets: fee_rule
+                .brackets
+                .into_iter()
+                .map(|bracket| PaymentFeeBracketResponse {
+                    up_to: bracket.up_to,
+                    fee: bracket.fee,
+                })
+                .collect(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::application::dto::{PaymentMethodDTO, PaymentMethodListDTO};
+    use crate::domain::entities::{
+        PaymentFeeBracket, PaymentFeeKind, PaymentFeeRule, PaymentMethod,
+    };
+    use crate::domain::value_objects::Money;
+
+    fn flat_fee(amount: u32) -> PaymentFeeRuleDTO {
+        PaymentFeeRuleDTO {
+            fee_type: "flat".to_string(),
+            amount: Some(amount),
+            rate: None,
+            min_fee: None,
+            max_fee: None,
+            brackets: vec![],
+        }
+    }
+
+    /// 金額帯のDTOは支払い方法からの変換でのみ作られるため、ドメインのルールから組み立てる
+    fn tiered_fee() -> PaymentFeeRuleDTO {
+        let fee_rule = PaymentFeeRule::new(
+            PaymentFeeKind::Tiered {
+                brackets: vec![
+                    PaymentFeeBracket::new(Some(Money::from_yen(10000)), Money::from_yen(330)),
+                    PaymentFeeBracket::new(None, Money::from_yen(440)),
+                ],
+            },
+            None,
+            None,
+        )
+        .unwrap();
+        let payment_method = PaymentMethod::new(
+            "cod".to_string(),
+            "代引き".to_string(),
+            "商品到着時に現金でお支払い".to_string(),
+            true,
+            1,
+            fee_rule,
+        )
+        .unwrap();
+
+        PaymentMethodDTO::from_payment_method(&payment_method).fee_rule
+    }
 
     #[test]
     fn test_present() {
@@ -37,11 +99,13 @@ mod tests {
                 id: "credit_card".to_string(),
                 name: "クレジットカード".to_string(),
                 description: "VISA、MasterCard、JCB対応".to_string(),
+                fee_rule: flat_fee(0),
             },
             PaymentMethodDTO {
                 id: "cod".to_string(),
                 name: "代引き".to_string(),
                 description: "商品到着時に現金でお支払い".to_string(),
+                fee_rule: tiered_fee(),
             },
         ]);
 
@@ -55,7 +119,13 @@ mod tests {
             Some("VISA、MasterCard、JCB対応".to_string())
         );
 
+        assert_eq!(response.items[0].fee_rule.fee_type, "flat");
+        assert_eq!(response.items[0].fee_rule.amount, Some(0));
+
         assert_eq!(response.items[1].id, "cod");
+        assert_eq!(response.items[1].fee_rule.fee_type, "tiered");
+        assert_eq!(response.items[1].fee_rule.brackets.len(), 2);
+        assert_eq!(response.items[1].fee_rule.brackets[1].up_to, None);
         assert_eq!(response.items[1].name, Some("代引き".to_string()));
         assert_eq!(
             response.items[1].description,
@@ -78,21 +148,25 @@ mod tests {
                 id: "credit_card".to_string(),
                 name: "クレジットカード".to_string(),
                 description: "VISA、MasterCard、JCB対応".to_string(),
+                fee_rule: flat_fee(0),
             },
             PaymentMethodDTO {
                 id: "cod".to_string(),
                 name: "代引き".to_string(),
                 description: "商品到着時に現金でお支払い".to_string(),
+                fee_rule: tiered_fee(),
             },
             PaymentMethodDTO {
                 id: "bank_transfer".to_string(),
                 name: "銀行振込".to_string(),
                 description: "指定口座への事前振込".to_string(),
+                fee_rule: flat_fee(0),
             },
             PaymentMethodDTO {
                 id: "convenience_store".to_string(),
                 name: "コンビニ支払い".to_string(),
                 description: "セブンイレブン、ファミリーマート等".to_string(),
+                fee_rule: flat_fee(200),
             },
         ]);
 
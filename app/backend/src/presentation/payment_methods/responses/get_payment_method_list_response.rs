@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use super::PaymentFeeRuleResponse;
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPaymentMethodListResponse {
@@ -15,6 +17,8 @@ pub struct PaymentMethodListItemResponse {
     pub name: Option<String>,
     #[schema(nullable = false)]
     pub description: Option<String>,
+    /// 支払い手数料ルール（フロントエンドで事前に手数料を表示するため）
+    pub fee_rule: PaymentFeeRuleResponse,
 }
 
 impl GetPaymentMethodListResponse {
@@ -1,5 +1,7 @@
 mod get_payment_method_list_response;
+mod payment_fee_rule_response;
 
 pub use get_payment_method_list_response::{
     GetPaymentMethodListResponse, PaymentMethodListItemResponse,
 };
+pub use payment_fee_rule_response::{PaymentFeeBracketResponse, PaymentFeeRuleResponse};
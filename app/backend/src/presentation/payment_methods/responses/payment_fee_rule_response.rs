@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 支払い手数料ルール
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentFeeRuleResponse {
+    /// 計算方式（flat: 定額, percentage: 割合, tiered: 金額帯）
+    #[schema(example = "tiered")]
+    pub fee_type: String,
+    /// 定額の手数料（円、flatのみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, example = 200)]
+    pub amount: Option<u32>,
+    /// カート合計に対する割合（%、percentageのみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, example = 3.5)]
+    pub rate: Option<f64>,
+    /// 最低手数料（円）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub min_fee: Option<u32>,
+    /// 最高手数料（円）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub max_fee: Option<u32>,
+    /// 金額帯（tieredのみ、上限の昇順）
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub brackets: Vec<PaymentFeeBracketResponse>,
+}
+
+/// 手数料の金額帯
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentFeeBracketResponse {
+    /// カート合計がこの金額未満のときに適用（省略時は上限なし）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, example = 10000)]
+    pub up_to: Option<u32>,
+    /// 手数料（円）
+    #[schema(example = 330)]
+    pub fee: u32,
+}
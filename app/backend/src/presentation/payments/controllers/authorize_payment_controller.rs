@@ -0,0 +1,60 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::payments::presenters::PaymentPresenter;
+use crate::presentation::payments::requests::AuthorizePaymentRequest;
+use crate::presentation::payments::responses::PaymentResultResponse;
+
+/// Authorize Payment Controller - オーソリの単一責任
+pub struct AuthorizePaymentController;
+
+impl AuthorizePaymentController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/payments/authorize", post(handle))
+    }
+}
+
+/// POST /orders/{id}/payments/authorize - オーソリ処理
+/// 支払い待ちの注文の合計金額で与信枠を確保する（注文ステータスは変更しない）
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/payments/authorize",
+    operation_id = "authorize_payment",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = AuthorizePaymentRequest,
+    responses(
+        (status = 201, description = "オーソリ成功", body = PaymentResultResponse),
+        (status = 400, description = "リクエストが無効、またはオーソリできない注文です", body = ErrorResponse),
+        (status = 402, description = "決済代行会社がオーソリを拒否しました", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse),
+        (status = 502, description = "決済代行会社との通信に失敗しました", body = ErrorResponse)
+    ),
+    tag = "Payments"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<AuthorizePaymentRequest>,
+) -> Result<(StatusCode, Json<PaymentResultResponse>)> {
+    println!("->> AuthorizePaymentController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let result = dispatcher
+        .execute_authorize_payment_command(request.to_command(id))
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(PaymentPresenter::present_result(result)),
+    ))
+}
@@ -0,0 +1,54 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::CapturePaymentCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::payments::presenters::PaymentPresenter;
+use crate::presentation::payments::responses::PaymentResultResponse;
+
+/// Capture Payment Controller - 売上確定の単一責任
+pub struct CapturePaymentController;
+
+impl CapturePaymentController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/payments/capture", post(handle))
+    }
+}
+
+/// POST /orders/{id}/payments/capture - 売上確定処理
+/// オーソリ済みの決済を確定し、注文を支払い済み（paid）に遷移する
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/payments/capture",
+    operation_id = "capture_payment",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "売上確定成功", body = PaymentResultResponse),
+        (status = 400, description = "リクエストが無効、またはオーソリ済みの決済がありません", body = ErrorResponse),
+        (status = 402, description = "決済代行会社が売上確定を拒否しました", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在のステータスからは遷移できません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse),
+        (status = 502, description = "決済代行会社との通信に失敗しました", body = ErrorResponse)
+    ),
+    tag = "Payments"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<PaymentResultResponse>> {
+    println!("->> CapturePaymentController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let result = dispatcher
+        .execute_capture_payment_command(CapturePaymentCommand::new(id))
+        .await?;
+
+    Ok(Json(PaymentPresenter::present_result(result)))
+}
@@ -0,0 +1,51 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::queries::models::GetPaymentsQuery;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::payments::presenters::PaymentPresenter;
+use crate::presentation::payments::responses::PaymentHistoryResponse;
+
+/// Get Payments Controller - 注文の決済履歴取得の単一責任
+pub struct GetPaymentsController;
+
+impl GetPaymentsController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/payments", get(handle))
+    }
+}
+
+/// GET /orders/{id}/payments - 注文の決済履歴取得
+/// 拒否・失敗を含む決済操作の履歴と、現在の決済状態を返す
+#[utoipa::path(
+    get,
+    path = "/orders/{id}/payments",
+    operation_id = "get_payments",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "決済履歴取得成功", body = PaymentHistoryResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Payments"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<PaymentHistoryResponse>> {
+    println!("->> GetPaymentsController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let history = dispatcher
+        .execute_get_payments_query(GetPaymentsQuery::new(id)?)
+        .await?;
+
+    Ok(Json(PaymentPresenter::present_history(history)))
+}
@@ -0,0 +1,9 @@
+pub mod authorize_payment_controller;
+pub mod capture_payment_controller;
+pub mod get_payments_controller;
+pub mod void_payment_controller;
+
+pub use authorize_payment_controller::AuthorizePaymentController;
+pub use capture_payment_controller::CapturePaymentController;
+pub use get_payments_controller::GetPaymentsController;
+pub use void_payment_controller::VoidPaymentController;
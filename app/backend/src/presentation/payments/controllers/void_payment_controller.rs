@@ -0,0 +1,53 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::VoidPaymentCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::payments::presenters::PaymentPresenter;
+use crate::presentation::payments::responses::PaymentResultResponse;
+
+/// Void Payment Controller - オーソリ取消の単一責任
+pub struct VoidPaymentController;
+
+impl VoidPaymentController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/payments/void", post(handle))
+    }
+}
+
+/// POST /orders/{id}/payments/void - オーソリ取消処理
+/// 売上確定前のオーソリを取り消す（注文ステータスは変更しない）
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/payments/void",
+    operation_id = "void_payment",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "オーソリ取消成功", body = PaymentResultResponse),
+        (status = 400, description = "リクエストが無効、またはオーソリ済みの決済がありません", body = ErrorResponse),
+        (status = 402, description = "決済代行会社が取消を拒否しました", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse),
+        (status = 502, description = "決済代行会社との通信に失敗しました", body = ErrorResponse)
+    ),
+    tag = "Payments"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<PaymentResultResponse>> {
+    println!("->> VoidPaymentController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let result = dispatcher
+        .execute_void_payment_command(VoidPaymentCommand::new(id))
+        .await?;
+
+    Ok(Json(PaymentPresenter::present_result(result)))
+}
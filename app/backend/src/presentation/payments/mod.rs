@@ -0,0 +1,7 @@
+pub mod controllers;
+pub mod presenters;
+pub mod requests;
+pub mod responses;
+pub mod routes;
+
+pub use routes::routes;
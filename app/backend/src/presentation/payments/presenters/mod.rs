@@ -0,0 +1,3 @@
+mod payment_presenter;
+
+pub use payment_presenter::PaymentPresenter;
@@ -0,0 +1,44 @@
+use crate::application::dto::{PaymentHistoryDTO, PaymentResultDTO, PaymentTransactionDTO};
+use crate::presentation::payments::responses::{
+    PaymentHistoryResponse, PaymentResultResponse, PaymentTransactionResponse,
+};
+
+/// 決済API用プレゼンター
+pub struct PaymentPresenter;
+
+impl PaymentPresenter {
+    /// PaymentResultDTOをPaymentResultResponseに変換
+    pub fn present_result(result: PaymentResultDTO) -> PaymentResultResponse {
+        PaymentResultResponse {
+            order_id: result.order_id,
+            order_status: result.order_status,
+            payment_status: result.payment_status,
+            transaction: Self::present_transaction(result.transaction),
+        }
+    }
+
+    /// PaymentHistoryDTOをPaymentHistoryResponseに変換
+    pub fn present_history(history: PaymentHistoryDTO) -> PaymentHistoryResponse {
+        PaymentHistoryResponse {
+            order_id: history.order_id,
+            payment_status: history.payment_status,
+            transactions: history
+                .transactions
+                .into_iter()
+                .map(Self::present_transaction)
+                .collect(),
+        }
+    }
+
+    fn present_transaction(transaction: PaymentTransactionDTO) -> PaymentTransactionResponse {
+        PaymentTransactionResponse {
+            provider: transaction.provider,
+            operation: transaction.operation,
+            amount: transaction.amount,
+            status: transaction.status,
+            transaction_id: transaction.transaction_id,
+            failure_reason: transaction.failure_reason,
+            created_at: transaction.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::AuthorizePaymentCommand;
+
+/// オーソリリクエスト
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizePaymentRequest {
+    /// フロントエンドで取得したカードトークン
+    /// モック決済ではtok_decline・tok_insufficient_fundsで拒否、tok_unavailableで通信エラーになる
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Payment token must be between 1 and 255 characters"
+    ))]
+    #[schema(example = "tok_visa")]
+    pub payment_token: Option<String>,
+}
+
+impl AuthorizePaymentRequest {
+    pub fn to_command(&self, order_id: String) -> AuthorizePaymentCommand {
+        AuthorizePaymentCommand {
+            order_id,
+            payment_token: self.payment_token.clone(),
+        }
+    }
+}
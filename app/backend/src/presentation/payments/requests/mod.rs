@@ -0,0 +1,3 @@
+mod authorize_payment_request;
+
+pub use authorize_payment_request::AuthorizePaymentRequest;
@@ -0,0 +1,5 @@
+mod payment_response;
+
+pub use payment_response::{
+    PaymentHistoryResponse, PaymentResultResponse, PaymentTransactionResponse,
+};
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 決済記録レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentTransactionResponse {
+    /// 決済代行会社
+    #[schema(example = "mock")]
+    pub provider: String,
+    /// 操作（authorize, capture, void, refund）
+    #[schema(example = "authorize")]
+    pub operation: String,
+    /// 金額（円）
+    #[schema(example = 5500)]
+    pub amount: u32,
+    /// 結果（succeeded, failed）
+    #[schema(example = "succeeded")]
+    pub status: String,
+    /// 決済代行会社側の取引ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, example = "mock_txn_00000001")]
+    pub transaction_id: Option<String>,
+    /// 拒否・失敗の理由
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, example = "card_declined")]
+    pub failure_reason: Option<String>,
+    /// 記録日時（RFC3339）
+    pub created_at: String,
+}
+
+/// 決済操作の結果レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentResultResponse {
+    /// 注文ID
+    pub order_id: String,
+    /// 操作後の注文ステータス
+    #[schema(example = "paid")]
+    pub order_status: String,
+    /// 操作後の決済状態（unpaid, authorized, captured, voided, refunded）
+    #[schema(example = "captured")]
+    pub payment_status: String,
+    /// 記録した決済操作
+    pub transaction: PaymentTransactionResponse,
+}
+
+/// 注文の決済履歴レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentHistoryResponse {
+    /// 注文ID
+    pub order_id: String,
+    /// 現在の決済状態（unpaid, authorized, captured, voided, refunded）
+    #[schema(example = "authorized")]
+    pub payment_status: String,
+    /// 決済操作の履歴（古い順、拒否・失敗を含む）
+    pub transactions: Vec<PaymentTransactionResponse>,
+}
@@ -0,0 +1,16 @@
+use crate::infrastructure::Container;
+use crate::presentation::payments::controllers::{
+    AuthorizePaymentController, CapturePaymentController, GetPaymentsController,
+    VoidPaymentController,
+};
+use axum::Router;
+use std::sync::Arc;
+
+/// 決済関連のルーティング
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(AuthorizePaymentController::routes())
+        .merge(CapturePaymentController::routes())
+        .merge(VoidPaymentController::routes())
+        .merge(GetPaymentsController::routes())
+}
@@ -1,11 +1,13 @@
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum::{Json, Router, routing::get};
 use std::sync::Arc;
 
+use crate::application::error::ApplicationError;
 use crate::error::Result;
 use crate::infrastructure::Container;
 use crate::presentation::ErrorResponse;
 use crate::presentation::products::presenters::GetProductListPresenter;
+use crate::presentation::products::requests::GetProductListRequest;
 use crate::presentation::products::responses::GetProductListResponse;
 
 /// Get Product List Controller - 商品リスト取得の単一責任
@@ -20,26 +22,35 @@ impl GetProductListController {
 }
 
 /// GET /products - 商品リスト取得処理
-/// 統合されたリッチな商品リスト情報を返す
+/// カテゴリー・色・価格帯・タグなどで絞り込み、指定の並び順でページングした商品リストを返す
 #[utoipa::path(
     get,
     path = "/products",
     operation_id = "get_product_list",
+    params(GetProductListRequest),
     responses(
         (status = 200, description = "商品リスト取得成功", body = GetProductListResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
         (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
     ),
     tag = "Products"
 )]
 pub async fn handle(
     State(container): State<Arc<Container>>,
+    Query(request): Query<GetProductListRequest>,
 ) -> Result<Json<GetProductListResponse>> {
-    println!("->> GetProductListController::handle");
+    println!("->> GetProductListController::handle - {:?}", request);
+
+    let query = request.to_query().map_err(ApplicationError::Validation)?;
 
     let dispatcher = container.get_dispatcher();
 
-    let product_list = dispatcher.execute_get_product_list_query().await?; // ApplicationErrorからErrorへの自動変換を利用
+    let product_list = dispatcher.execute_get_product_list_query(query).await?; // ApplicationErrorからErrorへの自動変換を利用
 
-    println!("->> GetProductListController::handle - success for product_list");
+    println!(
+        "->> GetProductListController::handle - success, {} of {} products",
+        product_list.products.len(),
+        product_list.total_count
+    );
     Ok(Json(GetProductListPresenter::present(product_list)))
 }
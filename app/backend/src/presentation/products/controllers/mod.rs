@@ -1,5 +1,7 @@
 pub mod get_product_controller;
 pub mod get_product_list_controller;
+pub mod search_products_controller;
 
 pub use get_product_controller::GetProductController;
 pub use get_product_list_controller::GetProductListController;
+pub use search_products_controller::SearchProductsController;
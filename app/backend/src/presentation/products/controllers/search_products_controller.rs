@@ -0,0 +1,56 @@
+use axum::extract::{Query, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::error::ApplicationError;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::products::presenters::SearchProductsPresenter;
+use crate::presentation::products::requests::SearchProductsRequest;
+use crate::presentation::products::responses::SearchProductsResponse;
+
+/// Search Products Controller - 商品キーワード検索の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct SearchProductsController;
+
+impl SearchProductsController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/products/search", get(handle))
+    }
+}
+
+/// GET /products/search - 商品キーワード検索処理
+/// 商品名・説明・SKU名・素材・色名を全文検索し、関連度順に一致箇所のスニペット付きで返す
+#[utoipa::path(
+    get,
+    path = "/products/search",
+    operation_id = "search_products",
+    params(SearchProductsRequest),
+    responses(
+        (status = 200, description = "商品検索成功", body = SearchProductsResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Query(request): Query<SearchProductsRequest>,
+) -> Result<Json<SearchProductsResponse>> {
+    println!("->> SearchProductsController::handle - {:?}", request);
+
+    let query = request.to_query().map_err(ApplicationError::Validation)?;
+
+    let dispatcher = container.get_dispatcher();
+
+    let result = dispatcher.execute_search_products_query(query).await?;
+
+    println!(
+        "->> SearchProductsController::handle - success, {} of {} products",
+        result.hits.len(),
+        result.total_count
+    );
+    Ok(Json(SearchProductsPresenter::present(result)))
+}
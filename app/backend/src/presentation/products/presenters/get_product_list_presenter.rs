@@ -25,7 +25,7 @@ impl GetProductListPresenter {
         let products = view_model
             .products
             .into_iter()
-            .map(Self::present_get_product_list_item)
+            .map(Self::present_item)
             .collect();
 
         GetProductListResponse::new(
@@ -45,7 +45,7 @@ impl GetProductListPresenter {
     ///
     /// # Returns
     /// HTTPレスポンス用のGetProductListItemResponse
-    fn present_get_product_list_item(summary: ProductSummaryDTO) -> GetProductListItemResponse {
+    pub fn present_item(summary: ProductSummaryDTO) -> GetProductListItemResponse {
         // ViewModelから必要な情報を抽出
         let is_on_sale = summary.is_on_sale();
         let is_sold_out = summary.is_sold_out();
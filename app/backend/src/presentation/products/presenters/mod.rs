@@ -1,5 +1,7 @@
 mod get_product_list_presenter;
 mod get_product_presenter;
+mod search_products_presenter;
 
 pub use get_product_list_presenter::GetProductListPresenter;
 pub use get_product_presenter::GetProductPresenter;
+pub use search_products_presenter::SearchProductsPresenter;
@@ -0,0 +1,71 @@
+use crate::application::dto::ProductSearchResultDTO;
+use crate::presentation::products::presenters::GetProductListPresenter;
+use crate::presentation::products::responses::{
+    SearchProductsItemResponse, SearchProductsResponse,
+};
+
+/// GET /products/search API専用プレゼンター
+/// Clean Architecture: Interface Adapters層
+pub struct SearchProductsPresenter;
+
+impl SearchProductsPresenter {
+    /// ProductSearchResultDTOをSearchProductsResponseに変換（検索順位を保つ）
+    pub fn present(view_model: ProductSearchResultDTO) -> SearchProductsResponse {
+        let products = view_model
+            .hits
+            .into_iter()
+            .map(|hit| SearchProductsItemResponse {
+                product: GetProductListPresenter::present_item(hit.product),
+                snippet: hit.snippet,
+            })
+            .collect();
+
+        SearchProductsResponse {
+            products,
+            total_count: view_model.total_count,
+            page: view_model.page,
+            per_page: view_model.per_page,
+            has_next_page: view_model.has_next_page,
+            has_previous_page: view_model.has_previous_page,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::{ProductSearchHitDTO, ProductSummaryDTO};
+
+    #[test]
+    fn test_present_search_result() {
+        let hit = ProductSearchHitDTO {
+            product: ProductSummaryDTO::new(
+                "product_1".to_string(),
+                "ウォールナットデスク".to_string(),
+                "デスク".to_string(),
+                50000,
+                None,
+                None,
+                vec!["Walnut".to_string()],
+                false,
+                true,
+                3,
+            ),
+            snippet: "<mark>ウォールナット</mark>デスク".to_string(),
+        };
+        let view_model = ProductSearchResultDTO::new(vec![hit], 21, 1, 20);
+
+        let response = SearchProductsPresenter::present(view_model);
+
+        assert_eq!(response.products.len(), 1);
+        assert_eq!(response.products[0].product.id, "product_1");
+        assert_eq!(response.products[0].product.is_sold_out, Some(false));
+        assert_eq!(
+            response.products[0].snippet,
+            "<mark>ウォールナット</mark>デスク"
+        );
+        assert_eq!(response.total_count, 21);
+        assert!(response.has_next_page);
+        assert!(!response.has_previous_page);
+    }
+}
@@ -0,0 +1,141 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::application::queries::models::GetProductListQuery;
+use crate::application::repositories::ProductSortOrder;
+
+/// 商品一覧取得用のクエリパラメータ
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query, rename_all = "camelCase")]
+pub struct GetProductListRequest {
+    /// カテゴリーIDまたはスラッグ（子カテゴリーの商品も含む）
+    #[param(example = "desks")]
+    pub category: Option<String>,
+    /// 色名
+    #[param(example = "Walnut")]
+    pub color: Option<String>,
+    /// 価格の下限（円、セール中はセール価格で判定）
+    #[param(example = 10000)]
+    pub min_price: Option<u32>,
+    /// 価格の上限（円、セール中はセール価格で判定）
+    #[param(example = 50000)]
+    pub max_price: Option<u32>,
+    /// タグのスラッグ
+    #[param(example = "new_arrival")]
+    pub tag: Option<String>,
+    /// ベストセラー商品のみ（false の場合はベストセラー以外）
+    pub is_best_seller: Option<bool>,
+    /// 即配送可能な商品のみ（false の場合は即配送不可の商品）
+    pub is_quick_ship: Option<bool>,
+    /// 在庫のある商品のみ（false の場合は売り切れ商品）
+    pub in_stock: Option<bool>,
+    /// 並び順（display_order, name, price_asc, price_desc, newest）
+    #[param(example = "price_asc")]
+    pub sort: Option<String>,
+    /// ページ番号（1始まり）
+    #[param(example = 1)]
+    pub page: Option<u32>,
+    /// 1ページあたりの件数（最大100、page・perPageのどちらも指定しない場合は全件）
+    #[param(example = 20)]
+    pub per_page: Option<u32>,
+}
+
+impl GetProductListRequest {
+    pub fn to_query(&self) -> Result<GetProductListQuery, String> {
+        let mut query = GetProductListQuery::new(self.page, self.per_page);
+
+        if let (Some(min_price), Some(max_price)) = (self.min_price, self.max_price)
+            && min_price > max_price
+        {
+            return Err(format!(
+                "minPrice ({}) must not exceed maxPrice ({})",
+                min_price, max_price
+            ));
+        }
+
+        if let Some(sort) = &self.sort {
+            query.sort = sort.parse::<ProductSortOrder>()?;
+        }
+
+        query.category = Self::non_empty(&self.category);
+        query.color = Self::non_empty(&self.color);
+        query.tag = Self::non_empty(&self.tag);
+        query.min_price = self.min_price;
+        query.max_price = self.max_price;
+        query.is_best_seller = self.is_best_seller;
+        query.is_quick_ship = self.is_quick_ship;
+        query.in_stock = self.in_stock;
+
+        Ok(query)
+    }
+
+    fn non_empty(value: &Option<String>) -> Option<String> {
+        value
+            .as_ref()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_query_defaults() {
+        let query = GetProductListRequest::default().to_query().unwrap();
+        assert!(query.category.is_none());
+        assert!(query.in_stock.is_none());
+        assert_eq!(query.sort, ProductSortOrder::DisplayOrder);
+        assert_eq!(query.page, 1);
+        // ページを指定しない場合は全件
+        assert_eq!(query.per_page, None);
+    }
+
+    #[test]
+    fn test_to_query_with_filters() {
+        let request = GetProductListRequest {
+            category: Some(" desks ".to_string()),
+            color: Some("Walnut".to_string()),
+            min_price: Some(1000),
+            max_price: Some(5000),
+            tag: Some("".to_string()),
+            in_stock: Some(true),
+            sort: Some("price_desc".to_string()),
+            page: Some(2),
+            per_page: Some(10),
+            ..Default::default()
+        };
+
+        let query = request.to_query().unwrap();
+        assert_eq!(query.category, Some("desks".to_string()));
+        assert_eq!(query.color, Some("Walnut".to_string()));
+        assert_eq!(query.min_price, Some(1000));
+        assert_eq!(query.max_price, Some(5000));
+        assert!(query.tag.is_none());
+        assert_eq!(query.in_stock, Some(true));
+        assert_eq!(query.sort, ProductSortOrder::PriceDesc);
+        assert_eq!(query.page, 2);
+        assert_eq!(query.per_page, Some(10));
+    }
+
+    #[test]
+    fn test_to_query_invalid_sort() {
+        let request = GetProductListRequest {
+            sort: Some("popular".to_string()),
+            ..Default::default()
+        };
+        assert!(request.to_query().is_err());
+    }
+
+    #[test]
+    fn test_to_query_inverted_price_range() {
+        let request = GetProductListRequest {
+            min_price: Some(5000),
+            max_price: Some(1000),
+            ..Default::default()
+        };
+        assert!(request.to_query().is_err());
+    }
+}
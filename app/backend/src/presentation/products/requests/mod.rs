@@ -1 +1,5 @@
-// No requests currently needed
+mod get_product_list_request;
+mod search_products_request;
+
+pub use get_product_list_request::GetProductListRequest;
+pub use search_products_request::SearchProductsRequest;
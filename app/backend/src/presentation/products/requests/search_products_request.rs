@@ -0,0 +1,54 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::application::queries::models::SearchProductsQuery;
+
+/// 商品キーワード検索用のクエリパラメータ
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query, rename_all = "camelCase")]
+pub struct SearchProductsRequest {
+    /// 検索キーワード（空白区切りでAND検索、商品名・説明・SKU名・素材・色名が対象）
+    #[param(example = "ウォールナット デスク")]
+    pub q: Option<String>,
+    /// ページ番号（1始まり）
+    #[param(example = 1)]
+    pub page: Option<u32>,
+    /// 1ページあたりの件数（最大100）
+    #[param(example = 20)]
+    pub per_page: Option<u32>,
+}
+
+impl SearchProductsRequest {
+    pub fn to_query(&self) -> Result<SearchProductsQuery, String> {
+        SearchProductsQuery::new(
+            self.q.as_deref().unwrap_or_default(),
+            self.page,
+            self.per_page,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_query() {
+        let request = SearchProductsRequest {
+            q: Some("oak 机".to_string()),
+            page: Some(2),
+            per_page: Some(5),
+        };
+
+        let query = request.to_query().unwrap();
+        assert_eq!(query.terms, vec!["oak", "机"]);
+        assert_eq!(query.page, 2);
+        assert_eq!(query.per_page, 5);
+    }
+
+    #[test]
+    fn test_to_query_missing_keyword() {
+        assert!(SearchProductsRequest::default().to_query().is_err());
+    }
+}
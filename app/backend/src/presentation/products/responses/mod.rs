@@ -1,10 +1,12 @@
 mod get_product_list_response;
 mod get_product_response;
+mod search_products_response;
 mod variant_response;
 
 // Use Case固有のレスポンス型
 pub use get_product_list_response::{GetProductListItemResponse, GetProductListResponse};
 pub use get_product_response::GetProductResponse;
+pub use search_products_response::{SearchProductsItemResponse, SearchProductsResponse};
 
 // 共通で使用されるレスポンス型
 pub use variant_response::VariantResponse;
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::GetProductListItemResponse;
+
+/// GET /products/search API専用の検索結果レスポンス（関連度順）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchProductsResponse {
+    /// 検索に一致した商品
+    pub products: Vec<SearchProductsItemResponse>,
+    /// 総件数
+    pub total_count: u32,
+    /// 現在のページ番号
+    pub page: u32,
+    /// 1ページあたりの件数
+    pub per_page: u32,
+    /// 次のページがあるかどうか
+    pub has_next_page: bool,
+    /// 前のページがあるかどうか
+    pub has_previous_page: bool,
+}
+
+/// 検索結果の商品項目（商品一覧の項目に一致箇所のスニペットを加えたもの）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchProductsItemResponse {
+    #[serde(flatten)]
+    pub product: GetProductListItemResponse,
+    /// 一致箇所の抜粋（一致した語は<mark>タグで囲まれる）
+    #[schema(example = "<mark>ウォールナット</mark>無垢材を使用したデスク…")]
+    pub snippet: String,
+}
@@ -9,4 +9,5 @@ pub fn routes() -> Router<Arc<Container>> {
     Router::new()
         .merge(controllers::GetProductController::routes())
         .merge(controllers::GetProductListController::routes())
+        .merge(controllers::SearchProductsController::routes())
 }
@@ -0,0 +1,52 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::ApproveReturnCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::returns::presenters::ReturnPresenter;
+use crate::presentation::returns::responses::ReturnResponse;
+
+/// Approve Return Controller - 返品承認の単一責任
+pub struct ApproveReturnController;
+
+impl ApproveReturnController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/returns/{id}/approve", post(handle))
+    }
+}
+
+/// POST /returns/{id}/approve - 返品承認処理
+/// 申請中の返品を承認済み（approved）に遷移し、商品の返送を受け付ける
+#[utoipa::path(
+    post,
+    path = "/returns/{id}/approve",
+    operation_id = "approve_return",
+    params(
+        ("id" = String, Path, description = "返品ID", example = "550e8400-e29b-41d4-a716-446655440004")
+    ),
+    responses(
+        (status = 200, description = "返品承認成功", body = ReturnResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "返品が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在のステータスからは遷移できません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Returns"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<ReturnResponse>> {
+    println!("->> ApproveReturnController::handle - return_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let return_request = dispatcher
+        .execute_approve_return_command(ApproveReturnCommand::new(id))
+        .await?;
+
+    Ok(Json(ReturnPresenter::present(return_request)))
+}
@@ -0,0 +1,52 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::queries::models::GetOrderReturnsQuery;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::returns::presenters::ReturnPresenter;
+use crate::presentation::returns::responses::ReturnResponse;
+
+/// Get Order Returns Controller - 注文の返品一覧取得の単一責任
+pub struct GetOrderReturnsController;
+
+impl GetOrderReturnsController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/returns", get(handle))
+    }
+}
+
+/// GET /orders/{id}/returns - 注文の返品一覧を申請の古い順に取得
+#[utoipa::path(
+    get,
+    path = "/orders/{id}/returns",
+    operation_id = "get_order_returns",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    responses(
+        (status = 200, description = "返品一覧取得成功", body = Vec<ReturnResponse>),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Returns"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<ReturnResponse>>> {
+    println!("->> GetOrderReturnsController::handle - order_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let returns = dispatcher
+        .execute_get_order_returns_query(GetOrderReturnsQuery::new(id)?)
+        .await?;
+
+    Ok(Json(
+        returns.into_iter().map(ReturnPresenter::present).collect(),
+    ))
+}
@@ -0,0 +1,50 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::queries::models::GetReturnQuery;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::returns::presenters::ReturnPresenter;
+use crate::presentation::returns::responses::ReturnResponse;
+
+/// Get Return Controller - 返品取得の単一責任
+pub struct GetReturnController;
+
+impl GetReturnController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/returns/{id}", get(handle))
+    }
+}
+
+/// GET /returns/{id} - 返品の状況と返金額を取得
+#[utoipa::path(
+    get,
+    path = "/returns/{id}",
+    operation_id = "get_return",
+    params(
+        ("id" = String, Path, description = "返品ID", example = "550e8400-e29b-41d4-a716-446655440004")
+    ),
+    responses(
+        (status = 200, description = "返品取得成功", body = ReturnResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "返品が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Returns"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<ReturnResponse>> {
+    println!("->> GetReturnController::handle - return_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let return_request = dispatcher
+        .execute_get_return_query(GetReturnQuery::new(id)?)
+        .await?;
+
+    Ok(Json(ReturnPresenter::present(return_request)))
+}
@@ -0,0 +1,15 @@
+pub mod approve_return_controller;
+pub mod get_order_returns_controller;
+pub mod get_return_controller;
+pub mod receive_return_controller;
+pub mod refund_return_controller;
+pub mod reject_return_controller;
+pub mod request_return_controller;
+
+pub use approve_return_controller::ApproveReturnController;
+pub use get_order_returns_controller::GetOrderReturnsController;
+pub use get_return_controller::GetReturnController;
+pub use receive_return_controller::ReceiveReturnController;
+pub use refund_return_controller::RefundReturnController;
+pub use reject_return_controller::RejectReturnController;
+pub use request_return_controller::RequestReturnController;
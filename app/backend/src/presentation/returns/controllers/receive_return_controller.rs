@@ -0,0 +1,56 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::returns::presenters::ReturnPresenter;
+use crate::presentation::returns::requests::ReceiveReturnRequest;
+use crate::presentation::returns::responses::ReturnResponse;
+
+/// Receive Return Controller - 返品受領の単一責任
+pub struct ReceiveReturnController;
+
+impl ReceiveReturnController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/returns/{id}/receive", post(handle))
+    }
+}
+
+/// POST /returns/{id}/receive - 返品受領処理
+/// 返送された商品を検品して受領済み（received）に遷移する
+/// 再販売できる状態の商品は在庫に戻し、検品結果に応じて返金額を再計算する
+#[utoipa::path(
+    post,
+    path = "/returns/{id}/receive",
+    operation_id = "receive_return",
+    params(
+        ("id" = String, Path, description = "返品ID", example = "550e8400-e29b-41d4-a716-446655440004")
+    ),
+    request_body = ReceiveReturnRequest,
+    responses(
+        (status = 200, description = "返品受領成功", body = ReturnResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "返品が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在のステータスからは遷移できません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Returns"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<ReceiveReturnRequest>,
+) -> Result<Json<ReturnResponse>> {
+    println!("->> ReceiveReturnController::handle - return_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let return_request = dispatcher
+        .execute_receive_return_command(request.to_command(id))
+        .await?;
+
+    Ok(Json(ReturnPresenter::present(return_request)))
+}
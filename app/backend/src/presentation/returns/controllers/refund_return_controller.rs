@@ -0,0 +1,55 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::application::commands::models::RefundReturnCommand;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::returns::presenters::ReturnPresenter;
+use crate::presentation::returns::responses::ReturnResponse;
+
+/// Refund Return Controller - 返品返金の単一責任
+pub struct RefundReturnController;
+
+impl RefundReturnController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/returns/{id}/refund", post(handle))
+    }
+}
+
+/// POST /returns/{id}/refund - 返品返金処理
+/// 受領済みの返品を返金済み（refunded）に遷移する
+/// 決済代行会社で売上確定済みの場合は返金額だけ一部返金し、成功したときだけ遷移する
+#[utoipa::path(
+    post,
+    path = "/returns/{id}/refund",
+    operation_id = "refund_return",
+    params(
+        ("id" = String, Path, description = "返品ID", example = "550e8400-e29b-41d4-a716-446655440004")
+    ),
+    responses(
+        (status = 200, description = "返品返金成功", body = ReturnResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 402, description = "決済代行会社が返金を拒否しました", body = ErrorResponse),
+        (status = 404, description = "返品が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在のステータスからは遷移できません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse),
+        (status = 502, description = "決済代行会社との通信に失敗しました", body = ErrorResponse)
+    ),
+    tag = "Returns"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<ReturnResponse>> {
+    println!("->> RefundReturnController::handle - return_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let return_request = dispatcher
+        .execute_refund_return_command(RefundReturnCommand::new(id))
+        .await?;
+
+    Ok(Json(ReturnPresenter::present(return_request)))
+}
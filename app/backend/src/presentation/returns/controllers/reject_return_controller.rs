@@ -0,0 +1,55 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::returns::presenters::ReturnPresenter;
+use crate::presentation::returns::requests::RejectReturnRequest;
+use crate::presentation::returns::responses::ReturnResponse;
+
+/// Reject Return Controller - 返品却下の単一責任
+pub struct RejectReturnController;
+
+impl RejectReturnController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/returns/{id}/reject", post(handle))
+    }
+}
+
+/// POST /returns/{id}/reject - 返品却下処理
+/// 商品を受領する前の返品を却下（rejected）する。却下した数量は再び返品申請できる
+#[utoipa::path(
+    post,
+    path = "/returns/{id}/reject",
+    operation_id = "reject_return",
+    params(
+        ("id" = String, Path, description = "返品ID", example = "550e8400-e29b-41d4-a716-446655440004")
+    ),
+    request_body = RejectReturnRequest,
+    responses(
+        (status = 200, description = "返品却下成功", body = ReturnResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "返品が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在のステータスからは遷移できません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Returns"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<RejectReturnRequest>,
+) -> Result<Json<ReturnResponse>> {
+    println!("->> RejectReturnController::handle - return_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let return_request = dispatcher
+        .execute_reject_return_command(request.to_command(id))
+        .await?;
+
+    Ok(Json(ReturnPresenter::present(return_request)))
+}
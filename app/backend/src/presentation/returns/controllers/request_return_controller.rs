@@ -0,0 +1,61 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::returns::presenters::ReturnPresenter;
+use crate::presentation::returns::requests::RequestReturnRequest;
+use crate::presentation::returns::responses::ReturnResponse;
+
+/// Request Return Controller - 返品申請の単一責任
+pub struct RequestReturnController;
+
+impl RequestReturnController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/returns", post(handle))
+    }
+}
+
+/// POST /orders/{id}/returns - 返品申請処理
+/// 配達完了した注文の商品（一部の数量でも可）の返品を申請し、返金予定額を計算する
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/returns",
+    operation_id = "request_return",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = RequestReturnRequest,
+    responses(
+        (status = 201, description = "返品申請成功", body = ReturnResponse),
+        (status = 400, description = "リクエストが無効、または返品できない注文・数量です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Returns"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<RequestReturnRequest>,
+) -> Result<(StatusCode, Json<ReturnResponse>)> {
+    println!(
+        "->> RequestReturnController::handle - order_id: {}, reason: {}",
+        id, request.reason
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let return_request = dispatcher
+        .execute_request_return_command(request.to_command(id))
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ReturnPresenter::present(return_request)),
+    ))
+}
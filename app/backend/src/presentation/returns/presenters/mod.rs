@@ -0,0 +1,3 @@
+mod return_presenter;
+
+pub use return_presenter::ReturnPresenter;
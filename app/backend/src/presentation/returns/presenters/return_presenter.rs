@@ -0,0 +1,46 @@
+use crate::application::dto::ReturnDTO;
+use crate::presentation::returns::responses::{ReturnItemResponse, ReturnResponse};
+
+/// 返品API用プレゼンター
+pub struct ReturnPresenter;
+
+impl ReturnPresenter {
+    /// ReturnDTOをReturnResponseに変換
+    pub fn present(return_request: ReturnDTO) -> ReturnResponse {
+        ReturnResponse {
+            id: return_request.id,
+            order_id: return_request.order_id,
+            status: return_request.status,
+            reason: return_request.reason,
+            condition: return_request.condition,
+            note: return_request.note,
+            rejection_reason: return_request.rejection_reason,
+            items: return_request
+                .items
+                .into_iter()
+                .map(|item| ReturnItemResponse {
+                    sku_id: item.sku_id,
+                    sku_code: item.sku_code,
+                    product_name: item.product_name,
+                    quantity: item.quantity,
+                    tax_rate: item.tax_rate,
+                    amount: item.amount,
+                    tax_amount: item.tax_amount,
+                })
+                .collect(),
+            items_amount: return_request.items_amount,
+            tax_amount: return_request.tax_amount,
+            tax_included: return_request.tax_included,
+            restocking_fee_rate: return_request.restocking_fee_rate,
+            restocking_fee: return_request.restocking_fee,
+            refund_amount: return_request.refund_amount,
+            restockable: return_request.restockable,
+            created_at: return_request.created_at.to_rfc3339(),
+            updated_at: return_request.updated_at.to_rfc3339(),
+            approved_at: return_request.approved_at.map(|t| t.to_rfc3339()),
+            received_at: return_request.received_at.map(|t| t.to_rfc3339()),
+            refunded_at: return_request.refunded_at.map(|t| t.to_rfc3339()),
+            rejected_at: return_request.rejected_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod receive_return_request;
+mod reject_return_request;
+mod request_return_request;
+
+pub use receive_return_request::ReceiveReturnRequest;
+pub use reject_return_request::RejectReturnRequest;
+pub use request_return_request::{RequestReturnRequest, RequestReturnRequestItem};
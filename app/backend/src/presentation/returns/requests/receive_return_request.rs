@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::ReceiveReturnCommand;
+
+/// 返品受領リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiveReturnRequest {
+    /// 検品した商品の状態（unopened, opened, damaged。未指定の場合は申請時の状態）
+    #[validate(length(min = 1, message = "Condition cannot be empty"))]
+    #[schema(example = "opened")]
+    pub condition: Option<String>,
+}
+
+impl ReceiveReturnRequest {
+    pub fn to_command(&self, return_id: String) -> ReceiveReturnCommand {
+        ReceiveReturnCommand::new(return_id, self.condition.clone())
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::RejectReturnCommand;
+
+/// 返品却下リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RejectReturnRequest {
+    /// 却下理由
+    #[validate(length(min = 1, max = 1000, message = "Reason must be 1-1000 characters"))]
+    #[schema(example = "返品受付期間を過ぎているため")]
+    pub reason: String,
+}
+
+impl RejectReturnRequest {
+    pub fn to_command(&self, return_id: String) -> RejectReturnCommand {
+        RejectReturnCommand::new(return_id, self.reason.clone())
+    }
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::{RequestReturnCommand, RequestReturnCommandItem};
+
+/// 返品申請の明細
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestReturnRequestItem {
+    /// SKU ID
+    #[validate(length(min = 1, message = "SKU ID is required"))]
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub sku_id: String,
+    /// 返品する数量
+    #[validate(range(min = 1, message = "Quantity must be at least 1"))]
+    #[schema(example = 1)]
+    pub quantity: u32,
+}
+
+/// 返品申請リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestReturnRequest {
+    /// 返品する商品
+    #[validate(length(min = 1, message = "Return items cannot be empty"))]
+    #[validate(nested)]
+    pub items: Vec<RequestReturnRequestItem>,
+    /// 返品理由（defective: 不良品, wrong_item: 誤配送, not_as_described: 説明と異なる,
+    /// changed_mind: お客様都合, other: その他）
+    #[validate(length(min = 1, message = "Reason is required"))]
+    #[schema(example = "changed_mind")]
+    pub reason: String,
+    /// 商品の状態（unopened: 未開封, opened: 開封済み, damaged: 破損）
+    #[validate(length(min = 1, message = "Condition is required"))]
+    #[schema(example = "unopened")]
+    pub condition: String,
+    /// 補足
+    #[validate(length(max = 1000, message = "Note must be at most 1000 characters"))]
+    #[schema(example = "サイズが合わなかったため")]
+    pub note: Option<String>,
+}
+
+impl RequestReturnRequest {
+    pub fn to_command(&self, order_id: String) -> RequestReturnCommand {
+        RequestReturnCommand {
+            order_id,
+            items: self
+                .items
+                .iter()
+                .map(|item| RequestReturnCommandItem {
+                    sku_id: item.sku_id.clone(),
+                    quantity: item.quantity,
+                })
+                .collect(),
+            reason: self.reason.clone(),
+            condition: self.condition.clone(),
+            note: self.note.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(quantity: u32) -> RequestReturnRequest {
+        RequestReturnRequest {
+            items: vec![RequestReturnRequestItem {
+                sku_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+                quantity,
+            }],
+            reason: "changed_mind".to_string(),
+            condition: "unopened".to_string(),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_valid_request() {
+        assert!(request(1).validate().is_ok());
+    }
+
+    #[test]
+    fn test_zero_quantity_fails_validation() {
+        assert!(request(0).validate().is_err());
+    }
+
+    #[test]
+    fn test_empty_items_fails_validation() {
+        let mut request = request(1);
+        request.items.clear();
+        assert!(request.validate().is_err());
+    }
+}
@@ -0,0 +1,3 @@
+mod return_response;
+
+pub use return_response::{ReturnItemResponse, ReturnResponse};
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 返品レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnResponse {
+    /// 返品ID
+    pub id: String,
+    /// 注文ID
+    pub order_id: String,
+    /// 返品状態（requested, approved, received, refunded, rejected）
+    #[schema(example = "requested")]
+    pub status: String,
+    /// 返品理由（defective, wrong_item, not_as_described, changed_mind, other）
+    #[schema(example = "changed_mind")]
+    pub reason: String,
+    /// 商品の状態（unopened, opened, damaged）
+    #[schema(example = "unopened")]
+    pub condition: String,
+    /// 補足
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub note: Option<String>,
+    /// 却下理由
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub rejection_reason: Option<String>,
+    /// 返品する商品
+    pub items: Vec<ReturnItemResponse>,
+    /// 返品商品の金額（割引按分後）
+    #[schema(example = 880)]
+    pub items_amount: u32,
+    /// 返品商品の消費税額
+    #[schema(example = 88)]
+    pub tax_amount: u32,
+    /// 商品金額が税込かどうか
+    pub tax_included: bool,
+    /// 再入荷手数料率（%）
+    #[schema(example = 10)]
+    pub restocking_fee_rate: u32,
+    /// 再入荷手数料
+    #[schema(example = 0)]
+    pub restocking_fee: u32,
+    /// 返金額（送料・支払い手数料は返金しない）
+    #[schema(example = 968)]
+    pub refund_amount: u32,
+    /// 受領時に在庫へ戻すかどうか
+    pub restockable: bool,
+    /// 申請日時（RFC3339）
+    pub created_at: String,
+    /// 更新日時（RFC3339）
+    pub updated_at: String,
+    /// 承認日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub approved_at: Option<String>,
+    /// 受領日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub received_at: Option<String>,
+    /// 返金日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub refunded_at: Option<String>,
+    /// 却下日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub rejected_at: Option<String>,
+}
+
+/// 返品明細
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnItemResponse {
+    pub sku_id: String,
+    pub sku_code: String,
+    pub product_name: String,
+    pub quantity: u32,
+    /// 税率（%）
+    pub tax_rate: u32,
+    /// 返金対象額
+    pub amount: u32,
+    /// 按分した消費税額
+    pub tax_amount: u32,
+}
@@ -0,0 +1,20 @@
+use crate::infrastructure::Container;
+use crate::presentation::returns::controllers::{
+    ApproveReturnController, GetOrderReturnsController, GetReturnController,
+    ReceiveReturnController, RefundReturnController, RejectReturnController,
+    RequestReturnController,
+};
+use axum::Router;
+use std::sync::Arc;
+
+/// 返品（RMA）関連のルーティング
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(RequestReturnController::routes())
+        .merge(GetOrderReturnsController::routes())
+        .merge(GetReturnController::routes())
+        .merge(ApproveReturnController::routes())
+        .merge(RejectReturnController::routes())
+        .merge(ReceiveReturnController::routes())
+        .merge(RefundReturnController::routes())
+}
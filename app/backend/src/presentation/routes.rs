@@ -1,9 +1,18 @@
+use crate::presentation::admin_coupons::routes as admin_coupons_routes;
+use crate::presentation::admin_inventory::routes as admin_inventory_routes;
+use crate::presentation::admin_payment_methods::routes as admin_payment_methods_routes;
+use crate::presentation::admin_products::routes as admin_products_routes;
+use crate::presentation::auth::routes as auth_routes;
 use crate::presentation::cart::routes as cart_routes;
 use crate::presentation::categories::routes as categories_routes;
 use crate::presentation::colors::routes as colors_routes;
+use crate::presentation::orders::admin_routes as orders_admin_routes;
 use crate::presentation::orders::routes as orders_routes;
 use crate::presentation::payment_methods::routes as payment_methods_routes;
+use crate::presentation::payments::routes as payments_routes;
 use crate::presentation::products::routes as products_routes;
+use crate::presentation::returns::routes as returns_routes;
+use crate::presentation::shipments::routes as shipments_routes;
 use crate::presentation::shipping::routes as shipping_routes;
 use crate::presentation::swagger::swagger_routes;
 use crate::presentation::variants::routes as variants_routes;
@@ -20,7 +29,21 @@ pub fn routes() -> Router<Arc<Container>> {
         .merge(variants_routes())
         .merge(cart_routes())
         .merge(orders_routes())
+        .merge(auth_routes())
         .merge(shipping_routes())
         .merge(payment_methods_routes())
+        .merge(shipments_routes())
+        .merge(payments_routes())
+        .merge(returns_routes())
         .merge(swagger_routes()) // Swagger UI + OpenAPI JSON
 }
+
+/// 管理・運用向けのルーティング（管理用トークンで保護する）
+pub fn admin_routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(admin_products_routes())
+        .merge(admin_inventory_routes())
+        .merge(admin_payment_methods_routes())
+        .merge(admin_coupons_routes())
+        .merge(orders_admin_routes())
+}
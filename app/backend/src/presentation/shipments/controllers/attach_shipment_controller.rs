@@ -0,0 +1,61 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::shipments::presenters::ShipmentPresenter;
+use crate::presentation::shipments::requests::AttachShipmentRequest;
+use crate::presentation::shipments::responses::ShipmentResponse;
+
+/// Attach Shipment Controller - 出荷登録の単一責任
+pub struct AttachShipmentController;
+
+impl AttachShipmentController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/orders/{id}/shipments", post(handle))
+    }
+}
+
+/// POST /orders/{id}/shipments - 出荷登録処理
+/// 支払い済み・処理中の注文に配送業者と追跡番号を紐付ける
+#[utoipa::path(
+    post,
+    path = "/orders/{id}/shipments",
+    operation_id = "attach_shipment",
+    params(
+        ("id" = String, Path, description = "注文ID", example = "550e8400-e29b-41d4-a716-446655440000")
+    ),
+    request_body = AttachShipmentRequest,
+    responses(
+        (status = 201, description = "出荷登録成功", body = ShipmentResponse),
+        (status = 400, description = "リクエストが無効、または出荷を登録できない注文です", body = ErrorResponse),
+        (status = 404, description = "注文が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Shipments"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<AttachShipmentRequest>,
+) -> Result<(StatusCode, Json<ShipmentResponse>)> {
+    println!(
+        "->> AttachShipmentController::handle - order_id: {}, carrier: {}",
+        id, request.carrier
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let shipment = dispatcher
+        .execute_attach_shipment_command(request.to_command(id))
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ShipmentPresenter::present(shipment)),
+    ))
+}
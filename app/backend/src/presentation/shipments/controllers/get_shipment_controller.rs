@@ -0,0 +1,50 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::get};
+use std::sync::Arc;
+
+use crate::application::queries::models::GetShipmentQuery;
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::shipments::presenters::ShipmentPresenter;
+use crate::presentation::shipments::responses::ShipmentResponse;
+
+/// Get Shipment Controller - 出荷取得の単一責任
+pub struct GetShipmentController;
+
+impl GetShipmentController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/shipments/{id}", get(handle))
+    }
+}
+
+/// GET /shipments/{id} - 出荷の配送状況を取得
+#[utoipa::path(
+    get,
+    path = "/shipments/{id}",
+    operation_id = "get_shipment",
+    params(
+        ("id" = String, Path, description = "出荷ID", example = "550e8400-e29b-41d4-a716-446655440003")
+    ),
+    responses(
+        (status = 200, description = "出荷取得成功", body = ShipmentResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "出荷が見つかりません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Shipments"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+) -> Result<Json<ShipmentResponse>> {
+    println!("->> GetShipmentController::handle - shipment_id: {}", id);
+
+    let dispatcher = container.get_dispatcher();
+    let shipment = dispatcher
+        .execute_get_shipment_query(GetShipmentQuery::new(id)?)
+        .await?;
+
+    Ok(Json(ShipmentPresenter::present(shipment)))
+}
@@ -0,0 +1,7 @@
+pub mod attach_shipment_controller;
+pub mod get_shipment_controller;
+pub mod record_shipment_event_controller;
+
+pub use attach_shipment_controller::AttachShipmentController;
+pub use get_shipment_controller::GetShipmentController;
+pub use record_shipment_event_controller::RecordShipmentEventController;
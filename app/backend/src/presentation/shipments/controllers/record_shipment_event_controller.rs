@@ -0,0 +1,63 @@
+use axum::extract::{Path, State};
+use axum::{Json, Router, routing::post};
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::infrastructure::Container;
+use crate::presentation::ErrorResponse;
+use crate::presentation::common::extractors::ValidatedJson;
+use crate::presentation::shipments::presenters::ShipmentPresenter;
+use crate::presentation::shipments::requests::RecordShipmentEventRequest;
+use crate::presentation::shipments::responses::ShipmentResponse;
+
+/// Record Shipment Event Controller - 配送状況通知の単一責任
+pub struct RecordShipmentEventController;
+
+impl RecordShipmentEventController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new().route("/shipments/{id}/events", post(handle))
+    }
+}
+
+/// POST /shipments/{id}/events - 配送状況通知（配送業者Webhook）
+/// 配送状態を進め、発送・配達完了に合わせて注文ステータスを同期する。
+/// 同じ状態の再通知は何もせず現在の状態を返す。
+#[utoipa::path(
+    post,
+    path = "/shipments/{id}/events",
+    operation_id = "record_shipment_event",
+    params(
+        ("id" = String, Path, description = "出荷ID", example = "550e8400-e29b-41d4-a716-446655440003")
+    ),
+    request_body = RecordShipmentEventRequest,
+    responses(
+        (status = 200, description = "配送状況反映成功", body = ShipmentResponse),
+        (status = 400, description = "リクエストが無効です", body = ErrorResponse),
+        (status = 404, description = "出荷が見つかりません", body = ErrorResponse),
+        (status = 409, description = "現在の配送状態・注文ステータスからは遷移できません", body = ErrorResponse),
+        (status = 500, description = "内部サーバーエラー", body = ErrorResponse)
+    ),
+    tag = "Shipments"
+)]
+pub async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<RecordShipmentEventRequest>,
+) -> Result<Json<ShipmentResponse>> {
+    println!(
+        "->> RecordShipmentEventController::handle - shipment_id: {}, status: {}",
+        id, request.status
+    );
+
+    let dispatcher = container.get_dispatcher();
+    let shipment = dispatcher
+        .execute_record_shipment_event_command(request.to_command(id))
+        .await?;
+
+    println!(
+        "->> RecordShipmentEventController::handle - success, status: {}",
+        shipment.status
+    );
+    Ok(Json(ShipmentPresenter::present(shipment)))
+}
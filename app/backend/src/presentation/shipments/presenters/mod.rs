@@ -0,0 +1,3 @@
+mod shipment_presenter;
+
+pub use shipment_presenter::ShipmentPresenter;
@@ -0,0 +1,31 @@
+use crate::application::dto::ShipmentDTO;
+use crate::presentation::shipments::responses::{ShipmentAddressResponse, ShipmentResponse};
+
+/// 出荷API用プレゼンター
+pub struct ShipmentPresenter;
+
+impl ShipmentPresenter {
+    /// ShipmentDTOをShipmentResponseに変換
+    pub fn present(shipment: ShipmentDTO) -> ShipmentResponse {
+        ShipmentResponse {
+            id: shipment.id,
+            order_id: shipment.order_id,
+            status: shipment.status,
+            carrier: shipment.carrier,
+            tracking_number: shipment.tracking_number,
+            shipping_method: shipment.shipping_method,
+            recipient_name: shipment.recipient_name,
+            address: ShipmentAddressResponse {
+                postal_code: shipment.postal_code,
+                prefecture: shipment.prefecture,
+                city: shipment.city,
+                street: shipment.street,
+                building: shipment.building,
+            },
+            created_at: shipment.created_at.to_rfc3339(),
+            updated_at: shipment.updated_at.to_rfc3339(),
+            shipped_at: shipment.shipped_at.map(|t| t.to_rfc3339()),
+            delivered_at: shipment.delivered_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::AttachShipmentCommand;
+
+/// 出荷登録リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachShipmentRequest {
+    /// 配送業者
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Carrier must be between 1 and 100 characters"
+    ))]
+    #[schema(example = "ヤマト運輸")]
+    pub carrier: String,
+    /// 追跡番号
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Tracking number must be between 1 and 100 characters"
+    ))]
+    #[schema(example = "1234-5678-9012")]
+    pub tracking_number: String,
+}
+
+impl AttachShipmentRequest {
+    pub fn to_command(&self, order_id: String) -> AttachShipmentCommand {
+        AttachShipmentCommand {
+            order_id,
+            carrier: self.carrier.clone(),
+            tracking_number: self.tracking_number.clone(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod attach_shipment_request;
+mod record_shipment_event_request;
+
+pub use attach_shipment_request::AttachShipmentRequest;
+pub use record_shipment_event_request::RecordShipmentEventRequest;
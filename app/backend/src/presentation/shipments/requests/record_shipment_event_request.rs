@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::commands::models::RecordShipmentEventCommand;
+
+/// 配送状況通知リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordShipmentEventRequest {
+    /// 配送状態（shipped: 発送, in_transit: 配送中, delivered: 配達完了, failed: 配送失敗）
+    #[validate(length(min = 1, message = "Status is required"))]
+    #[schema(example = "shipped")]
+    pub status: String,
+}
+
+impl RecordShipmentEventRequest {
+    pub fn to_command(&self, shipment_id: String) -> RecordShipmentEventCommand {
+        RecordShipmentEventCommand {
+            shipment_id,
+            status: self.status.clone(),
+        }
+    }
+}
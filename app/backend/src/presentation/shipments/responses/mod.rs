@@ -0,0 +1,3 @@
+mod shipment_response;
+
+pub use shipment_response::{ShipmentAddressResponse, ShipmentResponse};
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 出荷（配送情報）レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipmentResponse {
+    /// 出荷ID
+    pub id: String,
+    /// 注文ID
+    pub order_id: String,
+    /// 配送状態（pending, processing, shipped, in_transit, delivered, failed）
+    #[schema(example = "shipped")]
+    pub status: String,
+    /// 配送業者
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub carrier: Option<String>,
+    /// 追跡番号
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub tracking_number: Option<String>,
+    /// 配送方法
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub shipping_method: Option<String>,
+    /// 届け先氏名
+    pub recipient_name: String,
+    /// 届け先住所
+    pub address: ShipmentAddressResponse,
+    /// 登録日時（RFC3339）
+    pub created_at: String,
+    /// 更新日時（RFC3339）
+    pub updated_at: String,
+    /// 発送日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub shipped_at: Option<String>,
+    /// 配達完了日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub delivered_at: Option<String>,
+}
+
+/// 届け先住所
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipmentAddressResponse {
+    pub postal_code: String,
+    pub prefecture: String,
+    pub city: String,
+    pub street: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub building: Option<String>,
+}
@@ -0,0 +1,14 @@
+use crate::infrastructure::Container;
+use crate::presentation::shipments::controllers::{
+    AttachShipmentController, GetShipmentController, RecordShipmentEventController,
+};
+use axum::Router;
+use std::sync::Arc;
+
+/// 出荷・配送追跡関連のルーティング
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(AttachShipmentController::routes())
+        .merge(GetShipmentController::routes())
+        .merge(RecordShipmentEventController::routes())
+}
@@ -1,6 +1,7 @@
 use crate::application::dto::ShippingMethodListDTO;
 use crate::presentation::shipping::responses::{
-    GetShippingMethodListItemResponse, GetShippingMethodListResponse,
+    GetShippingMethodListItemResponse, GetShippingMethodListResponse, OversizeSurchargeResponse,
+    ShippingRegionRateResponse,
 };
 
 /// Shipping Method List Presenter
@@ -19,6 +20,22 @@ impl GetShippingMethodListPresenter {
                 name: method.name,
                 description: method.description,
                 price: method.price,
+                free_shipping_threshold: method.free_shipping_threshold,
+                quick_ship_only: method.quick_ship_only,
+                region_rates: method
+                    .region_rates
+                    .into_iter()
+                    .map(|rate| ShippingRegionRateResponse {
+                        region: rate.region,
+                        fee: rate.fee,
+                    })
+                    .collect(),
+                oversize_surcharge: method.oversize_surcharge.map(|surcharge| {
+                    OversizeSurchargeResponse {
+                        min_size: surcharge.min_size,
+                        fee_per_item: surcharge.fee_per_item,
+                    }
+                }),
             })
             .collect();
 
@@ -39,6 +56,10 @@ mod tests {
             name: "標準配送".to_string(),
             description: "5-7営業日".to_string(),
             price: 500,
+            free_shipping_threshold: Some(10000),
+            quick_ship_only: false,
+            region_rates: Vec::new(),
+            oversize_surcharge: None,
         }];
 
         let shipping_method_list = ShippingMethodListDTO::new(methods);
@@ -47,5 +68,9 @@ mod tests {
         assert_eq!(response.shipping_methods.len(), 1);
         assert_eq!(response.shipping_methods[0].id, "standard");
         assert_eq!(response.shipping_methods[0].name, "標準配送");
+        assert_eq!(
+            response.shipping_methods[0].free_shipping_threshold,
+            Some(10000)
+        );
     }
 }
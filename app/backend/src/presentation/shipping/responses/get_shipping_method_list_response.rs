@@ -15,6 +15,30 @@ pub struct GetShippingMethodListItemResponse {
     pub name: String,
     pub description: String,
     pub price: u32,
+    /// 送料無料になる商品小計（なしは送料無料なし）
+    pub free_shipping_threshold: Option<u32>,
+    /// 即日出荷対象の商品のみで利用可能か
+    pub quick_ship_only: bool,
+    /// 地域別の配送料（記載のない地域は基本料金）
+    pub region_rates: Vec<ShippingRegionRateResponse>,
+    pub oversize_surcharge: Option<OversizeSurchargeResponse>,
+}
+
+/// 地域別の配送料
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ShippingRegionRateResponse {
+    /// 地域区分（hokkaido, tohoku, kanto, chubu, kinki, chugoku, shikoku, kyushu, okinawa）
+    pub region: String,
+    pub fee: u32,
+}
+
+/// 大型商品の追加料金（3辺合計が`minSize`以上の商品1点ごと）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OversizeSurchargeResponse {
+    pub min_size: f64,
+    pub fee_per_item: u32,
 }
 
 impl GetShippingMethodListResponse {
@@ -35,12 +59,23 @@ mod tests {
                 name: "標準配送".to_string(),
                 description: "5-7営業日".to_string(),
                 price: 500,
+                free_shipping_threshold: Some(10000),
+                quick_ship_only: false,
+                region_rates: vec![ShippingRegionRateResponse {
+                    region: "hokkaido".to_string(),
+                    fee: 1000,
+                }],
+                oversize_surcharge: None,
             },
             GetShippingMethodListItemResponse {
                 id: "express".to_string(),
                 name: "速達配送".to_string(),
                 description: "2-3営業日".to_string(),
                 price: 1000,
+                free_shipping_threshold: None,
+                quick_ship_only: false,
+                region_rates: Vec::new(),
+                oversize_surcharge: None,
             },
         ];
 
@@ -51,5 +86,7 @@ mod tests {
         assert!(json.contains("標準配送"));
         assert!(json.contains("5-7営業日"));
         assert!(json.contains("500"));
+        assert!(json.contains("freeShippingThreshold"));
+        assert!(json.contains("hokkaido"));
     }
 }
@@ -1,5 +1,6 @@
 mod get_shipping_method_list_response;
 
 pub use get_shipping_method_list_response::{
-    GetShippingMethodListItemResponse, GetShippingMethodListResponse,
+    GetShippingMethodListItemResponse, GetShippingMethodListResponse, OversizeSurchargeResponse,
+    ShippingRegionRateResponse,
 };
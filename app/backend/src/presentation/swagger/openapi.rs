@@ -1,23 +1,69 @@
-use utoipa::OpenApi;
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
 
 use crate::presentation::ErrorResponse;
-use crate::presentation::cart::requests::{CalculateCartItemRequest, CalculateCartRequest};
-use crate::presentation::cart::responses::{AppliedCouponResponse, CalculateCartItemResponse, CalculateCartResponse, CouponErrorResponse};
+use crate::presentation::common::TaxBreakdownResponse;
+use crate::presentation::admin_coupons::requests::{
+    CouponDiscountRequest, CreateCouponRequest, GenerateCouponCodesRequest, UpdateCouponRequest,
+};
+use crate::presentation::admin_coupons::responses::{CouponListResponse, CouponResponse};
+use crate::presentation::admin_inventory::requests::AdjustStockRequest;
+use crate::presentation::auth::requests::{LoginRequest, RegisterRequest};
+use crate::presentation::auth::responses::{CustomerResponse, SessionResponse};
+use crate::presentation::admin_inventory::responses::{
+    StockHistoryResponse, StockMovementResponse,
+};
+use crate::presentation::admin_products::requests::{
+    CreateProductRequest, ProductImageRequest, ProductSkuRequest, ScheduleSkuSaleRequest,
+    UpdateProductRequest, UpdateProductSkuRequest,
+};
+use crate::presentation::admin_products::responses::{
+    AdminPriceScheduleResponse, AdminProductImageResponse, AdminProductResponse,
+    AdminProductSkuResponse,
+};
+use crate::presentation::cart::requests::{
+    AddCartItemRequest, CalculateCartItemRequest, CalculateCartRequest, SetCartCouponRequest,
+    SetCartPaymentMethodRequest, SetCartShippingMethodRequest, UpdateCartItemRequest,
+};
+use crate::presentation::cart::responses::{AppliedCouponResponse, AppliedPromotionResponse, CalculateCartItemResponse, CalculateCartResponse, CouponErrorResponse, GetCartResponse};
 use crate::presentation::categories::responses::{CategoryResponse, GetCategoryListResponse};
 use crate::presentation::colors::responses::{GetColorListItemResponse, GetColorListResponse};
 use crate::presentation::orders::requests::{
-    CreateOrderRequest, CreateOrderRequestCustomerInfo, CreateOrderRequestItem,
-    CreateOrderRequestShippingAddress,
+    AddOrderNoteRequest, AmendOrderRequest, CancelOrderRequest, CreateOrderRequest,
+    CreateOrderRequestCustomerInfo,
+    CreateOrderRequestItem, CreateOrderRequestShippingAddress,
+};
+use crate::presentation::orders::responses::{
+    CreateOrderResponse, GetOrderEventsResponse, GetOrderResponse, ListOrdersItemResponse, ListOrdersResponse,
+    OrderCustomerResponse, OrderEventResponse, OrderItemResponse, OrderPaymentResponse, OrderPricingResponse,
+    OrderPromotionResponse, OrderShipmentResponse, OrderShippingResponse,
+};
+use crate::presentation::admin_payment_methods::requests::{
+    PaymentFeeBracketRequest, UpdatePaymentFeeRuleRequest,
 };
-use crate::presentation::orders::responses::CreateOrderResponse;
 use crate::presentation::payment_methods::responses::{
-    GetPaymentMethodListResponse, PaymentMethodListItemResponse,
+    GetPaymentMethodListResponse, PaymentFeeBracketResponse, PaymentFeeRuleResponse,
+    PaymentMethodListItemResponse,
+};
+use crate::presentation::payments::requests::AuthorizePaymentRequest;
+use crate::presentation::payments::responses::{
+    PaymentHistoryResponse, PaymentResultResponse, PaymentTransactionResponse,
 };
 use crate::presentation::products::responses::{
-    GetProductListItemResponse, GetProductListResponse, GetProductResponse, VariantResponse,
+    GetProductListItemResponse, GetProductListResponse, GetProductResponse,
+    SearchProductsItemResponse, SearchProductsResponse, VariantResponse,
+};
+use crate::presentation::returns::requests::{
+    ReceiveReturnRequest, RejectReturnRequest, RequestReturnRequest, RequestReturnRequestItem,
 };
+use crate::presentation::returns::responses::{ReturnItemResponse, ReturnResponse};
+use crate::presentation::shipments::requests::{
+    AttachShipmentRequest, RecordShipmentEventRequest,
+};
+use crate::presentation::shipments::responses::{ShipmentAddressResponse, ShipmentResponse};
 use crate::presentation::shipping::responses::{
-    GetShippingMethodListItemResponse, GetShippingMethodListResponse,
+    GetShippingMethodListItemResponse, GetShippingMethodListResponse, OversizeSurchargeResponse,
+    ShippingRegionRateResponse,
 };
 use crate::presentation::variants::requests::FindVariantsRequest;
 use crate::presentation::variants::responses::{FindVariantsItemResponse, FindVariantsResponse};
@@ -29,19 +75,81 @@ use crate::presentation::variants::responses::{FindVariantsItemResponse, FindVar
     paths(
         crate::presentation::products::controllers::get_product_controller::handle,
         crate::presentation::products::controllers::get_product_list_controller::handle,
+        crate::presentation::products::controllers::search_products_controller::handle,
         crate::presentation::categories::controllers::get_category_list_controller::handle,
         crate::presentation::colors::controllers::get_color_list_controller::handle,
         crate::presentation::variants::controllers::find_variants_controller::handle,
         crate::presentation::cart::controllers::calculate_cart_controller::handle,
+        crate::presentation::cart::controllers::create_cart_controller::handle,
+        crate::presentation::cart::controllers::get_cart_controller::handle,
+        crate::presentation::cart::controllers::add_cart_item_controller::handle,
+        crate::presentation::cart::controllers::update_cart_item_controller::handle,
+        crate::presentation::cart::controllers::remove_cart_item_controller::handle,
+        crate::presentation::cart::controllers::set_cart_coupon_controller::handle,
+        crate::presentation::cart::controllers::remove_cart_coupon_controller::handle,
+        crate::presentation::cart::controllers::set_cart_shipping_method_controller::handle,
+        crate::presentation::cart::controllers::set_cart_payment_method_controller::handle,
         crate::presentation::orders::controllers::create_order_controller::handle,
+        crate::presentation::orders::controllers::get_order_controller::handle,
+        crate::presentation::orders::controllers::get_order_events_controller::handle,
+        crate::presentation::orders::controllers::list_orders_controller::handle,
+        crate::presentation::orders::controllers::list_my_orders_controller::handle,
+        crate::presentation::orders::controllers::mark_order_paid_controller::handle,
+        crate::presentation::orders::controllers::process_order_controller::handle,
+        crate::presentation::orders::controllers::ship_order_controller::handle,
+        crate::presentation::orders::controllers::deliver_order_controller::handle,
+        crate::presentation::orders::controllers::amend_order_controller::handle,
+        crate::presentation::orders::controllers::cancel_order_controller::handle,
+        crate::presentation::orders::controllers::add_order_note_controller::handle,
+        crate::presentation::orders::controllers::refund_order_controller::handle,
+        crate::presentation::orders::controllers::get_invoice_controller::handle,
+        crate::presentation::orders::controllers::get_receipt_controller::handle,
         crate::presentation::shipping::controllers::get_shipping_method_list_controller::handle,
         crate::presentation::payment_methods::controllers::get_payment_method_list_controller::handle,
+        crate::presentation::admin_products::controllers::create_product_controller::handle,
+        crate::presentation::admin_products::controllers::get_admin_product_controller::handle,
+        crate::presentation::admin_products::controllers::update_product_controller::handle,
+        crate::presentation::admin_products::controllers::delete_product_controller::handle,
+        crate::presentation::admin_products::controllers::publish_product_controller::handle,
+        crate::presentation::admin_products::controllers::unpublish_product_controller::handle,
+        crate::presentation::admin_products::controllers::add_product_sku_controller::handle,
+        crate::presentation::admin_products::controllers::update_product_sku_controller::handle,
+        crate::presentation::admin_products::controllers::remove_product_sku_controller::handle,
+        crate::presentation::admin_products::controllers::schedule_sku_sale_controller::handle,
+        crate::presentation::admin_inventory::controllers::adjust_stock_controller::handle,
+        crate::presentation::admin_inventory::controllers::get_stock_history_controller::handle,
+        crate::presentation::shipments::controllers::attach_shipment_controller::handle,
+        crate::presentation::shipments::controllers::get_shipment_controller::handle,
+        crate::presentation::shipments::controllers::record_shipment_event_controller::handle,
+        crate::presentation::payments::controllers::authorize_payment_controller::handle,
+        crate::presentation::payments::controllers::capture_payment_controller::handle,
+        crate::presentation::payments::controllers::void_payment_controller::handle,
+        crate::presentation::payments::controllers::get_payments_controller::handle,
+        crate::presentation::returns::controllers::request_return_controller::handle,
+        crate::presentation::returns::controllers::get_order_returns_controller::handle,
+        crate::presentation::returns::controllers::get_return_controller::handle,
+        crate::presentation::returns::controllers::approve_return_controller::handle,
+        crate::presentation::returns::controllers::reject_return_controller::handle,
+        crate::presentation::returns::controllers::receive_return_controller::handle,
+        crate::presentation::returns::controllers::refund_return_controller::handle,
+        crate::presentation::admin_payment_methods::controllers::update_payment_fee_rule_controller::handle,
+        crate::presentation::admin_coupons::controllers::get_coupon_list_controller::handle,
+        crate::presentation::admin_coupons::controllers::create_coupon_controller::handle,
+        crate::presentation::admin_coupons::controllers::generate_coupon_codes_controller::handle,
+        crate::presentation::admin_coupons::controllers::get_coupon_controller::handle,
+        crate::presentation::admin_coupons::controllers::update_coupon_controller::handle,
+        crate::presentation::admin_coupons::controllers::delete_coupon_controller::handle,
+        crate::presentation::auth::controllers::register_controller::handle,
+        crate::presentation::auth::controllers::login_controller::handle,
+        crate::presentation::auth::controllers::logout_controller::handle,
     ),
     components(
         schemas(
             GetProductResponse,
             GetProductListResponse,
             GetProductListItemResponse,
+            SearchProductsResponse,
+            SearchProductsItemResponse,
             VariantResponse,
             GetCategoryListResponse,
             CategoryResponse,
@@ -55,16 +163,82 @@ use crate::presentation::variants::responses::{FindVariantsItemResponse, FindVar
             CalculateCartResponse,
             CalculateCartItemResponse,
             AppliedCouponResponse,
+            AppliedPromotionResponse,
             CouponErrorResponse,
+            GetCartResponse,
+            AddCartItemRequest,
+            UpdateCartItemRequest,
+            SetCartCouponRequest,
+            SetCartShippingMethodRequest,
+            SetCartPaymentMethodRequest,
             CreateOrderRequest,
             CreateOrderRequestItem,
             CreateOrderRequestCustomerInfo,
             CreateOrderRequestShippingAddress,
             CreateOrderResponse,
+            AmendOrderRequest,
+            CancelOrderRequest,
+            AddOrderNoteRequest,
+            GetOrderResponse,
+            OrderCustomerResponse,
+            OrderItemResponse,
+            OrderShippingResponse,
+            OrderPaymentResponse,
+            OrderPricingResponse,
+            OrderPromotionResponse,
+            OrderShipmentResponse,
+            ListOrdersResponse,
+            ListOrdersItemResponse,
+            GetOrderEventsResponse,
+            OrderEventResponse,
             GetShippingMethodListResponse,
             GetShippingMethodListItemResponse,
+            ShippingRegionRateResponse,
+            OversizeSurchargeResponse,
             GetPaymentMethodListResponse,
             PaymentMethodListItemResponse,
+            CreateProductRequest,
+            UpdateProductRequest,
+            ProductSkuRequest,
+            ProductImageRequest,
+            UpdateProductSkuRequest,
+            ScheduleSkuSaleRequest,
+            AdminProductResponse,
+            AdminProductSkuResponse,
+            AdminPriceScheduleResponse,
+            AdminProductImageResponse,
+            AdjustStockRequest,
+            StockHistoryResponse,
+            StockMovementResponse,
+            AttachShipmentRequest,
+            RecordShipmentEventRequest,
+            ShipmentResponse,
+            ShipmentAddressResponse,
+            AuthorizePaymentRequest,
+            PaymentResultResponse,
+            PaymentHistoryResponse,
+            PaymentTransactionResponse,
+            RequestReturnRequest,
+            RequestReturnRequestItem,
+            RejectReturnRequest,
+            ReceiveReturnRequest,
+            ReturnResponse,
+            ReturnItemResponse,
+            PaymentFeeRuleResponse,
+            PaymentFeeBracketResponse,
+            UpdatePaymentFeeRuleRequest,
+            PaymentFeeBracketRequest,
+            CreateCouponRequest,
+            UpdateCouponRequest,
+            GenerateCouponCodesRequest,
+            CouponDiscountRequest,
+            CouponResponse,
+            CouponListResponse,
+            RegisterRequest,
+            LoginRequest,
+            SessionResponse,
+            CustomerResponse,
+            TaxBreakdownResponse,
             ErrorResponse
         )
     ),
@@ -76,8 +250,17 @@ use crate::presentation::variants::responses::{FindVariantsItemResponse, FindVar
         (name = "Cart", description = "カート関連のAPI"),
         (name = "Orders", description = "注文関連のAPI"),
         (name = "Shipping", description = "配送関連のAPI"),
-        (name = "PaymentMethods", description = "支払い方法関連のAPI")
+        (name = "PaymentMethods", description = "支払い方法関連のAPI"),
+        (name = "AdminProducts", description = "商品管理用のAPI"),
+        (name = "AdminInventory", description = "在庫管理用のAPI"),
+        (name = "Shipments", description = "出荷・配送追跡関連のAPI"),
+        (name = "Payments", description = "決済（オーソリ・売上確定・取消）関連のAPI"),
+        (name = "Returns", description = "返品（RMA）関連のAPI"),
+        (name = "AdminPaymentMethods", description = "支払い方法管理用のAPI"),
+        (name = "AdminCoupons", description = "クーポン管理用のAPI"),
+        (name = "Auth", description = "会員登録・ログイン関連のAPI")
     ),
+    modifiers(&SecurityAddon),
     info(
         title = "ECサイト API",
         description = "Clean Architectureを適用したECサイトのREST API",
@@ -93,3 +276,26 @@ use crate::presentation::variants::responses::{FindVariantsItemResponse, FindVar
     )
 )]
 pub struct ApiDoc;
+
+/// セッショントークン（`Authorization: Bearer <token>`）と
+/// 管理用トークン（`Authorization: Bearer <ADMIN_API_TOKEN>`）の認証方式を登録
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+        components.add_security_scheme(
+            "admin_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .description(Some("管理用トークン（ADMIN_API_TOKEN）"))
+                    .build(),
+            ),
+        );
+    }
+}